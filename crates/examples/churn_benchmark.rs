@@ -66,6 +66,7 @@ struct AppState {
     device: Arc<wgpu::Device>,
     queue: Arc<wgpu::Queue>,
     surface_format: wgpu::TextureFormat,
+    present_modes: Vec<wgpu::PresentMode>,
     renderer: Renderer,
     last_frame: std::time::Instant,
     frame_count: u64,
@@ -123,9 +124,9 @@ impl ApplicationHandler for App {
         let caps = surface.get_capabilities(&adapter);
         let fmt = caps.formats.iter().copied().find(|f| f.is_srgb()).unwrap_or(caps.formats[0]);
         let size = window.inner_size();
-        surface.configure(&device, &wgpu::SurfaceConfiguration { usage: wgpu::TextureUsages::RENDER_ATTACHMENT, format: fmt, width: size.width, height: size.height, present_mode: wgpu::PresentMode::Fifo, alpha_mode: caps.alpha_modes[0], view_formats: vec![], desired_maximum_frame_latency: 1, color_space: wgpu::SurfaceColorSpace::Auto });
-
         let config = RendererConfig::new(size.width, size.height, fmt);
+        surface.configure(&device, &wgpu::SurfaceConfiguration { usage: wgpu::TextureUsages::RENDER_ATTACHMENT, format: fmt, width: size.width, height: size.height, present_mode: config.present_mode.resolve(&caps.present_modes), alpha_mode: caps.alpha_modes[0], view_formats: vec![], desired_maximum_frame_latency: 1, color_space: wgpu::SurfaceColorSpace::Auto });
+
         let scene = Scene::new(device.clone(), queue.clone());
         let debug_camera_buf = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Debug Camera Buffer"),
@@ -172,6 +173,7 @@ impl ApplicationHandler for App {
             device,
             queue,
             surface_format: fmt,
+            present_modes: caps.present_modes,
             renderer,
             last_frame: std::time::Instant::now(),
             frame_count: 0,
@@ -246,7 +248,7 @@ impl ApplicationHandler for App {
                     format: state.surface_format,
                     width: s.width,
                     height: s.height,
-                    present_mode: wgpu::PresentMode::Fifo,
+                    present_mode: state.renderer.present_mode().resolve(&state.present_modes),
                     alpha_mode: wgpu::CompositeAlphaMode::Auto,
                     view_formats: vec![],
                     desired_maximum_frame_latency: 1,
@@ -49,6 +49,7 @@ struct AppState {
     device: Arc<wgpu::Device>,
     queue: Arc<wgpu::Queue>,
     surface_format: wgpu::TextureFormat,
+    present_modes: Vec<wgpu::PresentMode>,
     renderer: Renderer,
     last_frame: std::time::Instant,
     start_time: std::time::Instant,
@@ -218,6 +219,7 @@ impl ApplicationHandler for App {
             .copied()
             .unwrap_or(caps.formats[0]);
         let size = window.inner_size();
+        let config = RendererConfig::new(size.width, size.height, surface_format);
         surface.configure(
             &device,
             &wgpu::SurfaceConfiguration {
@@ -225,7 +227,7 @@ impl ApplicationHandler for App {
                 format: surface_format,
                 width: size.width,
                 height: size.height,
-                present_mode: wgpu::PresentMode::Fifo,
+                present_mode: config.present_mode.resolve(&caps.present_modes),
                 alpha_mode: caps.alpha_modes[0],
                 view_formats: vec![],
                 desired_maximum_frame_latency: 2,
@@ -233,7 +235,6 @@ impl ApplicationHandler for App {
             },
         );
 
-        let config = RendererConfig::new(size.width, size.height, surface_format);
         let scene = Scene::new(device.clone(), queue.clone());
         let debug_camera_buf = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Debug Camera Buffer"),
@@ -303,6 +304,7 @@ impl ApplicationHandler for App {
             device,
             queue,
             surface_format,
+            present_modes: caps.present_modes,
             renderer,
             last_frame: std::time::Instant::now(),
             start_time: std::time::Instant::now(),
@@ -348,7 +350,7 @@ impl ApplicationHandler for App {
                         format: state.surface_format,
                         width: size.width,
                         height: size.height,
-                        present_mode: wgpu::PresentMode::Fifo,
+                        present_mode: state.renderer.present_mode().resolve(&state.present_modes),
                         alpha_mode: wgpu::CompositeAlphaMode::Opaque,
                         view_formats: vec![],
                         desired_maximum_frame_latency: 2,
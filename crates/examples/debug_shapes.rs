@@ -42,6 +42,7 @@ struct AppState {
     device: Arc<wgpu::Device>,
     queue: Arc<wgpu::Queue>,
     surface_format: wgpu::TextureFormat,
+    present_modes: Vec<wgpu::PresentMode>,
     renderer: Renderer,
     last_frame: std::time::Instant,
     cam_pos: glam::Vec3,
@@ -101,6 +102,7 @@ impl ApplicationHandler for App {
             .find(|f| f.is_srgb())
             .unwrap_or(caps.formats[0]);
         let size = window.inner_size();
+        let config = RendererConfig::new(size.width, size.height, format);
         surface.configure(
             &device,
             &wgpu::SurfaceConfiguration {
@@ -108,7 +110,7 @@ impl ApplicationHandler for App {
                 format,
                 width: size.width,
                 height: size.height,
-                present_mode: wgpu::PresentMode::AutoVsync,
+                present_mode: config.present_mode.resolve(&caps.present_modes),
                 alpha_mode: caps.alpha_modes[0],
                 view_formats: vec![],
                 desired_maximum_frame_latency: 2,
@@ -116,7 +118,6 @@ impl ApplicationHandler for App {
             },
         );
 
-        let config = RendererConfig::new(size.width, size.height, format);
         let scene = Scene::new(device.clone(), queue.clone());
         let debug_camera_buf = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Debug Camera Buffer"),
@@ -137,6 +138,7 @@ impl ApplicationHandler for App {
             config.surface_format, config.width, config.height, config.render_scale,
             config, scene, graph, debug_state, debug_camera_buf, cull_stats_buf,
         );
+        renderer.set_adapter(adapter);
         renderer.set_clear_color([0.12, 0.12, 0.16, 1.0]);
         renderer.set_ambient([0.20, 0.22, 0.30], 0.18);
         renderer.set_editor_mode(true);
@@ -147,6 +149,7 @@ impl ApplicationHandler for App {
             device,
             queue,
             surface_format: format,
+            present_modes: caps.present_modes,
             renderer,
             last_frame: std::time::Instant::now(),
             cam_pos: glam::Vec3::new(0.0, 3.0, 10.0),
@@ -171,7 +174,7 @@ impl ApplicationHandler for App {
                         format: state.surface_format,
                         width: sz.width,
                         height: sz.height,
-                        present_mode: wgpu::PresentMode::AutoVsync,
+                        present_mode: state.renderer.present_mode().resolve(&state.present_modes),
                         alpha_mode: wgpu::CompositeAlphaMode::Auto,
                         view_formats: vec![],
                         desired_maximum_frame_latency: 2,
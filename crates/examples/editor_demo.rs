@@ -71,6 +71,7 @@ struct AppState {
     device: Arc<wgpu::Device>,
     queue: Arc<wgpu::Queue>,
     surface_format: wgpu::TextureFormat,
+    present_modes: Vec<wgpu::PresentMode>,
     surface_alpha_mode: wgpu::CompositeAlphaMode,
     renderer: Renderer,
     last_frame: std::time::Instant,
@@ -148,6 +149,9 @@ impl ApplicationHandler for App {
             .unwrap_or(caps.formats[0]);
         let alpha_mode = caps.alpha_modes[0];
         let sz = window.inner_size();
+
+        // ── Renderer ──────────────────────────────────────────────────────
+        let config = RendererConfig::new(sz.width, sz.height, format);
         surface.configure(
             &device,
             &wgpu::SurfaceConfiguration {
@@ -155,7 +159,7 @@ impl ApplicationHandler for App {
                 format,
                 width: sz.width,
                 height: sz.height,
-                present_mode: wgpu::PresentMode::AutoVsync,
+                present_mode: config.present_mode.resolve(&caps.present_modes),
                 alpha_mode,
                 view_formats: vec![],
                 desired_maximum_frame_latency: 2,
@@ -163,8 +167,6 @@ impl ApplicationHandler for App {
             },
         );
 
-        // ── Renderer ──────────────────────────────────────────────────────
-        let config = RendererConfig::new(sz.width, sz.height, format);
         let scene = Scene::new(device.clone(), queue.clone());
         let debug_camera_buf = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Debug Camera Buffer"),
@@ -804,6 +806,7 @@ impl ApplicationHandler for App {
             device,
             queue,
             surface_format: format,
+            present_modes: caps.present_modes,
             surface_alpha_mode: alpha_mode,
             renderer,
             last_frame: std::time::Instant::now(),
@@ -1064,7 +1067,7 @@ impl AppState {
                 format: self.surface_format,
                 width,
                 height,
-                present_mode: wgpu::PresentMode::AutoVsync,
+                present_mode: self.renderer.present_mode().resolve(&self.present_modes),
                 alpha_mode: self.surface_alpha_mode,
                 view_formats: vec![],
                 desired_maximum_frame_latency: 2,
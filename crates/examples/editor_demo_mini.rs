@@ -72,6 +72,7 @@ struct AppState {
     device: Arc<wgpu::Device>,
     queue: Arc<wgpu::Queue>,
     surface_format: wgpu::TextureFormat,
+    present_modes: Vec<wgpu::PresentMode>,
     surface_alpha_mode: wgpu::CompositeAlphaMode,
     renderer: Renderer,
     last_frame: std::time::Instant,
@@ -152,6 +153,10 @@ impl ApplicationHandler for App {
             .unwrap_or(caps.formats[0]);
         let alpha_mode = caps.alpha_modes[0];
         let sz = window.inner_size();
+
+        // ── Renderer (FXAA pipeline: full-res, no TAA jitter/upscaling) ───
+        let config = RendererConfig::new(sz.width, sz.height, format)
+                .with_render_scale(1.0);
         surface.configure(
             &device,
             &wgpu::SurfaceConfiguration {
@@ -159,7 +164,7 @@ impl ApplicationHandler for App {
                 format,
                 width: sz.width,
                 height: sz.height,
-                present_mode: wgpu::PresentMode::AutoVsync,
+                present_mode: config.present_mode.resolve(&caps.present_modes),
                 alpha_mode,
                 view_formats: vec![],
                 desired_maximum_frame_latency: 2,
@@ -167,9 +172,6 @@ impl ApplicationHandler for App {
             },
         );
 
-        // ── Renderer (FXAA pipeline: full-res, no TAA jitter/upscaling) ───
-        let config = RendererConfig::new(sz.width, sz.height, format)
-                .with_render_scale(1.0);
         let scene = Scene::new(device.clone(), queue.clone());
         let debug_camera_buf = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Debug Camera Buffer"),
@@ -804,6 +806,7 @@ impl ApplicationHandler for App {
             device,
             queue,
             surface_format: format,
+            present_modes: caps.present_modes,
             surface_alpha_mode: alpha_mode,
             renderer,
             last_frame: std::time::Instant::now(),
@@ -1164,7 +1167,7 @@ impl AppState {
                 format: self.surface_format,
                 width,
                 height,
-                present_mode: wgpu::PresentMode::AutoVsync,
+                present_mode: self.renderer.present_mode().resolve(&self.present_modes),
                 alpha_mode: self.surface_alpha_mode,
                 view_formats: vec![],
                 desired_maximum_frame_latency: 2,
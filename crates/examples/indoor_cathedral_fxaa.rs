@@ -99,6 +99,7 @@ struct AppState {
     device: Arc<wgpu::Device>,
     queue: Arc<wgpu::Queue>,
     surface_format: wgpu::TextureFormat,
+    present_modes: Vec<wgpu::PresentMode>,
     renderer: Arc<Mutex<Renderer>>,
     action_rx: Receiver<HelioAction>,
     last_frame: std::time::Instant,
@@ -203,6 +204,9 @@ impl ApplicationHandler for App {
             .copied()
             .unwrap_or(caps.formats[0]);
         let size = window.inner_size();
+        let config = RendererConfig::new(size.width, size.height, format)
+                .with_shadow_quality(helio::ShadowQuality::Ultra)
+                .with_render_scale(1.0);
         surface.configure(
             &device,
             &wgpu::SurfaceConfiguration {
@@ -210,7 +214,7 @@ impl ApplicationHandler for App {
                 format,
                 width: size.width,
                 height: size.height,
-                present_mode: wgpu::PresentMode::Fifo,
+                present_mode: config.present_mode.resolve(&caps.present_modes),
                 alpha_mode: caps.alpha_modes[0],
                 view_formats: vec![],
                 desired_maximum_frame_latency: 2,
@@ -218,9 +222,6 @@ impl ApplicationHandler for App {
             },
         );
 
-        let config = RendererConfig::new(size.width, size.height, format)
-                .with_shadow_quality(helio::ShadowQuality::Ultra)
-                .with_render_scale(1.0);
         let scene = Scene::new(device.clone(), queue.clone());
         let debug_camera_buf = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Debug Camera Buffer"),
@@ -720,6 +721,7 @@ impl ApplicationHandler for App {
             device,
             queue,
             surface_format: format,
+            present_modes: caps.present_modes,
             renderer,
             action_rx,
             last_frame: std::time::Instant::now(),
@@ -879,7 +881,7 @@ impl ApplicationHandler for App {
                         format: state.surface_format,
                         width: s.width,
                         height: s.height,
-                        present_mode: wgpu::PresentMode::Fifo,
+                        present_mode: state.renderer.present_mode().resolve(&state.present_modes),
                         alpha_mode: wgpu::CompositeAlphaMode::Auto,
                         view_formats: vec![],
                         desired_maximum_frame_latency: 2,
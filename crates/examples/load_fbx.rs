@@ -31,6 +31,7 @@ struct AppState {
     device: Arc<wgpu::Device>,
     queue: Arc<wgpu::Queue>,
     surface_format: wgpu::TextureFormat,
+    present_modes: Vec<wgpu::PresentMode>,
     renderer: Renderer,
     point_light_id: helio::LightId,
     point_light_pos: Vec3,
@@ -133,6 +134,7 @@ impl ApplicationHandler for App {
             .copied()
             .unwrap_or(caps.formats[0]);
         let size = window.inner_size();
+        let config = RendererConfig::new(size.width, size.height, surface_format);
         surface.configure(
             &device,
             &wgpu::SurfaceConfiguration {
@@ -140,7 +142,7 @@ impl ApplicationHandler for App {
                 format: surface_format,
                 width: size.width,
                 height: size.height,
-                present_mode: wgpu::PresentMode::Fifo,
+                present_mode: config.present_mode.resolve(&caps.present_modes),
                 alpha_mode: caps.alpha_modes[0],
                 view_formats: vec![],
                 desired_maximum_frame_latency: 2,
@@ -148,7 +150,6 @@ impl ApplicationHandler for App {
             },
         );
 
-        let config = RendererConfig::new(size.width, size.height, surface_format);
         let scene = Scene::new(device.clone(), queue.clone());
         let debug_camera_buf = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Debug Camera Buffer"),
@@ -258,6 +259,7 @@ impl ApplicationHandler for App {
             device,
             queue,
             surface_format,
+            present_modes: caps.present_modes,
             renderer,
             point_light_id,
             point_light_pos,
@@ -302,7 +304,7 @@ impl ApplicationHandler for App {
                         format: state.surface_format,
                         width: size.width,
                         height: size.height,
-                        present_mode: wgpu::PresentMode::Fifo,
+                        present_mode: state.renderer.present_mode().resolve(&state.present_modes),
                         alpha_mode: wgpu::CompositeAlphaMode::Opaque,
                         view_formats: vec![],
                         desired_maximum_frame_latency: 2,
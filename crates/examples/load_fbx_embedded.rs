@@ -239,6 +239,7 @@ struct AppState {
     device: Arc<wgpu::Device>,
     queue: Arc<wgpu::Queue>,
     surface_format: wgpu::TextureFormat,
+    present_modes: Vec<wgpu::PresentMode>,
     renderer: Renderer,
     movement_speed: f32,
     last_frame: Instant,
@@ -335,6 +336,7 @@ impl ApplicationHandler for App {
             .copied()
             .unwrap_or(caps.formats[0]);
         let size = window.inner_size();
+        let config = RendererConfig::new(size.width, size.height, surface_format);
         surface.configure(
             &device,
             &wgpu::SurfaceConfiguration {
@@ -342,14 +344,13 @@ impl ApplicationHandler for App {
                 format: surface_format,
                 width: size.width,
                 height: size.height,
-                present_mode: wgpu::PresentMode::Fifo,
+                present_mode: config.present_mode.resolve(&caps.present_modes),
                 alpha_mode: caps.alpha_modes[0],
                 view_formats: vec![],
                 desired_maximum_frame_latency: 2,
                 color_space: wgpu::SurfaceColorSpace::Auto,
             },
         );
-        let config = RendererConfig::new(size.width, size.height, surface_format);
         let scene = Scene::new(device.clone(), queue.clone());
         let debug_camera_buf = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Debug Camera Buffer"),
@@ -408,6 +409,7 @@ impl ApplicationHandler for App {
                     device,
                     queue,
                     surface_format,
+                    present_modes: caps.present_modes,
                     renderer,
                     movement_speed: fallback_bounds.movement_speed(),
                     last_frame: Instant::now(),
@@ -459,6 +461,7 @@ impl ApplicationHandler for App {
             device,
             queue,
             surface_format,
+            present_modes: caps.present_modes,
             renderer,
             movement_speed: bounds.movement_speed(),
             last_frame: Instant::now(),
@@ -502,7 +505,7 @@ impl ApplicationHandler for App {
                         format: state.surface_format,
                         width: size.width,
                         height: size.height,
-                        present_mode: wgpu::PresentMode::Fifo,
+                        present_mode: state.renderer.present_mode().resolve(&state.present_modes),
                         alpha_mode: wgpu::CompositeAlphaMode::Opaque,
                         view_formats: vec![],
                         desired_maximum_frame_latency: 2,
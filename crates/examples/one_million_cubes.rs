@@ -52,6 +52,7 @@ struct AppState {
     surface: wgpu::Surface<'static>,
     device: Arc<wgpu::Device>,
     surface_format: wgpu::TextureFormat,
+    present_modes: Vec<wgpu::PresentMode>,
     renderer: Renderer,
     last_frame: std::time::Instant,
     start_time: std::time::Instant,
@@ -133,12 +134,13 @@ impl ApplicationHandler for App {
             .unwrap_or(surface_caps.formats[0]);
 
         let size = window.inner_size();
+        let config = RendererConfig::new(size.width, size.height, surface_format);
         let surface_config = wgpu::SurfaceConfiguration {
             usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
             format: surface_format,
             width: size.width,
             height: size.height,
-            present_mode: wgpu::PresentMode::Fifo,
+            present_mode: config.present_mode.resolve(&surface_caps.present_modes),
             alpha_mode: surface_caps.alpha_modes[0],
             view_formats: vec![],
             desired_maximum_frame_latency: 2,
@@ -146,7 +148,6 @@ impl ApplicationHandler for App {
         };
         surface.configure(&device, &surface_config);
 
-        let config = RendererConfig::new(size.width, size.height, surface_format);
         let scene = Scene::new(device.clone(), queue.clone());
         let debug_camera_buf = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Debug Camera Buffer"),
@@ -288,6 +289,7 @@ impl ApplicationHandler for App {
             surface,
             device,
             surface_format,
+            present_modes: surface_caps.present_modes,
             renderer,
             last_frame: std::time::Instant::now(),
             start_time: std::time::Instant::now(),
@@ -370,7 +372,7 @@ impl ApplicationHandler for App {
                     format: state.surface_format,
                     width: size.width,
                     height: size.height,
-                    present_mode: wgpu::PresentMode::Fifo,
+                    present_mode: state.renderer.present_mode().resolve(&state.present_modes),
                     alpha_mode: wgpu::CompositeAlphaMode::Auto,
                     view_formats: vec![],
                     desired_maximum_frame_latency: 2,
@@ -24,8 +24,8 @@ use std::time::Instant;
 
 use glam::{EulerRot, Mat4, Quat, Vec3};
 use helio::{
-    required_wgpu_features, required_wgpu_limits, Camera, DebugDrawState, LightId, Renderer,
-    RendererConfig, Scene, VirtualMeshUpload, VirtualObjectDescriptor,
+    required_wgpu_features, required_wgpu_limits, Camera, DebugDrawState, LightId, PresentMode,
+    Renderer, RendererConfig, Scene, VirtualMeshUpload, VirtualObjectDescriptor,
 };
 use helio_default_graphs::build_default_graph;
 use helio_asset_compat::{
@@ -87,6 +87,7 @@ struct AppState {
     device: Arc<wgpu::Device>,
     queue: Arc<wgpu::Queue>,
     surface_format: wgpu::TextureFormat,
+    present_modes: Vec<wgpu::PresentMode>,
     renderer: Renderer,
     last_frame: Instant,
     start_time: Instant,
@@ -208,6 +209,8 @@ impl ApplicationHandler for App {
             .copied()
             .unwrap_or(caps.formats[0]);
         let size = window.inner_size();
+        let config = RendererConfig::new(size.width, size.height, surface_format)
+            .with_present_mode(PresentMode::Immediate);
         surface.configure(
             &device,
             &wgpu::SurfaceConfiguration {
@@ -215,7 +218,7 @@ impl ApplicationHandler for App {
                 format: surface_format,
                 width: size.width,
                 height: size.height,
-                present_mode: wgpu::PresentMode::AutoNoVsync,
+                present_mode: config.present_mode.resolve(&caps.present_modes),
                 alpha_mode: caps.alpha_modes[0],
                 view_formats: vec![],
                 desired_maximum_frame_latency: 2,
@@ -223,7 +226,6 @@ impl ApplicationHandler for App {
             },
         );
 
-        let config = RendererConfig::new(size.width, size.height, surface_format);
         let scene = Scene::new(device.clone(), queue.clone());
         let debug_camera_buf = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Debug Camera Buffer"),
@@ -467,6 +469,7 @@ impl ApplicationHandler for App {
             device,
             queue,
             surface_format,
+            present_modes: caps.present_modes,
             renderer,
             last_frame: Instant::now(),
             start_time: Instant::now(),
@@ -521,7 +524,7 @@ impl ApplicationHandler for App {
                         format: state.surface_format,
                         width: size.width,
                         height: size.height,
-                        present_mode: wgpu::PresentMode::AutoNoVsync,
+                        present_mode: state.renderer.present_mode().resolve(&state.present_modes),
                         alpha_mode: wgpu::CompositeAlphaMode::Opaque,
                         view_formats: vec![],
                         desired_maximum_frame_latency: 2,
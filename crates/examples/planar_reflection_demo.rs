@@ -111,6 +111,7 @@ impl ApplicationHandler for App {
             .copied()
             .unwrap_or(caps.formats[0]);
         let size = window.inner_size();
+        let mut renderer_config = RendererConfig::new(size.width, size.height, surface_format);
         surface.configure(
             &device,
             &wgpu::SurfaceConfiguration {
@@ -118,7 +119,7 @@ impl ApplicationHandler for App {
                 format: surface_format,
                 width: size.width,
                 height: size.height,
-                present_mode: wgpu::PresentMode::Fifo,
+                present_mode: renderer_config.present_mode.resolve(&caps.present_modes),
                 alpha_mode: caps.alpha_modes[0],
                 color_space: wgpu::SurfaceColorSpace::Auto,
                 view_formats: vec![],
@@ -126,7 +127,6 @@ impl ApplicationHandler for App {
             },
         );
 
-        let mut renderer_config = RendererConfig::new(size.width, size.height, surface_format);
         // HELIO_DEBUG_MODE=30 -> SSR confidence, 31 -> SSR colour. See the debug
         // blocks in deferred_lighting.wgsl for the full list.
         if let Ok(mode) = std::env::var("HELIO_DEBUG_MODE") {
@@ -411,7 +411,7 @@ impl ApplicationHandler for App {
                         format: state.surface_format,
                         width: w,
                         height: h,
-                        present_mode: wgpu::PresentMode::Fifo,
+                        present_mode: state.renderer.present_mode().resolve(&caps.present_modes),
                         alpha_mode: caps.alpha_modes[0],
                         color_space: wgpu::SurfaceColorSpace::Auto,
                         view_formats: vec![],
@@ -53,6 +53,7 @@ struct AppState {
     device: Arc<wgpu::Device>,
     queue: Arc<wgpu::Queue>,
     surface_format: wgpu::TextureFormat,
+    present_modes: Vec<wgpu::PresentMode>,
     alpha_mode: wgpu::CompositeAlphaMode,
     renderer: Renderer,
     last_frame: Instant,
@@ -117,6 +118,7 @@ impl ApplicationHandler for App {
             .unwrap_or(caps.formats[0]);
         let alpha_mode = caps.alpha_modes[0];
 
+        let config = RendererConfig::new(size.width, size.height, surface_format);
         surface.configure(
             &device,
             &wgpu::SurfaceConfiguration {
@@ -124,7 +126,7 @@ impl ApplicationHandler for App {
                 format: surface_format,
                 width: size.width,
                 height: size.height,
-                present_mode: wgpu::PresentMode::Fifo,
+                present_mode: config.present_mode.resolve(&caps.present_modes),
                 alpha_mode,
                 view_formats: vec![],
                 desired_maximum_frame_latency: 2,
@@ -132,7 +134,6 @@ impl ApplicationHandler for App {
             },
         );
 
-        let config = RendererConfig::new(size.width, size.height, surface_format);
         let mut scene = Scene::new(device.clone(), queue.clone());
         let debug_camera_buf = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Debug Camera Buffer"),
@@ -572,6 +573,7 @@ impl ApplicationHandler for App {
             device,
             queue,
             surface_format,
+            present_modes: caps.present_modes,
             alpha_mode,
             renderer,
             last_frame: Instant::now(),
@@ -603,7 +605,7 @@ impl ApplicationHandler for App {
                             format: state.surface_format,
                             width: new_size.width,
                             height: new_size.height,
-                            present_mode: wgpu::PresentMode::Fifo,
+                            present_mode: state.renderer.present_mode().resolve(&state.present_modes),
                             alpha_mode: state.alpha_mode,
                             view_formats: vec![],
                             desired_maximum_frame_latency: 2,
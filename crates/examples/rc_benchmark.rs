@@ -53,6 +53,7 @@ struct AppState {
     device: Arc<wgpu::Device>,
     queue: Arc<wgpu::Queue>,
     surface_format: wgpu::TextureFormat,
+    present_modes: Vec<wgpu::PresentMode>,
     renderer: Renderer,
     last_frame: std::time::Instant,
     cam_pos: glam::Vec3,
@@ -115,6 +116,7 @@ impl ApplicationHandler for App {
             .copied()
             .find(|f| f.is_srgb())
             .unwrap_or(caps.formats[0]);
+        let config = RendererConfig::new(size.width, size.height, fmt);
         surface.configure(
             &device,
             &wgpu::SurfaceConfiguration {
@@ -122,7 +124,7 @@ impl ApplicationHandler for App {
                 format: fmt,
                 width: size.width,
                 height: size.height,
-                present_mode: wgpu::PresentMode::AutoVsync,
+                present_mode: config.present_mode.resolve(&caps.present_modes),
                 desired_maximum_frame_latency: 1,
                 alpha_mode: caps.alpha_modes[0],
                 view_formats: vec![],
@@ -130,7 +132,6 @@ impl ApplicationHandler for App {
             },
         );
 
-        let config = RendererConfig::new(size.width, size.height, fmt);
         let scene = Scene::new(device.clone(), queue.clone());
         let debug_camera_buf = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Debug Camera Buffer"),
@@ -220,6 +221,7 @@ impl ApplicationHandler for App {
             device,
             queue,
             surface_format: fmt,
+            present_modes: caps.present_modes,
             renderer,
             last_frame: std::time::Instant::now(),
             cam_pos: glam::Vec3::new(0.0, 2.5, 8.0),
@@ -329,7 +331,7 @@ impl ApplicationHandler for App {
                         format: state.surface_format,
                         width: sz.width,
                         height: sz.height,
-                        present_mode: wgpu::PresentMode::AutoVsync,
+                        present_mode: state.renderer.present_mode().resolve(&state.present_modes),
                         desired_maximum_frame_latency: 1,
                         alpha_mode: wgpu::CompositeAlphaMode::Auto,
                         view_formats: vec![],
@@ -45,6 +45,7 @@ struct AppState {
     device: Arc<wgpu::Device>,
     queue: Arc<wgpu::Queue>,
     surface_format: wgpu::TextureFormat,
+    present_modes: Vec<wgpu::PresentMode>,
     renderer: Renderer,
     last_frame: std::time::Instant,
     start_time: std::time::Instant,
@@ -128,21 +129,22 @@ impl ApplicationHandler for App {
             .unwrap_or(surface_caps.formats[0]);
 
         let size = window.inner_size();
-        let config = wgpu::SurfaceConfiguration {
+
+        // Features — data-free: all content comes from the Scene
+        let config = RendererConfig::new(size.width, size.height, surface_format);
+        let surface_config = wgpu::SurfaceConfiguration {
             usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
             format: surface_format,
             width: size.width,
             height: size.height,
-            present_mode: wgpu::PresentMode::Fifo,
+            present_mode: config.present_mode.resolve(&surface_caps.present_modes),
             alpha_mode: surface_caps.alpha_modes[0],
             view_formats: vec![],
             desired_maximum_frame_latency: 2,
             color_space: wgpu::SurfaceColorSpace::Auto,
         };
-        surface.configure(&device, &config);
+        surface.configure(&device, &surface_config);
 
-        // Features — data-free: all content comes from the Scene
-        let config = RendererConfig::new(size.width, size.height, surface_format);
         let scene = Scene::new(device.clone(), queue.clone());
         let debug_camera_buf = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Debug Camera Buffer"),
@@ -197,6 +199,7 @@ impl ApplicationHandler for App {
             device,
             queue,
             surface_format,
+            present_modes: surface_caps.present_modes,
             renderer,
             last_frame: std::time::Instant::now(),
             start_time: std::time::Instant::now(),
@@ -283,7 +286,7 @@ impl ApplicationHandler for App {
                     format: state.surface_format,
                     width: size.width,
                     height: size.height,
-                    present_mode: wgpu::PresentMode::Fifo,
+                    present_mode: state.renderer.present_mode().resolve(&state.present_modes),
                     alpha_mode: wgpu::CompositeAlphaMode::Auto,
                     view_formats: vec![],
                     desired_maximum_frame_latency: 2,
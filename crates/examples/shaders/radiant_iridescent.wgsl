@@ -298,6 +298,6 @@ fn fs_main(input: VertexOutput) -> GBufferOutput {
     out.emissive = vec4<f32>(surface.emissive, surface.specular_f0.b);
     out.lightmap_uv = input.lightmap_uv;
     out.sss = vec4<f32>(surface.subsurface_color, surface.subsurface_radius);
-    out.extra = vec4<f32>(surface.roughness_aniso_x, surface.roughness_aniso_y, surface.aniso_rotation, bitcast<f32>(surface.flags));
+    out.extra = vec4<f32>(surface.roughness_aniso_x, surface.roughness_aniso_y, surface.aniso_rotation, f32(surface.flags));
     return out;
 }
@@ -166,27 +166,11 @@ pub fn box_mesh(center: [f32; 3], half_extents: [f32; 3]) -> MeshUpload {
 }
 
 pub fn plane_mesh(center: [f32; 3], half_extent: f32) -> MeshUpload {
-    let c = Vec3::from_array(center);
-    let e = half_extent;
-    let normal = [0.0, 1.0, 0.0];
-    let tangent = [1.0, 0.0, 0.0];
-    let positions = [
-        c + Vec3::new(-e, 0.0, -e),
-        c + Vec3::new(e, 0.0, -e),
-        c + Vec3::new(e, 0.0, e),
-        c + Vec3::new(-e, 0.0, e),
-    ];
-    let uvs = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
-    let vertices = positions
-        .into_iter()
-        .zip(uvs)
-        .map(|(position, uv)| {
-            PackedVertex::from_components(position.to_array(), normal, uv, tangent, 1.0)
-        })
-        .collect();
-    // Reverse triangle winding so the top-facing plane is front-facing (visible from above).
-    let indices = vec![0, 2, 1, 0, 3, 2];
-    MeshUpload { vertices, indices }
+    let mut mesh = helio::create_plane_mesh(half_extent * 2.0, half_extent * 2.0);
+    for v in &mut mesh.vertices {
+        v.position = (Vec3::from_array(v.position) + Vec3::from_array(center)).to_array();
+    }
+    mesh
 }
 
 pub fn sphere_mesh(center: [f32; 3], radius: f32) -> MeshUpload {
@@ -69,6 +69,7 @@ struct AppState {
     surface: wgpu::Surface<'static>,
     device: Arc<wgpu::Device>,
     surface_format: wgpu::TextureFormat,
+    present_modes: Vec<wgpu::PresentMode>,
     renderer: Renderer,
     last_frame: std::time::Instant,
 
@@ -159,12 +160,20 @@ impl ApplicationHandler for App {
             .unwrap_or(surface_caps.formats[0]);
 
         let size = window.inner_size();
+
+        // Full internal resolution. RendererConfig defaults to render_scale 0.75,
+        // which upscales 960x540 -> 1280x720 and shows as soft, stair-stepped
+        // edges — fine in a game demo, but here it would be mistaken for fog
+        // quality. Fog accumulates at a quarter of *this*, so the base wants to
+        // be honest.
+        let config = RendererConfig::new(size.width, size.height, surface_format)
+            .with_render_scale(1.0);
         let cfg = wgpu::SurfaceConfiguration {
             usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
             format: surface_format,
             width: size.width,
             height: size.height,
-            present_mode: wgpu::PresentMode::Fifo,
+            present_mode: config.present_mode.resolve(&surface_caps.present_modes),
             alpha_mode: surface_caps.alpha_modes[0],
             view_formats: vec![],
             desired_maximum_frame_latency: 2,
@@ -172,13 +181,6 @@ impl ApplicationHandler for App {
         };
         surface.configure(&device, &cfg);
 
-        // Full internal resolution. RendererConfig defaults to render_scale 0.75,
-        // which upscales 960x540 -> 1280x720 and shows as soft, stair-stepped
-        // edges — fine in a game demo, but here it would be mistaken for fog
-        // quality. Fog accumulates at a quarter of *this*, so the base wants to
-        // be honest.
-        let config = RendererConfig::new(size.width, size.height, surface_format)
-            .with_render_scale(1.0);
         let scene = Scene::new(device.clone(), queue.clone());
         let debug_camera_buf = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Debug Camera Buffer"),
@@ -328,6 +330,7 @@ impl ApplicationHandler for App {
             surface,
             device,
             surface_format,
+            present_modes: surface_caps.present_modes,
             renderer,
             last_frame: std::time::Instant::now(),
             cam_pos: glam::Vec3::new(0.0, 2.0, 16.0),
@@ -416,7 +419,7 @@ impl ApplicationHandler for App {
                     format: state.surface_format,
                     width: size.width,
                     height: size.height,
-                    present_mode: wgpu::PresentMode::Fifo,
+                    present_mode: state.renderer.present_mode().resolve(&state.present_modes),
                     alpha_mode: wgpu::CompositeAlphaMode::Auto,
                     view_formats: vec![],
                     desired_maximum_frame_latency: 2,
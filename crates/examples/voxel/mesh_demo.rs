@@ -53,6 +53,7 @@ struct AppState {
     device: Arc<wgpu::Device>,
     queue: Arc<wgpu::Queue>,
     surface_format: wgpu::TextureFormat,
+    present_modes: Vec<wgpu::PresentMode>,
     alpha_mode: wgpu::CompositeAlphaMode,
     renderer: Renderer,
     last_frame: Instant,
@@ -96,7 +97,7 @@ impl AppState {
                 color_space: wgpu::SurfaceColorSpace::Auto,
                 width,
                 height,
-                present_mode: wgpu::PresentMode::Fifo,
+                present_mode: self.renderer.present_mode().resolve(&self.present_modes),
                 alpha_mode: self.alpha_mode,
                 view_formats: vec![],
                 desired_maximum_frame_latency: 2,
@@ -225,6 +226,12 @@ impl ApplicationHandler for App {
             .unwrap_or(caps.formats[0]);
         let alpha_mode = caps.alpha_modes[0];
 
+        // ── Renderer + custom graph with voxel pass ──────────────────────────
+        // render_scale defaults to 0.75 (Renderer's shared depth texture is sized
+        // from it), but our graph has no TAA upscale step and locks pre_aa at
+        // full window resolution — a scaled depth buffer here would mismatch the
+        // full-res color attachment, so pin it to 1.0.
+        let config = RendererConfig::new(size.width, size.height, surface_format).with_render_scale(1.0);
         surface.configure(
             &device,
             &wgpu::SurfaceConfiguration {
@@ -233,19 +240,13 @@ impl ApplicationHandler for App {
                 color_space: wgpu::SurfaceColorSpace::Auto,
                 width: size.width,
                 height: size.height,
-                present_mode: wgpu::PresentMode::Fifo,
+                present_mode: config.present_mode.resolve(&caps.present_modes),
                 alpha_mode,
                 view_formats: vec![],
                 desired_maximum_frame_latency: 2,
             },
         );
 
-        // ── Renderer + custom graph with voxel pass ──────────────────────────
-        // render_scale defaults to 0.75 (Renderer's shared depth texture is sized
-        // from it), but our graph has no TAA upscale step and locks pre_aa at
-        // full window resolution — a scaled depth buffer here would mismatch the
-        // full-res color attachment, so pin it to 1.0.
-        let config = RendererConfig::new(size.width, size.height, surface_format).with_render_scale(1.0);
         let mut scene = Scene::new(device.clone(), queue.clone());
         let debug_camera_buf = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Debug Camera Buffer"),
@@ -352,6 +353,7 @@ impl ApplicationHandler for App {
             device,
             queue,
             surface_format,
+            present_modes: caps.present_modes,
             alpha_mode,
             renderer,
             last_frame: Instant::now(),
@@ -0,0 +1,182 @@
+//! Axis, handedness and unit conversion baked into imported geometry.
+//!
+//! Helio is right-handed, +Y up, with one world unit per metre. Assets
+//! authored under other conventions come in sideways, mirrored or at the
+//! wrong size. An [`ImportTransform`] maps the source space onto Helio's at
+//! load time: vertex positions are transformed, normals use the
+//! inverse-transpose, tangents keep a consistent bitangent sign, and triangle
+//! winding is reversed when the conversion mirrors the geometry. Node
+//! transforms are re-expressed in the converted basis, so instancing and
+//! hierarchies survive without a per-frame root transform.
+
+use glam::{Mat3, Mat4, Vec3};
+
+/// Coordinate conventions of common asset sources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportPreset {
+    /// glTF 2.0: right-handed, +Y up, metres. Same as Helio; no conversion.
+    GltfYUp,
+    /// Blender native: right-handed, +Z up, −Y forward, metres.
+    BlenderZUp,
+    /// Maya defaults: right-handed, +Y up, centimetres.
+    MayaYUpCm,
+}
+
+/// Conversion from an asset's coordinate system into Helio's.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImportTransform {
+    /// Source basis in Helio space: column `i` is where source axis `i` ends
+    /// up. A negative determinant converts handedness.
+    pub axes: Mat3,
+    /// Length of one source unit in metres.
+    pub unit_to_meters: f32,
+    /// Extra uniform scale applied on top of the unit conversion.
+    pub scale: f32,
+}
+
+impl Default for ImportTransform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<ImportPreset> for ImportTransform {
+    fn from(preset: ImportPreset) -> Self {
+        Self::from_preset(preset)
+    }
+}
+
+impl ImportTransform {
+    /// No conversion.
+    pub const IDENTITY: Self = Self {
+        axes: Mat3::IDENTITY,
+        unit_to_meters: 1.0,
+        scale: 1.0,
+    };
+
+    pub fn from_preset(preset: ImportPreset) -> Self {
+        match preset {
+            ImportPreset::GltfYUp => Self::IDENTITY,
+            // (x, y, z) Z-up → (x, z, -y) Y-up; a rotation, so winding is kept.
+            ImportPreset::BlenderZUp => Self {
+                axes: Mat3::from_cols(Vec3::X, Vec3::NEG_Z, Vec3::Y),
+                ..Self::IDENTITY
+            },
+            ImportPreset::MayaYUpCm => Self {
+                unit_to_meters: 0.01,
+                ..Self::IDENTITY
+            },
+        }
+    }
+
+    pub fn with_axes(mut self, axes: Mat3) -> Self {
+        self.axes = axes;
+        self
+    }
+
+    pub fn with_unit_to_meters(mut self, unit_to_meters: f32) -> Self {
+        self.unit_to_meters = unit_to_meters;
+        self
+    }
+
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Linear part of the conversion, including scale.
+    pub fn matrix(&self) -> Mat3 {
+        self.axes * (self.unit_to_meters * self.scale)
+    }
+
+    /// Whether the conversion mirrors geometry, i.e. triangle winding and
+    /// bitangent handedness must be flipped.
+    pub fn flips_winding(&self) -> bool {
+        self.matrix().determinant() < 0.0
+    }
+
+    pub fn transform_point(&self, p: Vec3) -> Vec3 {
+        self.matrix() * p
+    }
+
+    /// Transform a normal by the inverse-transpose and renormalize.
+    pub fn transform_normal(&self, n: Vec3) -> Vec3 {
+        (self.matrix().inverse().transpose() * n).normalize_or_zero()
+    }
+
+    /// Transform a tangent and its bitangent sign.
+    pub fn transform_tangent(&self, t: Vec3, bitangent_sign: f32) -> (Vec3, f32) {
+        let sign = if self.flips_winding() { -bitangent_sign } else { bitangent_sign };
+        ((self.matrix() * t).normalize_or_zero(), sign)
+    }
+
+    /// Re-express a source-space node transform in Helio space, so it applies
+    /// to vertices converted with [`transform_point`](Self::transform_point).
+    pub fn convert_node_transform(&self, node: Mat4) -> Mat4 {
+        let m = Mat4::from_mat3(self.matrix());
+        m * node * m.inverse()
+    }
+
+    /// Reverse every triangle of a triangle-list index buffer if the
+    /// conversion mirrors geometry.
+    pub fn fix_winding(&self, indices: &mut [u32]) {
+        if self.flips_winding() {
+            for tri in indices.chunks_exact_mut(3) {
+                tri.swap(1, 2);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blender_z_up_maps_up_to_y() {
+        let xf = ImportTransform::from_preset(ImportPreset::BlenderZUp);
+        assert!(xf.transform_point(Vec3::Z).abs_diff_eq(Vec3::Y, 1e-6));
+        assert!(xf.transform_point(Vec3::NEG_Y).abs_diff_eq(Vec3::Z, 1e-6));
+        assert!(!xf.flips_winding());
+    }
+
+    #[test]
+    fn maya_converts_centimetres() {
+        let xf = ImportTransform::from_preset(ImportPreset::MayaYUpCm);
+        assert!(xf.transform_point(Vec3::new(100.0, 0.0, 0.0)).abs_diff_eq(Vec3::X, 1e-6));
+        // Normals stay unit length under scale.
+        assert!(xf.transform_normal(Vec3::Y).abs_diff_eq(Vec3::Y, 1e-6));
+    }
+
+    #[test]
+    fn mirroring_flips_winding_and_keeps_normals_perpendicular() {
+        let xf = ImportTransform::IDENTITY
+            .with_axes(Mat3::from_diagonal(Vec3::new(1.0, 1.0, -1.0)))
+            .with_scale(2.0);
+        assert!(xf.flips_winding());
+
+        let mut indices = vec![0, 1, 2, 3, 4, 5];
+        xf.fix_winding(&mut indices);
+        assert_eq!(indices, vec![0, 2, 1, 3, 5, 4]);
+
+        let (_, sign) = xf.transform_tangent(Vec3::X, 1.0);
+        assert_eq!(sign, -1.0);
+
+        let (a, b) = (Vec3::new(1.0, 0.0, 1.0), Vec3::new(0.0, 1.0, 1.0));
+        let n = a.cross(b);
+        let n_conv = xf.transform_normal(n);
+        assert!(n_conv.dot(xf.transform_point(a)).abs() < 1e-5);
+        assert!(n_conv.dot(xf.transform_point(b)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn node_transforms_commute_with_conversion() {
+        let xf = ImportTransform::from_preset(ImportPreset::BlenderZUp).with_unit_to_meters(0.5);
+        let node = Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0))
+            * Mat4::from_rotation_z(0.7);
+        let p = Vec3::new(0.3, -1.0, 2.0);
+        let expected = xf.transform_point(node.transform_point3(p));
+        let actual = xf.convert_node_transform(node).transform_point3(xf.transform_point(p));
+        assert!(actual.abs_diff_eq(expected, 1e-5));
+    }
+}
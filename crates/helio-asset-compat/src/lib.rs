@@ -3,12 +3,23 @@
 //! This crate provides a bridge between SolidRS (comprehensive 3D model loader)
 //! and Helio's GPU-driven rendering pipeline. It handles conversion of CPU-side
 //! scene data to GPU buffers while maintaining performance standards.
+//!
+//! # Normal maps
+//!
+//! Every loader (glTF, FBX, OBJ) imports normal maps as tangent space with
+//! green up ([`NormalMapConvention::TangentSpace`] without `flip_green`), as
+//! the glTF spec requires. FBX and OBJ do not record a convention; assets
+//! authored for DirectX need `Scene::set_normal_map_convention` after import.
+//!
+//! [`NormalMapConvention::TangentSpace`]: helio::NormalMapConvention::TangentSpace
 
 mod animation_system;
 mod camera_converter;
+mod import_transform;
 mod light_converter;
 mod material_converter;
 mod mesh_converter;
+mod model;
 mod scene_converter;
 mod texture_loader;
 
@@ -19,13 +30,16 @@ use std::io::Cursor;
 use std::path::PathBuf;
 
 pub use camera_converter::{extract_camera_data, CameraData};
+pub use import_transform::{ImportPreset, ImportTransform};
 pub use light_converter::convert_light;
 pub use material_converter::{
     convert_material, ConvertedMaterial, ConvertedMaterialTextures, ConvertedTextureRef,
 };
 pub use mesh_converter::{convert_primitive, convert_vertex};
+pub use model::{load_model, upload_model, LoadedModel, ModelBatch};
 pub use scene_converter::{
     convert_scene, ConvertedMesh, ConvertedMeshSection, ConvertedScene, ConvertedSectionedMesh,
+    MaterialLoadFailure,
 };
 
 use std::path::Path;
@@ -45,14 +59,27 @@ pub struct LoadConfig {
     /// transform so it acts as a unit-conversion factor (e.g. `Vec3::splat(0.01)`
     /// to convert centimetres → metres).  Defaults to `Vec3::ONE` (no change).
     pub import_scale: glam::Vec3,
+    /// Axis/handedness/unit conversion baked into vertex data at load time
+    /// (see [`ImportTransform`]).  Defaults to no conversion.
+    pub import_transform: ImportTransform,
+    /// Recompute vertex normals with [`helio::recompute_normals`] at this
+    /// smoothing angle in degrees: edges sharper than it stay hard.  `None`
+    /// keeps the file's normals, except for meshes that have none, which are
+    /// smoothed at [`DEFAULT_SMOOTHING_ANGLE_DEG`].
+    pub normal_smoothing_angle: Option<f32>,
 }
 
+/// Smoothing angle used for imported meshes that carry no normals.
+pub const DEFAULT_SMOOTHING_ANGLE_DEG: f32 = 60.0;
+
 impl Default for LoadConfig {
     fn default() -> Self {
         Self {
             flip_uv_y: false,
             merge_meshes: false,
             import_scale: glam::Vec3::ONE,
+            import_transform: ImportTransform::IDENTITY,
+            normal_smoothing_angle: None,
         }
     }
 }
@@ -72,6 +99,18 @@ impl LoadConfig {
         self.import_scale = scale;
         self
     }
+
+    /// Accepts an [`ImportTransform`] or an [`ImportPreset`].
+    pub fn with_import_transform(mut self, transform: impl Into<ImportTransform>) -> Self {
+        self.import_transform = transform.into();
+        self
+    }
+
+    /// Replace imported normals with ones smoothed at `degrees`.
+    pub fn with_normal_smoothing(mut self, degrees: f32) -> Self {
+        self.normal_smoothing_angle = Some(degrees);
+        self
+    }
 }
 
 /// Load a 3D scene file (FBX, glTF, OBJ, etc.) and convert to Helio structures
@@ -265,30 +304,58 @@ pub fn upload_scene_materials(
     renderer: &mut Renderer,
     scene: &ConvertedScene,
 ) -> Result<Vec<MaterialId>> {
-    let texture_ids: Result<Vec<TextureId>> = scene
+    // Textures that failed to load, and textures or materials the scene
+    // rejects, are replaced by the error material (logged and recorded in
+    // `Scene::load_failures`) rather than failing the whole upload. The
+    // material as a whole is swapped so no slot samples a stand-in texture.
+    let mut rejected = Vec::new();
+    let texture_ids: Vec<Option<TextureId>> = scene
         .textures
         .iter()
         .cloned()
-        .map(|texture| {
-            renderer
-                .scene_mut()
-                .insert_texture(texture)
-                .map_err(|err: helio::SceneError| AssetError::InvalidData(err.to_string()))
+        .enumerate()
+        .map(|(i, texture)| {
+            let asset = match &texture.label {
+                Some(label) => format!("{}: texture '{label}'", scene.name),
+                None => format!("{}: texture {i}", scene.name),
+            };
+            match renderer.scene_mut().insert_texture(texture) {
+                Ok(id) => Some(id),
+                Err(err) => {
+                    rejected.push((i, asset, err.to_string()));
+                    None
+                }
+            }
         })
         .collect();
-    let texture_ids = texture_ids?;
 
-    scene
+    Ok(scene
         .materials
         .iter()
-        .map(|material| {
+        .enumerate()
+        .map(|(i, material)| {
+            let failed_loads = scene
+                .load_failures
+                .iter()
+                .filter(|f| f.material_index == i)
+                .map(|f| (&f.asset, &f.reason));
+            let failed_uploads = rejected
+                .iter()
+                .filter(|(texture, ..)| material.textures.texture_indices().any(|t| t == *texture))
+                .map(|(_, asset, reason)| (asset, reason));
+            let mut error_material = None;
+            for (asset, reason) in failed_loads.chain(failed_uploads) {
+                error_material = Some(renderer.scene_mut().report_load_failure(asset, reason));
+            }
+            if let Some(id) = error_material {
+                return id;
+            }
             let asset = scene_converter::material_asset_from_converted(material, &texture_ids);
             renderer
                 .scene_mut()
-                .insert_material_asset(asset)
-                .map_err(|err: helio::SceneError| AssetError::InvalidData(err.to_string()))
+                .insert_material_asset_or_error(&format!("{}: material {i}", scene.name), asset)
         })
-        .collect()
+        .collect())
 }
 
 /// Upload the materials/textures and sectioned mesh from a [`ConvertedScene`] that
@@ -308,32 +375,7 @@ pub fn upload_sectioned_scene(
         .as_ref()
         .expect("upload_sectioned_scene called on a scene without sectioned_mesh; use merge_meshes=true");
 
-    // Upload textures and materials (same as upload_scene_materials).
-    let texture_ids: Result<Vec<TextureId>> = scene
-        .textures
-        .iter()
-        .cloned()
-        .map(|t| {
-            renderer
-                .scene_mut()
-                .insert_texture(t)
-                .map_err(|e: helio::SceneError| AssetError::InvalidData(e.to_string()))
-        })
-        .collect();
-    let texture_ids = texture_ids?;
-
-    let all_material_ids: Result<Vec<MaterialId>> = scene
-        .materials
-        .iter()
-        .map(|mat| {
-            let asset = scene_converter::material_asset_from_converted(mat, &texture_ids);
-            renderer
-                .scene_mut()
-                .insert_material_asset(asset)
-                .map_err(|e: helio::SceneError| AssetError::InvalidData(e.to_string()))
-        })
-        .collect();
-    let all_material_ids = all_material_ids?;
+    let all_material_ids = upload_scene_materials(renderer, scene)?;
 
     // Build the SectionedMeshUpload: shared vertices + per-section index lists.
     let upload = SectionedMeshUpload {
@@ -349,28 +391,31 @@ pub fn upload_sectioned_scene(
         .map(|sec| {
             sec.material_index
                 .and_then(|idx| all_material_ids.get(idx).copied())
-                .unwrap_or_else(|| {
-                    renderer.scene_mut().insert_material(helio::GpuMaterial {
-                        base_color: [0.7, 0.65, 0.55, 1.0],
-                        emissive: [0.0, 0.0, 0.0, 0.0],
-                        roughness_metallic: [0.6, 0.0, 1.5, 0.0],
-                        tex_base_color: helio::GpuMaterial::NO_TEXTURE,
-                        tex_normal: helio::GpuMaterial::NO_TEXTURE,
-                        tex_roughness: helio::GpuMaterial::NO_TEXTURE,
-                        tex_emissive: helio::GpuMaterial::NO_TEXTURE,
-                        tex_occlusion: helio::GpuMaterial::NO_TEXTURE,
-                        workflow: 0,
-                        flags: 0,
-                        material_class: 0,
-                        class_params: [0.0; 4],
-                    })
-                })
+                .unwrap_or_else(|| renderer.scene_mut().insert_material(fallback_material()))
         })
         .collect();
 
     Ok((multi_mesh_id, section_material_ids))
 }
 
+/// Neutral material for geometry that has no material in the source file.
+pub(crate) fn fallback_material() -> helio::GpuMaterial {
+    helio::GpuMaterial {
+        base_color: [0.7, 0.65, 0.55, 1.0],
+        emissive: [0.0, 0.0, 0.0, 0.0],
+        roughness_metallic: [0.6, 0.0, 1.5, 0.0],
+        tex_base_color: helio::GpuMaterial::NO_TEXTURE,
+        tex_normal: helio::GpuMaterial::NO_TEXTURE,
+        tex_roughness: helio::GpuMaterial::NO_TEXTURE,
+        tex_emissive: helio::GpuMaterial::NO_TEXTURE,
+        tex_occlusion: helio::GpuMaterial::NO_TEXTURE,
+        workflow: 0,
+        flags: 0,
+        material_class: 0,
+        class_params: [0.0; 4],
+    }
+}
+
 /// Result type for asset loading operations
 pub type Result<T> = std::result::Result<T, AssetError>;
 
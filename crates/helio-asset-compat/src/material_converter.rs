@@ -30,6 +30,25 @@ pub struct ConvertedMaterialTextures {
     pub alpha_cutoff: f32,
 }
 
+impl ConvertedMaterialTextures {
+    /// Indices into [`ConvertedScene::textures`](crate::ConvertedScene::textures)
+    /// of every bound slot.
+    pub fn texture_indices(&self) -> impl Iterator<Item = usize> {
+        [
+            self.base_color,
+            self.normal,
+            self.roughness_metallic,
+            self.emissive,
+            self.occlusion,
+            self.specular_color,
+            self.specular_weight,
+        ]
+        .into_iter()
+        .flatten()
+        .map(|texture| texture.texture_index)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ConvertedMaterial {
     pub gpu: GpuMaterial,
@@ -121,27 +121,80 @@ pub fn convert_primitive(
         }
     }
 
-    let vertices: Vec<PackedVertex> = mesh
+    let xf = &config.import_transform;
+    let mut vertices: Vec<PackedVertex> = mesh
         .vertices
         .iter()
-        .map(|v| convert_vertex(v, config.flip_uv_y))
+        .map(|v| convert_vertex(&apply_import_transform(v, xf), config.flip_uv_y))
         .collect();
 
-    let indices = primitive.indices.clone();
+    let mut indices = primitive.indices.clone();
+    xf.fix_winding(&mut indices);
+    // Normal and tangent generation index straight into `vertices`.
+    validate_indices(&mesh.name, &indices, vertices.len())?;
+
+    let missing_normals = mesh.vertices.iter().any(|v| v.normal.is_none());
+    let smoothing = config
+        .normal_smoothing_angle
+        .or(missing_normals.then_some(crate::DEFAULT_SMOOTHING_ANGLE_DEG));
+    if let Some(angle) = smoothing {
+        // Runs after the winding fix so face normals point outward.
+        let mut upload = helio::MeshUpload { vertices, indices };
+        helio::recompute_normals(&mut upload, angle);
+        vertices = upload.vertices;
+        indices = upload.indices;
+    }
 
-    let max_index = indices.iter().max().copied().unwrap_or(0);
-    let vertices_len = vertices.len();
-    if max_index >= vertices_len as u32 {
-        log::error!(
-            "Mesh '{}': index out of bounds — max_index={}, vertices={}; first 10: {:?}",
-            mesh.name, max_index, vertices_len,
-            &indices[0..indices.len().min(10)]
-        );
+    // Without authored tangents, derive them from the UVs so normal maps
+    // line up; the per-vertex fallback in `convert_vertex` ignores the UVs.
+    // Any authored tangent means the mesh's own are kept as they are.
+    if has_uvs && mesh.vertices.iter().all(|v| v.tangent.is_none()) {
+        let mut upload = helio::MeshUpload { vertices, indices };
+        helio::generate_tangents(&mut upload);
+        vertices = upload.vertices;
+        indices = upload.indices;
     }
 
     Ok((vertices, indices))
 }
 
+/// Reject index buffers that reference past the end of the vertex buffer.
+fn validate_indices(mesh_name: &str, indices: &[u32], vertex_count: usize) -> Result<()> {
+    let Some(&max_index) = indices.iter().max() else {
+        return Ok(());
+    };
+    if max_index as usize >= vertex_count {
+        return Err(crate::AssetError::InvalidData(format!(
+            "Mesh '{}': index out of bounds — max_index={}, vertices={}",
+            mesh_name, max_index, vertex_count
+        )));
+    }
+    Ok(())
+}
+
+/// Bake the import conversion into a source vertex (positions, normals and
+/// tangents; the bitangent sign flips with the winding).
+fn apply_import_transform(v: &Vertex, xf: &crate::ImportTransform) -> Vertex {
+    use solid_rs::glam::{Vec3 as SVec3, Vec4 as SVec4};
+
+    if *xf == crate::ImportTransform::IDENTITY {
+        return v.clone();
+    }
+    // solid_rs may link a different glam version than the workspace.
+    let p = xf.transform_point(glam::Vec3::new(v.position.x, v.position.y, v.position.z));
+    let mut out = v.clone();
+    out.position = SVec3::new(p.x, p.y, p.z);
+    out.normal = v.normal.map(|n| {
+        let n = xf.transform_normal(glam::Vec3::new(n.x, n.y, n.z));
+        SVec3::new(n.x, n.y, n.z)
+    });
+    out.tangent = v.tangent.map(|t| {
+        let (t3, w) = xf.transform_tangent(glam::Vec3::new(t.x, t.y, t.z), t.w);
+        SVec4::new(t3.x, t3.y, t3.z, w)
+    });
+    out
+}
+
 /// Convert a SolidRS mesh to Helio vertex/index buffers (deprecated - merges all primitives)
 ///
 /// DEPRECATED: This merges all primitives together and loses per-primitive material info.
@@ -246,5 +299,15 @@ mod tests {
         assert_eq!(packed.tex_coords0, [0.0, 0.0]);
         assert_eq!(packed.tex_coords1, [0.0, 0.0]);
     }
+
+    #[test]
+    fn out_of_range_index_is_an_error() {
+        assert!(validate_indices("tri", &[0, 1, 2], 3).is_ok());
+        assert!(validate_indices("empty", &[], 0).is_ok());
+
+        let err = validate_indices("tri", &[0, 1, 3], 3).unwrap_err();
+        assert!(matches!(err, crate::AssetError::InvalidData(_)));
+        assert!(err.to_string().contains("max_index=3"));
+    }
 }
 
@@ -0,0 +1,231 @@
+//! Draw-ready models: loaded geometry grouped into one mesh per material.
+//!
+//! [`upload_scene`](crate::upload_scene) mirrors the file's structure — one
+//! mesh per primitive per node — which leaves the caller to pair meshes with
+//! materials and issues a material switch per primitive. A [`LoadedModel`]
+//! instead welds every primitive that shares a material into a single mesh
+//! (node transforms baked in), so the model draws with exactly one batch per
+//! material.
+
+use glam::{Mat4, Vec3};
+use helio::{MaterialId, MeshId, MeshUpload, ObjectDescriptor, ObjectId, PackedVertex, Renderer};
+
+use crate::scene_converter::{group_by_material, ConvertedScene, MaterialGroup};
+use crate::{load_scene_file_with_config, upload_scene_materials, AssetError, LoadConfig, Result};
+
+/// One draw-ready batch: all geometry of a model that uses one material.
+#[derive(Debug, Clone, Copy)]
+pub struct ModelBatch {
+    pub mesh: MeshId,
+    pub material: MaterialId,
+    /// Index into [`ConvertedScene::materials`], or `None` when the geometry
+    /// had no material and uses the model's fallback material.
+    pub material_index: Option<usize>,
+    /// Bounding sphere of the batch in model space: `[x, y, z, radius]`.
+    pub bounds: [f32; 4],
+}
+
+/// A model uploaded to the renderer as one mesh per material.
+#[derive(Debug, Clone)]
+pub struct LoadedModel {
+    pub name: String,
+    /// Batches in the order their material first appears in the file.
+    pub batches: Vec<ModelBatch>,
+    /// Every material registered for the model, indexed like
+    /// [`ConvertedScene::materials`] (including ones no geometry uses).
+    pub material_ids: Vec<MaterialId>,
+}
+
+impl LoadedModel {
+    /// Place one instance of the model in the scene, one object per batch.
+    ///
+    /// Objects are static with default flags; adjust them through the
+    /// returned handles if needed.
+    pub fn insert_objects(&self, renderer: &mut Renderer, transform: Mat4) -> Result<Vec<ObjectId>> {
+        let max_scale = transform
+            .x_axis
+            .truncate()
+            .length()
+            .max(transform.y_axis.truncate().length())
+            .max(transform.z_axis.truncate().length());
+        self.batches
+            .iter()
+            .map(|batch| {
+                let center = transform.transform_point3(Vec3::new(batch.bounds[0], batch.bounds[1], batch.bounds[2]));
+                let id = renderer.scene_mut().insert_actor(helio::SceneActor::object(ObjectDescriptor {
+                    mesh: batch.mesh,
+                    material: batch.material,
+                    transform,
+                    bounds: [center.x, center.y, center.z, batch.bounds[3] * max_scale],
+                    flags: 0,
+                    groups: helio::GroupMask::NONE,
+                    movability: None,
+                    user_tag: 0,
+                }));
+                match id {
+                    helio::SceneActorId::Object(id) => Ok(id),
+                    _ => Err(AssetError::InvalidData(format!(
+                        "failed to insert object for model '{}'",
+                        self.name
+                    ))),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Load a model file and upload it as one mesh per material.
+///
+/// Materials (and their textures) from glTF or MTL are registered with the
+/// renderer's scene and referenced from each batch by handle.
+pub fn load_model<P: AsRef<std::path::Path>>(
+    path: P,
+    config: LoadConfig,
+    renderer: &mut Renderer,
+) -> Result<LoadedModel> {
+    let scene = load_scene_file_with_config(path, config)?;
+    upload_model(renderer, &scene)
+}
+
+/// Upload an already-converted scene as a [`LoadedModel`].
+///
+/// Works for scenes loaded with or without [`LoadConfig::merge_meshes`].
+pub fn upload_model(renderer: &mut Renderer, scene: &ConvertedScene) -> Result<LoadedModel> {
+    let material_ids = upload_scene_materials(renderer, scene)?;
+
+    let groups = match &scene.sectioned_mesh {
+        Some(sectioned) => sectioned
+            .sections
+            .iter()
+            .map(|section| {
+                let (vertices, indices) = compact(&sectioned.vertices, &section.indices);
+                MaterialGroup { material_index: section.material_index, vertices, indices }
+            })
+            .collect(),
+        None => group_by_material(&scene.meshes),
+    };
+
+    let mut fallback = None;
+    let mut batches = Vec::with_capacity(groups.len());
+    for group in groups {
+        if group.indices.is_empty() {
+            continue;
+        }
+        let material = match group.material_index.and_then(|idx| material_ids.get(idx).copied()) {
+            Some(id) => id,
+            None => *fallback.get_or_insert_with(|| {
+                renderer.scene_mut().insert_material(crate::fallback_material())
+            }),
+        };
+        let bounds = bounding_sphere(&group.vertices);
+        let mesh = match renderer.scene_mut().insert_actor(helio::SceneActor::mesh(MeshUpload {
+            vertices: group.vertices,
+            indices: group.indices,
+        })) {
+            helio::SceneActorId::Mesh(id) => id,
+            _ => {
+                return Err(AssetError::InvalidData(format!(
+                    "failed to upload mesh for model '{}'",
+                    scene.name
+                )))
+            }
+        };
+        batches.push(ModelBatch { mesh, material, material_index: group.material_index, bounds });
+    }
+
+    Ok(LoadedModel { name: scene.name.clone(), batches, material_ids })
+}
+
+/// Copy the vertices referenced by `indices` out of a shared buffer,
+/// remapping the indices to the compacted array.
+fn compact(vertices: &[PackedVertex], indices: &[u32]) -> (Vec<PackedVertex>, Vec<u32>) {
+    let mut remap = vec![u32::MAX; vertices.len()];
+    let mut out_vertices = Vec::new();
+    let out_indices = indices
+        .iter()
+        .map(|&idx| {
+            let slot = &mut remap[idx as usize];
+            if *slot == u32::MAX {
+                *slot = out_vertices.len() as u32;
+                out_vertices.push(vertices[idx as usize]);
+            }
+            *slot
+        })
+        .collect();
+    (out_vertices, out_indices)
+}
+
+/// Sphere around the AABB of `vertices`: `[x, y, z, radius]`.
+fn bounding_sphere(vertices: &[PackedVertex]) -> [f32; 4] {
+    let mut min = Vec3::splat(f32::MAX);
+    let mut max = Vec3::splat(f32::MIN);
+    for v in vertices {
+        let p = Vec3::from(v.position);
+        min = min.min(p);
+        max = max.max(p);
+    }
+    if vertices.is_empty() {
+        return [0.0; 4];
+    }
+    let center = (min + max) * 0.5;
+    let radius = vertices
+        .iter()
+        .map(|v| Vec3::from(v.position).distance(center))
+        .fold(0.0_f32, f32::max);
+    [center.x, center.y, center.z, radius]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConvertedMesh;
+
+    fn triangle(offset: f32, material_index: Option<usize>, node_transform: Mat4) -> ConvertedMesh {
+        let vertex = |x: f32, y: f32| {
+            PackedVertex::from_components([x + offset, y, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0], [1.0, 0.0, 0.0], 1.0)
+        };
+        ConvertedMesh {
+            name: String::new(),
+            vertices: vec![vertex(0.0, 0.0), vertex(1.0, 0.0), vertex(0.0, 1.0)],
+            indices: vec![0, 1, 2],
+            material_index,
+            node_transform,
+        }
+    }
+
+    #[test]
+    fn primitives_sharing_a_material_become_one_batch() {
+        let moved = Mat4::from_translation(Vec3::new(0.0, 0.0, 5.0));
+        let meshes = vec![
+            triangle(0.0, Some(1), Mat4::IDENTITY),
+            triangle(2.0, Some(0), Mat4::IDENTITY),
+            triangle(4.0, Some(1), moved),
+            triangle(6.0, None, Mat4::IDENTITY),
+        ];
+        let groups = group_by_material(&meshes);
+        let order: Vec<_> = groups.iter().map(|g| g.material_index).collect();
+        assert_eq!(order, [Some(1), Some(0), None]);
+
+        let welded = &groups[0];
+        assert_eq!(welded.vertices.len(), 6);
+        assert_eq!(welded.indices, [0, 1, 2, 3, 4, 5]);
+        // The second primitive's node transform is baked into its vertices.
+        assert_eq!(welded.vertices[3].position, [4.0, 0.0, 5.0]);
+    }
+
+    #[test]
+    fn compact_keeps_only_referenced_vertices() {
+        let source: Vec<PackedVertex> = triangle(0.0, None, Mat4::IDENTITY)
+            .vertices
+            .into_iter()
+            .chain(triangle(3.0, None, Mat4::IDENTITY).vertices)
+            .collect();
+        let (vertices, indices) = compact(&source, &[3, 5, 4, 5]);
+        assert_eq!(vertices.len(), 3);
+        assert_eq!(indices, [0, 1, 2, 1]);
+        assert_eq!(vertices[1].position, source[5].position);
+
+        let bounds = bounding_sphere(&vertices);
+        assert!((bounds[3] - (0.5f32 * 0.5 + 0.5 * 0.5).sqrt()).abs() < 1e-5);
+    }
+}
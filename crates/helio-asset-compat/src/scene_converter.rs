@@ -2,7 +2,10 @@
 
 use std::collections::HashMap;
 
-use helio::{MaterialAsset, MaterialTextureRef, MaterialTextures, PackedVertex, TextureUpload};
+use helio::{
+    MaterialAsset, MaterialTextureRef, MaterialTextures, NormalMapConvention, PackedVertex,
+    TextureUpload,
+};
 use solid_rs::Scene;
 
 use crate::material_converter::{convert_material, ConvertedMaterial, ConvertedTextureRef};
@@ -15,6 +18,11 @@ use crate::{camera_converter, light_converter, mesh_converter, CameraData, Resul
 ///   • Normal map encodes the curved depression geometry
 ///   • Light tiles ~195 (metallic silver), ±3 noise
 ///   • Dark tiles ~55 (graphite), ±15 fine grain noise
+///
+/// Keeps a failed texture's slot in [`ConvertedScene::textures`] filled for
+/// callers that upload the textures themselves;
+/// [`upload_scene_materials`](crate::upload_scene_materials) draws the
+/// materials using it with the error material instead.
 fn fallback_texture(semantic: TextureSemantic) -> helio::TextureUpload {
     let srgb = semantic.is_srgb();
 
@@ -148,6 +156,21 @@ pub struct ConvertedScene {
     /// Populated instead of `meshes` when [`crate::LoadConfig::merge_meshes`] is true.
     /// One section per unique material; all sections share one vertex buffer.
     pub sectioned_mesh: Option<ConvertedSectionedMesh>,
+    /// Textures that could not be loaded, one entry per material using them.
+    /// [`upload_scene_materials`](crate::upload_scene_materials) draws those
+    /// materials with the scene's error material.
+    pub load_failures: Vec<MaterialLoadFailure>,
+}
+
+/// A material whose texture failed to load or decode.
+#[derive(Debug, Clone)]
+pub struct MaterialLoadFailure {
+    /// Index into [`ConvertedScene::materials`].
+    pub material_index: usize,
+    /// The texture that failed, for [`helio::Scene::load_failures`].
+    pub asset: String,
+    /// Why it failed.
+    pub reason: String,
 }
 
 /// One material section of a sectioned (multi-material) merged mesh.
@@ -197,21 +220,17 @@ pub fn convert_scene(
 
     let mut textures = Vec::new();
     let mut texture_cache = HashMap::<(usize, TextureSemantic), usize>::new();
-    // Track which uploaded texture indices came from a fallback (missing file).
-    let mut fallback_texture_indices = std::collections::HashSet::<usize>::new();
-    // Track which material indices used at least one fallback texture.
-    let mut used_fallback_for_mat = vec![false; scene.materials.len()];
+    // Uploaded texture index → (asset, reason) for textures that failed to load.
+    let mut failed_textures = HashMap::<usize, (String, String)>::new();
+    let mut load_failures = Vec::new();
     let mut materials: Vec<ConvertedMaterial> = Vec::with_capacity(scene.materials.len());
     for (mat_idx, material) in scene.materials.iter().enumerate() {
         let mat = convert_material(material, |texture_ref, semantic| {
             let key = (texture_ref.texture_index, semantic);
             let converted_index = if let Some(&index) = texture_cache.get(&key) {
-                // A cache hit on a previously-failed texture still counts as a fallback.
-                if fallback_texture_indices.contains(&index) {
-                    used_fallback_for_mat[mat_idx] = true;
-                }
                 index
             } else {
+                let index = textures.len();
                 let upload = match load_texture_upload(
                     scene,
                     texture_ref.texture_index,
@@ -220,22 +239,28 @@ pub fn convert_scene(
                 ) {
                     Ok(u) => u,
                     Err(e) => {
-                        log::warn!(
-                            "Texture {} ({:?}) not found, using fallback: {}",
+                        let asset = format!(
+                            "{}: texture {} ({})",
+                            scene.name,
                             texture_ref.texture_index,
-                            semantic,
-                            e
+                            semantic.suffix()
                         );
-                        used_fallback_for_mat[mat_idx] = true;
-                        fallback_texture_indices.insert(textures.len());
+                        failed_textures.insert(index, (asset, e.to_string()));
                         fallback_texture(semantic)
                     }
                 };
-                let index = textures.len();
                 textures.push(upload);
                 texture_cache.insert(key, index);
                 index
             };
+            // A cache hit on a previously-failed texture fails this material too.
+            if let Some((asset, reason)) = failed_textures.get(&converted_index) {
+                load_failures.push(MaterialLoadFailure {
+                    material_index: mat_idx,
+                    asset: asset.clone(),
+                    reason: reason.clone(),
+                });
+            }
 
             Ok(ConvertedTextureRef {
                 texture_index: converted_index,
@@ -245,27 +270,6 @@ pub fn convert_scene(
         materials.push(mat);
     }
 
-    // Inject the checkerboard normal map ONLY for materials that actually used
-    // a fallback texture (i.e. their source textures were missing).  Materials
-    // whose textures loaded successfully keep their own normals — or no normal
-    // at all — rather than getting the debug grid baked on top.
-    let fallback_normal_index = textures.len();
-    let mut normal_fallback_pushed = false;
-    for (idx, mat) in materials.iter_mut().enumerate() {
-        if mat.textures.normal.is_none() && used_fallback_for_mat[idx] {
-            if !normal_fallback_pushed {
-                textures.push(fallback_texture(TextureSemantic::Normal));
-                normal_fallback_pushed = true;
-            }
-            mat.textures.normal = Some(crate::material_converter::ConvertedTextureRef {
-                texture_index: fallback_normal_index,
-                uv_channel: 0,
-                transform: Default::default(),
-            });
-            mat.textures.normal_scale = 1.0;
-        }
-    }
-
     // Walk the scene node DAG to compute per-mesh world-space transforms.
     // A mesh may be referenced by multiple nodes (instancing); we collect all
     // transforms so each instance becomes its own ConvertedMesh entry.
@@ -321,11 +325,14 @@ pub fn convert_scene(
         }
 
         // Pre-multiply each node transform by the import scale so all vertex
-        // positions are expressed in the caller's chosen unit system.
+        // positions are expressed in the caller's chosen unit system.  The
+        // import transform is baked into the vertices, so the node transform
+        // is re-expressed in the converted basis.
         let scale_mat = glam::Mat4::from_scale(config.import_scale);
 
         for &raw_node_transform in world_transforms {
-            let node_transform = scale_mat * raw_node_transform;
+            let node_transform =
+                scale_mat * config.import_transform.convert_node_transform(raw_node_transform);
             for (prim_idx, primitive) in mesh.primitives.iter().enumerate() {
                 let (vertices, indices) = mesh_converter::convert_primitive(mesh, primitive, config)?;
 
@@ -356,57 +363,7 @@ pub fn convert_scene(
     // Each mesh's node_transform is baked into its vertex positions/normals/
     // tangents so the merged result can live at IDENTITY.
     if config.merge_meshes && !meshes.is_empty() {
-        // Group sub-meshes by material_index so each material keeps its own
-        // vertex/index buffer.  Sub-meshes within the same group are welded
-        // together with node_transform baked into the vertices.
-        //
-        // We use a Vec of (material_index, bucket_index) to preserve the order
-        // materials are first encountered (important for deterministic output).
-        let mut material_order: Vec<Option<usize>> = Vec::new();
-        let mut group_verts: Vec<Vec<PackedVertex>> = Vec::new();
-        let mut group_indices: Vec<Vec<u32>> = Vec::new();
-
-        for sub in &meshes {
-            // Find or create the bucket for this material.
-            let bucket = material_order
-                .iter()
-                .position(|&m| m == sub.material_index)
-                .unwrap_or_else(|| {
-                    let idx = material_order.len();
-                    material_order.push(sub.material_index);
-                    group_verts.push(Vec::new());
-                    group_indices.push(Vec::new());
-                    idx
-                });
-
-            let base = group_verts[bucket].len() as u32;
-            let xform = sub.node_transform;
-            let normal_mat = glam::Mat3::from_mat4(xform.inverse().transpose());
-            let tangent_mat = glam::Mat3::from_mat4(xform);
-            let handedness_sign = if xform.determinant() < 0.0 { -1.0_f32 } else { 1.0 };
-
-            for v in &sub.vertices {
-                let pos = xform.transform_point3(glam::Vec3::from(v.position));
-                let norm_raw = unpack_snorm4x8(v.normal);
-                let tan_raw = unpack_snorm4x8(v.tangent);
-                let norm = (normal_mat * glam::Vec3::new(norm_raw[0], norm_raw[1], norm_raw[2]))
-                    .normalize_or_zero();
-                let tan = (tangent_mat * glam::Vec3::new(tan_raw[0], tan_raw[1], tan_raw[2]))
-                    .normalize_or_zero();
-                let mut mv = PackedVertex::from_components(
-                    pos.into(),
-                    norm.into(),
-                    v.tex_coords0,
-                    tan.into(),
-                    v.bitangent_sign * handedness_sign,
-                );
-                mv.tex_coords1 = v.tex_coords1;
-                group_verts[bucket].push(mv);
-            }
-            for &idx in &sub.indices {
-                group_indices[bucket].push(base + idx);
-            }
-        }
+        let groups = group_by_material(&meshes);
 
         // Build the ConvertedSectionedMesh: one shared vertex buffer (the union
         // of all per-bucket vertices, re-indexed so all sections' indices are
@@ -415,15 +372,14 @@ pub fn convert_scene(
         let mut shared_vertices: Vec<PackedVertex> = Vec::new();
         let mut sections: Vec<ConvertedMeshSection> = Vec::new();
 
-        for (i, mat_idx) in material_order.into_iter().enumerate() {
+        for group in groups {
             let base = shared_vertices.len() as u32;
             // Adjust indices to be absolute into the shared vertex array.
-            let abs_indices: Vec<u32> =
-                group_indices[i].iter().map(|&idx| base + idx).collect();
-            shared_vertices.extend_from_slice(&group_verts[i]);
+            let abs_indices: Vec<u32> = group.indices.iter().map(|&idx| base + idx).collect();
+            shared_vertices.extend_from_slice(&group.vertices);
             sections.push(ConvertedMeshSection {
                 indices: abs_indices,
-                material_index: mat_idx,
+                material_index: group.material_index,
             });
         }
 
@@ -452,6 +408,7 @@ pub fn convert_scene(
             lights,
             cameras,
             sectioned_mesh: Some(sectioned),
+            load_failures,
         });
     }
 
@@ -474,9 +431,72 @@ pub fn convert_scene(
         lights,
         cameras,
         sectioned_mesh: None,
+        load_failures,
     })
 }
 
+/// Geometry from every sub-mesh sharing one material, welded into one buffer.
+pub(crate) struct MaterialGroup {
+    /// Index into [`ConvertedScene::materials`], or `None` for unassigned geometry.
+    pub material_index: Option<usize>,
+    pub vertices: Vec<PackedVertex>,
+    /// Indices into `vertices`.
+    pub indices: Vec<u32>,
+}
+
+/// Group sub-meshes by `material_index`, baking each `node_transform` into
+/// the vertices so every group can live at IDENTITY.
+///
+/// Groups are returned in the order their material is first encountered, so
+/// the output is deterministic.
+pub(crate) fn group_by_material(meshes: &[ConvertedMesh]) -> Vec<MaterialGroup> {
+    let mut groups: Vec<MaterialGroup> = Vec::new();
+
+    for sub in meshes {
+        // Find or create the bucket for this material.
+        let bucket = groups
+            .iter()
+            .position(|g| g.material_index == sub.material_index)
+            .unwrap_or_else(|| {
+                groups.push(MaterialGroup {
+                    material_index: sub.material_index,
+                    vertices: Vec::new(),
+                    indices: Vec::new(),
+                });
+                groups.len() - 1
+            });
+        let group = &mut groups[bucket];
+
+        let base = group.vertices.len() as u32;
+        let xform = sub.node_transform;
+        let normal_mat = glam::Mat3::from_mat4(xform.inverse().transpose());
+        let tangent_mat = glam::Mat3::from_mat4(xform);
+        let handedness_sign = if xform.determinant() < 0.0 { -1.0_f32 } else { 1.0 };
+
+        for v in &sub.vertices {
+            let pos = xform.transform_point3(glam::Vec3::from(v.position));
+            let norm_raw = unpack_snorm4x8(v.normal);
+            let tan_raw = unpack_snorm4x8(v.tangent);
+            let norm = (normal_mat * glam::Vec3::new(norm_raw[0], norm_raw[1], norm_raw[2]))
+                .normalize_or_zero();
+            let tan = (tangent_mat * glam::Vec3::new(tan_raw[0], tan_raw[1], tan_raw[2]))
+                .normalize_or_zero();
+            let mut mv = PackedVertex::from_components(
+                pos.into(),
+                norm.into(),
+                v.tex_coords0,
+                tan.into(),
+                v.bitangent_sign * handedness_sign,
+            );
+            mv.tex_coords1 = v.tex_coords1;
+            group.vertices.push(mv);
+        }
+        group.indices.extend(sub.indices.iter().map(|&idx| base + idx));
+    }
+
+    groups
+}
+
 /// Unpack a `pack_snorm4x8` value back to four f32 components in [-1, 1].
 fn unpack_snorm4x8(packed: u32) -> [f32; 4] {
     let bytes = packed.to_le_bytes();
@@ -490,18 +510,22 @@ fn unpack_snorm4x8(packed: u32) -> [f32; 4] {
 
 fn remap_texture_slot(
     texture: Option<ConvertedTextureRef>,
-    texture_ids: &[helio::TextureId],
+    texture_ids: &[Option<helio::TextureId>],
 ) -> Option<MaterialTextureRef> {
-    texture.map(|texture| MaterialTextureRef {
-        texture: texture_ids[texture.texture_index],
+    let texture = texture?;
+    Some(MaterialTextureRef {
+        texture: texture_ids[texture.texture_index]?,
         uv_channel: texture.uv_channel,
         transform: texture.transform,
+        sampler: None,
     })
 }
 
+/// `texture_ids` maps converted texture indices to uploaded textures; slots
+/// whose texture was not uploaded are left empty.
 pub(crate) fn material_asset_from_converted(
     material: &ConvertedMaterial,
-    texture_ids: &[helio::TextureId],
+    texture_ids: &[Option<helio::TextureId>],
 ) -> MaterialAsset {
     MaterialAsset {
         gpu: material.gpu,
@@ -519,6 +543,8 @@ pub(crate) fn material_asset_from_converted(
             normal_scale: material.textures.normal_scale,
             occlusion_strength: material.textures.occlusion_strength,
             alpha_cutoff: material.textures.alpha_cutoff,
+            // glTF mandates OpenGL tangent space; FBX/OBJ carry no convention.
+            normal_map_convention: NormalMapConvention::default(),
         },
     }
 }
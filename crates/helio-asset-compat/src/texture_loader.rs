@@ -242,6 +242,7 @@ fn convert_sampler(sampler: &Sampler) -> TextureSamplerDesc {
         mag_filter,
         min_filter,
         mipmap_filter,
+        anisotropy_clamp: 1,
     }
 }
 
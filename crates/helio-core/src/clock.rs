@@ -0,0 +1,218 @@
+//! Frame pacing: delta-time clamping, fixed-timestep accumulation and
+//! smoothed FPS.
+//!
+//! [`FrameClock`] is fed the measured wall-clock delta once per frame and
+//! returns the delta that time-based passes should actually advance by. The
+//! clock never reads the system time itself, so it behaves identically on
+//! native and wasm and can be driven deterministically in tests or replays.
+
+/// Result of one [`FrameClock::tick`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrameTime {
+    /// Measured wall-clock delta passed to `tick`, in seconds.
+    pub raw_delta: f32,
+    /// Delta to advance simulation by this frame, in seconds.
+    ///
+    /// Clamped to [`FrameClock::max_delta`]. In fixed-timestep mode this is
+    /// `steps * fixed_step`, so it may be `0.0` on frames shorter than a step.
+    pub delta: f32,
+    /// Number of fixed steps consumed this frame (always `1` in
+    /// variable-timestep mode).
+    pub steps: u32,
+    /// Fraction of a fixed step left in the accumulator, in `[0, 1)`, for
+    /// interpolating between the last two simulated states. `0.0` in
+    /// variable-timestep mode.
+    pub alpha: f32,
+}
+
+/// Clamps per-frame delta time and optionally quantizes it to a fixed step.
+///
+/// Without clamping a single hitch (window drag, shader compile, debugger
+/// break) advances every animation by the full stall. The clock caps the
+/// delta at `max_delta`; in fixed-timestep mode it additionally caps the
+/// number of steps per frame so a slow frame cannot trigger an ever-growing
+/// catch-up loop.
+#[derive(Debug, Clone)]
+pub struct FrameClock {
+    max_delta: f32,
+    fixed_step: Option<f32>,
+    max_steps: u32,
+    accumulator: f32,
+    smoothing: f32,
+    smoothed_delta: f32,
+    elapsed: f64,
+    frame_count: u64,
+}
+
+impl Default for FrameClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FrameClock {
+    /// Variable timestep clamped to 100 ms, FPS smoothed over ~10 frames.
+    pub fn new() -> Self {
+        Self {
+            max_delta: 0.1,
+            fixed_step: None,
+            max_steps: 8,
+            accumulator: 0.0,
+            smoothing: 0.1,
+            smoothed_delta: 0.0,
+            elapsed: 0.0,
+            frame_count: 0,
+        }
+    }
+
+    /// Largest delta a single frame may advance by, in seconds.
+    pub fn with_max_delta(mut self, max_delta: f32) -> Self {
+        self.set_max_delta(max_delta);
+        self
+    }
+
+    /// Advance in whole multiples of `step` seconds (`None` = variable).
+    pub fn with_fixed_step(mut self, step: Option<f32>) -> Self {
+        self.set_fixed_step(step);
+        self
+    }
+
+    /// Upper bound on fixed steps consumed by one frame. Time beyond it is
+    /// dropped rather than carried into the next frame.
+    pub fn with_max_steps(mut self, max_steps: u32) -> Self {
+        self.max_steps = max_steps.max(1);
+        self
+    }
+
+    /// Weight of the newest frame in the FPS moving average, in `(0, 1]`.
+    pub fn with_smoothing(mut self, smoothing: f32) -> Self {
+        self.smoothing = smoothing.clamp(f32::EPSILON, 1.0);
+        self
+    }
+
+    pub fn set_max_delta(&mut self, max_delta: f32) {
+        self.max_delta = max_delta.max(0.0);
+    }
+
+    pub fn set_fixed_step(&mut self, step: Option<f32>) {
+        self.fixed_step = step.filter(|s| *s > 0.0);
+        self.accumulator = 0.0;
+    }
+
+    pub fn max_delta(&self) -> f32 {
+        self.max_delta
+    }
+
+    pub fn fixed_step(&self) -> Option<f32> {
+        self.fixed_step
+    }
+
+    /// Advance the clock by a measured frame delta (seconds).
+    ///
+    /// Negative or non-finite deltas are treated as zero.
+    pub fn tick(&mut self, raw_delta: f32) -> FrameTime {
+        let raw_delta = if raw_delta.is_finite() { raw_delta.max(0.0) } else { 0.0 };
+        let clamped = raw_delta.min(self.max_delta);
+
+        if self.frame_count == 0 {
+            self.smoothed_delta = raw_delta;
+        } else {
+            self.smoothed_delta += (raw_delta - self.smoothed_delta) * self.smoothing;
+        }
+        self.frame_count += 1;
+
+        let time = match self.fixed_step {
+            None => FrameTime { raw_delta, delta: clamped, steps: 1, alpha: 0.0 },
+            Some(step) => {
+                self.accumulator += clamped;
+                let available = (self.accumulator / step).floor() as u32;
+                let steps = available.min(self.max_steps);
+                self.accumulator -= steps as f32 * step;
+                if steps < available {
+                    // Drop the backlog instead of spiralling.
+                    self.accumulator %= step;
+                }
+                self.accumulator = self.accumulator.max(0.0);
+                FrameTime {
+                    raw_delta,
+                    delta: steps as f32 * step,
+                    steps,
+                    alpha: (self.accumulator / step).min(1.0 - f32::EPSILON),
+                }
+            }
+        };
+        self.elapsed += time.delta as f64;
+        time
+    }
+
+    /// Exponentially smoothed frames per second of the measured deltas.
+    pub fn fps(&self) -> f32 {
+        if self.smoothed_delta > 0.0 {
+            1.0 / self.smoothed_delta
+        } else {
+            0.0
+        }
+    }
+
+    /// Total simulated time, i.e. the sum of every returned `delta`.
+    pub fn elapsed(&self) -> f64 {
+        self.elapsed
+    }
+
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hitch_is_clamped() {
+        let mut clock = FrameClock::new();
+        let t = clock.tick(2.0);
+        assert_eq!(t.raw_delta, 2.0);
+        assert_eq!(t.delta, 0.1);
+    }
+
+    #[test]
+    fn invalid_deltas_are_zero() {
+        let mut clock = FrameClock::new();
+        assert_eq!(clock.tick(-1.0).delta, 0.0);
+        assert_eq!(clock.tick(f32::NAN).delta, 0.0);
+    }
+
+    #[test]
+    fn fixed_step_accumulates_remainder() {
+        let mut clock = FrameClock::new().with_fixed_step(Some(0.01));
+        let t = clock.tick(0.025);
+        assert_eq!(t.steps, 2);
+        assert!((t.delta - 0.02).abs() < 1e-6);
+        assert!((t.alpha - 0.5).abs() < 1e-3);
+        let t = clock.tick(0.005);
+        assert_eq!(t.steps, 1);
+        assert_eq!(clock.tick(0.0).steps, 0);
+    }
+
+    #[test]
+    fn fixed_step_caps_catch_up() {
+        let mut clock = FrameClock::new()
+            .with_max_delta(1.0)
+            .with_fixed_step(Some(0.01))
+            .with_max_steps(4);
+        let t = clock.tick(1.0);
+        assert_eq!(t.steps, 4);
+        // Backlog dropped: the next short frame does not keep catching up.
+        assert_eq!(clock.tick(0.0).steps, 0);
+    }
+
+    #[test]
+    fn fps_is_smoothed() {
+        let mut clock = FrameClock::new().with_smoothing(0.5);
+        clock.tick(1.0 / 60.0);
+        assert!((clock.fps() - 60.0).abs() < 1e-3);
+        clock.tick(1.0 / 30.0);
+        assert!(clock.fps() < 60.0 && clock.fps() > 30.0);
+    }
+}
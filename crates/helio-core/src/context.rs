@@ -231,6 +231,13 @@ pub struct PassContext<'a> {
 
     /// Component registry for type-erased storage access.
     pub components: &'a ComponentRegistry,
+
+    /// Shared per-pass uniform buffer; bind its group with the pass's slot
+    /// offset as the dynamic offset.
+    pub feature_uniforms: &'a crate::graph::FeatureUniforms,
+
+    /// Shared bind group of textures other passes produced for this frame.
+    pub feature_textures: &'a crate::graph::FeatureTextures,
 }
 
 impl<'a> PassContext<'a> {
@@ -260,6 +267,12 @@ impl<'a> PassContext<'a> {
         self.active_compute_pass
     }
 
+    /// Camera the frame is rendered from; the same value
+    /// [`PrepareContext::camera`] returned this frame.
+    pub fn camera(&self) -> &libhelio::GpuCameraUniforms {
+        self.scene.camera_uniforms
+    }
+
     /// Access a registered component storage by type.
     pub fn storage<T: Component + 'static>(&self) -> Option<&Vec<T>> {
         self.components.get_storage::<T>()
@@ -482,7 +495,9 @@ pub struct PrepareContext<'a> {
     /// Elapsed time since the previous frame, in seconds.
     ///
     /// Set by `RenderGraph::set_delta_time()` before each `execute()` call.
-    /// The high-level `Renderer` updates this automatically.  Direct
+    /// The high-level `Renderer` updates this automatically from its
+    /// [`FrameClock`](crate::FrameClock), so a hitch is clamped rather than
+    /// advancing animations by the whole stall.  Direct
     /// `RenderGraph` users should call `graph.set_delta_time(dt)` at the top
     /// of their render loop.
     ///
@@ -491,9 +506,59 @@ pub struct PrepareContext<'a> {
     /// than hard-coding `0.016`.  Returns `0.0` if the host has not yet
     /// called `set_delta_time()`.
     pub delta_time: f32,
+
+    /// Seconds of animation time: the sum of every `delta_time` so far.
+    ///
+    /// Set by `RenderGraph::set_time()`; the `Renderer` passes its frame
+    /// clock's elapsed time, so it survives graph rebuilds. Vertex animation
+    /// such as wind sway should read this rather than accumulating
+    /// `delta_time` per pass, so every pass displaces geometry identically.
+    pub time: f32,
+
+    /// Seed for stochastic sampling, set by `RenderGraph::set_random_seed()`
+    /// (`0` unless the host chose one).
+    ///
+    /// Passes should derive per-frame noise from
+    /// [`sampling_frame`](Self::sampling_frame) rather than from time or
+    /// `frame_num` alone, so the same seed and frame index reproduce the
+    /// same image.
+    pub random_seed: u64,
+
+    /// Frames the GPU may still be working on when this one is recorded,
+    /// set by `RenderGraph::set_frames_in_flight()` (`1..=3`, default `2`).
+    ///
+    /// Data written with [`write_buffer`](Self::write_buffer) needs a single
+    /// copy. Buffers the CPU maps (readbacks, mapped staging rings) need
+    /// this many copies, selected with [`frame_slot`](Self::frame_slot).
+    pub frames_in_flight: u32,
+
+    /// Shared per-pass uniform buffer; write the pass's slot here.
+    pub feature_uniforms: &'a crate::graph::FeatureUniforms,
 }
 
 impl<'a> PrepareContext<'a> {
+    /// Frame index to feed noise, jitter and sample rotation: `frame_num`
+    /// offset by [`random_seed`](Self::random_seed). With seed `0` this is
+    /// the plain frame number.
+    pub fn sampling_frame(&self) -> u32 {
+        self.frame_num.wrapping_add(self.random_seed) as u32
+    }
+
+    /// Index (`0..frames_in_flight`) of this frame's copy of CPU-mapped
+    /// per-frame data. No frame still on the GPU shares it.
+    pub fn frame_slot(&self) -> usize {
+        (self.frame_num % self.frames_in_flight.max(1) as u64) as usize
+    }
+
+    /// Camera the frame is rendered from.
+    ///
+    /// The renderer uploads the camera before any pass is prepared, so the
+    /// value is current in `prepare()` and stays the same through `execute()`
+    /// (see [`PassContext::camera`]) for the rest of the frame.
+    pub fn camera(&self) -> &libhelio::GpuCameraUniforms {
+        self.scene.camera.data()
+    }
+
     /// Upload bytes into a GPU buffer while participating in Helio's debug upload accounting.
     pub fn write_buffer(&self, buffer: &wgpu::Buffer, offset: u64, data: &[u8]) {
         crate::upload::write_buffer(self.queue, buffer, offset, data);
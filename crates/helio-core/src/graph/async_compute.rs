@@ -0,0 +1,112 @@
+//! Async compute placement for the render graph.
+//!
+//! Passes that return [`QueuePreference::AsyncCompute`](crate::QueuePreference)
+//! and are compute-only are candidates for an async compute queue. For each
+//! candidate the graph finds, from the declared reads and writes, the last
+//! graphics pass it must wait for and the first graphics pass that must wait
+//! for it. Everything in between can overlap. Hazards are read-after-write,
+//! write-after-read and write-after-write on any shared resource name.
+//! Dependencies between two async passes need no sync because they share a
+//! queue and keep graph order.
+//!
+//! wgpu currently exposes a single queue per device, so every pass is still
+//! submitted in graph order on that queue. The plan is computed at
+//! [`lock`](super::RenderGraph::lock) time and is what a multi-queue backend
+//! would use for placement and fences. It can also be inspected with
+//! [`async_compute_plan`](super::RenderGraph::async_compute_plan) or in the
+//! [`to_dot`](super::RenderGraph::to_dot) output.
+
+use std::ops::Range;
+
+/// An async compute pass and the graphics work it may overlap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AsyncComputeSpan {
+    /// Index of the async compute pass.
+    pub pass: usize,
+    /// Last graphics pass the async work must wait for, or `None` if it can
+    /// start with the frame.
+    pub wait_for: Option<usize>,
+    /// First graphics pass that must wait for the async work, or `None` if
+    /// nothing this frame depends on it.
+    pub signal_to: Option<usize>,
+    /// Pass indices strictly between `wait_for` and `signal_to`. The async
+    /// work can run alongside any graphics pass in this range.
+    pub overlap: Range<usize>,
+}
+
+fn conflicts(reads: &[&[&str]], writes: &[&[&str]], a: usize, b: usize) -> bool {
+    let shares = |x: &[&str], y: &[&str]| x.iter().any(|name| y.contains(name));
+    shares(writes[a], reads[b]) || shares(reads[a], writes[b]) || shares(writes[a], writes[b])
+}
+
+/// Plan async placement for every pass with `eligible[i] == true`.
+///
+/// `reads`, `writes` and `eligible` are indexed by pass and must have the
+/// same length.
+pub(crate) fn plan_async_compute(
+    reads: &[&[&str]],
+    writes: &[&[&str]],
+    eligible: &[bool],
+) -> Vec<AsyncComputeSpan> {
+    let count = eligible.len();
+    (0..count)
+        .filter(|&i| eligible[i])
+        .map(|i| {
+            let wait_for = (0..i)
+                .rev()
+                .find(|&j| !eligible[j] && conflicts(reads, writes, j, i));
+            let signal_to = (i + 1..count).find(|&j| !eligible[j] && conflicts(reads, writes, i, j));
+            AsyncComputeSpan {
+                pass: i,
+                wait_for,
+                signal_to,
+                overlap: wait_for.map_or(0, |j| j + 1)..signal_to.unwrap_or(count),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn async_pass_overlaps_independent_graphics_work() {
+        // Depth -> [AO async] -> Shadow -> GBuffer -> Light (reads ao)
+        let reads: [&[&str]; 5] = [&[], &["depth"], &[], &[], &["ao", "gbuffer"]];
+        let writes: [&[&str]; 5] = [&["depth"], &["ao"], &["shadow"], &["gbuffer"], &[]];
+        let eligible = [false, true, false, false, false];
+        let plan = plan_async_compute(&reads, &writes, &eligible);
+        assert_eq!(
+            plan,
+            vec![AsyncComputeSpan {
+                pass: 1,
+                wait_for: Some(0),
+                signal_to: Some(4),
+                overlap: 1..4,
+            }]
+        );
+    }
+
+    #[test]
+    fn write_after_read_is_a_sync_point() {
+        // Graphics pass 0 reads `history`, async pass 1 overwrites it.
+        let reads: [&[&str]; 3] = [&["history"], &[], &[]];
+        let writes: [&[&str]; 3] = [&[], &["history"], &[]];
+        let plan = plan_async_compute(&reads, &writes, &[false, true, false]);
+        assert_eq!(plan[0].wait_for, Some(0));
+        assert_eq!(plan[0].signal_to, None);
+        assert_eq!(plan[0].overlap, 1..3);
+    }
+
+    #[test]
+    fn async_to_async_dependencies_need_no_fence() {
+        let reads: [&[&str]; 3] = [&[], &["a"], &["b"]];
+        let writes: [&[&str]; 3] = [&["a"], &["b"], &[]];
+        let plan = plan_async_compute(&reads, &writes, &[true, true, false]);
+        assert_eq!(plan[0].wait_for, None);
+        assert_eq!(plan[0].signal_to, None);
+        assert_eq!(plan[1].signal_to, Some(2));
+        assert_eq!(plan[1].overlap, 0..2);
+    }
+}
@@ -1,11 +1,17 @@
 use crate::graph::executor::{format_bpp, format_name};
-use crate::graph::resource::GraphTexturePool;
-use crate::{GpuScene, PassContext, PrepareContext, Profiler, RenderPass, Result};
+use crate::graph::resource::{GraphTexture, GraphTexturePool};
+use crate::{GpuScene, PassContext, PrepareContext, Profiler, QueuePreference, RenderPass, Result};
 use libhelio::GBufferViews;
 use std::any::TypeId;
-use std::collections::HashMap;
-
-use super::resource_lifetime::ResourceLifetime;
+use std::collections::{HashMap, HashSet};
+
+use super::async_compute::{plan_async_compute, AsyncComputeSpan};
+use super::feature_textures::FeatureTextures;
+use super::feature_uniforms::FeatureUniforms;
+use super::frames_in_flight::{InFlight, DEFAULT_FRAMES_IN_FLIGHT, MAX_FRAMES_IN_FLIGHT};
+use super::merge::{plan_merge, MergeAction, MergePolicy};
+use super::reorder::{plan_move, plan_order};
+use super::resource_lifetime::{pass_accesses, ResourceLifetime};
 use super::scheduling::{CachedPass, PrePassAction};
 use super::{DebugPassInfo, DebugResourceInfo, FrameDebugData};
 
@@ -22,10 +28,23 @@ pub struct RenderGraph {
     pub(crate) output_w: u32,
     pub(crate) output_h: u32,
     delta_time: f32,
+    time: f32,
+    random_seed: u64,
+    /// See `frames_in_flight.rs`.
+    frames_in_flight: u32,
+    in_flight: InFlight<wgpu::SubmissionIndex>,
+    /// Last mode passed to [`set_debug_mode`](Self::set_debug_mode), forwarded
+    /// to passes merged in later.
+    debug_mode: u32,
+    /// Last convention passed to [`set_depth_convention`](Self::set_depth_convention),
+    /// forwarded to passes merged in later.
+    depth_convention: libhelio::DepthConvention,
     owns_device: bool,
     gpu_render_bundles: Vec<Option<wgpu::RenderBundle>>,
     resources_allocated: bool,
     pub(crate) subpass_chains: Vec<std::ops::Range<usize>>,
+    /// Async compute placement planned at lock time (see `async_compute.rs`).
+    pub(crate) async_compute: Vec<AsyncComputeSpan>,
     chain_membership: Vec<bool>,
     /// Previous frame's chain membership, used to detect which passes changed
     /// so only their bundles (and everything after) need rebuilding.
@@ -39,6 +58,15 @@ pub struct RenderGraph {
     /// Opaque storage for cross-crate data (e.g. a GraphRebuilder).
     /// Set by graph builders, consumed by the Renderer on construction.
     graph_data: Option<Box<dyn std::any::Any + Send + Sync>>,
+    /// Shared dynamic uniform buffer that passes carve parameter slots from.
+    feature_uniforms: FeatureUniforms,
+    /// Named pass outputs bound into one shared bind group for consumers.
+    pub(crate) feature_textures: FeatureTextures,
+    /// Passes switched off with [`set_pass_active`](Self::set_pass_active).
+    pub(crate) paused_passes: HashSet<String>,
+    /// Pool textures kept aside while the pool is rebuilt, so paused passes'
+    /// outputs survive the reallocation (see `hold_paused_outputs`).
+    pub(crate) held_textures: HashMap<String, GraphTexture>,
 }
 
 impl RenderGraph {
@@ -56,10 +84,17 @@ impl RenderGraph {
             output_w: 0,
             output_h: 0,
             delta_time: 0.0,
+            time: 0.0,
+            random_seed: 0,
+            frames_in_flight: DEFAULT_FRAMES_IN_FLIGHT,
+            in_flight: InFlight::new(),
+            debug_mode: 0,
+            depth_convention: libhelio::DepthConvention::Standard,
             owns_device: true,
             gpu_render_bundles: Vec::new(),
             resources_allocated: false,
             subpass_chains: Vec::new(),
+            async_compute: Vec::new(),
             chain_membership: Vec::new(),
             prev_chain_membership: Vec::new(),
             chain_generation: 0,
@@ -68,10 +103,17 @@ impl RenderGraph {
             pass_cache: Vec::new(),
             frame_count: 0,
             graph_data: None,
+            feature_uniforms: FeatureUniforms::new(device),
+            feature_textures: FeatureTextures::new(device, queue),
+            paused_passes: HashSet::new(),
+            held_textures: HashMap::new(),
         }
     }
 
-    pub fn new_with_external_device(device: &std::sync::Arc<wgpu::Device>, queue: &wgpu::Queue) -> Self {
+    pub fn new_with_external_device(
+        device: &std::sync::Arc<wgpu::Device>,
+        queue: &wgpu::Queue,
+    ) -> Self {
         let mut graph = Self::new(device, queue);
         graph.owns_device = false;
         graph
@@ -81,16 +123,62 @@ impl RenderGraph {
         self.delta_time = dt;
     }
 
+    /// Animation time in seconds, handed to passes as [`PrepareContext::time`].
+    pub fn set_time(&mut self, seconds: f32) {
+        self.time = seconds;
+    }
+
+    /// Seed for stochastic sampling, handed to passes as
+    /// [`PrepareContext::random_seed`]. Rendering frame N twice with the same
+    /// seed and inputs gives the same noise.
+    pub fn set_random_seed(&mut self, seed: u64) {
+        self.random_seed = seed;
+    }
+
+    pub fn random_seed(&self) -> u64 {
+        self.random_seed
+    }
+
+    /// Maximum number of submitted frames the GPU may still be working on,
+    /// clamped to `1..=MAX_FRAMES_IN_FLIGHT`. Takes effect at the end of
+    /// the next [`execute`](Self::execute).
+    /// See the `frames_in_flight` module docs for what passes must buffer.
+    pub fn set_frames_in_flight(&mut self, frames: u32) {
+        self.frames_in_flight = frames.clamp(1, MAX_FRAMES_IN_FLIGHT);
+    }
+
+    pub fn frames_in_flight(&self) -> u32 {
+        self.frames_in_flight
+    }
+
     /// Returns true when at least one pass reconstructs the renderer's
     /// subpixel camera-jitter sequence.
     pub fn requires_camera_jitter(&self) -> bool {
-        self.passes
-            .iter()
-            .any(|pass| pass.requires_camera_jitter())
+        self.passes.iter().any(|pass| pass.requires_camera_jitter())
     }
 
     // ── Public API ──────────────────────────────────────────────────────
 
+    /// Shared per-pass uniform buffer (layout and bind group).
+    pub fn feature_uniforms(&self) -> &FeatureUniforms {
+        &self.feature_uniforms
+    }
+
+    /// Mutable access for allocating slots while building the graph.
+    pub fn feature_uniforms_mut(&mut self) -> &mut FeatureUniforms {
+        &mut self.feature_uniforms
+    }
+
+    /// Shared bind group of textures produced by passes for other passes.
+    pub fn feature_textures(&self) -> &FeatureTextures {
+        &self.feature_textures
+    }
+
+    /// Mutable access for registering feature textures while building the graph.
+    pub fn feature_textures_mut(&mut self) -> &mut FeatureTextures {
+        &mut self.feature_textures
+    }
+
     /// Store opaque data (e.g. a GraphRebuilder) on the graph so the Renderer
     /// can retrieve it later without the caller having to pass it explicitly.
     pub fn set_graph_data<T: Send + Sync + 'static>(&mut self, data: T) {
@@ -118,9 +206,11 @@ impl RenderGraph {
                 pass.on_resize(&self.device, width, height);
             }
         } else {
+            self.hold_paused_outputs();
             self.pool.clear();
             self.collect_declarations();
             self.allocate_textures();
+            self.held_textures.clear();
             self.detect_subpass_chains();
             self.resources_allocated = true;
             for pass in &mut self.passes {
@@ -130,14 +220,33 @@ impl RenderGraph {
         }
     }
 
+    /// Re-collects every pass's resource declarations and reallocates the
+    /// graph textures at the current render size.
+    ///
+    /// Called automatically when a pass reports
+    /// [`RenderPass::resources_changed`]; existing texture views are invalid
+    /// afterwards.
+    pub fn reallocate_resources(&mut self) {
+        let (width, height) = (self.output_w, self.output_h);
+        if self.locked {
+            self.locked = false;
+            self.lock(width, height);
+        } else {
+            self.init_transients(width, height);
+        }
+    }
+
     pub fn init_transients(&mut self, width: u32, height: u32) {
         self.internal_w = width;
         self.internal_h = height;
         self.output_w = width;
         self.output_h = height;
+        self.hold_paused_outputs();
         self.pool.clear();
+        self.feature_uniforms.ensure_buffer(&self.device);
         self.collect_declarations();
         self.allocate_textures();
+        self.held_textures.clear();
         self.detect_subpass_chains();
         self.resources_allocated = true;
         self.rebuild_gpu_render_bundles();
@@ -146,11 +255,195 @@ impl RenderGraph {
     pub fn add_pass(&mut self, pass: Box<dyn RenderPass>) {
         assert!(!self.locked, "RenderGraph: cannot add_pass() after lock()");
         let type_id = pass.as_any().type_id();
-        self.pass_index_map.entry(type_id).or_insert(self.passes.len());
+        self.pass_index_map
+            .entry(type_id)
+            .or_insert(self.passes.len());
         self.passes.push(pass);
         self.gpu_render_bundles.push(None);
     }
 
+    /// Move every pass of `other` into this graph, failing on any pass name
+    /// that already exists here. See [`merge_with`](Self::merge_with).
+    pub fn merge(&mut self, other: RenderGraph) -> Result<()> {
+        self.merge_with(other, MergePolicy::Error)
+    }
+
+    /// Move every pass of `other` into this graph, resolving name collisions
+    /// with `policy`.
+    ///
+    /// Non-colliding passes are appended after the existing ones in `other`'s
+    /// order. Merged passes receive this graph's current debug mode. Other
+    /// per-graph state of `other` (profiler, textures) is dropped, except
+    /// its graph data when this graph has none. Both graphs must be
+    /// unlocked, and `other` must not have allocated feature uniform or
+    /// feature texture slots (their indices refer to its own bind groups);
+    /// an `Err` leaves this graph unchanged.
+    pub fn merge_with(&mut self, mut other: RenderGraph, policy: MergePolicy) -> Result<()> {
+        if self.locked || other.locked {
+            return Err(crate::Error::InvalidPassConfig(
+                "cannot merge render graphs after lock()".into(),
+            ));
+        }
+        if other.feature_uniforms.slot_count() > 0
+            || other.feature_textures.names().next().is_some()
+        {
+            return Err(crate::Error::InvalidPassConfig(
+                "cannot merge a render graph with its own feature uniform or texture slots; \
+                 allocate them from the destination graph"
+                    .into(),
+            ));
+        }
+        let existing: Vec<&str> = self.passes.iter().map(|p| p.name()).collect();
+        let incoming: Vec<&str> = other.passes.iter().map(|p| p.name()).collect();
+        let plan = plan_merge(&existing, &incoming, policy)?;
+
+        for (mut pass, action) in std::mem::take(&mut other.passes).into_iter().zip(plan) {
+            pass.set_debug_mode(self.debug_mode);
+            pass.set_depth_convention(self.depth_convention);
+            match action {
+                MergeAction::Append => {
+                    self.passes.push(pass);
+                    self.gpu_render_bundles.push(None);
+                }
+                MergeAction::Replace(index) => {
+                    self.passes[index] = pass;
+                    self.gpu_render_bundles[index] = None;
+                }
+                MergeAction::Skip => {}
+            }
+        }
+        if self.graph_data.is_none() {
+            self.graph_data = other.graph_data.take();
+        }
+        self.rebuild_pass_index();
+        Ok(())
+    }
+
+    /// Move the pass named `name` to position `new_index`, shifting the passes
+    /// in between. See [`set_pass_order`](Self::set_pass_order).
+    pub fn move_pass(&mut self, name: &str, new_index: usize) -> Result<()> {
+        let names: Vec<&str> = self.passes.iter().map(|p| p.name()).collect();
+        let order = plan_move(&names, name, new_index)?;
+        self.apply_pass_order(order);
+        Ok(())
+    }
+
+    /// Put the named passes in the given relative order.
+    ///
+    /// Listed passes take over the positions the listed passes held before;
+    /// unlisted passes keep theirs, so listing every pass sets the full
+    /// execution order. Every name is validated first, so an `Err` (unknown
+    /// or repeated name) leaves the graph unchanged. On a graph that has
+    /// allocated its resources, lifetimes, subpass chains and render bundles
+    /// are rebuilt for the new order.
+    pub fn set_pass_order(&mut self, order: &[&str]) -> Result<()> {
+        let names: Vec<&str> = self.passes.iter().map(|p| p.name()).collect();
+        let order = plan_order(&names, order)?;
+        self.apply_pass_order(order);
+        Ok(())
+    }
+
+    /// Pause or resume the pass named `name` without removing it.
+    ///
+    /// A paused pass skips `prepare` and `execute` but still publishes its
+    /// outputs, so later passes keep reading whatever it last wrote: pausing
+    /// the shadow pass freezes the shadow atlas, pausing TAA freezes its
+    /// history, and the shading that samples them is untouched. Meant for
+    /// bisecting artifacts and isolating pass cost; the pass's own enable
+    /// settings are left alone.
+    ///
+    /// Paused passes are left out of subpass chains, so on a graph that has
+    /// allocated its resources a change rebuilds lifetimes, chains and
+    /// bundles. The paused passes' own output textures are carried across
+    /// that rebuild untouched, contents included; every other graph texture
+    /// is reallocated. `Err` for a name that matches no pass.
+    pub fn set_pass_active(&mut self, name: &str, active: bool) -> Result<()> {
+        if !self.passes.iter().any(|p| p.name() == name) {
+            return Err(crate::Error::InvalidPassConfig(format!(
+                "set_pass_active: no pass named '{name}'"
+            )));
+        }
+        let changed = if active {
+            self.paused_passes.remove(name)
+        } else {
+            self.paused_passes.insert(name.to_string())
+        };
+        if changed && self.resources_allocated {
+            self.gpu_render_bundles.clear();
+            self.reallocate_resources();
+        }
+        Ok(())
+    }
+
+    /// Whether the pass named `name` runs, or `None` if no pass has that name.
+    pub fn is_pass_active(&self, name: &str) -> Option<bool> {
+        self.passes
+            .iter()
+            .any(|p| p.name() == name)
+            .then(|| !self.paused_passes.contains(name))
+    }
+
+    /// Set aside the current pool textures before it is cleared, if any pass
+    /// is paused. `allocate_textures` reuses the ones still written by a
+    /// paused pass with an unchanged description instead of allocating.
+    fn hold_paused_outputs(&mut self) {
+        self.held_textures.clear();
+        if self.paused_passes.is_empty() {
+            return;
+        }
+        self.held_textures = self
+            .pool
+            .textures()
+            .map(|(name, texture)| (name.to_string(), texture.clone()))
+            .collect();
+    }
+
+    pub(crate) fn pass_active(&self, name: &str) -> bool {
+        !self.paused_passes.contains(name)
+    }
+
+    /// Names of the passes paused with [`set_pass_active`](Self::set_pass_active).
+    pub fn paused_passes(&self) -> impl Iterator<Item = &str> {
+        self.paused_passes.iter().map(String::as_str)
+    }
+
+    /// Permute the passes (`order` lists old indices in their new order).
+    fn apply_pass_order(&mut self, order: Vec<usize>) {
+        if order.iter().enumerate().all(|(new, old)| new == *old) {
+            return;
+        }
+        let mut passes: Vec<Option<Box<dyn RenderPass>>> = std::mem::take(&mut self.passes)
+            .into_iter()
+            .map(Some)
+            .collect();
+        self.passes = order
+            .into_iter()
+            .map(|old| passes[old].take().expect("pass order is a permutation"))
+            .collect();
+        self.rebuild_pass_index();
+
+        // Bundles capture the resources published by earlier passes, and
+        // chains and lifetimes follow pass order; drop them all so they are
+        // rebuilt from scratch rather than incrementally.
+        self.gpu_render_bundles.clear();
+        if self.resources_allocated {
+            self.reallocate_resources();
+        } else {
+            self.gpu_render_bundles
+                .resize_with(self.passes.len(), || None);
+        }
+    }
+
+    /// Recompute the type → first-index lookup after passes were moved.
+    fn rebuild_pass_index(&mut self) {
+        self.pass_index_map.clear();
+        for (index, pass) in self.passes.iter().enumerate() {
+            self.pass_index_map
+                .entry(pass.as_any().type_id())
+                .or_insert(index);
+        }
+    }
+
     pub fn find_pass_mut<T: RenderPass + 'static>(&mut self) -> Option<&mut T> {
         let idx = *self.pass_index_map.get(&TypeId::of::<T>())?;
         self.passes[idx].as_any_mut().downcast_mut::<T>()
@@ -163,7 +456,9 @@ impl RenderGraph {
 
     /// Find the index of the first pass matching type `T`.
     pub fn pass_index_of<T: RenderPass + 'static>(&self) -> Option<usize> {
-        self.passes.iter().position(|p| (*p).as_any().downcast_ref::<T>().is_some())
+        self.passes
+            .iter()
+            .position(|p| (*p).as_any().downcast_ref::<T>().is_some())
     }
 
     /// Replace the pass at `index` with a new one.
@@ -188,11 +483,31 @@ impl RenderGraph {
 
     /// Propagate a renderer-wide debug mode change to every pass.
     pub fn set_debug_mode(&mut self, mode: u32) {
+        self.debug_mode = mode;
         for pass in &mut self.passes {
             pass.set_debug_mode(mode);
         }
     }
 
+    /// Propagate the shadow depth convention to every pass.
+    pub fn set_depth_convention(&mut self, convention: libhelio::DepthConvention) {
+        self.depth_convention = convention;
+        for pass in &mut self.passes {
+            pass.set_depth_convention(convention);
+        }
+    }
+
+    pub fn depth_convention(&self) -> libhelio::DepthConvention {
+        self.depth_convention
+    }
+
+    /// Propagate a quality preset to every pass.
+    pub fn apply_quality(&mut self, preset: crate::QualityPreset) {
+        for pass in &mut self.passes {
+            pass.apply_quality(preset);
+        }
+    }
+
     pub fn validate_dependencies(&self) -> std::result::Result<(), String> {
         use std::collections::HashSet;
         let mut available: HashSet<&str> = HashSet::new();
@@ -220,26 +535,48 @@ impl RenderGraph {
         Ok(())
     }
 
+    /// Print [`to_dot`](Self::to_dot) to stderr.
     pub fn dump_dependency_graph(&self) {
-        eprintln!("digraph RenderGraph {{");
-        for (i, pass) in self.passes.iter().enumerate() {
-            eprintln!("  {} [label=\"{}\"];", i, pass.name());
-            for &resource in pass.reads() {
-                for j in (0..i).rev() {
-                    if self.passes[j].writes().contains(&resource) {
-                        eprintln!("  {} -> {} [label=\"{}\"];", j, i, resource);
-                        break;
-                    }
-                }
-            }
-        }
-        eprintln!("}}");
+        eprint!("{}", self.to_dot());
+    }
+
+    /// Passes planned for the async compute queue and their sync points,
+    /// computed by [`lock`](Self::lock) from each pass's
+    /// [`queue_preference`](RenderPass::queue_preference) and declared
+    /// resources. Empty before the graph is locked.
+    ///
+    /// wgpu has a single queue per device, so execution still follows graph
+    /// order; the plan describes where a multi-queue backend would overlap
+    /// work.
+    pub fn async_compute_plan(&self) -> &[AsyncComputeSpan] {
+        &self.async_compute
     }
 
     pub fn profiler(&self) -> &Profiler {
         &self.profiler
     }
 
+    /// Number of passes in the graph.
+    pub fn pass_count(&self) -> usize {
+        self.passes.len()
+    }
+
+    /// Approximate bytes held by the graph-owned textures (render targets,
+    /// depth buffers and declared atlases).
+    pub fn texture_memory_bytes(&self) -> u64 {
+        self.pool.memory_bytes()
+    }
+
+    /// Per-pass [`gpu_memory_bytes`](RenderPass::gpu_memory_bytes), in pass
+    /// order, omitting passes that report nothing.
+    pub fn pass_memory(&self) -> Vec<(&'static str, u64)> {
+        self.passes
+            .iter()
+            .map(|pass| (pass.name(), pass.gpu_memory_bytes()))
+            .filter(|(_, bytes)| *bytes > 0)
+            .collect()
+    }
+
     /// Collect a snapshot of all resource and pass data for the debug overlay.
     pub fn collect_frame_debug_data(&self) -> FrameDebugData {
         let mut data = FrameDebugData::default();
@@ -251,11 +588,16 @@ impl RenderGraph {
 
         for (name, rl) in &self.resources {
             let bpp = format_bpp(rl.format);
-            let bytes = rl.width as u64 * rl.height as u64 * rl.depth_or_array_layers as u64 * bpp as u64 / 8;
+            let bytes =
+                rl.width as u64 * rl.height as u64 * rl.depth_or_array_layers as u64 * bpp as u64
+                    / 8;
             total_bytes += bytes;
             let alias = rl.alias_group.as_deref().unwrap_or("-").to_string();
             if rl.alias_group.is_some() {
-                alias_groups.entry(rl.alias_group.as_ref().unwrap()).or_default().push(name);
+                alias_groups
+                    .entry(rl.alias_group.as_ref().unwrap())
+                    .or_default()
+                    .push(name);
             }
             data.resources.push(DebugResourceInfo {
                 name: name.clone(),
@@ -273,16 +615,28 @@ impl RenderGraph {
         data.total_vram_kb = total_bytes / 1024;
 
         for (group, members) in &alias_groups {
-            let t: u64 = members.iter().filter_map(|n| {
-                self.resources.get(*n).map(|rl| {
-                    let bpp = format_bpp(rl.format);
-                    rl.width as u64 * rl.height as u64 * rl.depth_or_array_layers as u64 * bpp as u64 / 8
+            let t: u64 = members
+                .iter()
+                .filter_map(|n| {
+                    self.resources.get(*n).map(|rl| {
+                        let bpp = format_bpp(rl.format);
+                        rl.width as u64
+                            * rl.height as u64
+                            * rl.depth_or_array_layers as u64
+                            * bpp as u64
+                            / 8
+                    })
                 })
-            }).sum();
+                .sum();
             let saved = t * (members.len().saturating_sub(1) as u64);
             data.passes.push(DebugPassInfo {
                 index: 999,
-                name: format!("alias group '{}': {} members, ~{} KB saved", group, members.len(), saved / 1024),
+                name: format!(
+                    "alias group '{}': {} members, ~{} KB saved",
+                    group,
+                    members.len(),
+                    saved / 1024
+                ),
                 kind: String::new(),
                 writes: Vec::new(),
                 chain_marker: String::new(),
@@ -297,7 +651,9 @@ impl RenderGraph {
         }
 
         for (i, pass) in self.passes.iter().enumerate() {
-            let writes: Vec<String> = self.resources.iter()
+            let writes: Vec<String> = self
+                .resources
+                .iter()
                 .filter(|(_, rl)| rl.first_write_pass == i)
                 .map(|(n, _)| n.clone())
                 .collect();
@@ -305,8 +661,11 @@ impl RenderGraph {
             let marker = match pass_chain[i] {
                 Some(ci) => {
                     let chain = &self.subpass_chains[ci];
-                    if i == chain.start { format!("[{}.{}]", ci, chain.len()) }
-                    else { format!("|.{}", chain.len()) }
+                    if i == chain.start {
+                        format!("[{}.{}]", ci, chain.len())
+                    } else {
+                        format!("|.{}", chain.len())
+                    }
                 }
                 None => String::new(),
             };
@@ -320,8 +679,12 @@ impl RenderGraph {
         }
 
         for (ci, chain) in self.subpass_chains.iter().enumerate() {
-            let names: Vec<String> = self.passes[chain.start..chain.end].iter().map(|p| p.name().to_string()).collect();
-            data.subpass_chains.push(format!("chain {}: {}", ci, names.join(" → ")));
+            let names: Vec<String> = self.passes[chain.start..chain.end]
+                .iter()
+                .map(|p| p.name().to_string())
+                .collect();
+            data.subpass_chains
+                .push(format!("chain {}: {}", ci, names.join(" → ")));
         }
 
         data
@@ -344,7 +707,20 @@ impl RenderGraph {
         depth: &wgpu::TextureView,
         frame_resources: &libhelio::FrameResources<'_>,
     ) -> Result<wgpu::SubmissionIndex> {
-        assert!(self.locked, "RenderGraph::execute() requires lock() to be called first");
+        assert!(
+            self.locked,
+            "RenderGraph::execute() requires lock() to be called first"
+        );
+
+        // Poll every pass so each one clears its flag, then rebuild once.
+        let mut resources_changed = false;
+        for pass in &mut self.passes {
+            resources_changed |= pass.resources_changed();
+        }
+        if resources_changed {
+            self.reallocate_resources();
+        }
+        self.feature_uniforms.ensure_buffer(&scene.device);
 
         self.profiler.clear_cpu_timings();
 
@@ -353,11 +729,12 @@ impl RenderGraph {
             .create_command_encoder(&wgpu::CommandEncoderDescriptor {
                 label: Some("Render Graph"),
             });
-        let mut compute_encoder = scene
-            .device
-            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
-                label: Some("Compute Graph"),
-            });
+        let mut compute_encoder =
+            scene
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                    label: Some("Compute Graph"),
+                });
 
         let mut visible_frame_resources = *frame_resources;
 
@@ -365,11 +742,24 @@ impl RenderGraph {
         let mut chain_patch: Vec<Option<wgpu::RenderPassColorAttachment<'static>>> = Vec::new();
 
         for (pass_index, pass) in self.passes.iter_mut().enumerate() {
+            if self.paused_passes.contains(pass.name()) {
+                // Paused passes are never chained (see `lock`); their
+                // outputs keep the last frame they ran.
+                if let Some(actions) = self.pre_pass_actions.get(pass_index) {
+                    apply_pre_pass_actions(actions, &mut visible_frame_resources);
+                }
+                pass.publish(&mut visible_frame_resources);
+                continue;
+            }
+
             if let Some(bundle) = &self.gpu_render_bundles[pass_index] {
                 let pass_name = pass.name();
-                self.profiler.begin_gpu_pass(&mut compute_encoder, pass_name);
+                self.profiler
+                    .begin_gpu_pass(&mut compute_encoder, pass_name);
 
-                if let Some(desc) = pass.render_pass_descriptor(target, depth, &visible_frame_resources) {
+                if let Some(desc) =
+                    pass.render_pass_descriptor(target, depth, &visible_frame_resources)
+                {
                     let mut pass_encoder = encoder.begin_render_pass(&desc);
                     pass_encoder.execute_bundles(std::iter::once(bundle));
                 } else {
@@ -393,6 +783,8 @@ impl RenderGraph {
                         active_render_pass: None,
                         active_compute_pass: None,
                         components: &scene.components,
+                        feature_uniforms: &self.feature_uniforms,
+                        feature_textures: &self.feature_textures,
                         #[cfg(debug_assertions)]
                         chain_transparent: false,
                     };
@@ -417,33 +809,27 @@ impl RenderGraph {
                     width: self.internal_w,
                     height: self.internal_h,
                     delta_time: self.delta_time,
+                    time: self.time,
+                    random_seed: self.random_seed,
+                    frames_in_flight: self.frames_in_flight,
+                    feature_uniforms: &self.feature_uniforms,
                 };
                 pass.prepare(&prepare_ctx)?;
             }
 
             // Populate graph-owned output textures into FrameResources BEFORE execute().
             if let Some(actions) = self.pre_pass_actions.get(pass_index) {
-                for action in actions {
-                    match action {
-                        PrePassAction::Route { name, view } => {
-                            route_named_texture(name, view, &mut visible_frame_resources);
-                        }
-                        PrePassAction::Gbuffer { albedo, normal, orm, emissive } => {
-                            visible_frame_resources.gbuffer.write(
-                                GBufferViews { albedo, normal, orm, emissive },
-                                "Graph",
-                            );
-                        }
-                    }
-                }
+                apply_pre_pass_actions(actions, &mut visible_frame_resources);
             }
 
             // execute()
             let pass_name = pass.name();
-            self.profiler.begin_gpu_pass(&mut compute_encoder, pass_name);
+            self.profiler
+                .begin_gpu_pass(&mut compute_encoder, pass_name);
 
             // Migrated path: executor manages render pass (pass implements render_pass_descriptor).
-            if let Some(desc) = pass.render_pass_descriptor(target, depth, &visible_frame_resources) {
+            if let Some(desc) = pass.render_pass_descriptor(target, depth, &visible_frame_resources)
+            {
                 let cache = self.pass_cache.get(pass_index).and_then(|c| c.as_ref());
                 let is_chained = cache.map_or(false, |c| !c.chain_range.is_empty());
 
@@ -451,18 +837,22 @@ impl RenderGraph {
                     let c = cache.unwrap();
                     if pass_index == c.chain_range.start {
                         chain_patch.clear();
-                        chain_patch.extend(desc.color_attachments.iter().enumerate().map(|(i, opt)| {
-                            let mut a = opt.clone();
-                            if let Some(store) = c.store_ops.get(i).copied().flatten() {
-                                if let Some(ref mut att) = a {
-                                    att.ops.store = store;
+                        chain_patch.extend(desc.color_attachments.iter().enumerate().map(
+                            |(i, opt)| {
+                                let mut a = opt.clone();
+                                if let Some(store) = c.store_ops.get(i).copied().flatten() {
+                                    if let Some(ref mut att) = a {
+                                        att.ops.store = store;
+                                    }
                                 }
-                            }
-                            unsafe { std::mem::transmute::<
-                                Option<wgpu::RenderPassColorAttachment<'_>>,
-                                Option<wgpu::RenderPassColorAttachment<'static>>,
-                            >(a) }
-                        }));
+                                unsafe {
+                                    std::mem::transmute::<
+                                        Option<wgpu::RenderPassColorAttachment<'_>>,
+                                        Option<wgpu::RenderPassColorAttachment<'static>>,
+                                    >(a)
+                                }
+                            },
+                        ));
                         let chain_desc = wgpu::RenderPassDescriptor {
                             label: desc.label,
                             color_attachments: &chain_patch,
@@ -495,9 +885,13 @@ impl RenderGraph {
                         resource_pool: &self.pool,
                         subpass_index: c.subpass_index,
                         subpass_count: c.subpass_count,
-                        active_render_pass: chain_rp.as_mut().map(|rp| &mut **rp as *mut _ as *mut _),
+                        active_render_pass: chain_rp
+                            .as_mut()
+                            .map(|rp| &mut **rp as *mut _ as *mut _),
                         active_compute_pass: None,
                         components: &scene.components,
+                        feature_uniforms: &self.feature_uniforms,
+                        feature_textures: &self.feature_textures,
                         #[cfg(debug_assertions)]
                         chain_transparent: false,
                     };
@@ -505,24 +899,34 @@ impl RenderGraph {
 
                     if pass_index + 1 >= c.chain_range.end {
                         if let Some(mut rp) = chain_rp.take() {
-                            unsafe { std::mem::ManuallyDrop::drop(&mut rp); }
+                            unsafe {
+                                std::mem::ManuallyDrop::drop(&mut rp);
+                            }
                         }
                     }
                 } else {
                     if let Some(mut rp) = chain_rp.take() {
-                        unsafe { std::mem::ManuallyDrop::drop(&mut rp); }
+                        unsafe {
+                            std::mem::ManuallyDrop::drop(&mut rp);
+                        }
                     }
 
-                    let standalone_atts: Vec<Option<wgpu::RenderPassColorAttachment<'_>>> =
-                        desc.color_attachments.iter().enumerate().map(|(i, opt)| {
+                    let standalone_atts: Vec<Option<wgpu::RenderPassColorAttachment<'_>>> = desc
+                        .color_attachments
+                        .iter()
+                        .enumerate()
+                        .map(|(i, opt)| {
                             let mut a = opt.clone();
-                            if let Some(store) = cache.and_then(|c| c.store_ops.get(i).copied()).flatten() {
+                            if let Some(store) =
+                                cache.and_then(|c| c.store_ops.get(i).copied()).flatten()
+                            {
                                 if let Some(ref mut att) = a {
                                     att.ops.store = store;
                                 }
                             }
                             a
-                        }).collect();
+                        })
+                        .collect();
                     let standalone_desc = wgpu::RenderPassDescriptor {
                         label: desc.label,
                         color_attachments: &standalone_atts,
@@ -557,6 +961,8 @@ impl RenderGraph {
                             active_render_pass: Some(&mut rp as *mut _ as *mut _),
                             active_compute_pass: None,
                             components: &scene.components,
+                            feature_uniforms: &self.feature_uniforms,
+                            feature_textures: &self.feature_textures,
                             #[cfg(debug_assertions)]
                             chain_transparent: false,
                         };
@@ -564,18 +970,24 @@ impl RenderGraph {
                     }
                 }
             } else {
-                let bridged = self.chain_membership.get(pass_index).copied().unwrap_or(false)
+                let bridged = self
+                    .chain_membership
+                    .get(pass_index)
+                    .copied()
+                    .unwrap_or(false)
                     && pass.chain_transparent();
                 if !bridged {
                     if let Some(mut rp) = chain_rp.take() {
-                        unsafe { std::mem::ManuallyDrop::drop(&mut rp); }
+                        unsafe {
+                            std::mem::ManuallyDrop::drop(&mut rp);
+                        }
                     }
                 }
 
                 let scene_resources = scene.resources();
                 let mut ctx = PassContext {
                     encoder_ptr: std::ptr::addr_of_mut!(encoder),
-                        compute_encoder_ptr: std::ptr::addr_of_mut!(compute_encoder),
+                    compute_encoder_ptr: std::ptr::addr_of_mut!(compute_encoder),
                     target,
                     depth,
                     scene: scene_resources,
@@ -592,6 +1004,8 @@ impl RenderGraph {
                     active_render_pass: None,
                     active_compute_pass: None,
                     components: &scene.components,
+                    feature_uniforms: &self.feature_uniforms,
+                    feature_textures: &self.feature_textures,
                     #[cfg(debug_assertions)]
                     chain_transparent: bridged,
                 };
@@ -604,9 +1018,24 @@ impl RenderGraph {
         }
 
         self.profiler.resolve_gpu_queries(&mut compute_encoder);
-        let submission_index = scene.queue.submit([compute_encoder.finish(), encoder.finish()]);
+        let submission_index = scene
+            .queue
+            .submit([compute_encoder.finish(), encoder.finish()]);
         crate::upload::finish_frame();
 
+        // Bound CPU run-ahead. With an external device the host paces frames.
+        if self.owns_device {
+            if let Some(oldest) = self
+                .in_flight
+                .push(submission_index.clone(), self.frames_in_flight)
+            {
+                let _ = scene.device.poll(wgpu::PollType::Wait {
+                    submission_index: Some(oldest),
+                    timeout: None,
+                });
+            }
+        }
+
         if self.owns_device {
             self.profiler.read_gpu_timestamps_blocking(&scene.device);
         } else {
@@ -625,6 +1054,7 @@ impl RenderGraph {
         self.internal_h = height;
         self.output_w = width;
         self.output_h = height;
+        self.hold_paused_outputs();
         self.pool.clear();
         self.collect_declarations();
 
@@ -644,15 +1074,28 @@ impl RenderGraph {
                 self.pool.get_view("gbuffer_orm"),
                 self.pool.get_view("gbuffer_emissive"),
             ) {
-                canon.gbuffer.write(libhelio::GBufferViews { albedo: a, normal: n, orm: o, emissive: e }, "Graph");
+                canon.gbuffer.write(
+                    libhelio::GBufferViews {
+                        albedo: a,
+                        normal: n,
+                        orm: o,
+                        emissive: e,
+                    },
+                    "Graph",
+                );
             }
         }
 
         let dummy_target = {
             let tex = self.device.create_texture(&wgpu::TextureDescriptor {
                 label: Some("Lock Dummy Target"),
-                size: wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
-                mip_level_count: 1, sample_count: 1,
+                size: wgpu::Extent3d {
+                    width: 1,
+                    height: 1,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
                 dimension: wgpu::TextureDimension::D2,
                 format: wgpu::TextureFormat::Rgba8Unorm,
                 usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
@@ -660,35 +1103,58 @@ impl RenderGraph {
             });
             tex.create_view(&wgpu::TextureViewDescriptor::default())
         };
-        let dummy_depth = self.pool.get_view("depth").cloned()
-            .unwrap_or_else(|| {
-                let tex = self.device.create_texture(&wgpu::TextureDescriptor {
-                    label: Some("Lock Dummy Depth"),
-                    size: wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
-                    mip_level_count: 1, sample_count: 1,
-                    dimension: wgpu::TextureDimension::D2,
-                    format: wgpu::TextureFormat::Depth32Float,
-                    usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
-                    view_formats: &[],
-                });
-                tex.create_view(&wgpu::TextureViewDescriptor::default())
+        let dummy_depth = self.pool.get_view("depth").cloned().unwrap_or_else(|| {
+            let tex = self.device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("Lock Dummy Depth"),
+                size: wgpu::Extent3d {
+                    width: 1,
+                    height: 1,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Depth32Float,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+                view_formats: &[],
             });
+            tex.create_view(&wgpu::TextureViewDescriptor::default())
+        });
 
-        let probes: Vec<Option<(usize, Vec<usize>)>> = self.passes.iter().map(|pass| {
-            let desc = pass.render_pass_descriptor(&dummy_target, &dummy_depth, &canon)?;
-            let color_len = desc.color_attachments.len();
-            let mut signature: Vec<usize> = desc.color_attachments.iter().map(|opt| {
-                opt.as_ref().map(|a| a.view as *const wgpu::TextureView as usize).unwrap_or(0)
-            }).collect();
-            signature.push(
-                desc.depth_stencil_attachment.as_ref()
-                    .map(|d| d.view as *const wgpu::TextureView as usize)
-                    .unwrap_or(0)
-            );
-            Some((color_len, signature))
-        }).collect();
-        let attachments: Vec<Option<Vec<usize>>> = probes.iter()
-            .map(|p| p.as_ref().map(|(_, sig)| sig.clone()))
+        let probes: Vec<Option<(usize, Vec<usize>)>> = self
+            .passes
+            .iter()
+            .map(|pass| {
+                let desc = pass.render_pass_descriptor(&dummy_target, &dummy_depth, &canon)?;
+                let color_len = desc.color_attachments.len();
+                let mut signature: Vec<usize> = desc
+                    .color_attachments
+                    .iter()
+                    .map(|opt| {
+                        opt.as_ref()
+                            .map(|a| a.view as *const wgpu::TextureView as usize)
+                            .unwrap_or(0)
+                    })
+                    .collect();
+                signature.push(
+                    desc.depth_stencil_attachment
+                        .as_ref()
+                        .map(|d| d.view as *const wgpu::TextureView as usize)
+                        .unwrap_or(0),
+                );
+                Some((color_len, signature))
+            })
+            .collect();
+        // Paused passes probe as compute-only so no chain spans them.
+        let attachments: Vec<Option<Vec<usize>>> = probes
+            .iter()
+            .zip(&self.passes)
+            .map(|(p, pass)| {
+                if self.paused_passes.contains(pass.name()) {
+                    return None;
+                }
+                p.as_ref().map(|(_, sig)| sig.clone())
+            })
             .collect();
 
         // Phase 2: detect chains and compute chain_local BEFORE final allocation.
@@ -703,9 +1169,10 @@ impl RenderGraph {
             }
         }
         for rl in self.resources.values_mut() {
-            rl.chain_local = self.subpass_chains.iter().any(|c| {
-                c.start <= rl.first_write_pass && rl.last_read_pass < c.end
-            });
+            rl.chain_local = self
+                .subpass_chains
+                .iter()
+                .any(|c| c.start <= rl.first_write_pass && rl.last_read_pass < c.end);
         }
 
         // Phase 3: assign alias groups so chain-local and non-chain-local
@@ -718,29 +1185,67 @@ impl RenderGraph {
         // Phase 4: re-allocate textures with chain-aware alias groups.
         self.pool.clear();
         self.allocate_textures();
+        self.held_textures.clear();
         self.resources_allocated = true;
 
         // Phase 5: detect chain membership changes for incremental bundle rebuild.
         // Only advance the generation if membership actually changed.
         let membership_dirty = self.passes.len() != self.prev_chain_membership.len()
-            || self.chain_membership.iter().zip(&self.prev_chain_membership).any(|(c, p)| c != p);
+            || self
+                .chain_membership
+                .iter()
+                .zip(&self.prev_chain_membership)
+                .any(|(c, p)| c != p);
         if membership_dirty {
             self.chain_generation = self.chain_generation.wrapping_add(1);
         }
 
         // Phase 6: build pass cache and render bundles.
-        self.pass_cache = probes.into_iter().enumerate().map(|(pi, probe)| {
-            let (color_len, _) = probe?;
-            let chain = self.subpass_chains.iter().find(|c| c.contains(&pi));
-            let chain_range = chain.cloned().unwrap_or(0..0);
-            let subpass_index = chain.map_or(0, |c| (pi - c.start) as u32);
-            let subpass_count = chain.map_or(0, |c| c.len() as u32);
-            let store_ops: Vec<Option<wgpu::StoreOp>> = vec![None; color_len];
-            Some(CachedPass { store_ops, subpass_index, subpass_count, chain_range })
-        }).collect();
+        self.pass_cache = probes
+            .into_iter()
+            .enumerate()
+            .map(|(pi, probe)| {
+                let (color_len, _) = probe?;
+                let chain = self.subpass_chains.iter().find(|c| c.contains(&pi));
+                let chain_range = chain.cloned().unwrap_or(0..0);
+                let subpass_index = chain.map_or(0, |c| (pi - c.start) as u32);
+                let subpass_count = chain.map_or(0, |c| c.len() as u32);
+                let store_ops: Vec<Option<wgpu::StoreOp>> = vec![None; color_len];
+                Some(CachedPass {
+                    store_ops,
+                    subpass_index,
+                    subpass_count,
+                    chain_range,
+                })
+            })
+            .collect();
 
         self.rebuild_gpu_render_bundles_incremental();
 
+        // Phase 7: async compute placement. Only compute-only passes outside
+        // subpass chains qualify; anything else stays on the graphics queue.
+        {
+            let accesses: Vec<_> = self
+                .passes
+                .iter()
+                .map(|p| pass_accesses(p.as_ref()))
+                .collect();
+            let reads: Vec<&[&str]> = accesses.iter().map(|(r, _)| r.as_slice()).collect();
+            let writes: Vec<&[&str]> = accesses.iter().map(|(_, w)| w.as_slice()).collect();
+            let eligible: Vec<bool> = self.passes.iter().enumerate().map(|(i, pass)| {
+                let wants_async = pass.queue_preference() == QueuePreference::AsyncCompute;
+                let compute_only = self.pass_cache[i].is_none() && !self.chain_membership[i];
+                if wants_async && !compute_only {
+                    eprintln!(
+                        "[RenderGraph] pass '{}' prefers async compute but renders or is chained; keeping it on the graphics queue",
+                        pass.name()
+                    );
+                }
+                wants_async && compute_only
+            }).collect();
+            self.async_compute = plan_async_compute(&reads, &writes, &eligible);
+        }
+
         {
             let mut w_set: Vec<Vec<&str>> = Vec::with_capacity(self.passes.len());
             let mut r_set: Vec<Vec<&str>> = Vec::with_capacity(self.passes.len());
@@ -751,30 +1256,68 @@ impl RenderGraph {
                 p.declare_resources(&mut b);
                 for d in b.declarations() {
                     match d.access {
-                        crate::graph::ResourceAccess::Read => { if !r.contains(&d.name) { r.push(d.name); } }
-                        crate::graph::ResourceAccess::Write => { if !w.contains(&d.name) { w.push(d.name); } }
+                        crate::graph::ResourceAccess::Read => {
+                            if !r.contains(&d.name) {
+                                r.push(d.name);
+                            }
+                        }
+                        crate::graph::ResourceAccess::Write => {
+                            if !w.contains(&d.name) {
+                                w.push(d.name);
+                            }
+                        }
                     }
                 }
                 w_set.push(w);
                 r_set.push(r);
             }
-            eprintln!("[RenderGraph] {} passes, {} chain(s):", self.passes.len(), self.subpass_chains.len());
+            eprintln!(
+                "[RenderGraph] {} passes, {} chain(s):",
+                self.passes.len(),
+                self.subpass_chains.len()
+            );
             for i in 0..self.passes.len() {
                 let name = self.passes[i].name();
                 let is_chain_start = self.subpass_chains.iter().any(|c| c.start == i);
-                let is_chain_mid   = self.subpass_chains.iter().any(|c| i > c.start && i < c.end);
-                let marker = if is_chain_start { " ──chain──►" } else if is_chain_mid { " │         " } else { "           " };
-                let w_str = if w_set[i].is_empty() { "–".to_string() } else { w_set[i].join(",") };
-                let r_str = if r_set[i].is_empty() { "–".to_string() } else { r_set[i].join(",") };
+                let is_chain_mid = self.subpass_chains.iter().any(|c| i > c.start && i < c.end);
+                let marker = if is_chain_start {
+                    " ──chain──►"
+                } else if is_chain_mid {
+                    " │         "
+                } else {
+                    "           "
+                };
+                let w_str = if w_set[i].is_empty() {
+                    "–".to_string()
+                } else {
+                    w_set[i].join(",")
+                };
+                let r_str = if r_set[i].is_empty() {
+                    "–".to_string()
+                } else {
+                    r_set[i].join(",")
+                };
                 eprintln!("  {:>2}. {:<28} W: {}  R: {}", i, name, w_str, r_str);
                 if i + 1 < self.passes.len() {
                     let can_fuse = w_set[i].iter().any(|w| r_set[i + 1].contains(w));
-                    let is_fused = self.subpass_chains.iter().any(|c| c.contains(&i) && c.contains(&(i + 1)));
+                    let is_fused = self
+                        .subpass_chains
+                        .iter()
+                        .any(|c| c.contains(&i) && c.contains(&(i + 1)));
                     if is_fused && !can_fuse && self.passes[i + 1].chain_transparent() {
-                        eprintln!("  {:>2}.{:>2} CHAINED  (bridged over transparent pass '{}')", "", "", self.passes[i + 1].name());
+                        eprintln!(
+                            "  {:>2}.{:>2} CHAINED  (bridged over transparent pass '{}')",
+                            "",
+                            "",
+                            self.passes[i + 1].name()
+                        );
                     } else {
                         let why = if can_fuse {
-                            let common: Vec<&str> = w_set[i].iter().filter(|w| r_set[i + 1].contains(w)).copied().collect();
+                            let common: Vec<&str> = w_set[i]
+                                .iter()
+                                .filter(|w| r_set[i + 1].contains(w))
+                                .copied()
+                                .collect();
                             format!("fusable via {}", common.join(","))
                         } else {
                             let mut reasons = Vec::new();
@@ -797,6 +1340,20 @@ impl RenderGraph {
                 }
                 eprintln!("  {}", marker);
             }
+            for span in &self.async_compute {
+                eprintln!(
+                    "  async compute: {:<28} waits for {}, signals {}",
+                    self.passes[span.pass].name(),
+                    span.wait_for
+                        .map_or("frame start".to_string(), |j| self.passes[j]
+                            .name()
+                            .to_string()),
+                    span.signal_to
+                        .map_or("frame end".to_string(), |j| self.passes[j]
+                            .name()
+                            .to_string()),
+                );
+            }
         }
         self.locked = true;
     }
@@ -832,7 +1389,9 @@ impl RenderGraph {
         // Find the first pass whose chain membership diverged from the
         // previous frame.  Only passes at or after this index need
         // rebuilding because earlier passes' publishing is unchanged.
-        let first_dirty = self.prev_chain_membership.iter()
+        let first_dirty = self
+            .prev_chain_membership
+            .iter()
             .zip(&self.chain_membership)
             .position(|(old, new)| old != new);
 
@@ -840,7 +1399,9 @@ impl RenderGraph {
         // lengths (passes added or removed) the zipped scan won't detect it,
         // so fall back to the boundary at the shorter length.
         let start = first_dirty.unwrap_or_else(|| {
-            self.prev_chain_membership.len().min(self.chain_membership.len())
+            self.prev_chain_membership
+                .len()
+                .min(self.chain_membership.len())
         });
 
         if start == self.passes.len() {
@@ -869,10 +1430,43 @@ impl RenderGraph {
 
 // ── Standalone routing function ───────────────────────────────────────
 
-fn route_named_texture<'a>(name: &str, view: &'a wgpu::TextureView, frame: &mut libhelio::FrameResources<'a>) {
+/// Route a pass's graph-owned outputs into `frame`.
+fn apply_pre_pass_actions<'a>(
+    actions: &'a [PrePassAction],
+    frame: &mut libhelio::FrameResources<'a>,
+) {
+    for action in actions {
+        match action {
+            PrePassAction::Route { name, view } => route_named_texture(name, view, frame),
+            PrePassAction::Gbuffer {
+                albedo,
+                normal,
+                orm,
+                emissive,
+            } => {
+                frame.gbuffer.write(
+                    GBufferViews {
+                        albedo,
+                        normal,
+                        orm,
+                        emissive,
+                    },
+                    "Graph",
+                );
+            }
+        }
+    }
+}
+
+fn route_named_texture<'a>(
+    name: &str,
+    view: &'a wgpu::TextureView,
+    frame: &mut libhelio::FrameResources<'a>,
+) {
     match name {
         "pre_aa" => frame.pre_aa.write(view, "Graph"),
         "ssao" => frame.ssao.write(view, "Graph"),
+        "contact_shadows" => frame.contact_shadows.write(view, "Graph"),
         "fog_accum" => frame.fog_accum.write(view, "Graph"),
         "hiz" => frame.hiz.write(view, "Graph"),
         "sky_lut" => frame.sky_lut.write(view, "Graph"),
@@ -890,3 +1484,306 @@ fn route_named_texture<'a>(name: &str, view: &'a wgpu::TextureView, frame: &mut
         _ => {}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{FeatureTextureFallback, ResourceBuilder, ResourceSize};
+
+    fn device() -> Option<(
+        std::sync::Arc<wgpu::Device>,
+        wgpu::Queue,
+        wgpu::DownlevelFlags,
+    )> {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::new_without_display_handle());
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::default(),
+            compatible_surface: None,
+            force_fallback_adapter: false,
+            apply_limit_buckets: false,
+        }))
+        .ok()?;
+        let (device, queue) =
+            pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default())).ok()?;
+        Some((
+            std::sync::Arc::new(device),
+            queue,
+            adapter.get_downlevel_capabilities().flags,
+        ))
+    }
+
+    /// Stand-in for the shadow pass: writes a 1×1 `shadow_atlas`.
+    struct AtlasPass;
+
+    impl RenderPass for AtlasPass {
+        fn name(&self) -> &'static str {
+            "Shadow"
+        }
+        fn declare_resources(&self, builder: &mut ResourceBuilder) {
+            let size = ResourceSize::Absolute {
+                width: 1,
+                height: 1,
+            };
+            builder.write_color_raw("shadow_atlas", wgpu::TextureFormat::Depth32Float, size);
+            builder.with_extra_usage(wgpu::TextureUsages::COPY_SRC);
+        }
+        fn execute(&mut self, _ctx: &mut PassContext) -> Result<()> {
+            Ok(())
+        }
+        fn render_pass_descriptor<'a>(
+            &'a self,
+            _target: &'a wgpu::TextureView,
+            _depth: &'a wgpu::TextureView,
+            _resources: &'a libhelio::FrameResources<'a>,
+        ) -> Option<wgpu::RenderPassDescriptor<'a>> {
+            None
+        }
+    }
+
+    /// Reads the atlas and writes its own full-size target.
+    struct LightPass;
+
+    impl RenderPass for LightPass {
+        fn name(&self) -> &'static str {
+            "Light"
+        }
+        fn declare_resources(&self, builder: &mut ResourceBuilder) {
+            builder.read("shadow_atlas");
+            builder.write_color_raw(
+                "lit",
+                wgpu::TextureFormat::Rgba8Unorm,
+                ResourceSize::MatchSurface,
+            );
+        }
+        fn execute(&mut self, _ctx: &mut PassContext) -> Result<()> {
+            Ok(())
+        }
+        fn render_pass_descriptor<'a>(
+            &'a self,
+            _target: &'a wgpu::TextureView,
+            _depth: &'a wgpu::TextureView,
+            _resources: &'a libhelio::FrameResources<'a>,
+        ) -> Option<wgpu::RenderPassDescriptor<'a>> {
+            None
+        }
+    }
+
+    /// Writes a surface-sized, filterable `mask` for the feature texture tests.
+    struct MaskPass;
+
+    impl RenderPass for MaskPass {
+        fn name(&self) -> &'static str {
+            "Mask"
+        }
+        fn declare_resources(&self, builder: &mut ResourceBuilder) {
+            builder.write_color_raw(
+                "mask",
+                wgpu::TextureFormat::R8Unorm,
+                ResourceSize::MatchSurface,
+            );
+        }
+        fn execute(&mut self, _ctx: &mut PassContext) -> Result<()> {
+            Ok(())
+        }
+        fn render_pass_descriptor<'a>(
+            &'a self,
+            _target: &'a wgpu::TextureView,
+            _depth: &'a wgpu::TextureView,
+            _resources: &'a libhelio::FrameResources<'a>,
+        ) -> Option<wgpu::RenderPassDescriptor<'a>> {
+            None
+        }
+    }
+
+    fn clear_depth(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        view: &wgpu::TextureView,
+        depth: f32,
+    ) {
+        let mut encoder = device.create_command_encoder(&Default::default());
+        encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("atlas clear"),
+            color_attachments: &[],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view,
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(depth),
+                    store: wgpu::StoreOp::Store,
+                }),
+                stencil_ops: None,
+            }),
+            timestamp_writes: None,
+            occlusion_query_set: None,
+            multiview_mask: None,
+        });
+        queue.submit([encoder.finish()]);
+    }
+
+    fn read_depth(device: &wgpu::Device, queue: &wgpu::Queue, texture: &wgpu::Texture) -> f32 {
+        let readback = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("atlas readback"),
+            size: 256,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let mut encoder = device.create_command_encoder(&Default::default());
+        encoder.copy_texture_to_buffer(
+            wgpu::TexelCopyTextureInfo {
+                texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::DepthOnly,
+            },
+            wgpu::TexelCopyBufferInfo {
+                buffer: &readback,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(256),
+                    rows_per_image: None,
+                },
+            },
+            wgpu::Extent3d {
+                width: 1,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+        );
+        queue.submit([encoder.finish()]);
+        readback.slice(..).map_async(wgpu::MapMode::Read, |_| {});
+        device.poll(wgpu::PollType::wait_indefinitely()).unwrap();
+        let data = readback.slice(..).get_mapped_range().unwrap();
+        f32::from_le_bytes(data[..4].try_into().unwrap())
+    }
+
+    /// Pausing rebuilds the graph but hands the paused pass's outputs over
+    /// untouched: same texture object, same contents.
+    #[test]
+    fn pausing_keeps_paused_outputs() {
+        let Some((device, queue, downlevel)) = device() else {
+            eprintln!("skipping: no GPU adapter");
+            return;
+        };
+        let mut graph = RenderGraph::new(&device, &queue);
+        graph.add_pass(Box::new(AtlasPass));
+        graph.add_pass(Box::new(LightPass));
+        graph.lock(8, 8);
+
+        let atlas = graph.pool.get_texture("shadow_atlas").unwrap().clone();
+        let lit = graph.pool.get_texture("lit").unwrap().clone();
+        clear_depth(
+            &device,
+            &queue,
+            graph.pool.get_view("shadow_atlas").unwrap(),
+            0.25,
+        );
+
+        graph.set_pass_active("Shadow", false).unwrap();
+        assert_eq!(graph.pool.get_texture("shadow_atlas"), Some(&atlas));
+        assert_ne!(
+            graph.pool.get_texture("lit"),
+            Some(&lit),
+            "unpaused outputs are reallocated"
+        );
+        if downlevel.contains(wgpu::DownlevelFlags::DEPTH_TEXTURE_AND_BUFFER_COPIES) {
+            assert_eq!(read_depth(&device, &queue, &atlas), 0.25);
+        } else {
+            eprintln!("skipping contents check: no depth readback on this adapter");
+        }
+
+        // Further rebuilds while paused keep it too.
+        graph.reallocate_resources();
+        assert_eq!(graph.pool.get_texture("shadow_atlas"), Some(&atlas));
+
+        graph.set_pass_active("Shadow", true).unwrap();
+        assert_ne!(graph.pool.get_texture("shadow_atlas"), Some(&atlas));
+    }
+
+    #[test]
+    fn feature_texture_registration_is_stable_and_bounded() {
+        let Some((device, queue, _)) = device() else {
+            eprintln!("skipping: no GPU adapter");
+            return;
+        };
+        let mut graph = RenderGraph::new(&device, &queue);
+        let textures = graph.feature_textures_mut();
+        assert_eq!(
+            textures
+                .register("mask", FeatureTextureFallback::White)
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            textures
+                .register("shadow_atlas", FeatureTextureFallback::Black)
+                .unwrap(),
+            1
+        );
+        // A second registration (e.g. producer and consumer both registering)
+        // returns the existing slot.
+        assert_eq!(
+            textures
+                .register("mask", FeatureTextureFallback::White)
+                .unwrap(),
+            0
+        );
+        assert_eq!(textures.slot("shadow_atlas"), Some(1));
+        assert_eq!(textures.slot("unknown"), None);
+        assert_eq!(
+            textures.names().collect::<Vec<_>>(),
+            ["mask", "shadow_atlas"]
+        );
+
+        const EXTRA: [&str; 6] = ["f2", "f3", "f4", "f5", "f6", "f7"];
+        for name in EXTRA {
+            textures
+                .register(name, FeatureTextureFallback::Black)
+                .unwrap();
+        }
+        assert!(textures
+            .register("overflow", FeatureTextureFallback::Black)
+            .is_err());
+        assert_eq!(textures.slot("overflow"), None);
+    }
+
+    /// Registered slots follow the pool across reallocation, and slots whose
+    /// texture is missing or unbindable read their fallback.
+    #[test]
+    fn feature_textures_rebind_after_reallocation() {
+        let Some((device, queue, _)) = device() else {
+            eprintln!("skipping: no GPU adapter");
+            return;
+        };
+        let mut graph = RenderGraph::new(&device, &queue);
+        let textures = graph.feature_textures_mut();
+        textures
+            .register("mask", FeatureTextureFallback::White)
+            .unwrap();
+        textures
+            .register("shadow_atlas", FeatureTextureFallback::Black)
+            .unwrap();
+        textures
+            .register("missing", FeatureTextureFallback::Black)
+            .unwrap();
+        graph.add_pass(Box::new(MaskPass));
+        graph.add_pass(Box::new(AtlasPass));
+        graph.add_pass(Box::new(LightPass));
+        graph.lock(8, 8);
+
+        let mask = graph.pool.get_texture("mask").unwrap().clone();
+        assert_eq!(graph.feature_textures().bound_texture("mask"), Some(&mask));
+        // Depth textures cannot fill a filterable float slot.
+        assert_eq!(graph.feature_textures().bound_texture("shadow_atlas"), None);
+        assert_eq!(graph.feature_textures().bound_texture("missing"), None);
+        let _ = graph.feature_textures().bind_group();
+
+        graph.set_render_size(16, 16);
+        let resized = graph.pool.get_texture("mask").unwrap();
+        assert_eq!(resized.width(), 16);
+        assert_ne!(resized, &mask);
+        assert_eq!(
+            graph.feature_textures().bound_texture("mask"),
+            Some(resized)
+        );
+    }
+}
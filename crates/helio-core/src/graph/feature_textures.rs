@@ -0,0 +1,237 @@
+//! Shared bind group for textures produced by one pass and sampled by others.
+//!
+//! Compute pre-passes such as SSAO or GI produce a screen-space texture that
+//! the lighting shader samples. Rather than each producer exporting its own
+//! bind group (and each consumer reserving a bind group index for it),
+//! producers register their output name here and the graph binds every
+//! registered texture into one bind group with fixed binding slots:
+//!
+//! | Binding                            | Resource                                   |
+//! |------------------------------------|--------------------------------------------|
+//! | `0 .. FEATURE_TEXTURE_SLOTS`       | `texture_2d<f32>`, one per registered name |
+//! | `FEATURE_TEXTURE_SLOTS`            | linear clamp-to-edge `sampler`             |
+//!
+//! Ordering still comes from the regular resource declarations: the producer
+//! `write_color`s the texture and consumers `read` it in
+//! [`declare_resources`](crate::RenderPass::declare_resources). A slot whose
+//! texture the graph did not allocate, or whose texture cannot be bound as a
+//! filterable single-layer 2D texture, is bound to its fallback instead, so
+//! consumers can sample unconditionally.
+//!
+//! ```rust,ignore
+//! // Graph construction (producer or builder):
+//! let ao_slot = graph.feature_textures_mut().register("ssao", FeatureTextureFallback::White)?;
+//!
+//! // Consumer shader; the pass rewrites the constant to the slot returned above:
+//! // const AO_SLOT: u32 = 0u;
+//! // @group(3) @binding(AO_SLOT) var ao_tex: texture_2d<f32>;
+//! // @group(3) @binding(8) var feature_samp: sampler;
+//!
+//! // Consumer execute():
+//! rpass.set_bind_group(3, ctx.feature_textures.bind_group(), &[]);
+//! ```
+
+use crate::graph::GraphTexturePool;
+use crate::{Error, Result};
+
+/// Number of texture bindings in the shared bind group. The linear sampler
+/// sits at binding `FEATURE_TEXTURE_SLOTS`.
+pub const FEATURE_TEXTURE_SLOTS: u32 = 8;
+
+/// Value a slot reads when its texture is unavailable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeatureTextureFallback {
+    /// `(0, 0, 0, 0)`: additive contributions (GI, reflections) vanish.
+    Black,
+    /// `(1, 1, 1, 1)`: multiplicative terms (ambient occlusion) become neutral.
+    White,
+}
+
+/// Graph-owned registry of named feature textures and their bind group.
+pub struct FeatureTextures {
+    layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+    black: wgpu::TextureView,
+    white: wgpu::TextureView,
+    slots: Vec<(&'static str, FeatureTextureFallback)>,
+    /// Pool texture bound to each slot by the last rebuild (`None` = fallback).
+    bound: Vec<Option<wgpu::Texture>>,
+    bind_group: Option<wgpu::BindGroup>,
+}
+
+impl FeatureTextures {
+    pub(crate) fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        let mut entries: Vec<wgpu::BindGroupLayoutEntry> = (0..FEATURE_TEXTURE_SLOTS)
+            .map(|binding| wgpu::BindGroupLayoutEntry {
+                binding,
+                visibility: wgpu::ShaderStages::FRAGMENT | wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            })
+            .collect();
+        entries.push(wgpu::BindGroupLayoutEntry {
+            binding: FEATURE_TEXTURE_SLOTS,
+            visibility: wgpu::ShaderStages::FRAGMENT | wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+            count: None,
+        });
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Feature Textures BGL"),
+            entries: &entries,
+        });
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Feature Textures Sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            ..Default::default()
+        });
+        Self {
+            layout,
+            sampler,
+            black: solid_texture(device, queue, "Feature Texture Fallback (Black)", [0; 4]),
+            white: solid_texture(device, queue, "Feature Texture Fallback (White)", [255; 4]),
+            slots: Vec::new(),
+            bound: Vec::new(),
+            bind_group: None,
+        }
+    }
+
+    /// Layout of the shared bind group (see the module docs for bindings).
+    pub fn layout(&self) -> &wgpu::BindGroupLayout {
+        &self.layout
+    }
+
+    /// Assign a binding slot to the graph texture `name`.
+    ///
+    /// Registering a name that already has a slot returns the existing slot,
+    /// so producer and consumer can both register without coordinating.
+    /// Takes effect the next time the graph allocates its textures (on
+    /// `lock()` or a resize).
+    pub fn register(&mut self, name: &'static str, fallback: FeatureTextureFallback) -> Result<u32> {
+        if let Some(slot) = self.slot(name) {
+            return Ok(slot);
+        }
+        if self.slots.len() as u32 >= FEATURE_TEXTURE_SLOTS {
+            return Err(Error::InvalidPassConfig(format!(
+                "cannot register feature texture '{name}': all {FEATURE_TEXTURE_SLOTS} slots are taken"
+            )));
+        }
+        self.slots.push((name, fallback));
+        Ok(self.slots.len() as u32 - 1)
+    }
+
+    /// Binding slot of `name`, if registered.
+    pub fn slot(&self, name: &str) -> Option<u32> {
+        self.slots.iter().position(|(n, _)| *n == name).map(|i| i as u32)
+    }
+
+    /// Registered texture names, in slot order.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.slots.iter().map(|(name, _)| *name)
+    }
+
+    /// Graph texture currently bound for `name`, or `None` if the slot reads
+    /// its fallback (or `name` is not registered).
+    pub fn bound_texture(&self, name: &str) -> Option<&wgpu::Texture> {
+        self.slot(name).and_then(|slot| self.bound.get(slot as usize)?.as_ref())
+    }
+
+    /// The shared bind group.
+    ///
+    /// # Panics
+    ///
+    /// Before the graph has allocated its textures.
+    pub fn bind_group(&self) -> &wgpu::BindGroup {
+        self.bind_group
+            .as_ref()
+            .expect("feature texture bind group requested before the graph allocated textures")
+    }
+
+    /// Rebind every slot against the current texture pool.
+    pub(crate) fn rebuild(&mut self, device: &wgpu::Device, pool: &GraphTexturePool) {
+        self.bound = self
+            .slots
+            .iter()
+            .map(|(name, _)| {
+                pool.get_texture(name)
+                    .filter(|tex| bindable(tex) && pool.get_view(name).is_some())
+                    .cloned()
+            })
+            .collect();
+        let views: Vec<&wgpu::TextureView> = (0..FEATURE_TEXTURE_SLOTS as usize)
+            .map(|i| match self.slots.get(i) {
+                Some((name, fallback)) if self.bound[i].is_some() => {
+                    pool.get_view(name).unwrap_or(self.fallback_view(*fallback))
+                }
+                Some((_, fallback)) => self.fallback_view(*fallback),
+                None => &self.black,
+            })
+            .collect();
+        let mut entries: Vec<wgpu::BindGroupEntry> = views
+            .iter()
+            .enumerate()
+            .map(|(i, view)| wgpu::BindGroupEntry {
+                binding: i as u32,
+                resource: wgpu::BindingResource::TextureView(view),
+            })
+            .collect();
+        entries.push(wgpu::BindGroupEntry {
+            binding: FEATURE_TEXTURE_SLOTS,
+            resource: wgpu::BindingResource::Sampler(&self.sampler),
+        });
+        self.bind_group = Some(device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Feature Textures BG"),
+            layout: &self.layout,
+            entries: &entries,
+        }));
+    }
+
+    fn fallback_view(&self, fallback: FeatureTextureFallback) -> &wgpu::TextureView {
+        match fallback {
+            FeatureTextureFallback::Black => &self.black,
+            FeatureTextureFallback::White => &self.white,
+        }
+    }
+}
+
+/// Whether `tex` can be bound to a filterable `texture_2d<f32>` slot.
+fn bindable(tex: &wgpu::Texture) -> bool {
+    tex.dimension() == wgpu::TextureDimension::D2
+        && tex.depth_or_array_layers() == 1
+        && tex.sample_count() == 1
+        && tex.usage().contains(wgpu::TextureUsages::TEXTURE_BINDING)
+        && tex.format().sample_type(None, None)
+            == Some(wgpu::TextureSampleType::Float { filterable: true })
+}
+
+fn solid_texture(device: &wgpu::Device, queue: &wgpu::Queue, label: &str, rgba: [u8; 4]) -> wgpu::TextureView {
+    let size = wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 1 };
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some(label),
+        size,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        view_formats: &[],
+    });
+    queue.write_texture(
+        wgpu::TexelCopyTextureInfo {
+            texture: &texture,
+            mip_level: 0,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::All,
+        },
+        &rgba,
+        wgpu::TexelCopyBufferLayout { offset: 0, bytes_per_row: Some(4), rows_per_image: None },
+        size,
+    );
+    texture.create_view(&wgpu::TextureViewDescriptor::default())
+}
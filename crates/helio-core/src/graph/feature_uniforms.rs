@@ -0,0 +1,216 @@
+//! Shared per-pass uniform storage.
+//!
+//! Most passes only need a small block of parameters (intensities, radii,
+//! toggles) and would otherwise each create a uniform buffer, a bind group
+//! layout and a bind group for it. [`FeatureUniforms`] replaces that
+//! boilerplate with one graph-owned dynamic uniform buffer: each pass
+//! allocates a [`FeatureUniformSlot`] when it is constructed, writes its
+//! parameters into the slot in `prepare()`, and binds the shared bind group
+//! with the slot's dynamic offset in `execute()`.
+//!
+//! ```rust,ignore
+//! // Construction: reserve a slot and build the pipeline layout against the
+//! // shared layout.
+//! let slot = graph.feature_uniforms_mut().allocate::<FogParams>("Fog")?;
+//! let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+//!     bind_group_layouts: &[Some(&own_bgl), Some(graph.feature_uniforms().layout())],
+//!     ..
+//! });
+//!
+//! // prepare():
+//! ctx.feature_uniforms.write(ctx.queue, self.slot, &self.params);
+//!
+//! // execute():
+//! rpass.set_bind_group(1, ctx.feature_uniforms.bind_group(), &[self.slot.offset()]);
+//! ```
+//!
+//! In WGSL the slot is an ordinary uniform binding:
+//! `@group(1) @binding(0) var<uniform> params: FogParams;`.
+
+use crate::{Error, Result};
+
+/// Largest parameter block a single slot can hold, in bytes. This is also the
+/// binding size seen by the shader.
+pub const FEATURE_UNIFORM_SLOT_SIZE: u64 = 256;
+
+/// A pass's region of the shared feature uniform buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeatureUniformSlot {
+    offset: u32,
+    size: u32,
+}
+
+impl FeatureUniformSlot {
+    /// Byte offset into the shared buffer; pass it as the dynamic offset
+    /// when binding [`FeatureUniforms::bind_group`].
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Size in bytes requested at allocation.
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+}
+
+/// Graph-owned dynamic uniform buffer split into per-pass slots.
+///
+/// Slots are never freed; allocate them once while building the graph. The
+/// GPU buffer is (re)created when the graph is locked or, if slots were added
+/// afterwards, at the start of the next frame — passes recorded into render
+/// bundles must allocate before [`RenderGraph::lock`](super::RenderGraph::lock)
+/// so their bundles capture the final bind group.
+pub struct FeatureUniforms {
+    layout: wgpu::BindGroupLayout,
+    stride: u64,
+    slots: Vec<(&'static str, FeatureUniformSlot)>,
+    buffer: Option<wgpu::Buffer>,
+    bind_group: Option<wgpu::BindGroup>,
+}
+
+impl FeatureUniforms {
+    pub(crate) fn new(device: &wgpu::Device) -> Self {
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Feature Uniforms BGL"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX_FRAGMENT | wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: true,
+                    min_binding_size: wgpu::BufferSize::new(FEATURE_UNIFORM_SLOT_SIZE),
+                },
+                count: None,
+            }],
+        });
+        let alignment = device.limits().min_uniform_buffer_offset_alignment as u64;
+        Self {
+            layout,
+            stride: slot_stride(alignment),
+            slots: Vec::new(),
+            buffer: None,
+            bind_group: None,
+        }
+    }
+
+    /// Layout of the shared bind group: a single dynamic-offset uniform at
+    /// binding 0, visible to all stages.
+    pub fn layout(&self) -> &wgpu::BindGroupLayout {
+        &self.layout
+    }
+
+    /// Reserve a slot big enough for `T`.
+    pub fn allocate<T: bytemuck::Pod>(&mut self, label: &'static str) -> Result<FeatureUniformSlot> {
+        self.allocate_bytes(label, std::mem::size_of::<T>() as u64)
+    }
+
+    /// Reserve a slot of `size` bytes. Fails if `size` exceeds
+    /// [`FEATURE_UNIFORM_SLOT_SIZE`].
+    pub fn allocate_bytes(&mut self, label: &'static str, size: u64) -> Result<FeatureUniformSlot> {
+        if size == 0 || size > FEATURE_UNIFORM_SLOT_SIZE {
+            return Err(Error::InvalidPassConfig(format!(
+                "feature uniforms for '{label}' are {size} bytes; slots hold 1..={FEATURE_UNIFORM_SLOT_SIZE}"
+            )));
+        }
+        let slot = FeatureUniformSlot {
+            offset: (self.slots.len() as u64 * self.stride) as u32,
+            size: size as u32,
+        };
+        self.slots.push((label, slot));
+        Ok(slot)
+    }
+
+    /// Number of allocated slots.
+    pub fn slot_count(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Upload `value` into `slot`.
+    pub fn write<T: bytemuck::Pod>(&self, queue: &wgpu::Queue, slot: FeatureUniformSlot, value: &T) {
+        self.write_bytes(queue, slot, bytemuck::bytes_of(value));
+    }
+
+    /// Upload raw bytes into `slot`.
+    pub fn write_bytes(&self, queue: &wgpu::Queue, slot: FeatureUniformSlot, data: &[u8]) {
+        debug_assert!(
+            data.len() <= slot.size as usize,
+            "{} bytes written to a {}-byte feature uniform slot",
+            data.len(),
+            slot.size
+        );
+        if let Some(buffer) = &self.buffer {
+            crate::upload::write_buffer(queue, buffer, slot.offset as u64, data);
+        }
+    }
+
+    /// The shared bind group. Bind it with a slot's
+    /// [`offset`](FeatureUniformSlot::offset) as the dynamic offset.
+    ///
+    /// # Panics
+    ///
+    /// If no slot has been allocated, or before the graph is locked.
+    pub fn bind_group(&self) -> &wgpu::BindGroup {
+        self.bind_group
+            .as_ref()
+            .expect("feature uniform bind group requested before any slot was allocated")
+    }
+
+    /// Create or grow the GPU buffer so it covers every allocated slot.
+    pub(crate) fn ensure_buffer(&mut self, device: &wgpu::Device) {
+        let needed = buffer_size(self.slots.len(), self.stride);
+        let current = self.buffer.as_ref().map_or(0, |b| b.size());
+        if needed == 0 || needed <= current {
+            return;
+        }
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Feature Uniforms"),
+            size: needed,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Feature Uniforms BG"),
+            layout: &self.layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                    buffer: &buffer,
+                    offset: 0,
+                    size: wgpu::BufferSize::new(FEATURE_UNIFORM_SLOT_SIZE),
+                }),
+            }],
+        });
+        self.buffer = Some(buffer);
+        self.bind_group = Some(bind_group);
+    }
+}
+
+/// Distance between consecutive slots: the slot size rounded up to the
+/// device's dynamic-offset alignment.
+fn slot_stride(alignment: u64) -> u64 {
+    FEATURE_UNIFORM_SLOT_SIZE.div_ceil(alignment.max(1)) * alignment.max(1)
+}
+
+fn buffer_size(slots: usize, stride: u64) -> u64 {
+    slots as u64 * stride
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stride_respects_alignment() {
+        assert_eq!(slot_stride(256), 256);
+        assert_eq!(slot_stride(64), 256);
+        assert_eq!(slot_stride(512), 512);
+    }
+
+    #[test]
+    fn buffer_covers_last_slot_binding() {
+        let stride = slot_stride(256);
+        let last_offset = 2 * stride;
+        assert!(buffer_size(3, stride) >= last_offset + FEATURE_UNIFORM_SLOT_SIZE);
+        assert_eq!(buffer_size(0, stride), 0);
+    }
+}
@@ -0,0 +1,97 @@
+//! Frames in flight: how far the CPU may run ahead of the GPU.
+//!
+//! [`RenderGraph::execute`](super::RenderGraph::execute) submits and returns
+//! without waiting for that frame, so the CPU can record frame N+1 while the
+//! GPU still works on frame N. After submitting frame N the graph blocks only
+//! until frame N + 1 - `frames_in_flight` has finished, so at most
+//! `frames_in_flight` frames are ever unfinished. `1` serialises CPU and GPU;
+//! `2` (the default) is double buffering; `3` is the maximum.
+//!
+//! The wait happens only when the graph owns the device. With an external
+//! device (`new_with_external_device`) the host's event loop does all
+//! polling and is responsible for pacing. Presenting also throttles: the
+//! surface's `desired_maximum_frame_latency` should match
+//! `frames_in_flight`, or the smaller of the two wins.
+//!
+//! # Contract for passes
+//!
+//! How many copies of per-frame data a pass keeps depends on how it writes
+//! the data:
+//!
+//! - **`ctx.write_buffer` / `queue.write_buffer` / `write_texture`**: one
+//!   copy. These writes are staged and ordered on the queue timeline, so a
+//!   write for frame N+1 never lands while frame N's commands still read the
+//!   old contents.
+//! - **GPU-written history** (TAA history, shadow caches): one copy, or two
+//!   for ping-pong within a frame. Commands on one queue execute in order.
+//! - **CPU-mapped buffers** (readbacks through `map_async`, staging rings
+//!   written through a mapped range): `frames_in_flight` copies, indexed by
+//!   [`PrepareContext::frame_slot`](crate::PrepareContext::frame_slot). A
+//!   buffer mapped for frame N is still in use until that frame completes,
+//!   which can be up to `frames_in_flight - 1` frames later.
+//!
+//! GPU timestamp profiling reads its queries back with a blocking wait each
+//! frame, so CPU and GPU do not overlap while the profiler is enabled.
+
+use std::collections::VecDeque;
+
+/// Default number of frames in flight (double buffering).
+pub const DEFAULT_FRAMES_IN_FLIGHT: u32 = 2;
+/// Upper bound accepted by [`RenderGraph::set_frames_in_flight`](super::RenderGraph::set_frames_in_flight).
+pub const MAX_FRAMES_IN_FLIGHT: u32 = 3;
+
+/// Submissions that have not been waited on yet, oldest first.
+pub(crate) struct InFlight<T> {
+    pending: VecDeque<T>,
+}
+
+impl<T> InFlight<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            pending: VecDeque::with_capacity(MAX_FRAMES_IN_FLIGHT as usize),
+        }
+    }
+
+    /// Record the frame just submitted. Returns the submission to wait for
+    /// so that at most `limit - 1` frames stay pending while the CPU records
+    /// the next one. Waiting for it covers every older submission too.
+    pub(crate) fn push(&mut self, submission: T, limit: u32) -> Option<T> {
+        self.pending.push_back(submission);
+        let keep = limit.max(1) as usize - 1;
+        let mut wait_for = None;
+        while self.pending.len() > keep {
+            wait_for = self.pending.pop_front();
+        }
+        wait_for
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn double_buffering_waits_for_the_previous_frame() {
+        let mut in_flight = InFlight::new();
+        assert_eq!(in_flight.push(0, 2), None);
+        assert_eq!(in_flight.push(1, 2), Some(0));
+        assert_eq!(in_flight.push(2, 2), Some(1));
+    }
+
+    #[test]
+    fn one_frame_in_flight_waits_for_the_frame_itself() {
+        let mut in_flight = InFlight::new();
+        assert_eq!(in_flight.push(0, 1), Some(0));
+        // A limit of zero is treated as one.
+        assert_eq!(in_flight.push(1, 0), Some(1));
+    }
+
+    #[test]
+    fn lowering_the_limit_waits_for_the_newest_excess_frame() {
+        let mut in_flight = InFlight::new();
+        assert_eq!(in_flight.push(0, 3), None);
+        assert_eq!(in_flight.push(1, 3), None);
+        assert_eq!(in_flight.push(2, 2), Some(1));
+        assert_eq!(in_flight.push(3, 2), Some(2));
+    }
+}
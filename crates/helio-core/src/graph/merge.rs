@@ -0,0 +1,90 @@
+//! Combining render graphs built by different crates.
+//!
+//! A plugin crate can build its own [`RenderGraph`](super::RenderGraph) of
+//! passes and hand it to the application, which folds it into the base graph
+//! with [`RenderGraph::merge`](super::RenderGraph::merge). Passes are matched
+//! by [`RenderPass::name`](crate::RenderPass::name); [`MergePolicy`] decides
+//! what happens when an incoming name is already taken.
+
+use crate::{Error, Result};
+
+/// Collision policy for [`RenderGraph::merge_with`](super::RenderGraph::merge_with).
+///
+/// A collision is an incoming pass whose name matches a pass already in the
+/// destination graph. Duplicate names *within* either graph are left alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergePolicy {
+    /// Reject the merge on the first collision, leaving both graphs untouched.
+    #[default]
+    Error,
+    /// The incoming pass replaces the existing one at the existing pass's
+    /// position, so execution order of the base graph is preserved.
+    LastWins,
+    /// Keep the existing pass and drop the incoming one.
+    FirstWins,
+}
+
+/// What to do with one incoming pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MergeAction {
+    Append,
+    Replace(usize),
+    Skip,
+}
+
+/// Resolve every incoming pass against the existing pass names.
+///
+/// Non-colliding passes are appended in their original order after the
+/// existing ones. Fails before anything is applied, so an `Err` leaves the
+/// graph unchanged.
+pub(crate) fn plan_merge(
+    existing: &[&str],
+    incoming: &[&str],
+    policy: MergePolicy,
+) -> Result<Vec<MergeAction>> {
+    incoming
+        .iter()
+        .map(|name| match existing.iter().position(|e| e == name) {
+            None => Ok(MergeAction::Append),
+            Some(index) => match policy {
+                MergePolicy::Error => Err(Error::InvalidPassConfig(format!(
+                    "cannot merge render graphs: pass '{name}' already exists at index {index}"
+                ))),
+                MergePolicy::LastWins => Ok(MergeAction::Replace(index)),
+                MergePolicy::FirstWins => Ok(MergeAction::Skip),
+            },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: &[&str] = &["GBuffer", "DeferredLight", "PostProcess"];
+
+    #[test]
+    fn disjoint_passes_append() {
+        let plan = plan_merge(BASE, &["Outline", "Fog"], MergePolicy::Error).unwrap();
+        assert_eq!(plan, vec![MergeAction::Append, MergeAction::Append]);
+    }
+
+    #[test]
+    fn collision_errors_by_default() {
+        let err = plan_merge(BASE, &["Fog", "PostProcess"], MergePolicy::default()).unwrap_err();
+        assert!(err.to_string().contains("'PostProcess'"));
+    }
+
+    #[test]
+    fn collision_policies() {
+        let incoming = ["DeferredLight", "Fog"];
+        assert_eq!(
+            plan_merge(BASE, &incoming, MergePolicy::LastWins).unwrap(),
+            vec![MergeAction::Replace(1), MergeAction::Append]
+        );
+        assert_eq!(
+            plan_merge(BASE, &incoming, MergePolicy::FirstWins).unwrap(),
+            vec![MergeAction::Skip, MergeAction::Append]
+        );
+    }
+}
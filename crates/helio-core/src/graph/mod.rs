@@ -1,12 +1,25 @@
+mod async_compute;
 mod barriers;
+mod dot;
 mod execution;
 mod executor;
+mod feature_textures;
+mod feature_uniforms;
+mod frames_in_flight;
+mod merge;
+mod reorder;
 mod resource;
 mod resource_lifetime;
 mod scheduling;
 
+pub use async_compute::AsyncComputeSpan;
 pub use executor::{DebugPassInfo, DebugResourceInfo, FrameDebugData, RenderGraph};
+pub use feature_textures::{FeatureTextureFallback, FeatureTextures, FEATURE_TEXTURE_SLOTS};
+pub use feature_uniforms::{FeatureUniformSlot, FeatureUniforms, FEATURE_UNIFORM_SLOT_SIZE};
+pub use frames_in_flight::{DEFAULT_FRAMES_IN_FLIGHT, MAX_FRAMES_IN_FLIGHT};
+pub use merge::MergePolicy;
 pub use resource::{
-    GraphTexture, GraphTexturePool, ResSize, ResourceAccess, ResourceAllocator, ResourceBuilder,
-    ResourceDecl, ResourceFormat, ResourceHandle, ResourceSize, TextureDescriptor,
+    texture_memory_bytes, GraphTexture, GraphTexturePool, ResSize, ResourceAccess, ResourceAllocator,
+    ResourceBuilder, ResourceDecl, ResourceFormat, ResourceHandle, ResourceSize, TextureDescriptor,
+    TextureLifetime,
 };
@@ -0,0 +1,101 @@
+//! Reordering the passes of a built render graph.
+//!
+//! Pass order is execution order, and it also decides which pass wins when
+//! two passes publish the same frame resource. Graphs are assembled by
+//! appending passes, so [`RenderGraph::move_pass`](super::RenderGraph::move_pass)
+//! and [`RenderGraph::set_pass_order`](super::RenderGraph::set_pass_order)
+//! let an application (or an editor UI) fix the order afterwards without
+//! rebuilding the graph. Passes are matched by
+//! [`RenderPass::name`](crate::RenderPass::name); with duplicate names the
+//! first pass is meant.
+//!
+//! Both operations are planned as a permutation of pass indices first, so an
+//! `Err` leaves the graph unchanged.
+
+use crate::{Error, Result};
+
+/// New order (as old indices) after moving pass `name` to `new_index`.
+pub(crate) fn plan_move(names: &[&str], name: &str, new_index: usize) -> Result<Vec<usize>> {
+    let from = find(names, name)?;
+    if new_index >= names.len() {
+        return Err(Error::InvalidPassConfig(format!(
+            "cannot move pass '{name}' to index {new_index}: the graph has {} passes",
+            names.len()
+        )));
+    }
+    let mut order: Vec<usize> = (0..names.len()).collect();
+    let index = order.remove(from);
+    order.insert(new_index, index);
+    Ok(order)
+}
+
+/// New order (as old indices) that puts the passes listed in `order` in that
+/// relative order.
+///
+/// Listed passes take over the positions the listed passes occupied before,
+/// and unlisted passes keep theirs, so a partial list only permutes the
+/// passes it names. Listing every pass sets the full order.
+pub(crate) fn plan_order(names: &[&str], order: &[&str]) -> Result<Vec<usize>> {
+    let mut listed = Vec::with_capacity(order.len());
+    for name in order {
+        let index = find(names, name)?;
+        if listed.contains(&index) {
+            return Err(Error::InvalidPassConfig(format!(
+                "pass '{name}' is listed more than once in the pass order"
+            )));
+        }
+        listed.push(index);
+    }
+    let mut slots = listed.clone();
+    slots.sort_unstable();
+
+    let mut permutation: Vec<usize> = (0..names.len()).collect();
+    for (slot, index) in slots.into_iter().zip(listed) {
+        permutation[slot] = index;
+    }
+    Ok(permutation)
+}
+
+fn find(names: &[&str], name: &str) -> Result<usize> {
+    names
+        .iter()
+        .position(|n| *n == name)
+        .ok_or_else(|| Error::InvalidPassConfig(format!("no pass named '{name}' in the render graph")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: &[&str] = &["Shadow", "GBuffer", "DeferredLight", "Fog", "PostProcess"];
+
+    #[test]
+    fn move_forward_and_back() {
+        assert_eq!(plan_move(BASE, "Fog", 1).unwrap(), vec![0, 3, 1, 2, 4]);
+        assert_eq!(plan_move(BASE, "Shadow", 4).unwrap(), vec![1, 2, 3, 4, 0]);
+        assert_eq!(plan_move(BASE, "GBuffer", 1).unwrap(), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn move_rejects_unknown_pass_and_bad_index() {
+        assert!(plan_move(BASE, "Bloom", 0).unwrap_err().to_string().contains("'Bloom'"));
+        assert!(plan_move(BASE, "Fog", 5).is_err());
+    }
+
+    #[test]
+    fn partial_order_keeps_unlisted_positions() {
+        // Fog and DeferredLight swap; everything else stays put.
+        assert_eq!(plan_order(BASE, &["Fog", "DeferredLight"]).unwrap(), vec![0, 1, 3, 2, 4]);
+        // Listed passes fill slots 0, 2 and 4 in the given order.
+        assert_eq!(
+            plan_order(BASE, &["PostProcess", "Shadow", "DeferredLight"]).unwrap(),
+            vec![4, 1, 0, 3, 2]
+        );
+    }
+
+    #[test]
+    fn order_validates_every_name_first() {
+        assert!(plan_order(BASE, &["Fog", "Bloom"]).is_err());
+        assert!(plan_order(BASE, &["Fog", "Fog"]).unwrap_err().to_string().contains("more than once"));
+    }
+}
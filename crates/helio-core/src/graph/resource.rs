@@ -208,7 +208,42 @@ pub struct TextureDescriptor {
     pub alias_group: Option<String>,
 }
 
+/// A graph texture and the span of passes that use it, input to
+/// [`GraphTexturePool::plan_aliases`].
+#[derive(Debug, Clone)]
+pub struct TextureLifetime {
+    pub desc: TextureDescriptor,
+    /// Index of the pass that first writes the texture.
+    pub first_use: usize,
+    /// Index of the last pass that reads it (`first_use` when none does).
+    pub last_use: usize,
+}
+
+impl TextureLifetime {
+    /// Whether `self` may share an allocation with `other`: same alias group
+    /// and an identical texture description apart from the name.
+    fn shares_storage_with(&self, other: &TextureLifetime) -> bool {
+        let (a, b) = (&self.desc, &other.desc);
+        a.alias_group.is_some() && a.alias_group == b.alias_group && a.same_storage(b)
+    }
+}
+
+impl TextureDescriptor {
+    /// Whether a texture created from `self` could back `other`: identical
+    /// apart from the name and alias group.
+    pub fn same_storage(&self, other: &TextureDescriptor) -> bool {
+        self.format == other.format
+            && self.width == other.width
+            && self.height == other.height
+            && self.depth_or_array_layers == other.depth_or_array_layers
+            && self.mip_level_count == other.mip_level_count
+            && self.sample_count == other.sample_count
+            && self.usage == other.usage
+    }
+}
+
 /// A texture allocation owned by the graph.
+#[derive(Clone)]
 pub struct GraphTexture {
     pub texture: wgpu::Texture,
     pub view: wgpu::TextureView,
@@ -240,6 +275,79 @@ impl GraphTexturePool {
         device: &wgpu::Device,
         desc: TextureDescriptor,
     ) -> &GraphTexture {
+        let idx = self.textures.len();
+        self.textures.push(Self::create(device, &desc));
+        self.name_map.insert(desc.name.clone(), idx);
+
+        if let Some(group) = &desc.alias_group {
+            *self.alias_refs.entry(group.clone()).or_insert(0) += 1;
+        }
+
+        &self.textures[idx]
+    }
+
+    /// Assign each lifetime a physical texture slot, greedily in pass order.
+    ///
+    /// Textures in the same alias group with identical descriptions share a
+    /// slot when their `[first_use, last_use]` spans do not overlap; textures
+    /// without an alias group always get their own. Returns one slot index
+    /// per entry of `lifetimes`, numbered from 0.
+    pub fn plan_aliases(lifetimes: &[TextureLifetime]) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..lifetimes.len()).collect();
+        order.sort_by(|&a, &b| {
+            (lifetimes[a].first_use, &lifetimes[a].desc.name)
+                .cmp(&(lifetimes[b].first_use, &lifetimes[b].desc.name))
+        });
+
+        // Per slot: the lifetime that created it and the last pass using it.
+        let mut slots: Vec<(usize, usize)> = Vec::new();
+        let mut assignment = vec![0; lifetimes.len()];
+        for i in order {
+            let lifetime = &lifetimes[i];
+            let reusable = slots.iter().position(|&(owner, busy_until)| {
+                busy_until < lifetime.first_use && lifetime.shares_storage_with(&lifetimes[owner])
+            });
+            assignment[i] = match reusable {
+                Some(slot) => {
+                    slots[slot].1 = lifetime.last_use;
+                    slot
+                }
+                None => {
+                    slots.push((i, lifetime.last_use));
+                    slots.len() - 1
+                }
+            };
+        }
+        assignment
+    }
+
+    /// Allocate every texture in `lifetimes`, one `wgpu::Texture` per slot of
+    /// [`plan_aliases`](Self::plan_aliases). Aliased names resolve to the
+    /// same texture and view.
+    pub fn allocate_aliased(&mut self, device: &wgpu::Device, lifetimes: &[TextureLifetime]) {
+        let assignment = Self::plan_aliases(lifetimes);
+        let mut slot_textures: HashMap<usize, usize> = HashMap::new();
+        for (lifetime, slot) in lifetimes.iter().zip(assignment) {
+            let desc = &lifetime.desc;
+            let idx = match slot_textures.get(&slot) {
+                Some(&idx) => {
+                    if let Some(group) = &desc.alias_group {
+                        *self.alias_refs.entry(group.clone()).or_insert(0) += 1;
+                    }
+                    idx
+                }
+                None => {
+                    self.allocate(device, desc.clone());
+                    let idx = self.textures.len() - 1;
+                    slot_textures.insert(slot, idx);
+                    idx
+                }
+            };
+            self.name_map.insert(desc.name.clone(), idx);
+        }
+    }
+
+    fn create(device: &wgpu::Device, desc: &TextureDescriptor) -> GraphTexture {
         let texture = device.create_texture(&wgpu::TextureDescriptor {
             label: Some(&desc.name),
             size: wgpu::Extent3d {
@@ -259,15 +367,20 @@ impl GraphTexturePool {
             ..Default::default()
         });
 
-        let idx = self.textures.len();
-        self.textures.push(GraphTexture { texture, view, desc: desc.clone() });
-        self.name_map.insert(desc.name.clone(), idx);
+        GraphTexture { texture, view, desc: desc.clone() }
+    }
 
-        if let Some(group) = &desc.alias_group {
-            self.alias_refs.insert(group.clone(), 1);
-        }
+    /// Register an already-created texture under its descriptor's name,
+    /// outside any alias group.
+    pub fn insert(&mut self, texture: GraphTexture) {
+        let idx = self.textures.len();
+        self.name_map.insert(texture.desc.name.clone(), idx);
+        self.textures.push(texture);
+    }
 
-        &self.textures[idx]
+    /// Every named texture, aliased names included.
+    pub fn textures(&self) -> impl Iterator<Item = (&str, &GraphTexture)> {
+        self.name_map.iter().map(|(name, &idx)| (name.as_str(), &self.textures[idx]))
     }
 
     pub fn get_view(&self, name: &str) -> Option<&wgpu::TextureView> {
@@ -289,6 +402,23 @@ impl GraphTexturePool {
         }
     }
 
+    /// Approximate bytes held by every allocated texture, mip chains included.
+    pub fn memory_bytes(&self) -> u64 {
+        self.textures
+            .iter()
+            .map(|t| {
+                estimate_texture_bytes(
+                    t.desc.format,
+                    t.desc.width,
+                    t.desc.height,
+                    t.desc.depth_or_array_layers,
+                    t.desc.mip_level_count,
+                    t.desc.sample_count,
+                )
+            })
+            .sum()
+    }
+
     pub fn clear(&mut self) {
         self.textures.clear();
         self.name_map.clear();
@@ -296,6 +426,42 @@ impl GraphTexturePool {
     }
 }
 
+/// Approximate bytes held by `texture`, mip chain and MSAA samples included.
+///
+/// The same estimate the graph uses for its own pool, for passes reporting
+/// textures they allocate themselves from
+/// [`RenderPass::gpu_memory_bytes`](crate::RenderPass::gpu_memory_bytes).
+pub fn texture_memory_bytes(texture: &wgpu::Texture) -> u64 {
+    estimate_texture_bytes(
+        texture.format(),
+        texture.width(),
+        texture.height(),
+        texture.depth_or_array_layers(),
+        texture.mip_level_count(),
+        texture.sample_count(),
+    )
+}
+
+fn estimate_texture_bytes(
+    format: wgpu::TextureFormat,
+    width: u32,
+    height: u32,
+    layers: u32,
+    mip_level_count: u32,
+    sample_count: u32,
+) -> u64 {
+    let bpp = super::executor::format_bpp(format) as u64;
+    let layers = layers.max(1) as u64;
+    let samples = sample_count.max(1) as u64;
+    (0..mip_level_count.max(1))
+        .map(|mip| {
+            let w = (width >> mip).max(1) as u64;
+            let h = (height >> mip).max(1) as u64;
+            w * h * layers * samples * bpp / 8
+        })
+        .sum()
+}
+
 /// Allocates graph textures at a specific resolution.
 pub struct ResourceAllocator {
     pub pool: GraphTexturePool,
@@ -348,3 +514,49 @@ impl ResourceAllocator {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lifetime(name: &str, first_use: usize, last_use: usize, group: Option<&str>) -> TextureLifetime {
+        TextureLifetime {
+            desc: TextureDescriptor {
+                name: name.to_string(),
+                format: wgpu::TextureFormat::Rgba16Float,
+                width: 1920,
+                height: 1080,
+                depth_or_array_layers: 1,
+                mip_level_count: 1,
+                sample_count: 1,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+                alias_group: group.map(str::to_string),
+            },
+            first_use,
+            last_use,
+        }
+    }
+
+    #[test]
+    fn disjoint_lifetimes_in_a_group_share_one_texture() {
+        let plan = GraphTexturePool::plan_aliases(&[
+            lifetime("a", 0, 1, Some("g")),
+            lifetime("b", 2, 3, Some("g")),
+            lifetime("c", 3, 4, Some("g")),
+        ]);
+        // `a` is done before `b` starts; `c` overlaps `b` at pass 3, so it
+        // gets a texture of its own.
+        assert_eq!(plan[0], plan[1]);
+        assert_ne!(plan[1], plan[2]);
+
+        let mut wide = lifetime("wide", 2, 3, Some("g"));
+        wide.desc.width = 3840;
+        let plan = GraphTexturePool::plan_aliases(&[
+            lifetime("a", 0, 1, Some("g")),
+            wide,
+            lifetime("other_group", 2, 3, Some("h")),
+            lifetime("ungrouped", 2, 3, None),
+        ]);
+        assert_eq!(plan.iter().collect::<std::collections::HashSet<_>>().len(), 4);
+    }
+}
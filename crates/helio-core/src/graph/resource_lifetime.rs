@@ -5,7 +5,6 @@ use super::scheduling::PrePassAction;
 
 pub(crate) struct ResourceLifetime {
     pub(crate) first_write_pass: usize,
-    #[allow(dead_code)]
     pub(crate) last_read_pass: usize,
     pub(crate) format: wgpu::TextureFormat,
     pub(crate) width: u32,
@@ -15,6 +14,29 @@ pub(crate) struct ResourceLifetime {
     pub(crate) extra_usage: wgpu::TextureUsages,
     pub(crate) alias_group: Option<String>,
     pub(crate) chain_local: bool,
+    /// Read by a pass at or before the one that writes it, i.e. the previous
+    /// frame's contents are used (history buffers). Never aliased.
+    pub(crate) read_before_write: bool,
+}
+
+/// Every resource name `pass` reads and writes: the legacy
+/// [`reads`](crate::RenderPass::reads)/[`writes`](crate::RenderPass::writes)
+/// lists merged with its [`declare_resources`](crate::RenderPass::declare_resources).
+pub(crate) fn pass_accesses(pass: &dyn crate::RenderPass) -> (Vec<&'static str>, Vec<&'static str>) {
+    let mut reads = pass.reads().to_vec();
+    let mut writes = pass.writes().to_vec();
+    let mut builder = ResourceBuilder::new();
+    pass.declare_resources(&mut builder);
+    for decl in builder.declarations() {
+        let list = match decl.access {
+            crate::graph::ResourceAccess::Read => &mut reads,
+            crate::graph::ResourceAccess::Write => &mut writes,
+        };
+        if !list.contains(&decl.name) {
+            list.push(decl.name);
+        }
+    }
+    (reads, writes)
 }
 
 impl RenderGraph {
@@ -62,11 +84,13 @@ impl RenderGraph {
 
         for w in &writes {
             let mut last_read = w.pass_index;
+            let mut read_before_write = false;
             for (j, builder) in builders.iter().enumerate() {
-                for d in builder.declarations() {
-                    if d.access == crate::graph::ResourceAccess::Read && d.name == w.name && j > last_read {
-                        last_read = j;
-                    }
+                // Later writes count too: the texture is live until its
+                // last use of either kind.
+                for d in builder.declarations().iter().filter(|d| d.name == w.name) {
+                    last_read = last_read.max(j);
+                    read_before_write |= d.access == crate::graph::ResourceAccess::Read && j < w.pass_index;
                 }
             }
 
@@ -102,6 +126,7 @@ impl RenderGraph {
                 extra_usage: w.extra_usage,
                 alias_group: None,
                 chain_local: false,
+                read_before_write,
             });
         }
     }
@@ -110,14 +135,12 @@ impl RenderGraph {
     /// share a physical allocation.  Runs after `chain_local` is computed on
     /// every `ResourceLifetime`, before the final `allocate_textures()` call.
     pub(crate) fn assign_chain_aware_alias_groups(&mut self) {
-        let mut chain_group_gen: u32 = 0;
         for rl in self.resources.values_mut() {
-            if rl.chain_local {
-                // Every chain-local resource gets its own alias group keyed
-                // on its first_write_pass.  Resources written by the same
-                // pass are never alive concurrently so they may alias.
-                let g = format!("chain_local_{}", rl.first_write_pass);
-                rl.alias_group = Some(g);
+            if rl.chain_local && !rl.read_before_write {
+                // Chain-local contents never outlive their chain, so any two
+                // with disjoint pass spans may share memory; the pool checks
+                // the spans and descriptions when allocating.
+                rl.alias_group = Some("chain_local".to_string());
             } else {
                 rl.alias_group = None;
             }
@@ -125,32 +148,57 @@ impl RenderGraph {
     }
 
     pub(crate) fn allocate_textures(&mut self) {
-        use crate::graph::resource::TextureDescriptor;
+        use crate::graph::resource::{TextureDescriptor, TextureLifetime};
 
         self.pre_pass_actions.clear();
         if self.resources.is_empty() {
+            self.feature_textures.rebuild(&self.device, &self.pool);
             return;
         }
 
-        for (name, rl) in &self.resources {
-            let usage = if rl.format == wgpu::TextureFormat::R32Float {
-                wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::STORAGE_BINDING
-            } else {
-                wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING
-            } | rl.extra_usage;
-            let tex_desc = TextureDescriptor {
-                name: name.clone(),
-                format: rl.format,
-                width: rl.width,
-                height: rl.height,
-                depth_or_array_layers: rl.depth_or_array_layers,
-                mip_level_count: rl.mip_level_count,
-                sample_count: 1,
-                usage,
-                alias_group: rl.alias_group.clone(),
-            };
-            self.pool.allocate(&self.device, tex_desc);
+        let lifetimes: Vec<TextureLifetime> = self
+            .resources
+            .iter()
+            .map(|(name, rl)| {
+                let usage = if rl.format == wgpu::TextureFormat::R32Float {
+                    wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::STORAGE_BINDING
+                } else {
+                    wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING
+                } | rl.extra_usage;
+                TextureLifetime {
+                    desc: TextureDescriptor {
+                        name: name.clone(),
+                        format: rl.format,
+                        width: rl.width,
+                        height: rl.height,
+                        depth_or_array_layers: rl.depth_or_array_layers,
+                        mip_level_count: rl.mip_level_count,
+                        sample_count: 1,
+                        usage,
+                        alias_group: rl.alias_group.clone(),
+                    },
+                    first_use: rl.first_write_pass,
+                    last_use: rl.last_read_pass,
+                }
+            })
+            .collect();
+        // Outputs of paused passes keep the texture they had before the
+        // rebuild (and with it their contents) while the description holds.
+        let (kept, lifetimes): (Vec<TextureLifetime>, Vec<TextureLifetime>) =
+            lifetimes.into_iter().partition(|lt| {
+                let writer = self.passes.get(self.resources[&lt.desc.name].first_write_pass);
+                writer.is_some_and(|pass| self.paused_passes.contains(pass.name()))
+                    && self
+                        .held_textures
+                        .get(&lt.desc.name)
+                        .is_some_and(|held| held.desc.same_storage(&lt.desc))
+            });
+        for lt in kept {
+            let mut held = self.held_textures[&lt.desc.name].clone();
+            held.desc = lt.desc;
+            self.pool.insert(held);
         }
+        self.pool.allocate_aliased(&self.device, &lifetimes);
 
         let mut actions: Vec<Vec<PrePassAction>> = (0..self.passes.len()).map(|_| Vec::new()).collect();
         for (name, rl) in &self.resources {
@@ -204,5 +252,6 @@ impl RenderGraph {
         }
 
         self.pre_pass_actions = actions;
+        self.feature_textures.rebuild(&self.device, &self.pool);
     }
 }
@@ -134,7 +134,7 @@ impl RenderGraph {
             }
             writes_set.push(w);
             reads_set.push(r);
-            transparent.push(pass.chain_transparent());
+            transparent.push(pass.chain_transparent() && self.pass_active(pass.name()));
         }
         (writes_set, reads_set, transparent)
     }
@@ -372,12 +372,14 @@
 
 pub mod acceleration;
 pub mod actor;
+pub mod clock;
 pub mod component;
 pub mod context;
 pub mod entity;
 pub mod error;
 pub mod graph;
 pub mod profiling;
+pub mod quality;
 pub mod scene;
 pub mod shader;
 pub mod traits;
@@ -395,11 +397,17 @@ pub use crate::acceleration::{BlasManager, TlasInstanceInput, TlasManager};
 pub use crate::scene::managers::*;
 // Re-export core types
 pub use actor::Actor;
+pub use clock::{FrameClock, FrameTime};
 pub use component::{Component, ComponentRegistry, ComponentSlot, ComponentVec};
 pub use context::{PassContext, PrepareContext};
 pub use entity::Entity;
 pub use error::{Error, Result};
-pub use graph::{DebugPassInfo, DebugResourceInfo, FrameDebugData, RenderGraph};
+pub use graph::{
+    texture_memory_bytes, AsyncComputeSpan, DebugPassInfo, DebugResourceInfo, FeatureTextureFallback,
+    FeatureTextures, FeatureUniformSlot, FeatureUniforms, FrameDebugData, MergePolicy, RenderGraph,
+    DEFAULT_FRAMES_IN_FLIGHT, FEATURE_TEXTURE_SLOTS, FEATURE_UNIFORM_SLOT_SIZE, MAX_FRAMES_IN_FLIGHT,
+};
 pub use profiling::Profiler;
+pub use quality::QualityPreset;
 pub use scene::{GpuScene, SceneResources};
-pub use traits::{AsAny, DebugViewDescriptor, MaybeSend, MaybeSync, RenderPass};
+pub use traits::{AsAny, DebugViewDescriptor, MaybeSend, MaybeSync, QueuePreference, RenderPass};
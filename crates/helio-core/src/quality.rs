@@ -0,0 +1,66 @@
+//! Renderer-wide quality tiers.
+//!
+//! [`QualityPreset`] is a single settings slider over the per-pass quality
+//! knobs. The renderer broadcasts it to every pass through
+//! [`RenderPass::apply_quality`](crate::RenderPass::apply_quality); each pass
+//! maps the tier onto its own setters and ignores it if it has nothing to scale.
+
+/// Coarse quality tier applied across all passes in a graph.
+///
+/// Concrete values per tier:
+///
+/// | Setting                     | Low        | Medium     | High              | Ultra              |
+/// |-----------------------------|------------|------------|-------------------|--------------------|
+/// | Shadow atlas face size      | 512        | 1024       | 1024              | 2048               |
+/// | Shadow filtering            | 8-tap PCF  | 12-tap PCF | 12-tap PCF + PCSS | 16-tap PCF + PCSS  |
+/// | GI (radiance cascades)      | off        | `Medium`   | `High`            | `Ultra`            |
+/// | SSAO kernel samples         | 8          | 16         | 32                | 64                 |
+///
+/// MSAA is not part of the bundle: the deferred pipeline renders single-sampled
+/// and anti-aliases with TAA/FXAA/SMAA, chosen when the graph is built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum QualityPreset {
+    Low,
+    #[default]
+    Medium,
+    High,
+    Ultra,
+}
+
+impl QualityPreset {
+    pub const ALL: [QualityPreset; 4] = [
+        QualityPreset::Low,
+        QualityPreset::Medium,
+        QualityPreset::High,
+        QualityPreset::Ultra,
+    ];
+
+    /// Shadow filtering tier (PCF taps / PCSS).
+    pub fn shadow_quality(self) -> libhelio::ShadowQuality {
+        match self {
+            QualityPreset::Low => libhelio::ShadowQuality::Low,
+            QualityPreset::Medium => libhelio::ShadowQuality::Medium,
+            QualityPreset::High => libhelio::ShadowQuality::High,
+            QualityPreset::Ultra => libhelio::ShadowQuality::Ultra,
+        }
+    }
+
+    /// Width/height of each shadow atlas face in texels.
+    pub fn shadow_atlas_size(self) -> u32 {
+        match self {
+            QualityPreset::Low => 512,
+            QualityPreset::Medium | QualityPreset::High => 1024,
+            QualityPreset::Ultra => 2048,
+        }
+    }
+
+    /// SSAO hemisphere kernel samples per pixel.
+    pub fn ssao_samples(self) -> u32 {
+        match self {
+            QualityPreset::Low => 8,
+            QualityPreset::Medium => 16,
+            QualityPreset::High => 32,
+            QualityPreset::Ultra => 64,
+        }
+    }
+}
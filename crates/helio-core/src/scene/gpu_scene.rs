@@ -75,7 +75,7 @@ use crate::component::ComponentRegistry;
 use crate::scene::managers::{
     GpuAabbBuffer, GpuCameraBuffer, GpuDecalBuffer, GpuDrawCallBuffer, GpuIndirectBuffer,
     GpuInstanceBuffer, GpuLightBuffer, GpuMaterialBuffer, GpuShadowMatrixBuffer,
-    GpuVisibilityBuffer, GpuVoxelVolumeBuffer, GpuVoxelEditRing,
+    GpuVisibilityBuffer, GpuVoxelVolumeBuffer, GpuVoxelEditRing, GpuWindBuffer,
 };
 use crate::scene::managers::GrowableBuffer;
 use crate::scene::SceneResources;
@@ -178,6 +178,8 @@ pub struct GpuScene {
     pub camera: GpuCameraBuffer,
     pub instances: GpuInstanceBuffer,
     pub aabbs: GpuAabbBuffer,
+    /// Per-instance wind sway, parallel to `instances`.
+    pub wind: GpuWindBuffer,
     pub draw_calls: GpuDrawCallBuffer,
     pub lights: GpuLightBuffer,
     pub decals: GpuDecalBuffer,
@@ -224,6 +226,10 @@ pub struct GpuScene {
     /// its own per_caster_last_gen[] and only re-renders faces for dirty casters.
     pub per_caster_dirty_gen: [u64; 42],
 
+    /// Shadow resolution tier per caster slot, written by Scene::flush() under
+    /// adaptive shadow resolution. 0 (full resolution) otherwise.
+    pub per_caster_resolution_shift: [u32; 42],
+
     /// Type-erased component storage for the new Entity-Component system.
     pub components: ComponentRegistry,
 
@@ -249,6 +255,16 @@ pub struct GpuScene {
     /// during flush.  The GBuffer pass looks up WGSL by hash when building PSOs.
     pub graph_wgsl_snippets: std::collections::HashMap<u64, String>,
 
+    /// Whether any material has `FLAG_ALPHA_TEST` set.
+    /// Recomputed by `flush()` whenever the material buffer was written; the
+    /// shadow pass switches to its alpha-tested caster pipeline while set.
+    pub has_alpha_tested_materials: bool,
+
+    /// Whether any instance has a non-zero wind amplitude.
+    /// Recomputed by `flush()` whenever the wind buffer was written; the shadow
+    /// pass stops caching atlas faces while set.
+    pub has_wind: bool,
+
     /// Reflection capture GPU storage buffer.
     pub reflection_captures: GrowableBuffer<libhelio::GpuReflectionCapture>,
 
@@ -290,6 +306,7 @@ impl GpuScene {
         let camera = GpuCameraBuffer::new(&device);
         let instances = GpuInstanceBuffer::new(device.clone());
         let aabbs = GpuAabbBuffer::new(device.clone());
+        let wind = GpuWindBuffer::new(device.clone());
         let draw_calls = GpuDrawCallBuffer::new(device.clone());
         let lights = GpuLightBuffer::new(device.clone());
         let decals = GpuDecalBuffer::new(device.clone());
@@ -342,6 +359,7 @@ impl GpuScene {
             camera,
             instances,
             aabbs,
+            wind,
             draw_calls,
             lights,
             decals,
@@ -355,6 +373,7 @@ impl GpuScene {
             shadow_movable_draw_count: 0,
             movable_light_count: 0,
             per_caster_dirty_gen: [1u64; 42],
+            per_caster_resolution_shift: [0; 42],
             components: ComponentRegistry::new(),
             voxel_volumes,
             voxel_edit_ring,
@@ -366,6 +385,8 @@ impl GpuScene {
             material_class_ranges: Vec::new(),
             material_graph_hashes: Vec::new(),
             graph_wgsl_snippets: std::collections::HashMap::new(),
+            has_alpha_tested_materials: false,
+            has_wind: false,
             reflection_captures,
             blas_manager: BlasManager::new(device_for_rt.clone()),
             tlas_manager: TlasManager::new(device_for_rt, 65536),
@@ -400,8 +421,10 @@ impl GpuScene {
     pub fn resources(&self) -> SceneResources<'_> {
         SceneResources {
             camera: self.camera.buffer(),
+            camera_uniforms: self.camera.data(),
             instances: self.instances.buffer(),
             aabbs: self.aabbs.buffer(),
+            wind: self.wind.buffer(),
             draw_calls: self.draw_calls.buffer(),
             lights: self.lights.buffer(),
             decals: self.decals.buffer(),
@@ -424,6 +447,7 @@ impl GpuScene {
             movable_light_count: self.movable_light_count,
             static_objects_generation: self.static_objects_generation,
             per_caster_dirty_gen: self.per_caster_dirty_gen,
+            per_caster_resolution_shift: self.per_caster_resolution_shift,
             components: &self.components,
             voxel_volumes: self.voxel_volumes.buffer(),
             voxel_edit_ring: self.voxel_edit_ring.buffer(),
@@ -434,6 +458,8 @@ impl GpuScene {
             material_class_ranges: &self.material_class_ranges,
             material_graph_hashes: &self.material_graph_hashes,
             graph_wgsl_snippets: &self.graph_wgsl_snippets,
+            has_alpha_tested_materials: self.has_alpha_tested_materials,
+            has_wind: self.has_wind,
             reflection_captures: self.reflection_captures.buffer(),
             reflection_capture_count: self.reflection_captures.len() as u32,
             rt_available: self.tlas_manager.is_rt_available(),
@@ -492,9 +518,25 @@ impl GpuScene {
         self.camera.flush(queue);
         self.instances.flush(queue);
         self.aabbs.flush(queue);
+        // An emptied buffer is not marked dirty, so check that case too.
+        if self.wind.is_dirty() || self.wind.is_empty() {
+            self.has_wind = self
+                .wind
+                .as_slice()
+                .iter()
+                .any(|w| w.direction_amplitude[2] != 0.0);
+        }
+        self.wind.flush(queue);
         self.draw_calls.flush(queue);
         self.lights.flush(queue);
         self.decals.flush(queue);
+        if self.materials.is_dirty() {
+            self.has_alpha_tested_materials = self
+                .materials
+                .as_slice()
+                .iter()
+                .any(|m| m.flags & libhelio::FLAG_ALPHA_TEST != 0);
+        }
         self.materials.flush(queue);
         self.shadow_matrices.flush(queue);
         self.indirect.flush(queue);
@@ -7,7 +7,7 @@ use crate::upload;
 use bytemuck::Zeroable;
 use libhelio::{
     DrawIndexedIndirectArgs, GpuCameraUniforms, GpuDecal, GpuDrawCall, GpuInstanceAabb,
-    GpuInstanceData, GpuLight, GpuMaterial, GpuShadowMatrix,
+    GpuInstanceData, GpuLight, GpuMaterial, GpuShadowMatrix, GpuWindParams,
 };
 use std::sync::Arc;
 
@@ -80,6 +80,11 @@ impl<T: bytemuck::Pod> GrowableBuffer<T> {
         &self.data
     }
 
+    /// Returns true if CPU-side changes are waiting for the next `flush()`.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty_range.is_some()
+    }
+
     fn mark_dirty_range(&mut self, start: usize, end: usize) {
         if start >= end {
             return;
@@ -310,6 +315,8 @@ impl GpuCameraBuffer {
 pub struct GpuInstanceBuffer(pub GrowableBuffer<GpuInstanceData>);
 /// Storage buffer for per-instance AABBs (for GPU culling).
 pub struct GpuAabbBuffer(pub GrowableBuffer<GpuInstanceAabb>);
+/// Storage buffer for per-instance wind parameters (vertex sway).
+pub struct GpuWindBuffer(pub GrowableBuffer<GpuWindParams>);
 /// Storage buffer for draw call templates (source for indirect dispatch).
 pub struct GpuDrawCallBuffer(pub GrowableBuffer<GpuDrawCall>);
 /// Storage buffer for GPU lights.
@@ -371,6 +378,29 @@ impl std::ops::DerefMut for GpuAabbBuffer {
     }
 }
 
+impl GpuWindBuffer {
+    pub fn new(device: Arc<wgpu::Device>) -> Self {
+        Self(GrowableBuffer::new(
+            device,
+            4096,
+            wgpu::BufferUsages::STORAGE,
+            "Wind Buffer",
+        ))
+    }
+}
+
+impl std::ops::Deref for GpuWindBuffer {
+    type Target = GrowableBuffer<GpuWindParams>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl std::ops::DerefMut for GpuWindBuffer {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
 impl GpuDrawCallBuffer {
     pub fn new(device: Arc<wgpu::Device>) -> Self {
         Self(GrowableBuffer::new(
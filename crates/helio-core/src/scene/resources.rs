@@ -117,8 +117,12 @@ use crate::component::ComponentRegistry;
 /// ```
 pub struct SceneResources<'a> {
     pub camera: &'a wgpu::Buffer,
+    /// CPU copy of the uniforms in `camera` for this frame.
+    pub camera_uniforms: &'a libhelio::GpuCameraUniforms,
     pub instances: &'a wgpu::Buffer,
     pub aabbs: &'a wgpu::Buffer,
+    /// Per-instance [`GpuWindParams`](libhelio::GpuWindParams), indexed like `instances`.
+    pub wind: &'a wgpu::Buffer,
     pub draw_calls: &'a wgpu::Buffer,
     pub lights: &'a wgpu::Buffer,
     pub decals: &'a wgpu::Buffer,
@@ -156,6 +160,10 @@ pub struct SceneResources<'a> {
     /// Copied from GpuScene::per_caster_dirty_gen each frame. ShadowPass compares against
     /// its own last-rendered gen to decide which caster faces need re-rendering.
     pub per_caster_dirty_gen: [u64; 42],
+    /// Resolution tier per shadow caster slot (see
+    /// [`GpuLight::shadow_resolution_shift`](libhelio::GpuLight::shadow_resolution_shift)).
+    /// ShadowPass scissors each face to its caster's region.
+    pub per_caster_resolution_shift: [u32; 42],
 
     /// Component registry for type-erased storage access.
     pub components: &'a ComponentRegistry,
@@ -179,6 +187,12 @@ pub struct SceneResources<'a> {
     /// Compiled graph WGSL snippets keyed by hash. Populated during flush.
     pub graph_wgsl_snippets: &'a std::collections::HashMap<u64, String>,
 
+    /// Whether any material is alpha-tested (`FLAG_ALPHA_TEST`).
+    pub has_alpha_tested_materials: bool,
+
+    /// Whether any instance sways in the wind (non-zero amplitude).
+    pub has_wind: bool,
+
     /// Reflection capture storage buffer.
     pub reflection_captures: &'a wgpu::Buffer,
     /// Number of reflection captures in the buffer.
@@ -32,6 +32,9 @@
 //! untouched, so unmigrated passes that declare their own `Camera` keep working
 //! (and would otherwise collide with the prelude's).
 //!
+//! Leading `enable` directives are hoisted ahead of the prelude, since WGSL
+//! requires them before any declaration.
+//!
 //! # Caveat
 //!
 //! Prepending shifts line numbers, so naga diagnostics for a prelude-using
@@ -63,12 +66,27 @@ pub fn uses_prelude(source: &str) -> bool {
 /// The single point of truth for prelude expansion: [`module`] and the
 /// `wgsl_validation` test both go through here, so the test validates exactly
 /// what the runtime builds rather than an approximation of it.
+///
+/// `enable` directives are moved above the prelude rather than copied, so
+/// every other line still sits exactly [`prelude_lines`] below its original.
 pub fn resolve(source: &str) -> Cow<'_, str> {
-    if uses_prelude(source) {
-        Cow::Owned(format!("{PRELUDE}\n{source}"))
-    } else {
-        Cow::Borrowed(source)
+    if !uses_prelude(source) {
+        return Cow::Borrowed(source);
+    }
+    let (directives, body): (Vec<&str>, Vec<&str>) = source
+        .split_inclusive('\n')
+        .partition(|line| line.trim_start().starts_with("enable "));
+    let mut out = String::with_capacity(PRELUDE.len() + source.len() + 1);
+    for directive in directives {
+        out.push_str(directive);
+        if !directive.ends_with('\n') {
+            out.push('\n');
+        }
     }
+    out.push_str(PRELUDE);
+    out.push('\n');
+    out.extend(body);
+    Cow::Owned(out)
 }
 
 /// Creates a shader module, expanding the prelude if the source opts in.
@@ -109,11 +127,24 @@ mod tests {
             "fn helio_world_from_depth",
             "fn helio_view_depth",
             "fn helio_gbuffer_normal",
+            "struct HelioWindParams",
+            "fn helio_wind_offset",
         ] {
             assert!(PRELUDE.contains(symbol), "prelude is missing {symbol}");
         }
     }
 
+    #[test]
+    fn enable_directives_are_hoisted_above_the_prelude() {
+        let src = "enable wgpu_binding_array;\n//!use helio_prelude\nfoo\nbar";
+        let resolved = resolve(src);
+        assert!(resolved.starts_with("enable wgpu_binding_array;\n"));
+        assert_eq!(resolved.matches("enable wgpu_binding_array;").count(), 1);
+        let lines: Vec<&str> = resolved.lines().collect();
+        // Hoisting does not disturb the offset of the remaining lines.
+        assert_eq!(lines[prelude_lines() + 2], "foo");
+    }
+
     #[test]
     fn prelude_line_count_matches_what_resolve_prepends() {
         let src = "//!use helio_prelude\nfoo";
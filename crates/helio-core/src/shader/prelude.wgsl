@@ -28,7 +28,10 @@ struct Camera {
     position_near:  vec4<f32>,
     /// Camera forward direction (xyz) + far plane (w).
     forward_far:    vec4<f32>,
-    /// TAA jitter (xy) + frame index (z) + padding (w).
+    /// TAA jitter (xy) + frame index (z) + pre-exposure (w).
+    ///
+    /// Radiance written to the HDR target is multiplied by the pre-exposure;
+    /// a pass that reads the HDR target back as radiance divides it out.
     jitter_frame:   vec4<f32>,
     prev_view_proj: mat4x4<f32>,
 }
@@ -225,3 +228,34 @@ fn helio_hg_phase(cos_theta: f32, g: f32) -> f32 {
     let denom = 1.0 + g2 - 2.0 * g * cos_theta;
     return (1.0 - g2) / (4.0 * HELIO_PI * pow(max(denom, 1e-4), 1.5));
 }
+
+// ── Wind ────────────────────────────────────────────────────────────────────
+// Every pass that rasterises a swaying object must displace it identically:
+// the depth prepass and G-buffer outputs are `@invariant` and depth-tested
+// against each other, and a shadow drawn from the unswayed pose detaches from
+// the geometry that casts it. One copy, here.
+
+/// Per-instance wind sway (32 bytes). Mirror of libhelio `GpuWindParams`.
+struct HelioWindParams {
+    /// XZ direction (xy), amplitude (z), phase (w).
+    direction_amplitude: vec4<f32>,
+    /// Angular frequency (x); yzw unused.
+    frequency:           vec4<f32>,
+}
+
+/// World-space sway for a vertex at object-space `local_pos`.
+///
+/// Grows with the square of the height above the object's origin so trunks stay
+/// planted. `origin` is the instance's world translation, which offsets the
+/// phase so neighbours don't move in lockstep; `time` is in seconds.
+fn helio_wind_offset(wind: HelioWindParams, local_pos: vec3<f32>, origin: vec3<f32>, time: f32) -> vec3<f32> {
+    let amplitude = wind.direction_amplitude.z;
+    if amplitude == 0.0 {
+        return vec3<f32>(0.0);
+    }
+    let height = max(local_pos.y, 0.0);
+    let t = time * wind.frequency.x + wind.direction_amplitude.w + dot(origin.xz, vec2<f32>(0.13, 0.17));
+    let sway = sin(t) + 0.35 * sin(2.7 * t + 1.3);
+    let dir = vec3<f32>(wind.direction_amplitude.x, 0.0, wind.direction_amplitude.y);
+    return dir * (amplitude * height * height * sway);
+}
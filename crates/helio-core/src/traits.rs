@@ -100,6 +100,22 @@ impl<T> MaybeSync for T {}
 use crate::graph::ResourceBuilder;
 use crate::{PassContext, PrepareContext, Result};
 
+/// Which GPU queue a pass would like its work submitted on.
+///
+/// Returned by [`RenderPass::queue_preference`]. This is a hint: the graph
+/// plans async placement from it (see
+/// [`RenderGraph::async_compute_plan`](crate::RenderGraph::async_compute_plan)),
+/// and passes that do not qualify stay on the graphics queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QueuePreference {
+    /// Ordered with all other work on the graphics queue.
+    #[default]
+    Graphics,
+    /// Compute-only work that may overlap graphics work on an async compute
+    /// queue, e.g. GI injection, SSAO or particle simulation.
+    AsyncCompute,
+}
+
 /// Describes a debug visualisation mode that a render pass provides.
 ///
 /// Passes return a static slice of these from [`RenderPass::debug_views`] so
@@ -380,6 +396,16 @@ pub trait RenderPass: AsAny + MaybeSend + MaybeSync {
         false
     }
 
+    /// Queue this pass's work may run on. Default [`QueuePreference::Graphics`].
+    ///
+    /// Return [`QueuePreference::AsyncCompute`] only from compute-only passes
+    /// (no `render_pass_descriptor`, no render passes recorded in
+    /// `execute()`). The graph derives the cross-queue sync points from the
+    /// pass's declared reads and writes, so those must be complete.
+    fn queue_preference(&self) -> QueuePreference {
+        QueuePreference::Graphics
+    }
+
     /// Optionally prepares per-frame data before GPU execution.
     ///
     /// Called once per frame **before** `execute()`. Use this to upload per-frame uniforms
@@ -437,6 +463,29 @@ pub trait RenderPass: AsAny + MaybeSend + MaybeSync {
     /// The default keeps passes without debug visualisations source-compatible.
     fn set_debug_mode(&mut self, _mode: u32) {}
 
+    /// Applies a renderer-wide [`QualityPreset`](crate::QualityPreset).
+    ///
+    /// Passes with quality knobs map the tier onto their own settings (see the
+    /// table on `QualityPreset` for the values each tier stands for). Changes
+    /// take effect from the next `prepare`. The default is a no-op.
+    fn apply_quality(&mut self, _preset: crate::QualityPreset) {}
+
+    /// Updates the depth convention shadow atlases are rendered and sampled in.
+    ///
+    /// Passes that write, project into or compare against a shadow atlas
+    /// override this so depth tests, samplers, clear values and shaders flip
+    /// together. The default is a no-op.
+    fn set_depth_convention(&mut self, _convention: libhelio::DepthConvention) {}
+
+    /// Bytes of GPU memory held by resources this pass allocates itself.
+    ///
+    /// Graph-owned textures from [`declare_resources`](Self::declare_resources)
+    /// are accounted by the graph and must not be included. Used for frame
+    /// statistics only, so an estimate is fine. The default reports nothing.
+    fn gpu_memory_bytes(&self) -> u64 {
+        0
+    }
+
     /// Returns the debug visualisation modes this pass provides.
     ///
     /// The renderer aggregates these from all passes to build a discoverable
@@ -467,4 +516,15 @@ pub trait RenderPass: AsAny + MaybeSend + MaybeSync {
     /// `ResourceSlot`-based [`reads`](Self::reads) / [`writes`](Self::writes)
     /// methods for backward compatibility.
     fn declare_resources(&self, _builder: &mut ResourceBuilder) {}
+
+    /// Reports that this pass's [`declare_resources`](Self::declare_resources)
+    /// output changed since the graph last collected it.
+    ///
+    /// The graph polls this before every frame. Returning `true` makes it
+    /// re-collect declarations and reallocate its textures at the current
+    /// render size, e.g. after a quality change resizes a fixed-size atlas.
+    /// Implementations should clear their flag when returning `true`.
+    fn resources_changed(&mut self) -> bool {
+        false
+    }
 }
@@ -10,6 +10,7 @@ use helio_pass_debug_overlay::{DebugOverlayPass, DebugOverlayState};
 use helio_pass_deferred_light::DeferredLightPass;
 use helio_pass_fxaa::FxaaPass;
 use helio_pass_gbuffer::GBufferPass;
+use helio_pass_gradient_sky::GradientSkyPass;
 use helio_pass_hiz::HiZBuildPass;
 use helio_pass_hlfs::HlfsPass;
 use helio_pass_indirect_dispatch::IndirectDispatchPass;
@@ -119,7 +120,16 @@ fn add_common_early_passes(
     if scene.sky_context().has_sky {
         graph.add_pass(Box::new(SkyLutPass::new(device, camera_buf)));
 
-        graph.add_pass(Box::new(SkyPass::new(
+        let sky_pass = SkyPass::new(
+            device,
+            camera_buf,
+            config.surface_format,
+            graph,
+        )
+        .expect("a new graph has free feature slots");
+        graph.add_pass(Box::new(sky_pass));
+    } else if scene.sky_context().active_gradient().is_some() {
+        graph.add_pass(Box::new(GradientSkyPass::new(
             device,
             camera_buf,
             config.surface_format,
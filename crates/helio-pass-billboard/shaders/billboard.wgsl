@@ -39,7 +39,7 @@ struct QuadVertex {
 struct BillboardInstance {
     // world position (xyz) + unused pad (w)
     @location(2) world_pos_pad: vec4<f32>,
-    // scale (xy), screen_scale flag as f32 (z), unused (w)
+    // scale (xy), screen_scale flag as f32 (z), blend mode as f32 (w)
     @location(3) scale_flags:   vec4<f32>,
     // RGBA tint color
     @location(4) color:         vec4<f32>,
@@ -51,8 +51,15 @@ struct VertexOut {
     @builtin(position) clip_pos: vec4<f32>,
     @location(0)       uv:       vec2<f32>,
     @location(1)       color:    vec4<f32>,
+    @location(2) @interpolate(flat) blend_mode: u32,
 }
 
+// Must match BillboardBlendMode in libhelio/src/frame.rs.
+const BLEND_ALPHA:    u32 = 0u;
+const BLEND_ADDITIVE: u32 = 1u;
+const BLEND_MULTIPLY: u32 = 2u;
+const BLEND_OPAQUE:   u32 = 3u;
+
 // ── Vertex shader ───────────────────────────────────────────────────────────
 
 @vertex
@@ -91,6 +98,7 @@ fn vs_main(quad: QuadVertex, inst: BillboardInstance) -> VertexOut {
     out.clip_pos = camera.view_proj * vec4<f32>(final_pos, 1.0); // view_proj at offset 128
     out.uv       = quad.uv;
     out.color    = inst.color;
+    out.blend_mode = u32(max(round(inst.scale_flags.w), 0.0));
     return out;
 }
 
@@ -103,5 +111,13 @@ fn fs_main(in: VertexOut) -> @location(0) vec4<f32> {
     let rgb   = tex_color.rgb * in.color.rgb;
     let alpha = tex_color.a   * in.color.a;
     if alpha < 0.01 { discard; }
-    return vec4<f32>(rgb, alpha);
+    if in.blend_mode == BLEND_MULTIPLY {
+        // A ratio, not radiance: no pre-exposure. Fade towards white (no-op) by alpha.
+        return vec4<f32>(mix(vec3<f32>(1.0), rgb, alpha), alpha);
+    }
+    if in.blend_mode == BLEND_OPAQUE {
+        return vec4<f32>(rgb * camera.jitter_frame.w, 1.0);
+    }
+    // Alpha and additive: the pipeline blend state weights rgb by alpha.
+    return vec4<f32>(rgb * camera.jitter_frame.w, alpha);
 }
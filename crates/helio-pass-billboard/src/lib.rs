@@ -1,27 +1,82 @@
 //! Billboard pass — camera-facing instanced quads.
 //!
-//! Each billboard is a 6-vertex quad (2 triangles) rendered with alpha blending.
-//! Instance buffer is GPU-side; CPU uploads billboard data once per frame and issues
-//! a single instanced draw call. O(1) CPU.
+//! Each billboard is a 6-vertex quad (2 triangles). Every instance carries a
+//! [`BillboardBlendMode`]; instances are grouped by mode when they change and
+//! each non-empty group is one instanced draw with that mode's pipeline, so a
+//! frame costs at most four draw calls.
+
+use std::ops::Range;
 
 use bytemuck::{Pod, Zeroable};
 use helio_core::graph::ResourceBuilder;
 use helio_core::{PassContext, PrepareContext, RenderPass, Result as HelioResult};
+pub use libhelio::BillboardBlendMode;
 
 const MAX_BILLBOARDS: u32 = 65536;
 
+/// Leave destination alpha untouched (additive and multiply modes).
+const KEEP_DST_ALPHA: wgpu::BlendComponent = wgpu::BlendComponent {
+    src_factor: wgpu::BlendFactor::Zero,
+    dst_factor: wgpu::BlendFactor::One,
+    operation: wgpu::BlendOperation::Add,
+};
+
 /// Per-billboard instance data uploaded to the GPU.
 #[repr(C)]
 #[derive(Clone, Copy, Pod, Zeroable)]
 pub struct BillboardInstance {
     /// World-space position (xyz) + unused pad (w).
     pub world_pos: [f32; 4],
-    /// Scale (xy), screen_scale flag as f32 (z), unused (w).
+    /// Scale (xy), screen_scale flag as f32 (z), [`BillboardBlendMode`] as f32 (w).
     pub scale_flags: [f32; 4],
     /// RGBA tint color.
     pub color: [f32; 4],
 }
 
+impl BillboardInstance {
+    pub fn blend_mode(&self) -> BillboardBlendMode {
+        BillboardBlendMode::from_f32(self.scale_flags[3])
+    }
+
+    pub fn with_blend_mode(mut self, mode: BillboardBlendMode) -> Self {
+        self.scale_flags[3] = mode.as_f32();
+        self
+    }
+}
+
+/// Stable-partition `instances` into `out` by blend mode, in
+/// [`BillboardBlendMode::DRAW_ORDER`], returning the instance range of each mode.
+fn group_by_blend_mode(
+    instances: &[BillboardInstance],
+    out: &mut Vec<BillboardInstance>,
+) -> [Range<u32>; 4] {
+    let slot = |mode| {
+        BillboardBlendMode::DRAW_ORDER
+            .iter()
+            .position(|&m| m == mode)
+            .unwrap_or(0)
+    };
+    let mut counts = [0u32; 4];
+    for inst in instances {
+        counts[slot(inst.blend_mode())] += 1;
+    }
+    let mut batches: [Range<u32>; 4] = Default::default();
+    let mut start = 0;
+    for (batch, count) in batches.iter_mut().zip(counts) {
+        *batch = start..start + count;
+        start += count;
+    }
+    out.clear();
+    out.resize(instances.len(), BillboardInstance::zeroed());
+    let mut cursor = batches.clone().map(|batch| batch.start as usize);
+    for inst in instances {
+        let s = slot(inst.blend_mode());
+        out[cursor[s]] = *inst;
+        cursor[s] += 1;
+    }
+    batches
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Pod, Zeroable)]
 struct BillboardGlobals {
@@ -32,7 +87,11 @@ struct BillboardGlobals {
 }
 
 pub struct BillboardPass {
-    pipeline: wgpu::RenderPipeline,
+    /// Indexed like [`BillboardBlendMode::DRAW_ORDER`].
+    pipelines: [wgpu::RenderPipeline; 4],
+    /// Instance range of each blend mode in `instance_buf`, same indexing.
+    batches: [Range<u32>; 4],
+    sorted_scratch: Vec<BillboardInstance>,
     #[allow(dead_code)]
     bgl_0: wgpu::BindGroupLayout,
     #[allow(dead_code)]
@@ -48,9 +107,7 @@ pub struct BillboardPass {
     occluded_by_geometry: bool,
     #[allow(dead_code)]
     white_texture: wgpu::Texture,
-    #[allow(dead_code)]
     white_view: wgpu::TextureView,
-    #[allow(dead_code)]
     sampler: wgpu::Sampler,
 }
 
@@ -242,93 +299,14 @@ impl BillboardPass {
             mapped_at_creation: false,
         });
 
-        // ── Render pipeline ───────────────────────────────────────────────────
-        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Billboard Pipeline"),
-            layout: Some(&pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &shader,
-                entry_point: Some("vs_main"),
-                compilation_options: Default::default(),
-                buffers: &[
-                    // Slot 0: per-vertex quad data  (stride 16)
-                    Some(wgpu::VertexBufferLayout {
-                        array_stride: 16,
-                        step_mode: wgpu::VertexStepMode::Vertex,
-                        attributes: &[
-                            wgpu::VertexAttribute {
-                                format: wgpu::VertexFormat::Float32x2,
-                                offset: 0,
-                                shader_location: 0,
-                            },
-                            wgpu::VertexAttribute {
-                                format: wgpu::VertexFormat::Float32x2,
-                                offset: 8,
-                                shader_location: 1,
-                            },
-                        ],
-                    }),
-                    // Slot 1: per-instance billboard data  (stride 48)
-                    Some(wgpu::VertexBufferLayout {
-                        array_stride: 48,
-                        step_mode: wgpu::VertexStepMode::Instance,
-                        attributes: &[
-                            wgpu::VertexAttribute {
-                                format: wgpu::VertexFormat::Float32x4,
-                                offset: 0,
-                                shader_location: 2,
-                            },
-                            wgpu::VertexAttribute {
-                                format: wgpu::VertexFormat::Float32x4,
-                                offset: 16,
-                                shader_location: 3,
-                            },
-                            wgpu::VertexAttribute {
-                                format: wgpu::VertexFormat::Float32x4,
-                                offset: 32,
-                                shader_location: 4,
-                            },
-                        ],
-                    }),
-                ],
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &shader,
-                entry_point: Some("fs_main"),
-                compilation_options: Default::default(),
-                targets: &[Some(wgpu::ColorTargetState {
-                    format: target_format,
-                    blend: Some(wgpu::BlendState {
-                        color: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                        alpha: wgpu::BlendComponent::OVER,
-                    }),
-                    write_mask: wgpu::ColorWrites::ALL,
-                })],
-            }),
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
-                cull_mode: None, // billboards are always camera-facing
-                ..Default::default()
-            },
-            // Depth read-only: billboards depth-test against scene geometry but don't write depth.
-            depth_stencil: Some(wgpu::DepthStencilState {
-                format: wgpu::TextureFormat::Depth32Float,
-                depth_write_enabled: Some(false),
-                depth_compare: Some(wgpu::CompareFunction::LessEqual),
-                stencil: wgpu::StencilState::default(),
-                bias: wgpu::DepthBiasState::default(),
-            }),
-            multisample: wgpu::MultisampleState::default(),
-            multiview_mask: None,
-            cache: None,
-        });
+        // ── Render pipelines, one per blend mode ─────────────────────────────
+        let pipelines = BillboardBlendMode::DRAW_ORDER
+            .map(|mode| create_pipeline(device, &pipeline_layout, &shader, target_format, mode));
 
         Self {
-            pipeline,
+            pipelines,
+            batches: Default::default(),
+            sorted_scratch: Vec::new(),
             bgl_0,
             bgl_1,
             bind_group_0,
@@ -345,6 +323,26 @@ impl BillboardPass {
         }
     }
 
+    /// Replace the sprite sampler (clamp-to-edge, linear by default), e.g.
+    /// nearest filtering for pixel-art icons or repeat for tiled sprites.
+    pub fn set_sampler(&mut self, device: &wgpu::Device, desc: &wgpu::SamplerDescriptor) {
+        self.sampler = device.create_sampler(desc);
+        self.bind_group_1 = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Billboard BG1"),
+            layout: &self.bgl_1,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&self.white_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&self.sampler),
+                },
+            ],
+        });
+    }
+
     /// Upload billboard instances. Call once per frame (or when the set changes).
     pub fn set_occluded_by_geometry(&mut self, value: bool) {
         self.occluded_by_geometry = value;
@@ -352,12 +350,13 @@ impl BillboardPass {
 
     pub fn update_instances(&mut self, queue: &wgpu::Queue, instances: &[BillboardInstance]) {
         let count = instances.len().min(MAX_BILLBOARDS as usize);
+        self.batches = group_by_blend_mode(&instances[..count], &mut self.sorted_scratch);
         if count > 0 {
             helio_core::upload::write_buffer(
                 queue,
                 &self.instance_buf,
                 0,
-                bytemuck::cast_slice(&instances[..count]),
+                bytemuck::cast_slice(&self.sorted_scratch),
             );
         }
         self.instance_count = count as u32;
@@ -465,16 +464,22 @@ impl RenderPass for BillboardPass {
         // Upload billboard instances from the high-level renderer's frame data.
         if let Some(data) = ctx.frame_resources.billboards.get() {
             if data.generation != self.uploaded_generation {
-                let max_bytes = MAX_BILLBOARDS as usize * std::mem::size_of::<BillboardInstance>();
-                let upload_bytes = data.instances.len().min(max_bytes);
-                if upload_bytes > 0 {
-                    ctx.write_buffer(&self.instance_buf, 0, &data.instances[..upload_bytes]);
+                let stride = std::mem::size_of::<BillboardInstance>();
+                let count = (data.count.min(MAX_BILLBOARDS) as usize).min(data.instances.len() / stride);
+                let instances: Vec<BillboardInstance> = data.instances[..count * stride]
+                    .chunks_exact(stride)
+                    .map(bytemuck::pod_read_unaligned)
+                    .collect();
+                self.batches = group_by_blend_mode(&instances, &mut self.sorted_scratch);
+                if count > 0 {
+                    ctx.write_buffer(&self.instance_buf, 0, bytemuck::cast_slice(&self.sorted_scratch));
                 }
                 self.uploaded_generation = data.generation;
+                self.instance_count = count as u32;
             }
-            self.instance_count = data.count.min(MAX_BILLBOARDS);
         } else {
             self.instance_count = 0;
+            self.uploaded_generation = u64::MAX;
         }
         let globals = BillboardGlobals {
             frame: ctx.frame_num as u32,
@@ -542,12 +547,183 @@ impl RenderPass for BillboardPass {
             return Ok(());
         }
         let rp = unsafe { &mut *ctx.active_render_pass_ptr().unwrap() };
-        rp.set_pipeline(&self.pipeline);
         rp.set_bind_group(0, &self.bind_group_0, &[]);
         rp.set_bind_group(1, &self.bind_group_1, &[]);
         rp.set_vertex_buffer(0, self.quad_vertex_buf.slice(..));
         rp.set_vertex_buffer(1, self.instance_buf.slice(..));
-        rp.draw(0..6, 0..self.instance_count);
+        for (pipeline, batch) in self.pipelines.iter().zip(&self.batches) {
+            if !batch.is_empty() {
+                rp.set_pipeline(pipeline);
+                rp.draw(0..6, batch.clone());
+            }
+        }
         Ok(())
     }
 }
+
+fn create_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    shader: &wgpu::ShaderModule,
+    target_format: wgpu::TextureFormat,
+    mode: BillboardBlendMode,
+) -> wgpu::RenderPipeline {
+    let (label, blend) = match mode {
+        BillboardBlendMode::Opaque => ("Billboard Pipeline (Opaque)", None),
+        BillboardBlendMode::Alpha => (
+            "Billboard Pipeline (Alpha)",
+            Some(wgpu::BlendState {
+                color: wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::SrcAlpha,
+                    dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                    operation: wgpu::BlendOperation::Add,
+                },
+                alpha: wgpu::BlendComponent::OVER,
+            }),
+        ),
+        // dst += src.rgb * src.a; destination alpha is left alone.
+        BillboardBlendMode::Additive => (
+            "Billboard Pipeline (Additive)",
+            Some(wgpu::BlendState {
+                color: wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::SrcAlpha,
+                    dst_factor: wgpu::BlendFactor::One,
+                    operation: wgpu::BlendOperation::Add,
+                },
+                alpha: KEEP_DST_ALPHA,
+            }),
+        ),
+        // dst *= src.rgb; the shader fades src.rgb towards white by alpha.
+        BillboardBlendMode::Multiply => (
+            "Billboard Pipeline (Multiply)",
+            Some(wgpu::BlendState {
+                color: wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::Dst,
+                    dst_factor: wgpu::BlendFactor::Zero,
+                    operation: wgpu::BlendOperation::Add,
+                },
+                alpha: KEEP_DST_ALPHA,
+            }),
+        ),
+    };
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some(label),
+        layout: Some(layout),
+        vertex: wgpu::VertexState {
+            module: shader,
+            entry_point: Some("vs_main"),
+            compilation_options: Default::default(),
+            buffers: &[
+                // Slot 0: per-vertex quad data  (stride 16)
+                Some(wgpu::VertexBufferLayout {
+                    array_stride: 16,
+                    step_mode: wgpu::VertexStepMode::Vertex,
+                    attributes: &[
+                        wgpu::VertexAttribute {
+                            format: wgpu::VertexFormat::Float32x2,
+                            offset: 0,
+                            shader_location: 0,
+                        },
+                        wgpu::VertexAttribute {
+                            format: wgpu::VertexFormat::Float32x2,
+                            offset: 8,
+                            shader_location: 1,
+                        },
+                    ],
+                }),
+                // Slot 1: per-instance billboard data  (stride 48)
+                Some(wgpu::VertexBufferLayout {
+                    array_stride: 48,
+                    step_mode: wgpu::VertexStepMode::Instance,
+                    attributes: &[
+                        wgpu::VertexAttribute {
+                            format: wgpu::VertexFormat::Float32x4,
+                            offset: 0,
+                            shader_location: 2,
+                        },
+                        wgpu::VertexAttribute {
+                            format: wgpu::VertexFormat::Float32x4,
+                            offset: 16,
+                            shader_location: 3,
+                        },
+                        wgpu::VertexAttribute {
+                            format: wgpu::VertexFormat::Float32x4,
+                            offset: 32,
+                            shader_location: 4,
+                        },
+                    ],
+                }),
+            ],
+        },
+        fragment: Some(wgpu::FragmentState {
+            module: shader,
+            entry_point: Some("fs_main"),
+            compilation_options: Default::default(),
+            targets: &[Some(wgpu::ColorTargetState {
+                format: target_format,
+                blend,
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+        }),
+        primitive: wgpu::PrimitiveState {
+            topology: wgpu::PrimitiveTopology::TriangleList,
+            cull_mode: None, // billboards are always camera-facing
+            ..Default::default()
+        },
+        // Blended billboards depth-test against scene geometry but don't
+        // write depth; opaque ones write it so later billboards sort against them.
+        depth_stencil: Some(wgpu::DepthStencilState {
+            format: wgpu::TextureFormat::Depth32Float,
+            depth_write_enabled: Some(mode == BillboardBlendMode::Opaque),
+            depth_compare: Some(wgpu::CompareFunction::LessEqual),
+            stencil: wgpu::StencilState::default(),
+            bias: wgpu::DepthBiasState::default(),
+        }),
+        multisample: wgpu::MultisampleState::default(),
+        multiview_mask: None,
+        cache: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inst(id: f32, mode: BillboardBlendMode) -> BillboardInstance {
+        BillboardInstance {
+            world_pos: [id, 0.0, 0.0, 0.0],
+            scale_flags: [1.0, 1.0, 0.0, 0.0],
+            color: [1.0; 4],
+        }
+        .with_blend_mode(mode)
+    }
+
+    #[test]
+    fn grouping_is_stable_and_in_draw_order() {
+        use BillboardBlendMode::*;
+        let input = [
+            inst(0.0, Additive),
+            inst(1.0, Alpha),
+            inst(2.0, Opaque),
+            inst(3.0, Additive),
+            inst(4.0, Alpha),
+        ];
+        let mut out = Vec::new();
+        let batches = group_by_blend_mode(&input, &mut out);
+        // Opaque, Multiply, Alpha, Additive
+        assert_eq!(batches, [0..1, 1..1, 1..3, 3..5]);
+        let ids: Vec<f32> = out.iter().map(|i| i.world_pos[0]).collect();
+        assert_eq!(ids, [2.0, 1.0, 4.0, 0.0, 3.0]);
+    }
+
+    #[test]
+    fn zeroed_mode_field_is_alpha() {
+        let legacy = BillboardInstance {
+            world_pos: [0.0; 4],
+            scale_flags: [0.25, 0.25, 0.0, 0.0],
+            color: [1.0; 4],
+        };
+        assert_eq!(legacy.blend_mode(), BillboardBlendMode::Alpha);
+        assert_eq!(BillboardBlendMode::from_f32(7.0), BillboardBlendMode::Alpha);
+    }
+}
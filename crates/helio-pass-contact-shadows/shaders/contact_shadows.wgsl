@@ -0,0 +1,110 @@
+// Screen-space contact shadows.
+//
+// Fullscreen pass over the scene depth buffer.  For every pixel the world
+// position is reconstructed and a short ray is marched toward one light; each
+// step is projected back to the screen and compared against the depth buffer.
+// A step that lands behind the visible surface (by less than `thickness`) is
+// occluded.  Output is R8Unorm: 1 = lit, 0 = in contact shadow.  The deferred
+// lighting pass multiplies it into that light's shadow factor.
+
+struct Camera {
+    view:           mat4x4<f32>,
+    proj:           mat4x4<f32>,
+    view_proj:      mat4x4<f32>,
+    view_proj_inv:  mat4x4<f32>,
+    position_near:  vec4<f32>,
+    forward_far:    vec4<f32>,
+    jitter_frame:   vec4<f32>,
+    prev_view_proj: mat4x4<f32>,
+}
+
+// Matches ContactShadowUniform in lib.rs (32 bytes).
+struct Params {
+    // w = 0: xyz is the unit direction toward a directional light.
+    // w = 1: xyz is the world position of a point/spot light.
+    light:      vec4<f32>,
+    ray_length: f32,
+    thickness:  f32,
+    steps:      u32,
+    enabled:    u32,
+}
+
+@group(0) @binding(0) var<uniform> camera: Camera;
+@group(0) @binding(1) var<uniform> params: Params;
+@group(0) @binding(2) var depth_tex: texture_depth_2d;
+
+struct VSOut {
+    @builtin(position) clip_pos: vec4<f32>,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vi: u32) -> VSOut {
+    var pos = array<vec2<f32>, 3>(
+        vec2<f32>(-1.0, -1.0),
+        vec2<f32>( 3.0, -1.0),
+        vec2<f32>(-1.0,  3.0),
+    );
+    var out: VSOut;
+    out.clip_pos = vec4<f32>(pos[vi], 0.0, 1.0);
+    return out;
+}
+
+fn world_from_depth(pixel: vec2<f32>, depth: f32, size: vec2<f32>) -> vec3<f32> {
+    let uv  = pixel / size;
+    let ndc = vec2<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0);
+    let h   = camera.view_proj_inv * vec4<f32>(ndc, depth, 1.0);
+    return h.xyz / h.w;
+}
+
+// Distance in front of the camera (view space looks down -Z).
+fn view_depth(world: vec3<f32>) -> f32 {
+    return -(camera.view * vec4<f32>(world, 1.0)).z;
+}
+
+// Per-pixel step offset; varies with the frame so TAA resolves the banding.
+fn interleaved_gradient_noise(pixel: vec2<f32>) -> f32 {
+    return fract(52.9829189 * fract(dot(pixel, vec2<f32>(0.06711056, 0.00583715))));
+}
+
+@fragment
+fn fs_main(in: VSOut) -> @location(0) vec4<f32> {
+    if params.enabled == 0u { return vec4<f32>(1.0); }
+
+    let size  = vec2<f32>(textureDimensions(depth_tex));
+    let depth = textureLoad(depth_tex, vec2<i32>(in.clip_pos.xy), 0);
+    if depth >= 1.0 { return vec4<f32>(1.0); }
+
+    let world = world_from_depth(in.clip_pos.xy, depth, size);
+    var to_light = params.light.xyz;
+    var ray_length = params.ray_length;
+    if params.light.w != 0.0 {
+        let d = params.light.xyz - world;
+        let dist = length(d);
+        to_light = d / max(dist, 1e-4);
+        ray_length = min(ray_length, dist);
+    }
+
+    let noise = interleaved_gradient_noise(in.clip_pos.xy + camera.jitter_frame.z * 5.588238);
+    let steps = f32(params.steps);
+    for (var i = 0u; i < params.steps; i++) {
+        let t = (f32(i) + noise) / steps;
+        let p = world + to_light * (ray_length * t);
+        let clip = camera.view_proj * vec4<f32>(p, 1.0);
+        if clip.w <= 0.0 { break; }
+        let ndc = clip.xy / clip.w;
+        let uv  = vec2<f32>(ndc.x * 0.5 + 0.5, 0.5 - ndc.y * 0.5);
+        if any(uv < vec2<f32>(0.0)) || any(uv >= vec2<f32>(1.0)) { break; }
+
+        let texel = vec2<i32>(uv * size);
+        let scene_depth = textureLoad(depth_tex, texel, 0);
+        let scene_z = view_depth(world_from_depth(vec2<f32>(texel) + 0.5, scene_depth, size));
+        let ray_z = view_depth(p);
+        // Relative bias keeps the receiving surface from shadowing itself.
+        let delta = ray_z - scene_z;
+        if delta > ray_z * 0.002 && delta < params.thickness {
+            // Fade hits near the end of the ray so its cut-off does not show.
+            return vec4<f32>(smoothstep(0.75, 1.0, t));
+        }
+    }
+    return vec4<f32>(1.0);
+}
@@ -0,0 +1,309 @@
+//! Screen-space contact shadow pass.
+//!
+//! Shadow maps lose small-scale occlusion — feet on the ground, a cup on a
+//! table — to their texel size and depth bias. This pass ray-marches a short
+//! distance from every pixel toward one light against the depth buffer and
+//! writes an R8Unorm `contact_shadows` mask (1 = lit). `DeferredLightPass`
+//! multiplies the mask into the shadow factor of that light only.
+//!
+//! Add it after the pass that writes scene depth and before
+//! `DeferredLightPass`. O(1) CPU: single fullscreen draw.
+
+use bytemuck::{Pod, Zeroable};
+use helio_core::graph::{ResourceBuilder, ResourceSize};
+use helio_core::{PassContext, PrepareContext, RenderPass, Result as HelioResult};
+
+/// Upper bound on [`ContactShadowsPass::set_steps`].
+pub const MAX_STEPS: u32 = 64;
+
+/// Light whose direct contribution the contact shadows darken.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ContactShadowLight {
+    /// The first directional light in the scene (usually the sun).
+    #[default]
+    FirstDirectional,
+    /// A specific index into the scene's GPU light buffer.
+    Index(u32),
+}
+
+/// Parameters matching contact_shadows.wgsl `Params` (32 bytes).
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct ContactShadowUniform {
+    light: [f32; 4],
+    ray_length: f32,
+    thickness: f32,
+    steps: u32,
+    enabled: u32,
+}
+
+pub struct ContactShadowsPass {
+    pipeline: wgpu::RenderPipeline,
+    bgl: wgpu::BindGroupLayout,
+    bind_group: Option<wgpu::BindGroup>,
+    bind_group_key: Option<(usize, usize)>,
+    uniform_buf: wgpu::Buffer,
+    light: ContactShadowLight,
+    ray_length: f32,
+    thickness: f32,
+    steps: u32,
+    /// Light buffer index resolved in `prepare`, `u32::MAX` when none matched.
+    light_index: u32,
+}
+
+impl ContactShadowsPass {
+    pub fn new(device: &wgpu::Device) -> Self {
+        let shader = helio_core::shader::module(
+            device,
+            "Contact Shadows Shader",
+            include_str!("../shaders/contact_shadows.wgsl"),
+        );
+
+        let uniform_buf = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Contact Shadows Uniform"),
+            size: std::mem::size_of::<ContactShadowUniform>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        // Group 0: camera, parameters, scene depth
+        let bgl = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Contact Shadows BGL"),
+            entries: &[
+                uniform_entry(0),
+                uniform_entry(1),
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Depth,
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Contact Shadows PL"),
+            bind_group_layouts: &[Some(&bgl)],
+            immediate_size: 0,
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Contact Shadows Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                compilation_options: Default::default(),
+                buffers: &[],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                compilation_options: Default::default(),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: wgpu::TextureFormat::R8Unorm,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview_mask: None,
+            cache: None,
+        });
+
+        Self {
+            pipeline,
+            bgl,
+            bind_group: None,
+            bind_group_key: None,
+            uniform_buf,
+            light: ContactShadowLight::default(),
+            ray_length: 0.5,
+            thickness: 0.1,
+            steps: 16,
+            light_index: u32::MAX,
+        }
+    }
+
+    /// Choose the light to trace toward.
+    pub fn set_light(&mut self, light: ContactShadowLight) {
+        self.light = light;
+    }
+
+    pub fn light(&self) -> ContactShadowLight {
+        self.light
+    }
+
+    /// World-space length of the traced ray (default 0.5). Keep it short:
+    /// longer rays miss more occluders between steps and overlap what the
+    /// shadow map already covers.
+    pub fn set_ray_length(&mut self, length: f32) {
+        self.ray_length = length.max(0.0);
+    }
+
+    pub fn ray_length(&self) -> f32 {
+        self.ray_length
+    }
+
+    /// March steps per pixel (clamped to 1..=[`MAX_STEPS`], default 16).
+    pub fn set_steps(&mut self, steps: u32) {
+        self.steps = steps.clamp(1, MAX_STEPS);
+    }
+
+    pub fn steps(&self) -> u32 {
+        self.steps
+    }
+
+    /// Assumed thickness of depth-buffer surfaces in world units (default
+    /// 0.1). A ray step further behind the surface than this passes behind
+    /// the object instead of hitting it.
+    pub fn set_thickness(&mut self, thickness: f32) {
+        self.thickness = thickness.max(0.0);
+    }
+
+    pub fn thickness(&self) -> f32 {
+        self.thickness
+    }
+}
+
+impl RenderPass for ContactShadowsPass {
+    fn name(&self) -> &'static str {
+        "ContactShadows"
+    }
+
+    fn reads(&self) -> &'static [&'static str] {
+        &["depth"]
+    }
+
+    fn declare_resources(&self, builder: &mut ResourceBuilder) {
+        builder.write_color_raw("contact_shadows", wgpu::TextureFormat::R8Unorm, ResourceSize::MatchSurface);
+    }
+
+    fn writes(&self) -> &'static [&'static str] {
+        &["contact_shadows"]
+    }
+
+    fn publish<'a>(&'a self, frame: &mut libhelio::FrameResources<'a>) {
+        frame.contact_shadow_light = self.light_index;
+    }
+
+    fn render_pass_descriptor<'a>(
+        &'a self,
+        _target: &'a wgpu::TextureView,
+        _depth: &'a wgpu::TextureView,
+        resources: &'a libhelio::FrameResources<'a>,
+    ) -> Option<wgpu::RenderPassDescriptor<'a>> {
+        let mask_view = resources.contact_shadows.read("ContactShadows")?;
+        let color_attachments: &'a [Option<wgpu::RenderPassColorAttachment<'a>>] = Box::leak(Box::new([
+            Some(wgpu::RenderPassColorAttachment {
+                view: mask_view,
+                resolve_target: None,
+                depth_slice: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::WHITE),
+                    store: wgpu::StoreOp::Store,
+                },
+            }),
+        ]));
+        Some(wgpu::RenderPassDescriptor {
+            label: Some("ContactShadows"),
+            color_attachments,
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+            multiview_mask: None,
+        })
+    }
+
+    fn prepare(&mut self, ctx: &PrepareContext) -> HelioResult<()> {
+        let lights = ctx.scene.lights.as_slice();
+        let index = match self.light {
+            ContactShadowLight::FirstDirectional => lights
+                .iter()
+                .position(|l| l.light_type == libhelio::LightType::Directional as u32),
+            ContactShadowLight::Index(i) => ((i as usize) < lights.len()).then_some(i as usize),
+        };
+        self.light_index = index.map_or(u32::MAX, |i| i as u32);
+
+        let light = match index {
+            Some(i) => {
+                let l = &lights[i];
+                if l.light_type == libhelio::LightType::Directional as u32 {
+                    let d = l.direction_outer;
+                    [-d[0], -d[1], -d[2], 0.0]
+                } else {
+                    let p = l.position_range;
+                    [p[0], p[1], p[2], 1.0]
+                }
+            }
+            None => [0.0; 4],
+        };
+        let uniform = ContactShadowUniform {
+            light,
+            ray_length: self.ray_length,
+            thickness: self.thickness,
+            steps: self.steps,
+            enabled: (index.is_some() && self.ray_length > 0.0) as u32,
+        };
+        ctx.write_buffer(&self.uniform_buf, 0, bytemuck::bytes_of(&uniform));
+        Ok(())
+    }
+
+    fn execute(&mut self, ctx: &mut PassContext) -> HelioResult<()> {
+        if self.light_index == u32::MAX {
+            // Cleared to white by the render pass: nothing is shadowed.
+            return Ok(());
+        }
+
+        let key = (ctx.depth as *const _ as usize, ctx.scene.camera as *const _ as usize);
+        if self.bind_group_key != Some(key) {
+            self.bind_group = Some(ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Contact Shadows BG"),
+                layout: &self.bgl,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: ctx.scene.camera.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: self.uniform_buf.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: wgpu::BindingResource::TextureView(ctx.depth),
+                    },
+                ],
+            }));
+            self.bind_group_key = Some(key);
+        }
+
+        let rp = unsafe { &mut *ctx.active_render_pass_ptr().unwrap() };
+        rp.set_pipeline(&self.pipeline);
+        rp.set_bind_group(0, self.bind_group.as_ref(), &[]);
+        rp.draw(0..3, 0..1);
+        Ok(())
+    }
+}
+
+fn uniform_entry(binding: u32) -> wgpu::BindGroupLayoutEntry {
+    wgpu::BindGroupLayoutEntry {
+        binding,
+        visibility: wgpu::ShaderStages::FRAGMENT,
+        ty: wgpu::BindingType::Buffer {
+            ty: wgpu::BufferBindingType::Uniform,
+            has_dynamic_offset: false,
+            min_binding_size: None,
+        },
+        count: None,
+    }
+}
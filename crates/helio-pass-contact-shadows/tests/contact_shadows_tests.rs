@@ -0,0 +1,36 @@
+// Tests for helio-pass-contact-shadows: uniform layout and public API.
+// All tests are pure Rust — no GPU device required.
+
+use helio_pass_contact_shadows::{ContactShadowLight, ContactShadowsPass, MAX_STEPS};
+use std::mem;
+
+/// Mirror of the private ContactShadowUniform (contact_shadows.wgsl `Params`).
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct ContactShadowUniform {
+    light: [f32; 4],
+    ray_length: f32,
+    thickness: f32,
+    steps: u32,
+    enabled: u32,
+}
+
+#[test]
+fn uniform_is_32_bytes_and_16_byte_aligned() {
+    assert_eq!(mem::size_of::<ContactShadowUniform>(), 32);
+    assert_eq!(mem::size_of::<ContactShadowUniform>() % 16, 0);
+}
+
+#[test]
+fn default_light_is_first_directional() {
+    assert_eq!(ContactShadowLight::default(), ContactShadowLight::FirstDirectional);
+    assert_ne!(ContactShadowLight::Index(0), ContactShadowLight::FirstDirectional);
+}
+
+// The default step count (16) must be reachable, and the march stays short.
+const _: () = assert!(MAX_STEPS >= 16 && MAX_STEPS <= 128);
+
+#[test]
+fn pass_type_is_publicly_accessible() {
+    let _: std::marker::PhantomData<ContactShadowsPass> = std::marker::PhantomData;
+}
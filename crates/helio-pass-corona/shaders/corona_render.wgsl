@@ -115,5 +115,5 @@ fn fs_main(in: VOut) -> @location(0) vec4<f32> {
     let tex = textureSample(particle_tex, particle_sampler, atlas_uv);
     let alpha = tex.a * in.color.a;
     if alpha < 0.005 { discard; }
-    return vec4<f32>(in.color.rgb * tex.rgb, alpha);
+    return vec4<f32>(in.color.rgb * tex.rgb * camera.jitter_frame.w, alpha);
 }
@@ -821,7 +821,7 @@ impl RenderPass for CoronaPass {
             delta_time: ctx.delta_time,
             total_particles: self.max_particles,
             emitter_count: self.emitter_count,
-            frame_count: ctx.frame_num as u32,
+            frame_count: ctx.sampling_frame(),
             sort_k: 0,
             sort_j: 0,
             sort_lo: 0,
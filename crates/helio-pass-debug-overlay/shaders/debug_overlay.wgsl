@@ -125,13 +125,11 @@ fn render_pie(px: f32, py: f32) -> vec4f {
     return pie_data[slice_idx + count];
 }
 
-@fragment
-fn fs_main(@builtin(position) pos: vec4f) -> @location(0) vec4f {
-    let px = pos.x;
-    let py = pos.y;
+// Overlay colour at a pixel, straight alpha; a == 0 where nothing is drawn.
+fn overlay_color(px: f32, py: f32) -> vec4f {
     let ux = u32(px);
     let uy = u32(py);
-    if ux >= params.screen_w || uy >= params.screen_h { discard; }
+    if ux >= params.screen_w || uy >= params.screen_h { return vec4f(0.0); }
 
     let line_color = render_line(px, py);
     if line_color.a > 0.0 { return line_color; }
@@ -150,7 +148,16 @@ fn fs_main(@builtin(position) pos: vec4f) -> @location(0) vec4f {
     let sm = render_small(ux, uy);
     if sm.a > 0.0 { return sm; }
 
-    discard;
+    return vec4f(0.0);
+}
+
+// Writes into the overlay cache with blending disabled, so every pixel inside
+// the scissor rect is replaced — including ones that became transparent.
+// Output is premultiplied for the composite pass.
+@fragment
+fn fs_cache(@builtin(position) pos: vec4f) -> @location(0) vec4f {
+    let c = overlay_color(pos.x, pos.y);
+    return vec4f(c.rgb * c.a, c.a);
 }
 
 struct VertexOutput {
@@ -0,0 +1,27 @@
+// Blends the cached overlay (premultiplied alpha) over the final target.
+
+@group(0) @binding(0) var overlay_cache: texture_2d<f32>;
+
+struct VertexOutput {
+    @builtin(position) pos: vec4f,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vi: u32) -> VertexOutput {
+    var pos = array(
+        vec2f(-1.0, -1.0),
+        vec2f( 3.0, -1.0),
+        vec2f(-1.0,  3.0),
+    );
+    return VertexOutput(vec4f(pos[vi], 0.0, 1.0));
+}
+
+@fragment
+fn fs_main(@builtin(position) pos: vec4f) -> @location(0) vec4f {
+    let dims = textureDimensions(overlay_cache);
+    let p = vec2<u32>(pos.xy);
+    if p.x >= dims.x || p.y >= dims.y { discard; }
+    let c = textureLoad(overlay_cache, p, 0);
+    if c.a <= 0.0 { discard; }
+    return c;
+}
@@ -0,0 +1,82 @@
+// SDF text: one instanced quad per glyph, drawn over the shaded overlay cache
+// (premultiplied alpha, same scissor as the shading pass).
+
+struct SdfParams {
+    screen_w: u32,
+    screen_h: u32,
+    _pad0: u32,
+    _pad1: u32,
+}
+
+struct Glyph {
+    origin: vec2f,
+    size: f32,
+    code: u32,
+    color: vec4f,
+    outline_color: vec4f,
+    outline_width: f32,
+    glow: f32,
+    _pad: vec2f,
+}
+
+@group(0) @binding(0) var<uniform> params: SdfParams;
+@group(0) @binding(1) var<storage> glyphs: array<Glyph>;
+@group(0) @binding(2) var sdf_atlas: texture_2d<f32>;
+@group(0) @binding(3) var sdf_sampler: sampler;
+
+// Must match sdf.rs: 16×6 cells of 8×8 font texels plus a 2-texel margin.
+const ATLAS_COLS: u32 = 16u;
+const ATLAS_ROWS: f32 = 6.0;
+const GLYPH_TEXELS: f32 = 8.0;
+const MARGIN_TEXELS: f32 = 2.0;
+const CELL_TEXELS: f32 = 12.0;
+
+struct VertexOutput {
+    @builtin(position) pos: vec4f,
+    @location(0) uv: vec2f,
+    @location(1) @interpolate(flat) glyph: u32,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vi: u32, @builtin(instance_index) ii: u32) -> VertexOutput {
+    var corners = array(
+        vec2f(0.0, 0.0),
+        vec2f(1.0, 0.0),
+        vec2f(0.0, 1.0),
+        vec2f(0.0, 1.0),
+        vec2f(1.0, 0.0),
+        vec2f(1.0, 1.0),
+    );
+    let g = glyphs[ii];
+    let c = corners[vi];
+    let texel = g.size / GLYPH_TEXELS;
+    let p = g.origin - vec2f(MARGIN_TEXELS * texel) + c * CELL_TEXELS * texel;
+    let screen = vec2f(f32(params.screen_w), f32(params.screen_h));
+    let ndc = vec2f(p.x / screen.x * 2.0 - 1.0, 1.0 - p.y / screen.y * 2.0);
+
+    let idx = g.code - 32u;
+    let cell = vec2f(f32(idx % ATLAS_COLS), f32(idx / ATLAS_COLS));
+    let uv = (cell + c) / vec2f(f32(ATLAS_COLS), ATLAS_ROWS);
+    return VertexOutput(vec4f(ndc, 0.0, 1.0), uv, ii);
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4f {
+    let d = textureSample(sdf_atlas, sdf_sampler, in.uv).r;
+    // Distance-field units per screen pixel: keeps the edge ~1 px wide at any size.
+    let w = max(fwidth(d), 1e-4);
+    let g = glyphs[in.glyph];
+
+    let fill = smoothstep(0.5 - 0.5 * w, 0.5 + 0.5 * w, d);
+    var halo = 0.0;
+    if g.outline_width > 0.0 || g.glow > 0.0 {
+        let edge = 0.5 - g.outline_width * w;
+        halo = smoothstep(edge - (0.5 + g.glow) * w, edge + 0.5 * w, d);
+    }
+
+    let fill_c = vec4f(g.color.rgb * g.color.a, g.color.a) * fill;
+    let halo_c = vec4f(g.outline_color.rgb * g.outline_color.a, g.outline_color.a) * halo;
+    let c = fill_c + halo_c * (1.0 - fill_c.a);
+    if c.a <= 0.0 { discard; }
+    return c;
+}
@@ -0,0 +1,300 @@
+//! Frame-to-frame diffing of the overlay draw list.
+//!
+//! The overlay shader is evaluated per pixel over the whole screen, which is
+//! wasteful for a mostly-static HUD. [`DrawListSnapshot`] remembers what was
+//! drawn last frame and reports the pixel rectangle that changed, so the pass
+//! only re-shades that region of its cached overlay.
+
+use crate::sdf::SdfGlyph;
+use crate::{DebugOverlayState, CHAR_W, ROW_H};
+
+/// Small-font cell size in pixels (matches `SM_CHAR_W` / `SM_ROW_H` in the shader).
+const SMALL_CHAR_W: u32 = 8;
+const SMALL_ROW_H: u32 = 12;
+
+/// Lines are shaded within 1.5 px of the segment; pad their bounds to cover it.
+const LINE_PAD: f32 = 2.0;
+
+/// A pixel-space rectangle that must be re-shaded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DirtyRect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl DirtyRect {
+    pub fn full(width: u32, height: u32) -> Self {
+        Self { x: 0, y: 0, width, height }
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        let right = (self.x + self.width).max(other.x + other.width);
+        let bottom = (self.y + self.height).max(other.y + other.height);
+        Self { x, y, width: right - x, height: bottom - y }
+    }
+
+    pub fn covers(&self, width: u32, height: u32) -> bool {
+        self.x == 0 && self.y == 0 && self.width >= width && self.height >= height
+    }
+}
+
+/// Floating-point bounds accumulator, converted to a clamped [`DirtyRect`].
+#[derive(Clone, Copy, Debug)]
+struct Bounds {
+    min: [f32; 2],
+    max: [f32; 2],
+}
+
+impl Bounds {
+    const EMPTY: Self = Self {
+        min: [f32::MAX, f32::MAX],
+        max: [f32::MIN, f32::MIN],
+    };
+
+    fn add(&mut self, x0: f32, y0: f32, x1: f32, y1: f32) {
+        self.min[0] = self.min[0].min(x0.min(x1));
+        self.min[1] = self.min[1].min(y0.min(y1));
+        self.max[0] = self.max[0].max(x0.max(x1));
+        self.max[1] = self.max[1].max(y0.max(y1));
+    }
+
+    fn to_rect(self, screen_w: u32, screen_h: u32) -> Option<DirtyRect> {
+        let x0 = self.min[0].floor().max(0.0) as u32;
+        let y0 = self.min[1].floor().max(0.0) as u32;
+        let x1 = (self.max[0].ceil().max(0.0) as u32).min(screen_w);
+        let y1 = (self.max[1].ceil().max(0.0) as u32).min(screen_h);
+        (x1 > x0 && y1 > y0).then(|| DirtyRect { x: x0, y: y0, width: x1 - x0, height: y1 - y0 })
+    }
+}
+
+/// Copy of the draw list submitted last frame.
+#[derive(Default)]
+pub(crate) struct DrawListSnapshot {
+    grid_cols: u32,
+    grid_rows: u32,
+    char_grid: Vec<u32>,
+    small_cols: u32,
+    small_grid: Vec<u32>,
+    bars: Vec<f32>,
+    bar_colors: Vec<f32>,
+    pies: Vec<f32>,
+    pie_colors: Vec<f32>,
+    lines: Vec<f32>,
+    line_colors: Vec<f32>,
+    sdf_glyphs: Vec<SdfGlyph>,
+}
+
+impl DrawListSnapshot {
+    pub(crate) fn capture(&mut self, state: &DebugOverlayState) {
+        self.grid_cols = state.grid_cols;
+        self.grid_rows = state.grid_rows;
+        self.small_cols = state.small_cols;
+        self.char_grid.clone_from(&state.char_grid);
+        self.small_grid.clone_from(&state.small_grid);
+        self.bars.clone_from(&state.bars);
+        self.bar_colors.clone_from(&state.bar_colors);
+        self.pies.clone_from(&state.pies);
+        self.pie_colors.clone_from(&state.pie_colors);
+        self.lines.clone_from(&state.lines);
+        self.line_colors.clone_from(&state.line_colors);
+        self.sdf_glyphs.clone_from(&state.sdf_glyphs);
+    }
+
+    /// Pixel region whose shading differs between this snapshot and `state`,
+    /// or `None` if the draw lists are identical.
+    pub(crate) fn diff(
+        &self,
+        state: &DebugOverlayState,
+        screen_w: u32,
+        screen_h: u32,
+    ) -> Option<DirtyRect> {
+        if self.grid_cols != state.grid_cols
+            || self.grid_rows != state.grid_rows
+            || self.small_cols != state.small_cols
+        {
+            return Some(DirtyRect::full(screen_w, screen_h));
+        }
+
+        let mut bounds = Bounds::EMPTY;
+        diff_grid(&self.char_grid, &state.char_grid, state.grid_cols, CHAR_W, ROW_H, &mut bounds);
+        diff_grid(
+            &self.small_grid,
+            &state.small_grid,
+            state.small_cols,
+            SMALL_CHAR_W,
+            SMALL_ROW_H,
+            &mut bounds,
+        );
+
+        // Shapes are few; any change re-shades the union of old and new extents.
+        if self.bars != state.bars || self.bar_colors != state.bar_colors {
+            for b in self.bars.chunks_exact(4).chain(state.bars.chunks_exact(4)) {
+                bounds.add(b[0], b[1], b[0] + b[2], b[1] + b[3]);
+            }
+        }
+        if self.lines != state.lines || self.line_colors != state.line_colors {
+            for l in self.lines.chunks_exact(4).chain(state.lines.chunks_exact(4)) {
+                bounds.add(
+                    l[0].min(l[2]) - LINE_PAD,
+                    l[1].min(l[3]) - LINE_PAD,
+                    l[0].max(l[2]) + LINE_PAD,
+                    l[1].max(l[3]) + LINE_PAD,
+                );
+            }
+        }
+        if self.pies != state.pies || self.pie_colors != state.pie_colors {
+            // All slices share the first slice's centre and radius.
+            for p in self.pies.chunks_exact(4).take(1).chain(state.pies.chunks_exact(4).take(1)) {
+                bounds.add(p[0] - p[2], p[1] - p[2], p[0] + p[2], p[1] + p[2]);
+            }
+        }
+        if self.sdf_glyphs != state.sdf_glyphs {
+            for g in self.sdf_glyphs.iter().chain(&state.sdf_glyphs) {
+                let (x0, y0, x1, y1) = g.bounds();
+                bounds.add(x0, y0, x1, y1);
+            }
+        }
+
+        bounds.to_rect(screen_w, screen_h)
+    }
+}
+
+fn diff_grid(old: &[u32], new: &[u32], cols: u32, cell_w: u32, cell_h: u32, bounds: &mut Bounds) {
+    if cols == 0 {
+        return;
+    }
+    let len = old.len().max(new.len());
+    for i in 0..len {
+        if old.get(i) != new.get(i) {
+            let col = i as u32 % cols;
+            let row = i as u32 / cols;
+            bounds.add(
+                (col * cell_w) as f32,
+                (row * cell_h) as f32,
+                ((col + 1) * cell_w) as f32,
+                ((row + 1) * cell_h) as f32,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const W: u32 = 1120;
+    const H: u32 = 720;
+
+    fn state() -> DebugOverlayState {
+        let shared = DebugOverlayState::new();
+        let mut s = std::sync::Arc::try_unwrap(shared).ok().unwrap().into_inner().unwrap();
+        s.set_grid_size(W / CHAR_W, H / ROW_H);
+        s
+    }
+
+    fn snapshot_of(s: &DebugOverlayState) -> DrawListSnapshot {
+        let mut snap = DrawListSnapshot::default();
+        snap.capture(s);
+        snap
+    }
+
+    #[test]
+    fn unchanged_draw_list_is_clean() {
+        let mut s = state();
+        s.write_text(0, 0, "FPS: 60");
+        s.add_bar(10.0, 10.0, 50.0, 8.0, 1.0, 0.0, 0.0, 1.0);
+        let snap = snapshot_of(&s);
+        assert_eq!(snap.diff(&s, W, H), None);
+    }
+
+    #[test]
+    fn changed_char_dirties_only_its_cell() {
+        let mut s = state();
+        s.write_text(0, 0, "FPS: 60");
+        let snap = snapshot_of(&s);
+        s.write_text(0, 0, "FPS: 61");
+        assert_eq!(
+            snap.diff(&s, W, H),
+            Some(DirtyRect { x: 6 * CHAR_W, y: 0, width: CHAR_W, height: ROW_H })
+        );
+    }
+
+    #[test]
+    fn cleared_char_is_dirty() {
+        let mut s = state();
+        s.write_text(2, 3, "x");
+        let snap = snapshot_of(&s);
+        s.clear();
+        assert_eq!(
+            snap.diff(&s, W, H),
+            Some(DirtyRect { x: 2 * CHAR_W, y: 3 * ROW_H, width: CHAR_W, height: ROW_H })
+        );
+    }
+
+    #[test]
+    fn small_grid_reshape_is_full_redraw() {
+        let mut s = state();
+        s.write_small(0, 0, "ms");
+        let snap = snapshot_of(&s);
+        // Same cells, different row stride: every small-text position moves.
+        s.small_cols -= 1;
+        assert_eq!(snap.diff(&s, W, H), Some(DirtyRect::full(W, H)));
+    }
+
+    #[test]
+    fn moved_bar_dirties_old_and_new_extent() {
+        let mut s = state();
+        s.add_bar(10.0, 10.0, 20.0, 5.0, 1.0, 1.0, 1.0, 1.0);
+        let snap = snapshot_of(&s);
+        s.clear();
+        s.add_bar(100.0, 10.0, 20.0, 5.0, 1.0, 1.0, 1.0, 1.0);
+        assert_eq!(
+            snap.diff(&s, W, H),
+            Some(DirtyRect { x: 10, y: 10, width: 110, height: 5 })
+        );
+    }
+
+    #[test]
+    fn changed_sdf_text_dirties_glyph_extent() {
+        let mut s = state();
+        s.add_text(100.0, 100.0, 16.0, "ab", 1.0, 1.0, 1.0, 1.0);
+        let snap = snapshot_of(&s);
+        assert_eq!(snap.diff(&s, W, H), None);
+        s.clear();
+        s.add_text(100.0, 100.0, 16.0, "ac", 1.0, 1.0, 1.0, 1.0);
+        // Both glyphs plus the 2-texel (4 px at size 16) distance-field margin.
+        assert_eq!(
+            snap.diff(&s, W, H),
+            Some(DirtyRect { x: 96, y: 96, width: 40, height: 24 })
+        );
+    }
+
+    #[test]
+    fn grid_resize_is_full_redraw() {
+        let mut s = state();
+        let snap = snapshot_of(&s);
+        s.set_grid_size(10, 10);
+        assert_eq!(snap.diff(&s, W, H), Some(DirtyRect::full(W, H)));
+    }
+
+    #[test]
+    fn offscreen_changes_are_clamped_away() {
+        let mut s = state();
+        let snap = snapshot_of(&s);
+        s.add_bar(-50.0, -50.0, 10.0, 10.0, 1.0, 1.0, 1.0, 1.0);
+        assert_eq!(snap.diff(&s, W, H), None);
+    }
+
+    #[test]
+    fn union_spans_both_rects() {
+        let a = DirtyRect { x: 0, y: 0, width: 10, height: 10 };
+        let b = DirtyRect { x: 20, y: 5, width: 5, height: 20 };
+        assert_eq!(a.union(b), DirtyRect { x: 0, y: 0, width: 25, height: 25 });
+        assert!(DirtyRect::full(W, H).covers(W, H));
+        assert!(!a.covers(W, H));
+    }
+}
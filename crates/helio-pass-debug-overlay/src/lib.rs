@@ -1,6 +1,16 @@
 use std::sync::{Arc, Mutex};
 use helio_core::{PassContext, PrepareContext, RenderPass, Result as HelioResult};
 
+mod dirty;
+mod sdf;
+pub use dirty::DirtyRect;
+pub use sdf::TextStyle;
+use dirty::DrawListSnapshot;
+use sdf::SdfGlyph;
+
+/// Format of the cached overlay (premultiplied alpha).
+const CACHE_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8Unorm;
+
 pub const CHAR_W: u32 = 14;
 pub const ROW_H: u32 = 24;
 const DEFAULT_COLS: u32 = 80;
@@ -10,6 +20,7 @@ const MAX_ROWS: u32 = 90;
 const MAX_BARS: u32 = 512;
 const MAX_PIES: u32 = 64;
 const MAX_LINES: u32 = 128;
+const MAX_SDF_GLYPHS: u32 = 2048;
 
 const FONT8X8: [u8; 95 * 8] = {
     let mut data = [0u8; 95 * 8];
@@ -134,6 +145,9 @@ pub struct DebugOverlayState {
     pie_colors: Vec<f32>,
     lines: Vec<f32>,
     line_colors: Vec<f32>,
+    sdf_glyphs: Vec<SdfGlyph>,
+    /// Re-shade the whole overlay next frame instead of only the changed region.
+    full_redraw: bool,
     /// Optional callback invoked each frame before data upload.
     /// Receives &mut Self so the caller can write text, bars, etc.
     pub populate: Option<Box<dyn Fn(&mut Self) + Send + Sync>>,
@@ -157,10 +171,20 @@ impl DebugOverlayState {
             pie_colors: Vec::with_capacity(MAX_PIES as usize * 4),
             lines: Vec::with_capacity(MAX_LINES as usize * 4),
             line_colors: Vec::with_capacity(MAX_LINES as usize * 4),
+            sdf_glyphs: Vec::new(),
+            full_redraw: true,
             populate: None,
         }))
     }
 
+    /// Discard the cached overlay and re-shade every pixel next frame.
+    ///
+    /// The pass does this itself on resize and when the overlay is
+    /// re-enabled; call it after anything else invalidates the whole HUD.
+    pub fn force_full_redraw(&mut self) {
+        self.full_redraw = true;
+    }
+
     pub fn set_grid_size(&mut self, cols: u32, rows: u32) {
         if cols != self.grid_cols || rows != self.grid_rows {
             self.grid_cols = cols;
@@ -186,6 +210,7 @@ impl DebugOverlayState {
         self.pie_colors.clear();
         self.lines.clear();
         self.line_colors.clear();
+        self.sdf_glyphs.clear();
     }
 
     pub fn set_char(&mut self, col: u32, row: u32, ch: char) {
@@ -268,6 +293,20 @@ impl DebugOverlayState {
         self.pie_colors.push(clamp01(b)); self.pie_colors.push(clamp01(a));
     }
 
+    /// Draw `text` at pixel position `(x, y)` with glyphs `size` pixels tall.
+    ///
+    /// Unlike the grid text, this is rendered from a signed distance field and
+    /// stays sharp at any size and position. See [`add_text_styled`](Self::add_text_styled)
+    /// for outlines and glow.
+    pub fn add_text(&mut self, x: f32, y: f32, size: f32, text: &str, r: f32, g: f32, b: f32, a: f32) {
+        self.add_text_styled(x, y, text, &TextStyle::new(size, [r, g, b, a]));
+    }
+
+    /// Draw SDF text with a full [`TextStyle`]. `\n` starts a new line.
+    pub fn add_text_styled(&mut self, x: f32, y: f32, text: &str, style: &TextStyle) {
+        sdf::layout_text(&mut self.sdf_glyphs, MAX_SDF_GLYPHS as usize, x, y, text, style);
+    }
+
     pub fn bars_data(&self) -> &[f32] { &self.bars }
     pub fn bar_colors_data(&self) -> &[f32] { &self.bar_colors }
     pub fn pies_data(&self) -> &[f32] { &self.pies }
@@ -278,10 +317,25 @@ impl DebugOverlayState {
     pub fn small_grid_slice(&self) -> &[u32] { &self.small_grid }
 }
 
+/// Draws the debug overlay on top of the final target.
+///
+/// The overlay is shaded into a persistent cache texture, limited by a scissor
+/// rect to the region whose draw list changed since last frame, and then
+/// composited with a cheap blit. Frames where nothing changed skip the
+/// shading pass and the buffer uploads entirely.
 pub struct DebugOverlayPass {
     shared: Arc<Mutex<DebugOverlayState>>,
+    /// Shades dirty regions into `cache_view` (blending off, premultiplied).
     pipeline: wgpu::RenderPipeline,
     bgl: wgpu::BindGroupLayout,
+    composite_pipeline: wgpu::RenderPipeline,
+    composite_bgl: wgpu::BindGroupLayout,
+    composite_bind_group: Option<wgpu::BindGroup>,
+    cache_view: Option<wgpu::TextureView>,
+    snapshot: DrawListSnapshot,
+    /// Region to re-shade in the next `execute`, set by `prepare`.
+    dirty: Option<DirtyRect>,
+    was_enabled: bool,
     params_buf: wgpu::Buffer,
     char_buf: wgpu::Buffer,
     small_grid_buf: wgpu::Buffer,
@@ -293,6 +347,12 @@ pub struct DebugOverlayPass {
     sampler: wgpu::Sampler,
     bind_group: Option<wgpu::BindGroup>,
     bind_group_dirty: bool,
+    /// Draws SDF text instances over the freshly shaded region of the cache.
+    sdf_pipeline: wgpu::RenderPipeline,
+    sdf_bind_group: wgpu::BindGroup,
+    sdf_params_buf: wgpu::Buffer,
+    sdf_glyph_buf: wgpu::Buffer,
+    sdf_glyph_count: u32,
     screen_w: u32,
     screen_h: u32,
 }
@@ -421,22 +481,67 @@ impl DebugOverlayPass {
             },
             fragment: Some(wgpu::FragmentState {
                 module: &shader,
+                entry_point: Some("fs_cache"),
+                compilation_options: Default::default(),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: CACHE_FORMAT,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview_mask: None,
+            cache: None,
+        });
+
+        let composite_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("DebugOverlay Composite Shader"),
+            source: wgpu::ShaderSource::Wgsl(
+                include_str!("../shaders/debug_overlay_composite.wgsl").into(),
+            ),
+        });
+
+        let composite_bgl = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("DebugOverlay Composite BGL"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            }],
+        });
+
+        let composite_pl = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("DebugOverlay Composite PL"),
+            bind_group_layouts: &[Some(&composite_bgl)],
+            immediate_size: 0,
+        });
+
+        let composite_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("DebugOverlay Composite Pipeline"),
+            layout: Some(&composite_pl),
+            vertex: wgpu::VertexState {
+                module: &composite_shader,
+                entry_point: Some("vs_main"),
+                compilation_options: Default::default(),
+                buffers: &[],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &composite_shader,
                 entry_point: Some("fs_main"),
                 compilation_options: Default::default(),
                 targets: &[Some(wgpu::ColorTargetState {
                     format: target_format,
-                    blend: Some(wgpu::BlendState {
-                        color: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                        alpha: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::One,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                    }),
+                    blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
             }),
@@ -493,10 +598,20 @@ impl DebugOverlayPass {
             mapped_at_creation: false,
         });
 
+        let (sdf_pipeline, sdf_bind_group, sdf_params_buf, sdf_glyph_buf) =
+            create_sdf_resources(device, queue);
+
         Self {
             shared,
             pipeline,
             bgl,
+            composite_pipeline,
+            composite_bgl,
+            composite_bind_group: None,
+            cache_view: None,
+            snapshot: DrawListSnapshot::default(),
+            dirty: None,
+            was_enabled: false,
             params_buf,
             char_buf,
             small_grid_buf,
@@ -508,6 +623,11 @@ impl DebugOverlayPass {
             sampler,
             bind_group: None,
             bind_group_dirty: true,
+            sdf_pipeline,
+            sdf_bind_group,
+            sdf_params_buf,
+            sdf_glyph_buf,
+            sdf_glyph_count: 0,
             screen_w,
             screen_h,
         }
@@ -521,10 +641,52 @@ impl DebugOverlayPass {
         &self.shared
     }
 
+    /// Re-shade the whole overlay next frame. See [`DebugOverlayState::force_full_redraw`].
+    pub fn force_full_redraw(&self) {
+        self.shared.lock().unwrap().force_full_redraw();
+    }
+
     pub fn resize(&mut self, _device: &wgpu::Device, width: u32, height: u32) {
         self.screen_w = width;
         self.screen_h = height;
         self.bind_group_dirty = true;
+        // Recreated at the new size in the next prepare(), which also
+        // forces a full redraw.
+        self.cache_view = None;
+        self.composite_bind_group = None;
+    }
+
+    fn ensure_cache(&mut self, device: &wgpu::Device) -> bool {
+        if self.cache_view.is_some() {
+            return false;
+        }
+        let view = device
+            .create_texture(&wgpu::TextureDescriptor {
+                label: Some("DebugOverlay Cache"),
+                size: wgpu::Extent3d {
+                    width: self.screen_w.max(1),
+                    height: self.screen_h.max(1),
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: CACHE_FORMAT,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                    | wgpu::TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            })
+            .create_view(&wgpu::TextureViewDescriptor::default());
+        self.composite_bind_group = Some(device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("DebugOverlay Composite BG"),
+            layout: &self.composite_bgl,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&view),
+            }],
+        }));
+        self.cache_view = Some(view);
+        true
     }
 }
 
@@ -543,11 +705,12 @@ impl RenderPass for DebugOverlayPass {
     }
 
     fn prepare(&mut self, ctx: &PrepareContext) -> HelioResult<()> {
-        let mut shared = self.shared.lock().unwrap();
-
-        if !shared.enabled {
+        if !self.shared.lock().unwrap().enabled {
+            self.was_enabled = false;
             return Ok(());
         }
+        let cache_created = self.ensure_cache(ctx.device);
+        let mut shared = self.shared.lock().unwrap();
 
         // Write basic timing stats
         shared.clear();
@@ -567,6 +730,21 @@ impl RenderPass for DebugOverlayPass {
         }
         std::mem::swap(&mut populate, &mut shared.populate);
 
+        // Diff against last frame; an unchanged draw list leaves the cache valid
+        // and needs neither uploads nor shading.
+        let full = std::mem::take(&mut shared.full_redraw) || cache_created || !self.was_enabled;
+        self.was_enabled = true;
+        let dirty = if full {
+            Some(DirtyRect::full(self.screen_w, self.screen_h))
+        } else {
+            self.snapshot.diff(&shared, self.screen_w, self.screen_h)
+        };
+        let Some(dirty) = dirty else {
+            return Ok(());
+        };
+        self.snapshot.capture(&shared);
+        self.dirty = Some(self.dirty.map_or(dirty, |d| d.union(dirty)));
+
         let grid_cols = shared.grid_cols();
         let grid_rows = shared.grid_rows();
         let buf_size = (grid_cols * grid_rows * 4) as u64;
@@ -613,6 +791,12 @@ impl RenderPass for DebugOverlayPass {
             ctx.write_buffer(&self.line_buf, num, bytemuck::cast_slice(line_colors));
         }
 
+        self.sdf_glyph_count = shared.sdf_glyphs.len() as u32;
+        if !shared.sdf_glyphs.is_empty() {
+            ctx.write_buffer(&self.sdf_params_buf, 0, bytemuck::bytes_of(&[self.screen_w, self.screen_h, 0, 0]));
+            ctx.write_buffer(&self.sdf_glyph_buf, 0, bytemuck::cast_slice(&shared.sdf_glyphs));
+        }
+
         if self.bind_group.is_none() || self.bind_group_dirty {
             self.bind_group = Some(ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
                 label: Some("DebugOverlay BG"),
@@ -637,7 +821,45 @@ impl RenderPass for DebugOverlayPass {
     fn execute(&mut self, ctx: &mut PassContext) -> HelioResult<()> {
         let enabled = self.shared.lock().unwrap().enabled;
         if !enabled { return Ok(()); }
-        let Some(bg) = &self.bind_group else { return Ok(()) };
+        let dirty = self.dirty.take();
+        let (Some(cache_view), Some(composite_bg)) = (&self.cache_view, &self.composite_bind_group) else {
+            return Ok(());
+        };
+
+        // Re-shade only the changed region of the cache.
+        if let (Some(rect), Some(bg)) = (dirty, &self.bind_group) {
+            if rect.width > 0 && rect.height > 0 {
+                let load = if rect.covers(self.screen_w, self.screen_h) {
+                    wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT)
+                } else {
+                    wgpu::LoadOp::Load
+                };
+                let color_attachments = [Some(wgpu::RenderPassColorAttachment {
+                    view: cache_view,
+                    resolve_target: None,
+                    depth_slice: None,
+                    ops: wgpu::Operations { load, store: wgpu::StoreOp::Store },
+                })];
+                let desc = wgpu::RenderPassDescriptor {
+                    label: Some("DebugOverlay"),
+                    color_attachments: &color_attachments,
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                    multiview_mask: None,
+                };
+                let mut rp = unsafe { &mut *ctx.encoder_ptr }.begin_render_pass(&desc);
+                rp.set_pipeline(&self.pipeline);
+                rp.set_bind_group(0, bg, &[]);
+                rp.set_scissor_rect(rect.x, rect.y, rect.width, rect.height);
+                rp.draw(0..3, 0..1);
+                if self.sdf_glyph_count > 0 {
+                    rp.set_pipeline(&self.sdf_pipeline);
+                    rp.set_bind_group(0, &self.sdf_bind_group, &[]);
+                    rp.draw(0..6, 0..self.sdf_glyph_count);
+                }
+            }
+        }
 
         let color_attachment = wgpu::RenderPassColorAttachment {
             view: ctx.target,
@@ -650,7 +872,7 @@ impl RenderPass for DebugOverlayPass {
         };
         let color_attachments = [Some(color_attachment)];
         let desc = wgpu::RenderPassDescriptor {
-            label: Some("DebugOverlay"),
+            label: Some("DebugOverlay Composite"),
             color_attachments: &color_attachments,
             depth_stencil_attachment: None,
             timestamp_writes: None,
@@ -658,8 +880,8 @@ impl RenderPass for DebugOverlayPass {
             multiview_mask: None,
         };
         let mut rp = unsafe { &mut *ctx.encoder_ptr }.begin_render_pass(&desc);
-        rp.set_pipeline(&self.pipeline);
-        rp.set_bind_group(0, bg, &[]);
+        rp.set_pipeline(&self.composite_pipeline);
+        rp.set_bind_group(0, composite_bg, &[]);
         rp.draw(0..3, 0..1);
         Ok(())
     }
@@ -725,3 +947,157 @@ fn create_font_texture(device: &wgpu::Device, queue: &wgpu::Queue) -> wgpu::Text
 
     texture
 }
+
+fn create_sdf_resources(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+) -> (wgpu::RenderPipeline, wgpu::BindGroup, wgpu::Buffer, wgpu::Buffer) {
+    let size = wgpu::Extent3d {
+        width: sdf::ATLAS_W,
+        height: sdf::ATLAS_H,
+        depth_or_array_layers: 1,
+    };
+    let atlas = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("DebugOverlay SDF Atlas"),
+        size,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::R8Unorm,
+        usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        view_formats: &[],
+    });
+    queue.write_texture(
+        wgpu::TexelCopyTextureInfo {
+            texture: &atlas,
+            mip_level: 0,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::All,
+        },
+        &sdf::build_atlas(),
+        wgpu::TexelCopyBufferLayout {
+            offset: 0,
+            bytes_per_row: Some(sdf::ATLAS_W),
+            rows_per_image: Some(sdf::ATLAS_H),
+        },
+        size,
+    );
+    let atlas_view = atlas.create_view(&wgpu::TextureViewDescriptor::default());
+
+    // Distance fields need bilinear filtering; the bitmap font sampler is nearest.
+    let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+        label: Some("DebugOverlay SDF Sampler"),
+        mag_filter: wgpu::FilterMode::Linear,
+        min_filter: wgpu::FilterMode::Linear,
+        mipmap_filter: wgpu::MipmapFilterMode::Nearest,
+        ..Default::default()
+    });
+
+    let params_buf = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("DebugOverlay SDF Params"),
+        size: std::mem::size_of::<[u32; 4]>() as u64, // screen_w, screen_h, pad, pad
+        usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+    let glyph_buf = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("DebugOverlay SDF Glyphs"),
+        size: MAX_SDF_GLYPHS as u64 * std::mem::size_of::<SdfGlyph>() as u64,
+        usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+
+    let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("DebugOverlay SDF Shader"),
+        source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/debug_overlay_sdf.wgsl").into()),
+    });
+
+    let bgl = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        label: Some("DebugOverlay SDF BGL"),
+        entries: &[
+            wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            },
+            wgpu::BindGroupLayoutEntry {
+                binding: 1,
+                visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Storage { read_only: true },
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            },
+            wgpu::BindGroupLayoutEntry {
+                binding: 2,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            },
+            wgpu::BindGroupLayoutEntry {
+                binding: 3,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                count: None,
+            },
+        ],
+    });
+
+    let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("DebugOverlay SDF BG"),
+        layout: &bgl,
+        entries: &[
+            wgpu::BindGroupEntry { binding: 0, resource: params_buf.as_entire_binding() },
+            wgpu::BindGroupEntry { binding: 1, resource: glyph_buf.as_entire_binding() },
+            wgpu::BindGroupEntry { binding: 2, resource: wgpu::BindingResource::TextureView(&atlas_view) },
+            wgpu::BindGroupEntry { binding: 3, resource: wgpu::BindingResource::Sampler(&sampler) },
+        ],
+    });
+
+    let pl = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+        label: Some("DebugOverlay SDF PL"),
+        bind_group_layouts: &[Some(&bgl)],
+        immediate_size: 0,
+    });
+
+    let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("DebugOverlay SDF Pipeline"),
+        layout: Some(&pl),
+        vertex: wgpu::VertexState {
+            module: &shader,
+            entry_point: Some("vs_main"),
+            compilation_options: Default::default(),
+            buffers: &[],
+        },
+        fragment: Some(wgpu::FragmentState {
+            module: &shader,
+            entry_point: Some("fs_main"),
+            compilation_options: Default::default(),
+            targets: &[Some(wgpu::ColorTargetState {
+                format: CACHE_FORMAT,
+                blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+        }),
+        primitive: wgpu::PrimitiveState {
+            topology: wgpu::PrimitiveTopology::TriangleList,
+            ..Default::default()
+        },
+        depth_stencil: None,
+        multisample: wgpu::MultisampleState::default(),
+        multiview_mask: None,
+        cache: None,
+    });
+
+    (pipeline, bind_group, params_buf, glyph_buf)
+}
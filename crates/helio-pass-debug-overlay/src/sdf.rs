@@ -0,0 +1,231 @@
+//! Signed-distance-field text: scalable glyphs that stay crisp at any size.
+//!
+//! The grid text in the main overlay shader samples the 8×8 bitmap font with
+//! nearest filtering, so it only looks right at its native cell sizes. SDF text
+//! instead samples a distance field built from the same font at pass creation,
+//! so no external font asset is needed, and derives antialiased coverage (plus
+//! an optional outline and glow) from the distance in the fragment shader.
+
+use crate::FONT8X8;
+
+/// Glyphs per atlas row (same layout as the bitmap atlas).
+pub(crate) const ATLAS_COLS: u32 = 16;
+/// Atlas rows needed for the 95 printable ASCII glyphs.
+pub(crate) const ATLAS_ROWS: u32 = 6;
+/// Atlas texels per font texel.
+const SCALE: u32 = 4;
+/// Empty font texels around each glyph, so outlines and glows have distance
+/// data to read beyond the glyph's own 8×8 box.
+const MARGIN: u32 = 2;
+/// Atlas cell edge in texels (matches `CELL_TEXELS * SCALE` in the shader).
+pub(crate) const CELL_PX: u32 = (8 + 2 * MARGIN) * SCALE;
+/// Distance, in font texels, mapped to the full `[0, 1]` range around the
+/// 0.5 edge value. Outlines wider than this are truncated.
+const SPREAD: f32 = MARGIN as f32;
+
+pub(crate) const ATLAS_W: u32 = ATLAS_COLS * CELL_PX;
+pub(crate) const ATLAS_H: u32 = ATLAS_ROWS * CELL_PX;
+
+/// Line height of multi-line SDF text, relative to the glyph size.
+const LINE_SPACING: f32 = 1.25;
+
+/// Appearance of a run of SDF text.
+///
+/// Sizes are in pixels; `size` is the height of the 8×8 glyph cell, which is
+/// also the horizontal advance since the font is monospaced.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextStyle {
+    pub size: f32,
+    pub color: [f32; 4],
+    /// Outline thickness outside the glyph edge (0 = none).
+    pub outline_width: f32,
+    pub outline_color: [f32; 4],
+    /// Soft falloff beyond the outline (0 = hard edge).
+    pub glow: f32,
+}
+
+impl TextStyle {
+    pub fn new(size: f32, color: [f32; 4]) -> Self {
+        Self {
+            size,
+            color,
+            outline_width: 0.0,
+            outline_color: [0.0, 0.0, 0.0, 1.0],
+            glow: 0.0,
+        }
+    }
+
+    pub fn with_outline(mut self, width: f32, color: [f32; 4]) -> Self {
+        self.outline_width = width.max(0.0);
+        self.outline_color = color;
+        self
+    }
+
+    pub fn with_glow(mut self, radius: f32) -> Self {
+        self.glow = radius.max(0.0);
+        self
+    }
+}
+
+/// One glyph instance, laid out for the `glyphs` storage buffer.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub(crate) struct SdfGlyph {
+    /// Top-left corner of the glyph's 8×8 cell in pixels.
+    pub origin: [f32; 2],
+    pub size: f32,
+    pub code: u32,
+    pub color: [f32; 4],
+    pub outline_color: [f32; 4],
+    pub outline_width: f32,
+    pub glow: f32,
+    pub _pad: [f32; 2],
+}
+
+impl SdfGlyph {
+    /// Pixel bounds `(x0, y0, x1, y1)` of everything the glyph can shade,
+    /// including its margin.
+    pub fn bounds(&self) -> (f32, f32, f32, f32) {
+        let margin = self.size * MARGIN as f32 / 8.0;
+        (
+            self.origin[0] - margin,
+            self.origin[1] - margin,
+            self.origin[0] + self.size + margin,
+            self.origin[1] + self.size + margin,
+        )
+    }
+}
+
+/// Lay out `text` starting at `(x, y)` and append one instance per printable
+/// glyph. Spaces and unsupported characters advance without emitting; `\n`
+/// starts a new line. Returns `false` once `max` instances are reached.
+pub(crate) fn layout_text(
+    out: &mut Vec<SdfGlyph>,
+    max: usize,
+    x: f32,
+    y: f32,
+    text: &str,
+    style: &TextStyle,
+) -> bool {
+    let color = style.color.map(crate::clamp01);
+    let outline_color = style.outline_color.map(crate::clamp01);
+    let (mut pen_x, mut pen_y) = (x, y);
+    for ch in text.chars() {
+        if ch == '\n' {
+            pen_x = x;
+            pen_y += style.size * LINE_SPACING;
+            continue;
+        }
+        let code = ch as u32;
+        if (33..=126).contains(&code) {
+            if out.len() >= max {
+                return false;
+            }
+            out.push(SdfGlyph {
+                origin: [pen_x, pen_y],
+                size: style.size,
+                code,
+                color,
+                outline_color,
+                outline_width: style.outline_width,
+                glow: style.glow,
+                _pad: [0.0; 2],
+            });
+        }
+        pen_x += style.size;
+    }
+    true
+}
+
+/// Build the R8 distance-field atlas for ASCII 32..=126 from the bitmap font.
+///
+/// Each texel stores `0.5 + d / (2 * SPREAD)`, where `d` is the signed
+/// distance in font texels to the nearest glyph edge (positive inside).
+/// Distances are exact to the union of the font's square texels, computed
+/// once at startup.
+pub(crate) fn build_atlas() -> Vec<u8> {
+    let mut atlas = vec![0u8; (ATLAS_W * ATLAS_H) as usize];
+    for glyph in 0..95usize {
+        let bits = &FONT8X8[glyph * 8..glyph * 8 + 8];
+        let cell_x = (glyph as u32 % ATLAS_COLS) * CELL_PX;
+        let cell_y = (glyph as u32 / ATLAS_COLS) * CELL_PX;
+        for ty in 0..CELL_PX {
+            for tx in 0..CELL_PX {
+                // Texel centre in font-texel coordinates of the 8×8 box.
+                let px = (tx as f32 + 0.5) / SCALE as f32 - MARGIN as f32;
+                let py = (ty as f32 + 0.5) / SCALE as f32 - MARGIN as f32;
+                let d = signed_distance(bits, px, py);
+                let v = (0.5 + d / (2.0 * SPREAD)).clamp(0.0, 1.0);
+                atlas[((cell_y + ty) * ATLAS_W + cell_x + tx) as usize] = (v * 255.0).round() as u8;
+            }
+        }
+    }
+    atlas
+}
+
+fn texel_set(bits: &[u8], col: i32, row: i32) -> bool {
+    (0..8).contains(&col) && (0..8).contains(&row) && (bits[row as usize] >> (7 - col)) & 1 != 0
+}
+
+/// Signed distance from `(px, py)` to the edge of the glyph `bits`.
+fn signed_distance(bits: &[u8], px: f32, py: f32) -> f32 {
+    let inside = texel_set(bits, px.floor() as i32, py.floor() as i32);
+    // The nearest texel of the opposite state lies within the glyph box or,
+    // for points inside, in the empty ring around it.
+    let mut best = f32::MAX;
+    for row in -1..9 {
+        for col in -1..9 {
+            if texel_set(bits, col, row) == inside {
+                continue;
+            }
+            let dx = (col as f32 - px).max(px - (col + 1) as f32).max(0.0);
+            let dy = (row as f32 - py).max(py - (row + 1) as f32).max(0.0);
+            best = best.min(dx * dx + dy * dy);
+        }
+    }
+    let d = if best == f32::MAX { SPREAD } else { best.sqrt() };
+    if inside { d } else { -d }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texel(atlas: &[u8], ch: char, fx: f32, fy: f32) -> u8 {
+        let glyph = ch as u32 - 32;
+        let x = (glyph % ATLAS_COLS) * CELL_PX + ((fx + MARGIN as f32) * SCALE as f32) as u32;
+        let y = (glyph / ATLAS_COLS) * CELL_PX + ((fy + MARGIN as f32) * SCALE as f32) as u32;
+        atlas[(y * ATLAS_W + x) as usize]
+    }
+
+    #[test]
+    fn edge_maps_to_half() {
+        let atlas = build_atlas();
+        // '|' (0x18 rows) covers font columns 3..5 on rows 0..7.
+        assert!(texel(&atlas, '|', 3.5, 3.5) > 128);
+        assert!(texel(&atlas, '|', 1.5, 3.5) < 128);
+        // Just inside and just outside the left edge straddle 0.5.
+        let inside = texel(&atlas, '|', 3.1, 3.5) as i32;
+        let outside = texel(&atlas, '|', 2.9, 3.5) as i32;
+        assert!(inside > 127 && outside < 128 && inside - outside < 16);
+    }
+
+    #[test]
+    fn space_is_empty() {
+        let atlas = build_atlas();
+        for row in atlas.chunks_exact(ATLAS_W as usize).take(CELL_PX as usize) {
+            assert!(row[..CELL_PX as usize].iter().all(|&v| v == 0));
+        }
+    }
+
+    #[test]
+    fn layout_advances_and_wraps() {
+        let style = TextStyle::new(16.0, [1.0; 4]);
+        let mut glyphs = Vec::new();
+        assert!(layout_text(&mut glyphs, 16, 10.0, 20.0, "a b\nc", &style));
+        let origins: Vec<_> = glyphs.iter().map(|g| g.origin).collect();
+        assert_eq!(origins, vec![[10.0, 20.0], [42.0, 20.0], [10.0, 40.0]]);
+        assert!(!layout_text(&mut glyphs, 4, 0.0, 0.0, "xyz", &style));
+        assert_eq!(glyphs.len(), 4);
+    }
+}
@@ -50,6 +50,27 @@ struct Globals {
     // Number of entries in reflection_captures. Zero skips capture blending
     // entirely and falls through to the skylight cubemap (layer 0).
     reflection_capture_count: u32,
+    // Light whose direct term is multiplied by contact_shadow_tex; 0xFFFFFFFF = none.
+    contact_shadow_light: u32,
+    // 1 while ambient_sh replaces the hemisphere ambient.
+    has_ambient_sh: u32,
+    _pad1: u32,
+    _pad2: u32,
+    // Gradient sky (libhelio::GpuGradientSky), rgb pre-scaled by intensity.
+    // sky_gradient_zenith.w = 1 while the gradient is the scene background.
+    sky_gradient_zenith:  vec4<f32>,
+    sky_gradient_horizon: vec4<f32>,
+    sky_gradient_ground:  vec4<f32>,
+    // rgb = hemisphere ground colour (pre-scaled by ambient intensity);
+    // w = 0 falls back to sky_color * 0.15.
+    ambient_ground:       vec4<f32>,
+    // xyz = normalised direction the fill light travels.
+    fill_direction:       vec4<f32>,
+    // rgb = fill colour * intensity; zero disables it.
+    fill_color:           vec4<f32>,
+    // L2 SH ambient (libhelio::AmbientSh), pre-scaled by ambient intensity with
+    // the basis constants folded in.
+    ambient_sh:           array<vec4<f32>, 9>,
 }
 
 /// GpuLight (64 bytes, matches libhelio::GpuLight)
@@ -68,9 +89,9 @@ struct GpuLight {
     god_rays_weight:   f32,
     god_rays_decay:    f32,
     god_rays_exposure: f32,
-    _pad2_0:           u32,
-    _pad2_1:           u32,
-    _pad2_2:           u32,
+    shadow_bias:        f32,
+    shadow_normal_bias: f32,
+    shadow_resolution_shift: u32,
 }
 
 struct LightMatrix { mat: mat4x4<f32> }
@@ -103,13 +124,18 @@ struct CascadeConfig {
     pcss_light_size:  f32,  // PCSS light size (meters, 0.0 = disable)
 }
 
-/// Global shadow configuration (96 bytes, matches libhelio::ShadowConfig)
+/// Global shadow configuration (112 bytes, matches libhelio::ShadowConfig)
 struct ShadowConfig {
     cascades:             array<CascadeConfig, 4>,  // 64 bytes
     enable_pcss:          u32,                      // Global PCSS toggle
     pcss_blocker_samples: u32,                      // Blocker search samples
     pcss_filter_samples:  u32,                      // PCSS filter samples
     pcf_sample_count:     u32,                      // Standard PCF sample count (4/8/12/16)
+    fade_range:           vec4<f32>,                // x=fade start, y=fade end (metres from camera)
+    reversed_depth:       u32,                      // 1 = atlas uses reversed-Z (libhelio::DepthConvention)
+    _pad0:                u32,
+    _pad1:                u32,
+    _pad2:                u32,
 }
 
 @group(0) @binding(0) var <uniform> camera:        Camera;
@@ -130,8 +156,10 @@ struct ShadowConfig {
 @group(1) @binding(7) var gbuf_lightmap_uv: texture_2d<f32>;
 // SSS data (Rgba16Float): subsurface_color.rgb, subsurface_radius
 @group(1) @binding(8) var gbuf_sss: texture_2d<f32>;
-// Extra surface data (Rgba16Float): roughness_aniso_x, roughness_aniso_y, aniso_rotation, bitcast<f32>(surface_flags)
+// Extra surface data (Rgba16Float): roughness_aniso_x, roughness_aniso_y, aniso_rotation, f32(surface_flags)
 @group(1) @binding(9) var gbuf_extra: texture_2d<f32>;
+// Screen-space contact shadow mask (1 = lit) for light globals.contact_shadow_light.
+@group(1) @binding(10) var contact_shadow_tex: texture_2d<f32>;
 
 // Group 2 – lights, shadows, environment (same as forward geometry pass)
 @group(2) @binding(0) var <storage, read> lights:          array<GpuLight>;
@@ -237,20 +265,24 @@ fn point_light_face(dir: vec3<f32>) -> u32 {
     }
 }
 
-// Normal-offset bias constant (world-space units).
-// Shifts the shadow query point along the surface normal before projecting into
-// light space, eliminating self-shadowing without any visible surface gap.
-// This is the same technique used by UE4 ("Normal Shadow Bias") and Unity HDRP.
-const NORMAL_OFFSET_SCALE: f32 = 0.01;
+// Atlas depth in standard convention (0 = light near plane). The same mapping
+// turns a standard reference back into atlas depth, so comparisons are written
+// once for standard depth; the comparison sampler's function is flipped by
+// ShadowPass to match.
+fn shadow_depth(depth: f32) -> f32 {
+    return select(depth, 1.0 - depth, shadow_config.reversed_depth != 0u);
+}
 
 // High-quality PCF shadow sampling with Vogel disk pattern.
 // world_pos must already have normal-offset applied (call shadow_factor, not this directly).
+// depth_bias is the light's `shadow_bias`, subtracted from the receiver depth.
 // Adaptive sample count: cascade_idx determines quality (distant cascades use fewer samples).
 fn sample_cascade_shadow(
     layer: u32,
     cascade_idx: u32,
     cascade_scale: f32,
     world_pos: vec3<f32>,
+    depth_bias: f32,
     frag_coord: vec2<f32>,
     frame: u32
 ) -> f32 {
@@ -291,13 +323,13 @@ fn sample_cascade_shadow(
             shadow_atlas, shadow_sampler,
             shadow_uv + offset,
             i32(layer),
-            ndc.z,
+            shadow_depth(shadow_depth(ndc.z) - depth_bias),
         );
         let sta_lit = textureSampleCompareLevel(
             static_shadow_atlas, shadow_sampler,
             shadow_uv + offset,
             i32(layer),
-            ndc.z,
+            shadow_depth(shadow_depth(ndc.z) - depth_bias),
         );
         lit_sum += min(dyn_lit, sta_lit);
     }
@@ -335,7 +367,7 @@ fn pcss_blocker_search(
         // Use min of dynamic and static atlases — the closer occluder is the true blocker.
         let dyn_depth = textureLoad(shadow_atlas, pixel_coord, i32(layer), 0);
         let sta_depth = textureLoad(static_shadow_atlas, pixel_coord, i32(layer), 0);
-        let occluder_depth = min(dyn_depth, sta_depth);
+        let occluder_depth = min(shadow_depth(dyn_depth), shadow_depth(sta_depth));
 
         if occluder_depth < receiver_depth - 0.0001 {  // Is blocker
             blocker_sum += occluder_depth;
@@ -368,6 +400,7 @@ fn sample_cascade_shadow_pcss(
     layer: u32,
     cascade_idx: u32,
     world_pos: vec3<f32>,
+    depth_bias: f32,
     frag_coord: vec2<f32>,
     frame: u32
 ) -> f32 {
@@ -383,7 +416,7 @@ fn sample_cascade_shadow_pcss(
         return 1.0;
     }
 
-    let receiver_depth = ndc.z;
+    let receiver_depth = shadow_depth(ndc.z);
     let theta = hash22(frag_coord) * 6.28318530718;
 
     // Step 1: Blocker search (average occluder depth)
@@ -411,13 +444,13 @@ fn sample_cascade_shadow_pcss(
             shadow_atlas, shadow_sampler,
             shadow_uv + offset,
             i32(layer),
-            receiver_depth
+            shadow_depth(receiver_depth - depth_bias)
         );
         let sta_lit = textureSampleCompareLevel(
             static_shadow_atlas, shadow_sampler,
             shadow_uv + offset,
             i32(layer),
-            receiver_depth
+            shadow_depth(receiver_depth - depth_bias)
         );
         lit_sum += min(dyn_lit, sta_lit);
     }
@@ -425,7 +458,22 @@ fn sample_cascade_shadow_pcss(
     return lit_sum / f32(shadow_config.pcss_filter_samples);
 }
 
+// Blend toward fully lit between `shadow_config.fade_range.x` and `.y`
+// metres from the camera, hiding the cutoff at the shadow's far extent.
+fn shadow_distance_fade(world_pos: vec3<f32>) -> f32 {
+    let fade = shadow_config.fade_range;
+    if fade.y <= fade.x { return 0.0; }
+    let dist = length(world_pos - camera.position_near.xyz);
+    return smoothstep(fade.x, fade.y, dist);
+}
+
 fn shadow_factor(light_idx: u32, world_pos: vec3<f32>, N: vec3<f32>, frag_coord: vec2<f32>, frame: u32) -> f32 {
+    let fade = shadow_distance_fade(world_pos);
+    if fade >= 1.0 { return 1.0; }
+    return mix(shadow_factor_unfaded(light_idx, world_pos, N, frag_coord, frame), 1.0, fade);
+}
+
+fn shadow_factor_unfaded(light_idx: u32, world_pos: vec3<f32>, N: vec3<f32>, frag_coord: vec2<f32>, frame: u32) -> f32 {
     if !ENABLE_SHADOWS { return 1.0; }
     if light_idx >= MAX_SHADOW_LIGHTS { return 1.0; }
 
@@ -440,6 +488,7 @@ fn shadow_factor(light_idx: u32, world_pos: vec3<f32>, N: vec3<f32>, frag_coord:
     // constant depth-offset.  Scale by (1 - NdotL) so face-on surfaces (no
     // self-shadow risk) get near-zero offset while grazing surfaces get the full
     // amount — exactly matching the UE4 / Unity HDRP normal-bias approach.
+    // The offset distance (world units) is the light's `shadow_normal_bias`.
     var light_dir: vec3<f32>;
     if light.light_type == 0u {
         light_dir = normalize(-light.direction_outer.xyz);
@@ -447,14 +496,14 @@ fn shadow_factor(light_idx: u32, world_pos: vec3<f32>, N: vec3<f32>, frag_coord:
         light_dir = normalize(light.position_range.xyz - world_pos);
     }
     let NdotL         = max(dot(N, light_dir), 0.0);
-    let normal_offset = N * NORMAL_OFFSET_SCALE * (1.0 - NdotL);
+    let normal_offset = N * light.shadow_normal_bias * (1.0 - NdotL);
     let biased_pos    = world_pos + normal_offset;
 
     var layer: u32;
     if light.light_type > 0u && light.light_type < 2u {  // Point light (type 1)
         let to_frag = biased_pos - light.position_range.xyz;
         layer = light.shadow_index + point_light_face(to_frag);
-        return sample_cascade_shadow(layer, 0u, 1.0, biased_pos, frag_coord, frame);
+        return sample_cascade_shadow(layer, 0u, 1.0, biased_pos, light.shadow_bias, frag_coord, frame);
     } else if light.light_type == 0u {  // Directional light (type 0)
         let dist = length(world_pos - camera.position_near.xyz);
         let splits = globals.csm_splits;
@@ -509,10 +558,10 @@ fn shadow_factor(light_idx: u32, world_pos: vec3<f32>, N: vec3<f32>, frag_coord:
         let layer_a = light.shadow_index + cascade_a;
         var shadow_a: f32;
         if use_pcss {
-            shadow_a = sample_cascade_shadow_pcss(layer_a, cascade_a, biased_pos, frag_coord, frame);
+            shadow_a = sample_cascade_shadow_pcss(layer_a, cascade_a, biased_pos, light.shadow_bias, frag_coord, frame);
         } else {
             let cascade_scale_a = 1.0 + f32(cascade_a) * 1.5;
-            shadow_a = sample_cascade_shadow(layer_a, cascade_a, cascade_scale_a, biased_pos, frag_coord, frame);
+            shadow_a = sample_cascade_shadow(layer_a, cascade_a, cascade_scale_a, biased_pos, light.shadow_bias, frag_coord, frame);
         }
 
         // If no blending needed, return immediately
@@ -524,10 +573,10 @@ fn shadow_factor(light_idx: u32, world_pos: vec3<f32>, N: vec3<f32>, frag_coord:
             let layer_b = light.shadow_index + cascade_b;
             var shadow_b: f32;
             if use_pcss_b {
-                shadow_b = sample_cascade_shadow_pcss(layer_b, cascade_b, biased_pos, frag_coord, frame);
+                shadow_b = sample_cascade_shadow_pcss(layer_b, cascade_b, biased_pos, light.shadow_bias, frag_coord, frame);
             } else {
                 let cascade_scale_b = 1.0 + f32(cascade_b) * 1.5;
-                shadow_b = sample_cascade_shadow(layer_b, cascade_b, cascade_scale_b, biased_pos, frag_coord, frame);
+                shadow_b = sample_cascade_shadow(layer_b, cascade_b, cascade_scale_b, biased_pos, light.shadow_bias, frag_coord, frame);
             }
             return mix(shadow_a, shadow_b, blend);
         }
@@ -536,7 +585,7 @@ fn shadow_factor(light_idx: u32, world_pos: vec3<f32>, N: vec3<f32>, frag_coord:
     } else {
         // Spot light (type 2)
         layer = light.shadow_index;
-        return sample_cascade_shadow(layer, 0u, 1.0, biased_pos, frag_coord, frame);
+        return sample_cascade_shadow(layer, 0u, 1.0, biased_pos, light.shadow_bias, frag_coord, frame);
     }
 }
 
@@ -545,6 +594,7 @@ fn shadow_factor(light_idx: u32, world_pos: vec3<f32>, N: vec3<f32>, frag_coord:
 const SURFACE_FLAG_SUBSURFACE: u32 = 1u << 0u;
 const SURFACE_FLAG_ANISOTROPIC: u32 = 1u << 1u;
 const SURFACE_FLAG_LOW_SPECULAR: u32 = 1u << 2u;
+const SURFACE_FLAG_UNLIT: u32 = 1u << 3u;
 
 // ── BRDF helpers ─────────────────────────────────────────────────────────────
 
@@ -687,6 +737,19 @@ fn capture_weight(P: vec3<f32>, cap: GpuReflectionCapture) -> f32 {
     return clamp((radius - dist) / fade, 0.0, 1.0);
 }
 
+fn has_sky_gradient() -> bool {
+    return globals.sky_gradient_zenith.w > 0.5;
+}
+
+// Mirrors GradientSky::sample() in libhelio and gradient_sky() in gradient_sky.wgsl.
+fn sample_sky_gradient(dir: vec3<f32>) -> vec3<f32> {
+    let y = clamp(dir.y, -1.0, 1.0);
+    if y >= 0.0 {
+        return mix(globals.sky_gradient_horizon.rgb, globals.sky_gradient_zenith.rgb, sqrt(y));
+    }
+    return mix(globals.sky_gradient_horizon.rgb, globals.sky_gradient_ground.rgb, sqrt(-y));
+}
+
 // Blend every capture covering P, then let the skylight fill whatever coverage
 // is left over.
 //
@@ -727,8 +790,11 @@ fn sample_reflection_environment(P: vec3<f32>, R: vec3<f32>, lod: f32) -> vec3<f
 
     if accum_a < 0.999 {
         // Layer 0 stands in as the skylight: an un-parallaxed lookup for points
-        // no capture reaches.
-        let sky = textureSampleLevel(env_cube, env_sampler, R, 0, lod).rgb;
+        // no capture reaches. A gradient background replaces it.
+        var sky = textureSampleLevel(env_cube, env_sampler, R, 0, lod).rgb;
+        if has_sky_gradient() {
+            sky = sample_sky_gradient(R);
+        }
         accum = accum + sky * (1.0 - accum_a);
     }
     return accum;
@@ -824,8 +890,13 @@ fn pbr_direct_light(
 
 // ── Radiance Cascades GI ──────────────────────────────────────────────────────
 
-const RC_PROBE_DIM: u32 = 16u;
-const RC_DIR_DIM:   u32 = 4u;
+const RC_DIR_DIM: u32 = 4u;
+
+// Probe grid resolution follows the bound atlas (atlas_w = probe_dim * dir_dim),
+// so GI quality presets don't need a matching shader constant.
+fn rc_probe_dim() -> u32 {
+    return max(textureDimensions(rc_cascade0).x / RC_DIR_DIM, 1u);
+}
 
 fn rc_oct_decode(uv: vec2<f32>) -> vec3<f32> {
     let f  = uv * 2.0 - 1.0;
@@ -846,7 +917,8 @@ fn rc_corner_irradiance_precomp(
     px: u32, py: u32, pz: u32,
     cos_weights: array<f32, 16>,
 ) -> vec3<f32> {
-    let dim = RC_PROBE_DIM - 1u;
+    let probe_dim = rc_probe_dim();
+    let dim = probe_dim - 1u;
     let cpx = min(px, dim); let cpy = min(py, dim); let cpz = min(pz, dim);
     var irr  = vec3<f32>(0.0);
     var wsum = 0.0;
@@ -856,7 +928,7 @@ fn rc_corner_irradiance_precomp(
             let cos_w = cos_weights[idx];
             if cos_w > 0.001 {
                 let atlas_x = i32(cpx * RC_DIR_DIM + ddx);
-                let atlas_y = i32((cpy * RC_PROBE_DIM + cpz) * RC_DIR_DIM + ddy);
+                let atlas_y = i32((cpy * probe_dim + cpz) * RC_DIR_DIM + ddy);
                 irr  += textureLoad(rc_cascade0, vec2<i32>(atlas_x, atlas_y), 0).rgb * cos_w;
                 wsum += cos_w;
             }
@@ -899,9 +971,10 @@ fn sample_rc_irradiance(world_pos: vec3<f32>, normal: vec3<f32>) -> vec3<f32> {
         }
     }
 
-    let cell_size = world_size / f32(RC_PROBE_DIM);
+    let probe_dim = f32(rc_probe_dim());
+    let cell_size = world_size / probe_dim;
     let probe_f   = (world_pos - world_min) / cell_size - 0.5;
-    let pf        = clamp(probe_f, vec3<f32>(0.0), vec3<f32>(f32(RC_PROBE_DIM) - 1.0));
+    let pf        = clamp(probe_f, vec3<f32>(0.0), vec3<f32>(probe_dim - 1.0));
     let pi        = vec3<u32>(u32(pf.x), u32(pf.y), u32(pf.z));
     let frc       = fract(pf);
 
@@ -965,9 +1038,10 @@ fn sample_rc_specular(
         }
     }
 
-    let cell_size = world_size / f32(RC_PROBE_DIM);
+    let probe_dim = f32(rc_probe_dim());
+    let cell_size = world_size / probe_dim;
     let probe_f   = (world_pos - world_min) / cell_size - 0.5;
-    let pf        = clamp(probe_f, vec3<f32>(0.0), vec3<f32>(f32(RC_PROBE_DIM) - 1.0));
+    let pf        = clamp(probe_f, vec3<f32>(0.0), vec3<f32>(probe_dim - 1.0));
     let pi        = vec3<u32>(u32(pf.x), u32(pf.y), u32(pf.z));
     let frc       = fract(pf);
 
@@ -989,10 +1063,88 @@ fn sample_rc_specular(
 // Handled by PostProcessPass (helio-pass-postprocess). This pass writes raw HDR
 // linear light to pre_aa — no tonemapping or bloom here.
 
+const DEBUG_MODE_GI_IRRADIANCE: u32 = 43u;
+
 // ── Fragment entry ────────────────────────────────────────────────────────────
 
 @fragment
 fn fs_main(in: VSOut) -> @location(0) vec4<f32> {
+    // Pre-exposed so bright scenes stay in Rgba16Float's precise range;
+    // PostProcessPass divides the factor back out.
+    if globals.debug_mode == DEBUG_MODE_LIGHT_COMPLEXITY {
+        return vec4<f32>(light_complexity_overlay(in) * camera.jitter_frame.w, 1.0);
+    }
+    let shaded = shade_pixel(in);
+    return vec4<f32>(shaded.rgb * camera.jitter_frame.w, shaded.a);
+}
+
+// ── Debug mode 40: light complexity ──────────────────────────────────────────
+// Tints each culling tile by its light count and draws the legend along the
+// bottom edge. Bands and colours mirror libhelio::LIGHT_COMPLEXITY_LEGEND.
+const DEBUG_MODE_LIGHT_COMPLEXITY: u32 = 40u;
+const LIGHT_COMPLEXITY_BANDS: u32 = 8u;
+const LIGHT_COMPLEXITY_COLORS = array<vec3<f32>, 8>(
+    vec3<f32>(0.05, 0.05, 0.05),
+    vec3<f32>(0.0, 0.6, 0.0),
+    vec3<f32>(0.4, 0.8, 0.0),
+    vec3<f32>(0.8, 0.8, 0.0),
+    vec3<f32>(1.0, 0.5, 0.0),
+    vec3<f32>(1.0, 0.2, 0.0),
+    vec3<f32>(0.7, 0.0, 0.0),
+    vec3<f32>(1.0, 0.0, 1.0),
+);
+const LIGHT_COMPLEXITY_LEGEND_HEIGHT: f32 = 24.0;
+
+// 0 lights, 1, 2-3, 4-7, ... 32-63, then a full tile list.
+fn light_complexity_band(count: u32) -> u32 {
+    if count == 0u { return 0u; }
+    return min(firstLeadingBit(count) + 1u, LIGHT_COMPLEXITY_BANDS - 1u);
+}
+
+fn light_complexity_overlay(in: VSOut) -> vec3<f32> {
+    var colors = LIGHT_COMPLEXITY_COLORS;
+    let size = vec2<f32>(textureDimensions(gbuf_albedo));
+    let legend_top = size.y - LIGHT_COMPLEXITY_LEGEND_HEIGHT;
+    if in.clip_pos.y >= legend_top {
+        // One swatch per band, fewest lights on the left, black separators.
+        let swatch_width = size.x / f32(LIGHT_COMPLEXITY_BANDS);
+        let band = min(u32(in.clip_pos.x / swatch_width), LIGHT_COMPLEXITY_BANDS - 1u);
+        let into_swatch = in.clip_pos.x - f32(band) * swatch_width;
+        if into_swatch < 2.0 || in.clip_pos.y < legend_top + 2.0 {
+            return vec3<f32>(0.0);
+        }
+        return colors[band];
+    }
+
+    let pix = vec2<u32>(in.clip_pos.xy);
+    let tile = pix / TILE_SIZE;
+    let count = tile_light_counts[tile.y * globals.num_tiles_x + tile.x];
+    var color = colors[light_complexity_band(count)];
+    // Modulate geometry by albedo luminance so the scene stays readable.
+    if textureLoad(gbuf_depth, vec2<i32>(pix), 0) < 1.0 {
+        let albedo = textureLoad(gbuf_albedo, vec2<i32>(pix), 0).rgb;
+        color *= 0.4 + 0.6 * dot(albedo, vec3<f32>(0.2126, 0.7152, 0.0722));
+    }
+    // Faint tile borders.
+    if any(pix % TILE_SIZE == vec2<u32>(0u)) {
+        color *= 0.7;
+    }
+    return color;
+}
+
+// Directional ambient from globals.ambient_sh (band order matches
+// libhelio::AmbientSh; basis constants are already folded in).
+fn eval_ambient_sh(n: vec3<f32>) -> vec3<f32> {
+    var c = globals.ambient_sh;
+    let color = c[0].rgb
+        + c[1].rgb * n.y + c[2].rgb * n.z + c[3].rgb * n.x
+        + c[4].rgb * (n.x * n.y) + c[5].rgb * (n.y * n.z)
+        + c[6].rgb * (3.0 * n.z * n.z - 1.0)
+        + c[7].rgb * (n.x * n.z) + c[8].rgb * (n.x * n.x - n.y * n.y);
+    return max(color, vec3<f32>(0.0));
+}
+
+fn shade_pixel(in: VSOut) -> vec4<f32> {
     let pix = vec2<i32>(i32(in.clip_pos.x), i32(in.clip_pos.y));
 
     // ── Depth guard: sky areas (depth=1) are already in the target → discard ──
@@ -1019,6 +1171,8 @@ fn fs_main(in: VSOut) -> @location(0) vec4<f32> {
     let ssao_factor  = textureSample(screen_ao, screen_ao_samp, screen_uv).r;
     // Combined AO: material AO from G-buffer × screen-space AO.
     let ao_combined  = ao * ssao_factor;
+    // Contact shadows (applied to one light in the direct loop below).
+    let contact_shadow = textureSample(contact_shadow_tex, screen_ao_samp, screen_uv).r;
 
     // ── Debug mode: bypass lighting ───────────────────────────────────────────
     // Mode 1 (UV Grid) and Mode 2 (Texture Direct) show raw colors without lighting
@@ -1027,8 +1181,10 @@ fn fs_main(in: VSOut) -> @location(0) vec4<f32> {
     // Mode 5 (World normals) remaps N from [-1,1] → [0,1] as RGB (R=+X, G=+Y, B=+Z)
     // Mode 20 (VG triangle debug): per-face colour written into albedo by vg_gbuffer.wgsl
     // Mode 21 (VG LOD heatmap): LOD-level colour written into albedo by vg_gbuffer.wgsl
+    // Modes 41/42 (Face orientation): front/back colour written into albedo by gbuffer.wgsl
     if globals.debug_mode == 1u || globals.debug_mode == 2u || globals.debug_mode == 4u
-    || globals.debug_mode == 20u || globals.debug_mode == 21u {
+    || globals.debug_mode == 20u || globals.debug_mode == 21u
+    || globals.debug_mode == 41u || globals.debug_mode == 42u {
         return vec4<f32>(albedo, alpha);
     }
     if globals.debug_mode == 5u {
@@ -1102,6 +1258,13 @@ fn fs_main(in: VSOut) -> @location(0) vec4<f32> {
         return vec4<f32>(0.0, 0.0, 0.2, 1.0);
     }
 
+    // ── Debug mode 43: radiance-cascade irradiance ───────────────────────────
+    // The indirect diffuse light the cascades deliver, before albedo and
+    // Fresnel weighting. Black if the pipeline has no RC GI.
+    if globals.debug_mode == DEBUG_MODE_GI_IRRADIANCE {
+        return vec4<f32>(sample_rc_irradiance(world_pos, N), 1.0);
+    }
+
     // ── PBR setup ─────────────────────────────────────────────────────────────
     let F0  = clamp(vec3<f32>(normal_r.w, orm_r.a, emissive_r.a), vec3<f32>(0.0), vec3<f32>(0.999));
     let V   = normalize(camera.position_near.xyz - world_pos);
@@ -1110,7 +1273,13 @@ fn fs_main(in: VSOut) -> @location(0) vec4<f32> {
     // ── SSS / Extra surface data ──────────────────────────────────────────────
     let sss_r        = textureLoad(gbuf_sss, pix, 0);
     let extra_r      = textureLoad(gbuf_extra, pix, 0);
-    let surface_flags = bitcast<u32>(extra_r.a);
+    let surface_flags = u32(extra_r.a + 0.5);
+
+    // Unlit materials: base colour + emissive, no lights, shadows or GI.
+    if (surface_flags & SURFACE_FLAG_UNLIT) != 0u {
+        return vec4<f32>(albedo + emissive, alpha);
+    }
+
     let is_anisotropic = (surface_flags & SURFACE_FLAG_ANISOTROPIC) != 0u;
     let has_subsurface = (surface_flags & SURFACE_FLAG_SUBSURFACE) != 0u;
     let low_specular   = (surface_flags & SURFACE_FLAG_LOW_SPECULAR) != 0u;
@@ -1162,6 +1331,9 @@ fn fs_main(in: VSOut) -> @location(0) vec4<f32> {
             if !is_vg {
                 sf = shadow_factor(light_idx, world_pos, N, in.clip_pos.xy, globals.frame);
             }
+            if light_idx == globals.contact_shadow_light {
+                sf *= contact_shadow;
+            }
             let sss_color = sss_r.rgb;
             Lo += pbr_direct_light(light, world_pos, N, V, F0, albedo, roughness, metallic, sf, is_anisotropic, aniso_T, aniso_ax, aniso_ay, has_subsurface, sss_color);
         }
@@ -1262,9 +1434,18 @@ fn fs_main(in: VSOut) -> @location(0) vec4<f32> {
     // based global illumination.  When inactive the hemisphere ambient is used.
 
     let sky_color      = globals.ambient_color.rgb * globals.ambient_intensity;
-    let ground_color   = sky_color * 0.15;
+    let ground_color   = select(sky_color * 0.15, globals.ambient_ground.rgb, globals.ambient_ground.w > 0.5);
     let hemi_t         = N.y * 0.5 + 0.5;
-    let hemi           = mix(ground_color, sky_color, hemi_t) * albedo;
+    var hemi           = mix(ground_color, sky_color, hemi_t) * albedo;
+    if globals.has_ambient_sh != 0u {
+        hemi = eval_ambient_sh(N) * albedo;
+    } else if has_sky_gradient() {
+        // The background gradient doubles as the hemisphere: look it up along N.
+        hemi = sample_sky_gradient(N) * albedo;
+    }
+    // Non-shadowing Lambert fill shapes the ambient term; it is part of the
+    // fallback, so RC GI and lightmaps replace it like the hemisphere.
+    hemi += globals.fill_color.rgb * max(dot(N, -globals.fill_direction.xyz), 0.0) * albedo;
 
     // RC weight: 0 = no RC data, 1 = full RC coverage
     let rc_weight      = clamp(length(rc_irr) * 4.0, 0.0, 1.0);
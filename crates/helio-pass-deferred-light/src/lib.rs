@@ -26,6 +26,24 @@ struct DeferredGlobals {
     /// the shader skips capture blending and falls straight through to the
     /// skylight cubemap.
     reflection_capture_count: u32,
+    /// Light index the `contact_shadows` mask applies to (`u32::MAX` = none).
+    contact_shadow_light: u32,
+    /// 1 while `ambient_sh` replaces the hemisphere ambient.
+    has_ambient_sh: u32,
+    _pad: [u32; 2],
+    /// Gradient sky used as the ambient and reflection fallback while it is
+    /// the scene's background. `zenith[3] == 0` disables it.
+    sky_gradient: libhelio::GpuGradientSky,
+    /// rgb = hemisphere ground colour × ambient intensity. `w == 0` derives it
+    /// from the sky colour instead.
+    ambient_ground: [f32; 4],
+    /// xyz = normalised direction the fill light travels, w unused.
+    fill_direction: [f32; 4],
+    /// rgb = fill colour × intensity. All zero disables the fill.
+    fill_color: [f32; 4],
+    /// L2 ambient SH × ambient intensity, basis constants folded in
+    /// (see [`libhelio::AmbientSh::to_gpu`]).
+    ambient_sh: [[f32; 4]; 9],
 }
 
 pub struct DeferredLightPass {
@@ -40,7 +58,7 @@ pub struct DeferredLightPass {
     bind_group_1: Option<wgpu::BindGroup>,
     bind_group_2: Option<wgpu::BindGroup>,
     bind_group_3: Option<wgpu::BindGroup>,
-    bind_group_1_key: Option<(usize, usize, usize, usize, usize, usize, usize, usize)>,
+    bind_group_1_key: Option<(usize, usize, usize, usize, usize, usize, usize, usize, usize)>,
     bind_group_2_key:
         Option<(usize, usize, usize, usize, usize, usize, usize, usize, usize, usize, usize, usize)>,
     bind_group_3_key: Option<(usize, usize)>,
@@ -72,6 +90,14 @@ pub struct DeferredLightPass {
     /// Linear clamp sampler for planar reflection blending.
     planar_sampler: wgpu::Sampler,
     pub debug_mode: u32,
+    /// Shadow quality set through `apply_quality`, uploaded on the next `prepare`.
+    pending_shadow_quality: Option<libhelio::ShadowQuality>,
+    /// Depth convention set through `set_depth_convention`, uploaded on the next `prepare`.
+    pending_depth_convention: Option<libhelio::DepthConvention>,
+    /// Quality, fade range and depth convention last uploaded to `shadow_config_buf`.
+    shadow_quality: libhelio::ShadowQuality,
+    shadow_fade_range: [f32; 2],
+    depth_convention: libhelio::DepthConvention,
 }
 
 impl DeferredLightPass {
@@ -190,6 +216,8 @@ impl DeferredLightPass {
                 texture_entry(8, wgpu::TextureSampleType::Float { filterable: false }),
                 // Extra surface data: roughness_aniso_x, roughness_aniso_y, aniso_rotation, bitcast<f32>(flags) (Rgba16Float)
                 texture_entry(9, wgpu::TextureSampleType::Float { filterable: false }),
+                // Contact shadow mask (R8Unorm), sampled with the AO sampler
+                texture_entry(10, wgpu::TextureSampleType::Float { filterable: true }),
             ],
         });
         let bgl_2 = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -536,6 +564,11 @@ impl DeferredLightPass {
             fallback_planar_view,
             planar_sampler,
             debug_mode: 0,
+            pending_shadow_quality: None,
+            shadow_quality: libhelio::ShadowQuality::Medium,
+            shadow_fade_range: [0.0, 0.0],
+            pending_depth_convention: None,
+            depth_convention: libhelio::DepthConvention::Standard,
         }
     }
 
@@ -549,8 +582,16 @@ impl DeferredLightPass {
 
     /// Set shadow quality at runtime (zero CPU cost per frame, one-time buffer write).
     pub fn set_shadow_quality(&mut self, quality: libhelio::ShadowQuality, queue: &wgpu::Queue) {
-        let config = libhelio::ShadowConfig::from_quality(quality);
-        queue.write_buffer(&self.shadow_config_buf, 0, bytemuck::bytes_of(&config));
+        self.shadow_quality = quality;
+        queue.write_buffer(&self.shadow_config_buf, 0, bytemuck::bytes_of(&self.shadow_config()));
+        self.pending_shadow_quality = None;
+    }
+
+    fn shadow_config(&self) -> libhelio::ShadowConfig {
+        let [start, end] = self.shadow_fade_range;
+        libhelio::ShadowConfig::from_quality(self.shadow_quality)
+            .with_fade_range(start, end)
+            .with_depth_convention(self.depth_convention)
     }
 }
 
@@ -570,6 +611,7 @@ impl RenderPass for DeferredLightPass {
             "static_shadow_atlas",
             "shadow_sampler",
             "ssao",
+            "contact_shadows",
             "sky_lut",
             "tile_light_lists",
             "tile_light_counts",
@@ -600,11 +642,51 @@ impl RenderPass for DeferredLightPass {
     fn prepare(&mut self, ctx: &PrepareContext) -> HelioResult<()> {
         let main_scene_opt = ctx.frame_resources.main_scene.get();
         let main_scene = main_scene_opt.as_ref();
+
+        let mut shadow_config_dirty = false;
+        if let Some(quality) = self.pending_shadow_quality.take() {
+            self.shadow_quality = quality;
+            shadow_config_dirty = true;
+        }
+        if let Some(convention) = self.pending_depth_convention.take() {
+            shadow_config_dirty |= convention != self.depth_convention;
+            self.depth_convention = convention;
+        }
+        let fade_range = main_scene.map_or([0.0, 0.0], |main| main.shadow_fade_range);
+        if fade_range != self.shadow_fade_range {
+            self.shadow_fade_range = fade_range;
+            shadow_config_dirty = true;
+        }
+        if shadow_config_dirty {
+            ctx.write_buffer(&self.shadow_config_buf, 0, bytemuck::bytes_of(&self.shadow_config()));
+        }
         let (ambient_color, ambient_intensity) = if let Some(main_scene) = main_scene {
             (main_scene.ambient_color, main_scene.ambient_intensity)
         } else {
             ([0.5, 0.5, 0.6], 1.0) // Brighter fallback ambient: sky-blue tint
         };
+        let ambient_ground = match main_scene.and_then(|main| main.ambient_ground_color) {
+            Some([r, g, b]) => [r * ambient_intensity, g * ambient_intensity, b * ambient_intensity, 1.0],
+            None => [0.0; 4],
+        };
+        let (fill_direction, fill_color) = match main_scene.and_then(|main| main.fill_light) {
+            Some(fill) => {
+                let [x, y, z] = fill.direction;
+                let len = (x * x + y * y + z * z).sqrt();
+                if len > 1e-6 {
+                    let [r, g, b] = fill.color;
+                    let i = fill.intensity;
+                    ([x / len, y / len, z / len, 0.0], [r * i, g * i, b * i, 0.0])
+                } else {
+                    ([0.0; 4], [0.0; 4])
+                }
+            }
+            None => ([0.0; 4], [0.0; 4]),
+        };
+        let ambient_sh = main_scene.and_then(|main| main.ambient_sh).map(|sh| {
+            sh.to_gpu()
+                .map(|[r, g, b, _]| [r * ambient_intensity, g * ambient_intensity, b * ambient_intensity, 0.0])
+        });
         // Get RC bounds from frame resources (dual-tier GI: RC near, ambient far)
         let (rc_min, rc_max) = if let Some(main) = main_scene {
             (main.rc_world_min, main.rc_world_max)
@@ -618,7 +700,7 @@ impl RenderPass for DeferredLightPass {
         let has_rc_gi = ctx.frame_resources.rc_view.get().is_some();
 
         let globals = DeferredGlobals {
-            frame: ctx.frame_num as u32,
+            frame: ctx.sampling_frame(),
             delta_time: ctx.delta_time,
             light_count: ctx.scene.movable_light_count, // Only movable lights (static/stationary are baked)
             ambient_intensity,
@@ -633,6 +715,22 @@ impl RenderPass for DeferredLightPass {
             has_rc_gi: has_rc_gi as u32,
             num_tiles_x: ctx.width.div_ceil(16),
             reflection_capture_count: ctx.scene.reflection_captures.len() as u32,
+            contact_shadow_light: if ctx.frame_resources.contact_shadows.is_some() {
+                ctx.frame_resources.contact_shadow_light
+            } else {
+                u32::MAX
+            },
+            has_ambient_sh: ambient_sh.is_some() as u32,
+            _pad: [0; 2],
+            sky_gradient: ctx
+                .frame_resources
+                .sky
+                .active_gradient()
+                .map_or(libhelio::GpuGradientSky::zeroed(), |g| g.to_gpu()),
+            ambient_ground,
+            fill_direction,
+            fill_color,
+            ambient_sh: ambient_sh.unwrap_or_default(),
         };
         ctx.write_buffer(&self.globals_buf, 0, bytemuck::bytes_of(&globals));
         Ok(())
@@ -645,10 +743,11 @@ impl RenderPass for DeferredLightPass {
         resources: &'a libhelio::FrameResources<'a>,
     ) -> Option<wgpu::RenderPassDescriptor<'a>> {
         let pre_aa_view = resources.pre_aa.read("DeferredLight")?;
-        let load_op = if resources.sky_lut.is_some() {
+        // Keep the background drawn by SkyPass or GradientSkyPass.
+        let load_op = if resources.sky_lut.is_some() || resources.sky_gradient.is_some() {
             wgpu::LoadOp::Load
         } else {
-            wgpu::LoadOp::Clear(wgpu::Color::BLACK)
+            resources.scene_color_load_op(wgpu::Color::BLACK)
         };
         let color_attachments: &'a [Option<wgpu::RenderPassColorAttachment<'a>>] = Box::leak(Box::new([
             Some(wgpu::RenderPassColorAttachment {
@@ -683,6 +782,9 @@ impl RenderPass for DeferredLightPass {
         // when a baked AO texture is present) or fall back to the 1×1 white texture.
         let ao_view = ctx.resources.ssao.get().unwrap_or(&self.fallback_ao_view);
 
+        // Contact shadows from ContactShadowsPass; the white fallback leaves lights unshadowed.
+        let contact_view = ctx.resources.contact_shadows.get().unwrap_or(&self.fallback_ao_view);
+
         // Lightmap UVs from GBuffer
         let lightmap_uv_view = ctx.resources.gbuffer_lightmap_uv.get().unwrap_or(&self.fallback_lightmap_uv_view);
 
@@ -699,6 +801,7 @@ impl RenderPass for DeferredLightPass {
             ao_view as *const _ as usize,
             sss_view as *const _ as usize,
             extra_view as *const _ as usize,
+            contact_view as *const _ as usize,
         );
         if self.bind_group_1_key != Some(gbuffer_key) {
             self.bind_group_1 = Some(ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
@@ -725,6 +828,8 @@ impl RenderPass for DeferredLightPass {
                     texture_view_entry(8, sss_view),
                     // Extra surface data (binding 9)
                     texture_view_entry(9, extra_view),
+                    // Contact shadow mask (binding 10)
+                    texture_view_entry(10, contact_view),
                 ],
             }));
             self.bind_group_1_key = Some(gbuffer_key);
@@ -874,6 +979,14 @@ impl RenderPass for DeferredLightPass {
         self.debug_mode = mode;
     }
 
+    fn apply_quality(&mut self, preset: helio_core::QualityPreset) {
+        self.pending_shadow_quality = Some(preset.shadow_quality());
+    }
+
+    fn set_depth_convention(&mut self, convention: libhelio::DepthConvention) {
+        self.pending_depth_convention = Some(convention);
+    }
+
     fn debug_views(&self) -> &'static [DebugViewDescriptor] {
         static VIEWS: &[DebugViewDescriptor] = &[
             DebugViewDescriptor {
@@ -896,6 +1009,16 @@ impl RenderPass for DeferredLightPass {
                 debug_mode: 11,
                 description: "Light-space depth projection",
             },
+            DebugViewDescriptor {
+                name: "Light Complexity",
+                debug_mode: libhelio::DEBUG_MODE_LIGHT_COMPLEXITY,
+                description: "Lights per culling tile: green=few, red=many, magenta=tile list full",
+            },
+            DebugViewDescriptor {
+                name: "GI Irradiance",
+                debug_mode: libhelio::DEBUG_MODE_GI_IRRADIANCE,
+                description: "Radiance-cascade irradiance before albedo; black without RC GI",
+            },
         ];
         VIEWS
     }
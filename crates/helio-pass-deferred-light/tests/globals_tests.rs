@@ -202,3 +202,37 @@ fn no_light_count_means_zero() {
     assert_eq!(no_lights, 0);
 }
 
+
+#[test]
+fn gi_irradiance_debug_mode_matches_the_shader() {
+    let shader = include_str!("../shaders/deferred_lighting.wgsl");
+    assert!(shader.contains(&format!(
+        "const DEBUG_MODE_GI_IRRADIANCE: u32 = {}u;",
+        libhelio::DEBUG_MODE_GI_IRRADIANCE
+    )));
+}
+
+// ── Light complexity debug view ───────────────────────────────────────────────
+
+#[test]
+fn light_complexity_shader_colors_mirror_the_legend() {
+    let shader = include_str!("../shaders/deferred_lighting.wgsl");
+    assert!(shader.contains(&format!(
+        "const DEBUG_MODE_LIGHT_COMPLEXITY: u32 = {}u;",
+        libhelio::DEBUG_MODE_LIGHT_COMPLEXITY
+    )));
+    let table_start = shader
+        .find("const LIGHT_COMPLEXITY_COLORS")
+        .expect("colour table in deferred_lighting.wgsl");
+    let table = &shader[table_start..];
+    let table = &table[..table.find(");").unwrap()];
+    for band in libhelio::LIGHT_COMPLEXITY_LEGEND {
+        let [r, g, b] = band.color;
+        let entry = format!("vec3<f32>({r:?}, {g:?}, {b:?})");
+        assert!(table.contains(&entry), "missing {entry} for {band:?}");
+    }
+    assert_eq!(
+        table.matches("vec3<f32>(").count(),
+        libhelio::LIGHT_COMPLEXITY_LEGEND.len()
+    );
+}
@@ -2,17 +2,7 @@
 //!
 //! Transforms vertices through the camera view-projection and writes to the depth buffer.
 //! No fragment output — depth writes are implicit.
-
-struct Camera {
-    view:           mat4x4<f32>,
-    proj:           mat4x4<f32>,
-    view_proj:      mat4x4<f32>,
-    view_proj_inv:  mat4x4<f32>,
-    position_near:  vec4<f32>,
-    forward_far:    vec4<f32>,
-    jitter_frame:   vec4<f32>,
-    prev_view_proj: mat4x4<f32>,
-}
+//!use helio_prelude
 
 /// Per-instance GPU data.  Must match `GpuInstanceData` in libhelio.
 struct GpuInstanceData {
@@ -27,8 +17,18 @@ struct GpuInstanceData {
     _pad:          u32,
 }
 
+/// Must match `PrepassUniforms` in lib.rs (16 bytes).
+struct PrepassUniforms {
+    time:  f32,
+    _pad0: f32,
+    _pad1: f32,
+    _pad2: f32,
+}
+
 @group(0) @binding(0) var<uniform>       camera:        Camera;
 @group(0) @binding(1) var<storage, read> instance_data: array<GpuInstanceData>;
+@group(0) @binding(2) var<storage, read> wind_params:   array<HelioWindParams>;
+@group(0) @binding(3) var<uniform>       prepass:       PrepassUniforms;
 
 @vertex
 fn vs_main(
@@ -37,6 +37,7 @@ fn vs_main(
     @builtin(instance_index) slot:        u32,
 ) -> @invariant @builtin(position) vec4<f32> {
     let inst      = instance_data[slot];
-    let world_pos = inst.transform * vec4<f32>(position, 1.0);
+    var world_pos = inst.transform * vec4<f32>(position, 1.0);
+    world_pos += vec4<f32>(helio_wind_offset(wind_params[slot], position, inst.transform[3].xyz, prepass.time), 0.0);
     return camera.view_proj * world_pos;
 }
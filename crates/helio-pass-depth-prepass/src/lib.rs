@@ -8,13 +8,24 @@
 //! shared mesh vertex buffer (slot 0) and index buffer **before** this pass
 //! executes, or the GPU draw will read from undefined memory.
 
+use bytemuck::{Pod, Zeroable};
 use helio_core::{PassContext, PrepareContext, RenderPass, Result as HelioResult};
 
+/// Per-frame uniforms (matches `PrepassUniforms` in depth_prepass.wgsl).
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct PrepassUniforms {
+    /// Animation time for wind sway; must match the G-buffer's so depth lines up.
+    time: f32,
+    _pad: [f32; 3],
+}
+
 pub struct DepthPrepassPass {
     pipeline: wgpu::RenderPipeline,
     bind_group_layout: wgpu::BindGroupLayout,
     bind_group: Option<wgpu::BindGroup>,
-    bind_group_key: Option<(usize, usize)>,
+    bind_group_key: Option<(usize, usize, usize)>,
+    uniform_buf: wgpu::Buffer,
 }
 
 impl DepthPrepassPass {
@@ -22,10 +33,11 @@ impl DepthPrepassPass {
     ///
     /// * `depth_format` – format of the depth attachment (e.g. `Depth32Float`)
     pub fn new(device: &wgpu::Device, depth_format: wgpu::TextureFormat) -> Self {
-        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
-            label: Some("DepthPrepass Shader"),
-            source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/depth_prepass.wgsl").into()),
-        });
+        let shader = helio_core::shader::module(
+            device,
+            "DepthPrepass Shader",
+            include_str!("../shaders/depth_prepass.wgsl"),
+        );
 
         let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
             label: Some("DepthPrepass BGL"),
@@ -52,6 +64,28 @@ impl DepthPrepassPass {
                     },
                     count: None,
                 },
+                // binding 2: per-instance wind params (VERTEX, read-only storage)
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // binding 3: per-frame uniforms (VERTEX)
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
             ],
         });
 
@@ -111,11 +145,19 @@ impl DepthPrepassPass {
             cache: None,
         });
 
+        let uniform_buf = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("DepthPrepass Uniforms"),
+            size: std::mem::size_of::<PrepassUniforms>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
         Self {
             pipeline,
             bind_group_layout,
             bind_group: None,
             bind_group_key: None,
+            uniform_buf,
         }
     }
 }
@@ -129,7 +171,12 @@ impl RenderPass for DepthPrepassPass {
         &["main_scene"]
     }
 
-    fn prepare(&mut self, _ctx: &PrepareContext) -> HelioResult<()> {
+    fn prepare(&mut self, ctx: &PrepareContext) -> HelioResult<()> {
+        let uniforms = PrepassUniforms {
+            time: ctx.time,
+            _pad: [0.0; 3],
+        };
+        ctx.write_buffer(&self.uniform_buf, 0, bytemuck::bytes_of(&uniforms));
         Ok(())
     }
 
@@ -137,7 +184,7 @@ impl RenderPass for DepthPrepassPass {
         &'a self,
         _target: &'a wgpu::TextureView,
         depth: &'a wgpu::TextureView,
-        _resources: &'a libhelio::FrameResources<'a>,
+        resources: &'a libhelio::FrameResources<'a>,
     ) -> Option<wgpu::RenderPassDescriptor<'a>> {
         let color_attachments: &'a [Option<wgpu::RenderPassColorAttachment<'a>>] = Box::leak(Box::new([]));
         Some(wgpu::RenderPassDescriptor {
@@ -146,7 +193,7 @@ impl RenderPass for DepthPrepassPass {
             depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
                 view: depth,
                 depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
+                    load: resources.scene_depth_load_op(),
                     store: wgpu::StoreOp::Store,
                 }),
                 stencil_ops: None,
@@ -172,7 +219,8 @@ impl RenderPass for DepthPrepassPass {
         // Extract before the mutable encoder borrow.
         let camera_ptr = ctx.scene.camera as *const _ as usize;
         let instances_ptr = ctx.scene.instances as *const _ as usize;
-        let key = (camera_ptr, instances_ptr);
+        let wind_ptr = ctx.scene.wind as *const _ as usize;
+        let key = (camera_ptr, instances_ptr, wind_ptr);
         if self.bind_group_key != Some(key) {
             log::debug!("DepthPrepass: rebuilding bind group (buffer pointers changed)");
             self.bind_group = Some(ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
@@ -187,6 +235,14 @@ impl RenderPass for DepthPrepassPass {
                         binding: 1,
                         resource: ctx.scene.instances.as_entire_binding(),
                     },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: ctx.scene.wind.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 3,
+                        resource: self.uniform_buf.as_entire_binding(),
+                    },
                 ],
             }));
             self.bind_group_key = Some(key);
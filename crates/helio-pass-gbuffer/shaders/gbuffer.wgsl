@@ -12,17 +12,7 @@ enable wgpu_binding_array;
 //!   normal.a   = F0.r
 //!   orm.a      = F0.g
 //!   emissive.a = F0.b
-
-struct Camera {
-    view:           mat4x4<f32>,
-    proj:           mat4x4<f32>,
-    view_proj:      mat4x4<f32>,
-    view_proj_inv:  mat4x4<f32>,
-    position_near:  vec4<f32>,
-    forward_far:    vec4<f32>,
-    jitter_frame:   vec4<f32>,
-    prev_view_proj: mat4x4<f32>,
-}
+//!use helio_prelude
 
 struct Globals {
     frame: u32,
@@ -34,7 +24,7 @@ struct Globals {
     rc_world_max: vec4<f32>,
     csm_splits: vec4<f32>,
     debug_mode: u32,
-    _pad0: u32,
+    time: f32,
     _pad1: u32,
     _pad2: u32,
 }
@@ -59,10 +49,12 @@ const FLAG_HAS_NORMAL_MAP: u32 = 1u << 3u;
 const FLAG_HAS_CLEAR_COAT: u32 = 1u << 4u;
 const FLAG_HAS_SUBSURFACE: u32 = 1u << 5u;
 const FLAG_HAS_ANISOTROPY: u32 = 1u << 6u;
+const FLAG_UNLIT: u32 = 1u << 8u;
 
 const SURFACE_FLAG_SUBSURFACE: u32 = 1u << 0u;
 const SURFACE_FLAG_ANISOTROPIC: u32 = 1u << 1u;
 const SURFACE_FLAG_LOW_SPECULAR: u32 = 1u << 2u;
+const SURFACE_FLAG_UNLIT: u32 = 1u << 3u;
 
 /// Per-material texture metadata (224 bytes, matches helio::GpuMaterialTextures)
 struct MaterialTextureSlot {
@@ -82,7 +74,7 @@ struct MaterialTextureData {
     occlusion:          MaterialTextureSlot,
     specular_color:     MaterialTextureSlot,
     specular_weight:    MaterialTextureSlot,
-    params:             vec4<f32>,  // x=normal_scale, y=occlusion_strength, z=alpha_cutoff
+    params:             vec4<f32>,  // x=normal_scale, y=occlusion_strength, z=alpha_cutoff, w=normal map convention
 }
 
 /// Per-instance data (144 bytes). Must match `GpuInstanceData` in libhelio.
@@ -113,6 +105,7 @@ struct LightmapAtlasRegion {
 @group(0) @binding(1) var<uniform>          globals:                Globals;
 @group(0) @binding(2) var<storage, read>    instance_data:          array<GpuInstanceData>;
 @group(0) @binding(3) var<storage, read>    lightmap_atlas_regions: array<LightmapAtlasRegion>;
+@group(0) @binding(4) var<storage, read>    wind_params:            array<HelioWindParams>;
 
 @group(1) @binding(0) var<storage, read>    materials:          array<GpuMaterial>;
 @group(1) @binding(1) var<storage, read>    material_textures:  array<MaterialTextureData>;
@@ -137,6 +130,8 @@ struct VertexOutput {
     @location(4) bitangent_sign: f32,
     @location(5) @interpolate(flat) material_id:    u32,
     @location(6) lightmap_uv:    vec2<f32>,  // Lightmap atlas UV (or (0,0) if no lightmap)
+    @location(7) object_position: vec3<f32>, // Mesh-local position, before wind
+    @location(8) object_normal:   vec3<f32>, // Mesh-local normal
 }
 
 fn decode_snorm8x4(packed: u32) -> vec3<f32> {
@@ -146,7 +141,8 @@ fn decode_snorm8x4(packed: u32) -> vec3<f32> {
 @vertex
 fn vs_main(v: Vertex, @builtin(instance_index) slot: u32) -> VertexOutput {
     let inst       = instance_data[slot];
-    let world_pos  = inst.transform * vec4<f32>(v.position, 1.0);
+    var world_pos  = inst.transform * vec4<f32>(v.position, 1.0);
+    world_pos += vec4<f32>(helio_wind_offset(wind_params[slot], v.position, inst.transform[3].xyz, globals.time), 0.0);
 
     // Normals transform by the inverse-transpose (stored in normal_mat).
     let normal_mat = mat3x3<f32>(
@@ -171,6 +167,8 @@ fn vs_main(v: Vertex, @builtin(instance_index) slot: u32) -> VertexOutput {
     out.bitangent_sign = v.bitangent_sign;
     out.tex_coords     = v.tex_coords;
     out.material_id    = inst.material_id;
+    out.object_position = v.position;
+    out.object_normal   = decode_snorm8x4(v.normal);
     
     // Compute lightmap UV from atlas region.
     //
@@ -293,6 +291,39 @@ fn resolve_specular_f0(
 
 // ── Default PBR material evaluation ──────────────────────────────────────────
 
+// Normal map convention in `params.w` (see `NormalMapConvention`).
+const NORMAL_MAP_TANGENT: f32 = 0.0;
+const NORMAL_MAP_TANGENT_FLIP_GREEN: f32 = 1.0;
+const NORMAL_MAP_WORLD: f32 = 2.0;
+
+fn decode_normal_map(
+    material_tex: MaterialTextureData,
+    uv: vec2<f32>,
+    N_geom: vec3<f32>,
+    world_tangent: vec3<f32>,
+    bitangent_sign: f32,
+) -> vec3<f32> {
+    let convention = material_tex.params.w;
+    let texel = sample_texture(material_tex.normal, uv, vec4<f32>(0.5, 0.5, 1.0, 1.0));
+    if convention > NORMAL_MAP_WORLD - 0.5 {
+        // World space: the map already holds the final normal; the strength
+        // blends from the geometric normal.
+        let N_map = normalize(texel.rgb * 2.0 - 1.0);
+        return normalize(mix(N_geom, N_map, material_tex.params.x));
+    }
+    let T = normalize(world_tangent - dot(world_tangent, N_geom) * N_geom);
+    let B = cross(N_geom, T) * bitangent_sign;
+    // Z is rebuilt from XY so two-channel (BC5/RG) normal maps work too.
+    var norm_xy = texel.rg * 2.0 - 1.0;
+    if convention > NORMAL_MAP_TANGENT_FLIP_GREEN - 0.5 {
+        // DirectX convention stores green pointing down.
+        norm_xy.y = -norm_xy.y;
+    }
+    let norm_z = sqrt(max(1.0 - dot(norm_xy, norm_xy), 0.0));
+    let norm_ts = vec3<f32>(norm_xy * material_tex.params.x, norm_z);
+    return normalize(T * norm_ts.x + B * norm_ts.y + N_geom * norm_ts.z);
+}
+
 fn default_pbr_surface(material: GpuMaterial, material_tex: MaterialTextureData, input: VertexOutput) -> SurfaceData {
     let uv = input.tex_coords;
     let base_sample = sample_texture(material_tex.base_color, uv, vec4<f32>(1.0));
@@ -303,11 +334,7 @@ fn default_pbr_surface(material: GpuMaterial, material_tex: MaterialTextureData,
 
     var N: vec3<f32>;
     if (material.flags & FLAG_HAS_NORMAL_MAP) != 0u && material_tex.normal.texture_index != NO_TEXTURE {
-        let T = normalize(input.world_tangent - dot(input.world_tangent, N_geom) * N_geom);
-        let B = cross(N_geom, T) * input.bitangent_sign;
-        var norm_ts = sample_texture(material_tex.normal, uv, vec4<f32>(0.5, 0.5, 1.0, 1.0)).rgb * 2.0 - 1.0;
-        norm_ts = vec3<f32>(norm_ts.x * material_tex.params.x, norm_ts.y * material_tex.params.x, norm_ts.z);
-        N = normalize(T * norm_ts.x + B * norm_ts.y + N_geom * norm_ts.z);
+        N = decode_normal_map(material_tex, uv, N_geom, input.world_tangent, input.bitangent_sign);
     } else {
         N = N_geom;
     }
@@ -374,14 +401,53 @@ fn radiant_eval_surface(material: GpuMaterial, material_tex: MaterialTextureData
     s.roughness_aniso_y = roughness_aniso_y;
     s.aniso_rotation = aniso_rotation;
 
+    // Applied after any template so an override cannot drop it.
+    if (material.flags & FLAG_UNLIT) != 0u {
+        s.flags |= SURFACE_FLAG_UNLIT;
+    }
+
     return s;
 }
 
+// ── Debug modes 41/42: face orientation ──────────────────────────────────────
+// Front faces green, back faces red, and front faces whose vertex normal points
+// away from the camera (inverted normals) yellow. Mode 42 blends in the world
+// normal direction as RGB. Shaded by the view angle so the shape stays readable.
+// The pass disables back-face culling while either mode is active.
+const DEBUG_MODE_FACE_ORIENTATION: u32 = 41u;
+const DEBUG_MODE_FACE_ORIENTATION_NORMALS: u32 = 42u;
+
+fn face_orientation_color(input: VertexOutput, front_facing: bool) -> vec3<f32> {
+    let N = normalize(input.world_normal);
+    let V = normalize(camera.position_near.xyz - input.world_position);
+    var color = vec3<f32>(0.85, 0.1, 0.1);
+    if front_facing {
+        color = select(vec3<f32>(0.9, 0.8, 0.1), vec3<f32>(0.1, 0.8, 0.2), dot(N, V) >= 0.0);
+    }
+    if globals.debug_mode == DEBUG_MODE_FACE_ORIENTATION_NORMALS {
+        color = mix(color, N * 0.5 + 0.5, 0.5);
+    }
+    return color * (0.35 + 0.65 * abs(dot(N, V)));
+}
+
 @fragment
-fn fs_main(input: VertexOutput) -> GBufferOutput {
+fn fs_main(input: VertexOutput, @builtin(front_facing) front_facing: bool) -> GBufferOutput {
     let material = materials[input.material_id];
     let material_tex = material_textures[input.material_id];
 
+    if globals.debug_mode == DEBUG_MODE_FACE_ORIENTATION
+    || globals.debug_mode == DEBUG_MODE_FACE_ORIENTATION_NORMALS {
+        return GBufferOutput(
+            vec4<f32>(face_orientation_color(input, front_facing), 1.0),
+            vec4<f32>(normalize(input.world_normal), 0.0),
+            vec4<f32>(0.0),
+            vec4<f32>(0.0),
+            vec2<f32>(0.0),
+            vec4<f32>(0.0),
+            vec4<f32>(0.0)
+        );
+    }
+
     // DEBUG MODE 1: Show UVs as colors
     if globals.debug_mode == 1u {
         let uv = input.tex_coords;
@@ -443,7 +509,9 @@ fn fs_main(input: VertexOutput) -> GBufferOutput {
     out.emissive = vec4<f32>(surface.emissive, surface.specular_f0.b);
     out.lightmap_uv = input.lightmap_uv;
     out.sss = vec4<f32>(surface.subsurface_color, surface.subsurface_radius);
+    // Flags are stored as a plain float: a bitcast would be an f32 denormal
+    // that Rgba16Float flushes to zero. Small integers are exact in f16.
     out.extra = vec4<f32>(surface.roughness_aniso_x, surface.roughness_aniso_y,
-                          surface.aniso_rotation, bitcast<f32>(surface.flags));
+                          surface.aniso_rotation, f32(surface.flags));
     return out;
 }
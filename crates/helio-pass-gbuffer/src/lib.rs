@@ -12,6 +12,10 @@
 //! | 2    | orm      | Rgba8Unorm    | AO, roughness, metallic, F0.g     |
 //! | 3    | emissive | Rgba16Float   | emissive.rgb + F0.b               |
 //!
+//! Slots 4–6 (lightmap UV, SSS, extra surface data) follow in the same
+//! attachment. Every slot writes all channels by default; see
+//! [`GBufferPass::set_color_write_mask`] to restrict one.
+//!
 //! # Material Bind Group
 //!
 //! Group 1 provides material texture access:
@@ -31,6 +35,7 @@ use helio_core::graph::{ResourceBuilder, ResourceSize};
 use helio_core::{
     DebugViewDescriptor, PassContext, PrepareContext, RenderPass, Result as HelioResult,
 };
+use libhelio::DepthMode;
 use std::collections::HashMap;
 #[cfg(not(target_arch = "wasm32"))]
 use std::num::NonZeroU32;
@@ -42,6 +47,33 @@ const MAX_TEXTURES: usize = 256;
 #[cfg(any(target_arch = "wasm32", target_os = "macos", target_os = "ios", target_os = "android"))]
 const MAX_TEXTURES: usize = 16;
 
+/// Number of color attachments the G-buffer pipeline writes.
+pub const GBUFFER_TARGET_COUNT: usize = 7;
+
+/// Color attachment formats, in slot order.
+pub const GBUFFER_TARGET_FORMATS: [wgpu::TextureFormat; GBUFFER_TARGET_COUNT] = [
+    wgpu::TextureFormat::Rgba8Unorm,  // albedo
+    wgpu::TextureFormat::Rgba16Float, // normal
+    wgpu::TextureFormat::Rgba8Unorm,  // orm
+    wgpu::TextureFormat::Rgba16Float, // emissive
+    wgpu::TextureFormat::Rg16Float,   // lightmap uv
+    wgpu::TextureFormat::Rgba16Float, // sss
+    wgpu::TextureFormat::Rgba16Float, // extra
+];
+
+/// Color target states for the G-buffer pipeline with the given write masks.
+pub fn gbuffer_color_targets(
+    masks: &[wgpu::ColorWrites; GBUFFER_TARGET_COUNT],
+) -> [Option<wgpu::ColorTargetState>; GBUFFER_TARGET_COUNT] {
+    std::array::from_fn(|slot| {
+        Some(wgpu::ColorTargetState {
+            format: GBUFFER_TARGET_FORMATS[slot],
+            blend: None,
+            write_mask: masks[slot],
+        })
+    })
+}
+
 // ── Uniform types ─────────────────────────────────────────────────────────────
 
 /// Per-frame globals uploaded to the GPU each frame (matches `Globals` in gbuffer.wgsl).
@@ -57,7 +89,8 @@ pub struct GBufferGlobals {
     pub rc_world_max: [f32; 4],
     pub csm_splits: [f32; 4],
     pub debug_mode: u32,
-    pub _pad0: u32,
+    /// Animation time in seconds, from [`PrepareContext::time`]; drives wind sway.
+    pub time: f32,
     pub _pad1: u32,
     pub _pad2: u32,
 }
@@ -71,9 +104,9 @@ pub struct GBufferPass {
     pipeline_layout: wgpu::PipelineLayout,
     bind_group_layout_0: wgpu::BindGroupLayout,
     bind_group_layout_1: wgpu::BindGroupLayout,
-    /// Group 0: camera + globals + instance_data. Rebuilt when buffer pointers change.
+    /// Group 0: camera + globals + instance_data + wind. Rebuilt when buffer pointers change.
     bind_group_0: Option<wgpu::BindGroup>,
-    bind_group_0_key: Option<(usize, usize)>,
+    bind_group_0_key: Option<(usize, usize, usize)>,
     /// Group 1: materials + material_textures + bindless texture arrays.
     bind_group_1: Option<wgpu::BindGroup>,
     bind_group_1_version: Option<u64>,
@@ -87,6 +120,8 @@ pub struct GBufferPass {
     pub debug_mode: u32,
     /// Lightmap atlas regions buffer (empty until bake data is loaded)
     lightmap_atlas_regions_buf: wgpu::Buffer,
+    /// Per-slot color write masks baked into every pipeline.
+    color_write_masks: [wgpu::ColorWrites; GBUFFER_TARGET_COUNT],
 }
 
 impl GBufferPass {
@@ -116,10 +151,10 @@ impl GBufferPass {
                         },
                         count: None,
                     },
-                    // binding 1: globals (uniform, FRAGMENT)
+                    // binding 1: globals (uniform, VERTEX | FRAGMENT)
                     wgpu::BindGroupLayoutEntry {
                         binding: 1,
-                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
                         ty: wgpu::BindingType::Buffer {
                             ty: wgpu::BufferBindingType::Uniform,
                             has_dynamic_offset: false,
@@ -149,6 +184,17 @@ impl GBufferPass {
                         },
                         count: None,
                     },
+                    // binding 4: per-instance wind params (storage read, VERTEX)
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 4,
+                        visibility: wgpu::ShaderStages::VERTEX,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
                 ],
             });
 
@@ -187,6 +233,7 @@ impl GBufferPass {
             csm_splits: libhelio::CSM_SPLITS,
             debug_mode: 0,
             lightmap_atlas_regions_buf,
+            color_write_masks: [wgpu::ColorWrites::ALL; GBUFFER_TARGET_COUNT],
         }
     }
 }
@@ -322,7 +369,7 @@ impl RenderPass for GBufferPass {
             depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
                 view: depth,
                 depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
+                    load: resources.scene_depth_load_op(),
                     store: wgpu::StoreOp::Store,
                 }),
                 stencil_ops: None,
@@ -375,7 +422,7 @@ impl RenderPass for GBufferPass {
             rc_world_max,
             csm_splits: self.csm_splits,
             debug_mode: self.debug_mode,
-            _pad0: 0,
+            time: ctx.time,
             _pad1: 0,
             _pad2: 0,
         };
@@ -392,10 +439,11 @@ impl RenderPass for GBufferPass {
         }
         let main_scene = main_scene.read("GBuffer").unwrap();
 
-        // Rebuild bind group 0 when camera or instances buffer pointers change (GrowableBuffer realloc).
+        // Rebuild bind group 0 when camera, instances or wind buffer pointers change (GrowableBuffer realloc).
         let camera_ptr = ctx.scene.camera as *const _ as usize;
         let instances_ptr = ctx.scene.instances as *const _ as usize;
-        let key = (camera_ptr, instances_ptr);
+        let wind_ptr = ctx.scene.wind as *const _ as usize;
+        let key = (camera_ptr, instances_ptr, wind_ptr);
         if self.bind_group_0_key != Some(key) {
             log::debug!("GBuffer: rebuilding bind group 0 (buffer pointers changed)");
             self.bind_group_0 = Some(ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
@@ -418,6 +466,10 @@ impl RenderPass for GBufferPass {
                         binding: 3,
                         resource: self.lightmap_atlas_regions_buf.as_entire_binding(),
                     },
+                    wgpu::BindGroupEntry {
+                        binding: 4,
+                        resource: ctx.scene.wind.as_entire_binding(),
+                    },
                 ],
             }));
             self.bind_group_0_key = Some(key);
@@ -543,6 +595,11 @@ impl RenderPass for GBufferPass {
     }
 
     fn set_debug_mode(&mut self, mode: u32) {
+        // The face-orientation views need back faces, which the pipelines
+        // cull otherwise; rebuild them lazily when that changes.
+        if shows_back_faces(mode) != shows_back_faces(self.debug_mode) {
+            self.pipelines.clear();
+        }
         self.debug_mode = mode;
     }
 
@@ -563,6 +620,16 @@ impl RenderPass for GBufferPass {
                 debug_mode: 3,
                 description: "Geometry normals only (skip normal mapping)",
             },
+            DebugViewDescriptor {
+                name: "Face Orientation",
+                debug_mode: libhelio::DEBUG_MODE_FACE_ORIENTATION,
+                description: "Front faces green, back faces red, inverted normals yellow",
+            },
+            DebugViewDescriptor {
+                name: "Face Orientation + Normals",
+                debug_mode: libhelio::DEBUG_MODE_FACE_ORIENTATION_NORMALS,
+                description: "Face orientation blended with world normal direction",
+            },
         ];
         VIEWS
     }
@@ -627,6 +694,27 @@ impl GBufferPass {
         );
     }
 
+    /// Restrict which channels of G-buffer slot `slot` (see the module table)
+    /// the pass writes. Channels left out keep whatever the attachment held,
+    /// so another pass can own them. Defaults to [`wgpu::ColorWrites::ALL`].
+    ///
+    /// Pipelines are rebuilt lazily on the next draw.
+    ///
+    /// # Panics
+    ///
+    /// If `slot >= GBUFFER_TARGET_COUNT`.
+    pub fn set_color_write_mask(&mut self, slot: usize, mask: wgpu::ColorWrites) {
+        if self.color_write_masks[slot] != mask {
+            self.color_write_masks[slot] = mask;
+            self.pipelines.clear();
+        }
+    }
+
+    /// Current write mask of every slot.
+    pub fn color_write_masks(&self) -> [wgpu::ColorWrites; GBUFFER_TARGET_COUNT] {
+        self.color_write_masks
+    }
+
     /// Access the template registry for loading new surface archetypes at runtime.
     pub fn template_registry_mut(&mut self) -> &mut RadiantTemplateRegistry {
         &mut self.template_registry
@@ -717,56 +805,17 @@ impl GBufferPass {
                     module,
                     entry_point: Some("fs_main"),
                     compilation_options: Default::default(),
-                targets: &[
-                    Some(wgpu::ColorTargetState {
-                        format: wgpu::TextureFormat::Rgba8Unorm,
-                        blend: None,
-                        write_mask: wgpu::ColorWrites::ALL,
-                    }),
-                    Some(wgpu::ColorTargetState {
-                        format: wgpu::TextureFormat::Rgba16Float,
-                        blend: None,
-                        write_mask: wgpu::ColorWrites::ALL,
-                    }),
-                    Some(wgpu::ColorTargetState {
-                        format: wgpu::TextureFormat::Rgba8Unorm,
-                        blend: None,
-                        write_mask: wgpu::ColorWrites::ALL,
-                    }),
-                    Some(wgpu::ColorTargetState {
-                        format: wgpu::TextureFormat::Rgba16Float,
-                        blend: None,
-                        write_mask: wgpu::ColorWrites::ALL,
-                    }),
-                    Some(wgpu::ColorTargetState {
-                        format: wgpu::TextureFormat::Rg16Float,
-                        blend: None,
-                        write_mask: wgpu::ColorWrites::ALL,
-                    }),
-                    Some(wgpu::ColorTargetState {
-                        format: wgpu::TextureFormat::Rgba16Float,
-                        blend: None,
-                        write_mask: wgpu::ColorWrites::ALL,
-                    }),
-                    Some(wgpu::ColorTargetState {
-                        format: wgpu::TextureFormat::Rgba16Float,
-                        blend: None,
-                        write_mask: wgpu::ColorWrites::ALL,
-                    }),
-                ],
+                    targets: &gbuffer_color_targets(&self.color_write_masks),
                 }),
                 primitive: wgpu::PrimitiveState {
                     topology: wgpu::PrimitiveTopology::TriangleList,
-                    cull_mode: Some(wgpu::Face::Back),
+                    cull_mode: (!shows_back_faces(self.debug_mode)).then_some(wgpu::Face::Back),
                     ..Default::default()
                 },
-                depth_stencil: Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth32Float,
-                    depth_write_enabled: Some(true),
-                    depth_compare: Some(wgpu::CompareFunction::LessEqual),
-                    stencil: wgpu::StencilState::default(),
-                    bias: wgpu::DepthBiasState::default(),
-                }),
+                depth_stencil: Some(DepthMode::Normal.depth_stencil_state(
+                    wgpu::TextureFormat::Depth32Float,
+                    wgpu::CompareFunction::LessEqual,
+                )),
                 multisample: wgpu::MultisampleState::default(),
                 multiview_mask: None,
                 cache: None,
@@ -779,6 +828,12 @@ impl GBufferPass {
 
 // ── Helpers ───────────────────────────────────────────────────────────────────
 
+/// Whether `debug_mode` draws back faces instead of culling them.
+fn shows_back_faces(debug_mode: u32) -> bool {
+    debug_mode == libhelio::DEBUG_MODE_FACE_ORIENTATION
+        || debug_mode == libhelio::DEBUG_MODE_FACE_ORIENTATION_NORMALS
+}
+
 /// Build the BGL for group 1 (bindless materials + textures).
 fn create_gbuffer_material_bgl(device: &wgpu::Device) -> wgpu::BindGroupLayout {
     #[cfg(not(target_arch = "wasm32"))]
@@ -63,6 +63,11 @@ fn debug_mode_field_offset_is_80() {
     assert_eq!(std::mem::offset_of!(GBufferGlobals, debug_mode), 80);
 }
 
+#[test]
+fn time_field_offset_is_84() {
+    assert_eq!(std::mem::offset_of!(GBufferGlobals, time), 84);
+}
+
 // ── bytemuck: Zeroable ────────────────────────────────────────────────────────
 
 #[test]
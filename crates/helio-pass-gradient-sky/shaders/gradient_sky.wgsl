@@ -0,0 +1,63 @@
+// Gradient sky – vertical horizon/zenith/ground gradient behind the scene
+//
+// Bind groups:
+//   group(0)  binding(0)  Camera
+//   group(0)  binding(1)  GradientSky
+
+struct Camera {
+    view:           mat4x4<f32>,
+    proj:           mat4x4<f32>,
+    view_proj:      mat4x4<f32>,
+    view_proj_inv:  mat4x4<f32>,
+    position_near:  vec4<f32>,
+    forward_far:    vec4<f32>,
+    jitter_frame:   vec4<f32>,
+    prev_view_proj: mat4x4<f32>,
+}
+
+// Layout must match GpuGradientSky in libhelio/src/sky.rs (48 bytes).
+struct GradientSky {
+    zenith:  vec4<f32>,  // rgb * intensity
+    horizon: vec4<f32>,
+    ground:  vec4<f32>,
+}
+
+@group(0) @binding(0) var<uniform> camera:   Camera;
+@group(0) @binding(1) var<uniform> gradient: GradientSky;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0)       ndc_xy:        vec2<f32>,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vid: u32) -> VertexOutput {
+    let positions = array<vec2<f32>, 3>(
+        vec2<f32>(-1.0, -1.0),
+        vec2<f32>( 3.0, -1.0),
+        vec2<f32>(-1.0,  3.0),
+    );
+    let xy = positions[vid];
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(xy, 1.0, 1.0);   // z=1 → far plane
+    out.ndc_xy        = xy;
+    return out;
+}
+
+// Mirrors GradientSky::sample() in libhelio.
+fn gradient_sky(dir: vec3<f32>) -> vec3<f32> {
+    let y = clamp(dir.y, -1.0, 1.0);
+    if y >= 0.0 {
+        return mix(gradient.horizon.rgb, gradient.zenith.rgb, sqrt(y));
+    }
+    return mix(gradient.horizon.rgb, gradient.ground.rgb, sqrt(-y));
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let clip    = vec4<f32>(in.ndc_xy, 1.0, 1.0);
+    let world   = camera.view_proj_inv * clip;
+    let ray_dir = normalize(world.xyz / world.w - camera.position_near.xyz);
+    // HDR output, pre-exposed like every other writer of the HDR target.
+    return vec4<f32>(gradient_sky(ray_dir) * camera.jitter_frame.w, 1.0);
+}
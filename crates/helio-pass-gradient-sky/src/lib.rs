@@ -0,0 +1,192 @@
+//! Gradient sky pass.
+//!
+//! A zero-dependency alternative to the atmosphere: clears the HDR target and
+//! fills it with a vertical horizon/zenith/ground gradient picked by the view
+//! ray's Y component. Runs when the scene's sky context carries a
+//! [`GradientSky`] and no atmospheric sky; otherwise it only clears.
+//! O(1) CPU: single fullscreen draw.
+
+use helio_core::graph::{ResourceBuilder, ResourceSize};
+use helio_core::{PassContext, PrepareContext, RenderPass, Result as HelioResult};
+use libhelio::{GpuGradientSky, GradientSky};
+
+pub struct GradientSkyPass {
+    pipeline: wgpu::RenderPipeline,
+    bind_group: wgpu::BindGroup,
+    gradient_buf: wgpu::Buffer,
+    /// Last gradient written to `gradient_buf`.
+    uploaded: Option<GradientSky>,
+    /// The gradient is drawn this frame.
+    active: bool,
+    target_format: wgpu::TextureFormat,
+}
+
+impl GradientSkyPass {
+    /// Creates the gradient sky pass.
+    ///
+    /// - `camera_buf`: buffer whose first bytes match the Camera struct in gradient_sky.wgsl
+    /// - `target_format`: format of the HDR render target
+    pub fn new(
+        device: &wgpu::Device,
+        camera_buf: &wgpu::Buffer,
+        target_format: wgpu::TextureFormat,
+    ) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Gradient Sky Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/gradient_sky.wgsl").into()),
+        });
+
+        let gradient_buf = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Gradient Sky Uniforms"),
+            size: std::mem::size_of::<GpuGradientSky>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let uniform_entry = |binding| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let bgl = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Gradient Sky BGL"),
+            entries: &[uniform_entry(0), uniform_entry(1)],
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Gradient Sky BG"),
+            layout: &bgl,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: camera_buf.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: gradient_buf.as_entire_binding(),
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Gradient Sky PL"),
+            bind_group_layouts: &[Some(&bgl)],
+            immediate_size: 0,
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Gradient Sky Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                compilation_options: Default::default(),
+                buffers: &[],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                compilation_options: Default::default(),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: target_format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview_mask: None,
+            cache: None,
+        });
+
+        Self {
+            pipeline,
+            bind_group,
+            gradient_buf,
+            uploaded: None,
+            active: false,
+            target_format,
+        }
+    }
+}
+
+impl RenderPass for GradientSkyPass {
+    fn name(&self) -> &'static str {
+        "GradientSky"
+    }
+
+    fn declare_resources(&self, builder: &mut ResourceBuilder) {
+        builder.write_color_raw("pre_aa", self.target_format, ResourceSize::MatchSurface);
+    }
+
+    fn writes(&self) -> &'static [&'static str] {
+        &["pre_aa"]
+    }
+
+    fn prepare(&mut self, ctx: &PrepareContext) -> HelioResult<()> {
+        let gradient = ctx.frame_resources.sky.active_gradient();
+        self.active = gradient.is_some();
+        if let Some(gradient) = gradient {
+            if self.uploaded != Some(gradient) {
+                ctx.write_buffer(&self.gradient_buf, 0, bytemuck::bytes_of(&gradient.to_gpu()));
+                self.uploaded = Some(gradient);
+            }
+        }
+        Ok(())
+    }
+
+    fn publish<'a>(&'a self, frame: &mut libhelio::FrameResources<'a>) {
+        // Tells DeferredLight to load rather than clear pre_aa.
+        if self.active {
+            frame.sky_gradient.write(&self.gradient_buf, "GradientSky");
+        }
+    }
+
+    fn render_pass_descriptor<'a>(
+        &'a self,
+        _target: &'a wgpu::TextureView,
+        _depth: &'a wgpu::TextureView,
+        resources: &'a libhelio::FrameResources<'a>,
+    ) -> Option<wgpu::RenderPassDescriptor<'a>> {
+        let pre_aa_view = resources.pre_aa.read("GradientSky")?;
+        let color_attachments: &'a [Option<wgpu::RenderPassColorAttachment<'a>>] = Box::leak(Box::new([
+            Some(wgpu::RenderPassColorAttachment {
+                view: pre_aa_view,
+                resolve_target: None,
+                depth_slice: None,
+                ops: wgpu::Operations {
+                    load: resources.scene_color_load_op(wgpu::Color::BLACK),
+                    store: wgpu::StoreOp::Store,
+                },
+            }),
+        ]));
+        Some(wgpu::RenderPassDescriptor {
+            label: Some("GradientSky"),
+            color_attachments,
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+            multiview_mask: None,
+        })
+    }
+
+    fn execute(&mut self, ctx: &mut PassContext) -> HelioResult<()> {
+        if !self.active {
+            return Ok(());
+        }
+        let rp = unsafe { &mut *ctx.active_render_pass_ptr().unwrap() };
+        rp.set_pipeline(&self.pipeline);
+        rp.set_bind_group(0, &self.bind_group, &[]);
+        rp.draw(0..3, 0..1);
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+//! `GpuGradientSky` layout and `GradientSky` packing tests.
+//!
+//! No GPU. The uniform must match `struct GradientSky` in gradient_sky.wgsl.
+
+use libhelio::{GpuGradientSky, GradientSky};
+use std::mem::{offset_of, size_of};
+
+#[test]
+fn gpu_gradient_sky_is_three_vec4() {
+    assert_eq!(size_of::<GpuGradientSky>(), 48);
+    assert_eq!(offset_of!(GpuGradientSky, zenith), 0);
+    assert_eq!(offset_of!(GpuGradientSky, horizon), 16);
+    assert_eq!(offset_of!(GpuGradientSky, ground), 32);
+}
+
+#[test]
+fn to_gpu_folds_intensity_and_sets_enabled_flag() {
+    let gradient = GradientSky {
+        zenith_color: [0.1, 0.2, 0.4],
+        horizon_color: [0.5, 0.5, 0.5],
+        ground_color: [0.2, 0.1, 0.0],
+        intensity: 4.0,
+    };
+    let gpu = gradient.to_gpu();
+    assert_eq!(gpu.zenith, [0.4, 0.8, 1.6, 1.0]);
+    assert_eq!(gpu.horizon, [2.0, 2.0, 2.0, 1.0]);
+    assert_eq!(gpu.ground, [0.8, 0.4, 0.0, 1.0]);
+}
+
+#[test]
+fn gradient_is_monotonic_towards_zenith() {
+    let gradient = GradientSky {
+        zenith_color: [0.0, 0.0, 1.0],
+        horizon_color: [1.0, 1.0, 1.0],
+        ground_color: [0.0, 0.0, 0.0],
+        intensity: 1.0,
+    };
+    let mut prev = gradient.sample(0.0)[0];
+    for i in 1..=10 {
+        let r = gradient.sample(i as f32 / 10.0)[0];
+        assert!(r <= prev, "red should fall towards the zenith");
+        prev = r;
+    }
+}
@@ -45,9 +45,9 @@ struct GpuLight {
     god_rays_weight:   f32,
     god_rays_decay:    f32,
     god_rays_exposure: f32,
-    _pad2_0:           u32,
-    _pad2_1:           u32,
-    _pad2_2:           u32,
+    shadow_bias:        f32,
+    shadow_normal_bias: f32,
+    shadow_resolution_shift: u32,
 }
 
 struct LightSample {
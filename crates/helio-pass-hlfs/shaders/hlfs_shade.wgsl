@@ -55,15 +55,14 @@ struct GpuLight {
     god_rays_weight: f32,
     god_rays_decay: f32,
     god_rays_exposure: f32,
-    _pad2_0: u32,
-    _pad2_1: u32,
-    _pad2_2: u32,
+    shadow_bias: f32,
+    shadow_normal_bias: f32,
+    shadow_resolution_shift: u32,
 }
 
 const ENABLE_SHADOWS: bool = true;
 const MAX_SHADOW_LIGHTS: u32 = 42u;
 const ATLAS_SIZE: f32 = 1024.0;
-const NORMAL_OFFSET_SCALE: f32 = 0.01;
 const PI: f32 = 3.14159265359;
 
 fn pow5(x: f32) -> f32 {
@@ -117,6 +116,11 @@ struct ShadowConfig {
     pcss_blocker_samples: u32,
     pcss_filter_samples: u32,
     pcf_sample_count: u32,
+    fade_range: vec4<f32>,
+    reversed_depth: u32,
+    _pad0: u32,
+    _pad1: u32,
+    _pad2: u32,
 }
 
 @group(0) @binding(9) var<uniform> shadow_config: ShadowConfig;
@@ -150,6 +154,12 @@ fn point_light_face(dir: vec3<f32>) -> u32 {
     }
 }
 
+// Atlas depth in standard convention; also maps a standard reference back
+// to atlas depth (see deferred_lighting.wgsl).
+fn shadow_depth(depth: f32) -> f32 {
+    return select(depth, 1.0 - depth, shadow_config.reversed_depth != 0u);
+}
+
 fn pcss_blocker_search(
     layer: u32,
     shadow_uv: vec2<f32>,
@@ -170,7 +180,7 @@ fn pcss_blocker_search(
             continue;
         }
 
-        let occluder_depth = textureLoad(shadow_atlas, pixel_coord, i32(layer), 0);
+        let occluder_depth = shadow_depth(textureLoad(shadow_atlas, pixel_coord, i32(layer), 0));
         if occluder_depth < receiver_depth - 0.0001 {
             blocker_sum += occluder_depth;
             blocker_count += 1.0;
@@ -188,7 +198,7 @@ fn pcss_penumbra_size(receiver_depth: f32, avg_blocker_depth: f32, light_size: f
     return (receiver_depth - avg_blocker_depth) / max(avg_blocker_depth, 0.001) * light_size;
 }
 
-fn sample_cascade_shadow(layer: u32, cascade_idx: u32, cascade_scale: f32, world_pos: vec3<f32>, frag_coord: vec2<f32>, frame: u32) -> f32 {
+fn sample_cascade_shadow(layer: u32, cascade_idx: u32, cascade_scale: f32, world_pos: vec3<f32>, depth_bias: f32, frag_coord: vec2<f32>, frame: u32) -> f32 {
     let light_clip = shadow_matrices[layer].mat * vec4<f32>(world_pos, 1.0);
     if light_clip.w <= 0.0 { return 1.0; }
 
@@ -213,13 +223,13 @@ fn sample_cascade_shadow(layer: u32, cascade_idx: u32, cascade_scale: f32, world
     var lit_sum = 0.0;
     for (var i = 0u; i < pcf_count; i++) {
         let offset = vogel_disk_sample(i, pcf_count, theta) * (cascade_scale / ATLAS_SIZE);
-        lit_sum += textureSampleCompareLevel(shadow_atlas, shadow_sampler, shadow_uv + offset, i32(layer), ndc.z);
+        lit_sum += textureSampleCompareLevel(shadow_atlas, shadow_sampler, shadow_uv + offset, i32(layer), shadow_depth(shadow_depth(ndc.z) - depth_bias));
     }
 
     return lit_sum / f32(pcf_count);
 }
 
-fn sample_cascade_shadow_pcss(layer: u32, cascade_idx: u32, world_pos: vec3<f32>, frag_coord: vec2<f32>, frame: u32) -> f32 {
+fn sample_cascade_shadow_pcss(layer: u32, cascade_idx: u32, world_pos: vec3<f32>, depth_bias: f32, frag_coord: vec2<f32>, frame: u32) -> f32 {
     let config = shadow_config.cascades[cascade_idx];
     let light_clip = shadow_matrices[layer].mat * vec4<f32>(world_pos, 1.0);
     if light_clip.w <= 0.0 { return 1.0; }
@@ -231,7 +241,7 @@ fn sample_cascade_shadow_pcss(layer: u32, cascade_idx: u32, world_pos: vec3<f32>
         return 1.0;
     }
 
-    let receiver_depth = ndc.z;
+    let receiver_depth = shadow_depth(ndc.z);
     let theta = hash22(frag_coord) * 6.28318530718;
 
     // Blocker search uses unbiased depth so nearby occluders are correctly identified.
@@ -249,13 +259,28 @@ fn sample_cascade_shadow_pcss(layer: u32, cascade_idx: u32, world_pos: vec3<f32>
 
     for (var i = 0u; i < shadow_config.pcss_filter_samples; i++) {
         let offset = vogel_disk_sample(i, shadow_config.pcss_filter_samples, theta) * filter_radius;
-        lit_sum += textureSampleCompareLevel(shadow_atlas, shadow_sampler, shadow_uv + offset, i32(layer), receiver_depth);
+        lit_sum += textureSampleCompareLevel(shadow_atlas, shadow_sampler, shadow_uv + offset, i32(layer), shadow_depth(receiver_depth - depth_bias));
     }
 
     return lit_sum / f32(shadow_config.pcss_filter_samples);
 }
 
+// Blend toward fully lit between `shadow_config.fade_range.x` and `.y`
+// metres from the camera, hiding the cutoff at the shadow's far extent.
+fn shadow_distance_fade(world_pos: vec3<f32>) -> f32 {
+    let fade = shadow_config.fade_range;
+    if fade.y <= fade.x { return 0.0; }
+    let dist = length(world_pos - camera.position_near.xyz);
+    return smoothstep(fade.x, fade.y, dist);
+}
+
 fn shadow_factor(light_idx: u32, world_pos: vec3<f32>, N: vec3<f32>, frag_coord: vec2<f32>, frame: u32) -> f32 {
+    let fade = shadow_distance_fade(world_pos);
+    if fade >= 1.0 { return 1.0; }
+    return mix(shadow_factor_unfaded(light_idx, world_pos, N, frag_coord, frame), 1.0, fade);
+}
+
+fn shadow_factor_unfaded(light_idx: u32, world_pos: vec3<f32>, N: vec3<f32>, frag_coord: vec2<f32>, frame: u32) -> f32 {
     if !ENABLE_SHADOWS { return 1.0; }
     if light_idx >= MAX_SHADOW_LIGHTS { return 1.0; }
 
@@ -269,14 +294,14 @@ fn shadow_factor(light_idx: u32, world_pos: vec3<f32>, N: vec3<f32>, frag_coord:
         light_dir = normalize(light.position_range.xyz - world_pos);
     }
     let NdotL = max(dot(N, light_dir), 0.0);
-    let normal_offset = N * NORMAL_OFFSET_SCALE * (1.0 - NdotL);
+    let normal_offset = N * light.shadow_normal_bias * (1.0 - NdotL);
     let biased_pos = world_pos + normal_offset;
 
     var layer: u32;
     if light.light_type > 0u && light.light_type < 2u {
         let to_frag = biased_pos - light.position_range.xyz;
         layer = light.shadow_index + point_light_face(to_frag);
-        return sample_cascade_shadow(layer, 0u, 1.0, biased_pos, frag_coord, frame);
+        return sample_cascade_shadow(layer, 0u, 1.0, biased_pos, light.shadow_bias, frag_coord, frame);
     } else if light.light_type == 0u {
         let dist = length(world_pos - camera.position_near.xyz);
         let splits = globals.csm_splits;
@@ -313,10 +338,10 @@ fn shadow_factor(light_idx: u32, world_pos: vec3<f32>, N: vec3<f32>, frag_coord:
         let layer_a = light.shadow_index + cascade_a;
         var shadow_a: f32;
         if use_pcss {
-            shadow_a = sample_cascade_shadow_pcss(layer_a, cascade_a, biased_pos, frag_coord, frame);
+            shadow_a = sample_cascade_shadow_pcss(layer_a, cascade_a, biased_pos, light.shadow_bias, frag_coord, frame);
         } else {
             let cascade_scale_a = 1.0 + f32(cascade_a) * 1.5;
-            shadow_a = sample_cascade_shadow(layer_a, cascade_a, cascade_scale_a, biased_pos, frag_coord, frame);
+            shadow_a = sample_cascade_shadow(layer_a, cascade_a, cascade_scale_a, biased_pos, light.shadow_bias, frag_coord, frame);
         }
 
         if blend <= 0.001 { return shadow_a; }
@@ -326,10 +351,10 @@ fn shadow_factor(light_idx: u32, world_pos: vec3<f32>, N: vec3<f32>, frag_coord:
             let layer_b = light.shadow_index + cascade_b;
             var shadow_b: f32;
             if use_pcss_b {
-                shadow_b = sample_cascade_shadow_pcss(layer_b, cascade_b, biased_pos, frag_coord, frame);
+                shadow_b = sample_cascade_shadow_pcss(layer_b, cascade_b, biased_pos, light.shadow_bias, frag_coord, frame);
             } else {
                 let cascade_scale_b = 1.0 + f32(cascade_b) * 1.5;
-                shadow_b = sample_cascade_shadow(layer_b, cascade_b, cascade_scale_b, biased_pos, frag_coord, frame);
+                shadow_b = sample_cascade_shadow(layer_b, cascade_b, cascade_scale_b, biased_pos, light.shadow_bias, frag_coord, frame);
             }
             return mix(shadow_a, shadow_b, blend);
         }
@@ -337,7 +362,7 @@ fn shadow_factor(light_idx: u32, world_pos: vec3<f32>, N: vec3<f32>, frag_coord:
         return shadow_a;
     } else {
         layer = light.shadow_index;
-        return sample_cascade_shadow(layer, 0u, 1.0, biased_pos, frag_coord, frame);
+        return sample_cascade_shadow(layer, 0u, 1.0, biased_pos, light.shadow_bias, frag_coord, frame);
     }
 }
 
@@ -476,5 +501,5 @@ fn fs_main(in: VSOut) -> @location(0) vec4<f32> {
     let indirect_lighting = kD_ibl * (indirect + ambient) * albedo;
     let final_color = direct_lighting + indirect_lighting + emissive;
 
-    return vec4<f32>(final_color, 1.0);
+    return vec4<f32>(final_color * camera.jitter_frame.w, 1.0);
 }
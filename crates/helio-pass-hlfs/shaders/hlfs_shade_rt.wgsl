@@ -40,9 +40,9 @@ struct GpuLight {
     god_rays_weight: f32,
     god_rays_decay: f32,
     god_rays_exposure: f32,
-    _pad2_0: u32,
-    _pad2_1: u32,
-    _pad2_2: u32,
+    shadow_bias: f32,
+    shadow_normal_bias: f32,
+    shadow_resolution_shift: u32,
 }
 
 struct ShadowConfig {
@@ -51,6 +51,11 @@ struct ShadowConfig {
     pcss_blocker_samples: u32,
     pcss_filter_samples: u32,
     pcf_sample_count: u32,
+    fade_range: vec4<f32>,
+    reversed_depth: u32,
+    _pad0: u32,
+    _pad1: u32,
+    _pad2: u32,
 }
 
 struct CascadeConfig {
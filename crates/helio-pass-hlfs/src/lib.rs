@@ -93,6 +93,7 @@ pub struct HlfsPass {
 
     shadow_config_buf: wgpu::Buffer,
     shadow_quality: libhelio::ShadowQuality,
+    depth_convention: libhelio::DepthConvention,
 
     // RT path
     use_rt: bool,
@@ -978,6 +979,7 @@ impl HlfsPass {
             output_format,
             shadow_config_buf,
             shadow_quality: libhelio::ShadowQuality::High,
+            depth_convention: libhelio::DepthConvention::Standard,
             use_rt,
             rt_pipeline,
             bgl_shade0_rt,
@@ -988,7 +990,8 @@ impl HlfsPass {
 
     pub fn set_shadow_quality(&mut self, quality: libhelio::ShadowQuality, queue: &wgpu::Queue) {
         self.shadow_quality = quality;
-        let config = libhelio::ShadowConfig::from_quality(quality);
+        let config = libhelio::ShadowConfig::from_quality(quality)
+            .with_depth_convention(self.depth_convention);
         queue.write_buffer(&self.shadow_config_buf, 0, bytemuck::bytes_of(&config));
     }
 
@@ -1015,6 +1018,21 @@ impl RenderPass for HlfsPass {
         "HLFS"
     }
 
+    fn gpu_memory_bytes(&self) -> u64 {
+        // The per-pixel sample buffer dominates: K samples for every pixel.
+        helio_core::texture_memory_bytes(&self.output_texture) + self.sample_buffer.size()
+    }
+
+    fn apply_quality(&mut self, preset: helio_core::QualityPreset) {
+        // Uploaded from `shadow_quality` every prepare.
+        self.shadow_quality = preset.shadow_quality();
+    }
+
+    fn set_depth_convention(&mut self, convention: libhelio::DepthConvention) {
+        // Uploaded with the rest of the shadow config every prepare.
+        self.depth_convention = convention;
+    }
+
     fn render_pass_descriptor<'a>(
         &'a self,
         _target: &'a wgpu::TextureView,
@@ -1053,7 +1071,7 @@ impl RenderPass for HlfsPass {
 
         // Write legacy globals for existing shaders
         let legacy = LegacyGlobals {
-            frame: ctx.frame_num as u32,
+            frame: ctx.sampling_frame(),
             sample_count: SAMPLES_PER_PIXEL,
             light_count: ctx.scene.movable_light_count,
             screen_width: self.width,
@@ -1094,7 +1112,14 @@ impl RenderPass for HlfsPass {
         };
         ctx.write_buffer(&self.hlfs_globals_buf, 0, bytemuck::bytes_of(&hlfs));
 
-        let shadow_config = libhelio::ShadowConfig::from_quality(self.shadow_quality);
+        let [fade_start, fade_end] = ctx
+            .frame_resources
+            .main_scene
+            .get()
+            .map_or([0.0, 0.0], |main| main.shadow_fade_range);
+        let shadow_config = libhelio::ShadowConfig::from_quality(self.shadow_quality)
+            .with_fade_range(fade_start, fade_end)
+            .with_depth_convention(self.depth_convention);
         ctx.write_buffer(
             &self.shadow_config_buf,
             0,
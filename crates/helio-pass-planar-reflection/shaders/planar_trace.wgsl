@@ -147,8 +147,9 @@ fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
         textureStore(planar_output, px, vec4<f32>(0.0)); return;
     }
 
+    // scene_color is the pre-exposed HDR target; store scene-linear radiance.
     let reflection = textureSampleLevel(
         scene_color, linear_sampler, vec2<f32>(hu, hv), 0.0
-    ).rgb;
+    ).rgb / camera.jitter_frame.w;
     textureStore(planar_output, px, vec4<f32>(reflection, confidence));
 }
@@ -6,12 +6,13 @@
 // prelude's `Camera` is unused here — this file keeps its own `CameraUniforms`.
 //
 // Bind groups:
-//   @group(0) — main: uniforms, samplers, hdr/depth inputs, bloom sampled, avg_lum,
-//               noise, custom params, volume data, blend output
+//   @group(0) — main: uniforms, samplers, hdr/depth inputs, bloom sampled, exposure
+//               state, noise, custom params, volume data, blend output, histogram
 //   @group(1) — bloom compute: per-dispatch src (sampled) + dst (storage write)
 //
 // Entry points:
-//   cs_exposure              — compute: luminance histogram → avg log-luminance
+//   cs_exposure_histogram    — compute: scene luminance → weighted log2 histogram
+//   cs_exposure_adapt        — compute: histogram → avg luminance, adapted exposure
 //   cs_volume_blend          — compute: blend active post-process volumes → output
 //   cs_bloom_down_extract    — compute: extract brights from HDR → bloom mip 0
 //   cs_bloom_down            — compute: 2x downsample from bloom_src → bloom_dst
@@ -20,7 +21,7 @@
 //
 // Effect order (uber pass):
 //   INJECTION_POINT_0  — user effects (pre-blend)
-//   1. Exposure scale
+//   1. Exposure scale (compensation, plus adapted EV in auto mode)
 //   2. Bloom composite
 //   3. Color grading
 //   4. White balance
@@ -40,6 +41,11 @@ const PI: f32 = 3.14159265359;
 const WG_BLOOM: u32 = 8u;
 const WG_EXPOSURE_X: u32 = 16u;
 const WG_EXPOSURE_Y: u32 = 16u;
+// Histogram covers log2 luminance [HISTOGRAM_MIN_LOG2, HISTOGRAM_MIN_LOG2 + HISTOGRAM_RANGE_LOG2].
+// Bin 0 collects everything darker; bins 1..=255 span the range.
+const EXPOSURE_BINS: u32 = 256u;
+const HISTOGRAM_MIN_LOG2: f32 = -12.0;
+const HISTOGRAM_RANGE_LOG2: f32 = 24.0;
 const MAX_PP_VOLUMES: u32 = 256u;
 
 // ── GpuPostProcessUniforms ─────────────────────────────────────────────────────
@@ -107,23 +113,27 @@ struct GpuPostProcessUniforms {
     blend_weight_ca:           f32,
     blend_weight_grain:        f32,
     blend_weight_exposure:     f32,
-    _pad14:                    f32,
-    // ── Volumetric fog (64 bytes, offsets 304..368) ──
-    // fog_color lands at 336 and fog_emissive at 352 — both multiples of 16, which
+    bloom_composite:           u32,   // 0 = additive, 1 = energy-conserving
+    exposure_speed_up:         f32,   // 304
+    exposure_speed_down:       f32,   // 308
+    exposure_metering:         u32,   // 312 — 0 = average, 1 = centre-weighted
+    _pad15:                    f32,   // 316
+    // ── Volumetric fog (64 bytes, offsets 320..384) ──
+    // fog_color lands at 352 and fog_emissive at 368 — both multiples of 16, which
     // is what lets this match #[repr(C)] on the CPU. vec3<f32> aligns to 16 in WGSL
     // but to 4 in Rust, so reordering these fields silently desyncs the two sides.
-    fog_enabled:               u32,   // 304
-    fog_mode:                  u32,   // 308
-    fog_density:               f32,   // 312
-    fog_height_falloff:        f32,   // 316
-    fog_start_distance:        f32,   // 320
-    fog_max_distance:          f32,   // 324
-    fog_height:                f32,   // 328
-    fog_scattering_anisotropy: f32,   // 332
-    fog_color:                 vec3<f32>, // 336
-    _pad_fog_color:            f32,   // 348
-    fog_emissive:              vec3<f32>, // 352
-    _pad_fog_emissive:         f32,   // 364 → struct ends at 368
+    fog_enabled:               u32,   // 320
+    fog_mode:                  u32,   // 324
+    fog_density:               f32,   // 328
+    fog_height_falloff:        f32,   // 332
+    fog_start_distance:        f32,   // 336
+    fog_max_distance:          f32,   // 340
+    fog_height:                f32,   // 344
+    fog_scattering_anisotropy: f32,   // 348
+    fog_color:                 vec3<f32>, // 352
+    _pad_fog_color:            f32,   // 364
+    fog_emissive:              vec3<f32>, // 368
+    _pad_fog_emissive:         f32,   // 380 → struct ends at 384
 }
 
 struct CameraUniforms {
@@ -154,6 +164,15 @@ struct GpuPostProcessVolume {
     settings:       GpuPostProcessUniforms,  // 64
 }
 
+// ── Exposure state (matches ExposureState in lib.rs) ───────────────────────────
+
+struct ExposureState {
+    avg_log_luminance: f32,   // metered scene-linear log2 luminance (read back for pre-exposure)
+    exposure_ev:       f32,   // adapted exposure, applied by fs_uber in auto mode
+    delta_time:        f32,   // written by the CPU each frame
+    _pad:              f32,
+}
+
 // ── Group 0: main bindings ─────────────────────────────────────────────────────
 
 @group(0) @binding(0)  var<uniform>            postprocess:  GpuPostProcessUniforms;
@@ -167,7 +186,7 @@ struct GpuPostProcessVolume {
 @group(0) @binding(8)  var                     bloom_2:      texture_2d<f32>;
 @group(0) @binding(9)  var                     bloom_3:      texture_2d<f32>;
 @group(0) @binding(10) var                     bloom_4:      texture_2d<f32>;
-@group(0) @binding(11) var<storage, read_write> avg_luminance: array<f32>;
+@group(0) @binding(11) var<storage, read_write> exposure_state: ExposureState;
 @group(0) @binding(12) var                     noise_tex:    texture_2d<f32>;
 @group(0) @binding(13) var                     noise_samp:   sampler;
 @group(0) @binding(14) var<storage, read>      pp_custom:    array<vec4<f32>>;
@@ -178,6 +197,9 @@ struct GpuPostProcessVolume {
 // integrated from the camera to that froxel's depth. Bound to a 1x1x1 (0,0,0,1)
 // fallback when no fog pass is in the graph, which composites to a no-op.
 @group(0) @binding(17) var                     fog_input:    texture_3d<f32>;
+// Weighted luminance histogram (compute only). Filled by cs_exposure_histogram,
+// consumed and cleared by cs_exposure_adapt, so it is zero between frames.
+@group(0) @binding(18) var<storage, read_write> exposure_histogram: array<atomic<u32>, EXPOSURE_BINS>;
 
 // ── Group 1: per-dispatch bloom compute src/dst ────────────────────────────────
 
@@ -207,6 +229,13 @@ fn luminance(c: vec3<f32>) -> f32 {
     return dot(c, vec3<f32>(0.2126, 0.7152, 0.0722));
 }
 
+// hdr_input is written pre-exposed (camera.jitter_frame.w) to keep bright
+// radiance well inside f16 range. Every read divides it back out, so exposure,
+// bloom threshold and fog all keep working in scene-linear units.
+fn scene_linear(c: vec3<f32>) -> vec3<f32> {
+    return c / camera.jitter_frame.w;
+}
+
 // ── cs_volume_blend: GPU post-process volume blending ─────────────────────────
 // Single workgroup (1 thread) that reads all active volumes and blends them
 // with camera defaults, writing the result to blend_output.
@@ -269,6 +298,10 @@ fn blend_settings(base: GpuPostProcessUniforms, vol: GpuPostProcessUniforms, t:
     r.blend_weight_ca           = lerpf(base.blend_weight_ca, vol.blend_weight_ca, t);
     r.blend_weight_grain        = lerpf(base.blend_weight_grain, vol.blend_weight_grain, t);
     r.blend_weight_exposure     = lerpf(base.blend_weight_exposure, vol.blend_weight_exposure, t);
+    r.bloom_composite           = select(base.bloom_composite, vol.bloom_composite, t > 0.5);
+    r.exposure_speed_up         = lerpf(base.exposure_speed_up, vol.exposure_speed_up, t);
+    r.exposure_speed_down       = lerpf(base.exposure_speed_down, vol.exposure_speed_down, t);
+    r.exposure_metering         = select(base.exposure_metering, vol.exposure_metering, t > 0.5);
     // Fog. Every field must be assigned: `r` is declared uninitialized, so a field
     // left unwritten here is indeterminate — and the caller copies this whole struct
     // over the post-process uniform buffer, so a miss would corrupt fog config for
@@ -287,7 +320,7 @@ fn blend_settings(base: GpuPostProcessUniforms, vol: GpuPostProcessUniforms, t:
     // The struct is fully written by this function; uninitialized fields get default values.
     r._pad4 = 0.0; r._pad5 = 0.0; r._pad6 = 0.0; r._pad7 = 0.0; r._pad8 = 0.0;
     r._pad9 = 0.0; r._pad10 = 0.0; r._pad_vignette = 0.0; r._pad11 = 0.0;
-    r._pad12 = 0.0; r._pad13 = 0.0; r._pad14 = 0.0;
+    r._pad12 = 0.0; r._pad13 = 0.0; r._pad15 = 0.0;
     r._pad_fog_color = 0.0; r._pad_fog_emissive = 0.0;
     return r;
 }
@@ -366,36 +399,68 @@ fn cs_volume_blend(@builtin(local_invocation_index) lid: u32) {
     blend_output = result;
 }
 
-// ── cs_exposure: histogram-based auto exposure ─────────────────────────────────
+// ── Auto exposure: luminance histogram → adapted exposure ─────────────────────
+//
+// cs_exposure_histogram bins every pixel's scene-linear log2 luminance, weighted
+// by the metering mode. cs_exposure_adapt (one workgroup) averages the histogram,
+// eases exposure_state.exposure_ev toward the EV that maps that average to middle
+// grey, and clears the histogram for the next frame.
+
+var<workgroup> wg_histogram: array<atomic<u32>, EXPOSURE_BINS>;
+
+fn luminance_bin(l: f32) -> u32 {
+    if l < exp2(HISTOGRAM_MIN_LOG2) { return 0u; }
+    let t = clamp((log2(l) - HISTOGRAM_MIN_LOG2) / HISTOGRAM_RANGE_LOG2, 0.0, 1.0);
+    return u32(t * f32(EXPOSURE_BINS - 2u)) + 1u;
+}
 
-var<workgroup> wg_sum:   array<f32, 256>;
-var<workgroup> wg_count: array<u32, 256>;
+// Integer weight so the histogram stays atomic<u32>. Centre-weighted falls from
+// 16 at the centre to 1 at the edge of the inscribed circle and beyond.
+fn metering_weight(px: vec2<u32>, dims: vec2<u32>) -> u32 {
+    if postprocess.exposure_metering == 1u {
+        let uv = (vec2<f32>(px) + 0.5) / vec2<f32>(dims);
+        let d = clamp(length(uv - 0.5) * 2.0, 0.0, 1.0);
+        return u32(mix(16.0, 1.0, d * d));
+    }
+    return 1u;
+}
 
 @compute @workgroup_size(16, 16)
-fn cs_exposure(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(local_invocation_id) lid: vec3<u32>) {
+fn cs_exposure_histogram(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+    @builtin(local_invocation_index) lidx: u32,
+) {
+    atomicStore(&wg_histogram[lidx], 0u);
+    workgroupBarrier();
+
     let dims = textureDimensions(hdr_input);
-    let w = dims.x;
-    let h = dims.y;
-
-    let stride = 4u;
-    var sum_log: f32 = 0.0;
-    var count: u32 = 0u;
-
-    for (var y = gid.y * stride; y < h; y += stride * 16u) {
-        for (var x = gid.x * stride; x < w; x += stride * 16u) {
-            let col = textureLoad(hdr_input, vec2<i32>(i32(x), i32(y)), 0).rgb;
-            let l = max(luminance(col), 0.0001);
-            sum_log += log2(l);
-            count++;
-        }
+    if gid.x < dims.x && gid.y < dims.y {
+        let col = scene_linear(textureLoad(hdr_input, vec2<i32>(gid.xy), 0).rgb);
+        let bin = luminance_bin(luminance(col));
+        atomicAdd(&wg_histogram[bin], metering_weight(gid.xy, dims));
+    }
+    workgroupBarrier();
+
+    let count = atomicLoad(&wg_histogram[lidx]);
+    if count > 0u {
+        atomicAdd(&exposure_histogram[lidx], count);
     }
+}
 
-    let lidx = lid.y * 16u + lid.x;
-    wg_sum[lidx] = sum_log;
+var<workgroup> wg_sum:   array<f32, EXPOSURE_BINS>;
+var<workgroup> wg_count: array<u32, EXPOSURE_BINS>;
+
+@compute @workgroup_size(256)
+fn cs_exposure_adapt(@builtin(local_invocation_index) lidx: u32) {
+    // Bin 0 is below the metering range (mostly pure black: letterboxing, unlit
+    // interiors) and would drag the average toward overexposure.
+    let count = select(atomicLoad(&exposure_histogram[lidx]), 0u, lidx == 0u);
+    atomicStore(&exposure_histogram[lidx], 0u);
+    wg_sum[lidx] = f32(count) * f32(lidx);
     wg_count[lidx] = count;
     workgroupBarrier();
 
-    var reduce_active = 128u;
+    var reduce_active = EXPOSURE_BINS / 2u;
     loop {
         if reduce_active == 0u { break; }
         if lidx < reduce_active {
@@ -406,12 +471,41 @@ fn cs_exposure(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(local_inv
         reduce_active >>= 1u;
     }
 
-    if lidx == 0u && wg_count[0] > 0u {
-        let avg_log = wg_sum[0] / f32(wg_count[0]);
-        avg_luminance[0] = avg_log;
+    if lidx == 0u {
+        // An all-black frame keeps the previous average rather than snapping
+        // exposure to its maximum.
+        var avg_log = exposure_state.avg_log_luminance;
+        if wg_count[0] > 0u {
+            let avg_bin = wg_sum[0] / f32(wg_count[0]);
+            avg_log = (avg_bin - 1.0) / f32(EXPOSURE_BINS - 2u) * HISTOGRAM_RANGE_LOG2 + HISTOGRAM_MIN_LOG2;
+        }
+        exposure_state.avg_log_luminance = avg_log;
+
+        let target_ev = clamp(log2(0.18) - avg_log, postprocess.exposure_min, postprocess.exposure_max);
+        let current = exposure_state.exposure_ev;
+        // A lower target EV means the scene got brighter.
+        let seconds = select(postprocess.exposure_speed_down, postprocess.exposure_speed_up, target_ev < current);
+        var t = 1.0;
+        if seconds > 0.0 {
+            t = 1.0 - exp(-exposure_state.delta_time / seconds);
+        }
+        exposure_state.exposure_ev = mix(current, target_ev, t);
     }
 }
 
+// Soft-knee prefilter on scene-linear luminance (before exposure and
+// tonemapping): zero below `threshold - knee`, a quadratic ramp across
+// `threshold ± knee` that meets the linear `l - threshold` segment with
+// matching value and slope, so brights fade in without a visible edge.
+fn bloom_prefilter(color: vec3<f32>) -> vec3<f32> {
+    let l = luminance(color);
+    let knee = max(postprocess.bloom_knee, 0.0);
+    let thresh = postprocess.bloom_threshold;
+    let soft = clamp(l - thresh + knee, 0.0, 2.0 * knee);
+    let excess = max(soft * soft / (4.0 * knee + 0.0001), l - thresh);
+    return color * (excess / max(l, 0.0001));
+}
+
 // ── cs_bloom_down_extract: extract brights from HDR → mip 0 ───────────────────
 
 @compute @workgroup_size(8, 8)
@@ -435,22 +529,14 @@ fn cs_bloom_down_extract(@builtin(global_invocation_id) gid: vec3<u32>) {
             }
         }
     }
-    color *= 0.25;
+    color = scene_linear(color * 0.25);
 
-    let l = luminance(color);
-    let knee = postprocess.bloom_knee;
-    let thresh = postprocess.bloom_threshold;
-    var excess: f32;
-    if l <= thresh - knee {
-        excess = 0.0;
-    } else if l >= thresh {
-        excess = l - thresh;
-    } else {
-        let t = (l - (thresh - knee)) / knee;
-        excess = t * t * knee * 0.25;
+    var brights = bloom_prefilter(color);
+    // Energy-conserving bloom applies its weight at composite time, where it
+    // also removes the extracted brights from the scene.
+    if postprocess.bloom_composite == 0u {
+        brights *= postprocess.bloom_intensity * postprocess.blend_weight_bloom;
     }
-    var brights = color * (excess / max(l, 0.0001));
-    brights *= postprocess.bloom_intensity * postprocess.blend_weight_bloom;
     textureStore(bloom_dst, vec2<i32>(ix, iy), vec4<f32>(brights * postprocess.bloom_tint, 0.0));
 }
 
@@ -579,9 +665,9 @@ fn apply_ca(color: vec3<f32>, uv: vec2<f32>, dims: vec2<f32>) -> vec3<f32> {
     let dir = normalize(center);
     let r_uv = uv + dir * offset * (1.0 / dims);
     let b_uv = uv - dir * offset * (1.0 / dims);
-    let r = textureSampleLevel(hdr_input, linear_samp, r_uv, 0.0).r;
+    let r = scene_linear(textureSampleLevel(hdr_input, linear_samp, r_uv, 0.0).rgb).r;
     let g = color.g;
-    let b = textureSampleLevel(hdr_input, linear_samp, b_uv, 0.0).b;
+    let b = scene_linear(textureSampleLevel(hdr_input, linear_samp, b_uv, 0.0).rgb).b;
     return vec3<f32>(r, g, b);
 }
 
@@ -605,8 +691,11 @@ fn apply_grain(color: vec3<f32>, uv: vec2<f32>, dims: vec2<f32>) -> vec3<f32> {
 // ── Depth of Field (Gaussian approximation) ────────────────────────────────────
 
 fn dof_coc(depth: f32) -> f32 {
-    let linear_depth = -camera.proj[3][2] / (depth * 2.0 - 1.0 + camera.proj[2][2]);
-    let focal_dist = postprocess.dof_focal_distance;
+    let far = camera.forward_far.w;
+    let linear_depth = helio_view_depth(depth, camera.position_near.w, far);
+    // A focus point past the far plane behaves as focusing on the far plane:
+    // the sky stays sharp and everything nearer blurs as near field.
+    let focal_dist = min(postprocess.dof_focal_distance, far);
     let focal_region = postprocess.dof_focal_region;
     let near_blur = max(focal_dist - focal_region - linear_depth, 0.0) / max(postprocess.dof_near_transition, 0.001);
     let far_blur = max(linear_depth - (focal_dist + focal_region), 0.0) / max(postprocess.dof_far_transition, 0.001);
@@ -626,7 +715,7 @@ fn apply_dof(color: vec3<f32>, uv: vec2<f32>, depth: f32, dims: vec2<f32>) -> ve
     for (var dy = -(i32(taps) / 2); dy <= i32(taps) / 2; dy++) {
         for (var dx = -(i32(taps) / 2); dx <= i32(taps) / 2; dx++) {
             let offset = vec2<f32>(f32(dx), f32(dy)) * step * (1.0 / dims);
-            let tap = textureSampleLevel(hdr_input, linear_samp, uv + offset, 0.0).rgb;
+            let tap = scene_linear(textureSampleLevel(hdr_input, linear_samp, uv + offset, 0.0).rgb);
             let w = exp(-f32(dx * dx + dy * dy) / (2.0 * radius * 0.5));
             blurred += tap * w;
             total += w;
@@ -649,11 +738,61 @@ fn apply_motion_blur(color: vec3<f32>, uv: vec2<f32>) -> vec3<f32> {
     for (var i = 1u; i < samples; i++) {
         let t = f32(i) / f32(samples);
         let sample_uv = uv - velocity * t * max_len;
-        blurred += textureSampleLevel(hdr_input, linear_samp, sample_uv, 0.0).rgb;
+        blurred += scene_linear(textureSampleLevel(hdr_input, linear_samp, sample_uv, 0.0).rgb);
     }
     return blurred / f32(samples + 1u);
 }
 
+// ── Output transfer function ───────────────────────────────────────────────────
+// Mirrors libhelio::OutputTransfer; set per pipeline by PostProcessPass.
+
+override OUTPUT_TRANSFER: u32 = 0u;    // 0 sRGB, 1 Rec.709, 2 PQ/HDR10, 3 scRGB linear
+override OUTPUT_PEAK_NITS: f32 = 1000.0;
+override OUTPUT_HW_SRGB: bool = true;  // target applies the sRGB curve on write
+
+fn srgb_oetf(c: vec3<f32>) -> vec3<f32> {
+    let x = clamp(c, vec3<f32>(0.0), vec3<f32>(1.0));
+    return select(1.055 * pow(x, vec3<f32>(1.0 / 2.4)) - 0.055, x * 12.92, x <= vec3<f32>(0.0031308));
+}
+
+fn srgb_eotf(c: vec3<f32>) -> vec3<f32> {
+    return select(pow((c + 0.055) / 1.055, vec3<f32>(2.4)), c / 12.92, c <= vec3<f32>(0.04045));
+}
+
+fn rec709_oetf(c: vec3<f32>) -> vec3<f32> {
+    let x = clamp(c, vec3<f32>(0.0), vec3<f32>(1.0));
+    return select(1.099 * pow(x, vec3<f32>(0.45)) - 0.099, x * 4.5, x < vec3<f32>(0.018));
+}
+
+fn pq_oetf(c: vec3<f32>) -> vec3<f32> {
+    // BT.709 → BT.2020 primaries, then ST 2084 with tonemapped 1.0 at peak.
+    let to_2020 = mat3x3<f32>(
+        vec3<f32>(0.6274, 0.0691, 0.0164),
+        vec3<f32>(0.3293, 0.9195, 0.0880),
+        vec3<f32>(0.0433, 0.0114, 0.8956),
+    );
+    let y = clamp(to_2020 * max(c, vec3<f32>(0.0)) * (OUTPUT_PEAK_NITS / 10000.0), vec3<f32>(0.0), vec3<f32>(1.0));
+    let ym = pow(y, vec3<f32>(0.1593017578125));
+    return pow((0.8359375 + 18.8515625 * ym) / (1.0 + 18.6875 * ym), vec3<f32>(78.84375));
+}
+
+fn apply_output_transfer(color: vec3<f32>) -> vec3<f32> {
+    if OUTPUT_TRANSFER == 3u {
+        return color;
+    }
+    if OUTPUT_TRANSFER == 0u && OUTPUT_HW_SRGB {
+        return color; // the target's own sRGB write is the encoding
+    }
+    var encoded = srgb_oetf(color);
+    if OUTPUT_TRANSFER == 1u {
+        encoded = rec709_oetf(color);
+    } else if OUTPUT_TRANSFER == 2u {
+        encoded = pq_oetf(color);
+    }
+    // Undo the curve an sRGB target will apply so the stored signal is `encoded`.
+    return select(encoded, srgb_eotf(encoded), OUTPUT_HW_SRGB);
+}
+
 // ── fs_uber ────────────────────────────────────────────────────────────────────
 
 @fragment
@@ -661,7 +800,7 @@ fn fs_uber(in: VOut) -> @location(0) vec4<f32> {
     let dims = vec2<f32>(textureDimensions(hdr_input));
     let uv = in.uv;
 
-    var color = textureSampleLevel(hdr_input, linear_samp, uv, 0.0).rgb;
+    var color = scene_linear(textureSampleLevel(hdr_input, linear_samp, uv, 0.0).rgb);
 
     // 0. Volumetric fog composite.
     //
@@ -699,9 +838,16 @@ fn fs_uber(in: VOut) -> @location(0) vec4<f32> {
     //%P0
 
     // 1. Exposure
-    color *= exp2(postprocess.exposure_compensation);
+    var ev = postprocess.exposure_compensation;
+    if postprocess.exposure_mode == 1u {
+        ev += exposure_state.exposure_ev;
+    }
+    let exposure = exp2(ev);
+    let unexposed = color;
+    color *= exposure;
 
-    // 2. Bloom composite
+    // 2. Bloom composite — in exposed scene-linear HDR, before grading and
+    // tonemapping, so the tonemapper rolls off scene and bloom together.
     if postprocess.bloom_enabled != 0u && postprocess.bloom_intensity > 0.0 {
         var bloom = vec3<f32>(0.0);
         bloom += textureSampleLevel(bloom_0, linear_samp, uv, 0.0).rgb;
@@ -709,7 +855,17 @@ fn fs_uber(in: VOut) -> @location(0) vec4<f32> {
         bloom += textureSampleLevel(bloom_2, linear_samp, uv, 0.0).rgb;
         bloom += textureSampleLevel(bloom_3, linear_samp, uv, 0.0).rgb;
         bloom += textureSampleLevel(bloom_4, linear_samp, uv, 0.0).rgb;
-        color += bloom;
+        if postprocess.bloom_composite == 1u {
+            // Each mip holds the same energy at a wider radius, so their mean
+            // is the scattered brights. Swap this pixel's own brights for it:
+            // energy moves around the frame instead of being added.
+            let w = clamp(postprocess.bloom_intensity * postprocess.blend_weight_bloom, 0.0, 1.0);
+            let scattered = bloom * 0.2;
+            let local = bloom_prefilter(unexposed) * postprocess.bloom_tint;
+            color = max(color + w * (scattered - local) * exposure, vec3<f32>(0.0));
+        } else {
+            color += bloom;
+        }
     }
 
     // 3. Color grading
@@ -743,5 +899,8 @@ fn fs_uber(in: VOut) -> @location(0) vec4<f32> {
 
     //%P3
 
+    // 11. Output transfer function
+    color = apply_output_transfer(color);
+
     return vec4<f32>(color, 1.0);
 }
@@ -0,0 +1,608 @@
+//! User-effect injection markers in the uber shader.
+//!
+//! `postprocess.wgsl` marks each [`UserEffectPosition`] with a line comment
+//! `//%P<n>`, where `n` is the position's discriminant. A marker occupies its
+//! own line; whitespace around it and between `//` and `%P` is ignored, so
+//! reformatting the shader does not silently disconnect an injection point.
+//! [`validate_markers`] reports positions whose marker is missing, and
+//! [`unknown_markers`] lines that look like a marker but name no position.
+
+use std::ops::Range;
+
+use crate::{UserEffectEntry, UserEffectPosition};
+
+impl UserEffectPosition {
+    /// Every position, in shader order.
+    pub const ALL: [UserEffectPosition; 4] = [
+        UserEffectPosition::PreBlend,
+        UserEffectPosition::PostTonemap,
+        UserEffectPosition::PostGrain,
+        UserEffectPosition::Final,
+    ];
+
+    /// Canonical marker text for this position, e.g. `//%P1`.
+    pub fn marker(self) -> String {
+        format!("//%P{}", self as u32)
+    }
+}
+
+/// Parse a marker line, returning the position it stands for.
+pub(crate) fn parse_marker(line: &str) -> Option<UserEffectPosition> {
+    let index: u32 = line
+        .trim()
+        .strip_prefix("//")?
+        .trim_start()
+        .strip_prefix("%P")?
+        .parse()
+        .ok()?;
+    UserEffectPosition::ALL.get(index as usize).copied()
+}
+
+/// Check that `base_shader` contains a marker for every injection position.
+///
+/// Returns the positions without one; effects registered there would
+/// otherwise be dropped without any error.
+pub fn validate_markers(base_shader: &str) -> Result<(), Vec<UserEffectPosition>> {
+    let mut found = [false; UserEffectPosition::ALL.len()];
+    for pos in base_shader.lines().filter_map(parse_marker) {
+        found[pos as usize] = true;
+    }
+    let missing: Vec<_> = UserEffectPosition::ALL
+        .into_iter()
+        .filter(|pos| !found[*pos as usize])
+        .collect();
+    if missing.is_empty() { Ok(()) } else { Err(missing) }
+}
+
+/// Lines of `base_shader` that are written as a marker (`//%P…` on a line
+/// of its own) but do not name a known position, e.g. `//%P7` or `//%PI`,
+/// as 1-based line numbers with the offending text.
+///
+/// Such a line is a typo for a real marker: effects meant for that position
+/// would be dropped, while the malformed line itself is ignored.
+pub fn unknown_markers(base_shader: &str) -> Vec<(usize, String)> {
+    base_shader
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| {
+            let looks_like_marker = line
+                .trim()
+                .strip_prefix("//")
+                .is_some_and(|rest| rest.trim_start().starts_with("%P"));
+            looks_like_marker && parse_marker(line).is_none()
+        })
+        .map(|(i, line)| (i + 1, line.trim().to_owned()))
+        .collect()
+}
+
+/// A call queued at an injection marker, tagged with the index of the user
+/// effect entry that produced it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct InjectedCall {
+    pub(crate) entry: usize,
+    pub(crate) code: String,
+}
+
+/// Generated WGSL for a list of user effect entries, before splicing.
+struct Injections {
+    /// Module-scope definitions, in call order across all positions.
+    defs: Vec<(usize, UserEffectPosition, String)>,
+    calls_by_pos: [Vec<InjectedCall>; 4],
+}
+
+impl Injections {
+    /// Generate the definition and call site of every enabled entry.
+    ///
+    /// Each entry is either:
+    /// - A complete `fn user_effects(...)` definition (old API via `new_with_user_effects`)
+    ///   → placed verbatim at module scope; a call is emitted at the marker.
+    /// - A bare expression body (new API via `add_user_effect`)
+    ///   → wrapped in a generated `fn` and placed at module scope; a call emitted at the marker.
+    fn collect(entries: &[UserEffectEntry]) -> Self {
+        let mut defs = Vec::new();
+        let mut calls_by_pos: [Vec<InjectedCall>; 4] = Default::default();
+
+        // Stable sort, so equal orders keep insertion order.
+        let mut sorted: Vec<(usize, &UserEffectEntry)> = entries.iter().enumerate().collect();
+        sorted.sort_by_key(|(_, e)| e.order);
+
+        for (entry, e) in sorted {
+            let pos = e.position as usize;
+            if pos >= 4 || !e.enabled { continue; }
+
+            let trimmed = e.body.trim();
+            let fn_name = if trimmed.starts_with("fn ") {
+                // Old API: one or more complete function definitions; the
+                // main entry point is always `user_effects(...)`.
+                defs.push((entry, e.position, format!("{}\n", trimmed)));
+                "user_effects".to_string()
+            } else {
+                // New API: bare expression body — wrap in a generated function.
+                let fn_name = format!("userfx_{}", entry);
+                defs.push((entry, e.position, format!(
+                    "fn {}(color: vec3<f32>, uv: vec2<f32>, dims: vec2<f32>) -> vec3<f32> {{ return {}; }}\n",
+                    fn_name, trimmed
+                )));
+                fn_name
+            };
+            calls_by_pos[pos].push(InjectedCall {
+                entry,
+                code: format!("    color = {}(color, uv, dims);\n", fn_name),
+            });
+        }
+        Self { defs, calls_by_pos }
+    }
+}
+
+/// The spliced text for one injection position, cached between compositions.
+#[derive(Clone, Debug, Default)]
+struct PositionBlock {
+    /// Calls the block was stitched from; also records which entries
+    /// contribute to the position.
+    calls: Vec<InjectedCall>,
+    text: String,
+    /// Line count of each call, parallel to `calls`.
+    call_lines: Vec<usize>,
+    lines: usize,
+}
+
+impl PositionBlock {
+    fn stitch(position: UserEffectPosition, calls: Vec<InjectedCall>) -> Self {
+        if calls.is_empty() {
+            // Empty positions keep a comment so the generated source stays readable.
+            return Self {
+                calls,
+                text: format!("    {} (empty)\n", position.marker()),
+                call_lines: Vec::new(),
+                lines: 1,
+            };
+        }
+        let mut text = String::new();
+        let mut call_lines = Vec::with_capacity(calls.len());
+        for call in &calls {
+            call_lines.push(call.code.lines().count().max(1));
+            text.push_str(&call.code);
+        }
+        let lines = call_lines.iter().sum();
+        Self { calls, text, call_lines, lines }
+    }
+}
+
+/// Composes user effects into a base shader, reusing work across calls.
+///
+/// The base shader is split at its markers once. Each position's spliced
+/// calls are cached, and [`compose`](Self::compose) re-stitches only the
+/// positions whose calls changed; the rest of the source is concatenated
+/// from cached text instead of re-scanning the shader line by line.
+#[derive(Clone, Debug)]
+pub(crate) struct ShaderComposer {
+    /// Base shader text between markers; one more than `markers`.
+    segments: Vec<(String, usize)>,
+    /// Position of each marker, in source order. A position may repeat.
+    markers: Vec<UserEffectPosition>,
+    blocks: [Option<PositionBlock>; 4],
+    /// Positions re-stitched by the last [`compose`](Self::compose).
+    pub(crate) restitched: usize,
+}
+
+impl ShaderComposer {
+    pub(crate) fn new(base_shader: &str) -> Self {
+        let mut segments = vec![(String::new(), 0)];
+        let mut markers = Vec::new();
+        for line in base_shader.lines() {
+            match parse_marker(line) {
+                Some(pos) => {
+                    markers.push(pos);
+                    segments.push((String::new(), 0));
+                }
+                None => {
+                    let (text, lines) = segments.last_mut().unwrap();
+                    text.push_str(line);
+                    text.push('\n');
+                    *lines += 1;
+                }
+            }
+        }
+        Self { segments, markers, blocks: Default::default(), restitched: 0 }
+    }
+
+    /// Replace every marker with the calls queued for its position, then
+    /// append the definitions at module scope.
+    pub(crate) fn compose(&mut self, entries: &[UserEffectEntry]) -> ComposedShader {
+        let Injections { defs, calls_by_pos } = Injections::collect(entries);
+
+        self.restitched = 0;
+        for (pos, calls) in UserEffectPosition::ALL.into_iter().zip(calls_by_pos) {
+            let block = &mut self.blocks[pos as usize];
+            if block.as_ref().is_none_or(|b| b.calls != calls) {
+                *block = Some(PositionBlock::stitch(pos, calls));
+                self.restitched += 1;
+            }
+        }
+
+        let mut source = String::with_capacity(
+            self.segments.iter().map(|(text, _)| text.len()).sum::<usize>()
+                + self.blocks.iter().flatten().map(|b| b.text.len()).sum::<usize>(),
+        );
+        let mut regions = Vec::new();
+        let mut line_no = 1;
+        for (i, (text, lines)) in self.segments.iter().enumerate() {
+            source.push_str(text);
+            line_no += lines;
+            let Some(&pos) = self.markers.get(i) else { break };
+            let block = self.blocks[pos as usize].as_ref().unwrap();
+            let mut call_start = line_no;
+            for (call, &lines) in block.calls.iter().zip(&block.call_lines) {
+                regions.push(InjectedRegion {
+                    entry: call.entry,
+                    position: pos,
+                    kind: InjectedKind::Call,
+                    lines: call_start..call_start + lines,
+                });
+                call_start += lines;
+            }
+            source.push_str(&block.text);
+            line_no += block.lines;
+        }
+
+        if !defs.is_empty() {
+            source.push_str("\n// ── Injected user effects ──\n");
+            line_no += 2;
+            for (entry, position, def) in defs {
+                let lines = def.lines().count();
+                regions.push(InjectedRegion {
+                    entry,
+                    position,
+                    kind: InjectedKind::Definition,
+                    lines: line_no..line_no + lines,
+                });
+                source.push_str(&def);
+                line_no += lines;
+            }
+        }
+
+        ComposedShader { source, regions }
+    }
+}
+
+/// What an [`InjectedRegion`] holds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InjectedKind {
+    /// The call emitted at the entry's marker.
+    Call,
+    /// The entry's function definition, appended at module scope.
+    Definition,
+}
+
+/// Lines of the composed shader contributed by one user effect entry.
+///
+/// Every entry produces two regions: its call site at the marker and its
+/// definition at the end of the module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InjectedRegion {
+    /// Index of the entry in the order effects were added.
+    pub entry: usize,
+    pub position: UserEffectPosition,
+    pub kind: InjectedKind,
+    /// 1-based, end-exclusive line range, matching naga and wgpu diagnostics.
+    pub lines: Range<usize>,
+}
+
+/// The exact WGSL the uber pipeline was compiled from, with the lines each
+/// user effect contributed.
+#[derive(Clone, Debug, Default)]
+pub struct ComposedShader {
+    pub(crate) source: String,
+    pub(crate) regions: Vec<InjectedRegion>,
+}
+
+impl ComposedShader {
+    /// Source passed to `create_shader_module`, prelude included.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Injected regions, in source order within each kind.
+    pub fn regions(&self) -> &[InjectedRegion] {
+        &self.regions
+    }
+
+    /// The region containing 1-based `line`, e.g. from a compile error.
+    /// `None` means the line belongs to the built-in shader.
+    pub fn region_at_line(&self, line: usize) -> Option<&InjectedRegion> {
+        self.regions.iter().find(|region| region.lines.contains(&line))
+    }
+
+    /// Expand the shader prelude the way the GPU sees it, shifting the
+    /// regions by the lines it adds.
+    pub(crate) fn resolved(mut self) -> Self {
+        if helio_core::shader::uses_prelude(&self.source) {
+            let offset = helio_core::shader::prelude_lines();
+            self.source = helio_core::shader::resolve(&self.source).into_owned();
+            for region in &mut self.regions {
+                region.lines = region.lines.start + offset..region.lines.end + offset;
+            }
+        }
+        self
+    }
+}
+
+/// Replace the body of every effect named in `sources` with the contents of
+/// its file. Stops at the first unreadable file, leaving later effects as
+/// they were.
+pub(crate) fn read_effect_sources(
+    entries: &mut [UserEffectEntry],
+    sources: &[(String, std::path::PathBuf)],
+) -> Result<(), String> {
+    for (name, path) in sources {
+        let body = std::fs::read_to_string(path)
+            .map_err(|err| format!("failed to read effect '{name}' from {}: {err}", path.display()))?;
+        for entry in entries.iter_mut().filter(|e| e.name.as_deref() == Some(name.as_str())) {
+            entry.body.clone_from(&body);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UserEffectEntry;
+
+    /// Compose `entries` into `base_shader` and run the result through naga's
+    /// WGSL front end and validator, as `create_shader_module` would.
+    ///
+    /// Errors carry naga's diagnostic plus the user effect that owns the
+    /// failing line, so composition bugs are caught without a GPU.
+    fn validate_composition(base_shader: &str, entries: &[UserEffectEntry]) -> Result<(), String> {
+        let composed =
+            crate::PostProcessPass::compose_shader_source(base_shader, entries).resolved();
+        let source = composed.source();
+        let blame = |line: Option<u32>| match line.and_then(|l| composed.region_at_line(l as usize)) {
+            Some(region) => format!(
+                "in user effect {} ({}) {:?} at {:?}",
+                region.entry,
+                entries[region.entry].name.as_deref().unwrap_or("unnamed"),
+                region.kind,
+                region.position,
+            ),
+            None => "in the built-in shader".to_string(),
+        };
+
+        let module = naga::front::wgsl::parse_str(source).map_err(|e| {
+            let line = e.location(source).map(|loc| loc.line_number);
+            format!("{}\n{}", blame(line), e.emit_to_string(source))
+        })?;
+        naga::valid::Validator::new(
+            naga::valid::ValidationFlags::all(),
+            naga::valid::Capabilities::all(),
+        )
+        .validate(&module)
+        .map_err(|e| {
+            let line = e.location(source).map(|loc| loc.line_number);
+            format!("{}\n{}", blame(line), e.emit_to_string(source))
+        })?;
+        Ok(())
+    }
+
+    fn effect(position: UserEffectPosition, body: &str, name: &str) -> UserEffectEntry {
+        UserEffectEntry {
+            position,
+            body: body.into(),
+            name: Some(name.into()),
+            enabled: true,
+            order: 0,
+        }
+    }
+
+    #[test]
+    fn base_shader_has_every_marker() {
+        assert_eq!(validate_markers(crate::BASE_SHADER_SRC), Ok(()));
+        assert_eq!(unknown_markers(crate::BASE_SHADER_SRC), []);
+    }
+
+    #[test]
+    fn malformed_markers_are_reported_with_their_line() {
+        let shader = "//%P0\n  //%P7\n// %Pfinal\n// %P-1 \n// plain comment\nx = 1; //%P9\n";
+        assert_eq!(
+            unknown_markers(shader),
+            [(2, "//%P7".to_owned()), (3, "// %Pfinal".to_owned()), (4, "// %P-1".to_owned())]
+        );
+    }
+
+    #[test]
+    fn markers_tolerate_whitespace_and_report_missing() {
+        assert_eq!(parse_marker("    //%P2"), Some(UserEffectPosition::PostGrain));
+        assert_eq!(parse_marker("\t//  %P0  "), Some(UserEffectPosition::PreBlend));
+        assert_eq!(parse_marker("//%P7"), None);
+        assert_eq!(parse_marker("color = x; //%P1"), None);
+
+        let shader = "fn f() {\n  //%P0\n  // %P3\n}\n";
+        assert_eq!(
+            validate_markers(shader),
+            Err(vec![UserEffectPosition::PostTonemap, UserEffectPosition::PostGrain])
+        );
+    }
+
+    #[test]
+    fn composed_lines_map_back_to_their_effect() {
+        let entries = [
+            crate::UserEffectEntry {
+                position: UserEffectPosition::PostTonemap,
+                body: "color * 0.5".into(),
+                name: None,
+                enabled: true,
+                order: 0,
+            },
+            crate::UserEffectEntry {
+                position: UserEffectPosition::Final,
+                body: "color.bgr".into(),
+                name: None,
+                enabled: true,
+                order: 0,
+            },
+        ];
+        let composed = crate::PostProcessPass::build_shader_source(&entries).resolved();
+        let lines: Vec<&str> = composed.source().lines().collect();
+
+        assert_eq!(composed.regions().len(), 4);
+        for region in composed.regions() {
+            let text = lines[region.lines.start - 1];
+            let name = format!("userfx_{}", region.entry);
+            assert!(text.contains(&name), "line {} is {text:?}", region.lines.start);
+            let found = composed.region_at_line(region.lines.start).unwrap();
+            assert_eq!(found, region);
+        }
+        let call = composed
+            .regions()
+            .iter()
+            .find(|r| r.entry == 1 && r.kind == InjectedKind::Call)
+            .unwrap();
+        assert_eq!(call.position, UserEffectPosition::Final);
+        assert!(composed.region_at_line(1).is_none());
+    }
+
+    #[test]
+    fn disabled_effects_are_left_out_of_the_shader() {
+        let mut entries = vec![
+            crate::UserEffectEntry {
+                position: UserEffectPosition::PostTonemap,
+                body: "color * 0.5".into(),
+                name: Some("dim".into()),
+                enabled: false,
+                order: 0,
+            },
+            crate::UserEffectEntry {
+                position: UserEffectPosition::Final,
+                body: "color.bgr".into(),
+                name: Some("swizzle".into()),
+                enabled: true,
+                order: 0,
+            },
+        ];
+        let composed = crate::PostProcessPass::build_shader_source(&entries);
+        assert!(!composed.source().contains("color * 0.5"));
+        assert!(composed.regions().iter().all(|r| r.entry == 1));
+
+        entries[0].enabled = true;
+        let composed = crate::PostProcessPass::build_shader_source(&entries);
+        assert!(composed.source().contains("color * 0.5"));
+        assert_eq!(composed.regions().len(), 4);
+    }
+
+    #[test]
+    fn order_sorts_calls_within_a_position() {
+        let entry = |body: &str, order: i32| crate::UserEffectEntry {
+            position: UserEffectPosition::PostTonemap,
+            body: body.into(),
+            name: None,
+            enabled: true,
+            order,
+        };
+        let entries = [entry("color * 0.5", 10), entry("color.bgr", -5), entry("color + 0.1", 10)];
+        let composed = crate::PostProcessPass::build_shader_source(&entries);
+        let calls: Vec<usize> = composed
+            .regions()
+            .iter()
+            .filter(|r| r.kind == InjectedKind::Call)
+            .map(|r| r.entry)
+            .collect();
+        assert_eq!(calls, [1, 0, 2]);
+    }
+
+    #[test]
+    fn incremental_composition_matches_a_full_rebuild() {
+        let mut entries: Vec<_> = (0..12)
+            .map(|i| effect(UserEffectPosition::ALL[i % 4], &format!("color * {}.0", i + 1), &format!("fx{i}")))
+            .collect();
+        let mut composer = ShaderComposer::new(crate::BASE_SHADER_SRC);
+        composer.compose(&entries);
+        assert_eq!(composer.restitched, 4);
+
+        // Toggling one effect re-stitches only the position it injects into.
+        entries[5].enabled = false;
+        let incremental = composer.compose(&entries);
+        assert_eq!(composer.restitched, 1);
+        let full = crate::PostProcessPass::build_shader_source(&entries);
+        assert_eq!(incremental.source(), full.source());
+        assert_eq!(incremental.regions(), full.regions());
+
+        composer.compose(&entries);
+        assert_eq!(composer.restitched, 0);
+
+        // Removing an entry renumbers later ones, so every affected position changes.
+        entries.remove(0);
+        let incremental = composer.compose(&entries);
+        let full = crate::PostProcessPass::build_shader_source(&entries);
+        assert_eq!(incremental.source(), full.source());
+        assert_eq!(incremental.regions(), full.regions());
+    }
+
+    #[test]
+    fn documented_compositions_are_valid_wgsl() {
+        let base = crate::BASE_SHADER_SRC;
+        validate_composition(base, &[]).unwrap();
+
+        let mut every_position: Vec<_> = UserEffectPosition::ALL
+            .into_iter()
+            .map(|pos| effect(pos, "color * 0.9", "dim"))
+            .collect();
+        validate_composition(base, &every_position).unwrap();
+
+        // Several effects sharing a position, reordered and partly disabled.
+        every_position.push(effect(UserEffectPosition::PostTonemap, "color + vec3<f32>(uv, 0.0)", "tint"));
+        every_position.push(effect(UserEffectPosition::PostTonemap, "color.bgr", "swizzle"));
+        every_position[1].order = 5;
+        every_position[4].order = -1;
+        every_position[2].enabled = false;
+        validate_composition(base, &every_position).unwrap();
+
+        let legacy = effect(
+            UserEffectPosition::Final,
+            "fn user_effects(color: vec3<f32>, uv: vec2<f32>, dims: vec2<f32>) -> vec3<f32> {\n    return color;\n}",
+            "legacy",
+        );
+        validate_composition(base, &[legacy]).unwrap();
+    }
+
+    #[test]
+    fn composition_errors_name_the_offending_effect() {
+        let entries = [
+            effect(UserEffectPosition::PreBlend, "color * 0.5", "dim"),
+            effect(UserEffectPosition::PostGrain, "color * shadow_albedo", "broken"),
+        ];
+        let err = validate_composition(crate::BASE_SHADER_SRC, &entries).unwrap_err();
+        assert!(err.contains("user effect 1 (broken)"), "{err}");
+        assert!(err.contains("shadow_albedo"), "{err}");
+
+        let type_error = [effect(UserEffectPosition::Final, "uv", "wrong type")];
+        let err = validate_composition(crate::BASE_SHADER_SRC, &type_error).unwrap_err();
+        assert!(err.contains("user effect 0 (wrong type)"), "{err}");
+    }
+
+    #[test]
+    fn effect_sources_replace_bodies_by_name() {
+        let dir = std::env::temp_dir().join(format!("helio-pp-effects-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let tint = dir.join("tint.wgsl");
+        std::fs::write(&tint, "color * vec3<f32>(1.0, 0.5, 0.5)").unwrap();
+
+        let mut entries = [
+            effect(UserEffectPosition::PreBlend, "color", "tint"),
+            effect(UserEffectPosition::Final, "color", "tint"),
+            effect(UserEffectPosition::PostGrain, "color * 0.5", "dim"),
+        ];
+        read_effect_sources(&mut entries, &[("tint".into(), tint.clone())]).unwrap();
+        assert_eq!(entries[0].body, "color * vec3<f32>(1.0, 0.5, 0.5)");
+        assert_eq!(entries[1].body, entries[0].body);
+        assert_eq!(entries[2].body, "color * 0.5");
+        validate_composition(crate::BASE_SHADER_SRC, &entries).unwrap();
+
+        let missing = dir.join("missing.wgsl");
+        let err = read_effect_sources(&mut entries, &[("dim".into(), missing)]).unwrap_err();
+        assert!(err.contains("effect 'dim'"), "{err}");
+        assert_eq!(entries[2].body, "color * 0.5");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
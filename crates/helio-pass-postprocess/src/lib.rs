@@ -5,13 +5,20 @@
 //! `VolumetricFogPass`) see the blended values rather than the camera defaults.
 //!
 //! Sub-stages (execution order in `execute()`):
-//!   1. `cs_exposure`           — luminance histogram → avg luminance (compute)
-//!   2. `cs_bloom_down_extract` — extract brights from HDR → bloom mip 0 (compute)
-//!   3. `cs_bloom_down`         — 2x downsample mip chain, 4 passes (compute)
-//!   4. `fs_uber`               — tonemap, color grade, vignette, CA, grain (render)
+//!   1. `cs_exposure_histogram` — weighted log2 luminance histogram (compute)
+//!   2. `cs_exposure_adapt`     — histogram → avg luminance + adapted EV (compute)
+//!   3. `cs_bloom_down_extract` — extract brights from HDR → bloom mip 0 (compute)
+//!   4. `cs_bloom_down`         — 2x downsample mip chain, 4 passes (compute)
+//!   5. `fs_uber`               — tonemap, color grade, vignette, CA, grain (render)
+//!
+//! Auto exposure runs every frame so the metered average is always available
+//! (the renderer reads it back for automatic pre-exposure); the adapted EV is
+//! only applied in `fs_uber` under `ExposureMode::Auto`. Adaptation is eased
+//! with `PrepareContext::delta_time`, so it is frame-rate independent.
 //!
 //! Bind groups:
-//!   Main BGLs (group 0): uniforms, samplers, hdr/depth, bloom, noise, custom, volumes, blend output
+//!   Main BGLs (group 0): uniforms, samplers, hdr/depth, bloom, noise, custom, volumes, blend output,
+//!                        exposure state, histogram (compute only)
 //!   Bloom BGL (group 1): per-dispatch bloom src (sampled) + dst (storage write)
 //!   Blend BGL (group 0, separate layout): postprocess, camera, pp_volumes, blend_output
 //!
@@ -22,8 +29,12 @@
 use bytemuck;
 use helio_core::graph::ResourceBuilder;
 use helio_core::{PassContext, PrepareContext, RenderPass, Result as HelioResult};
+use libhelio::OutputTransfer;
 
+mod injection;
 mod volume_blend;
+pub use injection::{unknown_markers, validate_markers, ComposedShader, InjectedKind, InjectedRegion};
+use injection::{read_effect_sources, ShaderComposer};
 pub use volume_blend::PostProcessVolumeBlendPass;
 
 const BASE_SHADER_SRC: &str = include_str!("../shaders/postprocess.wgsl");
@@ -32,10 +43,15 @@ const BLOOM_MIPS: u32 = 5;
 const WG_BLOOM: u32 = 8;
 const WG_EXPOSURE_X: u32 = 16;
 const WG_EXPOSURE_Y: u32 = 16;
+const EXPOSURE_BINS: u64 = 256;
 #[allow(dead_code)]
 const MAX_PP_VOLUMES: u32 = 256;
 
 /// Position in the uber-shader effect chain where a user effect is injected.
+///
+/// Positions are the ordering lanes of the chain: every effect at an earlier
+/// position runs before any effect at a later one. Within a position, effects
+/// run by [`UserEffectEntry::order`], then in the order they were added.
 #[repr(u32)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum UserEffectPosition {
@@ -53,12 +69,31 @@ pub struct UserEffectEntry {
     /// Complete WGSL function body (e.g. `return color * 0.5;`).
     /// The function receives `(color: vec3<f32>, uv: vec2<f32>, dims: vec2<f32>) -> vec3<f32>`.
     pub body: String,
+    /// Name used to toggle the effect; unnamed effects are always enabled.
+    pub name: Option<String>,
+    /// Disabled effects stay registered but are left out of the composed shader.
+    pub enabled: bool,
+    /// Fine ordering within `position`: lower values run first, ties keep
+    /// insertion order. Defaults to 0.
+    pub order: i32,
+}
+
+/// Mirror of `ExposureState` in `postprocess.wgsl`.
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct ExposureState {
+    avg_log_luminance: f32,
+    exposure_ev: f32,
+    delta_time: f32,
+    _pad: f32,
 }
 
 pub struct PostProcessPass {
-    avg_luminance_buf: wgpu::Buffer,
+    exposure_state_buf: wgpu::Buffer,
+    exposure_histogram_buf: wgpu::Buffer,
 
-    exposure_pipeline: wgpu::ComputePipeline,
+    exposure_histogram_pipeline: wgpu::ComputePipeline,
+    exposure_adapt_pipeline: wgpu::ComputePipeline,
     bloom_extract_pipeline: wgpu::ComputePipeline,
     bloom_down_pipeline: wgpu::ComputePipeline,
     uber_pipeline: wgpu::RenderPipeline,
@@ -87,9 +122,8 @@ pub struct PostProcessPass {
     width: u32,
     height: u32,
     format: wgpu::TextureFormat,
-
-    first_frame: bool,
-
+    /// Encoding `fs_uber` applies last, baked into `uber_pipeline`.
+    output_transfer: OutputTransfer,
 
     // ── Bloom gating ───────────────────────────────────────────────────────
     bloom_active: bool,
@@ -111,8 +145,12 @@ pub struct PostProcessPass {
     pending_shader_snippet: Option<String>,
     // Multi-effect chain entries.
     user_effect_entries: Vec<UserEffectEntry>,
-    // Cached built shader source to avoid rebuilding identical configs.
-    cached_shader_source: Option<String>,
+    // Files `reload_shaders` re-reads named effects' bodies from.
+    user_effect_sources: Vec<(String, std::path::PathBuf)>,
+    // Source the uber pipeline was built from; identical rebuilds are skipped.
+    composed_shader: ComposedShader,
+    // Cached per-position splices, so toggling one effect re-stitches one position.
+    composer: ShaderComposer,
 }
 
 impl PostProcessPass {
@@ -140,19 +178,48 @@ impl PostProcessPass {
                 // behavior where user_effects was the only thing running.
                 position: UserEffectPosition::Final,
                 body: body.to_string(),
+                name: None,
+                enabled: true,
+                order: 0,
             }]
         }).unwrap_or_default();
 
-        let initial_src = Self::build_shader_source(&initial_entries);
+        if let Err(missing) = validate_markers(BASE_SHADER_SRC) {
+            panic!("postprocess.wgsl is missing user-effect injection markers for {missing:?}");
+        }
+        let unknown = unknown_markers(BASE_SHADER_SRC);
+        if !unknown.is_empty() {
+            panic!("postprocess.wgsl has injection markers for unknown positions (line, text): {unknown:?}");
+        }
+        let mut composer = ShaderComposer::new(BASE_SHADER_SRC);
+        let initial_shader = composer.compose(&initial_entries).resolved();
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("PostProcess Shader"),
-            source: wgpu::ShaderSource::Wgsl(helio_core::shader::resolve(&initial_src).into_owned().into()),
+            source: wgpu::ShaderSource::Wgsl(initial_shader.source().to_owned().into()),
         });
 
-        let avg_luminance_buf = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("PostProcess Avg Luminance"),
-            size: 4,
-            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+        let exposure_state_buf = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("PostProcess Exposure State"),
+            size: std::mem::size_of::<ExposureState>() as u64,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_DST
+                | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        // Start at middle grey with no adaptation, so the first frames neither
+        // flash nor fade in.
+        let initial_state = ExposureState {
+            avg_log_luminance: 0.18f32.log2(),
+            exposure_ev: 0.0,
+            delta_time: 0.0,
+            _pad: 0.0,
+        };
+        queue.write_buffer(&exposure_state_buf, 0, bytemuck::bytes_of(&initial_state));
+        // Zero-initialised on creation; cs_exposure_adapt clears it after use.
+        let exposure_histogram_buf = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("PostProcess Exposure Histogram"),
+            size: EXPOSURE_BINS * 4,
+            usage: wgpu::BufferUsages::STORAGE,
             mapped_at_creation: false,
         });
 
@@ -240,7 +307,7 @@ impl PostProcessPass {
         let fv = wgpu::ShaderStages::FRAGMENT;
         let cfv = wgpu::ShaderStages::COMPUTE | wgpu::ShaderStages::FRAGMENT;
 
-        // ── compute_main_bgl: b0-b16 (uses storage for b15-b16) ─────────────
+        // ── compute_main_bgl: b0-b14 + histogram at b18 ─────────────────────
         let compute_main_bgl = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
             label: Some("PostProcess Compute Main BGL"),
             entries: &[
@@ -254,6 +321,7 @@ impl PostProcessPass {
                 sampled_tex_entry(12, cfv, false),
                 sampler_entry(13, cfv, false),
                 storage_ro_entry(14, cfv),
+                storage_buf_entry(18, cv),
             ],
         });
 
@@ -353,38 +421,14 @@ impl PostProcessPass {
             })
         };
 
-        let exposure_pipeline = mk_compute("PostProcess Exposure", "cs_exposure", &exposure_pl);
+        let exposure_histogram_pipeline =
+            mk_compute("PostProcess Exposure Histogram", "cs_exposure_histogram", &exposure_pl);
+        let exposure_adapt_pipeline = mk_compute("PostProcess Exposure Adapt", "cs_exposure_adapt", &exposure_pl);
         let bloom_extract_pipeline = mk_compute("PostProcess Bloom Extract", "cs_bloom_down_extract", &bloom_pl);
         let bloom_down_pipeline = mk_compute("PostProcess Bloom Down", "cs_bloom_down", &bloom_pl);
 
-        let uber_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("PostProcess Uber Pipeline"),
-            layout: Some(&render_pl),
-            vertex: wgpu::VertexState {
-                module: &shader,
-                entry_point: Some("vs_fullscreen"),
-                buffers: &[],
-                compilation_options: Default::default(),
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &shader,
-                entry_point: Some("fs_uber"),
-                compilation_options: Default::default(),
-                targets: &[Some(wgpu::ColorTargetState {
-                    format,
-                    blend: None,
-                    write_mask: wgpu::ColorWrites::ALL,
-                })],
-            }),
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
-                ..Default::default()
-            },
-            depth_stencil: None,
-            multisample: wgpu::MultisampleState::default(),
-            multiview_mask: None,
-            cache: None,
-        });
+        let output_transfer = OutputTransfer::for_surface_format(format);
+        let uber_pipeline = create_uber_pipeline(device, &render_pl, &shader, format, output_transfer);
 
         // ── Noise texture ──────────────────────────────────────────────────
         let noise_size = 64u32;
@@ -461,8 +505,10 @@ impl PostProcessPass {
         let stored_snippet = user_effects_fn.map(|s| s.to_string());
 
         Self {
-            avg_luminance_buf,
-            exposure_pipeline,
+            exposure_state_buf,
+            exposure_histogram_buf,
+            exposure_histogram_pipeline,
+            exposure_adapt_pipeline,
             bloom_extract_pipeline,
             bloom_down_pipeline,
             uber_pipeline,
@@ -482,7 +528,7 @@ impl PostProcessPass {
             width,
             height,
             format,
-            first_frame: true,
+            output_transfer,
             bloom_active: true,
             noise_texture,
             noise_view,
@@ -493,7 +539,9 @@ impl PostProcessPass {
             user_shader_snippet: stored_snippet,
             pending_shader_snippet: None,
             user_effect_entries: initial_entries,
-            cached_shader_source: Some(initial_src),
+            user_effect_sources: Vec::new(),
+            composed_shader: initial_shader,
+            composer,
             uber_pl: render_pl,
         }
     }
@@ -501,104 +549,36 @@ impl PostProcessPass {
     // ── Shader source builder ────────────────────────────────────────────────
 
     /// Build the complete WGSL source by splicing user effect entries into the
-    /// base shader at `//%P0` through `//%P3` markers.
-    ///
-    /// Each entry is either:
-    /// - A complete `fn user_effects(...)` definition (old API via `new_with_user_effects`)
-    ///   → placed verbatim at module scope; a call is emitted at the marker.
-    /// - A bare expression body (new API via `add_user_effect`)
-    ///   → wrapped in a generated `fn` and placed at module scope; a call emitted at the marker.
-    fn build_shader_source(entries: &[UserEffectEntry]) -> String {
-        let base = BASE_SHADER_SRC;
-        let mut result = base.to_string();
-
-        // Collect module-scope definitions and per-position calls.
-        let mut defs = String::new();
-        let mut calls_by_pos: [Vec<String>; 4] = [vec![], vec![], vec![], vec![]];
-        let mut entry_index = 0usize;
-
-        for e in entries {
-            let pos = e.position as usize;
-            if pos >= 4 { continue; }
-
-            let trimmed = e.body.trim();
-
-            if trimmed.starts_with("fn ") {
-                // Old API: one or more complete function definitions.
-                // The last `fn` is usually the main `user_effects(...)` entry point.
-                // Extract its name for the call site.
-                let fn_name = "user_effects";
-                defs.push_str(&format!("{}\n", trimmed));
-                calls_by_pos[pos].push(format!("    color = {}(color, uv, dims);\n", fn_name));
-            } else {
-                // New API: bare expression body — wrap in a generated function.
-                let fn_name = format!("userfx_{}", entry_index);
-                entry_index += 1;
-                defs.push_str(&format!(
-                    "fn {}(color: vec3<f32>, uv: vec2<f32>, dims: vec2<f32>) -> vec3<f32> {{ return {}; }}\n",
-                    fn_name, trimmed
-                ));
-                calls_by_pos[pos].push(format!("    color = {}(color, uv, dims);\n", fn_name));
-            }
-        }
-
-        // Replace markers with calls, then append definitions at module scope.
-        for (pos, calls) in calls_by_pos.iter().enumerate() {
-            let marker = format!("//%P{}", pos);
-            if calls.is_empty() {
-                result = result.replace(&marker, &format!("//%P{} (empty)", pos));
-            } else {
-                let splice: String = calls.iter().flat_map(|c| c.chars()).collect();
-                result = result.replace(&marker, &splice);
-            }
-        }
-
-        if !defs.is_empty() {
-            result.push_str("\n// ── Injected user effects ──\n");
-            result.push_str(&defs);
-        }
+    /// base shader at `//%P0` through `//%P3` markers (see [`injection`]).
+    fn build_shader_source(entries: &[UserEffectEntry]) -> ComposedShader {
+        Self::compose_shader_source(BASE_SHADER_SRC, entries)
+    }
 
-        result
+    /// [`build_shader_source`](Self::build_shader_source) against an arbitrary
+    /// base shader, so tests can compose edited copies of `postprocess.wgsl`.
+    pub(crate) fn compose_shader_source(
+        base_shader: &str,
+        entries: &[UserEffectEntry],
+    ) -> ComposedShader {
+        ShaderComposer::new(base_shader).compose(entries)
     }
 
     fn rebuild_uber_from_entries(&mut self, device: &wgpu::Device) {
-        let source = Self::build_shader_source(&self.user_effect_entries);
-        if self.cached_shader_source.as_deref() == Some(&source) {
+        let composed = self.composer.compose(&self.user_effect_entries).resolved();
+        if self.composed_shader.source() == composed.source() {
             return; // identical — skip rebuild
         }
+        self.build_uber_pipeline(device, composed);
+    }
+
+    fn build_uber_pipeline(&mut self, device: &wgpu::Device, composed: ComposedShader) {
         let shader_mod = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("PostProcess Shader"),
-            source: wgpu::ShaderSource::Wgsl(helio_core::shader::resolve(&source).into_owned().into()),
-        });
-        self.uber_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("PostProcess Uber"),
-            layout: Some(&self.uber_pl),
-            vertex: wgpu::VertexState {
-                module: &shader_mod,
-                entry_point: Some("vs_fullscreen"),
-                buffers: &[],
-                compilation_options: Default::default(),
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &shader_mod,
-                entry_point: Some("fs_uber"),
-                compilation_options: Default::default(),
-                targets: &[Some(wgpu::ColorTargetState {
-                    format: self.format,
-                    blend: None,
-                    write_mask: wgpu::ColorWrites::ALL,
-                })],
-            }),
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
-                ..Default::default()
-            },
-            depth_stencil: None,
-            multisample: wgpu::MultisampleState::default(),
-            multiview_mask: None,
-            cache: None,
+            source: wgpu::ShaderSource::Wgsl(composed.source().to_owned().into()),
         });
-        self.cached_shader_source = Some(source);
+        self.uber_pipeline =
+            create_uber_pipeline(device, &self.uber_pl, &shader_mod, self.format, self.output_transfer);
+        self.composed_shader = composed;
     }
 
     // ── Public API ───────────────────────────────────────────────────────────
@@ -609,6 +589,22 @@ impl PostProcessPass {
         self.bloom_active = active;
     }
 
+    /// Select the transfer function applied as the last step of `fs_uber`.
+    ///
+    /// Defaults to [`OutputTransfer::for_surface_format`] of the output
+    /// format. Rebuilds the uber pipeline when the transfer changes.
+    pub fn set_output_transfer(&mut self, device: &wgpu::Device, transfer: OutputTransfer) {
+        if transfer == self.output_transfer {
+            return;
+        }
+        self.output_transfer = transfer;
+        self.build_uber_pipeline(device, self.composed_shader.clone());
+    }
+
+    pub fn output_transfer(&self) -> OutputTransfer {
+        self.output_transfer
+    }
+
     /// Queue a new user shader snippet to be applied at the start of the next frame.
     /// The pipeline rebuild happens in `prepare()`, not on the calling thread.
     /// Pass `None` to restore the default no-op.
@@ -617,18 +613,88 @@ impl PostProcessPass {
     }
 
     /// Add a user effect entry at the given position in the effect chain.
-    /// Effects are applied in the order they are added.
+    /// Effects at one position are applied in the order they are added,
+    /// unless reordered with [`set_user_effect_order`](Self::set_user_effect_order).
     /// Call `commit_user_effects()` to rebuild the uber-pipeline.
     pub fn add_user_effect(&mut self, position: UserEffectPosition, body: &str) {
         self.user_effect_entries.push(UserEffectEntry {
             position,
             body: body.to_string(),
+            name: None,
+            enabled: true,
+            order: 0,
         });
     }
 
+    /// Like [`add_user_effect`](Self::add_user_effect), but under `name` so
+    /// it can later be switched with [`apply_effect_set`](Self::apply_effect_set).
+    /// Adding a name that already exists adds a second effect; both toggle together.
+    pub fn add_named_user_effect(&mut self, name: &str, position: UserEffectPosition, body: &str) {
+        self.user_effect_entries.push(UserEffectEntry {
+            position,
+            body: body.to_string(),
+            name: Some(name.to_string()),
+            enabled: true,
+            order: 0,
+        });
+    }
+
+    /// Enable or disable every named effect listed in `effects`, then rebuild
+    /// the uber-pipeline once.
+    ///
+    /// Meant for quality presets and scene transitions that flip many effects
+    /// at once: N toggles cost one shader compile instead of N, and none if
+    /// the resulting shader is unchanged. Names that match no effect are
+    /// skipped and returned; the rest of the set is still applied.
+    pub fn apply_effect_set(&mut self, device: &wgpu::Device, effects: &[(&str, bool)]) -> Vec<String> {
+        let mut missing = Vec::new();
+        for &(name, enabled) in effects {
+            let mut found = false;
+            for entry in self
+                .user_effect_entries
+                .iter_mut()
+                .filter(|e| e.name.as_deref() == Some(name))
+            {
+                entry.enabled = enabled;
+                found = true;
+            }
+            if !found {
+                missing.push(name.to_string());
+            }
+        }
+        self.rebuild_uber_from_entries(device);
+        missing
+    }
+
+    /// Set the order of every effect named `name` within its position; lower
+    /// runs first. Returns `false` if no effect has that name. Call
+    /// [`commit_user_effects`](Self::commit_user_effects) to rebuild.
+    pub fn set_user_effect_order(&mut self, name: &str, order: i32) -> bool {
+        let mut found = false;
+        for entry in self
+            .user_effect_entries
+            .iter_mut()
+            .filter(|e| e.name.as_deref() == Some(name))
+        {
+            entry.order = order;
+            found = true;
+        }
+        found
+    }
+
+    /// Whether the named effect is currently part of the composed shader.
+    /// `None` if no effect has that name.
+    pub fn user_effect_enabled(&self, name: &str) -> Option<bool> {
+        self.user_effect_entries
+            .iter()
+            .find(|e| e.name.as_deref() == Some(name))
+            .map(|e| e.enabled)
+    }
+
     /// Remove all user effect entries and rebuild the pipeline.
     pub fn clear_user_effects(&mut self, device: &wgpu::Device) {
         self.user_effect_entries.clear();
+        self.user_effect_sources.clear();
         self.rebuild_uber_from_entries(device);
     }
 
@@ -638,6 +704,125 @@ impl PostProcessPass {
         self.rebuild_uber_from_entries(device);
     }
 
+    /// Back the named effect's body with the WGSL in `path`, so
+    /// [`reload_shaders`](Self::reload_shaders) picks up edits to it. Returns
+    /// `false` if no effect has that name. Registering a name again replaces
+    /// its path; the body already in use is kept until the next reload.
+    pub fn set_user_effect_source_path(&mut self, name: &str, path: impl Into<std::path::PathBuf>) -> bool {
+        if !self.user_effect_entries.iter().any(|e| e.name.as_deref() == Some(name)) {
+            return false;
+        }
+        let path = path.into();
+        match self.user_effect_sources.iter_mut().find(|(n, _)| n == name) {
+            Some((_, existing)) => *existing = path,
+            None => self.user_effect_sources.push((name.to_string(), path)),
+        }
+        true
+    }
+
+    /// Injection point and backing file of every effect registered with
+    /// [`set_user_effect_source_path`](Self::set_user_effect_source_path).
+    pub fn shader_source_paths(&self) -> Vec<(UserEffectPosition, std::path::PathBuf)> {
+        self.user_effect_sources
+            .iter()
+            .flat_map(|(name, path)| {
+                self.user_effect_entries
+                    .iter()
+                    .filter(move |e| e.name.as_deref() == Some(name.as_str()))
+                    .map(move |e| (e.position, path.clone()))
+            })
+            .collect()
+    }
+
+    /// Re-read the uber shader template (`postprocess.wgsl`) from `path` and
+    /// rebuild the pipeline with the current user effects, for iterating on
+    /// the shader without recompiling. The built-in copy is embedded at
+    /// compile time, so release builds never touch the file system.
+    ///
+    /// Missing or malformed injection markers and shader or pipeline
+    /// validation errors are returned instead of panicking, and the previous
+    /// pipeline stays in use. On the web the validation result arrives
+    /// asynchronously, so there compile errors go to the device's uncaptured
+    /// error handler instead.
+    ///
+    /// Effect bodies are left as they are; see
+    /// [`reload_shaders`](Self::reload_shaders) to re-read those too.
+    pub fn reload_base_shader(&mut self, device: &wgpu::Device, path: &std::path::Path) -> Result<(), String> {
+        let entries = self.user_effect_entries.clone();
+        self.rebuild_from_files(device, path, entries)
+    }
+
+    /// Like [`reload_base_shader`](Self::reload_base_shader), but also
+    /// re-reads every effect body registered with
+    /// [`set_user_effect_source_path`](Self::set_user_effect_source_path).
+    /// Nothing changes unless every file reads and the result compiles.
+    pub fn reload_shaders(&mut self, device: &wgpu::Device, template: &std::path::Path) -> Result<(), String> {
+        let mut entries = self.user_effect_entries.clone();
+        read_effect_sources(&mut entries, &self.user_effect_sources)?;
+        self.rebuild_from_files(device, template, entries)
+    }
+
+    fn rebuild_from_files(
+        &mut self,
+        device: &wgpu::Device,
+        path: &std::path::Path,
+        entries: Vec<UserEffectEntry>,
+    ) -> Result<(), String> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+        if let Err(missing) = validate_markers(&source) {
+            return Err(format!("{} is missing injection markers for {missing:?}", path.display()));
+        }
+        let unknown = unknown_markers(&source);
+        if !unknown.is_empty() {
+            return Err(format!(
+                "{} has injection markers for unknown positions (line, text): {unknown:?}",
+                path.display()
+            ));
+        }
+
+        let mut composer = ShaderComposer::new(&source);
+        let composed = composer.compose(&entries).resolved();
+        let scope = device.push_error_scope(wgpu::ErrorFilter::Validation);
+        let shader_mod = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("PostProcess Shader"),
+            source: wgpu::ShaderSource::Wgsl(composed.source().to_owned().into()),
+        });
+        let pipeline = create_uber_pipeline(device, &self.uber_pl, &shader_mod, self.format, self.output_transfer);
+        // Native backends validate synchronously, so the scope is already
+        // resolved; only the web backend leaves it pending.
+        let mut pop = std::pin::pin!(scope.pop());
+        let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+        if let std::task::Poll::Ready(Some(err)) = std::future::Future::poll(pop.as_mut(), &mut cx) {
+            return Err(format!("{}: {err}", path.display()));
+        }
+
+        self.composer = composer;
+        self.uber_pipeline = pipeline;
+        self.composed_shader = composed;
+        self.user_effect_entries = entries;
+        Ok(())
+    }
+
+    /// The WGSL the uber pipeline was last compiled from, exactly as passed
+    /// to `create_shader_module`.
+    pub fn composed_shader_source(&self) -> &str {
+        self.composed_shader.source()
+    }
+
+    /// The composed uber shader together with the lines each user effect
+    /// contributed, for attributing compile errors to an effect.
+    pub fn composed_shader(&self) -> &ComposedShader {
+        &self.composed_shader
+    }
+
+    /// Auto-exposure state. Its first `f32` is the metered average log2
+    /// scene-linear luminance of the last frame, written by `cs_exposure_adapt`.
+    /// `COPY_SRC`, so the renderer can read it back to drive automatic pre-exposure.
+    pub fn avg_luminance_buffer(&self) -> &wgpu::Buffer {
+        &self.exposure_state_buf
+    }
+
     /// Upload custom float4 parameters that the shader reads from `pp_custom`.
     pub fn set_custom_params(&mut self, params: &[[f32; 4]]) {
         self.custom_params.clear();
@@ -721,10 +906,11 @@ impl PostProcessPass {
                 wgpu::BindGroupEntry { binding: 3, resource: wgpu::BindingResource::TextureView(depth_view) },
                 wgpu::BindGroupEntry { binding: 4, resource: wgpu::BindingResource::Sampler(&self.linear_sampler) },
                 wgpu::BindGroupEntry { binding: 5, resource: wgpu::BindingResource::Sampler(&self.point_sampler) },
-                wgpu::BindGroupEntry { binding: 11, resource: self.avg_luminance_buf.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 11, resource: self.exposure_state_buf.as_entire_binding() },
                 wgpu::BindGroupEntry { binding: 12, resource: wgpu::BindingResource::TextureView(&self.noise_view) },
                 wgpu::BindGroupEntry { binding: 13, resource: wgpu::BindingResource::Sampler(&self.noise_sampler) },
                 wgpu::BindGroupEntry { binding: 14, resource: self.custom_params_buf.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 18, resource: self.exposure_histogram_buf.as_entire_binding() },
             ],
         }));
 
@@ -743,7 +929,7 @@ impl PostProcessPass {
                 wgpu::BindGroupEntry { binding: 8, resource: wgpu::BindingResource::TextureView(&self.bloom_sampled_views[2]) },
                 wgpu::BindGroupEntry { binding: 9, resource: wgpu::BindingResource::TextureView(&self.bloom_sampled_views[3]) },
                 wgpu::BindGroupEntry { binding: 10, resource: wgpu::BindingResource::TextureView(&self.bloom_sampled_views[4]) },
-                wgpu::BindGroupEntry { binding: 11, resource: self.avg_luminance_buf.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 11, resource: self.exposure_state_buf.as_entire_binding() },
                 wgpu::BindGroupEntry { binding: 12, resource: wgpu::BindingResource::TextureView(&self.noise_view) },
                 wgpu::BindGroupEntry { binding: 13, resource: wgpu::BindingResource::Sampler(&self.noise_sampler) },
                 wgpu::BindGroupEntry { binding: 14, resource: self.custom_params_buf.as_entire_binding() },
@@ -758,11 +944,71 @@ impl PostProcessPass {
     }
 }
 
+/// Build the `fs_uber` pipeline with `transfer` baked in through the
+/// `OUTPUT_*` override constants of `postprocess.wgsl`.
+fn create_uber_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    module: &wgpu::ShaderModule,
+    format: wgpu::TextureFormat,
+    transfer: OutputTransfer,
+) -> wgpu::RenderPipeline {
+    let constants = [
+        ("OUTPUT_TRANSFER", transfer.shader_id() as f64),
+        ("OUTPUT_PEAK_NITS", transfer.peak_nits() as f64),
+        ("OUTPUT_HW_SRGB", if format.is_srgb() { 1.0 } else { 0.0 }),
+    ];
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("PostProcess Uber"),
+        layout: Some(layout),
+        vertex: wgpu::VertexState {
+            module,
+            entry_point: Some("vs_fullscreen"),
+            buffers: &[],
+            compilation_options: Default::default(),
+        },
+        fragment: Some(wgpu::FragmentState {
+            module,
+            entry_point: Some("fs_uber"),
+            compilation_options: wgpu::PipelineCompilationOptions {
+                constants: &constants,
+                ..Default::default()
+            },
+            targets: &[Some(wgpu::ColorTargetState {
+                format,
+                blend: None,
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+        }),
+        primitive: wgpu::PrimitiveState {
+            topology: wgpu::PrimitiveTopology::TriangleList,
+            ..Default::default()
+        },
+        depth_stencil: None,
+        multisample: wgpu::MultisampleState::default(),
+        multiview_mask: None,
+        cache: None,
+    })
+}
+
 impl RenderPass for PostProcessPass {
     fn name(&self) -> &'static str {
         "PostProcess"
     }
 
+    fn gpu_memory_bytes(&self) -> u64 {
+        let textures: u64 = self
+            .bloom_textures
+            .iter()
+            .chain(std::iter::once(&self.noise_texture))
+            .map(helio_core::texture_memory_bytes)
+            .sum();
+        textures
+            + self.exposure_state_buf.size()
+            + self.exposure_histogram_buf.size()
+            + self.custom_params_buf.size()
+    }
+
     fn reads(&self) -> &'static [&'static str] {
         &["pre_aa", "fog_accum"]
     }
@@ -797,15 +1043,14 @@ impl RenderPass for PostProcessPass {
         self.render_main_bg = None;
         self.main_bg_key = None;
         self.bloom_extract_bg = None;
-        self.first_frame = true;
     }
 
     fn prepare(&mut self, ctx: &PrepareContext) -> HelioResult<()> {
-        if self.first_frame {
-            self.first_frame = false;
-            let initial: f32 = 0.18;
-            ctx.queue.write_buffer(&self.avg_luminance_buf, 0, bytemuck::bytes_of(&initial));
-        }
+        ctx.queue.write_buffer(
+            &self.exposure_state_buf,
+            std::mem::offset_of!(ExposureState, delta_time) as u64,
+            bytemuck::bytes_of(&ctx.delta_time),
+        );
 
         // Deferred shader rebuild: if a snippet was queued, apply it now.
         if self.pending_shader_snippet.is_some() {
@@ -818,6 +1063,9 @@ impl RenderPass for PostProcessPass {
                 self.user_effect_entries.push(UserEffectEntry {
                     position: UserEffectPosition::Final,
                     body: pending.clone(),
+                    name: None,
+                    enabled: true,
+                    order: 0,
                 });
                 self.rebuild_uber_from_entries(ctx.device);
                 self.user_shader_snippet = Some(pending);
@@ -891,17 +1139,21 @@ impl RenderPass for PostProcessPass {
         //    VolumetricFogPass reads the blended fog config earlier in the graph,
         //    and would silently get the unblended camera defaults instead.
 
-        // 1. Auto-exposure histogram
+        // 1. Auto-exposure: histogram, then average + adapt (one workgroup)
         {
             let mut cpass = unsafe { &mut *ce }.begin_compute_pass(&wgpu::ComputePassDescriptor {
                 label: Some("PostProcess Exposure"),
                 timestamp_writes: None,
             });
-            cpass.set_pipeline(&self.exposure_pipeline);
             cpass.set_bind_group(0, compute_bg, &[]);
-            let gx = (self.width / (4 * WG_EXPOSURE_X)).max(1);
-            let gy = (self.height / (4 * WG_EXPOSURE_Y)).max(1);
-            cpass.dispatch_workgroups(gx, gy, 1);
+            cpass.set_pipeline(&self.exposure_histogram_pipeline);
+            cpass.dispatch_workgroups(
+                self.width.div_ceil(WG_EXPOSURE_X),
+                self.height.div_ceil(WG_EXPOSURE_Y),
+                1,
+            );
+            cpass.set_pipeline(&self.exposure_adapt_pipeline);
+            cpass.dispatch_workgroups(1, 1, 1);
         }
 
         // 2. Bloom (only when active)
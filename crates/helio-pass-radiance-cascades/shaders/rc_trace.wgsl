@@ -1,7 +1,7 @@
 // Radiance Cascades - trace + merge compute shader.
 //
 // Y-UP octahedral encoding (Y is the pole axis, matching scene convention).
-// Atlas layout (atlas_w = probe_dim * dir_dim = 32 always):
+// Atlas layout (atlas_w = probe_dim * dir_dim, constant across cascades):
 //   atlas_x = probe_x * dir_dim  +  dir_x
 //   atlas_y = (probe_y * probe_dim + probe_z) * dir_dim  +  dir_y
 //
@@ -13,6 +13,11 @@
 
 enable wgpu_ray_query;
 
+// Mirrors config.rs; resolve_wgsl_constants() rewrites both lines with the
+// Rust values before compiling, so the CPU limit stays the only source.
+const MAX_CASCADES: u32 = 4u;
+const DIR_DIM: u32 = 4u;
+
 // GpuLight (matches Rust GpuLight in lighting.rs, 48 bytes)
 struct GpuLight {
     position:    vec3<f32>,
@@ -31,10 +36,16 @@ struct RCDynamic {
     world_max:   vec4<f32>,
     frame:       u32,
     light_count: u32,
-    _pad0:       u32,
-    _pad1:       u32,
+    probe_dim:   u32,   // cascade-0 probes per axis (fallback path only)
+    march_steps: u32,   // fallback path only
     /// Sky radiance for miss rays (rgb = linear colour, w unused).
     sky_color:   vec4<f32>,
+    max_ray_dist:     f32,
+    /// EMA weight of the current frame when integration_mode == 0.
+    temporal_blend:   f32,
+    /// 0 = temporal EMA, 1 = immediate (no history).
+    integration_mode: u32,
+    _pad0:            u32,
 }
 
 struct CascadeStatic {
@@ -43,9 +54,9 @@ struct CascadeStatic {
     dir_dim:          u32,
     t_max_bits:       u32,
     parent_probe_dim: u32,
-    parent_dir_dim:   u32,
+    parent_dir_dim:   u32,   // 0 for the coarsest cascade
+    t_min_bits:       u32,
     _pad0: u32,
-    _pad1: u32,
 }
 
 @group(0) @binding(0) var cascade_out:           texture_storage_2d<rgba16float, write>;
@@ -173,7 +184,7 @@ fn cs_trace(@builtin(global_invocation_id) gid: vec3<u32>) {
     let atlas_w   = probe_dim * dir_dim;
     let atlas_h   = probe_dim * probe_dim * dir_dim;
 
-    if gid.x >= atlas_w || gid.y >= atlas_h { return; }
+    if gid.x >= atlas_w || gid.y >= atlas_h || rc_stat.cascade_index >= MAX_CASCADES { return; }
 
     let dx  = gid.x % dir_dim;
     let px  = gid.x / dir_dim;
@@ -188,11 +199,13 @@ fn cs_trace(@builtin(global_invocation_id) gid: vec3<u32>) {
 
     let dir_uv = (vec2<f32>(f32(dx), f32(dy)) + 0.5) / f32(dir_dim);
     let dir    = oct_decode(dir_uv);
+    let t_min  = max(bitcast<f32>(rc_stat.t_min_bits), 0.001);
     let t_max  = bitcast<f32>(rc_stat.t_max_bits);
+    let has_parent = rc_stat.parent_dir_dim > 0u;
 
     var rq: ray_query;
     rayQueryInitialize(&rq, acc_struct,
-        RayDesc(0x01u, 0xFFu, 0.001, t_max, probe_pos, dir));
+        RayDesc(0x01u, 0xFFu, t_min, t_max, probe_pos, dir));
     rayQueryProceed(&rq);
     let isect = rayQueryGetCommittedIntersection(&rq);
 
@@ -217,6 +230,11 @@ fn cs_trace(@builtin(global_invocation_id) gid: vec3<u32>) {
 
         radiance   = light_contrib;
         throughput = 0.0;
+    } else if has_parent {
+        // Missed within this cascade's interval — the coarser cascade
+        // continues the ray, so stay transparent and let the merge fill it.
+        radiance   = vec3<f32>(0.0);
+        throughput = 1.0;
     } else {
         // Sky miss — the ray escaped to the sky.  Contribute sky radiance
         // based on the ray direction so the GI naturally fills shadowed areas
@@ -233,7 +251,7 @@ fn cs_trace(@builtin(global_invocation_id) gid: vec3<u32>) {
     // OPTIMIZED: Nearest-neighbor parent probe lookup instead of trilinear
     // Reduces from 8 probe reads (32 texture loads) to 1 probe read (1 texture load)
     // The slight reduction in smoothness is imperceptible due to temporal accumulation
-    if rc_stat.cascade_index < 3u && rc_stat.parent_dir_dim > 0u {
+    if has_parent {
         let pdim  = rc_stat.parent_dir_dim;
         let ppdim = rc_stat.parent_probe_dim;
 
@@ -250,9 +268,10 @@ fn cs_trace(@builtin(global_invocation_id) gid: vec3<u32>) {
     }
 
     // ── Temporal accumulation: EMA blend with previous frame ──────────────
-    // alpha=0.15 → ~6-frame convergence. First frame (history=0) blends cleanly.
+    // alpha = temporal_blend (0.15 at Medium → ~6-frame convergence). First
+    // frame (history=0) blends cleanly. Immediate mode (integration_mode == 1) uses alpha=1 and ignores history.
     let hist = textureLoad(cascade_history, vec2<i32>(i32(gid.x), i32(gid.y)), 0);
-    let alpha = 0.15;
+    let alpha = select(rc_dyn.temporal_blend, 1.0, rc_dyn.integration_mode == 1u);
     radiance   = mix(hist.rgb, radiance,   alpha);
     throughput = mix(hist.w,   throughput, alpha);
 
@@ -0,0 +1,254 @@
+//! Radiance-cascade quality presets and runtime configuration.
+
+use serde::{Deserialize, Serialize};
+
+/// Octahedral direction bins per probe axis in cascade 0.
+///
+/// Fixed across presets: the deferred-lighting and SSR consumers integrate a
+/// 4×4 bin layout, so only the probe grid and cascade count scale with quality.
+pub const DIR_DIM: u32 = 4;
+
+/// Upper bound on [`RadianceCascadesConfig::cascade_count`].
+///
+/// The single place the cascade limit lives: presets are checked against it at
+/// compile time and the shaders receive it through [`wgsl_constants`].
+pub const MAX_CASCADES: u32 = 4;
+
+/// WGSL declarations of [`MAX_CASCADES`] and [`DIR_DIM`].
+pub fn wgsl_constants() -> String {
+    format!("const MAX_CASCADES: u32 = {MAX_CASCADES}u;\nconst DIR_DIM: u32 = {DIR_DIM}u;")
+}
+
+/// Rewrites the `const MAX_CASCADES` / `const DIR_DIM` declarations in
+/// `source` with the values from [`wgsl_constants`].
+///
+/// The shaders keep literal declarations so they still validate as
+/// standalone files; whatever values they hold, the compiled shader always
+/// uses the CPU-side limits.
+pub fn resolve_wgsl_constants(source: &str) -> String {
+    let generated = wgsl_constants();
+    let mut out = String::with_capacity(source.len());
+    for line in source.lines() {
+        let trimmed = line.trim_start();
+        let replacement = ["const MAX_CASCADES:", "const DIR_DIM:"]
+            .iter()
+            .zip(generated.lines())
+            .find(|(prefix, _)| trimmed.starts_with(*prefix))
+            .map(|(_, decl)| decl);
+        out.push_str(replacement.unwrap_or(line));
+        out.push('\n');
+    }
+    out
+}
+
+// Raising a preset past the limit would trace cascades the shaders and
+// per-cascade buffers were never sized for.
+const _: () = {
+    let mut i = 0;
+    while i < GIQuality::ALL.len() {
+        assert!(
+            GIQuality::ALL[i].cascade_count() <= MAX_CASCADES,
+            "GIQuality preset exceeds MAX_CASCADES"
+        );
+        i += 1;
+    }
+};
+
+/// Named GI quality tiers, mapped to concrete parameters by
+/// [`RadianceCascadesConfig::preset`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GIQuality {
+    /// 1 cascade, 4³ probes, 16 march steps, 50 m rays — integrated GPUs.
+    Low,
+    /// 2 cascades, 8³ probes, 32 march steps, 100 m rays — default.
+    #[default]
+    Medium,
+    /// 3 cascades, 16³ probes, 48 march steps, 150 m rays — high-end PC.
+    High,
+    /// 4 cascades, 32³ probes, 64 march steps, 200 m rays — cinematic.
+    Ultra,
+}
+
+impl GIQuality {
+    /// Every tier, lowest first.
+    pub const ALL: [GIQuality; 4] = [
+        GIQuality::Low,
+        GIQuality::Medium,
+        GIQuality::High,
+        GIQuality::Ultra,
+    ];
+
+    /// Cascades traced at this tier; never more than [`MAX_CASCADES`].
+    pub const fn cascade_count(self) -> u32 {
+        match self {
+            GIQuality::Low => 1,
+            GIQuality::Medium => 2,
+            GIQuality::High => 3,
+            GIQuality::Ultra => MAX_CASCADES,
+        }
+    }
+
+    /// Tier selected by a renderer-wide [`QualityPreset`](helio_core::QualityPreset),
+    /// or `None` where the preset turns GI off.
+    pub const fn from_preset(preset: helio_core::QualityPreset) -> Option<GIQuality> {
+        match preset {
+            helio_core::QualityPreset::Low => None,
+            helio_core::QualityPreset::Medium => Some(GIQuality::Medium),
+            helio_core::QualityPreset::High => Some(GIQuality::High),
+            helio_core::QualityPreset::Ultra => Some(GIQuality::Ultra),
+        }
+    }
+}
+
+/// How each frame's trace result is folded into the cascade atlas.
+///
+/// Uploaded as `integration_mode` and read by `rc_trace.wgsl`, so it can be
+/// switched between frames with
+/// [`RadianceCascadesPass::set_integration_mode`](crate::RadianceCascadesPass::set_integration_mode).
+/// The screen-space fallback keeps no history and always behaves as
+/// [`Immediate`](Self::Immediate).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IntegrationMode {
+    /// Exponential moving average with the previous frame, weighted by
+    /// [`RadianceCascadesConfig::temporal_blend`]. Stable but lags behind
+    /// fast lighting changes.
+    #[default]
+    Temporal = 0,
+    /// Every frame overwrites the atlas. Noisy, but free of temporal lag —
+    /// useful when diagnosing ghosting.
+    Immediate = 1,
+}
+
+/// Runtime configuration of [`RadianceCascadesPass`](crate::RadianceCascadesPass).
+///
+/// Changing `probe_dim` or `cascade_count` resizes the cascade atlases; apply
+/// it through [`RadianceCascadesPass::set_config`](crate::RadianceCascadesPass::set_config)
+/// so the render graph reallocates them before the next frame.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RadianceCascadesConfig {
+    /// Quality tier this config was derived from (informational once edited).
+    pub quality: GIQuality,
+    /// Number of cascades traced on the ray-query path (1..=[`MAX_CASCADES`]).
+    /// Cascade `i` has `probe_dim >> i` probes per axis and
+    /// `DIR_DIM << i` direction bins. The screen-space fallback traces
+    /// cascade 0 only.
+    pub cascade_count: u32,
+    /// Probes per axis of the cascade-0 grid. Must be a power of two and at
+    /// least `1 << (cascade_count - 1)`.
+    pub probe_dim: u32,
+    /// EMA weight of the current frame in [`IntegrationMode::Temporal`].
+    pub temporal_blend: f32,
+    /// Distance covered by the coarsest cascade's rays (metres).
+    pub max_ray_distance: f32,
+    /// Screen-space march steps per ray on the fallback path.
+    pub march_steps: u32,
+    /// How trace results accumulate across frames on the ray-query path.
+    pub integration_mode: IntegrationMode,
+    /// Recenter the cascade volume on the camera every frame (snapped to the
+    /// probe grid, see [`volume_bounds`](Self::volume_bounds)). When `false`
+    /// the volume stays centred on the world origin.
+    pub follow_camera: bool,
+}
+
+impl Default for RadianceCascadesConfig {
+    fn default() -> Self {
+        Self::preset(GIQuality::Medium)
+    }
+}
+
+impl RadianceCascadesConfig {
+    /// Create a configuration from a quality preset.
+    ///
+    /// | Preset | Cascades | Probes | Blend | Ray dist | March steps | Atlas (cascade 0) |
+    /// |--------|----------|--------|-------|----------|-------------|-------------------|
+    /// | Low    | 1        | 4³     | 0.25  | 50 m     | 16          | 16 × 64           |
+    /// | Medium | 2        | 8³     | 0.15  | 100 m    | 32          | 32 × 256          |
+    /// | High   | 3        | 16³    | 0.10  | 150 m    | 48          | 64 × 1024         |
+    /// | Ultra  | 4        | 32³    | 0.08  | 200 m    | 64          | 128 × 4096        |
+    ///
+    /// All presets use [`IntegrationMode::Temporal`] and follow the camera.
+    pub fn preset(quality: GIQuality) -> Self {
+        let (probe_dim, temporal_blend, max_ray_distance, march_steps) = match quality {
+            GIQuality::Low => (4, 0.25, 50.0, 16),
+            GIQuality::Medium => (8, 0.15, 100.0, 32),
+            GIQuality::High => (16, 0.10, 150.0, 48),
+            GIQuality::Ultra => (32, 0.08, 200.0, 64),
+        };
+        Self {
+            quality,
+            cascade_count: quality.cascade_count(),
+            probe_dim,
+            temporal_blend,
+            max_ray_distance,
+            march_steps,
+            integration_mode: IntegrationMode::Temporal,
+            follow_camera: true,
+        }
+    }
+
+    /// Check that the configuration describes a valid cascade hierarchy.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.cascade_count == 0 || self.cascade_count > MAX_CASCADES {
+            return Err(format!(
+                "cascade_count {} out of range 1..={MAX_CASCADES}",
+                self.cascade_count
+            ));
+        }
+        if !self.probe_dim.is_power_of_two() || self.probe_dim < 1 << (self.cascade_count - 1) {
+            return Err(format!(
+                "probe_dim {} must be a power of two >= {} for {} cascades",
+                self.probe_dim,
+                1u32 << (self.cascade_count - 1),
+                self.cascade_count
+            ));
+        }
+        if self.temporal_blend.is_nan() || self.temporal_blend <= 0.0 || self.temporal_blend > 1.0 {
+            return Err(format!("temporal_blend {} must be in (0, 1]", self.temporal_blend));
+        }
+        if self.max_ray_distance.is_nan() || self.max_ray_distance <= 0.0 || self.march_steps == 0 {
+            return Err("max_ray_distance and march_steps must be positive".into());
+        }
+        Ok(())
+    }
+
+    /// Atlas size `(width, height)` of cascade `index`.
+    pub fn atlas_size(&self, index: u32) -> (u32, u32) {
+        let probe_dim = self.probe_dim >> index;
+        let dir_dim = DIR_DIM << index;
+        (probe_dim * dir_dim, probe_dim * probe_dim * dir_dim)
+    }
+
+    /// Ray interval `[t_min, t_max]` traced by cascade `index`.
+    ///
+    /// Intervals grow 4× per cascade and the coarsest one ends at
+    /// `max_ray_distance`.
+    pub fn ray_interval(&self, index: u32) -> (f32, f32) {
+        let base = self.max_ray_distance / 4f32.powi(self.cascade_count as i32 - 1);
+        let t_min = if index == 0 { 0.0 } else { base * 4f32.powi(index as i32 - 1) };
+        (t_min, base * 4f32.powi(index as i32))
+    }
+
+    /// World-space distance between neighbouring probes of the coarsest
+    /// cascade in a volume of half-size `half_extent`.
+    pub fn probe_spacing(&self, half_extent: f32) -> f32 {
+        let coarsest_dim = (self.probe_dim >> (self.cascade_count.max(1) - 1)).max(1);
+        2.0 * half_extent / coarsest_dim as f32
+    }
+
+    /// Cascade volume `(world_min, world_max)` of half-size `half_extent`.
+    ///
+    /// With [`follow_camera`](Self::follow_camera) the volume is centred on
+    /// `camera_pos` snapped to [`probe_spacing`](Self::probe_spacing). That
+    /// spacing is a whole number of probe cells in every finer cascade, so
+    /// probes keep their world positions while the camera moves and only
+    /// whole rows of probes enter or leave the volume — GI does not swim.
+    pub fn volume_bounds(&self, camera_pos: [f32; 3], half_extent: f32) -> ([f32; 3], [f32; 3]) {
+        let spacing = self.probe_spacing(half_extent);
+        let center = if self.follow_camera && spacing > 0.0 {
+            camera_pos.map(|p| (p / spacing).round() * spacing)
+        } else {
+            [0.0; 3]
+        };
+        (center.map(|c| c - half_extent), center.map(|c| c + half_extent))
+    }
+}
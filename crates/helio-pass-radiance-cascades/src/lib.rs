@@ -1,17 +1,21 @@
 const _RC_TRACE_WGSL: &str = include_str!("../shaders/rc_trace.wgsl");
 
+mod config;
+pub use config::{
+    resolve_wgsl_constants, wgsl_constants, GIQuality, IntegrationMode, RadianceCascadesConfig,
+    DIR_DIM, MAX_CASCADES,
+};
+
 use bytemuck::{Pod, Zeroable};
 use helio_core::graph::{ResourceBuilder, ResourceSize};
-use helio_core::{PassContext, PrepareContext, RenderPass, Result as HelioResult};
-
-const PROBE_DIM: u32 = 8;
-const DIR_DIM: u32 = 4;
-const ATLAS_W: u32 = PROBE_DIM * DIR_DIM;
-const ATLAS_H: u32 = PROBE_DIM * PROBE_DIM * DIR_DIM;
+use helio_core::{PassContext, PrepareContext, QueuePreference, RenderPass, Result as HelioResult};
 
 const WORKGROUP_SIZE_X: u32 = 8;
 const WORKGROUP_SIZE_Y: u32 = 8;
 
+/// Half-size of the cascade volume when no `MainSceneResources` supply one.
+const DEFAULT_HALF_EXTENT: f32 = 10.0;
+
 #[repr(C)]
 #[derive(Clone, Copy, Pod, Zeroable)]
 struct RCDynamic {
@@ -19,9 +23,13 @@ struct RCDynamic {
     world_max: [f32; 4],
     frame: u32,
     light_count: u32,
-    _pad0: u32,
-    _pad1: u32,
+    probe_dim: u32,
+    march_steps: u32,
     sky_color: [f32; 4],
+    max_ray_dist: f32,
+    temporal_blend: f32,
+    integration_mode: u32,
+    _pad0: u32,
 }
 
 #[repr(C)]
@@ -33,8 +41,15 @@ struct RCStatic {
     t_max_bits: u32,
     parent_probe_dim: u32,
     parent_dir_dim: u32,
+    t_min_bits: u32,
     _pad0: u32,
-    _pad1: u32,
+}
+
+/// Pass-owned atlas pair of a coarser cascade (index >= 1). Cascade 0 lives in
+/// the graph as `rc_cascades` / `rc_history` so downstream passes can read it.
+struct CascadeLevel {
+    atlas_view: wgpu::TextureView,
+    history_view: wgpu::TextureView,
 }
 
 pub struct RadianceCascadesPass {
@@ -46,8 +61,22 @@ pub struct RadianceCascadesPass {
     rt_bgl: Option<wgpu::BindGroupLayout>,
     fb_bind_group: Option<wgpu::BindGroup>,
     uniform_buf: wgpu::Buffer,
-    static_buf: Option<wgpu::Buffer>,
+    /// One static uniform per cascade (RT path only).
+    static_bufs: Vec<wgpu::Buffer>,
+    /// Coarser cascades 1..cascade_count (RT path only).
+    levels: Vec<CascadeLevel>,
+    /// Bound as the parent of the coarsest cascade, which has none.
+    dummy_parent_view: wgpu::TextureView,
+    config: RadianceCascadesConfig,
+    /// `levels`/`static_bufs` no longer match `config`; rebuilt in `prepare`.
+    levels_dirty: bool,
+    /// The `rc_cascades` atlas size or presence changed; reported via
+    /// `resources_changed`.
+    atlas_dirty: bool,
     use_rt: bool,
+    /// Cleared by [`set_enabled`](Self::set_enabled); a disabled pass traces
+    /// nothing and declares no `rc_cascades`, so consumers fall back to ambient.
+    enabled: bool,
 }
 
 const FALLBACK_WGSL: &str = r#"
@@ -56,9 +85,13 @@ struct RCDynamic {
     world_max:   vec4<f32>,
     frame:       u32,
     light_count: u32,
-    _pad0:       u32,
-    _pad1:       u32,
+    probe_dim:   u32,
+    march_steps: u32,
     sky_color:   vec4<f32>,
+    max_ray_dist:     f32,
+    temporal_blend:   f32,
+    integration_mode: u32,
+    _pad0:            u32,
 }
 
 struct Camera {
@@ -78,10 +111,8 @@ struct Camera {
 @group(0) @binding(3) var scene_color:  texture_2d<f32>;
 @group(0) @binding(4) var<uniform> camera:       Camera;
 
-const PROBE_DIM:   u32 = 8u;
-const DIR_DIM:     u32 = 4u;
-const MAX_RAY_DIST: f32 = 100.0;
-const MARCH_STEPS:  u32 = 32u;
+const MAX_CASCADES: u32 = 4u;
+const DIR_DIM: u32 = 4u;
 
 fn oct_decode(uv: vec2<f32>) -> vec3<f32> {
     let f  = uv * 2.0 - 1.0;
@@ -104,8 +135,9 @@ fn helio_ndc_to_uv(ndc: vec2<f32>) -> vec2<f32> {
 
 @compute @workgroup_size(8, 8)
 fn cs_main(@builtin(global_invocation_id) gid: vec3<u32>) {
-    let atlas_w = PROBE_DIM * DIR_DIM;
-    let atlas_h = PROBE_DIM * PROBE_DIM * DIR_DIM;
+    let probe_dim = rc_dyn.probe_dim;
+    let atlas_w = probe_dim * DIR_DIM;
+    let atlas_h = probe_dim * probe_dim * DIR_DIM;
 
     if gid.x >= atlas_w || gid.y >= atlas_h { return; }
 
@@ -113,18 +145,18 @@ fn cs_main(@builtin(global_invocation_id) gid: vec3<u32>) {
     let px = gid.x / DIR_DIM;
     let dy = gid.y % DIR_DIM;
     let pyz = gid.y / DIR_DIM;
-    let pz = pyz % PROBE_DIM;
-    let py = pyz / PROBE_DIM;
+    let pz = pyz % probe_dim;
+    let py = pyz / probe_dim;
 
     let dir_uv = (vec2<f32>(f32(dx), f32(dy)) + 0.5) / f32(DIR_DIM);
     let dir = oct_decode(dir_uv);
 
-    let t = (vec3<f32>(f32(px), f32(py), f32(pz)) + 0.5) / f32(PROBE_DIM);
+    let t = (vec3<f32>(f32(px), f32(py), f32(pz)) + 0.5) / f32(probe_dim);
     let world_size = rc_dyn.world_max.xyz - rc_dyn.world_min.xyz;
     let probe_pos = rc_dyn.world_min.xyz + t * world_size;
 
     let start_world = probe_pos;
-    let end_world   = start_world + dir * MAX_RAY_DIST;
+    let end_world   = start_world + dir * rc_dyn.max_ray_dist;
 
     let clip_start = camera.view_proj * vec4<f32>(start_world, 1.0);
     let clip_end   = camera.view_proj * vec4<f32>(end_world, 1.0);
@@ -152,8 +184,8 @@ fn cs_main(@builtin(global_invocation_id) gid: vec3<u32>) {
     var radiance = vec3<f32>(0.0);
     var hit = false;
 
-    for (var i: u32 = 1u; i <= MARCH_STEPS; i++) {
-        let t_step = f32(i) / f32(MARCH_STEPS);
+    for (var i: u32 = 1u; i <= rc_dyn.march_steps; i++) {
+        let t_step = f32(i) / f32(rc_dyn.march_steps);
         let uv = uv_start + delta_uv * t_step;
         let d  = depth_start + delta_depth * t_step;
 
@@ -166,7 +198,7 @@ fn cs_main(@builtin(global_invocation_id) gid: vec3<u32>) {
 
         if d >= scene_d {
             radiance = textureLoad(scene_color,
-                vec2<i32>(i32(uv.x * scene_dims.x), i32(uv.y * scene_dims.y)), 0).rgb;
+                vec2<i32>(i32(uv.x * scene_dims.x), i32(uv.y * scene_dims.y)), 0).rgb / camera.jitter_frame.w;
             hit = true;
             break;
         }
@@ -197,14 +229,18 @@ impl RadianceCascadesPass {
             mapped_at_creation: false,
         });
 
-        let static_buf = use_rt.then(|| {
-            device.create_buffer(&wgpu::BufferDescriptor {
-                label: Some("RC Static Uniform"),
-                size: std::mem::size_of::<RCStatic>() as u64,
-                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
-                mapped_at_creation: false,
+        let dummy_parent_view = device
+            .create_texture(&wgpu::TextureDescriptor {
+                label: Some("RC Dummy Parent"),
+                size: wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba16Float,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
             })
-        });
+            .create_view(&wgpu::TextureViewDescriptor::default());
 
         // ── Fallback BGL & pipeline ────────────────────────────────────
         let fb_bgl = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -265,7 +301,7 @@ impl RadianceCascadesPass {
 
         let fb_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("RC Fallback Shader"),
-            source: wgpu::ShaderSource::Wgsl(FALLBACK_WGSL.into()),
+            source: wgpu::ShaderSource::Wgsl(resolve_wgsl_constants(FALLBACK_WGSL).into()),
         });
 
         let fb_pl = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
@@ -371,7 +407,7 @@ impl RadianceCascadesPass {
 
             let rt_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
                 label: Some("RC Trace Shader"),
-                source: wgpu::ShaderSource::Wgsl(_RC_TRACE_WGSL.into()),
+                source: wgpu::ShaderSource::Wgsl(resolve_wgsl_constants(_RC_TRACE_WGSL).into()),
             });
 
             let rt_pl = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
@@ -401,10 +437,112 @@ impl RadianceCascadesPass {
             rt_bgl,
             fb_bind_group: None,
             uniform_buf,
-            static_buf,
+            static_bufs: Vec::new(),
+            levels: Vec::new(),
+            dummy_parent_view,
+            config: RadianceCascadesConfig::default(),
+            levels_dirty: true,
+            atlas_dirty: false,
             use_rt,
+            enabled: true,
+        }
+    }
+
+    pub fn config(&self) -> &RadianceCascadesConfig {
+        &self.config
+    }
+
+    /// Apply a new GI configuration.
+    ///
+    /// If the cascade-0 atlas size changes, the pass reports
+    /// [`RenderPass::resources_changed`] so the graph reallocates
+    /// `rc_cascades` / `rc_history` before the next frame; the coarser
+    /// cascades owned by this pass are rebuilt in the next `prepare`.
+    pub fn set_config(&mut self, config: RadianceCascadesConfig) -> HelioResult<()> {
+        config.validate().map_err(|e| {
+            helio_core::Error::InvalidPassConfig(format!("RadianceCascades: {e}"))
+        })?;
+        if config.atlas_size(0) != self.config.atlas_size(0) {
+            self.atlas_dirty = true;
+        }
+        if config.cascade_count != self.config.cascade_count
+            || config.probe_dim != self.config.probe_dim
+        {
+            self.levels_dirty = true;
+        }
+        self.config = config;
+        Ok(())
+    }
+
+    /// Change how trace results accumulate, from the next frame.
+    ///
+    /// Only a uniform changes, so unlike [`set_config`](Self::set_config)
+    /// this never reallocates atlases; flip it freely to A/B GI artifacts.
+    pub fn set_integration_mode(&mut self, mode: IntegrationMode) {
+        self.config.integration_mode = mode;
+    }
+
+    /// Switch to a named quality preset. See [`RadianceCascadesConfig::preset`].
+    pub fn set_quality(&mut self, quality: GIQuality) {
+        self.set_config(RadianceCascadesConfig::preset(quality))
+            .expect("GI presets are valid");
+    }
+
+    /// Turn GI on or off, from the next frame.
+    ///
+    /// While disabled the pass skips its trace and stops declaring
+    /// `rc_cascades` / `rc_history`, so the graph frees the atlases and
+    /// deferred lighting falls back to the ambient term.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        if enabled != self.enabled {
+            self.enabled = enabled;
+            self.atlas_dirty = true;
         }
     }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn rebuild_levels(&mut self, device: &wgpu::Device) {
+        let count = self.config.cascade_count;
+        self.static_bufs = (0..count)
+            .map(|i| {
+                device.create_buffer(&wgpu::BufferDescriptor {
+                    label: Some(&format!("RC Static Uniform {i}")),
+                    size: std::mem::size_of::<RCStatic>() as u64,
+                    usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+                    mapped_at_creation: false,
+                })
+            })
+            .collect();
+
+        let create_view = |label: &str, width: u32, height: u32| {
+            device
+                .create_texture(&wgpu::TextureDescriptor {
+                    label: Some(label),
+                    size: wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+                    mip_level_count: 1,
+                    sample_count: 1,
+                    dimension: wgpu::TextureDimension::D2,
+                    format: wgpu::TextureFormat::Rgba16Float,
+                    usage: wgpu::TextureUsages::TEXTURE_BINDING
+                        | wgpu::TextureUsages::STORAGE_BINDING,
+                    view_formats: &[],
+                })
+                .create_view(&wgpu::TextureViewDescriptor::default())
+        };
+        self.levels = (1..count)
+            .map(|i| {
+                let (w, h) = self.config.atlas_size(i);
+                CascadeLevel {
+                    atlas_view: create_view(&format!("RC Cascade {i}"), w, h),
+                    history_view: create_view(&format!("RC History {i}"), w, h),
+                }
+            })
+            .collect();
+        self.levels_dirty = false;
+    }
 }
 
 impl RenderPass for RadianceCascadesPass {
@@ -412,17 +550,50 @@ impl RenderPass for RadianceCascadesPass {
         "RadianceCascades"
     }
 
+    fn gpu_memory_bytes(&self) -> u64 {
+        // Cascade 0 is the graph-owned `rc_cascades` atlas; coarser cascades
+        // keep an Rgba16Float atlas plus a history copy each.
+        let textures: u64 = (1..self.levels.len() as u32 + 1)
+            .map(|i| {
+                let (w, h) = self.config.atlas_size(i);
+                w as u64 * h as u64 * 8 * 2
+            })
+            .sum();
+        let buffers: u64 = self.static_bufs.iter().map(|b| b.size()).sum();
+        textures + buffers + self.uniform_buf.size()
+    }
+
+    fn apply_quality(&mut self, preset: helio_core::QualityPreset) {
+        match GIQuality::from_preset(preset) {
+            Some(quality) => {
+                self.set_quality(quality);
+                self.set_enabled(true);
+            }
+            None => self.set_enabled(false),
+        }
+    }
+
     fn reads(&self) -> &'static [&'static str] {
         &["pre_aa"]
     }
 
+    fn queue_preference(&self) -> QueuePreference {
+        // Pure compute; the cascade update only has to land before the
+        // deferred lighting that samples it.
+        QueuePreference::AsyncCompute
+    }
+
     fn declare_resources(&self, builder: &mut ResourceBuilder) {
+        if !self.enabled {
+            return;
+        }
+        let (atlas_w, atlas_h) = self.config.atlas_size(0);
         builder.write_color_raw(
             "rc_cascades",
             wgpu::TextureFormat::Rgba16Float,
             ResourceSize::Absolute {
-                width: ATLAS_W,
-                height: ATLAS_H,
+                width: atlas_w,
+                height: atlas_h,
             },
         );
         builder.with_extra_usage(wgpu::TextureUsages::STORAGE_BINDING);
@@ -432,14 +603,18 @@ impl RenderPass for RadianceCascadesPass {
                 "rc_history",
                 wgpu::TextureFormat::Rgba16Float,
                 ResourceSize::Absolute {
-                    width: ATLAS_W,
-                    height: ATLAS_H,
+                    width: atlas_w,
+                    height: atlas_h,
                 },
             );
             builder.with_extra_usage(wgpu::TextureUsages::STORAGE_BINDING);
         }
     }
 
+    fn resources_changed(&mut self) -> bool {
+        std::mem::take(&mut self.atlas_dirty)
+    }
+
     fn render_pass_descriptor<'a>(
         &'a self,
         _target: &'a wgpu::TextureView,
@@ -450,29 +625,51 @@ impl RenderPass for RadianceCascadesPass {
     }
 
     fn prepare(&mut self, ctx: &PrepareContext) -> HelioResult<()> {
+        if !self.enabled {
+            return Ok(());
+        }
         let light_count = ctx.scene.lights.len() as u32;
         let sky = ctx.frame_resources.sky.sky_color;
+        let config = self.config;
+        // The renderer publishes the volume its GI consumers sample; it is
+        // built with `volume_bounds` already, so probes and lookups agree.
+        let (world_min, world_max) = match ctx.frame_resources.main_scene.get() {
+            Some(main) => (main.rc_world_min, main.rc_world_max),
+            None => config.volume_bounds(ctx.scene.camera.position(), DEFAULT_HALF_EXTENT),
+        };
         let dyn_data = RCDynamic {
-            world_min: [-10.0, -1.0, -10.0, 0.0],
-            world_max: [10.0, 10.0, 10.0, 0.0],
-            frame: ctx.frame_num as u32,
+            world_min: [world_min[0], world_min[1], world_min[2], 0.0],
+            world_max: [world_max[0], world_max[1], world_max[2], 0.0],
+            frame: ctx.sampling_frame(),
             light_count,
-            _pad0: 0,
-            _pad1: 0,
+            probe_dim: config.probe_dim,
+            march_steps: config.march_steps,
             sky_color: [sky[0], sky[1], sky[2], 0.0],
+            max_ray_dist: config.max_ray_distance,
+            temporal_blend: config.temporal_blend,
+            integration_mode: config.integration_mode as u32,
+            _pad0: 0,
         };
         ctx.write_buffer(&self.uniform_buf, 0, bytemuck::bytes_of(&dyn_data));
 
-        if let Some(ref static_buf) = self.static_buf {
+        if !self.use_rt {
+            return Ok(());
+        }
+        if self.levels_dirty {
+            self.rebuild_levels(ctx.device);
+        }
+        for (i, static_buf) in (0..config.cascade_count).zip(&self.static_bufs) {
+            let has_parent = i + 1 < config.cascade_count;
+            let (t_min, t_max) = config.ray_interval(i);
             let static_data = RCStatic {
-                cascade_index: 0,
-                probe_dim: PROBE_DIM,
-                dir_dim: DIR_DIM,
-                t_max_bits: f32::MAX.to_bits(),
-                parent_probe_dim: 0,
-                parent_dir_dim: 0,
+                cascade_index: i,
+                probe_dim: config.probe_dim >> i,
+                dir_dim: DIR_DIM << i,
+                t_max_bits: t_max.to_bits(),
+                parent_probe_dim: if has_parent { config.probe_dim >> (i + 1) } else { 0 },
+                parent_dir_dim: if has_parent { DIR_DIM << (i + 1) } else { 0 },
+                t_min_bits: t_min.to_bits(),
                 _pad0: 0,
-                _pad1: 0,
             };
             ctx.write_buffer(static_buf, 0, bytemuck::bytes_of(&static_data));
         }
@@ -481,6 +678,9 @@ impl RenderPass for RadianceCascadesPass {
     }
 
     fn execute(&mut self, ctx: &mut PassContext) -> HelioResult<()> {
+        if !self.enabled {
+            return Ok(());
+        }
         if self.use_rt {
             self.execute_rt(ctx)
         } else {
@@ -535,8 +735,9 @@ impl RadianceCascadesPass {
                 ],
             }));
 
-        let wg_x = ATLAS_W.div_ceil(WORKGROUP_SIZE_X);
-        let wg_y = ATLAS_H.div_ceil(WORKGROUP_SIZE_Y);
+        let (atlas_w, atlas_h) = self.config.atlas_size(0);
+        let wg_x = atlas_w.div_ceil(WORKGROUP_SIZE_X);
+        let wg_y = atlas_h.div_ceil(WORKGROUP_SIZE_Y);
 
         let desc = wgpu::ComputePassDescriptor {
             label: Some("RadianceCascades (Fallback)"),
@@ -585,50 +786,11 @@ impl RadianceCascadesPass {
             return self.execute_fallback(ctx);
         };
 
-        // NB: entries must be in binding order to match BGL.
-        let entries = [
-            wgpu::BindGroupEntry {
-                binding: 0,
-                resource: wgpu::BindingResource::TextureView(&cascade_out_view),
-            },
-            wgpu::BindGroupEntry {
-                binding: 1,
-                resource: wgpu::BindingResource::TextureView(&cascade_out_view),
-            },
-            wgpu::BindGroupEntry {
-                binding: 2,
-                resource: self.uniform_buf.as_entire_binding(),
-            },
-            wgpu::BindGroupEntry {
-                binding: 3,
-                resource: self.static_buf.as_ref().unwrap().as_entire_binding(),
-            },
-            wgpu::BindGroupEntry {
-                binding: 4,
-                resource: tlas.as_binding(),
-            },
-            wgpu::BindGroupEntry {
-                binding: 5,
-                resource: lights_buf.as_entire_binding(),
-            },
-            wgpu::BindGroupEntry {
-                binding: 6,
-                resource: wgpu::BindingResource::TextureView(&history_view),
-            },
-            wgpu::BindGroupEntry {
-                binding: 7,
-                resource: wgpu::BindingResource::TextureView(&history_view),
-            },
-        ];
-
-        let bind_group = ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("RC Trace BG"),
-            layout: rt_bgl,
-            entries: &entries,
-        });
-
-        let wg_x = ATLAS_W.div_ceil(WORKGROUP_SIZE_X);
-        let wg_y = ATLAS_H.div_ceil(WORKGROUP_SIZE_Y);
+        // (atlas, history) per cascade, finest first.
+        let targets: Vec<(&wgpu::TextureView, &wgpu::TextureView)> =
+            std::iter::once((&cascade_out_view, &history_view))
+                .chain(self.levels.iter().map(|l| (&l.atlas_view, &l.history_view)))
+                .collect();
 
         let desc = wgpu::ComputePassDescriptor {
             label: Some("RadianceCascades (RT)"),
@@ -636,8 +798,63 @@ impl RadianceCascadesPass {
         };
         let mut pass = unsafe { &mut *ctx.encoder_ptr }.begin_compute_pass(&desc);
         pass.set_pipeline(rt_pipeline);
-        pass.set_bind_group(0, &bind_group, &[]);
-        pass.dispatch_workgroups(wg_x, wg_y, 1);
+
+        // Coarse → fine: each cascade merges the one above it, which was
+        // written by the previous dispatch.
+        for i in (0..targets.len()).rev() {
+            let (out_view, hist_view) = targets[i];
+            let parent_view = targets.get(i + 1).map_or(&self.dummy_parent_view, |t| t.0);
+
+            // NB: entries must be in binding order to match BGL.
+            let entries = [
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(out_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(parent_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: self.uniform_buf.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: self.static_bufs[i].as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: tlas.as_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: lights_buf.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 6,
+                    resource: wgpu::BindingResource::TextureView(hist_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 7,
+                    resource: wgpu::BindingResource::TextureView(hist_view),
+                },
+            ];
+
+            let bind_group = ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("RC Trace BG"),
+                layout: rt_bgl,
+                entries: &entries,
+            });
+
+            let (atlas_w, atlas_h) = self.config.atlas_size(i as u32);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(
+                atlas_w.div_ceil(WORKGROUP_SIZE_X),
+                atlas_h.div_ceil(WORKGROUP_SIZE_Y),
+                1,
+            );
+        }
         Ok(())
     }
 }
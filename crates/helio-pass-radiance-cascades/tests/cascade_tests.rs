@@ -1,6 +1,7 @@
 // Pure math tests for helio-pass-radiance-cascades constants and struct layout.
 // No crate imports required — all types and constants are defined locally.
 
+// Cascade 0 of the default (Medium) GI preset.
 const PROBE_DIM: u32 = 8;
 const DIR_DIM: u32 = 4;
 const ATLAS_W: u32 = 32; // PROBE_DIM * DIR_DIM
@@ -13,9 +14,13 @@ struct RCDynamic {
     world_max: [f32; 4],
     frame: u32,
     light_count: u32,
-    _pad0: u32,
-    _pad1: u32,
+    probe_dim: u32,
+    march_steps: u32,
     sky_color: [f32; 4],
+    max_ray_dist: f32,
+    temporal_blend: f32,
+    integration_mode: u32,
+    _pad0: u32,
 }
 
 // ── Named constant values ─────────────────────────────────────────────────────
@@ -111,8 +116,8 @@ fn workgroup_dispatch_total_for_8x8_groups() {
 // ── RCDynamic struct layout ───────────────────────────────────────────────────
 
 #[test]
-fn rcdynamic_size_is_64_bytes() {
-    assert_eq!(std::mem::size_of::<RCDynamic>(), 64);
+fn rcdynamic_size_is_80_bytes() {
+    assert_eq!(std::mem::size_of::<RCDynamic>(), 80);
 }
 
 #[test]
@@ -128,7 +133,7 @@ fn rcdynamic_world_max_field_is_16_bytes() {
 
 #[test]
 fn rcdynamic_scalar_u32_fields_are_4_bytes() {
-    // frame, light_count, _pad0, _pad1 — each 4 bytes, 4 × 4 = 16
+    // frame, light_count, probe_dim, march_steps — each 4 bytes, 4 × 4 = 16
     assert_eq!(4 * std::mem::size_of::<u32>(), 16);
 }
 
@@ -139,9 +144,9 @@ fn rcdynamic_sky_color_is_16_bytes() {
 
 #[test]
 fn rcdynamic_field_sum_matches_struct_size() {
-    // world_min(16) + world_max(16) + 4×u32(16) + sky_color(16) = 64
-    let computed = 16 + 16 + 4 * std::mem::size_of::<u32>() + 16;
-    assert_eq!(computed, 64);
+    // world_min(16) + world_max(16) + 4×u32(16) + sky_color(16) + 4×32-bit params(16) = 80
+    let computed = 16 + 16 + 4 * std::mem::size_of::<u32>() + 16 + 4 * std::mem::size_of::<u32>();
+    assert_eq!(computed, 80);
     assert_eq!(computed, std::mem::size_of::<RCDynamic>());
 }
 
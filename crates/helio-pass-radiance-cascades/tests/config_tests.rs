@@ -0,0 +1,256 @@
+// Tests for the GI quality presets and RadianceCascadesConfig validation.
+
+use helio_pass_radiance_cascades::{
+    resolve_wgsl_constants, GIQuality, IntegrationMode, RadianceCascadesConfig, DIR_DIM,
+    MAX_CASCADES,
+};
+
+const ALL: [GIQuality; 4] = GIQuality::ALL;
+
+// ── Presets ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn every_preset_validates() {
+    for q in ALL {
+        assert_eq!(RadianceCascadesConfig::preset(q).validate(), Ok(()), "{q:?}");
+    }
+}
+
+#[test]
+fn preset_records_its_quality() {
+    for q in ALL {
+        assert_eq!(RadianceCascadesConfig::preset(q).quality, q);
+    }
+}
+
+#[test]
+fn default_is_medium() {
+    assert_eq!(GIQuality::default(), GIQuality::Medium);
+    assert_eq!(
+        RadianceCascadesConfig::default(),
+        RadianceCascadesConfig::preset(GIQuality::Medium)
+    );
+}
+
+#[test]
+fn medium_matches_legacy_atlas() {
+    // The pass used a fixed 8³ × 4² atlas before presets existed.
+    let c = RadianceCascadesConfig::preset(GIQuality::Medium);
+    assert_eq!(c.probe_dim, 8);
+    assert_eq!(c.atlas_size(0), (32, 256));
+}
+
+#[test]
+fn preset_parameters_are_monotonic() {
+    for w in ALL.windows(2) {
+        let lo = RadianceCascadesConfig::preset(w[0]);
+        let hi = RadianceCascadesConfig::preset(w[1]);
+        assert!(hi.cascade_count > lo.cascade_count);
+        assert!(hi.probe_dim > lo.probe_dim);
+        assert!(hi.max_ray_distance > lo.max_ray_distance);
+        assert!(hi.march_steps > lo.march_steps);
+    }
+}
+
+#[test]
+fn presets_use_temporal_integration() {
+    for q in ALL {
+        assert_eq!(
+            RadianceCascadesConfig::preset(q).integration_mode,
+            IntegrationMode::Temporal
+        );
+    }
+}
+
+#[test]
+fn ultra_uses_max_cascades() {
+    assert_eq!(RadianceCascadesConfig::preset(GIQuality::Ultra).cascade_count, MAX_CASCADES);
+}
+
+#[test]
+fn preset_cascade_count_matches_quality() {
+    for q in ALL {
+        let count = q.cascade_count();
+        assert_eq!(RadianceCascadesConfig::preset(q).cascade_count, count, "{q:?}");
+        assert!((1..=MAX_CASCADES).contains(&count), "{q:?}");
+    }
+}
+
+#[test]
+fn low_quality_preset_turns_gi_off() {
+    use helio_core::QualityPreset;
+
+    assert_eq!(GIQuality::from_preset(QualityPreset::Low), None);
+    assert_eq!(GIQuality::from_preset(QualityPreset::Medium), Some(GIQuality::Medium));
+    assert_eq!(GIQuality::from_preset(QualityPreset::High), Some(GIQuality::High));
+    assert_eq!(GIQuality::from_preset(QualityPreset::Ultra), Some(GIQuality::Ultra));
+}
+
+#[test]
+fn integration_mode_discriminants_match_rc_trace() {
+    // rc_trace.wgsl tests `integration_mode == 1u` for Immediate.
+    assert_eq!(IntegrationMode::Temporal as u32, 0);
+    assert_eq!(IntegrationMode::Immediate as u32, 1);
+    let src = include_str!("../shaders/rc_trace.wgsl");
+    assert!(src.contains("rc_dyn.integration_mode == 1u"));
+}
+
+#[test]
+fn wgsl_constants_follow_the_rust_limits() {
+    let src = resolve_wgsl_constants("const MAX_CASCADES: u32 = 99u;\n  const DIR_DIM: u32 = 1u;\nfn f() {}");
+    assert!(src.contains(&format!("const MAX_CASCADES: u32 = {MAX_CASCADES}u;")));
+    assert!(src.contains(&format!("const DIR_DIM: u32 = {DIR_DIM}u;")));
+    assert!(!src.contains("99u") && !src.contains("= 1u;"));
+}
+
+#[test]
+fn rc_trace_declarations_match_the_rust_limits() {
+    // The file's own values are overwritten at load time, but stale ones
+    // would mislead anyone reading the shader.
+    let src = include_str!("../shaders/rc_trace.wgsl");
+    assert_eq!(resolve_wgsl_constants(src).trim_end(), src.trim_end());
+}
+
+// ── Atlas sizes ───────────────────────────────────────────────────────────────
+
+#[test]
+fn atlas_width_is_constant_across_cascades() {
+    for q in ALL {
+        let c = RadianceCascadesConfig::preset(q);
+        let w0 = c.atlas_size(0).0;
+        for i in 0..c.cascade_count {
+            assert_eq!(c.atlas_size(i).0, w0);
+        }
+    }
+}
+
+#[test]
+fn atlas_height_halves_per_cascade() {
+    let c = RadianceCascadesConfig::preset(GIQuality::Ultra);
+    for i in 1..c.cascade_count {
+        assert_eq!(c.atlas_size(i).1 * 2, c.atlas_size(i - 1).1);
+    }
+}
+
+#[test]
+fn coarsest_cascade_has_at_least_one_probe() {
+    for q in ALL {
+        let c = RadianceCascadesConfig::preset(q);
+        assert!(c.probe_dim >> (c.cascade_count - 1) >= 1);
+    }
+}
+
+#[test]
+fn cascade_zero_width_is_probe_dim_times_dir_dim() {
+    for q in ALL {
+        let c = RadianceCascadesConfig::preset(q);
+        assert_eq!(c.atlas_size(0).0, c.probe_dim * DIR_DIM);
+    }
+}
+
+// ── Ray intervals ─────────────────────────────────────────────────────────────
+
+#[test]
+fn ray_intervals_are_contiguous() {
+    for q in ALL {
+        let c = RadianceCascadesConfig::preset(q);
+        assert_eq!(c.ray_interval(0).0, 0.0);
+        for i in 1..c.cascade_count {
+            assert_eq!(c.ray_interval(i).0, c.ray_interval(i - 1).1);
+        }
+    }
+}
+
+#[test]
+fn coarsest_interval_ends_at_max_ray_distance() {
+    for q in ALL {
+        let c = RadianceCascadesConfig::preset(q);
+        let (_, t_max) = c.ray_interval(c.cascade_count - 1);
+        assert!((t_max - c.max_ray_distance).abs() < 1e-3);
+    }
+}
+
+// ── Volume placement ──────────────────────────────────────────────────────────
+
+#[test]
+fn volume_snaps_to_coarsest_probe_spacing() {
+    // Medium: 8³ probes, 2 cascades → coarsest grid is 4³; 80 m half extent → 40 m.
+    let c = RadianceCascadesConfig::default();
+    assert_eq!(c.probe_spacing(80.0), 40.0);
+    let (min, max) = c.volume_bounds([25.0, -19.0, 101.0], 80.0);
+    assert_eq!(min, [-40.0, -80.0, 40.0]);
+    assert_eq!(max, [120.0, 80.0, 200.0]);
+}
+
+#[test]
+fn volume_is_stable_within_one_probe_cell() {
+    let c = RadianceCascadesConfig::default();
+    let a = c.volume_bounds([41.0, 0.0, 0.0], 80.0);
+    let b = c.volume_bounds([59.0, 0.0, 0.0], 80.0);
+    assert_eq!(a, b);
+}
+
+#[test]
+fn fixed_volume_stays_at_origin() {
+    let c = RadianceCascadesConfig {
+        follow_camera: false,
+        ..Default::default()
+    };
+    assert_eq!(c.volume_bounds([500.0, 3.0, -70.0], 10.0), ([-10.0; 3], [10.0; 3]));
+}
+
+// ── Validation ────────────────────────────────────────────────────────────────
+
+#[test]
+fn validate_rejects_zero_cascades() {
+    let c = RadianceCascadesConfig {
+        cascade_count: 0,
+        ..Default::default()
+    };
+    assert!(c.validate().is_err());
+}
+
+#[test]
+fn validate_rejects_too_many_cascades() {
+    let mut c = RadianceCascadesConfig::preset(GIQuality::Ultra);
+    c.cascade_count = MAX_CASCADES + 1;
+    assert!(c.validate().is_err());
+}
+
+#[test]
+fn validate_rejects_non_power_of_two_probe_dim() {
+    let c = RadianceCascadesConfig {
+        probe_dim: 12,
+        ..Default::default()
+    };
+    assert!(c.validate().is_err());
+}
+
+#[test]
+fn validate_rejects_probe_dim_too_small_for_cascades() {
+    let mut c = RadianceCascadesConfig::preset(GIQuality::Ultra);
+    c.probe_dim = 4;
+    assert!(c.validate().is_err());
+}
+
+#[test]
+fn validate_rejects_out_of_range_blend() {
+    let mut c = RadianceCascadesConfig {
+        temporal_blend: 0.0,
+        ..Default::default()
+    };
+    assert!(c.validate().is_err());
+    c.temporal_blend = 1.5;
+    assert!(c.validate().is_err());
+    c.temporal_blend = f32::NAN;
+    assert!(c.validate().is_err());
+}
+
+#[test]
+fn validate_accepts_immediate_blend_of_one() {
+    let c = RadianceCascadesConfig {
+        temporal_blend: 1.0,
+        ..Default::default()
+    };
+    assert_eq!(c.validate(), Ok(()));
+}
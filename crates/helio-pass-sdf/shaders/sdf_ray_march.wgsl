@@ -20,7 +20,7 @@ struct CameraUniform {
     inv_view_proj: mat4x4<f32>,
     position_near: vec4<f32>,   // xyz = pos, w = near
     forward_far: vec4<f32>,     // xyz = forward, w = far
-    jitter_frame: vec4<f32>,    // xy = jitter, z = frame, w = pad
+    jitter_frame: vec4<f32>,    // xy = jitter, z = frame, w = pre-exposure
     prev_view_proj: mat4x4<f32>,
 };
 
@@ -578,9 +578,9 @@ fn fs_main(in: VsOutput) -> FsOutput {
 
         let lit = ambient + diffuse;
         let debug_color = mix(level_color * lit, vec3<f32>(1.0, 1.0, 1.0), edge * 0.8);
-        out.color = vec4<f32>(mix(debug_color, SKY_COLOR, fog), 1.0);
+        out.color = vec4<f32>(mix(debug_color, SKY_COLOR, fog) * camera.jitter_frame.w, 1.0);
     } else {
-        out.color = vec4<f32>(final_color, 1.0);
+        out.color = vec4<f32>(final_color * camera.jitter_frame.w, 1.0);
     }
 
     // ── Depth output ─────────────────────────────────────────────────────
@@ -234,6 +234,10 @@ impl RenderPass for ShadowCullPass {
         "ShadowCull"
     }
 
+    fn gpu_memory_bytes(&self) -> u64 {
+        self.face_indirect_buf.size() + self.face_counts_buf.size()
+    }
+
     fn render_pass_descriptor<'a>(
         &'a self,
         _target: &'a wgpu::TextureView,
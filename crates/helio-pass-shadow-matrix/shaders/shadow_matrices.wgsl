@@ -13,6 +13,8 @@
 const FACES_PER_LIGHT: u32 = 6u;
 const CSM_SPLITS: vec4f = vec4f(16.0, 80.0, 300.0, 1400.0);
 const SCENE_DEPTH: f32 = 4000.0;
+// libhelio::MAX_SHADOW_RESOLUTION_SHIFT
+const MAX_RESOLUTION_SHIFT: u32 = 3u;
 
 // Light types (must match LightType in libhelio/src/light.rs)
 const LIGHT_TYPE_DIRECTIONAL: u32 = 0u;
@@ -37,9 +39,9 @@ struct GpuLight {
     god_rays_weight:   f32,
     god_rays_decay:    f32,
     god_rays_exposure: f32,
-    _pad2_0:           u32,
-    _pad2_1:           u32,
-    _pad2_2:           u32,
+    shadow_bias:        f32,
+    shadow_normal_bias: f32,
+    shadow_resolution_shift: u32,
 }
 
 /// Must match GpuShadowMatrix in uniforms.rs (64 bytes)
@@ -63,7 +65,7 @@ struct CameraUniforms {
 struct ShadowMatrixParams {
     light_count: u32,
     shadow_atlas_size: u32,
-    _pad0: u32,
+    reversed_depth: u32,  // 1 = libhelio::DepthConvention::Reversed
     _pad1: u32,
 }
 
@@ -119,6 +121,36 @@ fn mat4_look_at_rh(eye: vec3f, center: vec3f, up: vec3f) -> mat4x4f {
     );
 }
 
+/// Final light view-projection in the atlas depth convention. Reversed depth
+/// remaps z to w - z, so the near plane lands on 1 and the far plane on 0.
+///
+/// Lights at a reduced resolution tier render into the top-left
+/// `1 / 2^shift` of their atlas layer: x and y are remapped so the light's
+/// frustum lands in that region, and ShadowPass scissors to it.
+fn light_view_proj(proj: mat4x4f, view: mat4x4f, shift: u32) -> mat4x4f {
+    var view_proj = proj * view;
+    if params.reversed_depth != 0u {
+        let flip = mat4x4f(
+            vec4f(1.0, 0.0, 0.0, 0.0),
+            vec4f(0.0, 1.0, 0.0, 0.0),
+            vec4f(0.0, 0.0, -1.0, 0.0),
+            vec4f(0.0, 0.0, 1.0, 1.0),
+        );
+        view_proj = flip * view_proj;
+    }
+    if shift == 0u {
+        return view_proj;
+    }
+    let s = 1.0 / f32(1u << min(shift, MAX_RESOLUTION_SHIFT));
+    let region = mat4x4f(
+        vec4f(s, 0.0, 0.0, 0.0),
+        vec4f(0.0, s, 0.0, 0.0),
+        vec4f(0.0, 0.0, 1.0, 0.0),
+        vec4f(s - 1.0, 1.0 - s, 0.0, 1.0),
+    );
+    return region * view_proj;
+}
+
 // ── Point light matrices (6 cube faces) ───────────────────────────────────────
 
 fn compute_point_light_matrices(light_idx: u32, position: vec3f, range: f32) {
@@ -138,7 +170,7 @@ fn compute_point_light_matrices(light_idx: u32, position: vec3f, range: f32) {
     );
 
     for (var i = 0u; i < 6u; i++) {
-        shadow_mats[base + i].mat = proj * views[i];
+        shadow_mats[base + i].mat = light_view_proj(proj, views[i], lights[light_idx].shadow_resolution_shift);
     }
 }
 
@@ -156,13 +188,14 @@ fn compute_spot_matrix(light_idx: u32, position: vec3f, direction: vec3f, range:
     let view = mat4_look_at_rh(position, position + dir, up);
     let proj = mat4_perspective_rh(fov, 1.0, 0.05, max(range, 0.1));
 
-    shadow_mats[base].mat = proj * view;
+    shadow_mats[base].mat = light_view_proj(proj, view, lights[light_idx].shadow_resolution_shift);
 }
 
 // ── Directional light cascades (CSM with sphere-fit + texel snap) ─────────────
 
 fn compute_directional_cascades(light_idx: u32, direction: vec3f) {
     let base = lights[light_idx].shadow_index;
+    let shift = lights[light_idx].shadow_resolution_shift;
     let dir = normalize(direction);
     let up = select(vec3f(0.0, 1.0, 0.0), vec3f(0.0, 0.0, 1.0), abs(dot(dir, vec3f(0.0, 1.0, 0.0))) > 0.99);
 
@@ -217,8 +250,9 @@ fn compute_directional_cascades(light_idx: u32, direction: vec3f) {
             radius = max(radius, length(cc[i] - centroid));
         }
 
-        // Snap radius to texel boundaries
-        let texel_size = (2.0 * radius) / f32(max(params.shadow_atlas_size, 1u));
+        // Snap radius to texel boundaries of the caster's resolution tier
+        let region_size = params.shadow_atlas_size >> min(shift, MAX_RESOLUTION_SHIFT);
+        let texel_size = (2.0 * radius) / f32(max(region_size, 1u));
         let radius_snap = ceil(radius / texel_size) * texel_size;
 
         // Texel-snapped light view
@@ -238,7 +272,7 @@ fn compute_directional_cascades(light_idx: u32, direction: vec3f) {
         let light_view = mat4_look_at_rh(stable_centroid - dir * SCENE_DEPTH, stable_centroid, up);
         let proj = mat4_orthographic_rh(-radius_snap, radius_snap, -radius_snap, radius_snap, 0.1, SCENE_DEPTH * 2.0);
 
-        shadow_mats[base + cascade_idx].mat = proj * light_view;
+        shadow_mats[base + cascade_idx].mat = light_view_proj(proj, light_view, shift);
     }
 
     // Fill slots 4-5 with identity (point light faces 4-5 unused for directional)
@@ -13,7 +13,8 @@ const WORKGROUP_SIZE: u32 = 64;
 struct ShadowMatrixUniforms {
     light_count: u32,
     shadow_atlas_size: u32,
-    _pad: [u32; 2],
+    reversed_depth: u32,
+    _pad: u32,
 }
 
 pub struct ShadowMatrixPass {
@@ -23,6 +24,7 @@ pub struct ShadowMatrixPass {
     uniform_buf: wgpu::Buffer,
     bind_group: wgpu::BindGroup,
     shadow_atlas_size: u32,
+    depth_convention: libhelio::DepthConvention,
 }
 
 impl ShadowMatrixPass {
@@ -166,8 +168,14 @@ impl ShadowMatrixPass {
             uniform_buf,
             bind_group,
             shadow_atlas_size: shadow_atlas_size.max(1),
+            depth_convention: libhelio::DepthConvention::Standard,
         }
     }
+
+    /// Depth convention the light projections are built for.
+    pub fn depth_convention(&self) -> libhelio::DepthConvention {
+        self.depth_convention
+    }
 }
 
 impl RenderPass for ShadowMatrixPass {
@@ -175,6 +183,10 @@ impl RenderPass for ShadowMatrixPass {
         "ShadowMatrix"
     }
 
+    fn set_depth_convention(&mut self, convention: libhelio::DepthConvention) {
+        self.depth_convention = convention;
+    }
+
     fn render_pass_descriptor<'a>(
         &'a self,
         _target: &'a wgpu::TextureView,
@@ -188,7 +200,8 @@ impl RenderPass for ShadowMatrixPass {
         let u = ShadowMatrixUniforms {
             light_count: ctx.scene.lights.len() as u32,
             shadow_atlas_size: self.shadow_atlas_size,
-            _pad: [0; 2],
+            reversed_depth: self.depth_convention.is_reversed() as u32,
+            _pad: 0,
         };
         ctx.queue
             .write_buffer(&self.uniform_buf, 0, bytemuck::bytes_of(&u));
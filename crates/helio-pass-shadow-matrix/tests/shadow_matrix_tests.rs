@@ -12,7 +12,8 @@ const WORKGROUP_SIZE: u32 = 64;
 struct ShadowMatrixUniforms {
     light_count: u32,
     shadow_atlas_size: u32,
-    _pad: [u32; 2],
+    reversed_depth: u32,
+    _pad: u32,
 }
 
 // Derived constants
@@ -29,7 +30,7 @@ fn shadow_matrix_uniforms_size_is_16_bytes() {
 
 #[test]
 fn shadow_matrix_uniforms_has_four_u32_fields() {
-    // light_count(u32) + shadow_atlas_size(u32) + _pad([u32;2]) = 4 × 4 = 16 bytes.
+    // light_count + shadow_atlas_size + reversed_depth + _pad = 4 × 4 = 16 bytes.
     let expected = 4 * std::mem::size_of::<u32>();
     assert_eq!(expected, 16);
 }
@@ -47,11 +48,11 @@ fn shadow_matrix_uniforms_is_multiple_of_16() {
 
 #[test]
 fn shadow_matrix_uniforms_pad_fields_count() {
-    // _pad is [u32; 2] = 8 bytes of padding to reach 16-byte total.
-    let payload_bytes = std::mem::size_of::<u32>() * 2; // light_count + shadow_atlas_size
+    // _pad is a single u32 = 4 bytes of padding to reach 16-byte total.
+    let payload_bytes = std::mem::size_of::<u32>() * 3; // light_count + shadow_atlas_size + reversed_depth
     let total = std::mem::size_of::<ShadowMatrixUniforms>();
     let padding = total - payload_bytes;
-    assert_eq!(padding, 8);
+    assert_eq!(padding, 4);
 }
 
 // ── Workgroup dispatch math ───────────────────────────────────────────────────
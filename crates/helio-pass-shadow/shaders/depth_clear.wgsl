@@ -1,7 +1,8 @@
 /// Depth-clear vertex shader.
 ///
 /// Generates a single full-screen triangle (vertices computed in the shader, no
-/// vertex buffer needed) and writes depth = 1.0 (far plane) to every pixel.
+/// vertex buffer needed) and writes the far plane to every pixel: depth 1.0,
+/// or 0.0 from `vs_main_reversed` for reversed-depth atlases.
 /// Used by `ShadowPass` to GPU-clear individual shadow atlas faces before
 /// re-rendering movable-object shadow geometry onto them.
 ///
@@ -19,3 +20,10 @@ fn vs_main(@builtin(vertex_index) vid: u32) -> @builtin(position) vec4<f32> {
     let y = f32(vid & 2u) * 2.0 - 1.0;
     return vec4<f32>(x, y, 1.0, 1.0);
 }
+
+@vertex
+fn vs_main_reversed(@builtin(vertex_index) vid: u32) -> @builtin(position) vec4<f32> {
+    let x = f32((vid << 1u) & 2u) * 2.0 - 1.0;
+    let y = f32(vid & 2u) * 2.0 - 1.0;
+    return vec4<f32>(x, y, 0.0, 1.0);
+}
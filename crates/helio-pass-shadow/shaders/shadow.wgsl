@@ -7,6 +7,7 @@
 // Design mirrors Unreal Engine 4 "Shadow Depth Pass" and Unity HDRP
 // "Shadow Caster Pass": position-only transform, depth-write only,
 // front-face culled to eliminate self-shadowing acne.
+//!use helio_prelude
 
 // ── Types ─────────────────────────────────────────────────────────────────────
 
@@ -32,6 +33,14 @@ struct FaceIndex {
     _pad2: u32,
 }
 
+// Must match `ShadowUniforms` in lib.rs (16 bytes).
+struct ShadowUniforms {
+    time:  f32,
+    _pad0: f32,
+    _pad1: f32,
+    _pad2: f32,
+}
+
 // ── Bindings ──────────────────────────────────────────────────────────────────
 
 // Pre-computed light-space view-projection matrices; one per shadow atlas face.
@@ -40,6 +49,9 @@ struct FaceIndex {
 @group(0) @binding(1) var<storage, read> instances:       array<GpuInstanceData>;
 // Current face selection, updated each pass via dynamic offset into a pre-written buffer.
 @group(0) @binding(2) var<uniform>       face:            FaceIndex;
+// Per-instance wind sway, parallel to `instances`; casters sway with their geometry.
+@group(0) @binding(3) var<storage, read> wind_params:     array<HelioWindParams>;
+@group(0) @binding(4) var<uniform>       shadow:          ShadowUniforms;
 
 // ── Vertex stage ──────────────────────────────────────────────────────────────
 
@@ -48,7 +60,9 @@ fn vs_main(
     @location(0)             position: vec3<f32>,
     @builtin(instance_index) slot:     u32,
 ) -> @builtin(position) vec4<f32> {
-    let world = instances[slot].transform * vec4<f32>(position, 1.0);
+    let inst  = instances[slot];
+    var world = inst.transform * vec4<f32>(position, 1.0);
+    world += vec4<f32>(helio_wind_offset(wind_params[slot], position, inst.transform[3].xyz, shadow.time), 0.0);
     return shadow_matrices[face.value] * world;
 }
 
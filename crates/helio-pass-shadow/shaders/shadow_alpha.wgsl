@@ -0,0 +1,155 @@
+enable wgpu_binding_array;
+
+// Shadow caster pass — alpha-tested variant.
+//
+// Same projection as `shadow.wgsl`, plus a fragment stage that discards texels
+// of alpha-tested (`FLAG_ALPHA_TEST`) materials below their cutoff, so cutout
+// geometry such as foliage and fences casts the shape of its texture instead of
+// a solid quad.  Fragments of other materials fall through and write depth as
+// in the depth-only pipeline.
+//
+// The alpha test mirrors the GBuffer pass: base-colour factor × base-colour
+// texture alpha, compared against `MaterialTextureData.params.z`.
+//!use helio_prelude
+
+// ── Types ─────────────────────────────────────────────────────────────────────
+
+// Per-instance world transform.  Must match GpuInstanceData in libhelio (144 bytes).
+struct GpuInstanceData {
+    transform:    mat4x4<f32>,   // offset   0
+    normal_mat_0: vec4<f32>,     // offset  64  (unused in shadow pass)
+    normal_mat_1: vec4<f32>,     // offset  80
+    normal_mat_2: vec4<f32>,     // offset  96
+    bounds:       vec4<f32>,     // offset 112
+    mesh_id:      u32,           // offset 128
+    material_id:  u32,           // offset 132
+    flags:        u32,           // offset 136
+    _pad:         u32,           // offset 140
+}
+
+struct FaceIndex {
+    value: u32,
+    _pad0: u32,
+    _pad1: u32,
+    _pad2: u32,
+}
+
+// Must match `ShadowUniforms` in lib.rs (16 bytes).
+struct ShadowUniforms {
+    time:  f32,
+    _pad0: f32,
+    _pad1: f32,
+    _pad2: f32,
+}
+
+// GPU material (112 bytes, matches libhelio::GpuMaterial).
+struct GpuMaterial {
+    base_color:         vec4<f32>,
+    emissive:           vec4<f32>,
+    roughness_metallic: vec4<f32>,
+    tex_base_color:     u32,
+    tex_normal:         u32,
+    tex_roughness:      u32,
+    tex_emissive:       u32,
+    tex_occlusion:      u32,
+    workflow:           u32,
+    flags:              u32,
+    material_class:     u32,
+    class_params:       vec4<f32>,
+}
+
+const FLAG_ALPHA_TEST: u32 = 1u << 2u;
+const NO_TEXTURE: u32 = 0xffffffffu;
+
+// Per-material texture metadata (224 bytes, matches helio::GpuMaterialTextures).
+struct MaterialTextureSlot {
+    texture_index: u32,
+    uv_channel:    u32,
+    _pad0:         u32,
+    _pad1:         u32,
+    offset_scale:  vec4<f32>,
+    rotation:      vec4<f32>,
+}
+
+struct MaterialTextureData {
+    base_color:         MaterialTextureSlot,
+    normal:             MaterialTextureSlot,
+    roughness_metallic: MaterialTextureSlot,
+    emissive:           MaterialTextureSlot,
+    occlusion:          MaterialTextureSlot,
+    specular_color:     MaterialTextureSlot,
+    specular_weight:    MaterialTextureSlot,
+    params:             vec4<f32>,  // x=normal_scale, y=occlusion_strength, z=alpha_cutoff
+}
+
+struct VertexOutput {
+    @builtin(position)              clip_pos:    vec4<f32>,
+    @location(0)                    tex_coords:  vec2<f32>,
+    @location(1) @interpolate(flat) material_id: u32,
+}
+
+// ── Bindings ──────────────────────────────────────────────────────────────────
+
+@group(0) @binding(0) var<storage, read> shadow_matrices: array<mat4x4<f32>>;
+@group(0) @binding(1) var<storage, read> instances:       array<GpuInstanceData>;
+@group(0) @binding(2) var<uniform>       face:            FaceIndex;
+@group(0) @binding(3) var<storage, read> wind_params:     array<HelioWindParams>;
+@group(0) @binding(4) var<uniform>       shadow:          ShadowUniforms;
+
+// Scene material tables, shared with the GBuffer pass.
+@group(1) @binding(0) var<storage, read> materials:         array<GpuMaterial>;
+@group(1) @binding(1) var<storage, read> material_textures: array<MaterialTextureData>;
+@group(1) @binding(2) var                scene_textures:    binding_array<texture_2d<f32>, 256>;
+@group(1) @binding(3) var                scene_samplers:    binding_array<sampler, 256>;
+
+fn select_uv(slot: MaterialTextureSlot, base_uv: vec2<f32>) -> vec2<f32> {
+    let scaled = base_uv * slot.offset_scale.zw;
+    let s = slot.rotation.x;
+    let c = slot.rotation.y;
+    let rotated = vec2<f32>(
+        scaled.x * c - scaled.y * s,
+        scaled.x * s + scaled.y * c,
+    );
+    return rotated + slot.offset_scale.xy;
+}
+
+fn sample_texture(slot: MaterialTextureSlot, base_uv: vec2<f32>, fallback: vec4<f32>) -> vec4<f32> {
+    if slot.texture_index == NO_TEXTURE {
+        return fallback;
+    }
+    let uv = select_uv(slot, base_uv);
+    return textureSample(scene_textures[slot.texture_index], scene_samplers[slot.texture_index], uv);
+}
+
+// ── Vertex stage ──────────────────────────────────────────────────────────────
+
+@vertex
+fn vs_main(
+    @location(0)             position:   vec3<f32>,
+    @location(1)             tex_coords: vec2<f32>,
+    @builtin(instance_index) slot:       u32,
+) -> VertexOutput {
+    let instance = instances[slot];
+    var out: VertexOutput;
+    var world = instance.transform * vec4<f32>(position, 1.0);
+    world += vec4<f32>(helio_wind_offset(wind_params[slot], position, instance.transform[3].xyz, shadow.time), 0.0);
+    out.clip_pos = shadow_matrices[face.value] * world;
+    out.tex_coords = tex_coords;
+    out.material_id = instance.material_id;
+    return out;
+}
+
+// ── Fragment stage ────────────────────────────────────────────────────────────
+
+@fragment
+fn fs_main(in: VertexOutput) {
+    let material = materials[in.material_id];
+    if (material.flags & FLAG_ALPHA_TEST) == 0u {
+        return;
+    }
+    let material_tex = material_textures[in.material_id];
+    let alpha = material.base_color.a * sample_texture(material_tex.base_color, in.tex_coords, vec4<f32>(1.0)).a;
+    if alpha < material_tex.params.z {
+        discard;
+    }
+}
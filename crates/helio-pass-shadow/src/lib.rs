@@ -45,6 +45,29 @@
 //!
 //! Light movement is still detected CPU-side via `per_caster_dirty_gen` (O(N_lights),
 //! negligible).  Light-dirty faces use `LoadOp::Clear` + full movable geometry draws.
+//!
+//! # Alpha-tested casters
+//!
+//! While the scene has any `FLAG_ALPHA_TEST` material, casters are drawn with a
+//! variant pipeline that adds a fragment stage: it samples the material's
+//! base-colour alpha through the scene's bindless texture table (group 1, shared
+//! with the GBuffer pass) and discards below the material's cutoff, so foliage
+//! and fences cast cutout shadows.  Other materials keep writing depth
+//! unconditionally; scenes without alpha-tested materials stay depth-only.
+//!
+//! # Wind
+//!
+//! Casters are displaced by the same `helio_wind_offset` as the GBuffer, so a
+//! swaying tree's shadow sways with it. Wind moves vertices without bumping any
+//! generation counter, so while `SceneResources::has_wind` is set every face
+//! is treated as light-dirty and re-rendered each frame.
+//!
+//! # Adaptive resolution
+//!
+//! Under `AdaptiveShadowResolution` each caster has a resolution tier
+//! (`SceneResources::per_caster_resolution_shift`). A reduced caster's
+//! matrices map its frustum into the top-left corner of its layers, and its
+//! faces are scissored to that region plus `SHADOW_REGION_MARGIN` texels.
 
 use helio_core::graph::{ResourceBuilder, ResourceSize};
 use helio_core::{PassContext, PrepareContext, RenderPass, Result as HelioResult};
@@ -65,14 +88,54 @@ const FACE_BUF_STRIDE: u64 = 256;
 /// Must match `MAX_DRAWS_PER_FACE` in helio-pass-shadow-cull.
 const MAX_DRAWS_PER_FACE: u32 = 4096;
 
+/// Size of the scene's bindless texture table. Must match the GBuffer pass.
+/// Capped at 16 on wasm32, Apple native Metal, and Android; 256 on other desktop backends.
+#[cfg(not(any(target_arch = "wasm32", target_os = "macos", target_os = "ios", target_os = "android")))]
+const MAX_TEXTURES: usize = 256;
+#[cfg(any(target_arch = "wasm32", target_os = "macos", target_os = "ios", target_os = "android"))]
+const MAX_TEXTURES: usize = 16;
+
+/// Per-frame uniforms (matches `ShadowUniforms` in shadow.wgsl).
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct ShadowUniforms {
+    time: f32,
+    _pad: [f32; 3],
+}
+
 // ── Pass struct ───────────────────────────────────────────────────────────────
 
 pub struct ShadowPass {
     /// Shadow geometry pipeline (depth-only, front-face culled, depth-bias = 2.0).
     pipeline: wgpu::RenderPipeline,
 
-    /// Depth-clear pipeline — renders a full-screen triangle at z=1.0 with
-    /// `DepthCompare::Always` to GPU-clear individual atlas faces before geometry.
+    /// Same as `pipeline` with `unclipped_depth`, built on first use of
+    /// [`set_depth_clamp`](Self::set_depth_clamp).
+    clamped_pipeline: Option<wgpu::RenderPipeline>,
+    pipeline_layout: wgpu::PipelineLayout,
+    shader: wgpu::ShaderModule,
+    /// Requested depth-clamp state; validated against the device in `prepare`.
+    depth_clamp: bool,
+    depth_clamp_changed: bool,
+    /// Depth convention the atlases are rendered in; pipelines, samplers and
+    /// clear values are rebuilt in `prepare` when it changes.
+    depth_convention: libhelio::DepthConvention,
+    depth_convention_changed: bool,
+
+    /// Alpha-tested caster pipelines, indexed by depth clamp; built on first use.
+    alpha_pipelines: [Option<wgpu::RenderPipeline>; 2],
+    alpha_pipeline_layout: wgpu::PipelineLayout,
+    alpha_shader: wgpu::ShaderModule,
+    /// Whether the alpha-tested pipeline is active (the scene has alpha-tested materials).
+    alpha_test: bool,
+    /// Group 1 of the alpha-tested pipeline: materials + bindless texture table.
+    bgl_1: wgpu::BindGroupLayout,
+    bg_1: Option<wgpu::BindGroup>,
+    /// `material_textures.version` that `bg_1` was built against.
+    bg_1_version: Option<u64>,
+
+    /// Depth-clear pipeline — renders a full-screen triangle at the far plane
+    /// with `DepthCompare::Always` to GPU-clear individual atlas faces before geometry.
     depth_clear_pipeline: wgpu::RenderPipeline,
 
     #[allow(dead_code)]
@@ -86,17 +149,30 @@ pub struct ShadowPass {
     /// Per-face face-index values, written once at construction and never touched again.
     face_idx_buf: wgpu::Buffer,
 
+    /// [`ShadowUniforms`]; animation time for wind sway, written every `prepare`.
+    uniform_buf: wgpu::Buffer,
+
     // ── Dynamic shadow atlas (Movable objects only) ───────────────────────────
+    /// One single-layer view per atlas face, created once and reused every
+    /// frame. Rebuilt only when the graph reallocates `face_views_source`.
     face_views: Box<[wgpu::TextureView]>,
+    /// Atlas texture `face_views` were created from.
+    face_views_source: Option<wgpu::Texture>,
     bg_0: Option<wgpu::BindGroup>,
-    bg_0_key: Option<(usize, usize)>,
+    bg_0_key: Option<(usize, usize, usize)>,
 
     // ── Static shadow atlas (Static/Stationary objects only) ─────────────────
     static_face_views: Box<[wgpu::TextureView]>,
+    static_face_views_source: Option<wgpu::Texture>,
     /// Last `static_objects_generation` rendered.  `None` = never rendered.
     static_atlas_cache_gen: Option<u64>,
 
     pub compare_sampler: wgpu::Sampler,
+    /// Variant of `compare_sampler` with a white border, so PCF taps past
+    /// the face edge read as lit. Created on the first border-clamp request.
+    border_sampler: Option<wgpu::Sampler>,
+    border_clamp: bool,
+    border_clamp_changed: bool,
 
     // ── GPU dirty buffers (shared with ShadowDirtyPass) ───────────────────────
     /// `array<u32, 256>` — 0 = clean, 1 = dirty (written by ShadowDirtyPass).
@@ -138,6 +214,10 @@ pub struct ShadowPass {
     /// False on macOS Metal, WASM, and older Vulkan/DX12.  When false the ObjectDirty path
     /// falls back to a full LoadOp::Clear + multi_draw_indexed_indirect (no per-face GPU culling).
     supports_multi_draw_count: bool,
+
+    /// Atlas face render passes recorded by the last `execute` (static and
+    /// dynamic layers counted separately; 0 on fully cached frames).
+    faces_rendered: u32,
 }
 
 impl ShadowPass {
@@ -157,15 +237,10 @@ impl ShadowPass {
     ) -> Self {
         let atlas_layers = atlas_layers.clamp(1, MAX_SHADOW_FACES as u32);
         // ── Shader ────────────────────────────────────────────────────────────
-        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
-            label: Some("Shadow"),
-            source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/shadow.wgsl").into()),
-        });
-
-        let clear_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
-            label: Some("Shadow/DepthClear"),
-            source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/depth_clear.wgsl").into()),
-        });
+        let shader =
+            helio_core::shader::module(device, "Shadow", include_str!("../shaders/shadow.wgsl"));
+        let alpha_shader =
+            helio_core::shader::module(device, "Shadow/AlphaTest", &shadow_alpha_source());
 
         // ── Bind Group Layout 0 ───────────────────────────────────────────────
         let bgl_0 = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -204,6 +279,28 @@ impl ShadowPass {
                     },
                     count: None,
                 },
+                // binding 3: wind — per-instance sway, parallel to instances
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // binding 4: ShadowUniforms — animation time
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
             ],
         });
 
@@ -214,97 +311,25 @@ impl ShadowPass {
             immediate_size: 0,
         });
 
-        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Shadow Pipeline"),
-            layout: Some(&pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &shader,
-                entry_point: Some("vs_main"),
-                compilation_options: Default::default(),
-                // Shared mesh vertex buffer layout (stride = 40 bytes, matches GBuffer pass).
-                // Only position (Float32x3 at offset 0) is needed for depth projection.
-                buffers: &[Some(wgpu::VertexBufferLayout {
-                    array_stride: 40,
-                    step_mode: wgpu::VertexStepMode::Vertex,
-                    attributes: &[wgpu::VertexAttribute {
-                        format: wgpu::VertexFormat::Float32x3,
-                        offset: 0,
-                        shader_location: 0,
-                    }],
-                })],
-            },
-            // Depth-only: no colour outputs, no fragment shader.
-            // The GPU writes depth from the vertex clip position automatically.
-            fragment: None,
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
-                // Front-face culling: light "looks into" the scene; culling the faces
-                // visible to the light prevents writing depth for lit-surface geometry
-                // directly, eliminating shadow acne.  Identical convention to UE4/Unity.
-                cull_mode: Some(wgpu::Face::Front),
-                ..Default::default()
-            },
-            depth_stencil: Some(wgpu::DepthStencilState {
-                format: wgpu::TextureFormat::Depth32Float,
-                depth_write_enabled: Some(true),
-                depth_compare: Some(wgpu::CompareFunction::Less),
-                stencil: wgpu::StencilState::default(),
-                // slope_scale compensates for FP depth precision on surfaces at
-                // grazing angles to the light.  Without it the shadow map depth for
-                // a surface can be equal-to or less-than the depth reconstructed in
-                // the lighting shader for that same surface, causing self-shadowing
-                // on every light independently (making each light appear to inherit
-                // every other light's shadow geometry).
-                // constant is left at 0 — that was the source of the visible offset.
-                bias: wgpu::DepthBiasState {
-                    constant: 0,
-                    slope_scale: 2.0,
-                    clamp: 0.0,
-                },
-            }),
-            multisample: wgpu::MultisampleState::default(),
-            multiview_mask: None,
-            cache: None,
-        });
-
-        // ── Depth-clear pipeline ───────────────────────────────────────────────
-        // GPU-clear individual shadow atlas faces: renders a full-screen triangle
-        // at depth=1.0 (far plane) using DepthCompare::Always to overwrite existing
-        // depth values.  No vertex buffer, no fragment shader, no depth bias.
-        let depth_clear_pipeline_layout =
-            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
-                label: Some("Shadow/DepthClear PL"),
-                bind_group_layouts: &[],
-                immediate_size: 0,
-            });
+        let convention = libhelio::DepthConvention::Standard;
+        let pipeline = Self::create_geometry_pipeline(
+            device,
+            &pipeline_layout,
+            &shader,
+            false,
+            false,
+            convention,
+        );
 
-        let depth_clear_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Shadow/DepthClear Pipeline"),
-            layout: Some(&depth_clear_pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &clear_shader,
-                entry_point: Some("vs_main"),
-                compilation_options: Default::default(),
-                buffers: &[],
-            },
-            fragment: None,
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
-                cull_mode: None,
-                ..Default::default()
-            },
-            depth_stencil: Some(wgpu::DepthStencilState {
-                format: wgpu::TextureFormat::Depth32Float,
-                depth_write_enabled: Some(true),
-                depth_compare: Some(wgpu::CompareFunction::Always),
-                stencil: wgpu::StencilState::default(),
-                bias: wgpu::DepthBiasState::default(),
-            }),
-            multisample: wgpu::MultisampleState::default(),
-            multiview_mask: None,
-            cache: None,
+        let bgl_1 = create_material_bgl(device);
+        let alpha_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Shadow/AlphaTest PL"),
+            bind_group_layouts: &[Some(&bgl_0), Some(&bgl_1)],
+            immediate_size: 0,
         });
 
+        let depth_clear_pipeline = Self::create_depth_clear_pipeline(device, convention);
+
         // ── Clear indirect buffer ──────────────────────────────────────────────
         // 256 non-indexed draw commands, each drawing 3 vertices (the clear triangle).
         // Layout per command (16 bytes): { vertex_count: 3, instance_count: 1,
@@ -344,35 +369,51 @@ impl ShadowPass {
         });
         queue.write_buffer(&face_idx_buf, 0, &face_idx_data);
 
+        let uniform_buf = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Shadow/Uniforms"),
+            size: std::mem::size_of::<ShadowUniforms>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
         // ── Face views (lazily initialized from graph-owned textures) ──────────
         let face_views = Box::default();
         let static_face_views = Box::default();
 
-        // Comparison sampler for PCF shadow lookups in the lighting pass.
-        let compare_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
-            label: Some("Shadow/Compare"),
-            address_mode_u: wgpu::AddressMode::ClampToEdge,
-            address_mode_v: wgpu::AddressMode::ClampToEdge,
-            address_mode_w: wgpu::AddressMode::ClampToEdge,
-            mag_filter: wgpu::FilterMode::Linear,
-            min_filter: wgpu::FilterMode::Linear,
-            mipmap_filter: wgpu::MipmapFilterMode::Nearest,
-            compare: Some(wgpu::CompareFunction::LessEqual),
-            ..Default::default()
-        });
+        let compare_sampler = Self::create_compare_sampler(device, convention);
 
         Self {
             pipeline,
+            clamped_pipeline: None,
+            pipeline_layout,
+            shader,
+            depth_clamp: false,
+            depth_clamp_changed: false,
+            depth_convention: convention,
+            depth_convention_changed: false,
+            alpha_pipelines: [None, None],
+            alpha_pipeline_layout,
+            alpha_shader,
+            alpha_test: false,
+            bgl_1,
+            bg_1: None,
+            bg_1_version: None,
             depth_clear_pipeline,
             bgl_0,
             bg_0: None,
             bg_0_key: None,
             static_atlas_cache_gen: None,
             face_idx_buf,
+            uniform_buf,
             clear_indirect_buf,
             face_views,
+            face_views_source: None,
             static_face_views,
+            static_face_views_source: None,
             compare_sampler,
+            border_sampler: None,
+            border_clamp: false,
+            border_clamp_changed: false,
             face_dirty_buf,
             face_geom_count_buf,
             face_cull_indirect,
@@ -385,9 +426,225 @@ impl ShadowPass {
                 .contains(wgpu::Features::MULTI_DRAW_INDIRECT_COUNT),
             atlas_size,
             atlas_layers,
+            faces_rendered: 0,
+        }
+    }
+
+    /// Clamp caster depth to the light's near plane instead of clipping.
+    ///
+    /// Occluders between the light and its near plane otherwise vanish from
+    /// the shadow map, leaving holes. Needs `Features::DEPTH_CLIP_CONTROL`;
+    /// on devices without it the request is dropped with a warning in the
+    /// next `prepare`.
+    pub fn set_depth_clamp(&mut self, enabled: bool) {
+        if enabled != self.depth_clamp {
+            self.depth_clamp = enabled;
+            self.depth_clamp_changed = true;
+        }
+    }
+
+    /// Convention the atlases are rendered in, set through
+    /// [`RenderPass::set_depth_convention`].
+    pub fn depth_convention(&self) -> libhelio::DepthConvention {
+        self.depth_convention
+    }
+
+    /// Sample outside each shadow face as fully lit instead of clamping to
+    /// the edge texel.
+    ///
+    /// With edge clamping, filter taps near a spotlight's frustum edge read
+    /// the outermost texel and smear its shadow past the cone. Needs
+    /// `Features::ADDRESS_MODE_CLAMP_TO_BORDER`; on devices without it the
+    /// request is dropped with a warning in the next `prepare`.
+    pub fn set_border_clamp(&mut self, enabled: bool) {
+        if enabled != self.border_clamp {
+            self.border_clamp = enabled;
+            self.border_clamp_changed = true;
         }
     }
 
+    /// Whether border clamping is active (false after an unsupported request).
+    pub fn border_clamp(&self) -> bool {
+        self.border_clamp
+    }
+
+    /// Comparison sampler consumers should use for the atlases.
+    pub fn shadow_sampler(&self) -> &wgpu::Sampler {
+        match &self.border_sampler {
+            Some(sampler) if self.border_clamp => sampler,
+            _ => &self.compare_sampler,
+        }
+    }
+
+    /// Atlas face render passes recorded last frame. Cached frames report 0.
+    pub fn faces_rendered(&self) -> u32 {
+        self.faces_rendered
+    }
+
+    /// Whether depth clamping is active (false after an unsupported request).
+    pub fn depth_clamp(&self) -> bool {
+        self.depth_clamp
+    }
+
+    /// Whether casters are drawn with the alpha-tested pipeline.
+    pub fn alpha_test(&self) -> bool {
+        self.alpha_test
+    }
+
+    /// Caster pipeline. `unclipped_depth` requires `Features::DEPTH_CLIP_CONTROL`;
+    /// `alpha_tested` expects the alpha-test shader and pipeline layout.
+    fn create_geometry_pipeline(
+        device: &wgpu::Device,
+        layout: &wgpu::PipelineLayout,
+        shader: &wgpu::ShaderModule,
+        unclipped_depth: bool,
+        alpha_tested: bool,
+        convention: libhelio::DepthConvention,
+    ) -> wgpu::RenderPipeline {
+        // Shared mesh vertex buffer layout (stride = 40 bytes, matches GBuffer pass).
+        // Position (Float32x3 at offset 0) is all depth projection needs; the
+        // alpha test also reads UV0 (Float32x2 at offset 16).
+        const POSITION: wgpu::VertexAttribute = wgpu::VertexAttribute {
+            format: wgpu::VertexFormat::Float32x3,
+            offset: 0,
+            shader_location: 0,
+        };
+        const UV0: wgpu::VertexAttribute = wgpu::VertexAttribute {
+            format: wgpu::VertexFormat::Float32x2,
+            offset: 16,
+            shader_location: 1,
+        };
+        let attributes: &[wgpu::VertexAttribute] =
+            if alpha_tested { &[POSITION, UV0] } else { &[POSITION] };
+        let label = match (unclipped_depth, alpha_tested) {
+            (false, false) => "Shadow Pipeline",
+            (true, false) => "Shadow Pipeline (Depth Clamp)",
+            (false, true) => "Shadow Pipeline (Alpha Test)",
+            (true, true) => "Shadow Pipeline (Alpha Test, Depth Clamp)",
+        };
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some(label),
+            layout: Some(layout),
+            vertex: wgpu::VertexState {
+                module: shader,
+                entry_point: Some("vs_main"),
+                compilation_options: Default::default(),
+                buffers: &[Some(wgpu::VertexBufferLayout {
+                    array_stride: 40,
+                    step_mode: wgpu::VertexStepMode::Vertex,
+                    attributes,
+                })],
+            },
+            // Depth-only: no colour outputs. Without a fragment shader the GPU
+            // writes depth from the vertex clip position automatically; the
+            // alpha-test fragment stage only decides which fragments survive.
+            fragment: alpha_tested.then(|| wgpu::FragmentState {
+                module: shader,
+                entry_point: Some("fs_main"),
+                compilation_options: Default::default(),
+                targets: &[],
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                // Front-face culling: light "looks into" the scene; culling the faces
+                // visible to the light prevents writing depth for lit-surface geometry
+                // directly, eliminating shadow acne.  Identical convention to UE4/Unity.
+                cull_mode: Some(wgpu::Face::Front),
+                // Depth clamp: casters in front of the light's near plane are
+                // flattened onto it instead of clipped away.
+                unclipped_depth,
+                ..Default::default()
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float,
+                depth_write_enabled: Some(true),
+                depth_compare: Some(convention.compare(wgpu::CompareFunction::Less)),
+                stencil: wgpu::StencilState::default(),
+                // slope_scale compensates for FP depth precision on surfaces at
+                // grazing angles to the light.  Without it the shadow map depth for
+                // a surface can be equal-to or less-than the depth reconstructed in
+                // the lighting shader for that same surface, causing self-shadowing
+                // on every light independently (making each light appear to inherit
+                // every other light's shadow geometry).
+                // constant is left at 0 — that was the source of the visible offset.
+                bias: convention.depth_bias(wgpu::DepthBiasState {
+                    constant: 0,
+                    slope_scale: 2.0,
+                    clamp: 0.0,
+                }),
+            }),
+            multisample: wgpu::MultisampleState::default(),
+            multiview_mask: None,
+            cache: None,
+        })
+    }
+
+    /// GPU-clear individual shadow atlas faces: renders a full-screen triangle
+    /// at the far plane using DepthCompare::Always to overwrite existing depth
+    /// values. No vertex buffer, no fragment shader, no depth bias.
+    fn create_depth_clear_pipeline(
+        device: &wgpu::Device,
+        convention: libhelio::DepthConvention,
+    ) -> wgpu::RenderPipeline {
+        let clear_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Shadow/DepthClear"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/depth_clear.wgsl").into()),
+        });
+        let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Shadow/DepthClear PL"),
+            bind_group_layouts: &[],
+            immediate_size: 0,
+        });
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Shadow/DepthClear Pipeline"),
+            layout: Some(&layout),
+            vertex: wgpu::VertexState {
+                module: &clear_shader,
+                entry_point: Some(if convention.is_reversed() {
+                    "vs_main_reversed"
+                } else {
+                    "vs_main"
+                }),
+                compilation_options: Default::default(),
+                buffers: &[],
+            },
+            fragment: None,
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                cull_mode: None,
+                ..Default::default()
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float,
+                depth_write_enabled: Some(true),
+                depth_compare: Some(wgpu::CompareFunction::Always),
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState::default(),
+            multiview_mask: None,
+            cache: None,
+        })
+    }
+
+    /// Comparison sampler for PCF shadow lookups in the lighting pass.
+    fn create_compare_sampler(
+        device: &wgpu::Device,
+        convention: libhelio::DepthConvention,
+    ) -> wgpu::Sampler {
+        device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Shadow/Compare"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::MipmapFilterMode::Nearest,
+            compare: Some(convention.compare(wgpu::CompareFunction::LessEqual)),
+            ..Default::default()
+        })
+    }
+
     fn create_face_views(
         texture: &wgpu::Texture,
         label: &str,
@@ -435,6 +692,15 @@ impl RenderPass for ShadowPass {
         "Shadow"
     }
 
+    /// Flips the caster depth test and bias, the face clear value and the
+    /// comparison sampler from the next `prepare`.
+    fn set_depth_convention(&mut self, convention: libhelio::DepthConvention) {
+        if convention != self.depth_convention {
+            self.depth_convention = convention;
+            self.depth_convention_changed = true;
+        }
+    }
+
     fn reads(&self) -> &'static [&'static str] {
         &["main_scene"]
     }
@@ -443,30 +709,146 @@ impl RenderPass for ShadowPass {
         &["shadow_atlas", "shadow_sampler", "static_shadow_atlas"]
     }
 
-    fn publish<'a>(&'a self, _frame: &mut libhelio::FrameResources<'a>) {}
+    fn publish<'a>(&'a self, frame: &mut libhelio::FrameResources<'a>) {
+        frame.shadow_sampler.write(self.shadow_sampler(), "Shadow");
+    }
+
+    fn gpu_memory_bytes(&self) -> u64 {
+        // The atlases are graph-owned; the culled indirect buffers belong to
+        // ShadowCullPass.
+        self.clear_indirect_buf.size() + self.face_idx_buf.size() + self.uniform_buf.size()
+    }
 
-    fn prepare(&mut self, _ctx: &PrepareContext) -> HelioResult<()> {
+    fn prepare(&mut self, ctx: &PrepareContext) -> HelioResult<()> {
+        let uniforms = ShadowUniforms { time: ctx.time, _pad: [0.0; 3] };
+        ctx.write_buffer(&self.uniform_buf, 0, bytemuck::bytes_of(&uniforms));
+
+        let mut pipeline_changed = false;
+        if std::mem::take(&mut self.depth_convention_changed) {
+            let convention = self.depth_convention;
+            self.pipeline = Self::create_geometry_pipeline(
+                ctx.device,
+                &self.pipeline_layout,
+                &self.shader,
+                false,
+                false,
+                convention,
+            );
+            self.clamped_pipeline = None;
+            self.alpha_pipelines = [None, None];
+            self.depth_clear_pipeline = Self::create_depth_clear_pipeline(ctx.device, convention);
+            self.compare_sampler = Self::create_compare_sampler(ctx.device, convention);
+            self.border_sampler = None;
+            self.border_clamp_changed |= self.border_clamp;
+            pipeline_changed = true;
+        }
+
+        if std::mem::take(&mut self.border_clamp_changed) && self.border_clamp {
+            if !ctx
+                .device
+                .features()
+                .contains(wgpu::Features::ADDRESS_MODE_CLAMP_TO_BORDER)
+            {
+                log::warn!(
+                    "Shadow border clamp requested but the device lacks \
+                     ADDRESS_MODE_CLAMP_TO_BORDER; shadow edges will keep clamping"
+                );
+                self.border_clamp = false;
+            } else if self.border_sampler.is_none() {
+                self.border_sampler = Some(ctx.device.create_sampler(&wgpu::SamplerDescriptor {
+                    label: Some("Shadow/CompareBorder"),
+                    address_mode_u: wgpu::AddressMode::ClampToBorder,
+                    address_mode_v: wgpu::AddressMode::ClampToBorder,
+                    address_mode_w: wgpu::AddressMode::ClampToEdge,
+                    mag_filter: wgpu::FilterMode::Linear,
+                    min_filter: wgpu::FilterMode::Linear,
+                    mipmap_filter: wgpu::MipmapFilterMode::Nearest,
+                    compare: Some(self.depth_convention.compare(wgpu::CompareFunction::LessEqual)),
+                    // The far plane: every reference passes.
+                    border_color: Some(self.depth_convention.far_border_color()),
+                    ..Default::default()
+                }));
+            }
+        }
+
+        if std::mem::take(&mut self.depth_clamp_changed) {
+            if self.depth_clamp
+                && !ctx.device.features().contains(wgpu::Features::DEPTH_CLIP_CONTROL)
+            {
+                log::warn!(
+                    "Shadow depth clamp requested but the device lacks DEPTH_CLIP_CONTROL; \
+                     casters in front of the light near plane will still be clipped"
+                );
+                self.depth_clamp = false;
+            } else {
+                pipeline_changed = true;
+            }
+        }
+        if ctx.scene.has_alpha_tested_materials != self.alpha_test {
+            self.alpha_test = ctx.scene.has_alpha_tested_materials;
+            pipeline_changed = true;
+        }
+        if !pipeline_changed {
+            return Ok(());
+        }
+
+        let clamp = self.depth_clamp;
+        if self.alpha_test {
+            if self.alpha_pipelines[clamp as usize].is_none() {
+                self.alpha_pipelines[clamp as usize] = Some(Self::create_geometry_pipeline(
+                    ctx.device,
+                    &self.alpha_pipeline_layout,
+                    &self.alpha_shader,
+                    clamp,
+                    true,
+                    self.depth_convention,
+                ));
+            }
+        } else if clamp && self.clamped_pipeline.is_none() {
+            self.clamped_pipeline = Some(Self::create_geometry_pipeline(
+                ctx.device,
+                &self.pipeline_layout,
+                &self.shader,
+                true,
+                false,
+                self.depth_convention,
+            ));
+        }
+        // Cached atlas faces were rendered with the other pipeline.
+        self.static_atlas_cache_gen = None;
+        self.per_caster_last_gen = [u64::MAX; 42];
+        self.last_movable_objects_gen = u64::MAX;
         Ok(())
     }
 
     fn execute(&mut self, ctx: &mut PassContext) -> HelioResult<()> {
+        self.faces_rendered = 0;
         let face_count = (ctx.scene.shadow_count as usize)
             .min(self.atlas_layers as usize)
             .min(MAX_SHADOW_FACES);
         let static_draw_count = ctx.scene.shadow_static_draw_count;
         let movable_draw_count = ctx.scene.shadow_movable_draw_count;
-
-        // ── Lazily initialize per-face views from graph-owned textures ─────────
-        if self.face_views.is_empty() {
-            if let Some(tex) = ctx.resource_pool.get_texture("shadow_atlas") {
+        let resolution_shifts = ctx.scene.per_caster_resolution_shift;
+
+        // ── Per-face views of the graph-owned atlases ──────────────────────────
+        // Created on first use and kept across frames. A graph reallocation
+        // hands out new textures, so the views (and whatever the old atlases
+        // held) are replaced only then.
+        if let Some(tex) = ctx.resource_pool.get_texture("shadow_atlas") {
+            if self.face_views_source.as_ref() != Some(tex) {
                 self.face_views =
                     Self::create_face_views(tex, "Shadow/DynamicFace", self.atlas_layers);
+                self.face_views_source = Some(tex.clone());
+                self.per_caster_last_gen = [u64::MAX; 42];
+                self.last_movable_objects_gen = u64::MAX;
             }
         }
-        if self.static_face_views.is_empty() {
-            if let Some(tex) = ctx.resource_pool.get_texture("static_shadow_atlas") {
+        if let Some(tex) = ctx.resource_pool.get_texture("static_shadow_atlas") {
+            if self.static_face_views_source.as_ref() != Some(tex) {
                 self.static_face_views =
                     Self::create_face_views(tex, "Shadow/StaticFace", self.atlas_layers);
+                self.static_face_views_source = Some(tex.clone());
+                self.static_atlas_cache_gen = None;
             }
         }
 
@@ -482,7 +864,12 @@ impl RenderPass for ShadowPass {
         let shadow_count = ctx.scene.shadow_count;
         let caster_count = (face_count / 6).min(42);
 
-        let need_static = self.static_atlas_cache_gen != Some(static_gen)
+        // Swaying casters move every frame without touching any generation
+        // counter, so no cached face can be trusted while one exists.
+        let swaying = ctx.scene.has_wind;
+
+        let need_static = swaying
+            || self.static_atlas_cache_gen != Some(static_gen)
             || shadow_count != self.last_rendered_shadow_count;
 
         // Per-caster dirty check for LIGHT movement only.
@@ -490,7 +877,7 @@ impl RenderPass for ShadowPass {
         let mut dirty_casters = [false; 42];
         let mut any_dirty_caster = false;
         for slot in 0..caster_count {
-            if ctx.scene.per_caster_dirty_gen[slot] != self.per_caster_last_gen[slot] {
+            if swaying || ctx.scene.per_caster_dirty_gen[slot] != self.per_caster_last_gen[slot] {
                 dirty_casters[slot] = true;
                 any_dirty_caster = true;
             }
@@ -510,11 +897,12 @@ impl RenderPass for ShadowPass {
         let vertices = main_scene.mesh_buffers.vertices;
         let indices = main_scene.mesh_buffers.indices;
 
-        // ── Shared bind group (shadow_matrices + instances + face_idx) ──────────
+        // ── Shared bind group (shadow_matrices + instances + face_idx + wind) ───
         // Rebuilt only on GrowableBuffer reallocation (O(1) amortised).
         let sm_ptr = ctx.scene.shadow_matrices as *const _ as usize;
         let inst_ptr = ctx.scene.instances as *const _ as usize;
-        let key = (sm_ptr, inst_ptr);
+        let wind_ptr = ctx.scene.wind as *const _ as usize;
+        let key = (sm_ptr, inst_ptr, wind_ptr);
         if self.bg_0_key != Some(key) {
             self.bg_0 = Some(ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
                 label: Some("Shadow BG 0"),
@@ -536,13 +924,43 @@ impl RenderPass for ShadowPass {
                             size: std::num::NonZeroU64::new(16),
                         }),
                     },
+                    wgpu::BindGroupEntry {
+                        binding: 3,
+                        resource: ctx.scene.wind.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 4,
+                        resource: self.uniform_buf.as_entire_binding(),
+                    },
                 ],
             }));
             self.bg_0_key = Some(key);
         }
         let bg = self.bg_0.as_ref().unwrap();
 
-        let pipeline = &self.pipeline;
+        // ── Material bind group (alpha-tested pipeline only) ───────────────────
+        // Rebuilt when the scene's texture table changes, like GBuffer group 1.
+        if self.alpha_test && self.bg_1_version != Some(main_scene.material_textures.version) {
+            self.bg_1 = Some(create_material_bind_group(
+                ctx.device,
+                &self.bgl_1,
+                ctx.scene.materials,
+                &main_scene.material_textures,
+            ));
+            self.bg_1_version = Some(main_scene.material_textures.version);
+        }
+        let bg_1 = self.bg_1.as_ref().filter(|_| self.alpha_test);
+
+        let pipeline = if self.alpha_test {
+            self.alpha_pipelines[self.depth_clamp as usize]
+                .as_ref()
+                .expect("alpha-tested shadow pipeline is built in prepare")
+        } else {
+            match &self.clamped_pipeline {
+                Some(clamped) if self.depth_clamp => clamped,
+                _ => &self.pipeline,
+            }
+        };
 
         // ── Static atlas render ────────────────────────────────────────────────
         if need_static || any_dirty_caster {
@@ -555,6 +973,7 @@ impl RenderPass for ShadowPass {
                     }
                     let face_view = &self.static_face_views[face];
                     let dyn_offset = (face as u64 * FACE_BUF_STRIDE) as u32;
+                    self.faces_rendered += 1;
                     let mut pass = unsafe { &mut *ctx.encoder_ptr }.begin_render_pass(
                         &wgpu::RenderPassDescriptor {
                             label: Some("Shadow/Static"),
@@ -563,7 +982,7 @@ impl RenderPass for ShadowPass {
                                 wgpu::RenderPassDepthStencilAttachment {
                                     view: face_view,
                                     depth_ops: Some(wgpu::Operations {
-                                        load: wgpu::LoadOp::Clear(1.0),
+                                        load: wgpu::LoadOp::Clear(self.depth_convention.far_depth()),
                                         store: wgpu::StoreOp::Store,
                                     }),
                                     stencil_ops: None,
@@ -574,8 +993,12 @@ impl RenderPass for ShadowPass {
                             multiview_mask: None,
                         },
                     );
+                    set_face_region(&mut pass, self.atlas_size, face_resolution_shift(&resolution_shifts, face));
                     pass.set_pipeline(pipeline);
                     pass.set_bind_group(0, bg, &[dyn_offset]);
+                    if let Some(bg_1) = bg_1 {
+                        pass.set_bind_group(1, bg_1, &[]);
+                    }
                     pass.set_vertex_buffer(0, vertices.slice(..));
                     pass.set_index_buffer(indices.slice(..), wgpu::IndexFormat::Uint32);
                     #[cfg(not(target_arch = "wasm32"))]
@@ -588,6 +1011,7 @@ impl RenderPass for ShadowPass {
             } else if need_static {
                 for face in 0..face_count {
                     let face_view = &self.static_face_views[face];
+                    self.faces_rendered += 1;
                     let _pass = unsafe { &mut *ctx.encoder_ptr }.begin_render_pass(
                         &wgpu::RenderPassDescriptor {
                             label: Some("Shadow/StaticClear"),
@@ -596,7 +1020,7 @@ impl RenderPass for ShadowPass {
                                 wgpu::RenderPassDepthStencilAttachment {
                                     view: face_view,
                                     depth_ops: Some(wgpu::Operations {
-                                        load: wgpu::LoadOp::Clear(1.0),
+                                        load: wgpu::LoadOp::Clear(self.depth_convention.far_depth()),
                                         store: wgpu::StoreOp::Store,
                                     }),
                                     stencil_ops: None,
@@ -646,6 +1070,7 @@ impl RenderPass for ShadowPass {
 
                 if light_dirty {
                     // ── Light moved: full clear + culled draws ─────────────────
+                    self.faces_rendered += 1;
                     let mut pass = unsafe { &mut *ctx.encoder_ptr }.begin_render_pass(
                         &wgpu::RenderPassDescriptor {
                             label: Some("Shadow/Dynamic/LightDirty"),
@@ -654,7 +1079,7 @@ impl RenderPass for ShadowPass {
                                 wgpu::RenderPassDepthStencilAttachment {
                                     view: face_view,
                                     depth_ops: Some(wgpu::Operations {
-                                        load: wgpu::LoadOp::Clear(1.0),
+                                        load: wgpu::LoadOp::Clear(self.depth_convention.far_depth()),
                                         store: wgpu::StoreOp::Store,
                                     }),
                                     stencil_ops: None,
@@ -665,9 +1090,13 @@ impl RenderPass for ShadowPass {
                             multiview_mask: None,
                         },
                     );
+                    set_face_region(&mut pass, self.atlas_size, face_resolution_shift(&resolution_shifts, face));
                     if movable_draw_count > 0 {
                         pass.set_pipeline(pipeline);
                         pass.set_bind_group(0, bg, &[dyn_offset]);
+                        if let Some(bg_1) = bg_1 {
+                            pass.set_bind_group(1, bg_1, &[]);
+                        }
                         pass.set_vertex_buffer(0, vertices.slice(..));
                         pass.set_index_buffer(indices.slice(..), wgpu::IndexFormat::Uint32);
                         let face_offset = face as u64 * MAX_DRAWS_PER_FACE as u64 * 20;
@@ -704,6 +1133,7 @@ impl RenderPass for ShadowPass {
                     //   Fall back to a full clear + draw all movable geometry,
                     //   equivalent to the LightDirty path but without per-face culling.
                     if self.supports_multi_draw_count {
+                        self.faces_rendered += 1;
                         let mut pass = unsafe { &mut *ctx.encoder_ptr }.begin_render_pass(
                             &wgpu::RenderPassDescriptor {
                                 label: Some("Shadow/Dynamic/ObjectDirty"),
@@ -723,6 +1153,7 @@ impl RenderPass for ShadowPass {
                                 multiview_mask: None,
                             },
                         );
+                        set_face_region(&mut pass, self.atlas_size, face_resolution_shift(&resolution_shifts, face));
 
                         if movable_draw_count > 0 {
                             // 1. Depth-clear triangle (GPU count 0 or 1 from face_dirty_buf).
@@ -738,6 +1169,9 @@ impl RenderPass for ShadowPass {
                             // 2. Shadow geometry (GPU count 0 or movable_draw_count from face_geom_count_buf).
                             pass.set_pipeline(pipeline);
                             pass.set_bind_group(0, bg, &[dyn_offset]);
+                            if let Some(bg_1) = bg_1 {
+                                pass.set_bind_group(1, bg_1, &[]);
+                            }
                             pass.set_vertex_buffer(0, vertices.slice(..));
                             pass.set_index_buffer(indices.slice(..), wgpu::IndexFormat::Uint32);
                             let face_offset = face as u64 * MAX_DRAWS_PER_FACE as u64 * 20;
@@ -751,6 +1185,7 @@ impl RenderPass for ShadowPass {
                         }
                     } else {
                         // Fallback: full clear + draw all movable geometry (no per-face GPU culling).
+                        self.faces_rendered += 1;
                         let mut pass = unsafe { &mut *ctx.encoder_ptr }.begin_render_pass(
                             &wgpu::RenderPassDescriptor {
                                 label: Some("Shadow/Dynamic/ObjectDirty/Fallback"),
@@ -759,7 +1194,7 @@ impl RenderPass for ShadowPass {
                                     wgpu::RenderPassDepthStencilAttachment {
                                         view: face_view,
                                         depth_ops: Some(wgpu::Operations {
-                                            load: wgpu::LoadOp::Clear(1.0),
+                                            load: wgpu::LoadOp::Clear(self.depth_convention.far_depth()),
                                             store: wgpu::StoreOp::Store,
                                         }),
                                         stencil_ops: None,
@@ -770,9 +1205,13 @@ impl RenderPass for ShadowPass {
                                 multiview_mask: None,
                             },
                         );
+                        set_face_region(&mut pass, self.atlas_size, face_resolution_shift(&resolution_shifts, face));
                         if movable_draw_count > 0 {
                             pass.set_pipeline(pipeline);
                             pass.set_bind_group(0, bg, &[dyn_offset]);
+                            if let Some(bg_1) = bg_1 {
+                                pass.set_bind_group(1, bg_1, &[]);
+                            }
                             pass.set_vertex_buffer(0, vertices.slice(..));
                             pass.set_index_buffer(indices.slice(..), wgpu::IndexFormat::Uint32);
                             let face_offset = face as u64 * MAX_DRAWS_PER_FACE as u64 * 20;
@@ -799,3 +1238,143 @@ impl RenderPass for ShadowPass {
         Ok(())
     }
 }
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+/// Resolution tier of the caster that owns atlas `face`.
+fn face_resolution_shift(shifts: &[u32], face: usize) -> u32 {
+    shifts.get(face / 6).copied().unwrap_or(0)
+}
+
+/// Limit a face's draws to its caster's atlas region plus a filter margin.
+/// The light's matrices already map its frustum into the region, so the
+/// margin picks up the casters just outside it.
+fn set_face_region(pass: &mut wgpu::RenderPass, atlas_size: u32, shift: u32) {
+    if shift == 0 {
+        return;
+    }
+    let size = (libhelio::shadow_region_size(atlas_size, shift) + libhelio::SHADOW_REGION_MARGIN)
+        .min(atlas_size);
+    pass.set_scissor_rect(0, 0, size, size);
+}
+
+/// Alpha-test shader source, resized to this platform's bindless table.
+///
+/// Same rewrite as the GBuffer shader: individual bindings on wasm (baseline
+/// WebGPU has no `binding_array`), otherwise the declared length is resized to
+/// [`MAX_TEXTURES`] so it matches the BGL.
+fn shadow_alpha_source() -> String {
+    let src = include_str!("../shaders/shadow_alpha.wgsl");
+    #[cfg(target_arch = "wasm32")]
+    {
+        libhelio::shader::apply_webgpu_material_bindings(src, MAX_TEXTURES)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        src.replace(
+            "binding_array<texture_2d<f32>, 256>",
+            &format!("binding_array<texture_2d<f32>, {MAX_TEXTURES}>"),
+        )
+        .replace(
+            "binding_array<sampler, 256>",
+            &format!("binding_array<sampler, {MAX_TEXTURES}>"),
+        )
+    }
+}
+
+/// BGL for group 1 of the alpha-tested pipeline; same bindings as GBuffer group 1.
+fn create_material_bgl(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    let storage = |binding| wgpu::BindGroupLayoutEntry {
+        binding,
+        visibility: wgpu::ShaderStages::FRAGMENT,
+        ty: wgpu::BindingType::Buffer {
+            ty: wgpu::BufferBindingType::Storage { read_only: true },
+            has_dynamic_offset: false,
+            min_binding_size: None,
+        },
+        count: None,
+    };
+    let texture = |binding, count| wgpu::BindGroupLayoutEntry {
+        binding,
+        visibility: wgpu::ShaderStages::FRAGMENT,
+        ty: wgpu::BindingType::Texture {
+            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+            view_dimension: wgpu::TextureViewDimension::D2,
+            multisampled: false,
+        },
+        count,
+    };
+    let sampler = |binding, count| wgpu::BindGroupLayoutEntry {
+        binding,
+        visibility: wgpu::ShaderStages::FRAGMENT,
+        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+        count,
+    };
+
+    let mut entries = vec![storage(0), storage(1)];
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let count = std::num::NonZeroU32::new(MAX_TEXTURES as u32);
+        entries.push(texture(2, count));
+        entries.push(sampler(3, count));
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        for index in 0..MAX_TEXTURES as u32 {
+            entries.push(texture(2 + index, None));
+        }
+        for index in 0..MAX_TEXTURES as u32 {
+            entries.push(sampler(2 + MAX_TEXTURES as u32 + index, None));
+        }
+    }
+    device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        label: Some("Shadow BGL 1"),
+        entries: &entries,
+    })
+}
+
+fn create_material_bind_group(
+    device: &wgpu::Device,
+    layout: &wgpu::BindGroupLayout,
+    materials: &wgpu::Buffer,
+    textures: &libhelio::MaterialTextureBindings,
+) -> wgpu::BindGroup {
+    let mut entries = vec![
+        wgpu::BindGroupEntry { binding: 0, resource: materials.as_entire_binding() },
+        wgpu::BindGroupEntry {
+            binding: 1,
+            resource: textures.material_textures.as_entire_binding(),
+        },
+    ];
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        entries.push(wgpu::BindGroupEntry {
+            binding: 2,
+            resource: wgpu::BindingResource::TextureViewArray(textures.texture_views),
+        });
+        entries.push(wgpu::BindGroupEntry {
+            binding: 3,
+            resource: wgpu::BindingResource::SamplerArray(textures.samplers),
+        });
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        for (index, view) in textures.texture_views.iter().enumerate() {
+            entries.push(wgpu::BindGroupEntry {
+                binding: 2 + index as u32,
+                resource: wgpu::BindingResource::TextureView(view),
+            });
+        }
+        for (index, sampler) in textures.samplers.iter().enumerate() {
+            entries.push(wgpu::BindGroupEntry {
+                binding: 2 + MAX_TEXTURES as u32 + index as u32,
+                resource: wgpu::BindingResource::Sampler(sampler),
+            });
+        }
+    }
+    device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("Shadow BG 1"),
+        layout,
+        entries: &entries,
+    })
+}
@@ -217,6 +217,10 @@ impl RenderPass for SkyLutPass {
         // Upload Nishita atmosphere and optional volumetric cloud parameters.
         // A real engine would derive these from a SkySystem component.
         let mut uniforms = ShaderSkyUniforms::earth_like();
+        if let Some([x, y, z]) = ctx.frame_resources.sky.sun_direction {
+            let len = (x * x + y * y + z * z).sqrt().max(1e-6);
+            uniforms.sun_direction = [x / len, y / len, z / len];
+        }
 
         if let Some(clouds) = ctx.frame_resources.sky.clouds {
             uniforms.clouds_enabled = 1;
@@ -2,7 +2,8 @@
 //
 // Bind groups:
 //   group(0)  binding(0)  Camera        (view_proj, position, time, view_proj_inv)
-//   group(1)  binding(0)  SkyUniforms
+//   group(1)  binding(0)  SkyUniforms   (graph feature uniforms, dynamic offset)
+//   group(2)  feature textures: sky_lut + shared linear sampler
 
 // ──────────────────────────────────────────────────────────────────────────────
 // Structs
@@ -53,8 +54,13 @@ struct SkyUniforms {
 
 @group(0) @binding(0) var<uniform> camera: Camera;
 @group(1) @binding(0) var<uniform> sky:        SkyUniforms;
-@group(1) @binding(1) var          sky_lut:     texture_2d<f32>;
-@group(1) @binding(2) var          sky_sampler: sampler;
+
+// Graph feature textures (helio_core::graph::FeatureTextures). SkyPass::new
+// rewrites SKY_LUT_SLOT to the slot the graph assigned to "sky_lut"; the
+// shared linear sampler always sits at binding 8.
+const SKY_LUT_SLOT: u32 = 0u;
+@group(2) @binding(SKY_LUT_SLOT) var sky_lut:     texture_2d<f32>;
+@group(2) @binding(8)            var sky_sampler: sampler;
 
 // ──────────────────────────────────────────────────────────────────────────────
 // Vertex shader – emit full-screen triangle covering the far plane
@@ -328,7 +334,7 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         let dark = vec3<f32>(0.02, 0.01, 0.005);
         let descent = pow(t, 1.8); // smooth non-linear falloff
         let sunset_col = mix(sky_col, dark, descent);
-        return vec4<f32>(aces_approx(sunset_col * sky.exposure), 1.0);
+        return vec4<f32>(aces_approx(sunset_col * sky.exposure) * camera.jitter_frame.w, 1.0);
     }
 
     // Sun disc — rendered per-pixel so it stays sharp at any resolution
@@ -342,5 +348,6 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     sky_col = trace_clouds(camera.position_near.xyz, ray_dir, sky_col);
 
     let final_col = aces_approx(sky_col * sky.exposure);
-    return vec4<f32>(final_col, 1.0);
+    // Pre-exposed like every other writer of the HDR target.
+    return vec4<f32>(final_col * camera.jitter_frame.w, 1.0);
 }
@@ -4,8 +4,8 @@
 //! O(1) CPU: single fullscreen draw.
 
 use bytemuck::{Pod, Zeroable};
-use helio_core::graph::{ResourceBuilder, ResourceSize};
-use helio_core::{PassContext, PrepareContext, RenderPass, Result as HelioResult};
+use helio_core::graph::{FeatureTextureFallback, FeatureUniformSlot, ResourceBuilder, ResourceSize};
+use helio_core::{PassContext, PrepareContext, RenderGraph, RenderPass, Result as HelioResult};
 
 /// Sky uniforms matching the WGSL shader layout (112 bytes, 16-byte aligned).
 /// Must match the layout used in sky.wgsl and sky_lut.wgsl.
@@ -75,13 +75,8 @@ pub struct SkyPass {
     pipeline: wgpu::RenderPipeline,
     #[allow(dead_code)]
     bgl_0: wgpu::BindGroupLayout,
-    #[allow(dead_code)]
-    bgl_1: wgpu::BindGroupLayout,
     bind_group_0: wgpu::BindGroup,
-    bind_group_1: Option<wgpu::BindGroup>,
-    bind_group_1_key: Option<usize>,
-    sky_uniform_buf: wgpu::Buffer,
-    sky_lut_sampler: wgpu::Sampler,
+    uniform_slot: FeatureUniformSlot,
     #[allow(dead_code)]
     width: u32,
     #[allow(dead_code)]
@@ -94,33 +89,27 @@ impl SkyPass {
     ///
     /// - `camera_buf`: buffer whose first bytes match the sky.wgsl Camera struct
     /// - `target_format`: format of the HDR render target
+    /// - `graph`: the graph the pass will be added to; the sky uniforms live
+    ///   in its feature uniforms and the LUT is sampled from its feature textures
+    ///
+    /// Fails only if every feature texture slot is already taken.
     pub fn new(
         device: &wgpu::Device,
         camera_buf: &wgpu::Buffer,
         target_format: wgpu::TextureFormat,
-    ) -> Self {
+        graph: &mut RenderGraph,
+    ) -> HelioResult<Self> {
+        let uniform_slot = graph.feature_uniforms_mut().allocate::<ShaderSkyUniforms>("Sky")?;
+        let sky_lut_slot = graph
+            .feature_textures_mut()
+            .register("sky_lut", FeatureTextureFallback::Black)?;
+        let source = include_str!("../shaders/sky.wgsl").replace(
+            "const SKY_LUT_SLOT: u32 = 0u;",
+            &format!("const SKY_LUT_SLOT: u32 = {sky_lut_slot}u;"),
+        );
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Sky Shader"),
-            source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/sky.wgsl").into()),
-        });
-
-        let sky_uniform_buf = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("Sky Uniforms"),
-            size: std::mem::size_of::<ShaderSkyUniforms>() as u64,
-            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
-            mapped_at_creation: false,
-        });
-
-        // Linear-clamp sampler for the sky LUT
-        let sky_lut_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
-            label: Some("Sky LUT Sampler"),
-            address_mode_u: wgpu::AddressMode::ClampToEdge,
-            address_mode_v: wgpu::AddressMode::ClampToEdge,
-            address_mode_w: wgpu::AddressMode::ClampToEdge,
-            mag_filter: wgpu::FilterMode::Linear,
-            min_filter: wgpu::FilterMode::Linear,
-            mipmap_filter: wgpu::MipmapFilterMode::Nearest,
-            ..Default::default()
+            source: wgpu::ShaderSource::Wgsl(source.into()),
         });
 
         // Group 0: camera uniform
@@ -138,42 +127,6 @@ impl SkyPass {
             }],
         });
 
-        // Group 1: sky uniforms + LUT texture + LUT sampler
-        // sky.wgsl: @group(1) @binding(0) sky uniforms
-        //           @group(1) @binding(1) sky_lut texture_2d<f32>
-        //           @group(1) @binding(2) sky_sampler sampler
-        let bgl_1 = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
-            label: Some("Sky BGL1"),
-            entries: &[
-                wgpu::BindGroupLayoutEntry {
-                    binding: 0,
-                    visibility: wgpu::ShaderStages::FRAGMENT,
-                    ty: wgpu::BindingType::Buffer {
-                        ty: wgpu::BufferBindingType::Uniform,
-                        has_dynamic_offset: false,
-                        min_binding_size: None,
-                    },
-                    count: None,
-                },
-                wgpu::BindGroupLayoutEntry {
-                    binding: 1,
-                    visibility: wgpu::ShaderStages::FRAGMENT,
-                    ty: wgpu::BindingType::Texture {
-                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
-                        view_dimension: wgpu::TextureViewDimension::D2,
-                        multisampled: false,
-                    },
-                    count: None,
-                },
-                wgpu::BindGroupLayoutEntry {
-                    binding: 2,
-                    visibility: wgpu::ShaderStages::FRAGMENT,
-                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
-                    count: None,
-                },
-            ],
-        });
-
         let bind_group_0 = device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Sky BG0"),
             layout: &bgl_0,
@@ -183,9 +136,15 @@ impl SkyPass {
             }],
         });
 
+        // Group 1: sky uniforms, a slot in the graph's feature uniform buffer.
+        // Group 2: the graph's feature textures (sky LUT + linear sampler).
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("Sky PL"),
-            bind_group_layouts: &[Some(&bgl_0), Some(&bgl_1)],
+            bind_group_layouts: &[
+                Some(&bgl_0),
+                Some(graph.feature_uniforms().layout()),
+                Some(graph.feature_textures().layout()),
+            ],
             immediate_size: 0,
         });
 
@@ -218,19 +177,15 @@ impl SkyPass {
             cache: None,
         });
 
-        Self {
+        Ok(Self {
             pipeline,
             bgl_0,
-            bgl_1,
             bind_group_0,
-            bind_group_1: None,
-            bind_group_1_key: None,
-            sky_uniform_buf,
-            sky_lut_sampler,
+            uniform_slot,
             width: 0,
             height: 0,
             target_format,
-        }
+        })
     }
 
 }
@@ -240,7 +195,12 @@ impl RenderPass for SkyPass {
         "Sky"
     }
 
+    fn reads(&self) -> &'static [&'static str] {
+        &["sky_lut"]
+    }
+
     fn declare_resources(&self, builder: &mut ResourceBuilder) {
+        builder.read("sky_lut");
         builder.write_color_raw("pre_aa", self.target_format, ResourceSize::MatchSurface);
     }
 
@@ -254,6 +214,10 @@ impl RenderPass for SkyPass {
         }
 
         let mut uniforms = ShaderSkyUniforms::earth_like();
+        if let Some([x, y, z]) = ctx.frame_resources.sky.sun_direction {
+            let len = (x * x + y * y + z * z).sqrt().max(1e-6);
+            uniforms.sun_direction = [x / len, y / len, z / len];
+        }
 
         if let Some(clouds) = ctx.frame_resources.sky.clouds {
             uniforms.clouds_enabled = 1;
@@ -268,7 +232,7 @@ impl RenderPass for SkyPass {
         }
 
         uniforms.time_sky = (ctx.frame_num as f32) * 0.03;
-        ctx.write_buffer(&self.sky_uniform_buf, 0, bytemuck::bytes_of(&uniforms));
+        ctx.feature_uniforms.write(ctx.queue, self.uniform_slot, &uniforms);
         Ok(())
     }
 
@@ -285,7 +249,7 @@ impl RenderPass for SkyPass {
                 resolve_target: None,
                 depth_slice: None,
                 ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                    load: resources.scene_color_load_op(wgpu::Color::BLACK),
                     store: wgpu::StoreOp::Store,
                 },
             }),
@@ -301,39 +265,13 @@ impl RenderPass for SkyPass {
     }
 
     fn execute(&mut self, ctx: &mut PassContext) -> HelioResult<()> {
-        // Lazy init: build sky LUT bind group from graph-owned sky_lut texture.
-        if let Some(sky_lut_view) = ctx.resources.sky_lut.read("Sky") {
-            let key = sky_lut_view as *const _ as usize;
-            if self.bind_group_1_key != Some(key) {
-                self.bind_group_1 = Some(ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
-                    label: Some("Sky BG1"),
-                    layout: &self.bgl_1,
-                    entries: &[
-                        wgpu::BindGroupEntry {
-                            binding: 0,
-                            resource: self.sky_uniform_buf.as_entire_binding(),
-                        },
-                        wgpu::BindGroupEntry {
-                            binding: 1,
-                            resource: wgpu::BindingResource::TextureView(sky_lut_view),
-                        },
-                        wgpu::BindGroupEntry {
-                            binding: 2,
-                            resource: wgpu::BindingResource::Sampler(&self.sky_lut_sampler),
-                        },
-                    ],
-                }));
-                self.bind_group_1_key = Some(key);
-            }
-        }
-
         let rp = unsafe { &mut *ctx.active_render_pass_ptr().unwrap() };
         if ctx.resources.sky.has_sky {
             rp.set_pipeline(&self.pipeline);
             rp.set_bind_group(0, &self.bind_group_0, &[]);
-            if let Some(ref bg) = self.bind_group_1 {
-                rp.set_bind_group(1, bg, &[]);
-            }
+            rp.set_bind_group(1, ctx.feature_uniforms.bind_group(), &[self.uniform_slot.offset()]);
+            // The graph rebinds the LUT whenever it reallocates textures.
+            rp.set_bind_group(2, ctx.feature_textures.bind_group(), &[]);
             rp.draw(0..3, 0..1);
         }
         Ok(())
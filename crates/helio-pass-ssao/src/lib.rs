@@ -10,18 +10,8 @@ use helio_core::{PassContext, PrepareContext, RenderPass, Result as HelioResult}
 
 const KERNEL_SIZE: usize = 64;
 const NOISE_DIM: u32 = 4;
-
-/// Camera uniform matching ssao.wgsl CameraUniform (272 bytes, 4 × mat4 + vec3 + pad).
-#[repr(C)]
-#[derive(Clone, Copy, Pod, Zeroable)]
-struct SsaoCameraUniform {
-    view: [[f32; 4]; 4],
-    proj: [[f32; 4]; 4],
-    view_proj: [[f32; 4]; 4],
-    inv_view_proj: [[f32; 4]; 4],
-    position: [f32; 3],
-    _pad0: f32,
-}
+const DEFAULT_RADIUS: f32 = 0.5;
+const DEFAULT_BIAS: f32 = 0.025;
 
 /// Globals matching ssao.wgsl Globals (80 bytes).
 #[repr(C)]
@@ -68,6 +58,14 @@ pub struct SsaoPass {
     /// When set, replaces the runtime SSAO computation with a pre-baked AO texture.
     /// The pass skips GPU execution and publishes this view into `frame.ssao` instead.
     baked_ao_override: Option<std::sync::Arc<wgpu::TextureView>>,
+    /// View-space sampling radius, in world units.
+    radius: f32,
+    /// Depth offset that keeps flat surfaces from occluding themselves.
+    bias: f32,
+    /// Kernel samples evaluated per pixel (1..=KERNEL_SIZE).
+    sample_count: u32,
+    /// Kernel must be regenerated for `sample_count` before the next frame.
+    kernel_dirty: bool,
 }
 
 impl SsaoPass {
@@ -90,7 +88,7 @@ impl SsaoPass {
 
         let ssao_camera_buf = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("SSAO Camera"),
-            size: std::mem::size_of::<SsaoCameraUniform>() as u64,
+            size: std::mem::size_of::<libhelio::GpuCameraUniforms>() as u64,
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
             mapped_at_creation: false,
         });
@@ -110,7 +108,7 @@ impl SsaoPass {
         });
 
         // 64-sample hemisphere kernel — fixed size, O(1)
-        let kernel = generate_kernel();
+        let kernel = generate_kernel(KERNEL_SIZE as u32);
         let sample_kernel_buf = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("SSAO Kernel"),
             size: (KERNEL_SIZE * std::mem::size_of::<[f32; 4]>()) as u64,
@@ -382,6 +380,10 @@ impl SsaoPass {
             noise_texture,
             noise_sampler,
             baked_ao_override: None,
+            radius: DEFAULT_RADIUS,
+            bias: DEFAULT_BIAS,
+            sample_count: KERNEL_SIZE as u32,
+            kernel_dirty: false,
         }
     }
 }
@@ -391,6 +393,10 @@ impl RenderPass for SsaoPass {
         "SSAO"
     }
 
+    fn apply_quality(&mut self, preset: helio_core::QualityPreset) {
+        self.set_sample_count(preset.ssao_samples());
+    }
+
     fn reads(&self) -> &'static [&'static str] {
         &["pre_aa", "depth"]
     }
@@ -444,16 +450,21 @@ impl RenderPass for SsaoPass {
     }
 
     fn prepare(&mut self, ctx: &PrepareContext) -> HelioResult<()> {
-        // TODO: Derive view, proj, inv_view_proj from scene camera for accurate SSAO.
-        // Currently zeroed — GPU will return 1.0 (no occlusion) for sky pixels.
-        let camera = SsaoCameraUniform::zeroed();
-        ctx.write_buffer(&self.ssao_camera_buf, 0, bytemuck::bytes_of(&camera));
+        // The shader reconstructs view-space positions from depth with the
+        // frame camera's inverse view-projection and view matrices.
+        ctx.write_buffer(&self.ssao_camera_buf, 0, bytemuck::bytes_of(ctx.camera()));
+
+        if self.kernel_dirty {
+            let kernel = generate_kernel(self.sample_count);
+            ctx.write_buffer(&self.sample_kernel_buf, 0, bytemuck::cast_slice(&kernel));
+            self.kernel_dirty = false;
+        }
 
         let ssao = SsaoUniform {
-            radius: 0.5,
-            bias: 0.025,
+            radius: self.radius,
+            bias: self.bias,
             power: 2.0,
-            samples: KERNEL_SIZE as u32,
+            samples: self.sample_count,
             noise_scale: [
                 ctx.width as f32 / NOISE_DIM as f32,
                 ctx.height as f32 / NOISE_DIM as f32,
@@ -492,6 +503,42 @@ impl SsaoPass {
     pub fn set_baked_ao(&mut self, view: Option<std::sync::Arc<wgpu::TextureView>>) {
         self.baked_ao_override = view;
     }
+
+    /// Set the number of hemisphere samples per pixel (clamped to 1..=64).
+    ///
+    /// The kernel is regenerated so its radial distribution spans the full
+    /// radius at any count, rather than truncating the 64-sample kernel.
+    pub fn set_sample_count(&mut self, count: u32) {
+        let count = count.clamp(1, KERNEL_SIZE as u32);
+        if count != self.sample_count {
+            self.sample_count = count;
+            self.kernel_dirty = true;
+        }
+    }
+
+    pub fn sample_count(&self) -> u32 {
+        self.sample_count
+    }
+
+    /// Set the view-space radius (world units) the hemisphere kernel spans.
+    /// Larger radii darken broader creases but miss fine contact detail.
+    pub fn set_radius(&mut self, radius: f32) {
+        self.radius = radius.max(0.0);
+    }
+
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    /// Set the depth bias a sample must clear to count as occluding. Raise it
+    /// if flat surfaces show self-occlusion acne.
+    pub fn set_bias(&mut self, bias: f32) {
+        self.bias = bias.max(0.0);
+    }
+
+    pub fn bias(&self) -> f32 {
+        self.bias
+    }
 }
 
 // ── Private helpers ────────────────────────────────────────────────────────────
@@ -509,23 +556,27 @@ fn gbuf_float_entry(binding: u32) -> wgpu::BindGroupLayoutEntry {
     }
 }
 
-/// Deterministic 64-sample hemisphere kernel in tangent space (z ≥ 0).
-fn generate_kernel() -> [[f32; 4]; KERNEL_SIZE] {
+/// Deterministic hemisphere kernel in tangent space (z ≥ 0).
+///
+/// Only the first `count` entries are used; their scale ramps over `count`
+/// so a short kernel still reaches the full radius.
+fn generate_kernel(count: u32) -> [[f32; 4]; KERNEL_SIZE] {
     let mut result = [[0f32; 4]; KERNEL_SIZE];
     let mut state: u32 = 1_234_567;
     let mut rng = move || -> f32 {
         state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
         (state >> 16) as f32 / 65_535.0
     };
-    for i in 0..KERNEL_SIZE {
+    let count = (count as usize).clamp(1, KERNEL_SIZE);
+    for (i, sample) in result.iter_mut().take(count).enumerate() {
         let x = rng() * 2.0 - 1.0;
         let y = rng() * 2.0 - 1.0;
         let z = rng(); // [0, 1] → +z hemisphere in tangent space
         let len = (x * x + y * y + z * z).sqrt().max(1e-6);
         // Accelerating scale: more samples near origin for better near-field AO
-        let t = (i as f32) / (KERNEL_SIZE as f32);
+        let t = (i as f32) / (count as f32);
         let scale = 0.1 + 0.9 * t * t;
-        result[i] = [x / len * scale, y / len * scale, z / len * scale, 0.0];
+        *sample = [x / len * scale, y / len * scale, z / len * scale, 0.0];
     }
     result
 }
@@ -582,7 +633,7 @@ mod test_utils {
 
     #[test]
     fn generate_kernel_has_valid_hemisphere_samples() {
-        let kernel = generate_kernel();
+        let kernel = generate_kernel(KERNEL_SIZE as u32);
         assert_eq!(kernel.len(), KERNEL_SIZE);
 
         let mut has_nonzero = false;
@@ -604,10 +655,18 @@ mod test_utils {
 
     #[test]
     fn generate_kernel_is_deterministic() {
-        let a = generate_kernel();
-        let b = generate_kernel();
+        let a = generate_kernel(KERNEL_SIZE as u32);
+        let b = generate_kernel(KERNEL_SIZE as u32);
         assert_eq!(a, b, "generate_kernel() must be deterministic");
     }
+
+    #[test]
+    fn short_kernel_spans_full_radius() {
+        let kernel = generate_kernel(8);
+        let length = |s: &[f32; 4]| (s[0] * s[0] + s[1] * s[1] + s[2] * s[2]).sqrt();
+        assert!(length(&kernel[7]) > 0.7, "last used sample should reach near the full radius");
+        assert!(kernel[8..].iter().all(|s| length(s) == 0.0), "unused samples stay zero");
+    }
 }
 
 
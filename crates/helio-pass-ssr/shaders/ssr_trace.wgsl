@@ -209,6 +209,7 @@ fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
         0.0, 1.0,
     );
 
-    let reflection = textureSampleLevel(scene_color, linear_sampler, hit_uv, 0.0).rgb;
+    // scene_color is the pre-exposed HDR target; store scene-linear radiance.
+    let reflection = textureSampleLevel(scene_color, linear_sampler, hit_uv, 0.0).rgb / camera.jitter_frame.w;
     textureStore(ssr_output, px, vec4<f32>(reflection, confidence));
 }
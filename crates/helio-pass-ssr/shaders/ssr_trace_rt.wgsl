@@ -271,7 +271,8 @@ fn cs_rt(@builtin(global_invocation_id) gid: vec3<u32>) {
                 0.0, 1.0,
             );
 
-            let ssr_col = textureSampleLevel(scene_color, linear_sampler, hit_uv, 0.0).rgb;
+            // scene_color is pre-exposed; RC radiance is not.
+            let ssr_col = textureSampleLevel(scene_color, linear_sampler, hit_uv, 0.0).rgb / camera.jitter_frame.w;
 
             if confidence >= 0.5 || !rt_hit {
                 final_color = ssr_col;
@@ -281,7 +282,7 @@ fn cs_rt(@builtin(global_invocation_id) gid: vec3<u32>) {
                 let rt_clip = camera.view_proj * vec4<f32>(rt_hit_pos, 1.0);
                 let rt_uv = helio_ndc_to_uv(rt_clip.xy / rt_clip.w);
                 if all(rt_uv >= vec2<f32>(0.0)) && all(rt_uv <= vec2<f32>(1.0)) {
-                    let rt_col = textureSampleLevel(scene_color, linear_sampler, rt_uv, 0.0).rgb;
+                    let rt_col = textureSampleLevel(scene_color, linear_sampler, rt_uv, 0.0).rgb / camera.jitter_frame.w;
                     final_color = mix(ssr_col, rt_col, 1.0 - confidence);
                     final_confidence = max(confidence, 0.5);
                 } else {
@@ -295,7 +296,7 @@ fn cs_rt(@builtin(global_invocation_id) gid: vec3<u32>) {
             let rt_clip = camera.view_proj * vec4<f32>(rt_hit_pos, 1.0);
             let rt_uv = helio_ndc_to_uv(rt_clip.xy / rt_clip.w);
             if all(rt_uv >= vec2<f32>(0.0)) && all(rt_uv <= vec2<f32>(1.0)) {
-                final_color = textureSampleLevel(scene_color, linear_sampler, rt_uv, 0.0).rgb;
+                final_color = textureSampleLevel(scene_color, linear_sampler, rt_uv, 0.0).rgb / camera.jitter_frame.w;
                 final_confidence = 0.6;
             } else {
                 final_color = sample_rc_reflection(world_pos, R2, roughness);
@@ -310,7 +311,7 @@ fn cs_rt(@builtin(global_invocation_id) gid: vec3<u32>) {
         let rt_clip = camera.view_proj * vec4<f32>(rt_hit_pos, 1.0);
         let rt_uv = helio_ndc_to_uv(rt_clip.xy / rt_clip.w);
         if all(rt_uv >= vec2<f32>(0.0)) && all(rt_uv <= vec2<f32>(1.0)) {
-            final_color = textureSampleLevel(scene_color, linear_sampler, rt_uv, 0.0).rgb;
+            final_color = textureSampleLevel(scene_color, linear_sampler, rt_uv, 0.0).rgb / camera.jitter_frame.w;
             final_confidence = 0.5;
         } else {
             final_color = sample_rc_reflection(world_pos, R2, roughness);
@@ -42,7 +42,8 @@ struct TaaUniform {
     upscale_factor: f32,
     reset: u32,
     time_delta: f32,
-    _pad: f32,
+    // Current pre-exposure over the one the history was written at.
+    history_scale: f32,
 }
 @group(0) @binding(6) var<uniform> taa: TaaUniform;
 
@@ -127,7 +128,8 @@ struct ColorRange {
 // Statistics are computed in tonemapped YCoCg space so they match the space
 // used during blending. Including alpha would corrupt the stats because the
 // history texture stores a large confidence value (1/blend_rate) in alpha.
-fn sample_range(tex: texture_2d<f32>, uv: vec2<f32>, step: vec2<f32>) -> ColorRange {
+// `scale` brings the texture to the current frame's pre-exposure.
+fn sample_range(tex: texture_2d<f32>, uv: vec2<f32>, step: vec2<f32>, scale: f32) -> ColorRange {
     var min_color = vec3<f32>(C_POS_INFTY);
     var max_color = vec3<f32>(C_NEG_INFTY);
     var total_weight = 0.0;
@@ -137,7 +139,7 @@ fn sample_range(tex: texture_2d<f32>, uv: vec2<f32>, step: vec2<f32>) -> ColorRa
     for (var y = -1; y <= 1; y = y + 1) {
         for (var x = -1; x <= 1; x = x + 1) {
             let s = textureSampleLevel(tex, point_sampler, uv + vec2<f32>(f32(x), f32(y)) * step, 0.0);
-            let q = rgb_to_ycocg(tonemap(s.rgb));
+            let q = rgb_to_ycocg(tonemap(s.rgb * scale));
 
             min_color = min(min_color, q);
             max_color = max(max_color, q);
@@ -215,15 +217,17 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     }
 
     // ── History sample ──────────────────────────────────────────────────────
-    let history_rgb = sample_catmull_rom(history_frame, linear_sampler, history_uv);
+    // Rescaled to this frame's pre-exposure, which may have changed since the
+    // history was written.
+    let history_rgb = sample_catmull_rom(history_frame, linear_sampler, history_uv) * taa.history_scale;
 
     // ── Tonemap for stable accumulation ─────────────────────────────────────
     let current_color = tonemap(original_color.rgb);
     let history_color = tonemap(history_rgb);
 
     // ── Weighted 3×3 YCoCg neighbourhood analysis ──────────────────────────
-    let next_range = sample_range(current_frame, cur_uv, in_texel);
-    let prev_range = sample_range(history_frame, history_uv, out_texel);
+    let next_range = sample_range(current_frame, cur_uv, in_texel, 1.0);
+    let prev_range = sample_range(history_frame, history_uv, out_texel, taa.history_scale);
 
     // Clamp history to current frame's YCoCg [min, max]  → prevents ghosting
     let clamped = clamp_to_range(history_color, next_range);
@@ -53,7 +53,7 @@ struct TaaUniform {
     upscale_factor: f32, // output_width / internal_width (≥ 1.0)
     reset: u32,          // 1 on the very first frame so RESET path runs
     time_delta: f32,     // seconds since last frame
-    _pad: f32,
+    history_scale: f32,  // this frame's pre-exposure / history's pre-exposure
 }
 
 /// Post-TAA sharpening blit.
@@ -133,6 +133,10 @@ pub struct TaaPass {
     /// Set to true on construction; cleared after the first prepare() so the
     /// shader's RESET path runs exactly once to prime the history texture.
     first_frame: bool,
+    /// Pre-exposure the history texture was accumulated at. History is
+    /// rescaled by `current / history` so a pre-exposure change does not
+    /// flash through the accumulated frames.
+    history_pre_exposure: f32,
     /// Internal (geometry) render resolution — used to compute the upscale factor.
     internal_width: u32,
     internal_height: u32,
@@ -368,6 +372,7 @@ impl TaaPass {
             linear_sampler,
             point_sampler,
             first_frame: true,
+            history_pre_exposure: 1.0,
             internal_width,
             internal_height,
             output_width,
@@ -392,6 +397,12 @@ fn tex_entry(binding: u32, sample_type: wgpu::TextureSampleType) -> wgpu::BindGr
 impl RenderPass for TaaPass {
     fn name(&self) -> &'static str { "TAA" }
 
+    fn gpu_memory_bytes(&self) -> u64 {
+        helio_core::texture_memory_bytes(&self.history_texture)
+            + helio_core::texture_memory_bytes(&self.output_texture)
+            + self.taa_uniform_buf.size()
+    }
+
     fn requires_camera_jitter(&self) -> bool {
         true
     }
@@ -462,18 +473,25 @@ impl RenderPass for TaaPass {
     }
 
     fn prepare(&mut self, ctx: &PrepareContext) -> HelioResult<()> {
-        let jitter = r1_r2_jitter(ctx.frame_num);
+        let jitter = r1_r2_jitter(ctx.sampling_frame() as u64);
         let reset = if self.first_frame { self.first_frame = false; 1u32 } else { 0u32 };
         let upscale_factor = (self.output_width as f32 / self.internal_width as f32)
             .max(1.0)
             .min(16.0);
         let time_delta = ctx.delta_time.max(0.0);
+        let pre_exposure = ctx.camera().jitter_frame[3];
+        let history_scale = if reset != 0 || self.history_pre_exposure <= 0.0 {
+            1.0
+        } else {
+            pre_exposure / self.history_pre_exposure
+        };
+        self.history_pre_exposure = pre_exposure;
         let uniforms = TaaUniform {
             jitter,
             upscale_factor,
             reset,
             time_delta,
-            _pad: 0.0,
+            history_scale,
         };
         ctx.queue.write_buffer(&self.taa_uniform_buf, 0, bytemuck::bytes_of(&uniforms));
         Ok(())
@@ -12,7 +12,7 @@ struct TaaUniform {
     upscale_factor: f32,
     reset: u32,
     time_delta: f32,
-    _pad: f32,
+    history_scale: f32,
 }
 
 /// Replicates the `r1_r2_jitter()` function from `lib.rs`.
@@ -30,7 +30,7 @@ fn r1_r2_jitter(frame: u64) -> [f32; 2] {
 #[test]
 fn taa_uniform_size_is_24() {
     assert_eq!(mem::size_of::<TaaUniform>(), 24,
-        "jitter(8) + upscale_factor(4) + reset(4) + time_delta(4) + _pad(4) = 24");
+        "jitter(8) + upscale_factor(4) + reset(4) + time_delta(4) + history_scale(4) = 24");
 }
 
 #[test]
@@ -5,10 +5,16 @@
 //! Alpha blending is handled by the render pipeline blend state.
 //! A full implementation would add Group 1 for per-material colors/textures.
 
+// Must match libhelio::GpuCameraUniforms.
 struct Camera {
-    view_proj: mat4x4<f32>,
-    position:  vec3<f32>,
-    time:      f32,
+    view:           mat4x4<f32>,
+    proj:           mat4x4<f32>,
+    view_proj:      mat4x4<f32>,
+    inv_view_proj:  mat4x4<f32>,
+    position_near:  vec4<f32>,
+    forward_far:    vec4<f32>,
+    jitter_frame:   vec4<f32>,   // xy = jitter, z = frame, w = pre-exposure
+    prev_view_proj: mat4x4<f32>,
 }
 
 struct Globals {
@@ -83,5 +89,5 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     let normal_shade = in.world_normal * 0.5 + 0.5;
     let color = ambient + normal_shade * 0.3;
     let alpha = 0.5; // Fixed 50% alpha; full impl reads per-material alpha
-    return vec4<f32>(color, alpha);
+    return vec4<f32>(color * camera.jitter_frame.w, alpha);
 }
@@ -17,6 +17,7 @@
 use bytemuck::{Pod, Zeroable};
 use helio_core::graph::ResourceBuilder;
 use helio_core::{PassContext, PrepareContext, RenderPass, Result as HelioResult};
+use libhelio::DepthMode;
 
 #[repr(C)]
 #[derive(Clone, Copy, Pod, Zeroable)]
@@ -198,13 +199,10 @@ impl TransparentPass {
                 ..Default::default()
             },
             // Read-only depth: transparent objects test against opaque depth but don't write it.
-            depth_stencil: Some(wgpu::DepthStencilState {
-                format: wgpu::TextureFormat::Depth32Float,
-                depth_write_enabled: Some(false),
-                depth_compare: Some(wgpu::CompareFunction::Less),
-                stencil: wgpu::StencilState::default(),
-                bias: wgpu::DepthBiasState::default(),
-            }),
+            depth_stencil: Some(DepthMode::TestNoWrite.depth_stencil_state(
+                wgpu::TextureFormat::Depth32Float,
+                wgpu::CompareFunction::Less,
+            )),
             multisample: wgpu::MultisampleState::default(),
             multiview_mask: None,
             cache: None,
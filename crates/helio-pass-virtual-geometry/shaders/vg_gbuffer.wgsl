@@ -8,17 +8,7 @@ enable wgpu_binding_array;
 //
 // This shader is compiled into `helio-pass-virtual-geometry` and draws only
 // VG meshlets that survived the cull compute shader.
-
-struct Camera {
-    view:           mat4x4<f32>,
-    proj:           mat4x4<f32>,
-    view_proj:      mat4x4<f32>,
-    view_proj_inv:  mat4x4<f32>,
-    position_near:  vec4<f32>,
-    forward_far:    vec4<f32>,
-    jitter_frame:   vec4<f32>,
-    prev_view_proj: mat4x4<f32>,
-}
+//!use helio_prelude
 
 struct Globals {
     frame:             u32,
@@ -30,7 +20,7 @@ struct Globals {
     rc_world_max:      vec4<f32>,
     csm_splits:        vec4<f32>,
     debug_mode:        u32,
-    _pad0:             u32,
+    time:              f32,
     _pad1:             u32,
     _pad2:             u32,
 }
@@ -94,6 +84,7 @@ struct VgDrawMetadata {
 @group(0) @binding(1) var<uniform>       globals:       Globals;
 @group(0) @binding(2) var<storage, read> instance_data: array<GpuInstanceData>;
 @group(0) @binding(3) var<storage, read> draw_metadata: array<VgDrawMetadata>;
+@group(0) @binding(4) var<storage, read> wind_params:   array<HelioWindParams>;
 
 @group(1) @binding(0) var<storage, read> materials:          array<GpuMaterial>;
 @group(1) @binding(1) var<storage, read> material_textures:  array<MaterialTextureData>;
@@ -127,7 +118,8 @@ fn decode_snorm8x4(packed: u32) -> vec3<f32> {
 fn vs_main(v: Vertex, @builtin(instance_index) draw_slot: u32) -> VertexOutput {
     let draw      = draw_metadata[draw_slot];
     let inst      = instance_data[draw.instance_index];
-    let world_pos = inst.transform * vec4<f32>(v.position, 1.0);
+    var world_pos = inst.transform * vec4<f32>(v.position, 1.0);
+    world_pos += vec4<f32>(helio_wind_offset(wind_params[draw.instance_index], v.position, inst.transform[3].xyz, globals.time), 0.0);
 
     let normal_mat = mat3x3<f32>(
         inst.normal_mat_0.xyz,
@@ -165,6 +157,8 @@ struct GBufferOutput {
 const NO_TEXTURE: u32 = 0xffffffffu;
 const MATERIAL_WORKFLOW_METALLIC: u32 = 0u;
 const MATERIAL_WORKFLOW_SPECULAR: u32 = 1u;
+const FLAG_UNLIT: u32 = 1u << 8u;
+const SURFACE_FLAG_UNLIT: u32 = 1u << 3u;
 
 fn select_uv(slot: MaterialTextureSlot, base_uv: vec2<f32>) -> vec2<f32> {
     let scaled = base_uv * slot.offset_scale.zw;
@@ -206,6 +200,39 @@ fn resolve_specular_f0(
     );
 }
 
+// Normal map convention in `params.w` (see `NormalMapConvention`).
+const NORMAL_MAP_TANGENT: f32 = 0.0;
+const NORMAL_MAP_TANGENT_FLIP_GREEN: f32 = 1.0;
+const NORMAL_MAP_WORLD: f32 = 2.0;
+
+fn decode_normal_map(
+    material_tex: MaterialTextureData,
+    uv: vec2<f32>,
+    N_geom: vec3<f32>,
+    world_tangent: vec3<f32>,
+    bitangent_sign: f32,
+) -> vec3<f32> {
+    let convention = material_tex.params.w;
+    let texel = sample_texture(material_tex.normal, uv, vec4<f32>(0.5, 0.5, 1.0, 1.0));
+    if convention > NORMAL_MAP_WORLD - 0.5 {
+        // World space: the map already holds the final normal; the strength
+        // blends from the geometric normal.
+        let N_map = normalize(texel.rgb * 2.0 - 1.0);
+        return normalize(mix(N_geom, N_map, material_tex.params.x));
+    }
+    let T = normalize(world_tangent - dot(world_tangent, N_geom) * N_geom);
+    let B = cross(N_geom, T) * bitangent_sign;
+    // Z is rebuilt from XY so two-channel (BC5/RG) normal maps work too.
+    var norm_xy = texel.rg * 2.0 - 1.0;
+    if convention > NORMAL_MAP_TANGENT_FLIP_GREEN - 0.5 {
+        // DirectX convention stores green pointing down.
+        norm_xy.y = -norm_xy.y;
+    }
+    let norm_z = sqrt(max(1.0 - dot(norm_xy, norm_xy), 0.0));
+    let norm_ts = vec3<f32>(norm_xy * material_tex.params.x, norm_z);
+    return normalize(T * norm_ts.x + B * norm_ts.y + N_geom * norm_ts.z);
+}
+
 @fragment
 fn fs_main(input: VertexOutput) -> GBufferOutput {
     // ── Normal rendering ──────────────────────────────────────────────────────
@@ -223,11 +250,7 @@ fn fs_main(input: VertexOutput) -> GBufferOutput {
     let N_geom = normalize(input.world_normal);
     var N: vec3<f32>;
     if material_tex.normal.texture_index != NO_TEXTURE {
-        let T = normalize(input.world_tangent - dot(input.world_tangent, N_geom) * N_geom);
-        let B = cross(N_geom, T) * input.bitangent_sign;
-        var norm_ts = sample_texture(material_tex.normal, uv, vec4<f32>(0.5, 0.5, 1.0, 1.0)).rgb * 2.0 - 1.0;
-        norm_ts = vec3<f32>(norm_ts.x * material_tex.params.x, norm_ts.y * material_tex.params.x, norm_ts.z);
-        N = normalize(T * norm_ts.x + B * norm_ts.y + N_geom * norm_ts.z);
+        N = decode_normal_map(material_tex, uv, N_geom, input.world_tangent, input.bitangent_sign);
     } else {
         N = N_geom;
     }
@@ -249,7 +272,8 @@ fn fs_main(input: VertexOutput) -> GBufferOutput {
     out.emissive = vec4<f32>(emissive, specular_f0.b);
     out.vg_flag = vec2<f32>(-2.0, -2.0);
     out.sss     = vec4<f32>(0.0);
-    out.extra   = vec4<f32>(0.0);
+    let surface_flags = select(0u, SURFACE_FLAG_UNLIT, (material.flags & FLAG_UNLIT) != 0u);
+    out.extra   = vec4<f32>(0.0, 0.0, 0.0, f32(surface_flags));
     return out;
 }
 
@@ -300,7 +324,8 @@ fn vs_debug_lod(v: Vertex, @builtin(instance_index) draw_slot: u32) -> LodVertex
     let draw      = draw_metadata[draw_slot];
     let lod_level = draw.lod_level;
     let inst      = instance_data[draw.instance_index];
-    let world_pos = inst.transform * vec4<f32>(v.position, 1.0);
+    var world_pos = inst.transform * vec4<f32>(v.position, 1.0);
+    world_pos += vec4<f32>(helio_wind_offset(wind_params[draw.instance_index], v.position, inst.transform[3].xyz, globals.time), 0.0);
     var out: LodVertexOutput;
     out.clip_position  = camera.view_proj * world_pos;
     out.world_position = world_pos.xyz;
@@ -249,7 +249,8 @@ pub(crate) struct VgGlobals {
     pub rc_world_max: [f32; 4],
     pub csm_splits: [f32; 4],
     pub debug_mode: u32,
-    _pad0: u32,
+    /// Animation time in seconds; drives wind sway like the GBuffer's.
+    pub time: f32,
     _pad1: u32,
     _pad2: u32,
 }
@@ -42,6 +42,8 @@ pub struct VirtualGeometryPass {
     pub(crate) meshlet_buf: wgpu::Buffer,
     pub(crate) object_buf: wgpu::Buffer,
     pub(crate) instance_buf: wgpu::Buffer,
+    /// Per-object wind sway, parallel to `instance_buf`.
+    pub(crate) wind_buf: wgpu::Buffer,
     pub(crate) instance_cull_buf: wgpu::Buffer,
     pub(crate) instance_cull_scratch: Vec<InstanceCullData>,
     pub(crate) work_item_buf: wgpu::Buffer,
@@ -79,27 +81,25 @@ impl VirtualGeometryPass {
             label: Some("VG Cull Shader"),
             source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/vg_cull.wgsl").into()),
         });
-        let draw_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
-            label: Some("VG GBuffer Shader"),
-            source: wgpu::ShaderSource::Wgsl({
-                let s = include_str!("../shaders/vg_gbuffer.wgsl")
-                    .replace(
-                        "binding_array<texture_2d<f32>, 256>",
-                        &format!("binding_array<texture_2d<f32>, {MAX_TEXTURES}>"),
-                    )
-                    .replace(
-                        "binding_array<sampler, 256>",
-                        &format!("binding_array<sampler, {MAX_TEXTURES}>"),
-                    );
-                #[cfg(target_arch = "wasm32")]
-                let s = libhelio::shader::apply_webgpu_material_bindings(&s, MAX_TEXTURES);
-                s.into()
-            }),
+        let draw_shader = helio_core::shader::module(device, "VG GBuffer Shader", &{
+            let s = include_str!("../shaders/vg_gbuffer.wgsl")
+                .replace(
+                    "binding_array<texture_2d<f32>, 256>",
+                    &format!("binding_array<texture_2d<f32>, {MAX_TEXTURES}>"),
+                )
+                .replace(
+                    "binding_array<sampler, 256>",
+                    &format!("binding_array<sampler, {MAX_TEXTURES}>"),
+                );
+            #[cfg(target_arch = "wasm32")]
+            let s = libhelio::shader::apply_webgpu_material_bindings(&s, MAX_TEXTURES);
+            s
         });
 
         let meshlet_buf = Self::make_meshlet_buf(device, INITIAL_MESHLETS);
         let object_buf = Self::make_object_buf(device, INITIAL_OBJECTS);
         let instance_buf = Self::make_instance_buf(device, INITIAL_INSTANCES);
+        let wind_buf = Self::make_wind_buf(device, INITIAL_INSTANCES);
         let instance_cull_buf = Self::make_instance_cull_buf(device, INITIAL_INSTANCES);
         let work_item_buf = Self::make_work_item_buf(device, INITIAL_OBJECTS);
         let initial_publication_capacity =
@@ -318,6 +318,16 @@ impl VirtualGeometryPass {
                     },
                     count: None,
                 },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
             ],
         });
 
@@ -341,6 +351,10 @@ impl VirtualGeometryPass {
                     binding: 3,
                     resource: draw_metadata_buf.as_entire_binding(),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: wind_buf.as_entire_binding(),
+                },
             ],
         }));
 
@@ -517,6 +531,7 @@ impl VirtualGeometryPass {
             meshlet_buf,
             object_buf,
             instance_buf,
+            wind_buf,
             instance_cull_buf,
             instance_cull_scratch: Vec::with_capacity(INITIAL_INSTANCES as usize),
             work_item_buf,
@@ -608,6 +623,15 @@ impl VirtualGeometryPass {
         })
     }
 
+    fn make_wind_buf(device: &wgpu::Device, capacity: u64) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("VG Wind Buffer"),
+            size: capacity * std::mem::size_of::<libhelio::GpuWindParams>() as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        })
+    }
+
     fn make_object_buf(device: &wgpu::Device, capacity: u64) -> wgpu::Buffer {
         device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("VG Object Buffer"),
@@ -701,6 +725,10 @@ impl VirtualGeometryPass {
                     binding: 3,
                     resource: self.draw_metadata_buf.as_entire_binding(),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: self.wind_buf.as_entire_binding(),
+                },
             ],
         }));
     }
@@ -749,6 +777,7 @@ impl RenderPass for VirtualGeometryPass {
             let instance_capacity = self.instance_buf.size() / 144;
             if (vg.object_count as u64) > instance_capacity {
                 self.instance_buf = Self::make_instance_buf(ctx.device, vg.object_count as u64 * 2);
+                self.wind_buf = Self::make_wind_buf(ctx.device, vg.object_count as u64 * 2);
                 self.instance_cull_buf =
                     Self::make_instance_cull_buf(ctx.device, vg.object_count as u64 * 2);
                 grew = true;
@@ -776,6 +805,7 @@ impl RenderPass for VirtualGeometryPass {
             ctx.write_buffer(&self.meshlet_buf, 0, vg.meshlets);
             ctx.write_buffer(&self.object_buf, 0, vg.objects);
             ctx.write_buffer(&self.instance_buf, 0, vg.instances);
+            ctx.write_buffer(&self.wind_buf, 0, vg.wind);
             ctx.write_buffer(&self.work_item_buf, 0, vg.work_items);
 
             let instances: &[GpuInstanceData] = bytemuck::cast_slice(vg.instances);
@@ -956,7 +986,7 @@ impl RenderPass for VirtualGeometryPass {
             ],
             csm_splits: [5.0, 20.0, 60.0, 200.0],
             debug_mode: self.debug_mode,
-            _pad0: 0,
+            time: ctx.time,
             _pad1: 0,
             _pad2: 0,
         };
@@ -63,8 +63,8 @@ fn cull_shader_parses_and_validates() {
 
 #[test]
 fn gbuffer_shader_parses_and_validates() {
-    let source = include_str!("../shaders/vg_gbuffer.wgsl");
-    let module = naga::front::wgsl::parse_str(source).expect("VG draw shader must parse");
+    let source = helio_core::shader::resolve(include_str!("../shaders/vg_gbuffer.wgsl"));
+    let module = naga::front::wgsl::parse_str(&source).expect("VG draw shader must parse");
     let mut validator = naga::valid::Validator::new(
         naga::valid::ValidationFlags::all(),
         naga::valid::Capabilities::all(),
@@ -91,7 +91,7 @@ fn browser_shader_variants_parse_and_validate() {
     const MAX_TEXTURES: usize = 16;
 
     let fix_material_shader = |source: &str| {
-        let source = source
+        let source = helio_core::shader::resolve(source)
             .replace(
                 "binding_array<texture_2d<f32>, 256>",
                 &format!("binding_array<texture_2d<f32>, {MAX_TEXTURES}>"),
@@ -83,6 +83,10 @@ pub struct VolumetricFogPass {
     fog_uniform_buf: wgpu::Buffer,
     globals_buf: wgpu::Buffer,
     shadow_sampler: wgpu::Sampler,
+    /// Shadow atlas depth convention; `shadow_sampler` is rebuilt in `prepare`
+    /// when it changes.
+    depth_convention: libhelio::DepthConvention,
+    depth_convention_changed: bool,
     linear_sampler: wgpu::Sampler,
 
     /// Ping-ponged scattering grids: one is read as history while the other is
@@ -106,6 +110,19 @@ pub struct VolumetricFogPass {
     temporal_blend: f32,
 }
 
+fn create_shadow_sampler(
+    device: &wgpu::Device,
+    convention: libhelio::DepthConvention,
+) -> wgpu::Sampler {
+    device.create_sampler(&wgpu::SamplerDescriptor {
+        label: Some("Volumetric Fog Shadow Sampler"),
+        mag_filter: wgpu::FilterMode::Linear,
+        min_filter: wgpu::FilterMode::Linear,
+        compare: Some(convention.compare(wgpu::CompareFunction::LessEqual)),
+        ..Default::default()
+    })
+}
+
 fn make_grid(device: &wgpu::Device, label: &str) -> (wgpu::Texture, wgpu::TextureView) {
     let tex = device.create_texture(&wgpu::TextureDescriptor {
         label: Some(label),
@@ -275,13 +292,8 @@ impl VolumetricFogPass {
             mapped_at_creation: false,
         });
 
-        let shadow_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
-            label: Some("Volumetric Fog Shadow Sampler"),
-            mag_filter: wgpu::FilterMode::Linear,
-            min_filter: wgpu::FilterMode::Linear,
-            compare: Some(wgpu::CompareFunction::LessEqual),
-            ..Default::default()
-        });
+        let shadow_sampler =
+            create_shadow_sampler(device, libhelio::DepthConvention::Standard);
         // Clamped + trilinear: history reprojection and the composite both rely on
         // filtering across all three axes.
         let linear_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
@@ -308,6 +320,8 @@ impl VolumetricFogPass {
             fog_uniform_buf,
             globals_buf,
             shadow_sampler,
+            depth_convention: libhelio::DepthConvention::Standard,
+            depth_convention_changed: false,
             linear_sampler,
             scatter_view: [v0, v1],
             _scatter: [s0, s1],
@@ -344,6 +358,13 @@ impl RenderPass for VolumetricFogPass {
         "VolumetricFogPass"
     }
 
+    fn set_depth_convention(&mut self, convention: libhelio::DepthConvention) {
+        if convention != self.depth_convention {
+            self.depth_convention = convention;
+            self.depth_convention_changed = true;
+        }
+    }
+
     fn writes(&self) -> &'static [&'static str] {
         &["fog_accum"]
     }
@@ -371,7 +392,12 @@ impl RenderPass for VolumetricFogPass {
     }
 
     fn prepare(&mut self, ctx: &PrepareContext) -> HelioResult<()> {
-        self.frame = ctx.frame_num as u32;
+        self.frame = ctx.sampling_frame();
+        if std::mem::take(&mut self.depth_convention_changed) {
+            self.shadow_sampler = create_shadow_sampler(ctx.device, self.depth_convention);
+            self.inject_bg = [None, None];
+            self.inject_bg_key = None;
+        }
 
         let globals = FogGlobals {
             csm_splits: libhelio::CSM_SPLITS,
@@ -41,9 +41,9 @@ struct GpuLight {
     god_rays_weight:   f32,
     god_rays_decay:    f32,
     god_rays_exposure: f32,
-    _pad2_0:           u32,
-    _pad2_1:           u32,
-    _pad2_2:           u32,
+    shadow_bias:        f32,
+    shadow_normal_bias: f32,
+    shadow_resolution_shift: u32,
 }
 
 struct MeshletParams {
@@ -136,5 +136,5 @@ fn fs_main(in: VertexOutput, @builtin(front_facing) front: bool) -> @location(0)
     }
     let lit = col * (ambient + direct) + emissive * 0.1;
 
-    return vec4(lit, 1.0);
+    return vec4(lit * camera.jitter_frame.w, 1.0);
 }
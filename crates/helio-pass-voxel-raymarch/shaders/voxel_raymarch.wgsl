@@ -51,9 +51,9 @@ struct GpuLight {
     god_rays_weight:   f32,
     god_rays_decay:    f32,
     god_rays_exposure: f32,
-    _pad2_0:           u32,
-    _pad2_1:           u32,
-    _pad2_2:           u32,
+    shadow_bias:        f32,
+    shadow_normal_bias: f32,
+    shadow_resolution_shift: u32,
 }
 
 struct HitResult {
@@ -313,7 +313,7 @@ fn main(
         }
         let lit = col * (ambient + direct);
 
-        textureStore(out_color, vec2<i32>(i32(px), i32(py)), vec4<f32>(lit, 1.0));
+        textureStore(out_color, vec2<i32>(i32(px), i32(py)), vec4<f32>(lit * camera.jitter_frame.w, 1.0));
         textureStore(out_normal, vec2<i32>(i32(px), i32(py)), vec4<f32>(n * 0.5 + 0.5, best_t));
     } else {
         textureStore(out_color, vec2<i32>(i32(px), i32(py)), vec4<f32>(0.0, 0.0, 0.0, 0.0));
@@ -152,7 +152,8 @@ fn sky_color(ray: vec3f, light_dir: vec3f) -> vec3f {
     let zenith  = vec3f(0.10, 0.30, 0.80);
     let sky     = mix(horizon, zenith, up * up);
     let spec    = pow(max(0.0, dot(normalize(light_dir), ray)), 5000.0);
-    return sky + vec3f(spec) * vec3f(10.0, 8.0, 6.0);
+    // Scaled into the pre-exposed units of scene_color it is blended with.
+    return (sky + vec3f(spec) * vec3f(10.0, 8.0, 6.0)) * camera.jitter_frame.w;
 }
 
 @fragment
@@ -22,7 +22,7 @@ struct Camera {
     inv_view_proj:  mat4x4f,
     position_near:  vec4f,  // xyz = camera world position
     forward_far:    vec4f,
-    jitter_frame:   vec4f,  // w = frame index (used as time)
+    jitter_frame:   vec4f,  // z = frame index (used as time), w = pre-exposure
     prev_view_proj: mat4x4f,
 }
 
@@ -131,7 +131,7 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4f {
     let cam_depth = surface_h - cam_pos.y;
 
     // ---- Animated lens distortion ----------------------------------------
-    // jitter_frame: xy = TAA jitter, z = frame counter, w = 0 (unused).
+    // jitter_frame: xy = TAA jitter, z = frame counter, w = pre-exposure.
     // wave_params: x = amplitude, y = frequency, z = speed, w = steepness.
     let wave_amplitude = vol.wave_params.x;
     let wave_speed     = vol.wave_params.z;
@@ -312,6 +312,31 @@ impl RenderPass for WaterSimPass {
         "WaterSim"
     }
 
+    fn gpu_memory_bytes(&self) -> u64 {
+        let textures: u64 = [
+            &self._tex_a,
+            &self._tex_b,
+            &self._depth_copy_tex,
+            &self._tint_scratch_tex,
+        ]
+        .into_iter()
+        .map(helio_core::texture_memory_bytes)
+        .sum();
+        let buffers: u64 = [
+            &self.drop_buf,
+            &self.update_buf,
+            &self.normal_buf,
+            &self.surface_vbuf,
+            &self.surface_ibuf,
+            &self.volume_vbuf,
+            &self.volume_ibuf,
+        ]
+        .into_iter()
+        .map(wgpu::Buffer::size)
+        .sum();
+        textures + buffers
+    }
+
     fn render_pass_descriptor<'a>(
         &'a self,
         _target: &'a wgpu::TextureView,
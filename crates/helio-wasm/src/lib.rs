@@ -105,6 +105,15 @@ pub trait HelioWasmApp: Sized + 'static {
         0.75
     }
 
+    /// Initial swapchain present mode. Defaults to [`helio::PresentMode::Vsync`].
+    ///
+    /// Unsupported modes fall back as documented on [`helio::PresentMode`];
+    /// browsers only offer `Fifo`, so every mode resolves to vsync on the web.
+    /// Change it at runtime with [`Renderer::set_present_mode`](helio::Renderer::set_present_mode).
+    fn present_mode() -> helio::PresentMode {
+        helio::PresentMode::Vsync
+    }
+
     /// Optionally build a custom render graph for this demo.
     ///
     /// Return `None` (the default) to use helio's standard deferred graph.
@@ -141,8 +150,9 @@ pub trait HelioWasmApp: Sized + 'static {
 
     /// Called every frame. Return the camera to render from.
     ///
-    /// `dt` — delta time in seconds since the last frame.  
-    /// `elapsed` — total seconds since the demo started.  
+    /// `dt` — delta time in seconds of the last frame, clamped by the
+    /// renderer's [`FrameClock`](helio::FrameClock).  
+    /// `elapsed` — sum of those deltas since the demo started.  
     /// `input` — keyboard / mouse snapshot for this frame.
     fn update(
         &mut self,
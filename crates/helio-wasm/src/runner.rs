@@ -15,26 +15,10 @@ use winit::{
     window::{Window, WindowId},
 };
 
-use helio::{DebugDrawState, Renderer, RendererConfig, Scene};
+use helio::{DebugDrawState, PresentMode, Renderer, RendererConfig, Scene};
 
 use crate::{HelioWasmApp, InputState};
 
-// ── Platform-specific time helper ─────────────────────────────────────────────
-
-#[cfg(not(target_arch = "wasm32"))]
-fn now_secs() -> f64 {
-    use std::time::{SystemTime, UNIX_EPOCH};
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs_f64()
-}
-
-#[cfg(target_arch = "wasm32")]
-fn now_secs() -> f64 {
-    js_sys::Date::now() / 1000.0
-}
-
 // ── Cursor helpers ────────────────────────────────────────────────────────────
 
 fn grab_cursor(window: &Window) {
@@ -86,7 +70,11 @@ struct RunnerState<T: HelioWasmApp> {
     surface: wgpu::Surface<'static>,
     device: Arc<wgpu::Device>,
     queue: Arc<wgpu::Queue>,
-    surface_format: wgpu::TextureFormat,
+    surface_config: wgpu::SurfaceConfiguration,
+    /// Present modes the surface supports, for resolving `PresentMode` requests.
+    supported_present_modes: Vec<wgpu::PresentMode>,
+    /// Mode the surface is currently configured with (as requested).
+    present_mode: PresentMode,
     renderer: Renderer,
     demo: T,
 
@@ -97,10 +85,6 @@ struct RunnerState<T: HelioWasmApp> {
     cursor_pos: (f32, f32),
     mouse_left_just_pressed: bool,
     mouse_left_just_released: bool,
-
-    // Timing
-    start_time: f64,
-    last_time: f64,
 }
 
 // ── WasmRunner ────────────────────────────────────────────────────────────────
@@ -220,18 +204,9 @@ impl<T: HelioWasmApp> ApplicationHandler for WasmRunner<T> {
 
             WindowEvent::Resized(new_size) => {
                 if new_size.width > 0 && new_size.height > 0 {
-                    let config = wgpu::SurfaceConfiguration {
-                        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
-                        format: state.surface_format,
-                        width: new_size.width,
-                        height: new_size.height,
-                        color_space: wgpu::SurfaceColorSpace::Auto,
-                        present_mode: wgpu::PresentMode::Fifo,
-                        desired_maximum_frame_latency: 2,
-                        alpha_mode: wgpu::CompositeAlphaMode::Auto,
-                        view_formats: vec![],
-                    };
-                    state.surface.configure(&state.device, &config);
+                    state.surface_config.width = new_size.width;
+                    state.surface_config.height = new_size.height;
+                    reconfigure_surface(state);
                     state
                         .renderer
                         .set_render_size(new_size.width, new_size.height);
@@ -367,20 +342,19 @@ async fn init_wgpu<T: HelioWasmApp>(
     let size = window.inner_size();
     let width = size.width.max(1);
     let height = size.height.max(1);
-    surface.configure(
-        &device,
-        &wgpu::SurfaceConfiguration {
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
-            format: surface_format,
-            width,
-            height,
-            color_space: wgpu::SurfaceColorSpace::Auto,
-            present_mode: wgpu::PresentMode::Fifo,
-            desired_maximum_frame_latency: 2,
-            alpha_mode: caps.alpha_modes[0],
-            view_formats: vec![],
-        },
-    );
+    let present_mode = T::present_mode();
+    let surface_config = wgpu::SurfaceConfiguration {
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        format: surface_format,
+        width,
+        height,
+        color_space: wgpu::SurfaceColorSpace::Auto,
+        present_mode: present_mode.resolve(&caps.present_modes),
+        desired_maximum_frame_latency: 2,
+        alpha_mode: caps.alpha_modes[0],
+        view_formats: vec![],
+    };
+    surface.configure(&device, &surface_config);
 
     let scene = Scene::new(device.clone(), queue.clone());
 
@@ -434,7 +408,9 @@ async fn init_wgpu<T: HelioWasmApp>(
         width,
         height,
         render_scale,
-        RendererConfig::new(width, height, surface_format).with_render_scale(render_scale),
+        RendererConfig::new(width, height, surface_format)
+            .with_render_scale(render_scale)
+            .with_present_mode(present_mode),
         scene,
         graph,
         debug_state,
@@ -450,13 +426,14 @@ async fn init_wgpu<T: HelioWasmApp>(
         height,
     );
 
-    let now = now_secs();
     *state_cell.borrow_mut() = Some(RunnerState {
         window,
         surface,
         device,
         queue,
-        surface_format,
+        surface_config,
+        supported_present_modes: caps.present_modes,
+        present_mode,
         renderer,
         demo,
         keys: HashSet::new(),
@@ -465,19 +442,32 @@ async fn init_wgpu<T: HelioWasmApp>(
         cursor_pos: (0.0, 0.0),
         mouse_left_just_pressed: false,
         mouse_left_just_released: false,
-        start_time: now,
-        last_time: now,
     });
     hide_loading_overlay();
 }
 
+// ── Surface configuration ─────────────────────────────────────────────────────
+
+/// Re-applies `state.surface_config`, resolving the renderer's requested
+/// present mode against what the surface supports.
+fn reconfigure_surface<T: HelioWasmApp>(state: &mut RunnerState<T>) {
+    let requested = state.renderer.present_mode();
+    let resolved = requested.resolve(&state.supported_present_modes);
+    if requested != state.present_mode {
+        log::info!("helio-wasm: present mode {requested:?} resolved to {resolved:?}");
+    }
+    state.present_mode = requested;
+    state.surface_config.present_mode = resolved;
+    state.surface.configure(&state.device, &state.surface_config);
+}
+
 // ── Per-frame render helper ───────────────────────────────────────────────────
 
 fn render_frame<T: HelioWasmApp>(state: &mut RunnerState<T>) {
-    let now = now_secs();
-    let dt = (now - state.last_time).min(0.1) as f32;
-    let elapsed = (now - state.start_time) as f32;
-    state.last_time = now;
+    // The renderer's frame clock is the only clock: the demo advances by the
+    // delta it measured and clamped for the previous frame.
+    let dt = state.renderer.delta_time();
+    let elapsed = state.renderer.frame_clock().elapsed() as f32;
 
     let delta = state.mouse_delta;
     state.mouse_delta = (0.0, 0.0);
@@ -500,6 +490,10 @@ fn render_frame<T: HelioWasmApp>(state: &mut RunnerState<T>) {
 
     let camera = state.demo.update(&mut state.renderer, dt, elapsed, &input);
 
+    if state.renderer.present_mode() != state.present_mode {
+        reconfigure_surface(state);
+    }
+
     let output = match state.surface.get_current_texture() {
         wgpu::CurrentSurfaceTexture::Success(texture)
         | wgpu::CurrentSurfaceTexture::Suboptimal(texture) => texture,
@@ -42,3 +42,54 @@ fn fs_main_depth(input: VertexOut) -> @location(0) vec4<f32> {
     }
     return input.color;
 }
+
+// ── Point sprites ────────────────────────────────────────────────────────────
+// One instanced quad per point, expanded in clip space so `size` stays a
+// constant pixel diameter regardless of distance.
+
+struct PointParams {
+    viewport: vec2<f32>,
+    _pad: vec2<f32>,
+}
+
+@group(1) @binding(0) var<uniform> point_params: PointParams;
+
+struct PointOut {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+    @location(1) local: vec2<f32>,
+}
+
+@vertex
+fn vs_point(
+    @builtin(vertex_index) vi: u32,
+    @location(0) center_size: vec4<f32>,
+    @location(1) color: vec4<f32>,
+) -> PointOut {
+    var corners = array(
+        vec2<f32>(-1.0, -1.0),
+        vec2<f32>( 1.0, -1.0),
+        vec2<f32>(-1.0,  1.0),
+        vec2<f32>(-1.0,  1.0),
+        vec2<f32>( 1.0, -1.0),
+        vec2<f32>( 1.0,  1.0),
+    );
+    let corner = corners[vi];
+    var clip = debug_camera.view_proj * vec4<f32>(center_size.xyz, 1.0);
+    // A `size`-pixel diameter spans `size / viewport` NDC units per half-axis.
+    clip = vec4<f32>(clip.xy + corner * center_size.w / point_params.viewport * clip.w, clip.zw);
+
+    var out: PointOut;
+    out.clip_position = clip;
+    out.color = color;
+    out.local = corner;
+    return out;
+}
+
+@fragment
+fn fs_point(input: PointOut) -> @location(0) vec4<f32> {
+    if dot(input.local, input.local) > 1.0 {
+        discard;
+    }
+    return input.color;
+}
@@ -0,0 +1,122 @@
+//! Top-down minimap: flat-shaded scene geometry seen through an orthographic
+//! camera looking straight down.
+//!
+//! `cs_build_indirect` expands every draw call to its full instance count, so
+//! the minimap never inherits the main camera's frustum / occlusion culling.
+
+struct MinimapUniforms {
+    view_proj: mat4x4<f32>,
+    /// xyz = direction towards the light, w unused.
+    light_dir: vec4<f32>,
+    /// x = 1 to draw only instances carrying `flag_mask`, y = flag_mask,
+    /// z = draw call count.
+    params:    vec4<u32>,
+}
+
+/// Per-instance GPU data.  Must match `GpuInstanceData` in libhelio.
+struct GpuInstanceData {
+    transform:      mat4x4<f32>,
+    normal_mat_0:   vec4<f32>,
+    normal_mat_1:   vec4<f32>,
+    normal_mat_2:   vec4<f32>,
+    bounds:         vec4<f32>,
+    mesh_id:        u32,
+    material_id:    u32,
+    flags:          u32,
+    lightmap_index: u32,
+}
+
+struct GpuMaterial {
+    base_color:         vec4<f32>,
+    emissive:           vec4<f32>,
+    roughness_metallic: vec4<f32>,
+    tex_base_color:     u32,
+    tex_normal:         u32,
+    tex_roughness:      u32,
+    tex_emissive:       u32,
+    tex_occlusion:      u32,
+    workflow:           u32,
+    flags:              u32,
+    material_class:     u32,
+    class_params:       vec4<f32>,
+}
+
+struct GpuDrawCall {
+    index_count:    u32,
+    first_index:    u32,
+    vertex_offset:  i32,
+    first_instance: u32,
+    instance_count: u32,
+}
+
+struct DrawIndexedIndirect {
+    index_count:    u32,
+    instance_count: u32,
+    first_index:    u32,
+    base_vertex:    i32,
+    first_instance: u32,
+}
+
+@group(0) @binding(0) var<uniform>       minimap:       MinimapUniforms;
+@group(0) @binding(1) var<storage, read> instance_data: array<GpuInstanceData>;
+@group(0) @binding(2) var<storage, read> materials:     array<GpuMaterial>;
+
+@group(1) @binding(0) var<storage, read>       draw_calls: array<GpuDrawCall>;
+@group(1) @binding(1) var<storage, read_write> indirect:   array<DrawIndexedIndirect>;
+
+@compute @workgroup_size(64)
+fn cs_build_indirect(@builtin(global_invocation_id) gid: vec3<u32>) {
+    let idx = gid.x;
+    if idx >= minimap.params.z { return; }
+    let dc = draw_calls[idx];
+    indirect[idx] = DrawIndexedIndirect(
+        dc.index_count,
+        dc.instance_count,
+        dc.first_index,
+        dc.vertex_offset,
+        dc.first_instance,
+    );
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) world_normal: vec3<f32>,
+    @location(1) @interpolate(flat) material_id: u32,
+}
+
+@vertex
+fn vs_main(
+    @location(0) position: vec3<f32>,
+    @location(3) normal:   u32,
+    @builtin(instance_index) slot: u32,
+) -> VertexOutput {
+    let inst = instance_data[slot];
+    var out: VertexOutput;
+    out.material_id = inst.material_id;
+
+    // Unflagged instances collapse to a single point outside the clip volume,
+    // so every triangle of theirs is degenerate and gets discarded.
+    if minimap.params.x != 0u && (inst.flags & minimap.params.y) == 0u {
+        out.clip_position = vec4<f32>(0.0, 0.0, -2.0, 1.0);
+        out.world_normal = vec3<f32>(0.0, 1.0, 0.0);
+        return out;
+    }
+
+    let normal_mat = mat3x3<f32>(
+        inst.normal_mat_0.xyz,
+        inst.normal_mat_1.xyz,
+        inst.normal_mat_2.xyz,
+    );
+    out.clip_position = minimap.view_proj * (inst.transform * vec4<f32>(position, 1.0));
+    out.world_normal = normal_mat * unpack4x8snorm(normal).xyz;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let material = materials[in.material_id];
+    let n = normalize(in.world_normal);
+    let ndotl = max(dot(n, minimap.light_dir.xyz), 0.0);
+    let color = material.base_color.rgb * (0.35 + 0.65 * ndotl) + material.emissive.rgb;
+    return vec4<f32>(color, 1.0);
+}
@@ -0,0 +1,66 @@
+//! Shaded-wireframe overlay.
+//!
+//! Redraws the scene's opaque geometry over the lit image and keeps only the
+//! fragments near a triangle edge, found from the hardware barycentrics — no
+//! edge buffer and no line topology. Depth-tested against the scene depth
+//! with a small bias, so hidden edges stay hidden.
+
+struct Camera {
+    view:           mat4x4<f32>,
+    proj:           mat4x4<f32>,
+    view_proj:      mat4x4<f32>,
+    view_proj_inv:  mat4x4<f32>,
+    position_near:  vec4<f32>,
+    forward_far:    vec4<f32>,
+    jitter_frame:   vec4<f32>,
+    prev_view_proj: mat4x4<f32>,
+}
+
+/// Per-instance GPU data.  Must match `GpuInstanceData` in libhelio.
+struct GpuInstanceData {
+    transform:     mat4x4<f32>,
+    normal_mat_0:  vec4<f32>,
+    normal_mat_1:  vec4<f32>,
+    normal_mat_2:  vec4<f32>,
+    bounds:        vec4<f32>,
+    mesh_id:       u32,
+    material_id:   u32,
+    flags:         u32,
+    _pad:          u32,
+}
+
+struct WireframeParams {
+    color:     vec4<f32>,
+    /// Edge width in pixels.
+    thickness: f32,
+    _pad0:     f32,
+    _pad1:     f32,
+    _pad2:     f32,
+}
+
+@group(0) @binding(0) var<uniform>       camera:        Camera;
+@group(0) @binding(1) var<storage, read> instance_data: array<GpuInstanceData>;
+@group(1) @binding(0) var<uniform>       params:        WireframeParams;
+
+@vertex
+fn vs_main(
+    @location(0)             position: vec3<f32>,
+    @builtin(instance_index) slot:     u32,
+) -> @invariant @builtin(position) vec4<f32> {
+    let world_pos = instance_data[slot].transform * vec4<f32>(position, 1.0);
+    return camera.view_proj * world_pos;
+}
+
+@fragment
+fn fs_main(@builtin(barycentric) bary: vec3<f32>) -> @location(0) vec4<f32> {
+    // Screen-space rate of change turns the barycentric distance to each edge
+    // into pixels, so lines keep a constant width at any distance.
+    let px = fwidth(bary);
+    let half_width = max(params.thickness, 0.0) * 0.5;
+    let cover = 1.0 - smoothstep(px * (half_width - 0.5), px * (half_width + 0.5), bary);
+    let edge = max(cover.x, max(cover.y, cover.z));
+    if edge <= 0.0 {
+        discard;
+    }
+    return vec4<f32>(params.color.rgb, params.color.a * edge);
+}
@@ -0,0 +1,197 @@
+//! CPU-side merging of small static meshes into shared vertex/index buffers.
+//!
+//! Instancing covers many copies of one mesh; [`MeshBatcher`] covers the
+//! other static case — many *different* small meshes (or one mesh at varied
+//! transforms) drawn with the same material. Transforms are baked into the
+//! vertices, so each resulting batch is placed with an identity transform and
+//! costs one draw call instead of one per source mesh.
+
+use glam::{Mat3, Mat4, Vec3};
+
+use crate::mesh::{pack_snorm4x8, unpack_snorm4x8, MeshUpload, PackedVertex};
+
+/// Default per-batch vertex cap: keeps every batch addressable with 16-bit
+/// indices and small enough that per-batch culling still rejects something.
+pub const DEFAULT_MAX_BATCH_VERTICES: u32 = 1 << 16;
+
+/// Merges static meshes that share a material into as few [`MeshUpload`]s as
+/// the vertex cap allows.
+///
+/// A batcher does not know about materials: use one per material and insert
+/// every resulting batch with that material and `Mat4::IDENTITY`.
+///
+/// ```rust,ignore
+/// let mut batcher = MeshBatcher::new();
+/// for pos in crate_positions {
+///     batcher.add(&crate_mesh, Mat4::from_translation(pos));
+/// }
+/// for batch in batcher.build() {
+///     let mesh = scene.insert_actor(SceneActor::mesh(batch)).as_mesh().unwrap();
+///     // Insert an object with `mesh`, the shared material and `Mat4::IDENTITY`.
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MeshBatcher {
+    max_vertices: u32,
+    batches: Vec<MeshUpload>,
+}
+
+impl Default for MeshBatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MeshBatcher {
+    pub fn new() -> Self {
+        Self::with_max_vertices(DEFAULT_MAX_BATCH_VERTICES)
+    }
+
+    /// Start a new batch whenever adding a mesh would exceed `max_vertices`.
+    ///
+    /// A single mesh larger than the cap is never split; it gets a batch of
+    /// its own.
+    pub fn with_max_vertices(max_vertices: u32) -> Self {
+        Self {
+            max_vertices: max_vertices.max(1),
+            batches: Vec::new(),
+        }
+    }
+
+    /// Append `mesh` with `transform` baked into its vertices.
+    ///
+    /// Positions use `transform`, normals its inverse-transpose, and tangents
+    /// its linear part. Mirroring transforms (negative determinant) flip the
+    /// triangle winding and bitangent sign so faces stay front-facing.
+    pub fn add(&mut self, mesh: &MeshUpload, transform: Mat4) {
+        if mesh.vertices.is_empty() || mesh.indices.is_empty() {
+            return;
+        }
+        let added = mesh.vertices.len() as u64;
+        let needs_new = match self.batches.last() {
+            None => true,
+            Some(batch) => {
+                !batch.vertices.is_empty()
+                    && batch.vertices.len() as u64 + added > self.max_vertices as u64
+            }
+        };
+        if needs_new {
+            self.batches.push(MeshUpload { vertices: Vec::new(), indices: Vec::new() });
+        }
+        let batch = self.batches.last_mut().expect("batch pushed above");
+
+        let linear = Mat3::from_mat4(transform);
+        let normal_mat = linear.inverse().transpose();
+        let mirrored = linear.determinant() < 0.0;
+        let base = batch.vertices.len() as u32;
+
+        batch.vertices.extend(mesh.vertices.iter().map(|v| {
+            let position = transform.transform_point3(Vec3::from(v.position));
+            let [nx, ny, nz, nw] = unpack_snorm4x8(v.normal);
+            let [tx, ty, tz, tw] = unpack_snorm4x8(v.tangent);
+            let normal = (normal_mat * Vec3::new(nx, ny, nz)).normalize_or_zero();
+            let tangent = (linear * Vec3::new(tx, ty, tz)).normalize_or_zero();
+            PackedVertex {
+                position: position.to_array(),
+                bitangent_sign: if mirrored { -v.bitangent_sign } else { v.bitangent_sign },
+                tex_coords0: v.tex_coords0,
+                tex_coords1: v.tex_coords1,
+                normal: pack_snorm4x8([normal.x, normal.y, normal.z, nw]),
+                tangent: pack_snorm4x8([tangent.x, tangent.y, tangent.z, tw]),
+            }
+        }));
+
+        for tri in mesh.indices.chunks_exact(3) {
+            let (a, b, c) = (tri[0] + base, tri[1] + base, tri[2] + base);
+            if mirrored {
+                batch.indices.extend_from_slice(&[a, c, b]);
+            } else {
+                batch.indices.extend_from_slice(&[a, b, c]);
+            }
+        }
+    }
+
+    /// Number of batches [`build`](Self::build) will return.
+    pub fn batch_count(&self) -> usize {
+        self.batches.len()
+    }
+
+    /// Consume the batcher, returning one merged mesh per batch.
+    pub fn build(self) -> Vec<MeshUpload> {
+        self.batches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle() -> MeshUpload {
+        let v = |p: [f32; 3]| {
+            PackedVertex::from_components(p, [0.0, 0.0, 1.0], [0.0, 0.0], [1.0, 0.0, 0.0], 1.0)
+        };
+        MeshUpload {
+            vertices: vec![v([0.0, 0.0, 0.0]), v([1.0, 0.0, 0.0]), v([0.0, 1.0, 0.0])],
+            indices: vec![0, 1, 2],
+        }
+    }
+
+    #[test]
+    fn transforms_are_baked_and_indices_rebased() {
+        let mut batcher = MeshBatcher::new();
+        batcher.add(&triangle(), Mat4::IDENTITY);
+        batcher.add(&triangle(), Mat4::from_translation(Vec3::new(10.0, 0.0, 0.0)));
+        let batches = batcher.build();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].vertices.len(), 6);
+        assert_eq!(batches[0].indices, vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(batches[0].vertices[4].position, [11.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn normals_use_inverse_transpose() {
+        let mut batcher = MeshBatcher::new();
+        // Stretching along Z flattens the surface, tilting normals away from Z.
+        let mut mesh = triangle();
+        mesh.vertices[0] = PackedVertex::from_components(
+            [0.0; 3],
+            [0.6, 0.0, 0.8],
+            [0.0, 0.0],
+            [0.8, 0.0, -0.6],
+            1.0,
+        );
+        batcher.add(&mesh, Mat4::from_scale(Vec3::new(1.0, 1.0, 4.0)));
+        let n = unpack_snorm4x8(batcher.build()[0].vertices[0].normal);
+        // (0.6, 0, 0.8) scaled by (1, 1, 1/4) and renormalized.
+        let expected = Vec3::new(0.6, 0.0, 0.2).normalize();
+        assert!((Vec3::new(n[0], n[1], n[2]) - expected).length() < 0.02);
+    }
+
+    #[test]
+    fn mirroring_flips_winding() {
+        let mut batcher = MeshBatcher::new();
+        batcher.add(&triangle(), Mat4::from_scale(Vec3::new(-1.0, 1.0, 1.0)));
+        let batch = &batcher.build()[0];
+        assert_eq!(batch.indices, vec![0, 2, 1]);
+        assert_eq!(batch.vertices[0].bitangent_sign, -1.0);
+    }
+
+    #[test]
+    fn vertex_cap_splits_batches() {
+        let mut batcher = MeshBatcher::with_max_vertices(7);
+        for _ in 0..3 {
+            batcher.add(&triangle(), Mat4::IDENTITY);
+        }
+        let batches = batcher.build();
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[1].indices, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn oversized_mesh_gets_its_own_batch() {
+        let mut batcher = MeshBatcher::with_max_vertices(2);
+        batcher.add(&triangle(), Mat4::IDENTITY);
+        batcher.add(&triangle(), Mat4::IDENTITY);
+        assert_eq!(batcher.batch_count(), 2);
+    }
+}
@@ -0,0 +1,473 @@
+//! Recorded camera paths for flythroughs, cinematics and benchmark runs.
+//!
+//! A [`CameraPath`] is a time-sorted list of [`CameraKeyframe`]s. Sampling it
+//! yields a [`Camera`] ready for [`Renderer::render`](crate::Renderer::render),
+//! so playback needs no renderer support: advance a clock, sample, render.
+//!
+//! [`PathInterpolation::CatmullRom`] (the default) passes through every
+//! keyframe with a continuous velocity, including across unevenly spaced
+//! keys, so motion has no visible kinks. Rotations are interpolated on the
+//! quaternion components and renormalised, which keeps the angular velocity
+//! continuous as well.
+//!
+//! # File format
+//!
+//! Paths are stored as plain text, one keyframe per line:
+//!
+//! ```text
+//! # time  position (x y z)  rotation quaternion (x y z w)  vertical fov (degrees)
+//! interpolation catmull-rom
+//! key 0.0  0.0 2.0 10.0  0.0 0.0 0.0 1.0  60.0
+//! key 2.5  5.0 2.0 5.0   0.0 0.38268 0.0 0.92388  55.0
+//! ```
+//!
+//! Blank lines and `#` comments are ignored. `interpolation` is optional
+//! (`linear` or `catmull-rom`) and `near` / `far` lines override the clip
+//! planes.
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+use glam::{Mat4, Quat, Vec3, Vec4};
+use thiserror::Error;
+
+use crate::scene::Camera;
+
+/// Error type for loading camera paths.
+#[derive(Debug, Error)]
+pub enum CameraPathError {
+    /// The file could not be read or written.
+    #[error("failed to access camera path file: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A line could not be parsed.
+    #[error("camera path line {line}: {message}")]
+    Parse {
+        /// 1-based line number.
+        line: usize,
+        /// What was wrong with the line.
+        message: String,
+    },
+}
+
+/// How [`CameraPath::sample`] blends between keyframes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PathInterpolation {
+    /// Straight segments; position and fov are continuous but velocity
+    /// jumps at every keyframe.
+    Linear,
+    /// Catmull-Rom spline through every keyframe with continuous velocity.
+    #[default]
+    CatmullRom,
+}
+
+/// A camera pose at a point in time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraKeyframe {
+    /// Time of the keyframe in seconds.
+    pub time: f32,
+    /// Camera position in world space.
+    pub position: Vec3,
+    /// Camera orientation; the camera looks down its local -Z with +Y up.
+    pub rotation: Quat,
+    /// Vertical field of view in radians.
+    pub fov_y: f32,
+}
+
+impl CameraKeyframe {
+    pub fn new(time: f32, position: Vec3, rotation: Quat, fov_y: f32) -> Self {
+        Self { time, position, rotation: rotation.normalize(), fov_y }
+    }
+
+    /// Keyframe for a camera at `position` looking at `target`, matching
+    /// [`Camera::perspective_look_at`].
+    pub fn look_at(time: f32, position: Vec3, target: Vec3, up: Vec3, fov_y: f32) -> Self {
+        let view = Mat4::look_at_rh(position, target, up);
+        Self::new(time, position, Quat::from_mat4(&view).inverse(), fov_y)
+    }
+
+    /// World-to-camera transform for this pose.
+    pub fn view_matrix(&self) -> Mat4 {
+        Mat4::from_rotation_translation(self.rotation, self.position).inverse()
+    }
+}
+
+/// Keyframed camera path with linear or Catmull-Rom playback.
+///
+/// # Example
+/// ```ignore
+/// let path = CameraPath::from_file("assets/flythrough.campath")?;
+/// let t = clock.elapsed().as_secs_f32() % path.duration();
+/// renderer.render(&path.sample(t, width as f32 / height as f32), &view)?;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CameraPath {
+    keyframes: Vec<CameraKeyframe>,
+    interpolation: PathInterpolation,
+    near: f32,
+    far: f32,
+}
+
+impl Default for CameraPath {
+    fn default() -> Self {
+        Self::new(PathInterpolation::default())
+    }
+}
+
+impl CameraPath {
+    pub fn new(interpolation: PathInterpolation) -> Self {
+        Self { keyframes: Vec::new(), interpolation, near: 0.1, far: 1000.0 }
+    }
+
+    /// Read and parse the camera path file at `path`.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, CameraPathError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Parse a camera path held in memory (see the module docs for the format).
+    pub fn parse(text: &str) -> Result<Self, CameraPathError> {
+        let mut path = Self::default();
+        for (index, raw) in text.lines().enumerate() {
+            let line = index + 1;
+            let err = |message: String| CameraPathError::Parse { line, message };
+            let content = raw.split('#').next().unwrap_or("").trim();
+            let mut tokens = content.split_whitespace();
+            let Some(keyword) = tokens.next() else {
+                continue;
+            };
+            let values: Vec<&str> = tokens.collect();
+            let numbers = |count: usize| -> Result<Vec<f32>, CameraPathError> {
+                if values.len() != count {
+                    return Err(err(format!(
+                        "`{keyword}` expects {count} values, found {}",
+                        values.len()
+                    )));
+                }
+                values
+                    .iter()
+                    .map(|v| {
+                        v.parse::<f32>()
+                            .ok()
+                            .filter(|n| n.is_finite())
+                            .ok_or_else(|| err(format!("invalid number `{v}`")))
+                    })
+                    .collect()
+            };
+            match keyword {
+                "interpolation" => {
+                    path.interpolation = match values.as_slice() {
+                        ["linear"] => PathInterpolation::Linear,
+                        ["catmull-rom"] => PathInterpolation::CatmullRom,
+                        _ => return Err(err(format!("unknown interpolation `{}`", values.join(" ")))),
+                    };
+                }
+                "near" => path.near = numbers(1)?[0],
+                "far" => path.far = numbers(1)?[0],
+                "key" => {
+                    let n = numbers(9)?;
+                    let rotation = Quat::from_xyzw(n[4], n[5], n[6], n[7]);
+                    if rotation.length_squared() < 1e-8 {
+                        return Err(err("zero-length rotation quaternion".into()));
+                    }
+                    path.add_keyframe(CameraKeyframe::new(
+                        n[0],
+                        Vec3::new(n[1], n[2], n[3]),
+                        rotation,
+                        n[8].to_radians(),
+                    ));
+                }
+                other => return Err(err(format!("unknown keyword `{other}`"))),
+            }
+        }
+        Ok(path)
+    }
+
+    /// Serialise the path in the format read by [`parse`](Self::parse).
+    pub fn to_text(&self) -> String {
+        let mut out = String::from("# time  position (x y z)  rotation (x y z w)  fov_y (degrees)\n");
+        let interpolation = match self.interpolation {
+            PathInterpolation::Linear => "linear",
+            PathInterpolation::CatmullRom => "catmull-rom",
+        };
+        let _ = writeln!(out, "interpolation {interpolation}");
+        let _ = writeln!(out, "near {}", self.near);
+        let _ = writeln!(out, "far {}", self.far);
+        for k in &self.keyframes {
+            let _ = writeln!(
+                out,
+                "key {} {} {} {} {} {} {} {} {}",
+                k.time,
+                k.position.x,
+                k.position.y,
+                k.position.z,
+                k.rotation.x,
+                k.rotation.y,
+                k.rotation.z,
+                k.rotation.w,
+                k.fov_y.to_degrees(),
+            );
+        }
+        out
+    }
+
+    /// Write the path to `path` in the text format.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), CameraPathError> {
+        std::fs::write(path, self.to_text())?;
+        Ok(())
+    }
+
+    /// Insert a keyframe, keeping the path sorted by time. A keyframe at the
+    /// same time as an existing one replaces it.
+    pub fn add_keyframe(&mut self, keyframe: CameraKeyframe) {
+        match self.keyframes.binary_search_by(|k| k.time.total_cmp(&keyframe.time)) {
+            Ok(index) => self.keyframes[index] = keyframe,
+            Err(index) => self.keyframes.insert(index, keyframe),
+        }
+        // q and -q are the same orientation; keep neighbours in the same
+        // hemisphere so interpolation takes the short way round.
+        for i in 1..self.keyframes.len() {
+            let prev = self.keyframes[i - 1].rotation;
+            let rotation = &mut self.keyframes[i].rotation;
+            if prev.dot(*rotation) < 0.0 {
+                *rotation = -*rotation;
+            }
+        }
+    }
+
+    pub fn keyframes(&self) -> &[CameraKeyframe] {
+        &self.keyframes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keyframes.is_empty()
+    }
+
+    pub fn interpolation(&self) -> PathInterpolation {
+        self.interpolation
+    }
+
+    pub fn set_interpolation(&mut self, interpolation: PathInterpolation) {
+        self.interpolation = interpolation;
+    }
+
+    /// Near and far clip planes used by [`sample`](Self::sample).
+    pub fn clip_planes(&self) -> (f32, f32) {
+        (self.near, self.far)
+    }
+
+    pub fn set_clip_planes(&mut self, near: f32, far: f32) {
+        self.near = near;
+        self.far = far;
+    }
+
+    /// Time of the first keyframe (0 for an empty path).
+    pub fn start_time(&self) -> f32 {
+        self.keyframes.first().map_or(0.0, |k| k.time)
+    }
+
+    /// Time of the last keyframe (0 for an empty path).
+    pub fn end_time(&self) -> f32 {
+        self.keyframes.last().map_or(0.0, |k| k.time)
+    }
+
+    /// Length of the path in seconds.
+    pub fn duration(&self) -> f32 {
+        self.end_time() - self.start_time()
+    }
+
+    /// Interpolated pose at time `t`, clamped to the ends of the path.
+    /// Returns `None` for an empty path.
+    pub fn pose(&self, t: f32) -> Option<CameraKeyframe> {
+        let keys = &self.keyframes;
+        let (first, last) = (keys.first()?, keys.last()?);
+        if keys.len() == 1 || t <= first.time {
+            return Some(CameraKeyframe { time: t, ..*first });
+        }
+        if t >= last.time {
+            return Some(CameraKeyframe { time: t, ..*last });
+        }
+
+        // Segment [i, i + 1] containing t.
+        let i = keys.partition_point(|k| k.time <= t) - 1;
+        let (k0, k1) = (&keys[i], &keys[i + 1]);
+        let dt = k1.time - k0.time;
+        let s = (t - k0.time) / dt;
+
+        let (position, rotation, fov_y) = match self.interpolation {
+            PathInterpolation::Linear => (
+                k0.position.lerp(k1.position, s),
+                k0.rotation.slerp(k1.rotation, s),
+                k0.fov_y + (k1.fov_y - k0.fov_y) * s,
+            ),
+            PathInterpolation::CatmullRom => {
+                let c0 = key_vector(k0);
+                let c1 = key_vector(k1);
+                let m0 = self.tangent(i);
+                let m1 = self.tangent(i + 1);
+                let (p, q, fov) = hermite(c0, m0 * dt, c1, m1 * dt, s);
+                (p, Quat::from_vec4(q).normalize(), fov)
+            }
+        };
+        Some(CameraKeyframe { time: t, position, rotation, fov_y })
+    }
+
+    /// Camera at time `t` for a viewport with the given aspect ratio.
+    ///
+    /// Uses the same projection as [`Camera::perspective_look_at`]. An empty
+    /// path yields a camera at the origin looking down -Z with a 60° fov.
+    pub fn sample(&self, t: f32, aspect: f32) -> Camera {
+        let pose = self.pose(t).unwrap_or(CameraKeyframe {
+            time: t,
+            position: Vec3::ZERO,
+            rotation: Quat::IDENTITY,
+            fov_y: 60f32.to_radians(),
+        });
+        let proj = Mat4::perspective_rh(pose.fov_y, aspect, self.near, self.far);
+        Camera::from_matrices(pose.view_matrix(), proj, pose.position, self.near, self.far)
+    }
+
+    /// Velocity (per second) of every interpolated channel at keyframe `i`.
+    ///
+    /// Central differences over the neighbouring keys, one-sided at the ends.
+    /// Dividing by the actual time span keeps the spline C1 in time even when
+    /// keyframes are unevenly spaced.
+    fn tangent(&self, i: usize) -> KeyVector {
+        let keys = &self.keyframes;
+        let prev = &keys[i.saturating_sub(1)];
+        let next = &keys[(i + 1).min(keys.len() - 1)];
+        let span = next.time - prev.time;
+        let (a, b) = (key_vector(prev), key_vector(next));
+        KeyVector {
+            position: (b.position - a.position) / span,
+            rotation: (b.rotation - a.rotation) / span,
+            fov_y: (b.fov_y - a.fov_y) / span,
+        }
+    }
+}
+
+/// Interpolated channels of a keyframe, with the rotation as a raw 4-vector.
+#[derive(Clone, Copy)]
+struct KeyVector {
+    position: Vec3,
+    rotation: Vec4,
+    fov_y: f32,
+}
+
+impl std::ops::Mul<f32> for KeyVector {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Self { position: self.position * rhs, rotation: self.rotation * rhs, fov_y: self.fov_y * rhs }
+    }
+}
+
+fn key_vector(k: &CameraKeyframe) -> KeyVector {
+    KeyVector { position: k.position, rotation: Vec4::from(k.rotation), fov_y: k.fov_y }
+}
+
+/// Cubic Hermite blend of every channel; `m0`/`m1` are pre-scaled by the
+/// segment length.
+fn hermite(p0: KeyVector, m0: KeyVector, p1: KeyVector, m1: KeyVector, s: f32) -> (Vec3, Vec4, f32) {
+    let s2 = s * s;
+    let s3 = s2 * s;
+    let h00 = 2.0 * s3 - 3.0 * s2 + 1.0;
+    let h10 = s3 - 2.0 * s2 + s;
+    let h01 = -2.0 * s3 + 3.0 * s2;
+    let h11 = s3 - s2;
+    (
+        p0.position * h00 + m0.position * h10 + p1.position * h01 + m1.position * h11,
+        p0.rotation * h00 + m0.rotation * h10 + p1.rotation * h01 + m1.rotation * h11,
+        p0.fov_y * h00 + m0.fov_y * h10 + p1.fov_y * h01 + m1.fov_y * h11,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_path(interpolation: PathInterpolation) -> CameraPath {
+        let mut path = CameraPath::new(interpolation);
+        // Deliberately uneven spacing and out-of-order insertion.
+        path.add_keyframe(CameraKeyframe::look_at(3.0, Vec3::new(10.0, 4.0, 0.0), Vec3::ZERO, Vec3::Y, 0.9));
+        path.add_keyframe(CameraKeyframe::look_at(0.0, Vec3::new(0.0, 2.0, 10.0), Vec3::ZERO, Vec3::Y, 1.0));
+        path.add_keyframe(CameraKeyframe::look_at(1.0, Vec3::new(7.0, 3.0, 7.0), Vec3::ZERO, Vec3::Y, 1.1));
+        path.add_keyframe(CameraKeyframe::look_at(3.5, Vec3::new(7.0, 1.0, -7.0), Vec3::ZERO, Vec3::Y, 0.8));
+        path
+    }
+
+    #[test]
+    fn sample_passes_through_keyframes_and_clamps() {
+        for interpolation in [PathInterpolation::Linear, PathInterpolation::CatmullRom] {
+            let path = sample_path(interpolation);
+            assert_eq!(path.start_time(), 0.0);
+            assert_eq!(path.duration(), 3.5);
+            for key in path.keyframes() {
+                let pose = path.pose(key.time).unwrap();
+                assert!(pose.position.abs_diff_eq(key.position, 1e-4));
+                assert!(pose.rotation.abs_diff_eq(key.rotation, 1e-4));
+                assert!((pose.fov_y - key.fov_y).abs() < 1e-5);
+            }
+            assert_eq!(path.pose(-1.0).unwrap().position, path.keyframes()[0].position);
+            assert_eq!(path.pose(9.0).unwrap().position, path.keyframes()[3].position);
+        }
+    }
+
+    #[test]
+    fn catmull_rom_velocity_is_continuous_at_keyframes() {
+        let path = sample_path(PathInterpolation::CatmullRom);
+        let h = 1e-3;
+        for t in [1.0, 3.0] {
+            let before = (path.pose(t).unwrap().position - path.pose(t - h).unwrap().position) / h;
+            let after = (path.pose(t + h).unwrap().position - path.pose(t).unwrap().position) / h;
+            assert!(before.abs_diff_eq(after, 0.05), "kink at t={t}: {before} vs {after}");
+
+            let rot_before = Vec4::from(path.pose(t).unwrap().rotation) - Vec4::from(path.pose(t - h).unwrap().rotation);
+            let rot_after = Vec4::from(path.pose(t + h).unwrap().rotation) - Vec4::from(path.pose(t).unwrap().rotation);
+            assert!((rot_before / h).abs_diff_eq(rot_after / h, 0.05), "rotation kink at t={t}");
+        }
+
+        // Linear paths do kink, which is what the spline is for.
+        let linear = sample_path(PathInterpolation::Linear);
+        let before = (linear.pose(1.0).unwrap().position - linear.pose(1.0 - h).unwrap().position) / h;
+        let after = (linear.pose(1.0 + h).unwrap().position - linear.pose(1.0).unwrap().position) / h;
+        assert!(!before.abs_diff_eq(after, 0.05));
+    }
+
+    #[test]
+    fn sample_matches_perspective_look_at() {
+        let path = sample_path(PathInterpolation::CatmullRom);
+        let camera = path.sample(0.0, 16.0 / 9.0);
+        let expected = Camera::perspective_look_at(
+            Vec3::new(0.0, 2.0, 10.0),
+            Vec3::ZERO,
+            Vec3::Y,
+            1.0,
+            16.0 / 9.0,
+            0.1,
+            1000.0,
+        );
+        assert!(camera.view.abs_diff_eq(expected.view, 1e-4));
+        assert!(camera.proj.abs_diff_eq(expected.proj, 1e-5));
+        assert_eq!(camera.position, expected.position);
+    }
+
+    #[test]
+    fn text_round_trip_and_errors() {
+        let mut path = sample_path(PathInterpolation::Linear);
+        path.set_clip_planes(0.5, 250.0);
+        let parsed = CameraPath::parse(&path.to_text()).unwrap();
+        assert_eq!(parsed.interpolation(), PathInterpolation::Linear);
+        assert_eq!(parsed.clip_planes(), (0.5, 250.0));
+        assert_eq!(parsed.keyframes().len(), 4);
+        for (a, b) in parsed.keyframes().iter().zip(path.keyframes()) {
+            assert!(a.position.abs_diff_eq(b.position, 1e-5));
+            assert!(a.rotation.abs_diff_eq(b.rotation, 1e-5));
+            assert!((a.fov_y - b.fov_y).abs() < 1e-5);
+        }
+
+        let err = CameraPath::parse("# ok\nkey 0 1 2 3 0 0 0 1\n").unwrap_err();
+        assert!(matches!(err, CameraPathError::Parse { line: 2, .. }), "{err}");
+        assert!(CameraPath::parse("interpolation cubic").is_err());
+        assert!(CameraPath::parse("key 0 0 0 0 0 0 0 0 60").is_err());
+    }
+}
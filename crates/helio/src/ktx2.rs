@@ -0,0 +1,405 @@
+//! KTX2 texture container loading.
+//!
+//! KTX2 stores GPU-ready texel data — usually BCn, ASTC or ETC2 with its full
+//! mip chain — so loading it is a file read and an upload, with no decode or
+//! recompression. 2D textures go into the scene's material texture pool via
+//! [`Scene::load_ktx2`](crate::Scene::load_ktx2); cubemaps and texture arrays
+//! (skyboxes, IBL, virtual-texture pages) are created directly with
+//! [`Ktx2Texture::create_texture`].
+//!
+//! Supercompression: none is always supported, Zstandard with the `ktx2-zstd`
+//! feature. Basis Universal payloads (BasisLZ/ETC1S and UASTC) need a
+//! transcoder this crate does not ship and are rejected with
+//! [`Ktx2Error::BasisUniversal`]; re-encode them to a block format
+//! (e.g. `ktx create --format BC7_SRGB_BLOCK`) for the runtime path.
+
+use std::path::Path;
+
+use thiserror::Error;
+
+use crate::material::{TextureSamplerDesc, TextureUpload};
+
+const IDENTIFIER: [u8; 12] = [0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A];
+const HEADER_LEN: usize = 80;
+const LEVEL_INDEX_ENTRY_LEN: usize = 24;
+
+/// Error type for KTX2 parsing.
+#[derive(Debug, Error)]
+pub enum Ktx2Error {
+    /// The file could not be read.
+    #[error("failed to read KTX2 file: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The data does not start with the KTX2 identifier.
+    #[error("not a KTX2 file")]
+    NotKtx2,
+
+    /// A header field, level index entry or level points outside the data.
+    #[error("truncated KTX2 file: {0}")]
+    Truncated(&'static str),
+
+    /// The file is valid KTX2 but uses a layout the renderer cannot upload.
+    #[error("unsupported KTX2 texture: {0}")]
+    UnsupportedLayout(String),
+
+    /// No `wgpu` format corresponds to the file's `vkFormat`.
+    #[error("unsupported KTX2 vkFormat {0}")]
+    UnsupportedFormat(u32),
+
+    /// The level data uses a supercompression scheme that is not available.
+    #[error("unsupported KTX2 supercompression scheme {name} ({scheme}){hint}")]
+    UnsupportedSupercompression {
+        /// `supercompressionScheme` from the header.
+        scheme: u32,
+        /// Name of the scheme.
+        name: &'static str,
+        /// How to make the file loadable, if there is a way.
+        hint: &'static str,
+    },
+
+    /// The file holds Basis Universal data, which must be transcoded.
+    #[error("KTX2 file contains Basis Universal data; transcoding is not supported, re-encode it to a BCn/ASTC/ETC2 format")]
+    BasisUniversal,
+
+    /// A level's byte length does not match its size and format.
+    #[error("KTX2 level {level} is {actual} bytes, expected {expected}")]
+    LevelSize {
+        /// Mip level index.
+        level: u32,
+        /// Bytes implied by size, format, layers and faces.
+        expected: usize,
+        /// Bytes stored in the file (after supercompression is removed).
+        actual: usize,
+    },
+}
+
+/// A parsed KTX2 texture with its level data in upload order.
+#[derive(Debug, Clone)]
+pub struct Ktx2Texture {
+    pub format: wgpu::TextureFormat,
+    pub width: u32,
+    pub height: u32,
+    /// Array layers in the file (1 for a plain texture).
+    pub array_layers: u32,
+    /// 6 for cubemaps and cubemap arrays, 1 otherwise.
+    pub faces: u32,
+    pub mip_level_count: u32,
+    /// Mip-major texel data: every layer and face of level 0, then level 1, …
+    /// Faces vary fastest, matching `wgpu` cube array layer order.
+    pub data: Vec<u8>,
+}
+
+impl Ktx2Texture {
+    /// Read and parse the KTX2 file at `path`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Ktx2Error> {
+        Self::parse(&std::fs::read(path)?)
+    }
+
+    /// Parse a KTX2 file held in memory.
+    pub fn parse(bytes: &[u8]) -> Result<Self, Ktx2Error> {
+        if bytes.len() < IDENTIFIER.len() || bytes[..IDENTIFIER.len()] != IDENTIFIER {
+            return Err(Ktx2Error::NotKtx2);
+        }
+        if bytes.len() < HEADER_LEN {
+            return Err(Ktx2Error::Truncated("header"));
+        }
+        let u32_at = |offset: usize| u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+        let u64_at = |offset: usize| u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap());
+
+        let vk_format = u32_at(12);
+        let width = u32_at(20);
+        let height = u32_at(24);
+        let depth = u32_at(28);
+        let array_layers = u32_at(32).max(1);
+        let faces = u32_at(36);
+        let mip_level_count = u32_at(40).max(1);
+        let scheme = u32_at(44);
+
+        match scheme {
+            0 => {}
+            1 => return Err(Ktx2Error::BasisUniversal),
+            2 if cfg!(feature = "ktx2-zstd") => {}
+            2 => {
+                return Err(Ktx2Error::UnsupportedSupercompression {
+                    scheme,
+                    name: "Zstandard",
+                    hint: "; enable the `ktx2-zstd` feature",
+                })
+            }
+            3 => {
+                return Err(Ktx2Error::UnsupportedSupercompression { scheme, name: "ZLIB", hint: "" })
+            }
+            _ => {
+                return Err(Ktx2Error::UnsupportedSupercompression { scheme, name: "unknown", hint: "" })
+            }
+        }
+        if vk_format == 0 {
+            // VK_FORMAT_UNDEFINED without BasisLZ is UASTC, which also needs transcoding.
+            return Err(Ktx2Error::BasisUniversal);
+        }
+        let format = wgpu_format(vk_format).ok_or(Ktx2Error::UnsupportedFormat(vk_format))?;
+
+        if height == 0 || depth != 0 {
+            return Err(Ktx2Error::UnsupportedLayout(format!(
+                "only 2D textures are supported (height {height}, depth {depth})"
+            )));
+        }
+        if faces != 1 && faces != 6 {
+            return Err(Ktx2Error::UnsupportedLayout(format!("{faces} faces (expected 1 or 6)")));
+        }
+        let full_chain = 32 - width.max(height).leading_zeros();
+        if width == 0 || mip_level_count > full_chain {
+            return Err(Ktx2Error::UnsupportedLayout(format!(
+                "{mip_level_count} mip levels for a {width}x{height} texture"
+            )));
+        }
+
+        let index_end = HEADER_LEN + mip_level_count as usize * LEVEL_INDEX_ENTRY_LEN;
+        if bytes.len() < index_end {
+            return Err(Ktx2Error::Truncated("level index"));
+        }
+        let images = (array_layers * faces) as usize;
+        let block_size = format.block_copy_size(None).ok_or(Ktx2Error::UnsupportedFormat(vk_format))? as usize;
+        let (block_w, block_h) = format.block_dimensions();
+
+        let mut data = Vec::new();
+        for level in 0..mip_level_count {
+            let entry = HEADER_LEN + level as usize * LEVEL_INDEX_ENTRY_LEN;
+            let offset = usize::try_from(u64_at(entry)).map_err(|_| Ktx2Error::Truncated("level data"))?;
+            let length = usize::try_from(u64_at(entry + 8)).map_err(|_| Ktx2Error::Truncated("level data"))?;
+            let stored = offset
+                .checked_add(length)
+                .and_then(|end| bytes.get(offset..end))
+                .ok_or(Ktx2Error::Truncated("level data"))?;
+
+            let w = (width >> level).max(1);
+            let h = (height >> level).max(1);
+            let expected = w.div_ceil(block_w) as usize * h.div_ceil(block_h) as usize * block_size * images;
+            let start = data.len();
+            match scheme {
+                #[cfg(feature = "ktx2-zstd")]
+                2 => data.extend(zstd::bulk::decompress(stored, expected)?),
+                _ => data.extend_from_slice(stored),
+            }
+            let actual = data.len() - start;
+            if actual != expected {
+                return Err(Ktx2Error::LevelSize { level, expected, actual });
+            }
+        }
+
+        Ok(Self {
+            format,
+            width,
+            height,
+            array_layers,
+            faces,
+            mip_level_count,
+            data,
+        })
+    }
+
+    /// Whether the file is a cubemap (or cubemap array).
+    pub fn is_cubemap(&self) -> bool {
+        self.faces == 6
+    }
+
+    /// `wgpu` array layers: layers × faces.
+    pub fn layer_count(&self) -> u32 {
+        self.array_layers * self.faces
+    }
+
+    /// Upload every layer, face and mip level into a new texture.
+    ///
+    /// Use a `Cube`/`CubeArray` view dimension for cubemaps. The device must
+    /// support [`format`](Self::format).
+    pub fn create_texture(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        label: Option<&str>,
+    ) -> wgpu::Texture {
+        use wgpu::util::DeviceExt;
+        helio_core::upload::record_upload_bytes(self.data.len() as u64);
+        device.create_texture_with_data(
+            queue,
+            &wgpu::TextureDescriptor {
+                label,
+                size: wgpu::Extent3d {
+                    width: self.width,
+                    height: self.height,
+                    depth_or_array_layers: self.layer_count(),
+                },
+                mip_level_count: self.mip_level_count,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: self.format,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            },
+            wgpu::util::TextureDataOrder::MipMajor,
+            &self.data,
+        )
+    }
+
+    /// Convert a single-layer 2D texture into a [`TextureUpload`] for the
+    /// material texture pool. Cubemaps and arrays are rejected: the pool only
+    /// holds 2D textures.
+    pub fn into_upload(
+        self,
+        label: impl Into<String>,
+        sampler: TextureSamplerDesc,
+    ) -> Result<TextureUpload, Ktx2Error> {
+        if self.layer_count() != 1 {
+            return Err(Ktx2Error::UnsupportedLayout(format!(
+                "{} layers x {} faces cannot go into the 2D texture pool; use Ktx2Texture::create_texture",
+                self.array_layers, self.faces
+            )));
+        }
+        Ok(TextureUpload::compressed(
+            label,
+            self.width,
+            self.height,
+            self.format,
+            self.mip_level_count,
+            self.data,
+            sampler,
+        ))
+    }
+}
+
+/// `wgpu` format for a Vulkan `VkFormat` value, for the formats KTX2 files
+/// commonly ship with.
+fn wgpu_format(vk_format: u32) -> Option<wgpu::TextureFormat> {
+    use wgpu::{AstcBlock, AstcChannel, TextureFormat as F};
+    Some(match vk_format {
+        9 => F::R8Unorm,
+        16 => F::Rg8Unorm,
+        37 => F::Rgba8Unorm,
+        43 => F::Rgba8UnormSrgb,
+        44 => F::Bgra8Unorm,
+        50 => F::Bgra8UnormSrgb,
+        76 => F::R16Float,
+        83 => F::Rg16Float,
+        97 => F::Rgba16Float,
+        100 => F::R32Float,
+        103 => F::Rg32Float,
+        109 => F::Rgba32Float,
+        122 => F::Rg11b10Ufloat,
+        123 => F::Rgb9e5Ufloat,
+        // BC1 RGB and RGBA share a block layout; the RGB variants decode with alpha 1.
+        131 | 133 => F::Bc1RgbaUnorm,
+        132 | 134 => F::Bc1RgbaUnormSrgb,
+        135 => F::Bc2RgbaUnorm,
+        136 => F::Bc2RgbaUnormSrgb,
+        137 => F::Bc3RgbaUnorm,
+        138 => F::Bc3RgbaUnormSrgb,
+        139 => F::Bc4RUnorm,
+        140 => F::Bc4RSnorm,
+        141 => F::Bc5RgUnorm,
+        142 => F::Bc5RgSnorm,
+        143 => F::Bc6hRgbUfloat,
+        144 => F::Bc6hRgbFloat,
+        145 => F::Bc7RgbaUnorm,
+        146 => F::Bc7RgbaUnormSrgb,
+        147 => F::Etc2Rgb8Unorm,
+        148 => F::Etc2Rgb8UnormSrgb,
+        149 => F::Etc2Rgb8A1Unorm,
+        150 => F::Etc2Rgb8A1UnormSrgb,
+        151 => F::Etc2Rgba8Unorm,
+        152 => F::Etc2Rgba8UnormSrgb,
+        153 => F::EacR11Unorm,
+        154 => F::EacR11Snorm,
+        155 => F::EacRg11Unorm,
+        156 => F::EacRg11Snorm,
+        157 => F::Astc { block: AstcBlock::B4x4, channel: AstcChannel::Unorm },
+        158 => F::Astc { block: AstcBlock::B4x4, channel: AstcChannel::UnormSrgb },
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a KTX2 file with `levels` levels of `level_len(level)` bytes each.
+    fn ktx2(vk_format: u32, width: u32, height: u32, layers: u32, faces: u32, levels: &[Vec<u8>], scheme: u32) -> Vec<u8> {
+        let mut out = IDENTIFIER.to_vec();
+        for field in [vk_format, 1, width, height, 0, layers, faces, levels.len() as u32, scheme] {
+            out.extend(field.to_le_bytes());
+        }
+        out.extend([0u8; 32]); // DFD / KVD / SGD index (unused by the loader)
+        let mut offset = (HEADER_LEN + levels.len() * LEVEL_INDEX_ENTRY_LEN) as u64;
+        let mut index = Vec::new();
+        // Level data is stored smallest first, as the spec recommends.
+        let mut offsets = vec![0; levels.len()];
+        for (level, data) in levels.iter().enumerate().rev() {
+            offsets[level] = offset;
+            offset += data.len() as u64;
+        }
+        for (level, data) in levels.iter().enumerate() {
+            for field in [offsets[level], data.len() as u64, data.len() as u64] {
+                index.extend(field.to_le_bytes());
+            }
+        }
+        out.extend(index);
+        for data in levels.iter().rev() {
+            out.extend(data);
+        }
+        out
+    }
+
+    #[test]
+    fn parses_bc7_mip_chain_into_mip_major_order() {
+        let levels = vec![vec![1u8; 4 * 16], vec![2u8; 16], vec![3u8; 16], vec![4u8; 16]];
+        let texture = Ktx2Texture::parse(&ktx2(146, 8, 8, 0, 1, &levels, 0)).unwrap();
+        assert_eq!(texture.format, wgpu::TextureFormat::Bc7RgbaUnormSrgb);
+        assert_eq!((texture.width, texture.height, texture.mip_level_count), (8, 8, 4));
+        assert_eq!(texture.data, levels.concat());
+
+        let upload = texture.into_upload("bc7", TextureSamplerDesc::default()).unwrap();
+        assert_eq!(upload.expected_data_len(), Some(upload.data.len()));
+    }
+
+    #[test]
+    fn cubemap_keeps_all_faces_and_stays_out_of_the_2d_pool() {
+        let levels = vec![vec![0u8; 4 * 4 * 8 * 6], vec![0u8; 2 * 2 * 8 * 6], vec![0u8; 8 * 6]];
+        let texture = Ktx2Texture::parse(&ktx2(97, 4, 4, 0, 6, &levels, 0)).unwrap();
+        assert!(texture.is_cubemap());
+        assert_eq!(texture.layer_count(), 6);
+        assert!(matches!(
+            texture.into_upload("sky", TextureSamplerDesc::default()),
+            Err(Ktx2Error::UnsupportedLayout(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_bad_files_with_descriptive_errors() {
+        assert!(matches!(Ktx2Texture::parse(b"\x89PNG\r\n\x1a\n...."), Err(Ktx2Error::NotKtx2)));
+
+        let level = vec![vec![0u8; 16]];
+        assert!(matches!(Ktx2Texture::parse(&ktx2(146, 4, 4, 0, 1, &level, 1)), Err(Ktx2Error::BasisUniversal)));
+        assert!(matches!(Ktx2Texture::parse(&ktx2(0, 4, 4, 0, 1, &level, 0)), Err(Ktx2Error::BasisUniversal)));
+        assert!(matches!(
+            Ktx2Texture::parse(&ktx2(146, 4, 4, 0, 1, &level, 3)),
+            Err(Ktx2Error::UnsupportedSupercompression { scheme: 3, .. })
+        ));
+        assert!(matches!(Ktx2Texture::parse(&ktx2(1000, 4, 4, 0, 1, &level, 0)), Err(Ktx2Error::UnsupportedFormat(1000))));
+        assert!(matches!(
+            Ktx2Texture::parse(&ktx2(146, 8, 8, 0, 1, &level, 0)),
+            Err(Ktx2Error::LevelSize { level: 0, expected: 64, actual: 16 })
+        ));
+
+        let mut truncated = ktx2(146, 4, 4, 0, 1, &level, 0);
+        truncated.truncate(truncated.len() - 1);
+        assert!(matches!(Ktx2Texture::parse(&truncated), Err(Ktx2Error::Truncated(_))));
+    }
+
+    #[cfg(feature = "ktx2-zstd")]
+    #[test]
+    fn zstd_levels_are_decompressed() {
+        let raw = vec![7u8; 64];
+        let packed = zstd::bulk::compress(&raw, 3).unwrap();
+        let texture = Ktx2Texture::parse(&ktx2(146, 8, 8, 0, 1, &[packed], 2)).unwrap();
+        assert_eq!(texture.data, raw);
+    }
+}
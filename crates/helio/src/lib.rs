@@ -10,47 +10,68 @@
 //! - partial dirty-range uploads to `helio-core` managers.
 
 mod arena;
+mod batcher;
+mod camera_path;
 mod editor;
 mod groups;
 mod handles;
+mod ktx2;
 mod material;
 mod mesh;
+mod orbit_camera;
 mod picking;
+mod primitives;
 mod quark_commands;
 pub mod radiant;
 mod renderer;
 mod scene;
 mod terrain;
+#[cfg(feature = "texture-compression")]
+mod texture_compression;
 mod vg;
 
 #[cfg(target_arch = "wasm32")]
 mod wasm_cpp_alloc;
 
+pub use batcher::{MeshBatcher, DEFAULT_MAX_BATCH_VERTICES};
+pub use camera_path::{CameraKeyframe, CameraPath, CameraPathError, PathInterpolation};
 pub use editor::{EditorState, GizmoAxis, GizmoMode};
 pub use groups::{GroupId, GroupMask};
 pub use handles::{
     DecalId, LightId, MaterialId, MeshId, MultiMeshId, ObjectId, SectionedInstanceId, TextureId,
     VirtualObjectId, VoxelVolumeId, WaterHitboxId, WaterVolumeId,
 };
+pub use ktx2::{Ktx2Error, Ktx2Texture};
 pub use material::{
-    MaterialAsset, MaterialTextureRef, MaterialTextures, TextureSamplerDesc, TextureTransform,
+    MaterialAsset, MaterialTextureRef, MaterialTextures, NormalMapConvention, TextureSamplerDesc,
+    TextureTransform,
     TextureUpload, MAX_TEXTURES,
 };
-pub use mesh::{MeshBuffers, MeshSlice, MeshUpload, PackedVertex, SectionedMeshUpload};
+pub use mesh::{
+    generate_tangents, recompute_normals, MeshBuffers, MeshSlice, MeshUpload, PackedVertex,
+    SectionedMeshUpload,
+};
+pub use orbit_camera::{OrbitCamera, MAX_ORBIT_PITCH};
 pub use picking::{PickHit, ScenePicker};
+pub use primitives::{create_plane_mesh, create_subdivided_plane};
 pub use quark_commands::{register_helio_commands, HelioAction, HelioCommandBridge};
 pub use renderer::{
-    required_experimental_features, required_wgpu_features, required_wgpu_limits, DebugCameraUniform, DebugDrawPass,
-    DebugDrawState, GiConfig, GraphRebuilder, PerfOverlayMode, Renderer, RendererConfig,
+    required_experimental_features, required_wgpu_features, required_wgpu_limits, DebugCameraUniform, DebugDrawPass, DebugPoint,
+    DebugDrawState, FrameStats, GiConfig, GpuMemoryStats, GraphRebuilder, HelioCapabilities, PerfOverlayMode, OBJECT_FLAG_MINIMAP, PreExposure, PresentMode, ReadbackRequest, Renderer, RendererConfig,
+    ShadedWireframe,
 };
 pub use scene::{
-    Camera, DecalActor, ObjectDescriptor, PickableObject, ReflectionCaptureActor,
+    Camera, DecalActor, Frustum, LoadFailure, ObjectDescriptor, PickableObject, ReflectionCaptureActor,
     ReflectionCaptureDescriptor, Result as SceneResult, Scene, SceneActor,
     SceneActorId, SceneActorTrait, SceneError, VoxelMode, VoxelVolumeDescriptor,
     WaterHitboxActor, WaterHitboxDescriptor,
     WaterVolumeActor, WaterVolumeDescriptor,
 };
+#[cfg(feature = "async")]
+pub use scene::ResourceLoad;
 pub use terrain::{VoxelTerrain, VOXEL_TERRAIN_GRID_DIM};
+#[cfg(feature = "texture-compression")]
+pub use texture_compression::{compress_rgba8, BcFormat};
 pub use vg::{VirtualMeshId, VirtualMeshUpload, VirtualObjectDescriptor};
 
 #[cfg(feature = "bake")]
@@ -60,10 +81,17 @@ pub use helio_bake::{
 };
 pub use helio_core::{
     Actor, Component, ComponentRegistry, ComponentSlot, ComponentVec, DebugViewDescriptor,
-    DrawIndexedIndirectArgs, Entity, Error, GpuCameraUniforms, GpuDrawCall, GpuInstanceAabb,
-    GpuInstanceData, GpuLight, GpuMaterial, GpuScene, RenderGraph, RenderPass, Result,
+    DrawIndexedIndirectArgs, Entity, Error, FrameClock, FrameTime, GpuCameraUniforms, GpuDrawCall, GpuInstanceAabb,
+    GpuInstanceData, GpuLight, GpuMaterial, GpuScene, QualityPreset, RenderGraph, RenderPass, Result,
+};
+pub use libhelio::{
+    light_complexity_band, AdaptiveShadowResolution, AmbientSh, BillboardBlendMode, DepthConvention,
+    DepthMode, FillLight, GradientSky, LightComplexityBand, LightType, LoadOp, Movability,
+    OutputTransfer, PatternKind, PatternSpace, ProceduralPattern, ShadowQuality, SkyActor,
+    TimeOfDay, TriplanarMapping, TriplanarSpace, VolumetricClouds, WindParams,
+    DEBUG_MODE_FACE_ORIENTATION, DEBUG_MODE_FACE_ORIENTATION_NORMALS, DEBUG_MODE_GI_IRRADIANCE,
+    DEBUG_MODE_LIGHT_COMPLEXITY, LIGHT_COMPLEXITY_LEGEND,
 };
-pub use libhelio::{LightType, Movability, ShadowQuality, SkyActor, VolumetricClouds};
 
 /// Convert a [`MeshUpload`] with a world-space transform into a [`BakeMesh`] for use
 /// in a [`BakeRequest`].
@@ -11,6 +11,10 @@ pub const MAX_TEXTURES: usize = 256;
 #[cfg(any(target_arch = "wasm32", target_os = "macos", target_os = "ios", target_os = "android"))]
 pub const MAX_TEXTURES: usize = 16;
 
+/// Sampler state for a scene texture.
+///
+/// The scene keeps one `wgpu::Sampler` per distinct descriptor, so textures
+/// and materials that ask for the same state share it.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct TextureSamplerDesc {
     pub address_mode_u: wgpu::AddressMode,
@@ -19,6 +23,71 @@ pub struct TextureSamplerDesc {
     pub mag_filter: wgpu::FilterMode,
     pub min_filter: wgpu::FilterMode,
     pub mipmap_filter: wgpu::MipmapFilterMode,
+    /// Maximum anisotropy (1–16, 1 = off). Only honoured when all three
+    /// filters are linear; wgpu rejects anisotropy otherwise.
+    pub anisotropy_clamp: u16,
+}
+
+impl TextureSamplerDesc {
+    /// Same address mode on all three axes, trilinear filtering.
+    pub fn with_address_mode(address_mode: wgpu::AddressMode) -> Self {
+        Self {
+            address_mode_u: address_mode,
+            address_mode_v: address_mode,
+            address_mode_w: address_mode,
+            ..Self::default()
+        }
+    }
+
+    /// Nearest-neighbour filtering (pixel art, lookup tables).
+    pub fn nearest(self) -> Self {
+        Self {
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            mipmap_filter: wgpu::MipmapFilterMode::Nearest,
+            anisotropy_clamp: 1,
+            ..self
+        }
+    }
+
+    /// Request anisotropic filtering, switching all filters to linear.
+    pub fn anisotropic(self, anisotropy_clamp: u16) -> Self {
+        Self {
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::MipmapFilterMode::Linear,
+            anisotropy_clamp,
+            ..self
+        }
+    }
+
+    /// Anisotropy actually passed to wgpu: clamped to 1–16, and 1 unless
+    /// every filter is linear.
+    pub fn effective_anisotropy(&self) -> u16 {
+        let all_linear = self.mag_filter == wgpu::FilterMode::Linear
+            && self.min_filter == wgpu::FilterMode::Linear
+            && self.mipmap_filter == wgpu::MipmapFilterMode::Linear;
+        if all_linear {
+            self.anisotropy_clamp.clamp(1, 16)
+        } else {
+            1
+        }
+    }
+
+    /// The `wgpu` descriptor for this sampler state.
+    pub fn to_wgpu<'a>(&self, label: Option<&'a str>) -> wgpu::SamplerDescriptor<'a> {
+        wgpu::SamplerDescriptor {
+            label,
+            address_mode_u: self.address_mode_u,
+            address_mode_v: self.address_mode_v,
+            address_mode_w: self.address_mode_w,
+            mag_filter: self.mag_filter,
+            min_filter: self.min_filter,
+            mipmap_filter: self.mipmap_filter,
+            anisotropy_clamp: self.effective_anisotropy(),
+            ..Default::default()
+        }
+    }
 }
 
 impl Default for TextureSamplerDesc {
@@ -30,6 +99,7 @@ impl Default for TextureSamplerDesc {
             mag_filter: wgpu::FilterMode::Linear,
             min_filter: wgpu::FilterMode::Linear,
             mipmap_filter: wgpu::MipmapFilterMode::Linear,
+            anisotropy_clamp: 1,
         }
     }
 }
@@ -39,7 +109,13 @@ pub struct TextureUpload {
     pub label: Option<String>,
     pub width: u32,
     pub height: u32,
+    /// Any sampleable format, including block-compressed ones (BC1–BC7) when
+    /// the device has `TEXTURE_COMPRESSION_BC`.
     pub format: wgpu::TextureFormat,
+    /// Mip levels contained in `data`, largest first (1 = no mip chain).
+    pub mip_level_count: u32,
+    /// Texel data for every mip level, tightly packed: rows of texel blocks
+    /// with no padding, levels back to back.
     pub data: Vec<u8>,
     pub sampler: TextureSamplerDesc,
 }
@@ -62,10 +138,52 @@ impl TextureUpload {
             } else {
                 wgpu::TextureFormat::Rgba8Unorm
             },
+            mip_level_count: 1,
+            data,
+            sampler,
+        }
+    }
+
+    /// Pre-compressed texture data, e.g. BC7 or BC5 blocks read from a KTX2
+    /// or DDS file, uploaded as-is.
+    ///
+    /// `data` holds `mip_level_count` levels, largest first. Block-compressed
+    /// textures need `width` and `height` to be multiples of the block size
+    /// (4 for BCn); smaller mips are padded to whole blocks.
+    pub fn compressed(
+        label: impl Into<String>,
+        width: u32,
+        height: u32,
+        format: wgpu::TextureFormat,
+        mip_level_count: u32,
+        data: Vec<u8>,
+        sampler: TextureSamplerDesc,
+    ) -> Self {
+        Self {
+            label: Some(label.into()),
+            width,
+            height,
+            format,
+            mip_level_count,
             data,
             sampler,
         }
     }
+
+    /// Byte size `data` must have for this size, format and mip count, or
+    /// `None` for formats that cannot be uploaded from the CPU (depth-stencil).
+    pub fn expected_data_len(&self) -> Option<usize> {
+        let block_size = self.format.block_copy_size(None)? as usize;
+        let (block_w, block_h) = self.format.block_dimensions();
+        let total = (0..self.mip_level_count)
+            .map(|level| {
+                let w = (self.width >> level).max(1);
+                let h = (self.height >> level).max(1);
+                w.div_ceil(block_w) as usize * h.div_ceil(block_h) as usize * block_size
+            })
+            .sum();
+        Some(total)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -90,6 +208,12 @@ pub struct MaterialTextureRef {
     pub texture: TextureId,
     pub uv_channel: u32,
     pub transform: TextureTransform,
+    /// Sampler to use for this slot instead of the texture's own. `None`
+    /// keeps the sampler the texture was inserted with.
+    ///
+    /// An override takes an extra bindless slot per distinct (texture,
+    /// sampler) pair, shared by every material that uses the same pair.
+    pub sampler: Option<TextureSamplerDesc>,
 }
 
 impl MaterialTextureRef {
@@ -98,6 +222,44 @@ impl MaterialTextureRef {
             texture,
             uv_channel: 0,
             transform: TextureTransform::default(),
+            sampler: None,
+        }
+    }
+
+    /// Sample this slot with `sampler` instead of the texture's own sampler.
+    pub fn with_sampler(mut self, sampler: TextureSamplerDesc) -> Self {
+        self.sampler = Some(sampler);
+        self
+    }
+}
+
+/// How a material's normal map encodes its normals.
+///
+/// Tangent-space maps with green up (+Y, OpenGL, glTF, Blender) are the
+/// default. DirectX-style maps (Unreal, 3ds Max, Substance "DirectX" export)
+/// store green down and need `flip_green`. World-space maps (baked object
+/// normals) skip the tangent frame entirely. Picking the wrong one shows up
+/// as lighting that comes from the wrong side on bumps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalMapConvention {
+    TangentSpace { flip_green: bool },
+    WorldSpace,
+}
+
+impl Default for NormalMapConvention {
+    fn default() -> Self {
+        Self::TangentSpace { flip_green: false }
+    }
+}
+
+impl NormalMapConvention {
+    /// Encoding in `GpuMaterialTextures::params.w`. Must match
+    /// `NORMAL_MAP_*` in gbuffer.wgsl.
+    pub(crate) fn as_f32(self) -> f32 {
+        match self {
+            Self::TangentSpace { flip_green: false } => 0.0,
+            Self::TangentSpace { flip_green: true } => 1.0,
+            Self::WorldSpace => 2.0,
         }
     }
 }
@@ -114,6 +276,8 @@ pub struct MaterialTextures {
     pub normal_scale: f32,
     pub occlusion_strength: f32,
     pub alpha_cutoff: f32,
+    /// How `normal` is encoded. Ignored without a normal map.
+    pub normal_map_convention: NormalMapConvention,
 }
 
 impl Default for MaterialTextures {
@@ -129,10 +293,18 @@ impl Default for MaterialTextures {
             normal_scale: 1.0,
             occlusion_strength: 1.0,
             alpha_cutoff: 0.5,
+            normal_map_convention: NormalMapConvention::default(),
         }
     }
 }
 
+impl MaterialTextures {
+    pub fn with_normal_map_convention(mut self, convention: NormalMapConvention) -> Self {
+        self.normal_map_convention = convention;
+        self
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct MaterialAsset {
     pub gpu: GpuMaterial,
@@ -52,7 +52,7 @@ impl PackedVertex {
     }
 }
 
-fn pack_snorm4x8(v: [f32; 4]) -> u32 {
+pub(crate) fn pack_snorm4x8(v: [f32; 4]) -> u32 {
     let to_i8 = |x: f32| -> u32 {
         let clamped = x.clamp(-1.0, 1.0);
         let scaled = (clamped * 127.0).round() as i8;
@@ -62,6 +62,11 @@ fn pack_snorm4x8(v: [f32; 4]) -> u32 {
     to_i8(v[0]) | (to_i8(v[1]) << 8) | (to_i8(v[2]) << 16) | (to_i8(v[3]) << 24)
 }
 
+pub(crate) fn unpack_snorm4x8(packed: u32) -> [f32; 4] {
+    let component = |shift: u32| (((packed >> shift) as u8 as i8) as f32 / 127.0).max(-1.0);
+    [component(0), component(8), component(16), component(24)]
+}
+
 #[derive(Debug, Clone)]
 pub struct MeshUpload {
     pub vertices: Vec<PackedVertex>,
@@ -110,6 +115,117 @@ pub struct MeshBuffers<'a> {
     pub indices: &'a wgpu::Buffer,
 }
 
+// ── Normal recomputation ──────────────────────────────────────────────────────
+
+/// Replace a mesh's vertex normals with face normals averaged across soft
+/// edges only ("autosmooth").
+///
+/// Each triangle corner averages the angle-weighted normals of the faces that
+/// share its position and lie within `smoothing_angle_deg` of the corner's own
+/// face. Creases sharper than the angle therefore stay hard, while curved
+/// surfaces shade smoothly: `0.0` gives fully faceted normals, `180.0` fully
+/// smooth ones. Vertices are matched by position, so meshes that are already
+/// split at UV seams still smooth across them.
+///
+/// A vertex whose corners end up with different normals is split, so the
+/// vertex count can grow; indices are rewritten to match. Tangents are
+/// re-orthogonalised against the new normals and keep their handedness.
+/// Corners of degenerate triangles keep the vertex's existing normal.
+pub fn recompute_normals(mesh: &mut MeshUpload, smoothing_angle_deg: f32) {
+    use glam::Vec3;
+    use std::collections::HashMap;
+
+    let cos_limit = smoothing_angle_deg.clamp(0.0, 180.0).to_radians().cos();
+    let tri_count = mesh.indices.len() / 3;
+
+    // Group vertices sharing a position; -0.0 and 0.0 compare equal.
+    let mut group_of_position: HashMap<[u32; 3], u32> = HashMap::new();
+    let groups: Vec<u32> = mesh
+        .vertices
+        .iter()
+        .map(|v| {
+            let key = v.position.map(|c| (c + 0.0).to_bits());
+            let next = group_of_position.len() as u32;
+            *group_of_position.entry(key).or_insert(next)
+        })
+        .collect();
+
+    // Unit face normals (zero for degenerate faces), the interior angle at
+    // each corner, and the (face, corner) pairs around each position.
+    let mut face_normals = Vec::with_capacity(tri_count);
+    let mut corner_angles = Vec::with_capacity(tri_count);
+    let mut corners_of_group: Vec<Vec<(u32, u8)>> = vec![Vec::new(); group_of_position.len()];
+    for (t, tri) in mesh.indices.chunks_exact(3).enumerate() {
+        let p = [tri[0], tri[1], tri[2]].map(|i| Vec3::from(mesh.vertices[i as usize].position));
+        let (e1, e2) = (p[1] - p[0], p[2] - p[0]);
+        let cross = e1.cross(e2);
+        // Relative test, so slivers left by near-coincident vertices (a UV
+        // sphere's pole, say) don't contribute a direction made of noise.
+        let degenerate =
+            cross.length() <= 1e-6 * e1.length_squared().max(e2.length_squared());
+        face_normals.push(if degenerate { Vec3::ZERO } else { cross.normalize() });
+        corner_angles.push([
+            e1.angle_between(e2),
+            (p[0] - p[1]).angle_between(p[2] - p[1]),
+            (p[0] - p[2]).angle_between(p[1] - p[2]),
+        ]);
+        for (k, &i) in tri.iter().enumerate() {
+            corners_of_group[groups[i as usize] as usize].push((t as u32, k as u8));
+        }
+    }
+
+    // The first corner to reach a vertex claims it with its normal; corners
+    // that need a different normal share one copy per distinct normal.
+    let mut claimed: Vec<Option<u32>> = vec![None; mesh.vertices.len()];
+    let mut copies: HashMap<(u32, u32), u32> = HashMap::new();
+    for t in 0..tri_count {
+        let own = face_normals[t];
+        if own == Vec3::ZERO {
+            continue;
+        }
+        for corner in &mut mesh.indices[t * 3..t * 3 + 3] {
+            let index = *corner;
+            // Angle-weighted, so how a face happens to be triangulated does
+            // not bias the result.
+            let normal = corners_of_group[groups[index as usize] as usize]
+                .iter()
+                .filter(|&&(f, _)| face_normals[f as usize].dot(own) >= cos_limit)
+                .map(|&(f, k)| face_normals[f as usize] * corner_angles[f as usize][k as usize])
+                .sum::<Vec3>()
+                .normalize_or(own);
+            let packed = pack_snorm4x8([normal.x, normal.y, normal.z, 0.0]);
+
+            *corner = match claimed[index as usize] {
+                Some(owner) if owner == packed => index,
+                Some(_) => *copies.entry((index, packed)).or_insert_with(|| {
+                    let copy = with_normal(mesh.vertices[index as usize], normal);
+                    mesh.vertices.push(copy);
+                    (mesh.vertices.len() - 1) as u32
+                }),
+                None => {
+                    claimed[index as usize] = Some(packed);
+                    let v = &mut mesh.vertices[index as usize];
+                    *v = with_normal(*v, normal);
+                    index
+                }
+            };
+        }
+    }
+}
+
+/// `v` with `normal`, its tangent re-orthogonalised to stay in the new
+/// tangent plane.
+fn with_normal(mut v: PackedVertex, normal: glam::Vec3) -> PackedVertex {
+    let [tx, ty, tz, _] = unpack_snorm4x8(v.tangent);
+    let t = glam::Vec3::new(tx, ty, tz);
+    let t = (t - normal * normal.dot(t))
+        .try_normalize()
+        .unwrap_or_else(|| normal.any_orthonormal_vector());
+    v.normal = pack_snorm4x8([normal.x, normal.y, normal.z, 0.0]);
+    v.tangent = pack_snorm4x8([t.x, t.y, t.z, 0.0]);
+    v
+}
+
 // ── Free-list range allocator ─────────────────────────────────────────────────
 
 /// First-fit range allocator with coalescing and tail-trimming.
@@ -475,3 +591,215 @@ impl MeshPool {
         Some(MeshUpload { vertices, indices })
     }
 }
+
+// ── Tangent generation ────────────────────────────────────────────────────────
+
+/// Compute per-vertex tangents from positions, normals and UV0 (Lengyel's
+/// method), replacing whatever the mesh's constructor put there.
+///
+/// Each triangle contributes its texture-space U and V directions to its
+/// three vertices; the sums are orthogonalised against the vertex normal and
+/// the handedness is stored in `bitangent_sign`, so the shader's
+/// `cross(N, T) * bitangent_sign` reproduces the V direction. Vertices are
+/// shared by index, so a mesh split at UV seams keeps separate tangents on
+/// each side.
+///
+/// Triangles with zero area in texture space are skipped. A vertex that only
+/// touches such triangles gets an arbitrary tangent perpendicular to its
+/// normal and a sign of `+1.0`, never NaN.
+pub fn generate_tangents(mesh: &mut MeshUpload) {
+    use glam::{Vec2, Vec3};
+
+    let mut u_dirs = vec![Vec3::ZERO; mesh.vertices.len()];
+    let mut v_dirs = vec![Vec3::ZERO; mesh.vertices.len()];
+    for tri in mesh.indices.chunks_exact(3) {
+        let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| &mesh.vertices[i as usize]);
+        let (e1, e2) = (
+            Vec3::from(b.position) - Vec3::from(a.position),
+            Vec3::from(c.position) - Vec3::from(a.position),
+        );
+        let (d1, d2) = (
+            Vec2::from(b.tex_coords0) - Vec2::from(a.tex_coords0),
+            Vec2::from(c.tex_coords0) - Vec2::from(a.tex_coords0),
+        );
+        let det = d1.x * d2.y - d2.x * d1.y;
+        if det.abs() <= f32::EPSILON {
+            continue;
+        }
+        // Unnormalised, so larger triangles weigh more.
+        let u_dir = (e1 * d2.y - e2 * d1.y) / det;
+        let v_dir = (e2 * d1.x - e1 * d2.x) / det;
+        if !u_dir.is_finite() || !v_dir.is_finite() {
+            continue;
+        }
+        for &i in tri {
+            u_dirs[i as usize] += u_dir;
+            v_dirs[i as usize] += v_dir;
+        }
+    }
+
+    for ((v, u_dir), v_dir) in mesh.vertices.iter_mut().zip(u_dirs).zip(v_dirs) {
+        let [nx, ny, nz, _] = unpack_snorm4x8(v.normal);
+        let normal = Vec3::new(nx, ny, nz).normalize_or(Vec3::Y);
+        let (tangent, sign) = match (u_dir - normal * normal.dot(u_dir)).try_normalize() {
+            Some(t) => (t, if normal.cross(t).dot(v_dir) < 0.0 { -1.0 } else { 1.0 }),
+            None => (normal.any_orthonormal_vector(), 1.0),
+        };
+        v.tangent = pack_snorm4x8([tangent.x, tangent.y, tangent.z, 0.0]);
+        v.bitangent_sign = sign;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec3;
+
+    fn normal_of(v: &PackedVertex) -> Vec3 {
+        let [x, y, z, _] = unpack_snorm4x8(v.normal);
+        Vec3::new(x, y, z)
+    }
+
+    /// Unit cube with its eight corners shared by all six faces, outward winding.
+    fn welded_cube() -> MeshUpload {
+        let vertices = (0..8)
+            .map(|i| {
+                let p = [
+                    if i & 1 == 0 { -0.5 } else { 0.5 },
+                    if i & 2 == 0 { -0.5 } else { 0.5 },
+                    if i & 4 == 0 { -0.5 } else { 0.5 },
+                ];
+                PackedVertex::from_components(p, [0.0, 1.0, 0.0], [0.0, 0.0], [1.0, 0.0, 0.0], 1.0)
+            })
+            .collect();
+        let quads: [[u32; 4]; 6] = [
+            [0, 2, 3, 1], // -z
+            [4, 5, 7, 6], // +z
+            [0, 4, 6, 2], // -x
+            [1, 3, 7, 5], // +x
+            [0, 1, 5, 4], // -y
+            [2, 6, 7, 3], // +y
+        ];
+        let indices = quads
+            .iter()
+            .flat_map(|&[a, b, c, d]| [a, b, c, a, c, d])
+            .collect();
+        MeshUpload { vertices, indices }
+    }
+
+    fn uv_sphere(rings: u32, segments: u32) -> MeshUpload {
+        let mut vertices = Vec::new();
+        for r in 0..=rings {
+            let theta = r as f32 / rings as f32 * std::f32::consts::PI;
+            for s in 0..=segments {
+                let phi = s as f32 / segments as f32 * std::f32::consts::TAU;
+                let p = [theta.sin() * phi.cos(), theta.cos(), theta.sin() * phi.sin()];
+                vertices.push(PackedVertex::from_components(p, [0.0, 1.0, 0.0], [0.0, 0.0], [1.0, 0.0, 0.0], 1.0));
+            }
+        }
+        let stride = segments + 1;
+        let mut indices = Vec::new();
+        for r in 0..rings {
+            for s in 0..segments {
+                let a = r * stride + s;
+                let b = a + stride;
+                // One triangle per cell at the poles, where the other
+                // would collapse onto the pole.
+                if r > 0 {
+                    indices.extend_from_slice(&[a, a + 1, b]);
+                }
+                if r + 1 < rings {
+                    indices.extend_from_slice(&[a + 1, b + 1, b]);
+                }
+            }
+        }
+        MeshUpload { vertices, indices }
+    }
+
+    #[test]
+    fn cube_at_30_degrees_stays_faceted() {
+        let mut cube = welded_cube();
+        recompute_normals(&mut cube, 30.0);
+        // Each corner splits into one vertex per adjoining face.
+        assert_eq!(cube.vertices.len(), 24);
+        for tri in cube.indices.chunks_exact(3) {
+            let p = |i: u32| Vec3::from(cube.vertices[i as usize].position);
+            let face = (p(tri[1]) - p(tri[0])).cross(p(tri[2]) - p(tri[0])).normalize();
+            for &i in tri {
+                assert!(normal_of(&cube.vertices[i as usize]).dot(face) > 0.99);
+            }
+        }
+    }
+
+    #[test]
+    fn cube_at_180_degrees_is_fully_smooth() {
+        let mut cube = welded_cube();
+        recompute_normals(&mut cube, 180.0);
+        assert_eq!(cube.vertices.len(), 8);
+        for v in &cube.vertices {
+            let radial = Vec3::from(v.position).normalize();
+            assert!(normal_of(v).dot(radial) > 0.99);
+        }
+    }
+
+    #[test]
+    fn sphere_at_30_degrees_stays_smooth() {
+        let mut sphere = uv_sphere(16, 32);
+        let count = sphere.vertices.len();
+        recompute_normals(&mut sphere, 30.0);
+        assert_eq!(sphere.vertices.len(), count);
+        for &i in &sphere.indices {
+            let v = &sphere.vertices[i as usize];
+            let radial = Vec3::from(v.position).normalize();
+            assert!(normal_of(v).dot(radial) > 0.98);
+        }
+    }
+
+    fn tangent_of(v: &PackedVertex) -> Vec3 {
+        let [x, y, z, _] = unpack_snorm4x8(v.tangent);
+        Vec3::new(x, y, z)
+    }
+
+    /// Quad in the XY plane facing +Z with the given UVs at its corners
+    /// (-x-y, +x-y, +x+y, -x+y).
+    fn quad(uvs: [[f32; 2]; 4]) -> MeshUpload {
+        let corners = [[-1.0, -1.0, 0.0], [1.0, -1.0, 0.0], [1.0, 1.0, 0.0], [-1.0, 1.0, 0.0]];
+        let vertices = corners
+            .iter()
+            .zip(uvs)
+            .map(|(&p, uv)| PackedVertex::from_components(p, [0.0, 0.0, 1.0], uv, [0.0, 1.0, 0.0], 1.0))
+            .collect();
+        MeshUpload { vertices, indices: vec![0, 1, 2, 0, 2, 3] }
+    }
+
+    #[test]
+    fn tangents_follow_u_and_record_handedness() {
+        let mut plain = quad([[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+        generate_tangents(&mut plain);
+        for v in &plain.vertices {
+            assert!(tangent_of(v).dot(Vec3::X) > 0.99);
+            assert_eq!(v.bitangent_sign, 1.0);
+        }
+
+        // V mirrored: same tangent, flipped bitangent.
+        let mut mirrored = quad([[0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]]);
+        generate_tangents(&mut mirrored);
+        for v in &mirrored.vertices {
+            assert!(tangent_of(v).dot(Vec3::X) > 0.99);
+            assert_eq!(v.bitangent_sign, -1.0);
+        }
+    }
+
+    #[test]
+    fn degenerate_uvs_get_an_orthonormal_tangent() {
+        let mut collapsed = quad([[0.5, 0.5]; 4]);
+        generate_tangents(&mut collapsed);
+        for v in &collapsed.vertices {
+            let t = tangent_of(v);
+            assert!(t.is_finite());
+            assert!((t.length() - 1.0).abs() < 0.02);
+            assert!(t.dot(normal_of(v)).abs() < 0.02);
+            assert_eq!(v.bitangent_sign, 1.0);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! Orbit (arcball-style) camera controller for model viewers and editors.
+//!
+//! An [`OrbitCamera`] circles a target point at a distance, steered by yaw
+//! and pitch. Feed it mouse deltas with [`rotate`](OrbitCamera::rotate),
+//! [`pan`](OrbitCamera::pan) and [`zoom`](OrbitCamera::zoom), then build a
+//! [`Camera`] for [`Scene::update_camera`](crate::Scene::update_camera) each
+//! frame. Everything here is CPU math; the renderer sees an ordinary camera.
+
+use glam::{Mat4, Vec3};
+
+use crate::scene::Camera;
+
+/// Largest pitch magnitude, just short of straight up or down, so the view
+/// never flips over the pole.
+pub const MAX_ORBIT_PITCH: f32 = std::f32::consts::FRAC_PI_2 - 0.01;
+
+/// A camera orbiting `target`.
+///
+/// At zero yaw and pitch the camera sits on the target's +Z side looking
+/// down -Z. Positive yaw swings it to its right around the Y axis; positive
+/// pitch raises it, looking down at the target.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrbitCamera {
+    /// Point the camera looks at and orbits around.
+    pub target: Vec3,
+    /// Distance from the target, kept within `min_distance..=max_distance`.
+    pub distance: f32,
+    /// Rotation around the world Y axis, in radians.
+    pub yaw: f32,
+    /// Elevation above the target's horizontal plane, in radians, kept
+    /// within `±MAX_ORBIT_PITCH`.
+    pub pitch: f32,
+    /// Closest the camera may zoom in.
+    pub min_distance: f32,
+    /// Furthest the camera may zoom out.
+    pub max_distance: f32,
+    /// Near clip plane distance.
+    pub near: f32,
+    /// Far clip plane distance.
+    pub far: f32,
+}
+
+impl OrbitCamera {
+    /// Orbit `target` from `distance` away, at zero yaw and pitch.
+    ///
+    /// Distance limits default to `0.1..=10_000.0` and clip planes to
+    /// `0.1..1000.0`.
+    pub fn new(target: Vec3, distance: f32) -> Self {
+        let mut camera = Self {
+            target,
+            distance,
+            yaw: 0.0,
+            pitch: 0.0,
+            min_distance: 0.1,
+            max_distance: 10_000.0,
+            near: 0.1,
+            far: 1000.0,
+        };
+        camera.distance = camera.clamped_distance(distance);
+        camera
+    }
+
+    /// Limit how far the camera may zoom in and out. The current distance
+    /// is clamped to the new range.
+    pub fn with_distance_limits(mut self, min_distance: f32, max_distance: f32) -> Self {
+        self.min_distance = min_distance.max(0.0);
+        self.max_distance = max_distance.max(self.min_distance);
+        self.distance = self.clamped_distance(self.distance);
+        self
+    }
+
+    /// Set the near and far clip planes used by [`camera`](Self::camera).
+    pub fn with_clip_planes(mut self, near: f32, far: f32) -> Self {
+        self.near = near;
+        self.far = far;
+        self
+    }
+
+    /// Orbit by `dx` radians of yaw and `dy` radians of pitch.
+    pub fn rotate(&mut self, dx: f32, dy: f32) {
+        self.yaw = (self.yaw + dx).rem_euclid(std::f32::consts::TAU);
+        self.pitch = (self.pitch + dy).clamp(-MAX_ORBIT_PITCH, MAX_ORBIT_PITCH);
+    }
+
+    /// Move toward the target by a factor of `exp(delta)`: positive `delta`
+    /// zooms in, negative out. Scaling rather than stepping keeps scroll
+    /// speed proportional at any distance.
+    pub fn zoom(&mut self, delta: f32) {
+        self.distance = self.clamped_distance(self.distance * (-delta).exp());
+    }
+
+    /// Slide the target (and the camera with it) across the view plane.
+    ///
+    /// `dx` and `dy` are in units of the orbit distance, so a drag moves the
+    /// scene by the same fraction of the screen however far out the camera
+    /// is. Positive `dx` moves the target to the camera's right, positive
+    /// `dy` up.
+    pub fn pan(&mut self, dx: f32, dy: f32) {
+        let forward = self.forward();
+        let right = forward.cross(Vec3::Y).normalize();
+        let up = right.cross(forward);
+        self.target += (right * dx + up * dy) * self.distance;
+    }
+
+    /// Unit vector from the camera toward the target.
+    pub fn forward(&self) -> Vec3 {
+        -self.offset_direction()
+    }
+
+    /// Camera position in world space.
+    pub fn position(&self) -> Vec3 {
+        self.target + self.offset_direction() * self.distance
+    }
+
+    /// World-to-camera transform.
+    pub fn view_matrix(&self) -> Mat4 {
+        Mat4::look_at_rh(self.position(), self.target, Vec3::Y)
+    }
+
+    /// Camera for this frame, with the same projection as
+    /// [`Camera::perspective_look_at`].
+    pub fn camera(&self, fov_y_radians: f32, aspect: f32) -> Camera {
+        Camera::perspective_look_at(
+            self.position(),
+            self.target,
+            Vec3::Y,
+            fov_y_radians,
+            aspect,
+            self.near,
+            self.far,
+        )
+    }
+
+    fn offset_direction(&self) -> Vec3 {
+        let (sin_yaw, cos_yaw) = self.yaw.sin_cos();
+        let (sin_pitch, cos_pitch) = self.pitch.sin_cos();
+        Vec3::new(cos_pitch * sin_yaw, sin_pitch, cos_pitch * cos_yaw)
+    }
+
+    fn clamped_distance(&self, distance: f32) -> f32 {
+        distance.clamp(self.min_distance, self.max_distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orbits_at_distance_and_clamps_at_the_poles() {
+        let mut orbit = OrbitCamera::new(Vec3::new(1.0, 2.0, 3.0), 5.0);
+        assert!(orbit.position().abs_diff_eq(Vec3::new(1.0, 2.0, 8.0), 1e-5));
+
+        orbit.rotate(std::f32::consts::FRAC_PI_2, 0.0);
+        assert!(orbit.position().abs_diff_eq(Vec3::new(6.0, 2.0, 3.0), 1e-5));
+
+        orbit.rotate(0.0, 10.0);
+        assert_eq!(orbit.pitch, MAX_ORBIT_PITCH);
+        assert!((orbit.position().distance(orbit.target) - 5.0).abs() < 1e-4);
+        assert!(orbit.view_matrix().is_finite());
+    }
+
+    #[test]
+    fn zoom_and_pan_respect_limits_and_view_plane() {
+        let mut orbit = OrbitCamera::new(Vec3::ZERO, 10.0).with_distance_limits(2.0, 20.0);
+        orbit.zoom(100.0);
+        assert_eq!(orbit.distance, 2.0);
+        orbit.zoom(-100.0);
+        assert_eq!(orbit.distance, 20.0);
+
+        let forward = orbit.forward();
+        orbit.pan(0.1, 0.0);
+        assert!(orbit.target.abs_diff_eq(Vec3::new(2.0, 0.0, 0.0), 1e-5));
+        assert_eq!(orbit.forward(), forward);
+    }
+}
@@ -0,0 +1,91 @@
+//! Procedural base meshes.
+//!
+//! Vertex-displacement features (ocean waves, terrain heightfields, vertex
+//! wind) need vertices to move; a single quad has only its four corners. The
+//! plane builders here generate a regular grid at any resolution, with the
+//! 1×1 case matching the classic 4-vertex plane.
+
+use crate::mesh::{MeshUpload, PackedVertex};
+
+/// A flat `width × depth` quad in the XZ plane, centred on the origin and
+/// facing +Y. Same as [`create_subdivided_plane`] with one cell.
+pub fn create_plane_mesh(width: f32, depth: f32) -> MeshUpload {
+    create_subdivided_plane(width, depth, 1, 1)
+}
+
+/// A `width × depth` grid of `cols × rows` cells in the XZ plane, centred on
+/// the origin and facing +Y.
+///
+/// Produces `(cols + 1) * (rows + 1)` vertices and `6 * cols * rows` indices.
+/// UVs span `[0, 1]` across the whole plane (U along +X, V along +Z), and
+/// triangles wind counter-clockwise when seen from above. Zero counts are
+/// treated as one.
+pub fn create_subdivided_plane(width: f32, depth: f32, cols: u32, rows: u32) -> MeshUpload {
+    let cols = cols.max(1);
+    let rows = rows.max(1);
+    let normal = [0.0, 1.0, 0.0];
+    let tangent = [1.0, 0.0, 0.0];
+
+    let mut vertices = Vec::with_capacity(((cols + 1) * (rows + 1)) as usize);
+    for j in 0..=rows {
+        let v = j as f32 / rows as f32;
+        for i in 0..=cols {
+            let u = i as f32 / cols as f32;
+            let position = [(u - 0.5) * width, 0.0, (v - 0.5) * depth];
+            vertices.push(PackedVertex::from_components(position, normal, [u, v], tangent, 1.0));
+        }
+    }
+
+    let stride = cols + 1;
+    let mut indices = Vec::with_capacity((6 * cols * rows) as usize);
+    for j in 0..rows {
+        for i in 0..cols {
+            let i00 = j * stride + i;
+            let i10 = i00 + 1;
+            let i01 = i00 + stride;
+            let i11 = i01 + 1;
+            indices.extend_from_slice(&[i00, i11, i10, i00, i01, i11]);
+        }
+    }
+    MeshUpload { vertices, indices }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::unpack_snorm4x8;
+    use glam::Vec3;
+
+    #[test]
+    fn subdivided_plane_counts_and_normals() {
+        let mesh = create_subdivided_plane(20.0, 10.0, 10, 10);
+        assert_eq!(mesh.vertices.len(), 121);
+        assert_eq!(mesh.indices.len(), 600);
+        assert!(mesh.indices.iter().all(|&i| i < 121));
+        for v in &mesh.vertices {
+            assert_eq!(v.position[1], 0.0);
+            let n = unpack_snorm4x8(v.normal);
+            assert_eq!([n[0], n[1], n[2]], [0.0, 1.0, 0.0]);
+        }
+        assert_eq!(mesh.vertices[0].position, [-10.0, 0.0, -5.0]);
+        assert_eq!(mesh.vertices[120].position, [10.0, 0.0, 5.0]);
+        assert_eq!(mesh.vertices[120].tex_coords0, [1.0, 1.0]);
+    }
+
+    #[test]
+    fn triangles_face_up() {
+        let mesh = create_subdivided_plane(4.0, 4.0, 3, 2);
+        for tri in mesh.indices.chunks_exact(3) {
+            let p = |i: u32| Vec3::from(mesh.vertices[i as usize].position);
+            let n = (p(tri[1]) - p(tri[0])).cross(p(tri[2]) - p(tri[0]));
+            assert!(n.y > 0.0, "triangle {tri:?} faces down");
+        }
+    }
+
+    #[test]
+    fn unit_plane_is_a_single_quad() {
+        let mesh = create_plane_mesh(2.0, 2.0);
+        assert_eq!(mesh.vertices.len(), 4);
+        assert_eq!(mesh.indices, vec![0, 3, 1, 0, 2, 3]);
+    }
+}
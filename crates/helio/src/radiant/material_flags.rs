@@ -5,6 +5,7 @@
 use libhelio::{
     FLAG_ALPHA_BLEND, FLAG_ALPHA_TEST, FLAG_DOUBLE_SIDED, FLAG_HAS_ANISOTROPY,
     FLAG_HAS_CLEAR_COAT, FLAG_HAS_CUSTOM_SHADER, FLAG_HAS_NORMAL_MAP, FLAG_HAS_SUBSURFACE,
+    FLAG_UNLIT,
 };
 
 /// Check if a material has double-sided rendering enabled.
@@ -46,3 +47,8 @@ pub fn has_anisotropy(flags: u32) -> bool {
 pub fn has_custom_shader(flags: u32) -> bool {
     flags & FLAG_HAS_CUSTOM_SHADER != 0
 }
+
+/// Check if a material is unlit (base colour and emissive only).
+pub fn is_unlit(flags: u32) -> bool {
+    flags & FLAG_UNLIT != 0
+}
@@ -47,10 +47,7 @@ impl RadiantShaderCache {
             #[cfg(target_arch = "wasm32")]
             let source =
                 super::template::RadiantTemplate::apply_webgpu_fixups(&source, max_textures);
-            let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
-                label: Some(label),
-                source: wgpu::ShaderSource::Wgsl(source.into()),
-            });
+            let module = helio_core::shader::module(device, label, &source);
             self.modules.insert(key, module);
         }
         self.modules.get(&key).unwrap()
@@ -1,8 +1,8 @@
 use std::collections::HashMap;
 
 use libhelio::{
-    MATERIAL_CLASS_ANISOTROPIC, MATERIAL_CLASS_CLEAR_COAT, MATERIAL_CLASS_SKIN,
-    MATERIAL_CLASS_SUBSURFACE,
+    MATERIAL_CLASS_ANISOTROPIC, MATERIAL_CLASS_CLEAR_COAT, MATERIAL_CLASS_PATTERN,
+    MATERIAL_CLASS_SKIN, MATERIAL_CLASS_SUBSURFACE, MATERIAL_CLASS_TRIPLANAR,
 };
 
 pub struct RadiantTemplate {
@@ -162,6 +162,14 @@ impl RadiantTemplateRegistry {
             MATERIAL_CLASS_SKIN, "skin",
             include_str!("../../templates/skin.wgsl").to_string(),
         );
+        self.register_partial_str_with_id(
+            MATERIAL_CLASS_PATTERN, "pattern",
+            include_str!("../../templates/pattern.wgsl").to_string(),
+        );
+        self.register_partial_str_with_id(
+            MATERIAL_CLASS_TRIPLANAR, "triplanar",
+            include_str!("../../templates/triplanar.wgsl").to_string(),
+        );
     }
 
     pub fn get(&self, class: u32) -> Option<&RadiantTemplate> {
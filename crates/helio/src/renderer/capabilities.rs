@@ -0,0 +1,168 @@
+//! Device capability queries, so applications can pick settings the device accepts.
+
+use super::renderer_impl::Renderer;
+
+/// What the renderer's device supports, gathered in one place.
+///
+/// Query this before enabling optional features (ray-traced GI, MSAA, shadow
+/// depth clamp, GPU timings) instead of requesting them and relying on the
+/// renderer's fallback path.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HelioCapabilities {
+    /// Hardware ray queries (`EXPERIMENTAL_RAY_QUERY`), needed for ray-traced GI.
+    pub ray_query: bool,
+    /// Highest MSAA sample count usable for both the HDR colour target
+    /// (`Rgba16Float`) and the depth buffer (`Depth32Float`).
+    pub max_msaa_samples: u32,
+    /// Maximum layers of a 2D texture array (caps the shadow atlas).
+    pub max_texture_array_layers: u32,
+    /// Maximum compute workgroup size per dimension.
+    pub max_compute_workgroup_size: [u32; 3],
+    /// Maximum invocations in one compute workgroup.
+    pub max_compute_invocations_per_workgroup: u32,
+    /// Depth formats that can be created on this device.
+    pub depth_formats: Vec<wgpu::TextureFormat>,
+    /// GPU timestamp queries inside command encoders (per-pass GPU timings).
+    pub timestamp_queries: bool,
+    /// Unclipped depth, needed for [`Renderer::set_shadow_depth_clamp`].
+    pub depth_clamp: bool,
+    /// Border-colour addressing, needed for [`Renderer::set_shadow_border_clamp`].
+    pub border_clamp: bool,
+    /// Fragment barycentrics, needed for the shaded wireframe overlay.
+    pub shader_barycentrics: bool,
+    /// GPU-driven draw counts (`MULTI_DRAW_INDIRECT_COUNT`).
+    pub multi_draw_indirect_count: bool,
+    /// BC1–BC7 compressed textures (`TEXTURE_COMPRESSION_BC`), accepted by
+    /// [`Scene::insert_texture`](crate::Scene::insert_texture).
+    pub texture_compression_bc: bool,
+}
+
+impl HelioCapabilities {
+    /// Query `device`. Without an `adapter`, MSAA is reported at the WebGPU
+    /// baseline of 4 samples; pass the adapter the device was created from to
+    /// report adapter-specific sample counts when the device enabled
+    /// `TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES`.
+    pub fn new(device: &wgpu::Device, adapter: Option<&wgpu::Adapter>) -> Self {
+        let features = device.features();
+        let limits = device.limits();
+
+        let max_msaa_samples = match adapter {
+            Some(adapter)
+                if features.contains(wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES) =>
+            {
+                let color = adapter.get_texture_format_features(wgpu::TextureFormat::Rgba16Float);
+                let depth = adapter.get_texture_format_features(wgpu::TextureFormat::Depth32Float);
+                max_sample_count(color.flags & depth.flags)
+            }
+            _ => 4,
+        };
+
+        let mut depth_formats = vec![
+            wgpu::TextureFormat::Depth16Unorm,
+            wgpu::TextureFormat::Depth24Plus,
+            wgpu::TextureFormat::Depth24PlusStencil8,
+            wgpu::TextureFormat::Depth32Float,
+        ];
+        if features.contains(wgpu::Features::DEPTH32FLOAT_STENCIL8) {
+            depth_formats.push(wgpu::TextureFormat::Depth32FloatStencil8);
+        }
+
+        Self {
+            ray_query: features.contains(wgpu::Features::EXPERIMENTAL_RAY_QUERY),
+            max_msaa_samples,
+            max_texture_array_layers: limits.max_texture_array_layers,
+            max_compute_workgroup_size: [
+                limits.max_compute_workgroup_size_x,
+                limits.max_compute_workgroup_size_y,
+                limits.max_compute_workgroup_size_z,
+            ],
+            max_compute_invocations_per_workgroup: limits.max_compute_invocations_per_workgroup,
+            depth_formats,
+            // Same test as the GPU profiler: timings are written from inside encoders.
+            timestamp_queries: features.contains(wgpu::Features::TIMESTAMP_QUERY)
+                && features.contains(wgpu::Features::TIMESTAMP_QUERY_INSIDE_ENCODERS),
+            depth_clamp: features.contains(wgpu::Features::DEPTH_CLIP_CONTROL),
+            border_clamp: features.contains(wgpu::Features::ADDRESS_MODE_CLAMP_TO_BORDER),
+            shader_barycentrics: features.contains(wgpu::Features::SHADER_BARYCENTRICS),
+            multi_draw_indirect_count: features.contains(wgpu::Features::MULTI_DRAW_INDIRECT_COUNT),
+            texture_compression_bc: features.contains(wgpu::Features::TEXTURE_COMPRESSION_BC),
+        }
+    }
+
+    /// Whether `samples` is a usable MSAA sample count.
+    pub fn supports_msaa(&self, samples: u32) -> bool {
+        samples == 1 || (samples.is_power_of_two() && samples <= self.max_msaa_samples)
+    }
+
+    /// Surface formats among `formats` (usually `SurfaceCapabilities::formats`)
+    /// that can carry HDR output, each with the transfer function to pass to
+    /// [`RendererConfig::with_output_transfer`](super::RendererConfig::with_output_transfer).
+    ///
+    /// `Rgba16Float` is scRGB; `Rgb10a2Unorm` is HDR10 only when the platform
+    /// presents it in the PQ colour space, which `wgpu` does not report.
+    pub fn hdr_surface_formats(
+        formats: &[wgpu::TextureFormat],
+    ) -> Vec<(wgpu::TextureFormat, libhelio::OutputTransfer)> {
+        formats
+            .iter()
+            .filter_map(|&format| match format {
+                wgpu::TextureFormat::Rgba16Float => Some((format, libhelio::OutputTransfer::ScRgbLinear)),
+                wgpu::TextureFormat::Rgb10a2Unorm => Some((format, libhelio::OutputTransfer::pq())),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Highest sample count in `flags`, or 1.
+fn max_sample_count(flags: wgpu::TextureFormatFeatureFlags) -> u32 {
+    [16, 8, 4, 2]
+        .into_iter()
+        .find(|&count| flags.sample_count_supported(count))
+        .unwrap_or(1)
+}
+
+impl Renderer {
+    /// Capabilities of the renderer's device.
+    ///
+    /// MSAA is reported from the adapter given to [`set_adapter`](Self::set_adapter),
+    /// or at the WebGPU baseline of 4 samples without one.
+    pub fn capabilities(&self) -> HelioCapabilities {
+        HelioCapabilities::new(&self.device, self.adapter.as_ref())
+    }
+
+    /// Record the adapter the renderer's device was created from, so
+    /// [`capabilities`](Self::capabilities) can report adapter-specific
+    /// sample counts. Cleared by [`reinitialize`](Self::reinitialize).
+    pub fn set_adapter(&mut self, adapter: wgpu::Adapter) {
+        self.adapter = Some(adapter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{max_sample_count, HelioCapabilities};
+    use libhelio::OutputTransfer;
+    use wgpu::TextureFormatFeatureFlags as Flags;
+
+    #[test]
+    fn max_sample_count_picks_highest_supported() {
+        assert_eq!(max_sample_count(Flags::empty()), 1);
+        assert_eq!(max_sample_count(Flags::MULTISAMPLE_X4), 4);
+        assert_eq!(max_sample_count(Flags::MULTISAMPLE_X2 | Flags::MULTISAMPLE_X8), 8);
+    }
+
+    #[test]
+    fn hdr_surface_formats_map_to_transfers() {
+        use wgpu::TextureFormat as F;
+        let hdr = HelioCapabilities::hdr_surface_formats(&[
+            F::Bgra8UnormSrgb,
+            F::Rgba16Float,
+            F::Rgb10a2Unorm,
+        ]);
+        assert_eq!(
+            hdr,
+            [(F::Rgba16Float, OutputTransfer::ScRgbLinear), (F::Rgb10a2Unorm, OutputTransfer::pq())]
+        );
+    }
+}
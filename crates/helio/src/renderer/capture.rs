@@ -0,0 +1,187 @@
+//! Frame capture: copy a rendered color target back to the CPU, e.g. for
+//! golden-image tests and thumbnails.
+//!
+//! [`Renderer::capture_frame`] records a texture-to-buffer copy into the
+//! caller's encoder; once that encoder has been submitted,
+//! [`ReadbackRequest::resolve`] waits for the copy and returns the pixels.
+//! Bytes are returned as stored: an sRGB target yields sRGB-encoded values,
+//! a linear target linear ones, so the output matches the surface format.
+
+use std::sync::Arc;
+
+use helio_core::{Error, Result as HelioResult};
+
+use super::renderer_impl::Renderer;
+
+/// A pending copy of a color target into a mappable buffer.
+///
+/// Created by [`Renderer::capture_frame`].
+#[must_use = "the pixels are only available through `resolve`"]
+pub struct ReadbackRequest {
+    device: Arc<wgpu::Device>,
+    buffer: wgpu::Buffer,
+    width: u32,
+    height: u32,
+    padded_bytes_per_row: u32,
+    bgra: bool,
+}
+
+impl ReadbackRequest {
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Wait for the copy and return the pixels as tightly packed RGBA8
+    /// (`width * height * 4` bytes, top row first). BGRA targets are
+    /// swizzled to RGBA.
+    ///
+    /// Blocks on the device, so submit the encoder passed to
+    /// [`Renderer::capture_frame`] first. Not available on `wasm32`, where
+    /// buffer mapping only completes once control returns to the browser.
+    ///
+    /// # Errors
+    /// [`Error::Gpu`] if the buffer cannot be mapped (e.g. device lost).
+    pub fn resolve(&self) -> HelioResult<Vec<u8>> {
+        let slice = self.buffer.slice(..);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        self.device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .map_err(|e| Error::Gpu(format!("frame capture: {e}")))?;
+        receiver
+            .recv()
+            .map_err(|_| Error::Gpu("frame capture: map callback was dropped".into()))?
+            .map_err(|e| Error::Gpu(format!("frame capture: {e}")))?;
+
+        let pixels = {
+            let mapped = slice
+                .get_mapped_range()
+                .map_err(|e| Error::Gpu(format!("frame capture: {e}")))?;
+            unpack_rows(&mapped, self.width, self.height, self.padded_bytes_per_row, self.bgra)
+        };
+        self.buffer.unmap();
+        Ok(pixels)
+    }
+}
+
+/// Row pitch of a `width`-texel RGBA8 copy, rounded up to wgpu's 256-byte
+/// `bytes_per_row` alignment.
+fn padded_bytes_per_row(width: u32) -> u32 {
+    (width * 4).next_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT)
+}
+
+/// Strip the row padding from a mapped copy, swizzling BGRA to RGBA.
+fn unpack_rows(padded: &[u8], width: u32, height: u32, padded_bytes_per_row: u32, bgra: bool) -> Vec<u8> {
+    let row_bytes = (width * 4) as usize;
+    let mut pixels = Vec::with_capacity(row_bytes * height as usize);
+    for row in padded.chunks(padded_bytes_per_row as usize).take(height as usize) {
+        pixels.extend_from_slice(&row[..row_bytes]);
+    }
+    if bgra {
+        for texel in pixels.chunks_exact_mut(4) {
+            texel.swap(0, 2);
+        }
+    }
+    pixels
+}
+
+impl Renderer {
+    /// Record a copy of `source` (mip 0, layer 0) into a mappable buffer.
+    ///
+    /// `source` is typically the surface texture or an offscreen target the
+    /// frame was rendered to; it must be 8-bit RGBA or BGRA (unorm or sRGB)
+    /// and created with [`wgpu::TextureUsages::COPY_SRC`]. For a surface, add
+    /// `COPY_SRC` to the surface configuration's usage.
+    ///
+    /// Call after [`render`](Self::render), submit `encoder`, then
+    /// [`resolve`](ReadbackRequest::resolve) the request.
+    ///
+    /// # Errors
+    /// [`Error::InvalidPassConfig`] if `source` has an unsupported format or
+    /// lacks `COPY_SRC`.
+    pub fn capture_frame(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        source: &wgpu::Texture,
+    ) -> HelioResult<ReadbackRequest> {
+        let bgra = match source.format() {
+            wgpu::TextureFormat::Rgba8Unorm | wgpu::TextureFormat::Rgba8UnormSrgb => false,
+            wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb => true,
+            format => {
+                return Err(Error::InvalidPassConfig(format!(
+                    "capture_frame needs an 8-bit RGBA or BGRA target, got {format:?}"
+                )))
+            }
+        };
+        if !source.usage().contains(wgpu::TextureUsages::COPY_SRC) {
+            return Err(Error::InvalidPassConfig(
+                "capture_frame source texture was not created with COPY_SRC".into(),
+            ));
+        }
+
+        let (width, height) = (source.width(), source.height());
+        let padded_bytes_per_row = padded_bytes_per_row(width);
+        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Frame Capture Readback"),
+            size: padded_bytes_per_row as u64 * height as u64,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        encoder.copy_texture_to_buffer(
+            wgpu::TexelCopyTextureInfo {
+                texture: source,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::TexelCopyBufferInfo {
+                buffer: &buffer,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_bytes_per_row),
+                    rows_per_image: Some(height),
+                },
+            },
+            wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+        );
+
+        Ok(ReadbackRequest {
+            device: Arc::clone(&self.device),
+            buffer,
+            width,
+            height,
+            padded_bytes_per_row,
+            bgra,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_are_unpadded_and_bgra_is_swizzled() {
+        assert_eq!(padded_bytes_per_row(64), 256);
+        assert_eq!(padded_bytes_per_row(65), 512);
+
+        // 2x2 BGRA image with 256-byte rows; only the first 8 bytes of each
+        // row are texels.
+        let pitch = padded_bytes_per_row(2);
+        let mut padded = vec![0xAA; (pitch * 2) as usize];
+        padded[..8].copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        padded[pitch as usize..pitch as usize + 8].copy_from_slice(&[9, 10, 11, 12, 13, 14, 15, 16]);
+
+        assert_eq!(
+            unpack_rows(&padded, 2, 2, pitch, true),
+            [3, 2, 1, 4, 7, 6, 5, 8, 11, 10, 9, 12, 15, 14, 13, 16]
+        );
+        assert_eq!(unpack_rows(&padded, 2, 2, pitch, false)[..8], [1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+}
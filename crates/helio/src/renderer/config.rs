@@ -11,6 +11,49 @@ pub enum PerfOverlayMode {
     PassOutput = 4,
 }
 
+/// Swapchain presentation mode requested by the application.
+///
+/// Not every mode is available on every platform, so the request is resolved
+/// against the surface's capabilities with [`PresentMode::resolve`] whenever
+/// the surface is (re)configured:
+///
+/// | Requested   | Fallback order                    |
+/// |-------------|-----------------------------------|
+/// | `Vsync`     | `Fifo` (always supported)         |
+/// | `Mailbox`   | `Mailbox` → `Fifo`                |
+/// | `Immediate` | `Immediate` → `Mailbox` → `Fifo`  |
+///
+/// `Mailbox` never falls back to `Immediate`, since asking for it means the
+/// application wants low latency *without* tearing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PresentMode {
+    /// Present on vertical blank; frame rate is capped to the display.
+    #[default]
+    Vsync,
+    /// Low-latency triple buffering: the newest frame replaces any queued one
+    /// at vertical blank. Uncapped, no tearing.
+    Mailbox,
+    /// Present as soon as a frame is ready. Uncapped, may tear.
+    Immediate,
+}
+
+impl PresentMode {
+    /// Pick the wgpu present mode to configure the surface with, given the
+    /// modes reported by `Surface::get_capabilities`.
+    pub fn resolve(self, supported: &[wgpu::PresentMode]) -> wgpu::PresentMode {
+        let chain: &[wgpu::PresentMode] = match self {
+            PresentMode::Vsync => &[],
+            PresentMode::Mailbox => &[wgpu::PresentMode::Mailbox],
+            PresentMode::Immediate => &[wgpu::PresentMode::Immediate, wgpu::PresentMode::Mailbox],
+        };
+        chain
+            .iter()
+            .copied()
+            .find(|mode| supported.contains(mode))
+            .unwrap_or(wgpu::PresentMode::Fifo)
+    }
+}
+
 pub fn required_wgpu_features(adapter_features: wgpu::Features) -> wgpu::Features {
     #[cfg(not(target_arch = "wasm32"))]
     let required = wgpu::Features::TEXTURE_BINDING_ARRAY
@@ -19,8 +62,13 @@ pub fn required_wgpu_features(adapter_features: wgpu::Features) -> wgpu::Feature
     #[cfg(target_arch = "wasm32")]
     let required = wgpu::Features::INDIRECT_FIRST_INSTANCE;
     let mut optional = wgpu::Features::MULTI_DRAW_INDIRECT_COUNT | // compacted indirect count buffer
+        wgpu::Features::DEPTH_CLIP_CONTROL | // shadow depth clamp
+        wgpu::Features::ADDRESS_MODE_CLAMP_TO_BORDER | // shadow border clamp
+        wgpu::Features::SHADER_BARYCENTRICS | // shaded wireframe overlay
         wgpu::Features::TIMESTAMP_QUERY | // GPU profiling timestamp queries
         wgpu::Features::TIMESTAMP_QUERY_INSIDE_ENCODERS | // GPU profiling timestamps via encoder
+        wgpu::Features::TEXTURE_COMPRESSION_BC | // pre-compressed BCn texture uploads
+        wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES | // adapter MSAA sample counts
         wgpu::Features::VERTEX_WRITABLE_STORAGE;
     // Request ray tracing if available (native only, requires Vulkan)
     #[cfg(not(target_arch = "wasm32"))]
@@ -69,7 +117,7 @@ pub fn required_experimental_features(adapter_features: wgpu::Features) -> wgpu:
 
 #[cfg(test)]
 mod tests {
-    use super::{required_wgpu_features, RendererConfig};
+    use super::{required_wgpu_features, PresentMode, RendererConfig};
 
     #[test]
     fn indirect_first_instance_is_required_even_when_adapter_does_not_report_it() {
@@ -82,6 +130,7 @@ mod tests {
         let requested = required_wgpu_features(wgpu::Features::empty());
         assert!(!requested.contains(wgpu::Features::MULTI_DRAW_INDIRECT_COUNT));
         assert!(!requested.contains(wgpu::Features::TIMESTAMP_QUERY));
+        assert!(!requested.contains(wgpu::Features::ADDRESS_MODE_CLAMP_TO_BORDER));
     }
 
     #[test]
@@ -90,6 +139,31 @@ mod tests {
         assert_eq!((config.width, config.height), (1, 1));
         assert_eq!((config.internal_width(), config.internal_height()), (1, 1));
     }
+
+    #[test]
+    fn present_mode_uses_requested_mode_when_supported() {
+        let all = [
+            wgpu::PresentMode::Fifo,
+            wgpu::PresentMode::Mailbox,
+            wgpu::PresentMode::Immediate,
+        ];
+        assert_eq!(PresentMode::Vsync.resolve(&all), wgpu::PresentMode::Fifo);
+        assert_eq!(PresentMode::Mailbox.resolve(&all), wgpu::PresentMode::Mailbox);
+        assert_eq!(PresentMode::Immediate.resolve(&all), wgpu::PresentMode::Immediate);
+    }
+
+    #[test]
+    fn present_mode_falls_back_when_unsupported() {
+        let fifo_only = [wgpu::PresentMode::Fifo];
+        assert_eq!(PresentMode::Mailbox.resolve(&fifo_only), wgpu::PresentMode::Fifo);
+        assert_eq!(PresentMode::Immediate.resolve(&fifo_only), wgpu::PresentMode::Fifo);
+
+        let no_mailbox = [wgpu::PresentMode::Fifo, wgpu::PresentMode::Immediate];
+        assert_eq!(PresentMode::Mailbox.resolve(&no_mailbox), wgpu::PresentMode::Fifo);
+
+        let no_immediate = [wgpu::PresentMode::Fifo, wgpu::PresentMode::Mailbox];
+        assert_eq!(PresentMode::Immediate.resolve(&no_immediate), wgpu::PresentMode::Mailbox);
+    }
 }
 
 pub fn required_wgpu_limits(adapter_limits: wgpu::Limits) -> wgpu::Limits {
@@ -156,6 +230,39 @@ pub struct RendererConfig {
     /// reserves six consecutive faces. A capacity of 32 supports five lights
     /// while keeping the two 1024px browser atlases to 256 MiB total.
     pub shadow_face_capacity: u32,
+    /// Size each caster's shadow faces by its screen coverage under a budget.
+    /// `None` (the default) renders every caster at `shadow_atlas_size`.
+    pub adaptive_shadow_resolution: Option<libhelio::AdaptiveShadowResolution>,
+    /// Requested swapchain present mode. Applied by whoever owns the surface,
+    /// resolved against its capabilities via [`PresentMode::resolve`].
+    pub present_mode: PresentMode,
+    /// Quality tier broadcast to every pass after the graph is built. `None`
+    /// leaves each pass at the settings it was constructed with.
+    pub quality_preset: Option<helio_core::QualityPreset>,
+    /// Clamp shadow-caster depth to the light's near plane instead of
+    /// clipping. Needs `DEPTH_CLIP_CONTROL`; ignored with a warning without it.
+    pub shadow_depth_clamp: bool,
+    /// Treat samples outside a shadow face as lit instead of clamping to the
+    /// edge texel, which smears shadows past a spotlight's cone. Needs
+    /// `ADDRESS_MODE_CLAMP_TO_BORDER`; ignored with a warning without it.
+    pub shadow_border_clamp: bool,
+    /// Which end of the depth range is near in the shadow atlases. Reversed
+    /// depth keeps more float precision for distant casters.
+    pub depth_convention: libhelio::DepthConvention,
+    /// Transfer function applied as the last post-process step. `None`
+    /// derives it from `surface_format`
+    /// ([`OutputTransfer::for_surface_format`](libhelio::OutputTransfer::for_surface_format));
+    /// HDR10 swapchains need an explicit [`OutputTransfer::Pq`](libhelio::OutputTransfer::Pq).
+    pub output_transfer: Option<libhelio::OutputTransfer>,
+    /// Estimated GPU memory, in bytes, above which the renderer logs a
+    /// warning. See [`Renderer::set_gpu_memory_budget`](super::Renderer::set_gpu_memory_budget).
+    pub gpu_memory_budget: Option<u64>,
+    /// Frames the GPU may still be rendering while the CPU records the next
+    /// one (`1..=3`, default 2). `1` waits for each frame to finish before
+    /// returning from `render`. Only enforced when the renderer owns the
+    /// device; configure the surface's `desired_maximum_frame_latency` to
+    /// the same value.
+    pub frames_in_flight: u32,
 }
 
 impl RendererConfig {
@@ -171,6 +278,15 @@ impl RendererConfig {
             perf_overlay_mode: PerfOverlayMode::Disabled,
             shadow_atlas_size: 1024,
             shadow_face_capacity: 32,
+            adaptive_shadow_resolution: None,
+            present_mode: PresentMode::Vsync,
+            quality_preset: None,
+            shadow_depth_clamp: false,
+            shadow_border_clamp: false,
+            depth_convention: libhelio::DepthConvention::Standard,
+            output_transfer: None,
+            gpu_memory_budget: None,
+            frames_in_flight: helio_core::DEFAULT_FRAMES_IN_FLIGHT,
         }
     }
 
@@ -194,11 +310,61 @@ impl RendererConfig {
         self
     }
 
+    /// Apply a [`QualityPreset`](helio_core::QualityPreset): sets the shadow
+    /// filtering tier and atlas size here, and the per-pass knobs (GI, SSAO)
+    /// once the graph exists.
+    pub fn with_quality_preset(mut self, preset: helio_core::QualityPreset) -> Self {
+        self.shadow_quality = preset.shadow_quality();
+        self.shadow_atlas_size = preset.shadow_atlas_size();
+        self.quality_preset = Some(preset);
+        self
+    }
+
+    pub fn with_shadow_depth_clamp(mut self, enabled: bool) -> Self {
+        self.shadow_depth_clamp = enabled;
+        self
+    }
+
+    pub fn with_shadow_border_clamp(mut self, enabled: bool) -> Self {
+        self.shadow_border_clamp = enabled;
+        self
+    }
+
+    pub fn with_depth_convention(mut self, convention: libhelio::DepthConvention) -> Self {
+        self.depth_convention = convention;
+        self
+    }
+
+    pub fn with_output_transfer(mut self, transfer: libhelio::OutputTransfer) -> Self {
+        self.output_transfer = Some(transfer);
+        self
+    }
+
+    pub fn with_gpu_memory_budget(mut self, bytes: u64) -> Self {
+        self.gpu_memory_budget = Some(bytes);
+        self
+    }
+
+    pub fn with_present_mode(mut self, mode: PresentMode) -> Self {
+        self.present_mode = mode;
+        self
+    }
+
+    pub fn with_frames_in_flight(mut self, frames: u32) -> Self {
+        self.frames_in_flight = frames.clamp(1, helio_core::MAX_FRAMES_IN_FLIGHT);
+        self
+    }
+
     pub fn with_shadow_face_capacity(mut self, capacity: u32) -> Self {
         self.shadow_face_capacity = capacity.clamp(1, 256);
         self
     }
 
+    pub fn with_adaptive_shadow_resolution(mut self, adaptive: libhelio::AdaptiveShadowResolution) -> Self {
+        self.adaptive_shadow_resolution = Some(adaptive);
+        self
+    }
+
     pub fn internal_width(&self) -> u32 {
         (((self.width as f32) * self.render_scale).ceil() as u32).max(1)
     }
@@ -1,8 +1,10 @@
+use std::ops::Range;
 use std::sync::{Arc, Mutex};
 
 use helio_core::{PassContext, PrepareContext, RenderPass, Result as HelioResult};
+use libhelio::DepthMode;
 
-use super::renderer_impl::{DebugBatch, DebugVertex, Renderer};
+use super::renderer_impl::{DebugBatch, DebugPoint, DebugVertex, Renderer};
 
 pub struct DebugDrawState {
     pub editor_enabled: bool,
@@ -11,6 +13,8 @@ pub struct DebugDrawState {
     pub user_lines_generation: u64,
     pub user_tris: Vec<DebugVertex>,
     pub user_tris_generation: u64,
+    pub user_points: Vec<DebugPoint>,
+    pub user_points_generation: u64,
     /// Wireframe bounds of every scene volume, rebuilt by the renderer while
     /// the editor overlay is on. Kept separate from `user_lines` because the
     /// editor path deliberately ignores those.
@@ -18,9 +22,121 @@ pub struct DebugDrawState {
     /// Bumped whenever `editor_volume_lines` changes, so the pass can keep
     /// caching its uploads instead of re-sending the set every frame.
     pub editor_volume_generation: u64,
+    /// Draw every light's influence volume outside the editor overlay. See
+    /// [`Renderer::set_light_debug`].
+    pub light_debug: bool,
+    /// Light volume wireframes, rebuilt by the renderer while `light_debug`
+    /// is on and drawn after the user lines.
+    pub light_debug_lines: Vec<DebugVertex>,
+    /// Bumped whenever `light_debug_lines` changes.
+    pub light_debug_generation: u64,
     /// Color-blind mode for axis/gizmo colors:
     /// 0=None, 1=Protanopia, 2=Deuteranopia, 3=Tritanopia, 4=Achromatopsia
     pub color_blind_mode: u8,
+    /// Triangle edges drawn over the shaded scene, or `None` for off.
+    pub shaded_wireframe: Option<ShadedWireframe>,
+    /// Depth mode for user primitives queued from now on. Change it with
+    /// [`DebugDrawState::set_depth_mode`] so earlier primitives keep theirs.
+    pub depth_mode: DepthMode,
+    /// Where each depth mode took effect: the mode and the `user_lines`,
+    /// `user_tris` and `user_points` lengths at that moment. Always starts
+    /// at zero, so every queued primitive falls in exactly one span.
+    pub(crate) depth_switches: Vec<(DepthMode, [usize; 3])>,
+}
+
+/// Debug primitives default to testing against the scene without writing
+/// depth, so lines never hide the fills queued after them.
+const DEFAULT_DEBUG_DEPTH_MODE: DepthMode = DepthMode::TestNoWrite;
+
+const SWITCH_LINES: usize = 0;
+const SWITCH_TRIS: usize = 1;
+const SWITCH_POINTS: usize = 2;
+
+impl DebugDrawState {
+    /// Queue subsequent user primitives with `mode`. Already-queued
+    /// primitives keep the mode they were added under.
+    pub fn set_depth_mode(&mut self, mode: DepthMode) {
+        if mode == self.depth_mode {
+            return;
+        }
+        let lens = [self.user_lines.len(), self.user_tris.len(), self.user_points.len()];
+        match self.depth_switches.last_mut() {
+            // Nothing was queued under the previous mode; just replace it.
+            Some(last) if last.1 == lens => last.0 = mode,
+            _ => self.depth_switches.push((mode, lens)),
+        }
+        self.depth_mode = mode;
+    }
+
+    /// Forget the recorded spans after the user queues have been emptied.
+    /// The current mode carries over to whatever is queued next.
+    pub(crate) fn reset_depth_switches(&mut self) {
+        self.depth_switches.clear();
+        self.depth_switches.push((self.depth_mode, [0; 3]));
+    }
+
+    /// Spans of one user queue (`SWITCH_*`) grouped by depth mode.
+    fn depth_runs(&self, slot: usize, len: usize) -> Vec<DepthRun> {
+        let mut runs = Vec::with_capacity(self.depth_switches.len());
+        for (i, &(mode, starts)) in self.depth_switches.iter().enumerate() {
+            let start = starts[slot].min(len);
+            let end = self
+                .depth_switches
+                .get(i + 1)
+                .map_or(len, |next| next.1[slot].min(len));
+            if start < end {
+                runs.push(DepthRun {
+                    mode,
+                    range: start as u32..end as u32,
+                });
+            }
+        }
+        runs
+    }
+}
+
+/// A span of one debug vertex/instance buffer drawn with a single depth mode.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct DepthRun {
+    mode: DepthMode,
+    range: Range<u32>,
+}
+
+/// Clamp `runs` to the uploaded `count`, treating an empty list as the whole
+/// buffer in the default mode.
+fn draw_runs(runs: &[DepthRun], count: u32) -> impl Iterator<Item = (DepthMode, Range<u32>)> + '_ {
+    let whole = runs
+        .is_empty()
+        .then_some((DEFAULT_DEBUG_DEPTH_MODE, 0..count));
+    whole
+        .into_iter()
+        .chain(runs.iter().map(move |run| {
+            (run.mode, run.range.start.min(count)..run.range.end.min(count))
+        }))
+        .filter(|(_, range)| !range.is_empty())
+}
+
+/// Shaded-wireframe view: the normally lit scene with every triangle edge
+/// drawn on top, as in a DCC tool's "wireframe on shaded" mode.
+///
+/// Edges come from fragment barycentrics, so this needs
+/// `wgpu::Features::SHADER_BARYCENTRICS`; without it the overlay is skipped
+/// with a warning.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShadedWireframe {
+    /// Edge color; alpha is the overlay opacity.
+    pub color: [f32; 4],
+    /// Edge width in pixels.
+    pub thickness: f32,
+}
+
+impl Default for ShadedWireframe {
+    fn default() -> Self {
+        Self {
+            color: [0.0, 0.0, 0.0, 0.8],
+            thickness: 1.0,
+        }
+    }
 }
 
 impl Default for DebugDrawState {
@@ -32,51 +148,50 @@ impl Default for DebugDrawState {
             user_lines_generation: 0,
             user_tris: Vec::new(),
             user_tris_generation: 0,
+            user_points: Vec::new(),
+            user_points_generation: 0,
             editor_volume_lines: Vec::new(),
             editor_volume_generation: 0,
+            light_debug: false,
+            light_debug_lines: Vec::new(),
+            light_debug_generation: 0,
             color_blind_mode: 0,
+            shaded_wireframe: None,
+            depth_mode: DEFAULT_DEBUG_DEPTH_MODE,
+            depth_switches: vec![(DEFAULT_DEBUG_DEPTH_MODE, [0; 3])],
         }
     }
 }
 
 const MAX_DEBUG_VERTS: u32 = 65536;
 const MAX_DEBUG_TRIS: u32 = 65536;
+const MAX_DEBUG_POINTS: u32 = 16384;
 
-pub struct DebugPass {
-    pipeline_depth: wgpu::RenderPipeline,
+/// Point-sprite pipelines and buffers, created on the first non-empty point
+/// upload so renderers that never draw points pay nothing for them.
+struct DebugPointResources {
+    /// One pipeline per [`DepthMode`], indexed by `DepthMode::index`.
+    pipelines_depth: [wgpu::RenderPipeline; 3],
     pipeline_no_depth: wgpu::RenderPipeline,
-    pipeline_tri_depth: wgpu::RenderPipeline,
-    pipeline_tri_no_depth: wgpu::RenderPipeline,
-    #[allow(dead_code)]
-    bgl: wgpu::BindGroupLayout,
-    camera_buf: wgpu::Buffer,
-    bind_group: Option<wgpu::BindGroup>,
-    bind_group_key: Option<usize>,
-    vertex_buf: wgpu::Buffer,
-    pub vertex_count: u32,
-    tri_buf: wgpu::Buffer,
-    pub tri_count: u32,
-    depth_test_enabled: bool,
-    /// Test against the internal-res scene depth (and therefore draw into the
-    /// internal-res lit image) rather than the output-res dummy depth.
-    /// Required for real occlusion — see `render_pass_descriptor`.
-    use_scene_depth: bool,
+    /// Viewport size in pixels, for sizing sprites in screen space.
+    params_buf: wgpu::Buffer,
+    params_bind_group: wgpu::BindGroup,
+    instance_buf: wgpu::Buffer,
 }
 
-impl DebugPass {
-    pub fn new(
+impl DebugPointResources {
+    fn new(
         device: &wgpu::Device,
-        camera_buf: &wgpu::Buffer,
+        camera_bgl: &wgpu::BindGroupLayout,
         target_format: wgpu::TextureFormat,
-        depth_test: bool,
     ) -> Self {
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
-            label: Some("Debug Draw Shader"),
+            label: Some("Debug Point Shader"),
             source: wgpu::ShaderSource::Wgsl(include_str!("../../shaders/debug_draw.wgsl").into()),
         });
 
-        let bgl = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
-            label: Some("Debug Draw BGL"),
+        let params_bgl = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Debug Point BGL"),
             entries: &[wgpu::BindGroupLayoutEntry {
                 binding: 0,
                 visibility: wgpu::ShaderStages::VERTEX,
@@ -88,42 +203,183 @@ impl DebugPass {
                 count: None,
             }],
         });
+        let params_buf = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Debug Point Params"),
+            size: std::mem::size_of::<[f32; 4]>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let params_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Debug Point BG"),
+            layout: &params_bgl,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: params_buf.as_entire_binding(),
+            }],
+        });
+        let instance_buf = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Debug Point Buffer"),
+            size: (MAX_DEBUG_POINTS as usize * std::mem::size_of::<DebugPoint>()) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
 
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
-            label: Some("Debug Draw PL"),
-            bind_group_layouts: &[Some(&bgl)],
+            label: Some("Debug Point PL"),
+            bind_group_layouts: &[Some(camera_bgl), Some(&params_bgl)],
             immediate_size: 0,
         });
+        let attribs = [
+            wgpu::VertexAttribute {
+                format: wgpu::VertexFormat::Float32x4,
+                offset: 0,
+                shader_location: 0,
+            },
+            wgpu::VertexAttribute {
+                format: wgpu::VertexFormat::Float32x4,
+                offset: 16,
+                shader_location: 1,
+            },
+        ];
+        let vbl = wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<DebugPoint>() as u64,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &attribs,
+        };
+        let target = wgpu::ColorTargetState {
+            format: target_format,
+            blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+            write_mask: wgpu::ColorWrites::ALL,
+        };
+        let create = |label: &str, depth: Option<DepthMode>| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some("vs_point"),
+                    compilation_options: Default::default(),
+                    buffers: &[Some(vbl.clone())],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some("fs_point"),
+                    compilation_options: Default::default(),
+                    targets: &[Some(target.clone())],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    cull_mode: None,
+                    ..Default::default()
+                },
+                depth_stencil: depth.map(|mode| {
+                    mode.depth_stencil_state(
+                        wgpu::TextureFormat::Depth32Float,
+                        wgpu::CompareFunction::LessEqual,
+                    )
+                }),
+                multisample: wgpu::MultisampleState::default(),
+                multiview_mask: None,
+                cache: None,
+            })
+        };
+
+        Self {
+            pipelines_depth: DepthMode::ALL
+                .map(|mode| create(&format!("Debug Point Pipeline {mode:?}"), Some(mode))),
+            pipeline_no_depth: create("Debug Point Pipeline NoDepth", None),
+            params_buf,
+            params_bind_group,
+            instance_buf,
+        }
+    }
+}
+
+/// Shaded-wireframe pipeline and bindings, created the first time the
+/// overlay is switched on.
+struct WireframeResources {
+    pipeline: wgpu::RenderPipeline,
+    scene_bgl: wgpu::BindGroupLayout,
+    scene_bind_group: Option<wgpu::BindGroup>,
+    scene_bind_group_key: Option<(usize, usize)>,
+    params_buf: wgpu::Buffer,
+    params_bind_group: wgpu::BindGroup,
+}
+
+impl WireframeResources {
+    fn new(device: &wgpu::Device, target_format: wgpu::TextureFormat) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Wireframe Overlay Shader"),
+            source: wgpu::ShaderSource::Wgsl(
+                include_str!("../../shaders/wireframe_overlay.wgsl").into(),
+            ),
+        });
 
-        let vertex_buf = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("Debug Vertex Buffer"),
-            size: (MAX_DEBUG_VERTS as usize * std::mem::size_of::<DebugVertex>()) as u64,
-            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+        let buffer_entry = |binding, visibility, ty| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility,
+            ty: wgpu::BindingType::Buffer {
+                ty,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let scene_bgl = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Wireframe Overlay Scene BGL"),
+            entries: &[
+                buffer_entry(0, wgpu::ShaderStages::VERTEX, wgpu::BufferBindingType::Uniform),
+                buffer_entry(
+                    1,
+                    wgpu::ShaderStages::VERTEX,
+                    wgpu::BufferBindingType::Storage { read_only: true },
+                ),
+            ],
+        });
+        let params_bgl = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Wireframe Overlay Params BGL"),
+            entries: &[buffer_entry(
+                0,
+                wgpu::ShaderStages::FRAGMENT,
+                wgpu::BufferBindingType::Uniform,
+            )],
+        });
+        let params_buf = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Wireframe Overlay Params"),
+            size: std::mem::size_of::<[f32; 8]>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
             mapped_at_creation: false,
         });
+        let params_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Wireframe Overlay Params BG"),
+            layout: &params_bgl,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: params_buf.as_entire_binding(),
+            }],
+        });
 
-        let pipeline_depth = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Debug Draw Pipeline Depth"),
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Wireframe Overlay PL"),
+            bind_group_layouts: &[Some(&scene_bgl), Some(&params_bgl)],
+            immediate_size: 0,
+        });
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Wireframe Overlay Pipeline"),
             layout: Some(&pipeline_layout),
             vertex: wgpu::VertexState {
                 module: &shader,
                 entry_point: Some("vs_main"),
                 compilation_options: Default::default(),
+                // Position only, from the shared 40-byte PackedVertex stream.
                 buffers: &[Some(wgpu::VertexBufferLayout {
-                    array_stride: std::mem::size_of::<DebugVertex>() as u64,
+                    array_stride: 40,
                     step_mode: wgpu::VertexStepMode::Vertex,
-                    attributes: &[
-                        wgpu::VertexAttribute {
-                            format: wgpu::VertexFormat::Float32x3,
-                            offset: 0,
-                            shader_location: 0,
-                        },
-                        wgpu::VertexAttribute {
-                            format: wgpu::VertexFormat::Float32x4,
-                            offset: 16,
-                            shader_location: 1,
-                        },
-                    ],
+                    attributes: &[wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Float32x3,
+                        offset: 0,
+                        shader_location: 0,
+                    }],
                 })],
             },
             fragment: Some(wgpu::FragmentState {
@@ -132,12 +388,13 @@ impl DebugPass {
                 compilation_options: Default::default(),
                 targets: &[Some(wgpu::ColorTargetState {
                     format: target_format,
-                    blend: None,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
             }),
             primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::LineList,
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                cull_mode: Some(wgpu::Face::Back),
                 ..Default::default()
             },
             depth_stencil: Some(wgpu::DepthStencilState {
@@ -145,58 +402,155 @@ impl DebugPass {
                 depth_write_enabled: Some(false),
                 depth_compare: Some(wgpu::CompareFunction::LessEqual),
                 stencil: wgpu::StencilState::default(),
-                bias: wgpu::DepthBiasState::default(),
+                // Pull the overlay slightly toward the camera so it wins
+                // against the identical surface already in the depth buffer.
+                bias: wgpu::DepthBiasState {
+                    constant: -2,
+                    slope_scale: -1.0,
+                    clamp: 0.0,
+                },
             }),
             multisample: wgpu::MultisampleState::default(),
             multiview_mask: None,
             cache: None,
         });
 
-        let pipeline_no_depth = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Debug Draw Pipeline NoDepth"),
-            layout: Some(&pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &shader,
-                entry_point: Some("vs_main"),
-                compilation_options: Default::default(),
-                buffers: &[Some(wgpu::VertexBufferLayout {
-                    array_stride: std::mem::size_of::<DebugVertex>() as u64,
-                    step_mode: wgpu::VertexStepMode::Vertex,
-                    attributes: &[
-                        wgpu::VertexAttribute {
-                            format: wgpu::VertexFormat::Float32x3,
-                            offset: 0,
-                            shader_location: 0,
-                        },
-                        wgpu::VertexAttribute {
-                            format: wgpu::VertexFormat::Float32x4,
-                            offset: 16,
-                            shader_location: 1,
-                        },
-                    ],
-                })],
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &shader,
-                entry_point: Some("fs_main"),
-                compilation_options: Default::default(),
-                targets: &[Some(wgpu::ColorTargetState {
-                    format: target_format,
-                    blend: None,
-                    write_mask: wgpu::ColorWrites::ALL,
-                })],
-            }),
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::LineList,
-                ..Default::default()
-            },
-            depth_stencil: None,
-            multisample: wgpu::MultisampleState::default(),
-            multiview_mask: None,
-            cache: None,
+        Self {
+            pipeline,
+            scene_bgl,
+            scene_bind_group: None,
+            scene_bind_group_key: None,
+            params_buf,
+            params_bind_group,
+        }
+    }
+
+    fn upload(&self, queue: &wgpu::Queue, settings: &ShadedWireframe) {
+        let mut params = [0.0f32; 8];
+        params[..4].copy_from_slice(&settings.color);
+        params[4] = settings.thickness;
+        queue.write_buffer(&self.params_buf, 0, bytemuck::cast_slice(&params));
+    }
+
+    /// Redraw the scene's opaque geometry into the active render pass.
+    fn draw(&mut self, ctx: &mut PassContext) {
+        let draw_count = ctx.scene.draw_count;
+        let Some(main_scene) = ctx.resources.main_scene.as_ref() else {
+            return;
+        };
+        if draw_count == 0 {
+            return;
+        }
+        let key = (
+            ctx.scene.camera as *const _ as usize,
+            ctx.scene.instances as *const _ as usize,
+        );
+        if self.scene_bind_group_key != Some(key) {
+            self.scene_bind_group = Some(ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Wireframe Overlay Scene BG"),
+                layout: &self.scene_bgl,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: ctx.scene.camera.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: ctx.scene.instances.as_entire_binding(),
+                    },
+                ],
+            }));
+            self.scene_bind_group_key = Some(key);
+        }
+        let indirect = ctx.scene.indirect;
+
+        let Some(rp) = ctx.active_render_pass_ptr() else {
+            return;
+        };
+        let rp = unsafe { &mut *rp };
+        rp.set_pipeline(&self.pipeline);
+        rp.set_bind_group(0, self.scene_bind_group.as_ref().unwrap(), &[]);
+        rp.set_bind_group(1, &self.params_bind_group, &[]);
+        rp.set_vertex_buffer(0, main_scene.mesh_buffers.vertices.slice(..));
+        rp.set_index_buffer(main_scene.mesh_buffers.indices.slice(..), wgpu::IndexFormat::Uint32);
+        #[cfg(not(target_arch = "wasm32"))]
+        rp.multi_draw_indexed_indirect(indirect, 0, draw_count);
+        #[cfg(target_arch = "wasm32")]
+        for i in 0..draw_count {
+            rp.draw_indexed_indirect(indirect, i as u64 * 20);
+        }
+    }
+}
+
+pub struct DebugPass {
+    /// Line pipelines per [`DepthMode`], indexed by `DepthMode::index`.
+    line_pipelines: [wgpu::RenderPipeline; 3],
+    pipeline_no_depth: wgpu::RenderPipeline,
+    tri_pipelines: [wgpu::RenderPipeline; 3],
+    pipeline_tri_no_depth: wgpu::RenderPipeline,
+    bgl: wgpu::BindGroupLayout,
+    camera_buf: wgpu::Buffer,
+    bind_group: Option<wgpu::BindGroup>,
+    bind_group_key: Option<usize>,
+    vertex_buf: wgpu::Buffer,
+    pub vertex_count: u32,
+    tri_buf: wgpu::Buffer,
+    pub tri_count: u32,
+    points: Option<DebugPointResources>,
+    pub point_count: u32,
+    /// Depth mode per span of each buffer; empty draws the whole buffer in
+    /// the default mode.
+    line_runs: Vec<DepthRun>,
+    tri_runs: Vec<DepthRun>,
+    point_runs: Vec<DepthRun>,
+    target_format: wgpu::TextureFormat,
+    depth_test_enabled: bool,
+    /// Test against the internal-res scene depth (and therefore draw into the
+    /// internal-res lit image) rather than the output-res dummy depth.
+    /// Required for real occlusion — see `render_pass_descriptor`.
+    use_scene_depth: bool,
+}
+
+impl DebugPass {
+    pub fn new(
+        device: &wgpu::Device,
+        camera_buf: &wgpu::Buffer,
+        target_format: wgpu::TextureFormat,
+        depth_test: bool,
+    ) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Debug Draw Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../../shaders/debug_draw.wgsl").into()),
+        });
+
+        let bgl = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Debug Draw BGL"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Debug Draw PL"),
+            bind_group_layouts: &[Some(&bgl)],
+            immediate_size: 0,
+        });
+
+        let vertex_buf = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Debug Vertex Buffer"),
+            size: (MAX_DEBUG_VERTS as usize * std::mem::size_of::<DebugVertex>()) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
         });
 
-        let tri_attribs = [
+        let attribs = [
             wgpu::VertexAttribute {
                 format: wgpu::VertexFormat::Float32x3,
                 offset: 0,
@@ -208,10 +562,19 @@ impl DebugPass {
                 shader_location: 1,
             },
         ];
-        let tri_vbl = wgpu::VertexBufferLayout {
+        let vbl = wgpu::VertexBufferLayout {
             array_stride: std::mem::size_of::<DebugVertex>() as u64,
             step_mode: wgpu::VertexStepMode::Vertex,
-            attributes: &tri_attribs,
+            attributes: &attribs,
+        };
+        let line_target = wgpu::ColorTargetState {
+            format: target_format,
+            blend: None,
+            write_mask: wgpu::ColorWrites::ALL,
+        };
+        let line_prim = wgpu::PrimitiveState {
+            topology: wgpu::PrimitiveTopology::LineList,
+            ..Default::default()
         };
         let tri_target = wgpu::ColorTargetState {
             format: target_format,
@@ -223,57 +586,60 @@ impl DebugPass {
             cull_mode: None,
             ..Default::default()
         };
-
-        let pipeline_tri_depth = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Debug Tri Pipeline Depth"),
-            layout: Some(&pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &shader,
-                entry_point: Some("vs_main"),
-                compilation_options: Default::default(),
-                buffers: &[Some(tri_vbl.clone())],
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &shader,
-                entry_point: Some("fs_main"),
-                compilation_options: Default::default(),
-                targets: &[Some(tri_target.clone())],
-            }),
-            primitive: tri_prim,
-            depth_stencil: Some(wgpu::DepthStencilState {
-                format: wgpu::TextureFormat::Depth32Float,
-                depth_write_enabled: Some(false),
-                depth_compare: Some(wgpu::CompareFunction::LessEqual),
-                stencil: wgpu::StencilState::default(),
-                bias: wgpu::DepthBiasState::default(),
-            }),
-            multisample: wgpu::MultisampleState::default(),
-            multiview_mask: None,
-            cache: None,
-        });
-
-        let pipeline_tri_no_depth =
+        // `depth: None` builds the variant used when the pass has no depth
+        // attachment at all (depth test switched off for the whole pass).
+        let create = |label: &str,
+                      target: &wgpu::ColorTargetState,
+                      primitive: wgpu::PrimitiveState,
+                      depth: Option<DepthMode>| {
             device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-                label: Some("Debug Tri Pipeline NoDepth"),
+                label: Some(label),
                 layout: Some(&pipeline_layout),
                 vertex: wgpu::VertexState {
                     module: &shader,
                     entry_point: Some("vs_main"),
                     compilation_options: Default::default(),
-                    buffers: &[Some(tri_vbl)],
+                    buffers: &[Some(vbl.clone())],
                 },
                 fragment: Some(wgpu::FragmentState {
                     module: &shader,
                     entry_point: Some("fs_main"),
                     compilation_options: Default::default(),
-                    targets: &[Some(tri_target)],
+                    targets: &[Some(target.clone())],
+                }),
+                primitive,
+                depth_stencil: depth.map(|mode| {
+                    mode.depth_stencil_state(
+                        wgpu::TextureFormat::Depth32Float,
+                        wgpu::CompareFunction::LessEqual,
+                    )
                 }),
-                primitive: tri_prim,
-                depth_stencil: None,
                 multisample: wgpu::MultisampleState::default(),
                 multiview_mask: None,
                 cache: None,
-            });
+            })
+        };
+
+        let line_pipelines = DepthMode::ALL.map(|mode| {
+            create(
+                &format!("Debug Draw Pipeline {mode:?}"),
+                &line_target,
+                line_prim,
+                Some(mode),
+            )
+        });
+        let pipeline_no_depth =
+            create("Debug Draw Pipeline NoDepth", &line_target, line_prim, None);
+        let tri_pipelines = DepthMode::ALL.map(|mode| {
+            create(
+                &format!("Debug Tri Pipeline {mode:?}"),
+                &tri_target,
+                tri_prim,
+                Some(mode),
+            )
+        });
+        let pipeline_tri_no_depth =
+            create("Debug Tri Pipeline NoDepth", &tri_target, tri_prim, None);
 
         let tri_buf = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Debug Tri Buffer"),
@@ -283,9 +649,9 @@ impl DebugPass {
         });
 
         Self {
-            pipeline_depth,
+            line_pipelines,
             pipeline_no_depth,
-            pipeline_tri_depth,
+            tri_pipelines,
             pipeline_tri_no_depth,
             bgl,
             camera_buf: camera_buf.clone(),
@@ -295,6 +661,12 @@ impl DebugPass {
             vertex_count: 0,
             tri_buf,
             tri_count: 0,
+            points: None,
+            point_count: 0,
+            line_runs: Vec::new(),
+            tri_runs: Vec::new(),
+            point_runs: Vec::new(),
+            target_format,
             depth_test_enabled: depth_test,
             use_scene_depth: false,
         }
@@ -359,9 +731,44 @@ impl DebugPass {
         self.tri_count = count as u32;
     }
 
+    /// Upload point sprites, building the point pipelines on first use.
+    /// `viewport` is the target size in pixels that point sizes refer to.
+    pub fn update_points(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        points: &[DebugPoint],
+        viewport: [u32; 2],
+    ) {
+        let count = points.len().min(MAX_DEBUG_POINTS as usize);
+        if count > 0 {
+            let resources = self.points.get_or_insert_with(|| {
+                DebugPointResources::new(device, &self.bgl, self.target_format)
+            });
+            helio_core::upload::write_buffer(
+                queue,
+                &resources.instance_buf,
+                0,
+                bytemuck::cast_slice(&points[..count]),
+            );
+            let params = [viewport[0].max(1) as f32, viewport[1].max(1) as f32, 0.0, 0.0];
+            helio_core::upload::write_buffer(
+                queue,
+                &resources.params_buf,
+                0,
+                bytemuck::cast_slice(&params),
+            );
+        }
+        self.point_count = count as u32;
+    }
+
     pub fn clear(&mut self) {
         self.vertex_count = 0;
         self.tri_count = 0;
+        self.point_count = 0;
+        self.line_runs.clear();
+        self.tri_runs.clear();
+        self.point_runs.clear();
     }
 
     pub fn set_depth_test(&mut self, enabled: bool) {
@@ -373,24 +780,43 @@ impl DebugPass {
     fn draw_commands(&self, rp: &mut wgpu::RenderPass) {
         rp.set_bind_group(0, self.bind_group.as_ref().unwrap(), &[]);
 
+        // Without a depth attachment every mode collapses to the no-depth
+        // variant; otherwise each span picks its own pre-built pipeline.
         if self.vertex_count > 0 {
-            if self.depth_test_enabled {
-                rp.set_pipeline(&self.pipeline_depth);
-            } else {
-                rp.set_pipeline(&self.pipeline_no_depth);
-            }
             rp.set_vertex_buffer(0, self.vertex_buf.slice(..));
-            rp.draw(0..self.vertex_count, 0..1);
+            for (mode, range) in draw_runs(&self.line_runs, self.vertex_count) {
+                if self.depth_test_enabled {
+                    rp.set_pipeline(&self.line_pipelines[mode.index()]);
+                } else {
+                    rp.set_pipeline(&self.pipeline_no_depth);
+                }
+                rp.draw(range, 0..1);
+            }
         }
 
         if self.tri_count > 0 {
-            if self.depth_test_enabled {
-                rp.set_pipeline(&self.pipeline_tri_depth);
-            } else {
-                rp.set_pipeline(&self.pipeline_tri_no_depth);
-            }
             rp.set_vertex_buffer(0, self.tri_buf.slice(..));
-            rp.draw(0..self.tri_count, 0..1);
+            for (mode, range) in draw_runs(&self.tri_runs, self.tri_count) {
+                if self.depth_test_enabled {
+                    rp.set_pipeline(&self.tri_pipelines[mode.index()]);
+                } else {
+                    rp.set_pipeline(&self.pipeline_tri_no_depth);
+                }
+                rp.draw(range, 0..1);
+            }
+        }
+
+        if let Some(points) = self.points.as_ref().filter(|_| self.point_count > 0) {
+            rp.set_bind_group(1, &points.params_bind_group, &[]);
+            rp.set_vertex_buffer(0, points.instance_buf.slice(..));
+            for (mode, range) in draw_runs(&self.point_runs, self.point_count) {
+                if self.depth_test_enabled {
+                    rp.set_pipeline(&points.pipelines_depth[mode.index()]);
+                } else {
+                    rp.set_pipeline(&points.pipeline_no_depth);
+                }
+                rp.draw(0..6, range);
+            }
         }
     }
 
@@ -414,7 +840,7 @@ impl DebugPass {
         ctx: &mut PassContext,
         target: &wgpu::TextureView,
     ) -> HelioResult<()> {
-        if self.vertex_count == 0 && self.tri_count == 0 {
+        if self.vertex_count == 0 && self.tri_count == 0 && self.point_count == 0 {
             return Ok(());
         }
 
@@ -532,7 +958,7 @@ impl RenderPass for DebugPass {
     }
 
     fn execute(&mut self, ctx: &mut PassContext) -> HelioResult<()> {
-        if self.vertex_count == 0 && self.tri_count == 0 {
+        if self.vertex_count == 0 && self.tri_count == 0 && self.point_count == 0 {
             return Ok(());
         }
         self.ensure_bind_group(ctx.device);
@@ -547,12 +973,20 @@ pub struct DebugDrawPass {
     state: Arc<Mutex<DebugDrawState>>,
     editor_mode: bool,
     cached_line_gen: u64,
+    cached_light_gen: u64,
     cached_tri_gen: u64,
+    cached_point_gen: u64,
+    /// Viewport the uploaded point sizes were resolved against.
+    cached_point_viewport: [u32; 2],
     editor_grid_cache: Vec<DebugVertex>,
     editor_marker_lines: [DebugVertex; 6],
     editor_last_key: Option<(bool, i32, i32, i32)>,
     editor_last_cam: Option<[f32; 3]>,
     editor_last_volume_gen: Option<u64>,
+    wireframe: Option<WireframeResources>,
+    /// Settings currently uploaded to `wireframe`, or `None` when off.
+    wireframe_settings: Option<ShadedWireframe>,
+    wireframe_unsupported_warned: bool,
 }
 
 impl DebugDrawPass {
@@ -573,7 +1007,10 @@ impl DebugDrawPass {
             state,
             editor_mode,
             cached_line_gen: u64::MAX,
+            cached_light_gen: u64::MAX,
             cached_tri_gen: u64::MAX,
+            cached_point_gen: u64::MAX,
+            cached_point_viewport: [0, 0],
             editor_grid_cache: Vec::new(),
             editor_marker_lines: [DebugVertex {
                 position: [0.0, 0.0, 0.0],
@@ -583,6 +1020,9 @@ impl DebugDrawPass {
             editor_last_key: None,
             editor_last_cam: None,
             editor_last_volume_gen: None,
+            wireframe: None,
+            wireframe_settings: None,
+            wireframe_unsupported_warned: false,
         }
     }
 
@@ -590,6 +1030,32 @@ impl DebugDrawPass {
         self.pass.set_depth_test(enabled);
     }
 
+    /// Track the requested shaded-wireframe settings. Only the instance that
+    /// tests against scene depth draws the overlay; hidden edges would show
+    /// through anywhere else.
+    fn prepare_wireframe(&mut self, ctx: &PrepareContext, requested: Option<ShadedWireframe>) {
+        let requested = requested.filter(|_| self.pass.use_scene_depth);
+        let Some(settings) = requested else {
+            self.wireframe_settings = None;
+            return;
+        };
+        if !ctx.device.features().contains(wgpu::Features::SHADER_BARYCENTRICS) {
+            if !self.wireframe_unsupported_warned {
+                log::warn!("helio: shaded wireframe needs SHADER_BARYCENTRICS, which this device lacks");
+                self.wireframe_unsupported_warned = true;
+            }
+            return;
+        }
+        let target_format = self.pass.target_format;
+        let resources = self
+            .wireframe
+            .get_or_insert_with(|| WireframeResources::new(ctx.device, target_format));
+        if self.wireframe_settings != Some(settings) {
+            resources.upload(ctx.queue, &settings);
+            self.wireframe_settings = Some(settings);
+        }
+    }
+
     fn rebuild_editor_grid_cache(&mut self, center_x: f32, center_z: f32, grid_step: f32, color_blind_mode: u8) {
         self.editor_grid_cache.clear();
 
@@ -749,6 +1215,7 @@ impl RenderPass for DebugDrawPass {
     fn prepare(&mut self, ctx: &PrepareContext) -> HelioResult<()> {
         let state_arc = Arc::clone(&self.state);
         let state = state_arc.lock().unwrap();
+        self.prepare_wireframe(ctx, state.shaded_wireframe);
 
         if self.editor_mode {
             let editor_enabled = state.editor_enabled;
@@ -764,6 +1231,8 @@ impl RenderPass for DebugDrawPass {
                 self.editor_last_cam = None;
                 self.pass.update_tris(ctx.queue, &[]);
                 self.cached_tri_gen = 0;
+                self.pass.point_count = 0;
+                self.cached_point_gen = 0;
                 return Ok(());
             }
 
@@ -836,20 +1305,53 @@ impl RenderPass for DebugDrawPass {
                 self.pass.update_tris(ctx.queue, &[]);
                 self.cached_tri_gen = 0;
             }
+            self.pass.point_count = 0;
+            self.cached_point_gen = 0;
             return Ok(());
         }
 
         let user_lines_generation = state.user_lines_generation;
         let user_tris_generation = state.user_tris_generation;
 
-        if user_lines_generation != self.cached_line_gen {
-            self.pass.update_lines(ctx.queue, &state.user_lines);
+        let light_debug_generation = state.light_debug_generation;
+
+        if user_lines_generation != self.cached_line_gen
+            || light_debug_generation != self.cached_light_gen
+        {
+            let user_len = state.user_lines.len();
+            let mut runs = state.depth_runs(SWITCH_LINES, user_len);
+            if state.light_debug_lines.is_empty() {
+                self.pass.update_lines(ctx.queue, &state.user_lines);
+            } else {
+                // Light volumes share the line buffer, after the user lines,
+                // in a span of their own so user depth switches never apply.
+                let mut lines = Vec::with_capacity(user_len + state.light_debug_lines.len());
+                lines.extend_from_slice(&state.user_lines);
+                lines.extend_from_slice(&state.light_debug_lines);
+                self.pass.update_lines(ctx.queue, &lines);
+                runs.push(DepthRun {
+                    mode: DEFAULT_DEBUG_DEPTH_MODE,
+                    range: user_len as u32..lines.len() as u32,
+                });
+            }
+            self.pass.line_runs = runs;
             self.cached_line_gen = user_lines_generation;
+            self.cached_light_gen = light_debug_generation;
         }
         if user_tris_generation != self.cached_tri_gen {
             self.pass.update_tris(ctx.queue, &state.user_tris);
+            self.pass.tri_runs = state.depth_runs(SWITCH_TRIS, state.user_tris.len());
             self.cached_tri_gen = user_tris_generation;
         }
+        let viewport = [ctx.width, ctx.height];
+        if state.user_points_generation != self.cached_point_gen
+            || (self.pass.point_count > 0 && viewport != self.cached_point_viewport)
+        {
+            self.pass.update_points(ctx.device, ctx.queue, &state.user_points, viewport);
+            self.pass.point_runs = state.depth_runs(SWITCH_POINTS, state.user_points.len());
+            self.cached_point_gen = state.user_points_generation;
+            self.cached_point_viewport = viewport;
+        }
         Ok(())
     }
 
@@ -858,7 +1360,13 @@ impl RenderPass for DebugDrawPass {
         // from render_pass_descriptor() by this point, so reassigning
         // ctx.target would change nothing. Where this draws is decided purely
         // by where the pass sits in the graph.
-        self.pass.execute(ctx)
+        self.pass.execute(ctx)?;
+        if self.wireframe_settings.is_some() {
+            if let Some(wireframe) = self.wireframe.as_mut() {
+                wireframe.draw(ctx);
+            }
+        }
+        Ok(())
     }
 }
 
@@ -873,9 +1381,32 @@ impl Renderer {
                 s.user_tris_generation = s.user_tris_generation.wrapping_add(1);
             }
             s.user_tris.clear();
+            if !s.user_points.is_empty() {
+                s.user_points_generation = s.user_points_generation.wrapping_add(1);
+            }
+            s.user_points.clear();
+            s.reset_depth_switches();
         }
     }
 
+    /// Depth mode for debug lines, triangles and points queued after this
+    /// call: `TestNoWrite` (the default) hides them behind scene geometry,
+    /// `NoTestNoWrite` draws them on top of everything, and `Normal` also
+    /// lets them occlude each other. Primitives already queued keep theirs.
+    pub fn set_debug_depth_mode(&mut self, mode: DepthMode) {
+        if let Ok(mut s) = self.debug_state.lock() {
+            s.set_depth_mode(mode);
+        }
+    }
+
+    /// Depth mode applied to newly queued debug primitives.
+    pub fn debug_depth_mode(&self) -> DepthMode {
+        self.debug_state
+            .lock()
+            .map(|s| s.depth_mode)
+            .unwrap_or(DEFAULT_DEBUG_DEPTH_MODE)
+    }
+
     pub fn debug_batch<F>(&mut self, f: F)
     where
         F: FnOnce(&mut DebugBatch<'_>),
@@ -885,6 +1416,7 @@ impl Renderer {
                 state: &mut s,
                 lines_changed: false,
                 tris_changed: false,
+                points_changed: false,
             };
             f(&mut batch);
             batch.finish();
@@ -907,6 +1439,131 @@ impl Renderer {
         }
     }
 
+    /// Draw each light's influence volume: a cone for spot lights (outer
+    /// angle, attenuation range), a sphere for point and area lights, and a
+    /// direction arrow for directional lights.
+    ///
+    /// The wireframes are rebuilt from the scene every frame while enabled,
+    /// so they follow lights as they move. The editor overlay outlines light
+    /// volumes on its own, so this has no effect while editor mode is on.
+    pub fn set_light_debug(&mut self, enabled: bool) {
+        if let Ok(mut s) = self.debug_state.lock() {
+            s.light_debug = enabled;
+        }
+    }
+
+    /// Whether light influence volumes are drawn.
+    pub fn light_debug(&self) -> bool {
+        self.debug_state.lock().map(|s| s.light_debug).unwrap_or(false)
+    }
+
+    /// Replace the lit image with a light-complexity heatmap: each 16×16
+    /// culling tile is tinted by how many lights reach it, green for few and
+    /// red for many, with magenta marking tiles whose light list is full.
+    ///
+    /// A legend strip along the bottom edge shows one swatch per band of
+    /// [`LIGHT_COMPLEXITY_LEGEND`](libhelio::LIGHT_COMPLEXITY_LEGEND), which
+    /// also gives the light counts for labelling it in a UI. This is a debug
+    /// mode, so it replaces any other active one; disabling it returns to
+    /// normal rendering.
+    pub fn set_light_complexity_debug(&mut self, enabled: bool) {
+        if enabled {
+            self.set_debug_mode(libhelio::DEBUG_MODE_LIGHT_COMPLEXITY);
+        } else if self.light_complexity_debug() {
+            self.set_debug_mode(0);
+        }
+    }
+
+    /// Whether the light-complexity heatmap is shown.
+    pub fn light_complexity_debug(&self) -> bool {
+        self.debug_mode == libhelio::DEBUG_MODE_LIGHT_COMPLEXITY
+    }
+
+    /// Replace the lit image with a face-orientation view: front faces green,
+    /// back faces red, and front faces with inverted normals yellow. Back-face
+    /// culling is off while it is shown, so a mesh with flipped winding shows
+    /// up red instead of vanishing.
+    ///
+    /// `show_normals` additionally blends the world normal direction into
+    /// each face as RGB. Like other debug modes this replaces any active one;
+    /// disabling it returns to normal rendering.
+    pub fn set_face_orientation_debug(&mut self, enabled: bool, show_normals: bool) {
+        if enabled {
+            self.set_debug_mode(if show_normals {
+                libhelio::DEBUG_MODE_FACE_ORIENTATION_NORMALS
+            } else {
+                libhelio::DEBUG_MODE_FACE_ORIENTATION
+            });
+        } else if self.face_orientation_debug() {
+            self.set_debug_mode(0);
+        }
+    }
+
+    /// Whether the face-orientation view is shown.
+    pub fn face_orientation_debug(&self) -> bool {
+        self.debug_mode == libhelio::DEBUG_MODE_FACE_ORIENTATION
+            || self.debug_mode == libhelio::DEBUG_MODE_FACE_ORIENTATION_NORMALS
+    }
+
+    /// Replace the lit image with the irradiance the radiance cascades deliver
+    /// to each pixel, before albedo, to check that GI injection and
+    /// propagation reach the surfaces they should. Pixels stay black when the
+    /// active pipeline runs no radiance cascades.
+    ///
+    /// Like other debug modes this replaces any active one; disabling it
+    /// returns to normal rendering.
+    pub fn set_gi_irradiance_debug(&mut self, enabled: bool) {
+        if enabled {
+            self.set_debug_mode(libhelio::DEBUG_MODE_GI_IRRADIANCE);
+        } else if self.gi_irradiance_debug() {
+            self.set_debug_mode(0);
+        }
+    }
+
+    /// Whether the radiance-cascade irradiance view is shown.
+    pub fn gi_irradiance_debug(&self) -> bool {
+        self.debug_mode == libhelio::DEBUG_MODE_GI_IRRADIANCE
+    }
+
+    /// Draw every triangle edge over the shaded scene, or pass `None` to turn
+    /// the overlay off. See [`ShadedWireframe`].
+    pub fn set_shaded_wireframe(&mut self, wireframe: Option<ShadedWireframe>) {
+        if let Ok(mut s) = self.debug_state.lock() {
+            s.shaded_wireframe = wireframe;
+        }
+    }
+
+    /// Append a batch of line segments `(from, to, color)`.
+    pub fn draw_lines(&mut self, lines: &[(glam::Vec3, glam::Vec3, [f32; 4])]) {
+        if lines.is_empty() {
+            return;
+        }
+        if let Ok(mut s) = self.debug_state.lock() {
+            s.user_lines.reserve(lines.len() * 2);
+            for &(from, to, color) in lines {
+                s.user_lines.push(DebugVertex { position: from.to_array(), _pad: 0.0, color });
+                s.user_lines.push(DebugVertex { position: to.to_array(), _pad: 0.0, color });
+            }
+            s.user_lines_generation = s.user_lines_generation.wrapping_add(1);
+        }
+    }
+
+    /// Append a batch of round, screen-facing points `(position, size, color)`,
+    /// with `size` the diameter in pixels.
+    pub fn draw_points(&mut self, points: &[(glam::Vec3, f32, [f32; 4])]) {
+        if points.is_empty() {
+            return;
+        }
+        if let Ok(mut s) = self.debug_state.lock() {
+            s.user_points.extend(points.iter().map(|&(position, size, color)| DebugPoint {
+                position: position.to_array(),
+                size,
+                color,
+            }));
+            s.user_points_generation = s.user_points_generation.wrapping_add(1);
+        }
+    }
+
     pub fn debug_tri(&mut self, v0: [f32; 3], v1: [f32; 3], v2: [f32; 3], color: [f32; 4]) {
         if let Ok(mut s) = self.debug_state.lock() {
             s.user_tris.push(DebugVertex {
@@ -1227,3 +1884,50 @@ impl Renderer {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vert() -> DebugVertex {
+        DebugVertex {
+            position: [0.0; 3],
+            _pad: 0.0,
+            color: [1.0; 4],
+        }
+    }
+
+    #[test]
+    fn depth_switches_split_queued_lines_into_runs() {
+        let mut state = DebugDrawState::default();
+        state.user_lines.extend([vert(); 4]);
+        state.set_depth_mode(DepthMode::NoTestNoWrite);
+        state.user_lines.extend([vert(); 2]);
+        // A switch with nothing queued under it leaves no empty span behind.
+        state.set_depth_mode(DepthMode::Normal);
+        state.set_depth_mode(DepthMode::TestNoWrite);
+        state.user_lines.extend([vert(); 2]);
+
+        let runs = state.depth_runs(SWITCH_LINES, state.user_lines.len());
+        assert_eq!(
+            runs,
+            vec![
+                DepthRun { mode: DepthMode::TestNoWrite, range: 0..4 },
+                DepthRun { mode: DepthMode::NoTestNoWrite, range: 4..6 },
+                DepthRun { mode: DepthMode::TestNoWrite, range: 6..8 },
+            ]
+        );
+        assert!(state.depth_runs(SWITCH_TRIS, 0).is_empty());
+    }
+
+    #[test]
+    fn reset_keeps_current_mode_for_next_queue() {
+        let mut state = DebugDrawState::default();
+        state.set_depth_mode(DepthMode::NoTestNoWrite);
+        state.reset_depth_switches();
+        state.user_tris.extend([vert(); 3]);
+
+        let runs = state.depth_runs(SWITCH_TRIS, 3);
+        assert_eq!(runs, vec![DepthRun { mode: DepthMode::NoTestNoWrite, range: 0..3 }]);
+    }
+}
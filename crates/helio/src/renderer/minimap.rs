@@ -0,0 +1,667 @@
+//! Top-down orthographic minimap rendered into a scene texture.
+//!
+//! The minimap is a separate, flat-shaded draw of the scene geometry (base
+//! colour, one fixed light, no shadows or post-processing) from a camera
+//! looking straight down. It writes into a texture from the scene's texture
+//! pool, so UI code displays it like any other [`TextureId`].
+//!
+//! Draw calls are expanded to their full instance counts by a small compute
+//! pass first; the main camera's culled indirect buffer would drop everything
+//! outside the player's view.
+
+use bytemuck::{Pod, Zeroable};
+use glam::{Mat4, Vec3};
+
+use crate::handles::TextureId;
+use crate::scene::{Result as SceneResult, Scene};
+
+use super::renderer_impl::Renderer;
+
+/// [`ObjectDescriptor::flags`](crate::ObjectDescriptor::flags) bit marking an
+/// object as minimap geometry (terrain, markers). Only consulted when
+/// [`Renderer::set_minimap_flagged_only`] is on.
+pub const OBJECT_FLAG_MINIMAP: u32 = 1 << 2;
+
+/// Height of the captured slab above and below the minimap centre, in metres.
+const MINIMAP_HALF_DEPTH: f32 = 500.0;
+const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+const WORKGROUP_SIZE: u32 = 64;
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct MinimapUniforms {
+    view_proj: [[f32; 4]; 4],
+    light_dir: [f32; 4],
+    /// x = flagged only, y = flag mask, z = draw count.
+    params: [u32; 4],
+}
+
+impl MinimapUniforms {
+    fn new(center: Vec3, extent: f32, flagged_only: bool, draw_count: u32) -> Self {
+        let eye = center + Vec3::Y * MINIMAP_HALF_DEPTH;
+        // -Z up on screen: north (forward in a right-handed Y-up world) is up.
+        let view = Mat4::look_at_rh(eye, center, Vec3::NEG_Z);
+        let e = extent.max(f32::EPSILON);
+        let proj = Mat4::orthographic_rh(-e, e, -e, e, 0.0, 2.0 * MINIMAP_HALF_DEPTH);
+        Self {
+            view_proj: (proj * view).to_cols_array_2d(),
+            light_dir: Vec3::new(0.3, 1.0, 0.2).normalize().extend(0.0).to_array(),
+            params: [flagged_only as u32, OBJECT_FLAG_MINIMAP, draw_count, 0],
+        }
+    }
+}
+
+pub(crate) struct Minimap {
+    center: Vec3,
+    extent: f32,
+    size: u32,
+    /// Render every `update_interval` frames; 1 renders every frame.
+    update_interval: u32,
+    flagged_only: bool,
+    /// Frames since the texture was last rendered, or `None` if it must be
+    /// rendered on the next frame regardless of the interval.
+    frames_since_update: Option<u32>,
+    pub(crate) texture: TextureId,
+    depth_view: wgpu::TextureView,
+    draw_pipeline: wgpu::RenderPipeline,
+    indirect_pipeline: wgpu::ComputePipeline,
+    scene_bgl: wgpu::BindGroupLayout,
+    indirect_bgl: wgpu::BindGroupLayout,
+    uniform_buf: wgpu::Buffer,
+    indirect_buf: wgpu::Buffer,
+    indirect_capacity: u32,
+    scene_bind_group: Option<wgpu::BindGroup>,
+    scene_key: Option<(usize, usize)>,
+    indirect_bind_group: Option<wgpu::BindGroup>,
+    indirect_key: Option<(usize, usize)>,
+}
+
+impl Minimap {
+    fn new(device: &wgpu::Device, texture: TextureId, center: Vec3, extent: f32, size: u32) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Minimap Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../../shaders/minimap.wgsl").into()),
+        });
+
+        let storage = |binding: u32, visibility: wgpu::ShaderStages, read_only: bool| {
+            wgpu::BindGroupLayoutEntry {
+                binding,
+                visibility,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Storage { read_only },
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }
+        };
+        let scene_bgl = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Minimap Scene BGL"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT | wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                storage(1, wgpu::ShaderStages::VERTEX, true),
+                storage(2, wgpu::ShaderStages::FRAGMENT, true),
+            ],
+        });
+        let indirect_bgl = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Minimap Indirect BGL"),
+            entries: &[
+                storage(0, wgpu::ShaderStages::COMPUTE, true),
+                storage(1, wgpu::ShaderStages::COMPUTE, false),
+            ],
+        });
+
+        let draw_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Minimap Draw PL"),
+            bind_group_layouts: &[Some(&scene_bgl)],
+            immediate_size: 0,
+        });
+        // Shared mesh vertex buffer (stride 40): position at 0, packed normal at 32.
+        let draw_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Minimap Draw Pipeline"),
+            layout: Some(&draw_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                compilation_options: Default::default(),
+                buffers: &[Some(wgpu::VertexBufferLayout {
+                    array_stride: 40,
+                    step_mode: wgpu::VertexStepMode::Vertex,
+                    attributes: &[
+                        wgpu::VertexAttribute {
+                            format: wgpu::VertexFormat::Float32x3,
+                            offset: 0,
+                            shader_location: 0,
+                        },
+                        wgpu::VertexAttribute {
+                            format: wgpu::VertexFormat::Uint32,
+                            offset: 32,
+                            shader_location: 3,
+                        },
+                    ],
+                })],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                compilation_options: Default::default(),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: Scene::RENDER_TARGET_FORMAT,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                // Seen from above, winding is unreliable for thin geometry
+                // such as terrain skirts and decals; draw both faces.
+                cull_mode: None,
+                ..Default::default()
+            },
+            depth_stencil: Some(libhelio::DepthMode::Normal.depth_stencil_state(
+                DEPTH_FORMAT,
+                wgpu::CompareFunction::Less,
+            )),
+            multisample: wgpu::MultisampleState::default(),
+            multiview_mask: None,
+            cache: None,
+        });
+
+        let indirect_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Minimap Indirect PL"),
+            bind_group_layouts: &[Some(&scene_bgl), Some(&indirect_bgl)],
+            immediate_size: 0,
+        });
+        let indirect_pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Minimap Indirect Pipeline"),
+            layout: Some(&indirect_layout),
+            module: &shader,
+            entry_point: Some("cs_build_indirect"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        let uniform_buf = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Minimap Uniforms"),
+            size: std::mem::size_of::<MinimapUniforms>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let indirect_capacity = WORKGROUP_SIZE;
+        let indirect_buf = create_indirect_buffer(device, indirect_capacity);
+
+        Self {
+            center,
+            extent,
+            size,
+            update_interval: 1,
+            flagged_only: false,
+            frames_since_update: None,
+            texture,
+            depth_view: create_depth_view(device, size),
+            draw_pipeline,
+            indirect_pipeline,
+            scene_bgl,
+            indirect_bgl,
+            uniform_buf,
+            indirect_buf,
+            indirect_capacity,
+            scene_bind_group: None,
+            scene_key: None,
+            indirect_bind_group: None,
+            indirect_key: None,
+        }
+    }
+
+    /// Advance the cadence counter; true when this frame should render.
+    fn due(&mut self) -> bool {
+        let due = match self.frames_since_update {
+            None => true,
+            Some(frames) => frames + 1 >= self.update_interval,
+        };
+        self.frames_since_update = Some(if due {
+            0
+        } else {
+            self.frames_since_update.unwrap_or(0) + 1
+        });
+        due
+    }
+
+    fn upload_uniforms(&self, queue: &wgpu::Queue, draw_count: u32) {
+        let uniforms = MinimapUniforms::new(self.center, self.extent, self.flagged_only, draw_count);
+        queue.write_buffer(&self.uniform_buf, 0, bytemuck::bytes_of(&uniforms));
+    }
+
+    fn render(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, scene: &Scene) {
+        let Some(target) = scene.texture_view(self.texture) else {
+            return;
+        };
+        let resources = scene.gpu_scene().resources();
+        let draw_count = resources.draw_count;
+        self.upload_uniforms(queue, draw_count);
+
+        if draw_count > self.indirect_capacity {
+            self.indirect_capacity = draw_count.next_power_of_two();
+            self.indirect_buf = create_indirect_buffer(device, self.indirect_capacity);
+            self.indirect_key = None;
+        }
+
+        let scene_key = (
+            resources.instances as *const _ as usize,
+            resources.materials as *const _ as usize,
+        );
+        if self.scene_key != Some(scene_key) {
+            self.scene_bind_group = Some(device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Minimap Scene BG"),
+                layout: &self.scene_bgl,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: self.uniform_buf.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: resources.instances.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: resources.materials.as_entire_binding(),
+                    },
+                ],
+            }));
+            self.scene_key = Some(scene_key);
+        }
+        let indirect_key = (
+            resources.draw_calls as *const _ as usize,
+            &self.indirect_buf as *const _ as usize,
+        );
+        if self.indirect_key != Some(indirect_key) {
+            self.indirect_bind_group = Some(device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Minimap Indirect BG"),
+                layout: &self.indirect_bgl,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: resources.draw_calls.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: self.indirect_buf.as_entire_binding(),
+                    },
+                ],
+            }));
+            self.indirect_key = Some(indirect_key);
+        }
+        let (Some(scene_bg), Some(indirect_bg)) =
+            (self.scene_bind_group.as_ref(), self.indirect_bind_group.as_ref())
+        else {
+            return;
+        };
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Minimap"),
+        });
+        if draw_count > 0 {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("Minimap Indirect"),
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(&self.indirect_pipeline);
+            pass.set_bind_group(0, scene_bg, &[]);
+            pass.set_bind_group(1, indirect_bg, &[]);
+            pass.dispatch_workgroups(draw_count.div_ceil(WORKGROUP_SIZE), 1, 1);
+        }
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Minimap Draw"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: target,
+                    resolve_target: None,
+                    depth_slice: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: &self.depth_view,
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(1.0),
+                        store: wgpu::StoreOp::Discard,
+                    }),
+                    stencil_ops: None,
+                }),
+                timestamp_writes: None,
+                occlusion_query_set: None,
+                multiview_mask: None,
+            });
+            if draw_count > 0 {
+                let mesh_buffers = scene.mesh_buffers();
+                pass.set_pipeline(&self.draw_pipeline);
+                pass.set_bind_group(0, scene_bg, &[]);
+                pass.set_vertex_buffer(0, mesh_buffers.vertices.slice(..));
+                pass.set_index_buffer(mesh_buffers.indices.slice(..), wgpu::IndexFormat::Uint32);
+                #[cfg(not(target_arch = "wasm32"))]
+                pass.multi_draw_indexed_indirect(&self.indirect_buf, 0, draw_count);
+                #[cfg(target_arch = "wasm32")]
+                for i in 0..draw_count {
+                    pass.draw_indexed_indirect(&self.indirect_buf, i as u64 * 20);
+                }
+            }
+        }
+        queue.submit(std::iter::once(encoder.finish()));
+    }
+}
+
+fn create_indirect_buffer(device: &wgpu::Device, capacity: u32) -> wgpu::Buffer {
+    device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("Minimap Indirect"),
+        size: capacity as u64 * std::mem::size_of::<helio_core::DrawIndexedIndirectArgs>() as u64,
+        usage: wgpu::BufferUsages::INDIRECT | wgpu::BufferUsages::STORAGE,
+        mapped_at_creation: false,
+    })
+}
+
+fn create_depth_view(device: &wgpu::Device, size: u32) -> wgpu::TextureView {
+    device
+        .create_texture(&wgpu::TextureDescriptor {
+            label: Some("Minimap Depth"),
+            size: wgpu::Extent3d {
+                width: size,
+                height: size,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: DEPTH_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        })
+        .create_view(&wgpu::TextureViewDescriptor::default())
+}
+
+impl Renderer {
+    /// Render a top-down orthographic minimap centred on `center`, covering
+    /// `extent` metres either side of it, into a `size`×`size` texture.
+    ///
+    /// Returns the texture to display; it stays the same across calls unless
+    /// `size` changes. After the first call the minimap re-renders during
+    /// [`render`](Self::render) every
+    /// [`set_minimap_update_interval`](Self::set_minimap_update_interval)
+    /// frames, so call this again only to move or resize it. Moving it
+    /// forces a redraw on the next frame.
+    ///
+    /// # Errors
+    /// - [`SceneError::TextureCapacityExceeded`](crate::SceneError::TextureCapacityExceeded)
+    ///   if a new texture is needed and the pool is full
+    pub fn render_minimap(&mut self, center: Vec3, extent: f32, size: u32) -> SceneResult<TextureId> {
+        let size = size.max(1);
+        if let Some(minimap) = self.minimap.as_mut().filter(|m| m.size == size) {
+            if minimap.center != center || minimap.extent != extent {
+                minimap.center = center;
+                minimap.extent = extent;
+                minimap.frames_since_update = None;
+            }
+            return Ok(minimap.texture);
+        }
+
+        let texture = self.scene.insert_render_target("Minimap", size, size)?;
+        match self.minimap.as_mut() {
+            Some(minimap) => {
+                let old = std::mem::replace(&mut minimap.texture, texture);
+                if let Err(err) = self.scene.remove_texture(old) {
+                    log::warn!("helio: could not release previous minimap texture: {err}");
+                }
+                minimap.center = center;
+                minimap.extent = extent;
+                minimap.size = size;
+                minimap.depth_view = create_depth_view(&self.device, size);
+                minimap.frames_since_update = None;
+            }
+            None => {
+                self.minimap = Some(Minimap::new(&self.device, texture, center, extent, size));
+            }
+        }
+        Ok(texture)
+    }
+
+    /// Re-render the minimap every `frames` frames (clamped to at least 1).
+    pub fn set_minimap_update_interval(&mut self, frames: u32) {
+        if let Some(minimap) = self.minimap.as_mut() {
+            minimap.update_interval = frames.max(1);
+        }
+    }
+
+    /// Draw only objects whose flags include [`OBJECT_FLAG_MINIMAP`].
+    pub fn set_minimap_flagged_only(&mut self, flagged_only: bool) {
+        if let Some(minimap) = self.minimap.as_mut() {
+            if minimap.flagged_only != flagged_only {
+                minimap.flagged_only = flagged_only;
+                minimap.frames_since_update = None;
+            }
+        }
+    }
+
+    /// Texture the minimap renders into, if [`render_minimap`](Self::render_minimap) was called.
+    pub fn minimap_texture(&self) -> Option<TextureId> {
+        self.minimap.as_ref().map(|m| m.texture)
+    }
+
+    /// Stop rendering the minimap and release its texture.
+    ///
+    /// # Errors
+    /// - [`SceneError::ResourceInUse`](crate::SceneError::ResourceInUse) if a
+    ///   material still samples the texture; the minimap is stopped regardless
+    pub fn disable_minimap(&mut self) -> SceneResult<()> {
+        match self.minimap.take() {
+            Some(minimap) => self.scene.remove_texture(minimap.texture),
+            None => Ok(()),
+        }
+    }
+
+    /// Called once per frame from [`render`](Self::render), after the graph.
+    pub(crate) fn update_minimap(&mut self) {
+        if let Some(minimap) = self.minimap.as_mut() {
+            if minimap.due() {
+                minimap.render(&self.device, &self.queue, &self.scene);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device() -> Option<(wgpu::Device, wgpu::Queue)> {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::new_without_display_handle());
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::LowPower,
+            compatible_surface: None,
+            force_fallback_adapter: false,
+            apply_limit_buckets: false,
+        }))
+        .ok()?;
+        // vs_main reads the instance buffer.
+        if !adapter
+            .get_downlevel_capabilities()
+            .flags
+            .contains(wgpu::DownlevelFlags::VERTEX_STORAGE)
+        {
+            return None;
+        }
+        pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default())).ok()
+    }
+
+    /// Draw two unit quads through the minimap pipeline, flagged on the
+    /// left half of a 2×1 target and unflagged on the right, and report
+    /// which halves were shaded.
+    fn draw_left_flagged_right_plain(flagged_only: bool) -> Option<[bool; 2]> {
+        let (device, queue) = device()?;
+        let mut minimap = Minimap::new(&device, TextureId::from_raw(0, 0), Vec3::ZERO, 1.0, 2);
+        minimap.flagged_only = flagged_only;
+        minimap.upload_uniforms(&queue, 1);
+
+        let init = |label: &str, contents: &[u8], usage: wgpu::BufferUsages| {
+            wgpu::util::DeviceExt::create_buffer_init(
+                &device,
+                &wgpu::util::BufferInitDescriptor { label: Some(label), contents, usage },
+            )
+        };
+        // Quad spanning x ∈ [-0.5, 0.5], z ∈ [-1, 1] at y = 0; 40-byte
+        // vertices with the packed normal (+Y) at byte 32.
+        let mut vertices = Vec::new();
+        for [x, z] in [[-0.5f32, -1.0], [0.5, -1.0], [0.5, 1.0], [-0.5, 1.0]] {
+            vertices.extend_from_slice(bytemuck::bytes_of(&[x, 0.0, z]));
+            vertices.extend_from_slice(&[0; 20]);
+            vertices.extend_from_slice(&0x0000_7f00u32.to_le_bytes());
+            vertices.extend_from_slice(&[0; 4]);
+        }
+        let vertices = init("vertices", &vertices, wgpu::BufferUsages::VERTEX);
+        let indices = init(
+            "indices",
+            bytemuck::cast_slice(&[0u32, 1, 2, 0, 2, 3]),
+            wgpu::BufferUsages::INDEX,
+        );
+        let instance = |x: f32, flags: u32| libhelio::GpuInstanceData {
+            model: Mat4::from_translation(Vec3::new(x, 0.0, 0.0)).to_cols_array(),
+            normal_mat: [1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0],
+            bounds: [x, 0.0, 0.0, 1.0],
+            mesh_id: 0,
+            material_id: 0,
+            flags,
+            lightmap_index: u32::MAX,
+        };
+        // Bits 0 and 1 are the shadow flags every object usually carries.
+        let instances = [instance(-0.5, OBJECT_FLAG_MINIMAP | 0b11), instance(0.5, 0b11)];
+        let instances = init("instances", bytemuck::cast_slice(&instances), wgpu::BufferUsages::STORAGE);
+        let material = libhelio::GpuMaterial {
+            base_color: [1.0; 4],
+            emissive: [1.0; 4],
+            ..libhelio::GpuMaterial::zeroed()
+        };
+        let materials = init("materials", bytemuck::bytes_of(&material), wgpu::BufferUsages::STORAGE);
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &minimap.scene_bgl,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: minimap.uniform_buf.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 1, resource: instances.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 2, resource: materials.as_entire_binding() },
+            ],
+        });
+        let target = device.create_texture(&wgpu::TextureDescriptor {
+            label: None,
+            size: wgpu::Extent3d { width: 2, height: 2, depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: Scene::RENDER_TARGET_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let readback = device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: 512,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = device.create_command_encoder(&Default::default());
+        {
+            let view = target.create_view(&Default::default());
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: None,
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    depth_slice: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: &minimap.depth_view,
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(1.0),
+                        store: wgpu::StoreOp::Discard,
+                    }),
+                    stencil_ops: None,
+                }),
+                timestamp_writes: None,
+                occlusion_query_set: None,
+                multiview_mask: None,
+            });
+            pass.set_pipeline(&minimap.draw_pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.set_vertex_buffer(0, vertices.slice(..));
+            pass.set_index_buffer(indices.slice(..), wgpu::IndexFormat::Uint32);
+            pass.draw_indexed(0..6, 0, 0..2);
+        }
+        encoder.copy_texture_to_buffer(
+            target.as_image_copy(),
+            wgpu::TexelCopyBufferInfo {
+                buffer: &readback,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(256),
+                    rows_per_image: None,
+                },
+            },
+            target.size(),
+        );
+        queue.submit([encoder.finish()]);
+        readback.slice(..).map_async(wgpu::MapMode::Read, |_| {});
+        device.poll(wgpu::PollType::wait_indefinitely()).unwrap();
+        let texels = readback.slice(..).get_mapped_range().unwrap();
+        // Top row: texel 0 is the left (flagged) quad, texel 1 the right one.
+        Some([texels[0] > 0, texels[4] > 0])
+    }
+
+    #[test]
+    fn flagged_only_draws_just_minimap_instances() {
+        let Some(all) = draw_left_flagged_right_plain(false) else {
+            eprintln!("skipping: no GPU adapter with vertex storage");
+            return;
+        };
+        assert_eq!(all, [true, true]);
+        assert_eq!(draw_left_flagged_right_plain(true), Some([true, false]));
+    }
+
+    #[test]
+    fn camera_looks_straight_down_with_north_up() {
+        let center = Vec3::new(100.0, 5.0, -40.0);
+        let uniforms = MinimapUniforms::new(center, 20.0, false, 0);
+        let view_proj = Mat4::from_cols_array_2d(&uniforms.view_proj);
+        let ndc = |p: Vec3| view_proj.project_point3(p);
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-4;
+
+        let c = ndc(center);
+        assert!(close(c.x, 0.0) && close(c.y, 0.0));
+        // `extent` reaches the texture edges: east is right, north (-Z) is up.
+        assert!(close(ndc(center + Vec3::X * 20.0).x, 1.0));
+        assert!(close(ndc(center - Vec3::Z * 20.0).y, 1.0));
+
+        // Orthographic: height only moves depth, and higher is nearer.
+        let high = ndc(center + Vec3::new(5.0, 400.0, 5.0));
+        let low = ndc(center + Vec3::new(5.0, -400.0, 5.0));
+        assert!(close(high.x, low.x) && close(high.y, low.y));
+        assert!(0.0 < high.z && high.z < low.z && low.z < 1.0);
+
+        let light = Vec3::from_slice(&uniforms.light_dir[..3]);
+        assert!(close(light.length(), 1.0) && light.y > 0.0);
+        assert_eq!(uniforms.light_dir[3], 0.0);
+    }
+}
@@ -1,13 +1,24 @@
+mod capabilities;
+mod capture;
 mod config;
 mod debug;
 mod fullscreen;
+mod minimap;
+mod pre_exposure;
+mod reinit;
 mod render;
 mod renderer_impl;
 mod resize;
 mod setup;
+mod stats;
 
-pub use config::{required_experimental_features, required_wgpu_features, required_wgpu_limits, GiConfig, PerfOverlayMode, RendererConfig};
-pub use debug::{DebugDrawPass, DebugDrawState};
+pub use capabilities::HelioCapabilities;
+pub use capture::ReadbackRequest;
+pub use config::{required_experimental_features, required_wgpu_features, required_wgpu_limits, GiConfig, PerfOverlayMode, PresentMode, RendererConfig};
+pub use debug::{DebugDrawPass, DebugDrawState, ShadedWireframe};
+pub use minimap::OBJECT_FLAG_MINIMAP;
+pub use pre_exposure::PreExposure;
+pub use stats::{FrameStats, GpuMemoryStats};
 pub use renderer_impl::{
-    DebugBatch, DebugCameraUniform, DebugVertex, GraphRebuilder, Renderer,
+    DebugBatch, DebugCameraUniform, DebugPoint, DebugVertex, GraphRebuilder, Renderer,
 };
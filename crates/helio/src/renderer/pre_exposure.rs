@@ -0,0 +1,111 @@
+//! Pre-exposure: scaling HDR radiance before it is written to the `pre_aa` target.
+//!
+//! `pre_aa` is `Rgba16Float`. Bright scenes (sun-lit snow, emissive panels,
+//! specular highlights) can exceed what f16 stores accurately, and dark
+//! scenes lose precision near zero. Pre-exposure multiplies every radiance
+//! write by a factor close to the final exposure, so the stored values sit in
+//! the well-resolved middle of the f16 range. Every pass that reads `pre_aa`
+//! back as radiance (SSR, planar reflections, radiance-cascade fallback,
+//! post-process) divides the factor out again. Final image brightness does
+//! not change; only the precision of what is stored does.
+//!
+//! The factor reaches shaders as `camera.jitter_frame.w`.
+
+use super::renderer_impl::Renderer;
+
+/// Average scene luminance that auto pre-exposure maps to (middle grey).
+const AUTO_KEY: f32 = 0.18;
+/// Auto pre-exposure range. Wide enough for night and daylight scenes while
+/// keeping the factor itself far from f16 limits.
+const AUTO_MIN: f32 = 1.0 / 1024.0;
+const AUTO_MAX: f32 = 1024.0;
+/// Exponential smoothing rate (1/s) of auto pre-exposure, applied in log2 space.
+const AUTO_ADAPT_RATE: f32 = 3.0;
+
+/// How the pre-exposure factor is chosen each frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PreExposure {
+    /// A fixed factor. `1.0` (the default) disables pre-exposure.
+    Fixed(f32),
+    /// Track the previous frame's average luminance, read back asynchronously
+    /// from the post-process exposure reduction. Lags by a frame or two.
+    /// Falls back to the current factor when readback is unavailable
+    /// (e.g. on a renderer sharing an externally-owned device).
+    Auto,
+}
+
+impl Default for PreExposure {
+    fn default() -> Self {
+        Self::Fixed(1.0)
+    }
+}
+
+/// Step `current` toward the factor that maps `avg_log2_luminance` (the
+/// scene-linear average, with pre-exposure already removed) to middle grey.
+///
+/// Smoothing happens in log space so brightening and darkening adapt at the
+/// same perceived speed.
+pub(crate) fn auto_pre_exposure(current: f32, avg_log2_luminance: f32, dt: f32) -> f32 {
+    if !avg_log2_luminance.is_finite() {
+        return current;
+    }
+    let target = (AUTO_KEY / avg_log2_luminance.exp2()).clamp(AUTO_MIN, AUTO_MAX);
+    let t = 1.0 - (-AUTO_ADAPT_RATE * dt.max(0.0)).exp();
+    let log_current = current.clamp(AUTO_MIN, AUTO_MAX).log2();
+    (log_current + (target.log2() - log_current) * t).exp2()
+}
+
+impl Renderer {
+    /// Select how HDR radiance is pre-exposed before it is stored.
+    ///
+    /// A non-positive or non-finite fixed factor falls back to `1.0`.
+    pub fn set_pre_exposure(&mut self, mode: PreExposure) {
+        self.pre_exposure_mode = match mode {
+            PreExposure::Fixed(factor) if factor.is_finite() && factor > 0.0 => mode,
+            PreExposure::Fixed(_) => PreExposure::Fixed(1.0),
+            PreExposure::Auto => PreExposure::Auto,
+        };
+        if let PreExposure::Fixed(factor) = self.pre_exposure_mode {
+            self.pre_exposure = factor;
+        }
+    }
+
+    /// The current pre-exposure mode.
+    pub fn pre_exposure_mode(&self) -> PreExposure {
+        self.pre_exposure_mode
+    }
+
+    /// The factor applied to the most recently rendered frame.
+    pub fn pre_exposure(&self) -> f32 {
+        self.pre_exposure
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auto_converges_to_middle_grey() {
+        // Average luminance of 4.0 (scene-linear) wants a factor of 0.045.
+        let avg = 4.0f32.log2();
+        let mut pe = 1.0;
+        for _ in 0..600 {
+            pe = auto_pre_exposure(pe, avg, 1.0 / 60.0);
+        }
+        assert!((pe - AUTO_KEY / 4.0).abs() < 1e-3, "{pe}");
+    }
+
+    #[test]
+    fn auto_clamps_and_ignores_garbage() {
+        assert_eq!(auto_pre_exposure(2.0, f32::NAN, 0.016), 2.0);
+        let pe = auto_pre_exposure(1.0, -100.0, 1000.0);
+        assert!((pe - AUTO_MAX).abs() / AUTO_MAX < 1e-3, "{pe}");
+    }
+
+    #[test]
+    fn zero_dt_holds_the_factor() {
+        let pe = auto_pre_exposure(0.5, 10.0, 0.0);
+        assert!((pe - 0.5).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,248 @@
+use std::sync::Arc;
+
+use crate::scene::Scene;
+
+use super::renderer_impl::{CullStatsReadbackState, DebugCameraUniform, Renderer};
+use super::setup::RendererBuffers;
+
+impl Renderer {
+    /// Tear down every GPU resource and rebuild the renderer on a new device.
+    ///
+    /// Call this after recreating the GPU context on device loss, or when
+    /// moving to a different adapter. The render graph is rebuilt through the
+    /// [`GraphRebuilder`](super::GraphRebuilder), and renderer-owned buffers,
+    /// depth targets and readback staging are recreated on `device`.
+    ///
+    /// CPU-side settings survive: ambient and fill lighting, time of day,
+    /// clear colour, GI and shadow configuration, quality preset, debug and
+    /// editor modes, pre-exposure, billboards, corona emitters and the frame
+    /// clock.
+    ///
+    /// `scene` must be built on `device`. Meshes, textures and materials live
+    /// in GPU buffers owned by the scene, so the host repopulates it the same
+    /// way it built the original. The previous scene is returned so its
+    /// handles can be mapped across or it can simply be dropped.
+    ///
+    /// Device-bound renderer state is discarded: the minimap (its texture
+    /// belonged to the old scene), baked lighting data and the adapter given
+    /// to [`set_adapter`](Self::set_adapter). Pass settings made
+    /// directly on graph passes are lost, as they are on a resize rebuild.
+    ///
+    /// # Errors
+    /// [`Error::InvalidPassConfig`](helio_core::Error::InvalidPassConfig) if the
+    /// renderer has no graph rebuilder; the renderer is left unchanged.
+    pub fn reinitialize(
+        &mut self,
+        device: Arc<wgpu::Device>,
+        queue: Arc<wgpu::Queue>,
+        mut scene: Scene,
+    ) -> helio_core::Result<Scene> {
+        if self.graph_rebuilder.is_none() {
+            return Err(helio_core::Error::InvalidPassConfig(
+                "reinitialize requires a GraphRebuilder to recreate the render graph".into(),
+            ));
+        }
+
+        scene.set_shadow_face_capacity(self.shadow_face_capacity);
+        scene.set_adaptive_shadow_resolution(self.adaptive_shadow_resolution);
+        scene.set_shadow_atlas_size(self.shadow_atlas_size);
+        scene.set_render_size(self.output_width, self.output_height);
+        let old_scene = std::mem::replace(&mut self.scene, scene);
+        self.device = device;
+        self.queue = queue;
+        // The new device may come from another adapter.
+        self.adapter = None;
+
+        let RendererBuffers {
+            water_volumes_buffer,
+            water_hitboxes_buffer,
+            pp_volumes_buffer,
+            postprocess_buffer,
+            cull_stats_staging,
+            luminance_staging,
+        } = RendererBuffers::new(&self.device);
+        self.water_volumes_buffer = water_volumes_buffer;
+        self.water_hitboxes_buffer = water_hitboxes_buffer;
+        self.pp_volumes_buffer = pp_volumes_buffer;
+        self.postprocess_buffer = postprocess_buffer;
+        self.cull_stats_staging = cull_stats_staging;
+        self.luminance_staging = luminance_staging;
+        self.cull_stats_readback_state = CullStatsReadbackState::Idle;
+        self.luminance_readback_state = CullStatsReadbackState::Idle;
+        self.avg_log_luminance = None;
+
+        self.debug_camera_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Debug Camera Buffer"),
+            size: std::mem::size_of::<DebugCameraUniform>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        self.cull_stats_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Cull Stats Buffer"),
+            size: 32,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_SRC
+                | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        self.minimap = None;
+        #[cfg(feature = "bake")]
+        {
+            self.baked_data = None;
+        }
+        // Billboard and corona data are re-uploaded by the new graph.
+        self.billboard_dirty = true;
+        self.billboard_generation += 1;
+        self.corona_emitter_generation += 1;
+
+        // Rebuilds the depth targets and the graph against the new device.
+        self.pending_resize = None;
+        self.apply_resize_now(self.output_width, self.output_height);
+        self.enable_jitter = self.graph.requires_camera_jitter();
+
+        Ok(old_scene)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use helio_core::RenderGraph;
+
+    use super::*;
+    use crate::renderer::{DebugDrawPass, DebugDrawState, GraphRebuilder, RendererConfig};
+    use crate::scene::Camera;
+
+    const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8Unorm;
+
+    fn create_test_device() -> Option<(Arc<wgpu::Device>, Arc<wgpu::Queue>)> {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+            backends: wgpu::Backends::from_env().unwrap_or(wgpu::Backends::PRIMARY),
+            ..wgpu::InstanceDescriptor::new_without_display_handle()
+        });
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::LowPower,
+            compatible_surface: None,
+            force_fallback_adapter: false,
+            apply_limit_buckets: false,
+        }))
+        .ok()?;
+        if !adapter.features().contains(wgpu::Features::INDIRECT_FIRST_INSTANCE) {
+            return None;
+        }
+        let (device, queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor {
+            required_features: wgpu::Features::INDIRECT_FIRST_INSTANCE,
+            required_limits: wgpu::Limits::downlevel_defaults(),
+            ..Default::default()
+        }))
+        .ok()?;
+        Some((Arc::new(device), Arc::new(queue)))
+    }
+
+    fn debug_graph(
+        device: &Arc<wgpu::Device>,
+        queue: &Arc<wgpu::Queue>,
+        debug_state: Arc<Mutex<DebugDrawState>>,
+        debug_camera_buf: &wgpu::Buffer,
+    ) -> RenderGraph {
+        let mut graph = RenderGraph::new(device, queue);
+        graph.add_pass(Box::new(DebugDrawPass::new(
+            device,
+            debug_camera_buf,
+            FORMAT,
+            debug_state,
+            false,
+            false,
+        )));
+        graph
+    }
+
+    fn render_once(renderer: &mut Renderer) {
+        let target = renderer.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Reinit Test Target"),
+            size: wgpu::Extent3d { width: 64, height: 64, depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+        let view = target.create_view(&wgpu::TextureViewDescriptor::default());
+        let camera = Camera::perspective_look_at(
+            glam::Vec3::new(0.0, 2.0, 5.0),
+            glam::Vec3::ZERO,
+            glam::Vec3::Y,
+            1.0,
+            1.0,
+            0.1,
+            100.0,
+        );
+        renderer.debug_line([0.0; 3], [1.0, 1.0, 1.0], [1.0; 4]);
+        renderer.render(&camera, &view).expect("render after init");
+        let _ = renderer.device.poll(wgpu::PollType::wait_indefinitely());
+    }
+
+    #[test]
+    fn init_reinitialize_init_keeps_the_renderer_working() {
+        let Some((device, queue)) = create_test_device() else {
+            eprintln!("skipping: no adapter with INDIRECT_FIRST_INSTANCE");
+            return;
+        };
+        let config = RendererConfig::new(64, 64, FORMAT);
+        let debug_state = Arc::new(Mutex::new(DebugDrawState::default()));
+        let debug_camera_buf = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Debug Camera Buffer"),
+            size: std::mem::size_of::<DebugCameraUniform>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let cull_stats_buf = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Cull Stats Buffer"),
+            size: 32,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_SRC
+                | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let scene = Scene::new(device.clone(), queue.clone());
+        let graph = debug_graph(&device, &queue, debug_state.clone(), &debug_camera_buf);
+        let mut renderer = Renderer::new(
+            device.clone(),
+            queue.clone(),
+            FORMAT,
+            64,
+            64,
+            1.0,
+            config,
+            scene,
+            graph,
+            debug_state,
+            debug_camera_buf,
+            cull_stats_buf,
+        );
+        let rebuilder: GraphRebuilder =
+            Arc::new(|device, queue, _scene, _config, debug_state, debug_camera_buf, _cull_stats_buf| {
+                debug_graph(device, queue, debug_state, debug_camera_buf)
+            });
+        renderer.set_rebuilder(rebuilder);
+        renderer.set_ambient([0.3, 0.2, 0.1], 2.0);
+        render_once(&mut renderer);
+
+        let Some((device2, queue2)) = create_test_device() else {
+            return;
+        };
+        let scene2 = Scene::new(device2.clone(), queue2.clone());
+        renderer
+            .reinitialize(device2.clone(), queue2, scene2)
+            .expect("reinitialize with a rebuilder");
+
+        assert!(Arc::ptr_eq(&renderer.device, &device2));
+        assert_eq!(renderer.ambient_color, [0.3, 0.2, 0.1]);
+        assert_eq!(renderer.ambient_intensity, 2.0);
+        assert!(renderer.find_pass::<DebugDrawPass>().is_some());
+        render_once(&mut renderer);
+    }
+}
@@ -56,10 +56,49 @@ impl Renderer {
         }
     }
 
+    fn poll_luminance_readback(&mut self) {
+        let result = match &self.luminance_readback_state {
+            CullStatsReadbackState::Idle | CullStatsReadbackState::Disabled => return,
+            CullStatsReadbackState::Mapping(completion) => completion
+                .lock()
+                .ok()
+                .and_then(|mut completion| completion.take()),
+        };
+
+        match result {
+            Some(Ok(())) => {
+                let read_succeeded = match self.luminance_staging.slice(..).get_mapped_range() {
+                    Ok(mapped) => {
+                        if let Ok(bytes) = <[u8; 4]>::try_from(&mapped[..]) {
+                            self.avg_log_luminance = Some(f32::from_le_bytes(bytes));
+                        }
+                        drop(mapped);
+                        true
+                    }
+                    Err(_) => false,
+                };
+                self.luminance_staging.unmap();
+                self.luminance_readback_state = if read_succeeded {
+                    CullStatsReadbackState::Idle
+                } else {
+                    CullStatsReadbackState::Disabled
+                };
+            }
+            Some(Err(_)) => {
+                self.luminance_staging.unmap();
+                self.luminance_readback_state = CullStatsReadbackState::Disabled;
+            }
+            None => {}
+        }
+    }
+
     pub fn render(&mut self, camera: &Camera, target: &wgpu::TextureView) -> HelioResult<()> {
         // Browser WebGPU buffer mapping is asynchronous. Consume the previous
         // frame's completed readback before recording a new copy.
         self.poll_cull_stats_readback();
+        if self.owns_device {
+            self.poll_luminance_readback();
+        }
 
         if let Some((w, h)) = self.pending_resize.take() {
             self.apply_resize_now(w, h);
@@ -110,12 +149,20 @@ impl Renderer {
         }
 
         let now = Instant::now();
-        let dt = now.duration_since(self.last_render_time).as_secs_f32().min(0.1);
+        let raw_dt = if self.deterministic {
+            self.frame_clock.fixed_step().unwrap_or(1.0 / 60.0)
+        } else {
+            now.duration_since(self.last_render_time).as_secs_f32()
+        };
         self.last_render_time = now;
+        let dt = self.frame_clock.tick(raw_dt).delta;
         self.delta_time = dt;
-        self.frame_times[self.frame_times_cursor] = dt;
+        self.frame_times[self.frame_times_cursor] = raw_dt.min(0.1);
         self.frame_times_cursor = (self.frame_times_cursor + 1) % self.frame_times.len();
         self.graph.set_delta_time(dt);
+        self.graph.set_time(self.frame_clock.elapsed() as f32);
+        self.graph.set_random_seed(self.random_seed);
+        self.graph.set_frames_in_flight(self.frames_in_flight);
 
         let internal_w = (((self.output_width as f32) * self.render_scale).ceil() as u32).max(1);
         let internal_h = (((self.output_height as f32) * self.render_scale).ceil() as u32).max(1);
@@ -125,7 +172,7 @@ impl Renderer {
             self.jitter_cache_height = internal_h;
         }
 
-        let frame_idx = self.scene.gpu_scene().frame_count;
+        let frame_idx = self.scene.gpu_scene().frame_count.wrapping_add(self.random_seed);
         let (jitter_mat, jx, jy) = if self.enable_jitter {
             let jitter_mat = self.jitter_matrices[(frame_idx % 16) as usize];
             let raw = HALTON_JITTER[(frame_idx % 16) as usize];
@@ -151,6 +198,15 @@ impl Renderer {
             bytemuck::bytes_of(&debug_camera_uniform),
         );
 
+        self.pre_exposure = match self.pre_exposure_mode {
+            super::PreExposure::Fixed(factor) => factor,
+            super::PreExposure::Auto => match self.avg_log_luminance {
+                Some(avg) => super::pre_exposure::auto_pre_exposure(self.pre_exposure, avg, dt),
+                None => self.pre_exposure,
+            },
+        };
+        self.scene.set_pre_exposure(self.pre_exposure);
+
         let mut jittered_camera = camera.clone();
         jittered_camera.proj = jitter_mat * camera.proj;
         jittered_camera.jitter = [jx, jy];
@@ -176,9 +232,10 @@ impl Renderer {
                     {
                         let [x, y, z, _] = light.position_range;
                         let [r, g, b, _] = light.color_intensity;
+                        // Additive so the gizmo glows in the light's colour.
                         self.billboard_scratch.push(super::renderer_impl::BillboardInstance {
                             world_pos: [x, y, z, 0.0],
-                            scale_flags: [0.25, 0.25, 0.0, 0.0],
+                            scale_flags: [0.25, 0.25, 0.0, libhelio::BillboardBlendMode::Additive.as_f32()],
                             color: [r, g, b, 1.0],
                         });
                     }
@@ -285,10 +342,28 @@ impl Renderer {
                 state.editor_volume_lines = Vec::new();
                 state.editor_volume_generation = state.editor_volume_generation.wrapping_add(1);
             }
+            if state.light_debug && !state.editor_enabled {
+                let lines = self.scene.light_debug_lines();
+                if lines != state.light_debug_lines {
+                    state.light_debug_lines = lines;
+                    state.light_debug_generation = state.light_debug_generation.wrapping_add(1);
+                }
+            } else if !state.light_debug_lines.is_empty() {
+                state.light_debug_lines = Vec::new();
+                state.light_debug_generation = state.light_debug_generation.wrapping_add(1);
+            }
         }
+        // Camera-centred GI volume, snapped to the cascade probe grid when
+        // radiance cascades run so the probes do not swim as the camera moves.
         let rc_radius = self.gi_config.rc_radius;
-        let rc_min = [camera.position.x - rc_radius, camera.position.y - rc_radius, camera.position.z - rc_radius];
-        let rc_max = [camera.position.x + rc_radius, camera.position.y + rc_radius, camera.position.z + rc_radius];
+        let camera_pos = camera.position.to_array();
+        let (rc_min, rc_max) = match self
+            .graph
+            .find_pass::<helio_pass_radiance_cascades::RadianceCascadesPass>()
+        {
+            Some(rc) => rc.config().volume_bounds(camera_pos, rc_radius),
+            None => (camera_pos.map(|p| p - rc_radius), camera_pos.map(|p| p + rc_radius)),
+        };
 
         #[cfg(feature = "bake")]
         let baked_ao = self.baked_data.as_deref().and_then(|d| d.ao_view_ref());
@@ -341,9 +416,15 @@ impl Renderer {
                 clear_color: self.clear_color,
                 ambient_color: self.ambient_color,
                 ambient_intensity: self.ambient_intensity,
+                ambient_ground_color: self.ambient_ground_color,
+                ambient_sh: self.ambient_sh,
+                fill_light: self.fill_light,
+                shadow_fade_range: self.shadow_fade_range,
                 rc_world_min: rc_min,
                 rc_world_max: rc_max,
                 tlas: self.scene.tlas(),
+                color_load: self.color_load_op,
+                depth_load: self.depth_load_op,
             },
             "Renderer",
         );
@@ -391,6 +472,9 @@ impl Renderer {
             frame_resources.vg.write(vg_data, "Renderer");
         }
         frame_resources.sky = self.scene.sky_context();
+        if let Some(time_of_day) = self.time_of_day {
+            frame_resources.sky.sun_direction = Some(time_of_day.sun_direction());
+        }
         if let Some(ao) = baked_ao {
             frame_resources.baked_ao.write(ao, "Renderer");
         }
@@ -495,9 +579,35 @@ impl Renderer {
             self.cull_stats_readback_state = CullStatsReadbackState::Mapping(completion);
         }
 
+        if self.owns_device
+            && self.pre_exposure_mode == super::PreExposure::Auto
+            && matches!(self.luminance_readback_state, CullStatsReadbackState::Idle)
+        {
+            if let Some(pp) = self.graph.find_pass::<helio_pass_postprocess::PostProcessPass>() {
+                let mut read_encoder = self.device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                    label: Some("Luminance Readback"),
+                });
+                read_encoder.copy_buffer_to_buffer(pp.avg_luminance_buffer(), 0, &self.luminance_staging, 0, 4);
+                self.queue.submit(std::iter::once(read_encoder.finish()));
+
+                let completion = std::sync::Arc::new(std::sync::Mutex::new(None));
+                let callback_completion = std::sync::Arc::clone(&completion);
+                self.luminance_staging
+                    .slice(..)
+                    .map_async(wgpu::MapMode::Read, move |result| {
+                        if let Ok(mut completion) = callback_completion.lock() {
+                            *completion = Some(result);
+                        }
+                    });
+                self.luminance_readback_state = CullStatsReadbackState::Mapping(completion);
+            }
+        }
+
         drop(texture_views);
         drop(samplers);
+        self.update_minimap();
         self.scene.advance_frame();
+        self.check_gpu_memory_budget();
         Ok(())
     }
 }
@@ -8,7 +8,7 @@ use web_time::Instant;
 use bytemuck::{Pod, Zeroable};
 use helio_core::{RenderGraph, RenderPass};
 
-use super::config::{PerfOverlayMode, RendererConfig};
+use super::config::{PerfOverlayMode, PresentMode, RendererConfig};
 
 /// Closure that rebuilds the render graph on resize.
 pub type GraphRebuilder = Arc<
@@ -65,6 +65,16 @@ pub struct DebugVertex {
     pub color: [f32; 4],
 }
 
+/// A screen-facing round point drawn by the debug pass; `size` is its
+/// diameter in pixels.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Pod, Zeroable)]
+pub struct DebugPoint {
+    pub position: [f32; 3],
+    pub size: f32,
+    pub color: [f32; 4],
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Pod, Zeroable)]
 pub struct BillboardInstance {
@@ -73,6 +83,17 @@ pub struct BillboardInstance {
     pub color: [f32; 4],
 }
 
+impl BillboardInstance {
+    pub fn blend_mode(&self) -> libhelio::BillboardBlendMode {
+        libhelio::BillboardBlendMode::from_f32(self.scale_flags[3])
+    }
+
+    pub fn with_blend_mode(mut self, mode: libhelio::BillboardBlendMode) -> Self {
+        self.scale_flags[3] = mode.as_f32();
+        self
+    }
+}
+
 pub(crate) enum CullStatsReadbackState {
     Idle,
     Mapping(Arc<Mutex<Option<Result<(), wgpu::BufferAsyncError>>>>),
@@ -82,6 +103,9 @@ pub(crate) enum CullStatsReadbackState {
 pub struct Renderer {
     pub(crate) device: Arc<wgpu::Device>,
     pub(crate) queue: Arc<wgpu::Queue>,
+    /// Adapter `device` was created from, if the host provided it; only
+    /// [`capabilities`](Renderer::capabilities) reads it.
+    pub(crate) adapter: Option<wgpu::Adapter>,
     pub(crate) graph: RenderGraph,
     pub(crate) scene: Scene,
     pub(crate) depth_texture: wgpu::Texture,
@@ -96,11 +120,27 @@ pub struct Renderer {
     pub(crate) cull_stats_buffer: wgpu::Buffer,
     pub(crate) ambient_color: [f32; 3],
     pub(crate) ambient_intensity: f32,
+    pub(crate) ambient_ground_color: Option<[f32; 3]>,
+    pub(crate) ambient_sh: Option<libhelio::AmbientSh>,
+    pub(crate) fill_light: Option<libhelio::FillLight>,
+    pub(crate) time_of_day: Option<libhelio::TimeOfDay>,
+    pub(crate) shadow_fade_range: [f32; 2],
     pub(crate) clear_color: [f32; 4],
+    pub(crate) color_load_op: libhelio::LoadOp,
+    pub(crate) depth_load_op: libhelio::LoadOp,
     pub(crate) gi_config: GiConfig,
     pub(crate) shadow_quality: libhelio::ShadowQuality,
     pub(crate) shadow_atlas_size: u32,
     pub(crate) shadow_face_capacity: u32,
+    pub(crate) adaptive_shadow_resolution: Option<libhelio::AdaptiveShadowResolution>,
+    pub(crate) present_mode: PresentMode,
+    pub(crate) quality_preset: Option<helio_core::QualityPreset>,
+    pub(crate) shadow_depth_clamp: bool,
+    pub(crate) shadow_border_clamp: bool,
+    pub(crate) depth_convention: libhelio::DepthConvention,
+    pub(crate) output_transfer: Option<libhelio::OutputTransfer>,
+    pub(crate) gpu_memory_budget: Option<u64>,
+    pub(crate) over_gpu_memory_budget: bool,
     pub(crate) debug_mode: u32,
     pub(crate) editor_mode: bool,
     pub(crate) debug_state: Arc<Mutex<DebugDrawState>>,
@@ -120,10 +160,26 @@ pub struct Renderer {
     pub(crate) postprocess_buffer: wgpu::Buffer,
     pub(crate) last_render_time: Instant,
     pub(crate) delta_time: f32,
+    pub(crate) frame_clock: helio_core::FrameClock,
+    /// Seed for stochastic sampling (see [`Renderer::set_random_seed`]).
+    pub(crate) random_seed: u64,
+    /// Advance by a fixed delta instead of wall-clock time.
+    pub(crate) deterministic: bool,
+    /// Pushed to the graph every frame so rebuilt graphs keep it.
+    pub(crate) frames_in_flight: u32,
     pub(crate) graph_time_ms: f32,
     pub(crate) cull_stats_staging: wgpu::Buffer,
     pub(crate) cull_stats_readback_state: CullStatsReadbackState,
     pub(crate) cull_stats: [u32; 8],
+    pub(crate) pre_exposure_mode: super::PreExposure,
+    /// Factor written to `camera.jitter_frame.w` for the current frame.
+    pub(crate) pre_exposure: f32,
+    /// 4-byte staging copy of the post-process average log2 luminance,
+    /// read back only while pre-exposure is [`PreExposure::Auto`](super::PreExposure::Auto).
+    pub(crate) luminance_staging: wgpu::Buffer,
+    pub(crate) luminance_readback_state: CullStatsReadbackState,
+    /// Last scene-linear average log2 luminance read back from post-process.
+    pub(crate) avg_log_luminance: Option<f32>,
     pub(crate) frame_times: Vec<f32>,
     pub(crate) frame_times_cursor: usize,
     pub(crate) jitter_matrices: [glam::Mat4; 16],
@@ -145,12 +201,14 @@ pub struct Renderer {
     pub(crate) pending_resize: Option<(u32, u32)>,
     pub(crate) clear_target_next_frame: bool,
     pub(crate) graph_rebuilder: Option<GraphRebuilder>,
+    pub(crate) minimap: Option<super::minimap::Minimap>,
 }
 
 pub struct DebugBatch<'a> {
     pub(crate) state: &'a mut DebugDrawState,
     pub(crate) lines_changed: bool,
     pub(crate) tris_changed: bool,
+    pub(crate) points_changed: bool,
 }
 
 impl<'a> DebugBatch<'a> {
@@ -187,6 +245,17 @@ impl<'a> DebugBatch<'a> {
         self.tris_changed = true;
     }
 
+    /// Depth mode for primitives added after this call; see
+    /// [`Renderer::set_debug_depth_mode`].
+    pub fn set_depth_mode(&mut self, mode: libhelio::DepthMode) {
+        self.state.set_depth_mode(mode);
+    }
+
+    pub fn point(&mut self, position: [f32; 3], size: f32, color: [f32; 4]) {
+        self.state.user_points.push(DebugPoint { position, size, color });
+        self.points_changed = true;
+    }
+
     pub fn sphere(&mut self, center: [f32; 3], radius: f32, color: [f32; 4], segments: u32) {
         if segments < 4 {
             return;
@@ -316,6 +385,9 @@ impl<'a> DebugBatch<'a> {
         if self.tris_changed {
             self.state.user_tris_generation = self.state.user_tris_generation.wrapping_add(1);
         }
+        if self.points_changed {
+            self.state.user_points_generation = self.state.user_points_generation.wrapping_add(1);
+        }
     }
 }
 
@@ -328,10 +400,206 @@ impl Renderer {
         self.gi_config
     }
 
+    /// Switch how radiance cascades accumulate across frames, effective next
+    /// frame. No-op when the graph has no radiance-cascades pass.
+    pub fn set_gi_integration_mode(&mut self, mode: helio_pass_radiance_cascades::IntegrationMode) {
+        if let Some(rc) = self
+            .graph
+            .find_pass_mut::<helio_pass_radiance_cascades::RadianceCascadesPass>()
+        {
+            rc.set_integration_mode(mode);
+        }
+    }
+
     pub fn set_shadow_quality(&mut self, quality: libhelio::ShadowQuality) {
         self.shadow_quality = quality;
     }
 
+    /// Switch every quality knob to `preset` at once.
+    ///
+    /// Runtime-adjustable settings (shadow filtering, GI cascades, SSAO
+    /// samples) apply from the next frame. A different shadow atlas size
+    /// needs new atlas textures, so it rebuilds the graph when a
+    /// [`GraphRebuilder`] is available and otherwise takes effect on the next
+    /// rebuild.
+    pub fn set_quality_preset(&mut self, preset: helio_core::QualityPreset) {
+        self.quality_preset = Some(preset);
+        self.shadow_quality = preset.shadow_quality();
+        self.graph.apply_quality(preset);
+        let atlas_size = preset.shadow_atlas_size();
+        if atlas_size != self.shadow_atlas_size {
+            self.shadow_atlas_size = atlas_size;
+            self.scene.set_shadow_atlas_size(atlas_size);
+            if self.graph_rebuilder.is_some() {
+                self.pending_resize = Some((self.output_width, self.output_height));
+            }
+        }
+    }
+
+    pub fn quality_preset(&self) -> Option<helio_core::QualityPreset> {
+        self.quality_preset
+    }
+
+    /// Clamp shadow-caster depth to each light's near plane instead of
+    /// clipping, so occluders very close to a light still cast shadows.
+    ///
+    /// Requires `Features::DEPTH_CLIP_CONTROL` (requested by
+    /// [`required_wgpu_features`](crate::required_wgpu_features) when the
+    /// adapter has it); otherwise the shadow pass logs a warning and keeps
+    /// clipping.
+    pub fn set_shadow_depth_clamp(&mut self, enabled: bool) {
+        self.shadow_depth_clamp = enabled;
+        self.apply_shadow_depth_clamp();
+    }
+
+    pub fn shadow_depth_clamp(&self) -> bool {
+        self.shadow_depth_clamp
+    }
+
+    pub(crate) fn apply_shadow_depth_clamp(&mut self) {
+        let enabled = self.shadow_depth_clamp;
+        if let Some(shadow) = self.graph.find_pass_mut::<helio_pass_shadow::ShadowPass>() {
+            shadow.set_depth_clamp(enabled);
+        }
+    }
+
+    /// Sample outside each shadow map as fully lit instead of clamping to the
+    /// edge texel, so spotlight shadows do not smear past the cone.
+    ///
+    /// Applies to every light. Requires `Features::ADDRESS_MODE_CLAMP_TO_BORDER`
+    /// (requested by [`required_wgpu_features`](crate::required_wgpu_features)
+    /// when the adapter has it); otherwise the shadow pass logs a warning and
+    /// keeps edge clamping.
+    pub fn set_shadow_border_clamp(&mut self, enabled: bool) {
+        self.shadow_border_clamp = enabled;
+        self.apply_shadow_border_clamp();
+    }
+
+    pub fn shadow_border_clamp(&self) -> bool {
+        self.shadow_border_clamp
+    }
+
+    pub(crate) fn apply_shadow_border_clamp(&mut self) {
+        let enabled = self.shadow_border_clamp;
+        if let Some(shadow) = self.graph.find_pass_mut::<helio_pass_shadow::ShadowPass>() {
+            shadow.set_border_clamp(enabled);
+        }
+    }
+
+    /// Choose which end of the depth range is near in the shadow atlases.
+    ///
+    /// The shadow pass flips its depth test, bias, clear value and
+    /// comparison sampler, the light matrices are built for the new range,
+    /// and every pass sampling the atlases flips its comparison to match, so
+    /// lit and shadowed regions come out the same either way. The main depth
+    /// buffer keeps the standard convention. Cached shadow faces re-render on
+    /// the next frame.
+    pub fn set_depth_convention(&mut self, convention: libhelio::DepthConvention) {
+        self.depth_convention = convention;
+        self.apply_depth_convention();
+    }
+
+    pub fn depth_convention(&self) -> libhelio::DepthConvention {
+        self.depth_convention
+    }
+
+    pub(crate) fn apply_depth_convention(&mut self) {
+        self.graph.set_depth_convention(self.depth_convention);
+    }
+
+    /// Choose how the final image is encoded for the display: sRGB or
+    /// Rec.709 for SDR, PQ for HDR10 (`Rgb10a2Unorm` in the HDR10 colour
+    /// space), or linear scRGB for `Rgba16Float` swapchains. `None` goes
+    /// back to deriving it from the surface format.
+    ///
+    /// Applied by the post-process pass as its last step; graphs without
+    /// one write linear colour regardless.
+    pub fn set_output_transfer(&mut self, transfer: Option<libhelio::OutputTransfer>) {
+        self.output_transfer = transfer;
+        self.apply_output_transfer();
+    }
+
+    /// The transfer function in effect, including the surface-format default.
+    pub fn output_transfer(&self) -> libhelio::OutputTransfer {
+        self.output_transfer
+            .unwrap_or_else(|| libhelio::OutputTransfer::for_surface_format(self.surface_format))
+    }
+
+    pub(crate) fn apply_output_transfer(&mut self) {
+        let transfer = self.output_transfer();
+        if let Some(pp) = self.graph.find_pass_mut::<helio_pass_postprocess::PostProcessPass>() {
+            pp.set_output_transfer(&self.device, transfer);
+        }
+    }
+
+    /// Frame clock that turns measured frame deltas into the clamped (and
+    /// optionally fixed-step) `delta_time` handed to passes.
+    pub fn frame_clock(&self) -> &helio_core::FrameClock {
+        &self.frame_clock
+    }
+
+    /// Configure delta clamping or switch to a fixed timestep.
+    pub fn frame_clock_mut(&mut self) -> &mut helio_core::FrameClock {
+        &mut self.frame_clock
+    }
+
+    /// Seed all stochastic sampling (TAA jitter, shadow/GI/fog noise, corona
+    /// particles). Frame N rendered with the same seed and inputs produces the
+    /// same noise; a different seed gives a different but equally
+    /// reproducible sequence. Default `0`.
+    pub fn set_random_seed(&mut self, seed: u64) {
+        self.random_seed = seed;
+    }
+
+    pub fn random_seed(&self) -> u64 {
+        self.random_seed
+    }
+
+    /// Deterministic mode for golden-image tests and reproducible captures.
+    ///
+    /// Frames advance by the frame clock's fixed step (1/60 s when none is
+    /// set) instead of measured wall-clock time, so time-based effects such
+    /// as eye adaptation, water and sky animation depend only on the frame
+    /// index. Combine with [`set_random_seed`](Self::set_random_seed) and a
+    /// fixed [`PreExposure`](super::PreExposure): auto pre-exposure follows
+    /// an asynchronous GPU readback whose latency varies.
+    pub fn set_deterministic(&mut self, enabled: bool) {
+        self.deterministic = enabled;
+    }
+
+    pub fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    /// Delta time (seconds) the last frame's passes advanced by.
+    pub fn delta_time(&self) -> f32 {
+        self.delta_time
+    }
+
+    /// Maximum number of frames the GPU may still be rendering when
+    /// [`render`](Self::render) returns (`1..=3`). See
+    /// [`RendererConfig::frames_in_flight`](super::RendererConfig::frames_in_flight).
+    pub fn set_frames_in_flight(&mut self, frames: u32) {
+        self.frames_in_flight = frames.clamp(1, helio_core::MAX_FRAMES_IN_FLIGHT);
+    }
+
+    pub fn frames_in_flight(&self) -> u32 {
+        self.frames_in_flight
+    }
+
+    /// Requests a new swapchain present mode.
+    ///
+    /// The renderer does not own the surface; the surface owner should read
+    /// [`present_mode`](Self::present_mode) and reconfigure with
+    /// [`PresentMode::resolve`] when it changes.
+    pub fn set_present_mode(&mut self, mode: PresentMode) {
+        self.present_mode = mode;
+    }
+
+    pub fn present_mode(&self) -> PresentMode {
+        self.present_mode
+    }
+
     /// Overrides the graph-derived per-frame camera-jitter setting.
     ///
     /// Graphs containing a temporal reconstruction pass enable jitter
@@ -422,13 +690,211 @@ impl Renderer {
         self.graph.find_pass::<T>()
     }
 
+    /// Move a graph pass to a new execution position. See [`RenderGraph::move_pass`].
+    pub fn move_pass(&mut self, name: &str, new_index: usize) -> helio_core::Result<()> {
+        self.graph.move_pass(name, new_index)
+    }
+
+    /// Reorder graph passes by name. See [`RenderGraph::set_pass_order`].
+    pub fn set_pass_order(&mut self, order: &[&str]) -> helio_core::Result<()> {
+        self.graph.set_pass_order(order)
+    }
+
+    /// Pause or resume a graph pass by name, leaving its outputs frozen while
+    /// paused. Survives graph rebuilds. See [`RenderGraph::set_pass_active`].
+    pub fn set_pass_active(&mut self, name: &str, active: bool) -> helio_core::Result<()> {
+        self.graph.set_pass_active(name, active)
+    }
+
+    /// Whether a graph pass runs, or `None` if no pass has that name.
+    pub fn is_pass_active(&self, name: &str) -> Option<bool> {
+        self.graph.is_pass_active(name)
+    }
+
+    /// Switch a set of named post-process effects on or off with a single
+    /// uber-shader rebuild. See [`PostProcessPass::apply_effect_set`].
+    ///
+    /// Returns the names that matched no effect. Without a post-process pass
+    /// in the graph every name is returned.
+    ///
+    /// [`PostProcessPass::apply_effect_set`]: helio_pass_postprocess::PostProcessPass::apply_effect_set
+    pub fn apply_feature_set(&mut self, features: &[(&str, bool)]) -> Vec<String> {
+        match self.graph.find_pass_mut::<helio_pass_postprocess::PostProcessPass>() {
+            Some(pp) => pp.apply_effect_set(&self.device, features),
+            None => features.iter().map(|(name, _)| name.to_string()).collect(),
+        }
+    }
+
     pub fn set_clear_color(&mut self, color: [f32; 4]) {
         self.clear_color = color;
     }
 
+    /// Whether the scene colour is cleared at the start of each frame or
+    /// keeps what the previous frame (or the host) left there.
+    ///
+    /// [`LoadOp::Load`](libhelio::LoadOp::Load) lets new geometry accumulate
+    /// over earlier frames or composite over a pre-rendered background. Sky
+    /// passes still draw the background where they are in the graph.
+    pub fn set_color_load_op(&mut self, op: libhelio::LoadOp) {
+        self.color_load_op = op;
+    }
+
+    pub fn color_load_op(&self) -> libhelio::LoadOp {
+        self.color_load_op
+    }
+
+    /// Whether the main depth buffer is reset to the far plane each frame or
+    /// keeps the previous frame's depth, so new geometry is occluded by it.
+    pub fn set_depth_load_op(&mut self, op: libhelio::LoadOp) {
+        self.depth_load_op = op;
+    }
+
+    pub fn depth_load_op(&self) -> libhelio::LoadOp {
+        self.depth_load_op
+    }
+
+    /// Set the ambient sky colour and intensity. The hemisphere ground colour
+    /// reverts to a darkened copy of `color`; see
+    /// [`set_hemisphere_ambient`](Self::set_hemisphere_ambient) to pick it.
     pub fn set_ambient(&mut self, color: [f32; 3], intensity: f32) {
         self.ambient_color = color;
         self.ambient_intensity = intensity;
+        self.ambient_ground_color = None;
+    }
+
+    /// Hemisphere ambient: `sky_color` lights normals facing up, `ground_color`
+    /// normals facing down, blended by the normal's Y. Both are scaled by the
+    /// ambient intensity. Like the constant ambient it gives way to radiance
+    /// cascade GI and lightmaps, and a gradient sky background overrides it.
+    pub fn set_hemisphere_ambient(&mut self, sky_color: [f32; 3], ground_color: [f32; 3]) {
+        self.ambient_color = sky_color;
+        self.ambient_ground_color = Some(ground_color);
+    }
+
+    /// Directional ambient from spherical harmonics, replacing the sky and
+    /// ground colours (and a gradient sky's ambient) until cleared. The
+    /// ambient intensity still scales it.
+    ///
+    /// Build one with [`AmbientSh::from_hemisphere`](libhelio::AmbientSh::from_hemisphere),
+    /// [`from_gradient_sky`](libhelio::AmbientSh::from_gradient_sky), or
+    /// [`from_radiance`](libhelio::AmbientSh::from_radiance) for any sky model.
+    /// Like the hemisphere it gives way to radiance cascade GI and lightmaps.
+    pub fn set_ambient_sh(&mut self, sh: libhelio::AmbientSh) {
+        self.ambient_sh = Some(sh);
+    }
+
+    /// Go back to the hemisphere ambient after
+    /// [`set_ambient_sh`](Self::set_ambient_sh).
+    pub fn clear_ambient_sh(&mut self) {
+        self.ambient_sh = None;
+    }
+
+    pub fn ambient_sh(&self) -> Option<libhelio::AmbientSh> {
+        self.ambient_sh
+    }
+
+    /// Add a non-shadowing directional fill light to the ambient term.
+    ///
+    /// It never enters the shadow pass or the light list, so it is a cheap way
+    /// to shape forms from the side opposite the key light. `direction` is the
+    /// direction the light travels.
+    pub fn set_fill_light(&mut self, direction: [f32; 3], color: [f32; 3], intensity: f32) {
+        self.fill_light = Some(libhelio::FillLight { direction, color, intensity });
+    }
+
+    /// Remove the fill light set by [`set_fill_light`](Self::set_fill_light).
+    pub fn disable_fill_light(&mut self) {
+        self.fill_light = None;
+    }
+
+    /// Drive the sun from a time of day, in hours of local solar time
+    /// (`12.0` is noon).
+    ///
+    /// Updates the key directional light (the first directional in the
+    /// scene's light buffer; see
+    /// [`MAX_DIRECTIONAL_SHADOW_CASTERS`](libhelio::MAX_DIRECTIONAL_SHADOW_CASTERS))
+    /// with the sun's direction, colour and intensity, points the atmosphere's
+    /// sun the same way, and sets the ambient term for the sun's elevation.
+    /// Latitude, season and colours come from the current [`TimeOfDay`]
+    /// (the default one on first use); see
+    /// [`set_time_of_day_settings`](Self::set_time_of_day_settings).
+    ///
+    /// [`TimeOfDay`]: libhelio::TimeOfDay
+    pub fn set_time_of_day(&mut self, hours: f32) {
+        let mut time_of_day = self.time_of_day.unwrap_or_default();
+        time_of_day.hours = hours.rem_euclid(24.0);
+        self.set_time_of_day_settings(time_of_day);
+    }
+
+    /// Like [`set_time_of_day`](Self::set_time_of_day), with full control
+    /// over latitude, declination and the sunrise/sunset and ambient colours.
+    pub fn set_time_of_day_settings(&mut self, time_of_day: libhelio::TimeOfDay) {
+        self.time_of_day = Some(time_of_day);
+        self.apply_time_of_day();
+    }
+
+    /// Advance the time of day by `rate` hours per second of the last
+    /// frame's [`delta_time`](Self::delta_time), wrapping past midnight.
+    /// Call once per frame before [`render`](Self::render); does nothing
+    /// until a time of day has been set.
+    pub fn advance_time(&mut self, rate: f32) {
+        let Some(mut time_of_day) = self.time_of_day else { return };
+        time_of_day.advance(rate * self.delta_time);
+        self.set_time_of_day_settings(time_of_day);
+    }
+
+    pub fn time_of_day(&self) -> Option<&libhelio::TimeOfDay> {
+        self.time_of_day.as_ref()
+    }
+
+    /// Stop driving the sun. The key light and ambient keep their last
+    /// values, and the atmosphere returns to its built-in sun.
+    pub fn clear_time_of_day(&mut self) {
+        self.time_of_day = None;
+    }
+
+    pub(crate) fn apply_time_of_day(&mut self) {
+        let Some(time_of_day) = self.time_of_day else { return };
+        let key_light = self
+            .scene
+            .iter_lights()
+            .find(|(_, light, _)| light.light_type == libhelio::LightType::Directional as u32)
+            .map(|(id, light, _)| (id, *light));
+        if let Some((id, mut light)) = key_light {
+            let [x, y, z] = time_of_day.light_direction();
+            let (color, intensity) = time_of_day.sun_color_intensity();
+            light.direction_outer = [x, y, z, light.direction_outer[3]];
+            light.color_intensity = [color[0], color[1], color[2], intensity];
+            let updated = self.scene.update_light(id, light);
+            debug_assert!(updated.is_ok());
+        }
+        (self.ambient_color, self.ambient_intensity) = time_of_day.ambient();
+    }
+
+    /// Fade shadows to fully lit between `start` and `end` metres from the
+    /// camera, so they blend out instead of stopping at the last cascade or
+    /// a light's shadow range. `end <= start` (the default) disables the fade.
+    pub fn set_shadow_fade_range(&mut self, start: f32, end: f32) {
+        self.shadow_fade_range = [start, end];
+    }
+
+    pub fn shadow_fade_range(&self) -> [f32; 2] {
+        self.shadow_fade_range
+    }
+
+    /// Render each shadow caster at a resolution scaled by its light's
+    /// screen coverage, stepping casters down until the total fits the
+    /// budget. `None` renders every caster at full atlas resolution.
+    ///
+    /// Tiers are re-evaluated every frame with hysteresis; a caster that
+    /// changes tier re-renders its faces.
+    pub fn set_adaptive_shadow_resolution(&mut self, adaptive: Option<libhelio::AdaptiveShadowResolution>) {
+        self.adaptive_shadow_resolution = adaptive;
+        self.scene.set_adaptive_shadow_resolution(adaptive);
+    }
+
+    pub fn adaptive_shadow_resolution(&self) -> Option<libhelio::AdaptiveShadowResolution> {
+        self.adaptive_shadow_resolution
     }
 
     pub fn set_graph(&mut self, mut graph: RenderGraph) {
@@ -530,6 +996,15 @@ impl Renderer {
             perf_overlay_mode: PerfOverlayMode::Disabled,
             shadow_atlas_size: self.shadow_atlas_size,
             shadow_face_capacity: self.shadow_face_capacity,
+            adaptive_shadow_resolution: self.adaptive_shadow_resolution,
+            present_mode: self.present_mode,
+            quality_preset: self.quality_preset,
+            shadow_depth_clamp: self.shadow_depth_clamp,
+            shadow_border_clamp: self.shadow_border_clamp,
+            depth_convention: self.depth_convention,
+            output_transfer: self.output_transfer,
+            gpu_memory_budget: self.gpu_memory_budget,
+            frames_in_flight: self.frames_in_flight,
         }
     }
 }
@@ -3,7 +3,6 @@ use std::time::Instant;
 #[cfg(target_arch = "wasm32")]
 use web_time::Instant;
 
-use super::config::{PerfOverlayMode, RendererConfig};
 use super::renderer_impl::Renderer;
 
 impl Renderer {
@@ -48,29 +47,7 @@ impl Renderer {
 
         self.clear_target_next_frame = true;
 
-        if let Some(rebuilder) = &self.graph_rebuilder {
-            let config = RendererConfig {
-                width,
-                height,
-                surface_format: self.surface_format,
-                gi_config: self.gi_config,
-                shadow_quality: self.shadow_quality,
-                debug_mode: self.debug_mode,
-                render_scale: self.render_scale,
-                perf_overlay_mode: PerfOverlayMode::Disabled,
-                shadow_atlas_size: self.shadow_atlas_size,
-                shadow_face_capacity: self.shadow_face_capacity,
-            };
-            self.graph = rebuilder(
-                &self.device,
-                &self.queue,
-                &self.scene,
-                config,
-                self.debug_state.clone(),
-                &self.debug_camera_buffer,
-                &self.cull_stats_buffer,
-            );
-        } else {
+        if !self.rebuild_graph() {
             self.graph.set_render_size(internal_w, internal_h);
         }
 
@@ -82,6 +59,37 @@ impl Renderer {
         );
     }
 
+    /// Rebuild the graph from scratch through the [`GraphRebuilder`](super::GraphRebuilder)
+    /// and reapply renderer-level pass settings. Returns `false` when there is
+    /// no rebuilder, leaving the current graph untouched.
+    pub(crate) fn rebuild_graph(&mut self) -> bool {
+        let Some(rebuilder) = &self.graph_rebuilder else {
+            return false;
+        };
+        let paused: Vec<String> = self.graph.paused_passes().map(str::to_string).collect();
+        self.graph = rebuilder(
+            &self.device,
+            &self.queue,
+            &self.scene,
+            self.renderer_config(),
+            self.debug_state.clone(),
+            &self.debug_camera_buffer,
+            &self.cull_stats_buffer,
+        );
+        if let Some(preset) = self.quality_preset {
+            self.graph.apply_quality(preset);
+        }
+        self.apply_shadow_depth_clamp();
+        self.apply_shadow_border_clamp();
+        self.apply_depth_convention();
+        self.apply_output_transfer();
+        for name in paused {
+            // A rebuilt graph may no longer contain the pass.
+            let _ = self.graph.set_pass_active(&name, false);
+        }
+        true
+    }
+
     pub fn set_render_scale(&mut self, scale: f32) {
         self.render_scale = scale.clamp(0.25, 1.0);
         self.set_render_size(self.output_width, self.output_height);
@@ -14,6 +14,77 @@ use super::renderer_impl::{
     CullStatsReadbackState, DebugCameraUniform, GraphRebuilder, Renderer, HALTON_JITTER,
 };
 
+/// Renderer-owned GPU buffers that do not depend on the render size.
+/// Created at construction and again by [`Renderer::reinitialize`].
+pub(crate) struct RendererBuffers {
+    pub(crate) water_volumes_buffer: wgpu::Buffer,
+    pub(crate) water_hitboxes_buffer: wgpu::Buffer,
+    pub(crate) pp_volumes_buffer: wgpu::Buffer,
+    pub(crate) postprocess_buffer: wgpu::Buffer,
+    pub(crate) cull_stats_staging: wgpu::Buffer,
+    pub(crate) luminance_staging: wgpu::Buffer,
+}
+
+impl RendererBuffers {
+    pub(crate) fn new(device: &wgpu::Device) -> Self {
+        let water_volumes_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Water Volumes Buffer"),
+            size: 256 * 256,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let water_hitboxes_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Water Hitboxes Buffer"),
+            size: 256 * 80,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let pp_volumes_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("PostProcess Volumes Buffer"),
+            size: 256 * std::mem::size_of::<libhelio::GpuPostProcessVolume>() as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let postprocess_buf_size = std::mem::size_of::<libhelio::GpuPostProcessUniforms>() as u64;
+        let postprocess_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("PostProcess Uniforms Buffer"),
+            size: postprocess_buf_size,
+            // COPY_SRC: VolumetricFogPass copies the fog block out of this buffer
+            // rather than mirroring the whole 368-byte struct in its shader.
+            usage: wgpu::BufferUsages::UNIFORM
+                | wgpu::BufferUsages::COPY_DST
+                | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
+        let cull_stats_staging = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("CullStats Staging"),
+            size: 32,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let luminance_staging = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Luminance Staging"),
+            size: 4,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        Self {
+            water_volumes_buffer,
+            water_hitboxes_buffer,
+            pp_volumes_buffer,
+            postprocess_buffer,
+            cull_stats_staging,
+            luminance_staging,
+        }
+    }
+}
+
 impl Renderer {
     pub(crate) fn compute_jitter_matrices(width: u32, height: u32) -> [glam::Mat4; 16] {
         let mut matrices = [glam::Mat4::IDENTITY; 16];
@@ -65,7 +136,30 @@ impl Renderer {
         cull_stats_buffer: wgpu::Buffer,
     ) -> Self {
         scene.set_shadow_face_capacity(config.shadow_face_capacity);
+        scene.set_adaptive_shadow_resolution(config.adaptive_shadow_resolution);
+        scene.set_shadow_atlas_size(config.shadow_atlas_size);
         scene.set_render_size(width, height);
+        if let Some(preset) = config.quality_preset {
+            graph.apply_quality(preset);
+        }
+        if config.shadow_depth_clamp {
+            if let Some(shadow) = graph.find_pass_mut::<helio_pass_shadow::ShadowPass>() {
+                shadow.set_depth_clamp(true);
+            }
+        }
+        if config.shadow_border_clamp {
+            if let Some(shadow) = graph.find_pass_mut::<helio_pass_shadow::ShadowPass>() {
+                shadow.set_border_clamp(true);
+            }
+        }
+        if config.depth_convention.is_reversed() {
+            graph.set_depth_convention(config.depth_convention);
+        }
+        if let Some(transfer) = config.output_transfer {
+            if let Some(pp) = graph.find_pass_mut::<helio_pass_postprocess::PostProcessPass>() {
+                pp.set_output_transfer(&device, transfer);
+            }
+        }
 
         assert!(
             device
@@ -89,48 +183,10 @@ impl Renderer {
             (None, None)
         };
 
-        let water_volumes_buffer = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("Water Volumes Buffer"),
-            size: 256 * 256,
-            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
-            mapped_at_creation: false,
-        });
-
-        let water_hitboxes_buffer = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("Water Hitboxes Buffer"),
-            size: 256 * 80,
-            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
-            mapped_at_creation: false,
-        });
-
-        let pp_volumes_buffer = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("PostProcess Volumes Buffer"),
-            size: 256 * std::mem::size_of::<libhelio::GpuPostProcessVolume>() as u64,
-            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
-            mapped_at_creation: false,
-        });
-
-        let postprocess_buf_size = std::mem::size_of::<libhelio::GpuPostProcessUniforms>() as u64;
-        let postprocess_buffer = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("PostProcess Uniforms Buffer"),
-            size: postprocess_buf_size,
-            // COPY_SRC: VolumetricFogPass copies the fog block out of this buffer
-            // rather than mirroring the whole 368-byte struct in its shader.
-            usage: wgpu::BufferUsages::UNIFORM
-                | wgpu::BufferUsages::COPY_DST
-                | wgpu::BufferUsages::COPY_SRC,
-            mapped_at_creation: false,
-        });
+        let buffers = RendererBuffers::new(&device);
 
         let jitter_matrices = Self::compute_jitter_matrices(internal_w, internal_h);
 
-        let cull_stats_staging = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("CullStats Staging"),
-            size: 32,
-            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
-            mapped_at_creation: false,
-        });
-
         // Camera jitter is only valid when a temporal pass reconstructs it.
         // Applying it to FXAA/non-temporal graphs shifts the final image every
         // frame and presents as whole-scene shimmer.
@@ -142,6 +198,7 @@ impl Renderer {
         Self {
             device,
             queue,
+            adapter: None,
             graph,
             scene,
             depth_texture,
@@ -155,11 +212,27 @@ impl Renderer {
             debug_camera_buffer,
             ambient_color: [0.05, 0.05, 0.08],
             ambient_intensity: 1.0,
+            ambient_ground_color: None,
+            ambient_sh: None,
+            fill_light: None,
+            time_of_day: None,
+            shadow_fade_range: [0.0, 0.0],
             clear_color: [0.02, 0.02, 0.03, 1.0],
+            color_load_op: libhelio::LoadOp::Clear,
+            depth_load_op: libhelio::LoadOp::Clear,
             gi_config: config.gi_config,
             shadow_quality: config.shadow_quality,
             shadow_atlas_size: config.shadow_atlas_size,
             shadow_face_capacity: config.shadow_face_capacity,
+            adaptive_shadow_resolution: config.adaptive_shadow_resolution,
+            present_mode: config.present_mode,
+            quality_preset: config.quality_preset,
+            shadow_depth_clamp: config.shadow_depth_clamp,
+            shadow_border_clamp: config.shadow_border_clamp,
+            depth_convention: config.depth_convention,
+            output_transfer: config.output_transfer,
+            gpu_memory_budget: config.gpu_memory_budget,
+            over_gpu_memory_budget: false,
             debug_mode: config.debug_mode,
             editor_mode: false,
             debug_state,
@@ -173,15 +246,24 @@ impl Renderer {
             billboard_generation: 0,
             corona_emitters: Vec::new(),
             corona_emitter_generation: 0,
-            water_volumes_buffer,
-            water_hitboxes_buffer,
-            pp_volumes_buffer,
-            postprocess_buffer,
+            water_volumes_buffer: buffers.water_volumes_buffer,
+            water_hitboxes_buffer: buffers.water_hitboxes_buffer,
+            pp_volumes_buffer: buffers.pp_volumes_buffer,
+            postprocess_buffer: buffers.postprocess_buffer,
             last_render_time: Instant::now(),
             delta_time: 0.0,
-            cull_stats_staging,
+            frame_clock: helio_core::FrameClock::new(),
+            random_seed: 0,
+            deterministic: false,
+            frames_in_flight: config.frames_in_flight.clamp(1, helio_core::MAX_FRAMES_IN_FLIGHT),
+            cull_stats_staging: buffers.cull_stats_staging,
             cull_stats_readback_state: CullStatsReadbackState::Idle,
             cull_stats: [0; 8],
+            pre_exposure_mode: super::PreExposure::default(),
+            pre_exposure: 1.0,
+            luminance_staging: buffers.luminance_staging,
+            luminance_readback_state: CullStatsReadbackState::Idle,
+            avg_log_luminance: None,
             graph_time_ms: 0.0,
             frame_times: vec![0.0; 200],
             frame_times_cursor: 0,
@@ -195,6 +277,7 @@ impl Renderer {
             baked_data: None,
             clear_target_next_frame: true,
             owns_device: true,
+            minimap: None,
             pending_resize: None,
             // Note: pending_resize is intentionally None on init. The graph's
             // lock() already sized textures to config.width/height. Setting it
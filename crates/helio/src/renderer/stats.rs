@@ -0,0 +1,177 @@
+//! Per-frame rendering statistics for profiling HUDs and bug reports.
+
+use super::renderer_impl::Renderer;
+
+/// Snapshot of what the renderer submitted and how much GPU memory it holds.
+///
+/// Geometry counts describe the scene's object draws (before GPU culling).
+/// Culling counts come from an asynchronous readback and trail the current
+/// frame by one or two frames; they stay zero when the renderer does not own
+/// its device.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FrameStats {
+    /// Triangles in all object draws, instancing included.
+    pub triangles: u64,
+    /// Instanced object draw batches handed to GPU culling.
+    pub draw_calls: u32,
+    /// Draw batches rejected by frustum, sub-pixel or occlusion culling.
+    pub culled_draws: u32,
+    /// Passes in the render graph.
+    pub render_passes: u32,
+    /// Shadow atlas face passes recorded last frame (0 when fully cached).
+    pub shadow_faces: u32,
+    /// GPU memory held by renderer-owned resources.
+    pub memory: GpuMemoryStats,
+}
+
+/// Estimated GPU memory held by renderer-owned resources, in bytes.
+///
+/// Covers textures and the larger fixed buffers; pipelines, bind groups and
+/// scene geometry buffers are not included.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GpuMemoryStats {
+    /// Graph-owned textures: render targets, G-buffer, shadow atlases.
+    pub graph_textures: u64,
+    /// Scene depth buffers owned by the renderer.
+    pub depth_targets: u64,
+    /// Resources passes allocate themselves (e.g. GI cascades), per pass name.
+    pub passes: Vec<(&'static str, u64)>,
+}
+
+impl GpuMemoryStats {
+    pub fn total(&self) -> u64 {
+        self.graph_textures
+            + self.depth_targets
+            + self.passes.iter().map(|(_, bytes)| bytes).sum::<u64>()
+    }
+
+    /// Every line item, graph textures and depth targets included, largest
+    /// first.
+    pub fn by_size(&self) -> Vec<(&'static str, u64)> {
+        let mut items = vec![
+            ("graph textures", self.graph_textures),
+            ("depth targets", self.depth_targets),
+        ];
+        items.extend(self.passes.iter().copied());
+        items.sort_by_key(|&(_, bytes)| std::cmp::Reverse(bytes));
+        items
+    }
+}
+
+impl Renderer {
+    /// Collect statistics for the most recently rendered frame.
+    pub fn frame_stats(&self) -> FrameStats {
+        let draw_calls = self.scene.gpu_scene().draw_calls.as_slice();
+        let triangles = draw_calls
+            .iter()
+            .map(|dc| (dc.index_count / 3) as u64 * dc.instance_count as u64)
+            .sum();
+        // Slots written by the indirect-dispatch and occlusion-cull shaders:
+        // [1] frustum culled, [2] sub-pixel, [4] occluded.
+        let culled_draws = self.cull_stats[1] + self.cull_stats[2] + self.cull_stats[4];
+        let shadow_faces = self
+            .graph
+            .find_pass::<helio_pass_shadow::ShadowPass>()
+            .map_or(0, |shadow| shadow.faces_rendered());
+
+        FrameStats {
+            triangles,
+            draw_calls: draw_calls.len() as u32,
+            culled_draws,
+            render_passes: self.graph.pass_count() as u32,
+            shadow_faces,
+            memory: self.gpu_memory_stats(),
+        }
+    }
+
+    /// Estimated GPU memory held by renderer-owned resources, per feature.
+    pub fn gpu_memory_stats(&self) -> GpuMemoryStats {
+        let depth_bytes = |tex: &wgpu::Texture| {
+            tex.width() as u64 * tex.height() as u64 * tex.depth_or_array_layers() as u64 * 4
+        };
+        let depth_targets = depth_bytes(&self.depth_texture)
+            + self.full_res_depth_texture.as_ref().map_or(0, depth_bytes);
+        GpuMemoryStats {
+            graph_textures: self.graph.texture_memory_bytes(),
+            depth_targets,
+            passes: self.graph.pass_memory(),
+        }
+    }
+
+    /// Total of [`gpu_memory_stats`](Self::gpu_memory_stats), in bytes.
+    pub fn total_gpu_memory(&self) -> u64 {
+        self.gpu_memory_stats().total()
+    }
+
+    /// Warn when the estimated GPU memory exceeds `budget`, or `None` to
+    /// stop checking.
+    ///
+    /// Checked after every frame. A warning listing the largest consumers is
+    /// logged each time usage crosses over the budget, not on every frame it
+    /// stays over.
+    pub fn set_gpu_memory_budget(&mut self, budget: Option<u64>) {
+        self.gpu_memory_budget = budget;
+        self.over_gpu_memory_budget = false;
+    }
+
+    pub fn gpu_memory_budget(&self) -> Option<u64> {
+        self.gpu_memory_budget
+    }
+
+    /// Whether the last check found usage over the budget.
+    pub fn is_over_gpu_memory_budget(&self) -> bool {
+        self.over_gpu_memory_budget
+    }
+
+    pub(crate) fn check_gpu_memory_budget(&mut self) {
+        let Some(budget) = self.gpu_memory_budget else {
+            return;
+        };
+        let stats = self.gpu_memory_stats();
+        let total = stats.total();
+        let over = total > budget;
+        if over && !self.over_gpu_memory_budget {
+            let largest: Vec<String> = stats
+                .by_size()
+                .into_iter()
+                .take(4)
+                .map(|(name, bytes)| format!("{name} {}", mib(bytes)))
+                .collect();
+            log::warn!(
+                "GPU memory {} exceeds the {} budget; largest: {}",
+                mib(total),
+                mib(budget),
+                largest.join(", "),
+            );
+        }
+        self.over_gpu_memory_budget = over;
+    }
+}
+
+fn mib(bytes: u64) -> String {
+    format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GpuMemoryStats;
+
+    #[test]
+    fn memory_line_items_sort_largest_first() {
+        let stats = GpuMemoryStats {
+            graph_textures: 300,
+            depth_targets: 50,
+            passes: vec![("Shadow", 10), ("RadianceCascades", 500)],
+        };
+        assert_eq!(stats.total(), 860);
+        assert_eq!(
+            stats.by_size(),
+            [
+                ("RadianceCascades", 500),
+                ("graph textures", 300),
+                ("depth targets", 50),
+                ("Shadow", 10),
+            ]
+        );
+    }
+}
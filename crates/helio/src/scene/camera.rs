@@ -1,6 +1,6 @@
 //! Camera types and constructors, and scene camera update logic.
 
-use glam::{Mat4, Vec3};
+use glam::{Mat4, Vec3, Vec4};
 use helio_core::GpuCameraUniforms;
 use libhelio::PostProcessSettings;
 
@@ -125,6 +125,68 @@ impl Camera {
         let proj = Mat4::perspective_rh(fov_y_radians, aspect, near, far);
         Self::from_matrices(view, proj, position, near, far)
     }
+
+    /// The camera's view frustum in world space, for culling on the CPU.
+    ///
+    /// Ignores `jitter`; TAA offsets are sub-pixel and never change what is
+    /// visible.
+    pub fn frustum(&self) -> Frustum {
+        Frustum::from_view_proj(self.proj * self.view)
+    }
+}
+
+/// Six clip planes of a view-projection, in world space.
+///
+/// Planes point inward: `dot(n, p) + d >= 0` for points on the visible side.
+/// Tests are conservative (an AABB straddling two planes outside the frustum
+/// near a corner still counts as visible) and allocation-free, so they can
+/// run per object per frame.
+///
+/// # Example
+/// ```ignore
+/// let frustum = camera.frustum();
+/// for mesh in &meshes {
+///     if frustum.intersects_aabb(mesh.world_min, mesh.world_max) {
+///         draw(mesh);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frustum {
+    /// Left, right, bottom, top, near, far: `xyz` normal, `w` distance.
+    pub planes: [Vec4; 6],
+}
+
+impl Frustum {
+    /// Extract the planes of a view-projection matrix with wgpu's `0..1` clip
+    /// depth (Gribb/Hartmann). Works for standard, reversed-Z and infinite
+    /// projections; a plane at infinity never rejects anything.
+    pub fn from_view_proj(view_proj: Mat4) -> Self {
+        let m = view_proj.transpose();
+        let (x, y, z, w) = (m.x_axis, m.y_axis, m.z_axis, m.w_axis);
+        let planes = [w + x, w - x, w + y, w - y, z, w - z].map(|plane| {
+            let length = plane.truncate().length();
+            if length > 0.0 { plane / length } else { plane }
+        });
+        Self { planes }
+    }
+
+    /// Whether any part of the box `min..=max` may be inside the frustum.
+    pub fn intersects_aabb(&self, min: Vec3, max: Vec3) -> bool {
+        self.planes.iter().all(|plane| {
+            // The box corner furthest along the plane normal.
+            let normal = plane.truncate();
+            let corner = Vec3::select(normal.cmpge(Vec3::ZERO), max, min);
+            normal.dot(corner) + plane.w >= 0.0
+        })
+    }
+
+    /// Whether any part of the sphere may be inside the frustum.
+    pub fn intersects_sphere(&self, center: Vec3, radius: f32) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.truncate().dot(center) + plane.w >= -radius)
+    }
 }
 
 impl Scene {
@@ -164,7 +226,7 @@ impl Scene {
     /// scene.update_camera(camera);
     /// ```
     pub fn update_camera(&mut self, camera: Camera) {
-        let uniforms = GpuCameraUniforms::new(
+        let mut uniforms = GpuCameraUniforms::new(
             camera.view,
             camera.proj,
             camera.position,
@@ -174,6 +236,7 @@ impl Scene {
             camera.jitter,
             self.prev_view_proj,
         );
+        uniforms.jitter_frame[3] = self.pre_exposure;
         // Store the UNJITTERED view_proj so next frame's motion-vector
         // reprojection (prev_view_proj) is not contaminated by this frame's jitter.
         let inv_jitter = Mat4::from_translation(glam::Vec3::new(
@@ -184,4 +247,50 @@ impl Scene {
         self.gpu_scene.camera.update(uniforms);
         self.gpu_scene.camera_generation = self.gpu_scene.camera_generation.wrapping_add(1);
     }
+
+    /// Set the pre-exposure written into the camera uniform by the next
+    /// [`update_camera`](Self::update_camera). The renderer resolves this from
+    /// [`Renderer::set_pre_exposure`](crate::Renderer::set_pre_exposure) each frame.
+    pub(crate) fn set_pre_exposure(&mut self, pre_exposure: f32) {
+        self.pre_exposure = pre_exposure;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frustum_rejects_boxes_outside_each_plane() {
+        let camera = Camera::perspective_look_at(
+            Vec3::ZERO,
+            Vec3::NEG_Z,
+            Vec3::Y,
+            90.0_f32.to_radians(),
+            1.0,
+            0.1,
+            100.0,
+        );
+        let frustum = camera.frustum();
+        let unit = |center: Vec3| frustum.intersects_aabb(center - Vec3::splat(0.5), center + Vec3::splat(0.5));
+
+        assert!(unit(Vec3::new(0.0, 0.0, -10.0)));
+        // Straddling the right plane still counts.
+        assert!(unit(Vec3::new(10.2, 0.0, -10.0)));
+        assert!(!unit(Vec3::new(12.0, 0.0, -10.0)));
+        assert!(!unit(Vec3::new(0.0, -12.0, -10.0)));
+        assert!(!unit(Vec3::new(0.0, 0.0, 5.0)));
+        assert!(!unit(Vec3::new(0.0, 0.0, -101.0)));
+
+        assert!(frustum.intersects_sphere(Vec3::new(0.0, 0.0, 1.0), 1.5));
+        assert!(!frustum.intersects_sphere(Vec3::new(0.0, 0.0, -102.0), 1.0));
+    }
+
+    #[test]
+    fn infinite_reversed_z_has_no_far_plane() {
+        let proj = Mat4::perspective_infinite_reverse_rh(90.0_f32.to_radians(), 1.0, 0.1);
+        let frustum = Frustum::from_view_proj(proj);
+        assert!(frustum.intersects_sphere(Vec3::new(0.0, 0.0, -1.0e6), 1.0));
+        assert!(!frustum.intersects_sphere(Vec3::new(0.0, 0.0, -0.01), 0.001));
+    }
 }
@@ -49,6 +49,13 @@ pub struct Scene {
     /// Texture binding version (increments on add/remove)
     pub(in crate::scene) texture_binding_version: u64,
 
+    /// One sampler per distinct sampler state, shared by all textures using it
+    pub(in crate::scene) sampler_cache: HashMap<crate::material::TextureSamplerDesc, wgpu::Sampler>,
+
+    /// Sampler-override texture slots, keyed by base texture and sampler state
+    pub(in crate::scene) texture_variants:
+        HashMap<(TextureId, crate::material::TextureSamplerDesc), TextureId>,
+
     /// Material texture storage buffer (GPU-side texture descriptors)
     pub(in crate::scene) material_textures: GrowableBuffer<crate::material::GpuMaterialTextures>,
 
@@ -91,6 +98,9 @@ pub struct Scene {
     /// Previous frame's view-projection matrix (for temporal effects)
     pub(in crate::scene) prev_view_proj: glam::Mat4,
 
+    /// Pre-exposure written into the camera uniform by `update_camera`.
+    pub(in crate::scene) pre_exposure: f32,
+
     /// Bitmask of currently hidden groups — bit N = GroupId(N) is hidden.
     /// An object is invisible if any of its groups intersects this mask.
     pub(in crate::scene) group_hidden: GroupMask,
@@ -107,6 +117,24 @@ pub struct Scene {
     /// Six consecutive layers are reserved per realtime shadow caster.
     pub(in crate::scene) shadow_face_capacity: u32,
 
+    /// Screen-coverage-driven shadow resolution; `None` renders every caster
+    /// at full resolution.
+    pub(in crate::scene) adaptive_shadow_resolution: Option<libhelio::AdaptiveShadowResolution>,
+    /// Side length of a full-resolution shadow face, for per-light
+    /// resolution overrides.
+    pub(in crate::scene) shadow_atlas_size: u32,
+
+    /// Embedded checkerboard and the material substituted for failed assets,
+    /// created on first use.
+    pub(in crate::scene) error_texture: Option<TextureId>,
+    pub(in crate::scene) error_material: Option<MaterialId>,
+    /// Assets replaced by the error material, oldest first.
+    pub(in crate::scene) load_failures: Vec<super::resources::LoadFailure>,
+
+    /// Background loads waiting to be uploaded on the next flush.
+    #[cfg(feature = "async")]
+    pub(in crate::scene) async_loads: super::resources::AsyncLoadQueue,
+
     /// Per-frame custom trait-based scene actors.
     pub(in crate::scene) custom_actors: Vec<Box<dyn SceneActorTrait>>,
 
@@ -149,6 +177,9 @@ pub struct Scene {
     /// Instance data for all VG objects (one entry per VG object, in order).
     pub(in crate::scene) vg_cpu_instances: Vec<helio_core::GpuInstanceData>,
 
+    /// Wind sway for all VG objects, parallel to `vg_cpu_instances`.
+    pub(in crate::scene) vg_cpu_wind: Vec<libhelio::GpuWindParams>,
+
     /// Immutable 64-meshlet expansion spans for the second GPU cull stage.
     pub(in crate::scene) vg_cpu_work_items: Vec<libhelio::GpuVgWorkItem>,
 
@@ -275,6 +306,8 @@ impl Scene {
             gpu_scene: GpuScene::new(device.clone(), queue.clone()),
             textures: SparsePool::new(),
             texture_binding_version: 0,
+            sampler_cache: HashMap::new(),
+            texture_variants: HashMap::new(),
             material_textures: GrowableBuffer::new(
                 device,
                 256,
@@ -294,10 +327,18 @@ impl Scene {
             static_objects_dirty: true,      // rebuild static shadow atlas on first flush
             bake_invalidated: false,         // no bake configured yet
             prev_view_proj: glam::Mat4::IDENTITY,
+            pre_exposure: 1.0,
             group_hidden: GroupMask::NONE,
             movable_objects_generation: 0,
             movable_lights_generation: 0,
             shadow_face_capacity: 32,
+            adaptive_shadow_resolution: None,
+            shadow_atlas_size: 1024,
+            error_texture: None,
+            error_material: None,
+            load_failures: Vec::new(),
+            #[cfg(feature = "async")]
+            async_loads: super::resources::AsyncLoadQueue::new(),
             custom_actors: Vec::new(),
             vg_meshes: HashMap::new(),
             vg_next_mesh_id: 0,
@@ -310,6 +351,7 @@ impl Scene {
             vg_cpu_meshlets: Vec::new(),
             vg_cpu_objects: Vec::new(),
             vg_cpu_instances: Vec::new(),
+            vg_cpu_wind: Vec::new(),
             vg_cpu_work_items: Vec::new(),
             vg_max_draw_count: 0,
             radiant_graphs: RadiantGraphRegistry::new(),
@@ -334,6 +376,17 @@ impl Scene {
         self.shadow_face_capacity = capacity.clamp(1, 256);
     }
 
+    pub(crate) fn set_shadow_atlas_size(&mut self, size: u32) {
+        self.shadow_atlas_size = size.max(1);
+    }
+
+    pub(crate) fn set_adaptive_shadow_resolution(
+        &mut self,
+        adaptive: Option<libhelio::AdaptiveShadowResolution>,
+    ) {
+        self.adaptive_shadow_resolution = adaptive;
+    }
+
     pub fn insert_voxel_volume(
         &mut self,
         descriptor: VoxelVolumeDescriptor,
@@ -396,6 +449,47 @@ impl Scene {
         Ok(())
     }
 
+    /// Draw a material as a procedural checker, grid or dots pattern.
+    ///
+    /// Switches the material to [`MATERIAL_CLASS_PATTERN`](libhelio::MATERIAL_CLASS_PATTERN)
+    /// and uploads the pattern parameters; call again to change them at runtime.
+    /// Restore a regular surface with [`set_material_class`](Self::set_material_class).
+    pub fn set_material_pattern(
+        &mut self,
+        material_id: MaterialId,
+        pattern: libhelio::ProceduralPattern,
+    ) -> Result<()> {
+        let Some((slot, record)) = self.materials.get_mut_with_slot(material_id) else {
+            return Err(invalid("material"));
+        };
+        record.gpu.material_class = libhelio::MATERIAL_CLASS_PATTERN;
+        record.gpu.class_params = pattern.class_params();
+        let updated = self.gpu_scene.materials.update(slot, record.gpu);
+        debug_assert!(updated);
+        Ok(())
+    }
+
+    /// Texture a material by triplanar projection instead of mesh UVs.
+    ///
+    /// Switches the material to [`MATERIAL_CLASS_TRIPLANAR`](libhelio::MATERIAL_CLASS_TRIPLANAR)
+    /// and uploads the mapping; the material's own textures are projected.
+    /// Call again to change scale, space or blend sharpness at runtime.
+    /// Restore UV mapping with [`set_material_class`](Self::set_material_class).
+    pub fn set_material_triplanar(
+        &mut self,
+        material_id: MaterialId,
+        mapping: libhelio::TriplanarMapping,
+    ) -> Result<()> {
+        let Some((slot, record)) = self.materials.get_mut_with_slot(material_id) else {
+            return Err(invalid("material"));
+        };
+        record.gpu.material_class = libhelio::MATERIAL_CLASS_TRIPLANAR;
+        record.gpu.class_params = mapping.class_params();
+        let updated = self.gpu_scene.materials.update(slot, record.gpu);
+        debug_assert!(updated);
+        Ok(())
+    }
+
     pub fn edit_voxel_volume(&mut self, id: VoxelVolumeId, edit: VoxelEdit) -> Result<()> {
         if let Some(record) = self.voxel_volumes.get_mut(id) {
             record.edit(&edit);
@@ -20,7 +20,11 @@ const COLOR_WATER: [f32; 4] = [0.2, 0.55, 1.0, 1.0]; // blue
 const COLOR_WATER_HITBOX: [f32; 4] = [0.2, 0.9, 0.8, 1.0]; // teal
 const COLOR_DECAL: [f32; 4] = [1.0, 0.5, 0.2, 1.0]; // orange
 
+const COLOR_LIGHT_DIRECTIONAL: [f32; 4] = [1.0, 1.0, 0.6, 1.0]; // pale yellow
+
 const SPHERE_SEGMENTS: u32 = 24;
+/// Directional lights have no extent, so their arrow has a fixed length.
+const ARROW_LENGTH: f32 = 2.0;
 const CONE_SEGMENTS: u32 = 16;
 
 /// Accumulates line-list vertices. The debug pipeline is a `LineList`, so every
@@ -134,26 +138,31 @@ impl LineSink {
             prev = cur;
         }
     }
-}
 
-fn v3(a: &[f32; 4]) -> Vec3 {
-    Vec3::new(a[0], a[1], a[2])
-}
+    /// Shaft from `from` along `dir`, with a four-line head at the tip.
+    fn arrow(&mut self, from: Vec3, dir: Vec3, length: f32, color: [f32; 4]) {
+        let dir = dir.normalize_or_zero();
+        if dir == Vec3::ZERO {
+            return;
+        }
+        let tip = from + dir * length;
+        self.line(from, tip, color);
+        let (tangent, bitangent) = dir.any_orthonormal_pair();
+        let back = tip - dir * (length * 0.2);
+        let spread = length * 0.08;
+        for side in [tangent, -tangent, bitangent, -bitangent] {
+            self.line(tip, back + side * spread, color);
+        }
+    }
 
-impl super::Scene {
-    /// Wireframe bounds for every bounded volume in the scene.
+    /// The attenuation volume of every light, which is the invisible part.
     ///
-    /// Rebuilt from scene records rather than GPU buffers, since the editor
-    /// overlay needs CPU-side geometry and volumes number in the dozens.
-    pub(crate) fn editor_volume_debug_lines(&self) -> Vec<DebugVertex> {
-        let mut sink = LineSink::default();
-
-        // Lights — the attenuation volume, which is the invisible part.
-        //
-        // Spots get both cones: the outer one is where the light stops, the
-        // inner one where its falloff begins, and the gap between them is the
-        // fade. Points get their attenuation radius.
-        for (_, rec) in self.lights.iter_with_handles() {
+    /// Spots get both cones: the outer one is where the light stops, the
+    /// inner one where its falloff begins, and the gap between them is the
+    /// fade. Points get their attenuation radius. Directional lights are
+    /// unbounded, so they only get an arrow when `arrows` is set.
+    fn lights(&mut self, scene: &super::Scene, arrows: bool) {
+        for (_, rec) in scene.lights.iter_with_handles() {
             let g = &rec.gpu;
             let pos = v3(&g.position_range);
             let range = g.position_range[3];
@@ -162,7 +171,7 @@ impl super::Scene {
                 // Cone base radius at the far end of the range, from the
                 // half-angle the shader actually uses.
                 let outer_cos = g.direction_outer[3].clamp(-1.0, 1.0);
-                sink.wire_cone(
+                self.wire_cone(
                     pos,
                     dir,
                     range,
@@ -173,7 +182,7 @@ impl super::Scene {
                 // Only worth drawing when it is actually distinct from the
                 // outer cone; a hard-edged spot would just double the lines.
                 if inner_cos > outer_cos + 1e-4 {
-                    sink.wire_cone(
+                    self.wire_cone(
                         pos,
                         dir,
                         range,
@@ -184,10 +193,39 @@ impl super::Scene {
             } else if g.light_type == LightType::Point as u32
                 || g.light_type == LightType::Area as u32
             {
-                sink.wire_sphere(pos, range, COLOR_LIGHT);
+                self.wire_sphere(pos, range, COLOR_LIGHT);
+            } else if arrows && g.light_type == LightType::Directional as u32 {
+                self.arrow(pos, dir, ARROW_LENGTH, COLOR_LIGHT_DIRECTIONAL);
             }
-            // Directional lights are unbounded — nothing meaningful to outline.
         }
+    }
+}
+
+fn v3(a: &[f32; 4]) -> Vec3 {
+    Vec3::new(a[0], a[1], a[2])
+}
+
+impl super::Scene {
+    /// Influence volumes of every light, for [`Renderer::set_light_debug`].
+    ///
+    /// Directional lights draw as an arrow from their stored position along
+    /// their direction.
+    ///
+    /// [`Renderer::set_light_debug`]: crate::renderer::Renderer::set_light_debug
+    pub(crate) fn light_debug_lines(&self) -> Vec<DebugVertex> {
+        let mut sink = LineSink::default();
+        sink.lights(self, true);
+        sink.verts
+    }
+
+    /// Wireframe bounds for every bounded volume in the scene.
+    ///
+    /// Rebuilt from scene records rather than GPU buffers, since the editor
+    /// overlay needs CPU-side geometry and volumes number in the dozens.
+    pub(crate) fn editor_volume_debug_lines(&self) -> Vec<DebugVertex> {
+        let mut sink = LineSink::default();
+
+        sink.lights(self, false);
 
         // Reflection captures — sphere or oriented box, matching the shader's
         // own influence test.
@@ -28,6 +28,34 @@ pub enum SceneError {
     #[error("scene texture capacity exceeded")]
     TextureCapacityExceeded,
 
+    /// A texture format needs a device feature that was not enabled, e.g.
+    /// `TEXTURE_COMPRESSION_BC` for BCn textures.
+    #[error("texture format {format:?} is not supported by this device")]
+    UnsupportedTextureFormat {
+        /// The rejected format.
+        format: wgpu::TextureFormat,
+    },
+
+    /// Texture upload data does not match its declared size, format or mip count.
+    #[error("invalid texture data: {reason}")]
+    InvalidTextureData {
+        /// Human-readable description of the mismatch.
+        reason: String,
+    },
+
+    /// An image file could not be read or decoded.
+    #[error("failed to load image {}: {reason}", .path.display())]
+    ImageLoad {
+        /// The file that failed to load.
+        path: std::path::PathBuf,
+        /// Human-readable description of the failure.
+        reason: String,
+    },
+
+    /// A KTX2 file could not be read or parsed.
+    #[error(transparent)]
+    Ktx2(#[from] crate::ktx2::Ktx2Error),
+
     /// An operation was rejected because of an incompatible resource state.
     #[error("invalid operation: {reason}")]
     InvalidOperation {
@@ -5,8 +5,12 @@
 //! with flush operations.
 
 use bytemuck::Zeroable;
-use libhelio::{GpuDecal, GpuLight, GpuShadowMatrix};
+use libhelio::{
+    light_screen_coverage, shadow_resolution_shift, GpuDecal, GpuLight, GpuShadowMatrix, LightType,
+    MAX_DIRECTIONAL_SHADOW_CASTERS,
+};
 
+use crate::scene::types::LightRecord;
 use crate::scene::Scene;
 
 /// FNV-1a hash over f32 bit patterns. Used for per-caster shadow dirty tracking.
@@ -28,6 +32,69 @@ fn quantize_f32s<const N: usize>(vals: [f32; N], quantum: f32) -> [f32; N] {
     vals.map(|value| (value / quantum).round() * quantum)
 }
 
+/// Lights uploaded for realtime shading: the movable ones, in dense order.
+///
+/// Static and stationary lights are baked, so the GPU light list does not
+/// line up with the light arena. Each light's fixed shadow resolution is
+/// returned alongside it, at the same index as in the GPU list.
+fn runtime_lights<'a>(records: impl Iterator<Item = &'a LightRecord>) -> (Vec<GpuLight>, Vec<Option<u32>>) {
+    records
+        .filter(|record| record.movability.can_move())
+        .map(|record| {
+            let mut light = record.gpu;
+            if !record.casts_shadows {
+                // Shading skips the shadow lookup for u32::MAX, and the
+                // caster selection never grants it a slot.
+                light.shadow_index = u32::MAX;
+            }
+            (light, record.shadow_resolution)
+        })
+        .unzip()
+}
+
+/// Atlas faces a caster renders: six cube faces, four cascades or one spot face.
+fn shadow_faces(light_type: u32) -> u32 {
+    match light_type {
+        t if t == LightType::Directional as u32 => 4,
+        t if t == LightType::Point as u32 => 6,
+        _ => 1,
+    }
+}
+
+/// Pick the lights that get a shadow atlas slot, returned as ascending buffer
+/// indices. Lights with `shadow_index == u32::MAX` never request one.
+///
+/// Directional lights come first, in buffer order, capped at
+/// [`MAX_DIRECTIONAL_SHADOW_CASTERS`]; point and spot lights fill the remaining
+/// budget by `intensity × range²`.
+fn select_shadow_casters(lights: &[GpuLight], max_casters: usize) -> Vec<usize> {
+    let mut directional = Vec::new();
+    let mut local: Vec<(f32, usize)> = Vec::new();
+    for (i, light) in lights.iter().enumerate() {
+        if light.shadow_index == u32::MAX {
+            continue; // shadows disabled on this light (LightRecord::casts_shadows)
+        }
+        if light.light_type == LightType::Directional as u32 {
+            directional.push(i);
+        } else {
+            let range = light.position_range[3].max(0.001);
+            // intensity × range² — view-independent, stable across camera moves.
+            // Larger/brighter lights win the budget regardless of camera position.
+            local.push((light.color_intensity[3] * (range * range), i));
+        }
+    }
+    directional.truncate(MAX_DIRECTIONAL_SHADOW_CASTERS.min(max_casters));
+
+    // Stable sort: equally important lights keep buffer order.
+    local.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+    let local_budget = max_casters - directional.len();
+
+    let mut winners = directional;
+    winners.extend(local.iter().take(local_budget).map(|&(_, i)| i));
+    winners.sort_unstable();
+    winners
+}
+
 impl Scene {
     /// Flush pending changes to GPU buffers.
     ///
@@ -72,33 +139,32 @@ impl Scene {
     /// renderer.render(&scene, target)?;
     /// ```
     pub fn flush(&mut self) {
+        #[cfg(feature = "async")]
+        self.complete_async_loads();
+
         // ── Rebuild lights buffer to only contain movable lights ─────────────
         // Static/stationary lights are baked and should not contribute to real-time lighting.
         // This dramatically improves performance when scenes have many baked lights.
-        {
+        // `light_shadow_resolutions[i]` belongs to `gpu_scene.lights[i]`.
+        let light_shadow_resolutions = {
             let light_rec_count = self.lights.dense_len();
-            let mut movable_lights: Vec<GpuLight> = Vec::with_capacity(light_rec_count);
-
-            for i in 0..light_rec_count {
-                if let Some(record) = self.lights.get_dense(i) {
-                    if record.movability.can_move() {
-                        movable_lights.push(record.gpu);
-                    }
-                }
-            }
+            let (movable_lights, shadow_resolutions) =
+                runtime_lights((0..light_rec_count).filter_map(|i| self.lights.get_dense(i)));
 
             // Replace the lights buffer with only movable lights
-            self.gpu_scene.lights.set_data(movable_lights.clone());
-            self.gpu_scene.movable_light_count = movable_lights.len() as u32;
+            let movable_count = movable_lights.len();
+            self.gpu_scene.lights.set_data(movable_lights);
+            self.gpu_scene.movable_light_count = movable_count as u32;
 
-            if movable_lights.len() < light_rec_count {
+            if movable_count < light_rec_count {
                 log::trace!(
                     "[helio] Filtered lights for runtime: {} movable, {} static/stationary (baked)",
-                    movable_lights.len(),
-                    light_rec_count - movable_lights.len()
+                    movable_count,
+                    light_rec_count - movable_count
                 );
             }
-        }
+            shadow_resolutions
+        };
 
         // Assign shadow atlas slots to the highest-importance shadow-casting lights.
         //
@@ -109,7 +175,9 @@ impl Scene {
         // Solution — two-phase importance selection:
         //   Phase 1: Score every shadow-requesting light by VIEW-INDEPENDENT importance:
         //              intensity × range²
-        //            Directional lights always score ∞ (global, never culled).
+        //            Directional lights are global and take their own cascades, so up
+        //            to MAX_DIRECTIONAL_SHADOW_CASTERS of them (sun + moon, key + rim)
+        //            win first, in buffer order. Local lights share what is left.
         //            Sort descending → top 42 are the frame's active casters.
         //   Phase 2: Re-sort the WINNERS by their GPU buffer index (stable secondary key).
         //            Same lights that were in budget last frame keep the same atlas slots,
@@ -126,51 +194,27 @@ impl Scene {
                 ((self.shadow_face_capacity / FACES_PER_LIGHT) as usize).min(42);
             let light_count = self.gpu_scene.lights.len();
 
-            // Phase 1: score and select the top MAX_SHADOW_CASTERS.
-            let mut scored: Vec<(f32, usize)> = Vec::with_capacity(light_count);
-            for i in 0..light_count {
-                let light = self.gpu_scene.lights.0.as_slice()[i];
-                if light.shadow_index == u32::MAX {
-                    continue; // user explicitly disabled shadows on this light
-                }
-                let score = if light.light_type == 0 {
-                    // Directional: infinite range, always highest priority.
-                    f32::MAX
-                } else {
-                    let range = light.position_range[3].max(0.001);
-                    // intensity × range² — view-independent, stable across camera moves.
-                    // Larger/brighter lights win the budget regardless of camera position.
-                    light.color_intensity[3] * (range * range)
-                };
-                scored.push((score, i));
-            }
-
-            // Sort descending by importance to determine which lights win the budget.
-            scored.sort_unstable_by(|a, b| {
-                b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal)
-            });
-
-            let winner_count = scored.len().min(max_shadow_casters);
-
-            // Phase 2: re-sort winners by their buffer index (stable secondary key).
-            // Lights that stay in budget from frame to frame retain the same atlas slot,
-            // keeping per-caster dirty gens stable and avoiding spurious re-renders.
-            scored[..winner_count].sort_unstable_by_key(|&(_, i)| i);
+            let winners = select_shadow_casters(
+                &self.gpu_scene.lights.0.as_slice()[..light_count],
+                max_shadow_casters,
+            );
 
             // Assign atlas slots to winners; disable everything else.
             let mut next_layer: u32 = 0;
-            for (rank, &(_, i)) in scored.iter().enumerate() {
+            let mut winners = winners.into_iter().peekable();
+            for i in 0..light_count {
                 let light = self.gpu_scene.lights.0.as_slice()[i];
-                if rank < max_shadow_casters {
-                    let mut assigned = light;
-                    assigned.shadow_index = next_layer;
-                    self.gpu_scene.lights.update(i, assigned);
+                if light.shadow_index == u32::MAX {
+                    continue;
+                }
+                let mut updated = light;
+                if winners.next_if_eq(&i).is_some() {
+                    updated.shadow_index = next_layer;
                     next_layer += FACES_PER_LIGHT;
                 } else {
-                    let mut disabled = light;
-                    disabled.shadow_index = u32::MAX;
-                    self.gpu_scene.lights.update(i, disabled);
+                    updated.shadow_index = u32::MAX;
                 }
+                self.gpu_scene.lights.update(i, updated);
             }
             let needed = (next_layer as usize).max(1);
             if self.gpu_scene.shadow_matrices.len() != needed {
@@ -180,6 +224,63 @@ impl Scene {
             }
         }
 
+        // ── Shadow resolution ────────────────────────────────────────────────
+        // Lights with a fixed resolution get that tier; the rest are sized by
+        // screen coverage under what is left of the adaptive budget. The tier
+        // travels in the light to the matrix pass, which maps the light into
+        // its region, and per slot to ShadowPass for the scissor.
+        {
+            let light_count = self.gpu_scene.lights.len();
+            let casters: Vec<(usize, usize)> = (0..light_count)
+                .filter_map(|i| {
+                    let slot = self.gpu_scene.lights.0.as_slice()[i].shadow_index;
+                    (slot != u32::MAX && (slot / 6) < 42).then_some((i, (slot / 6) as usize))
+                })
+                .collect();
+            let mut shifts = [0u32; 42];
+            let mut fixed_faces = 0.0;
+            let mut adaptive_casters = Vec::with_capacity(casters.len());
+            for &(i, slot) in &casters {
+                match light_shadow_resolutions.get(i).copied().flatten() {
+                    Some(resolution) => {
+                        let shift = shadow_resolution_shift(self.shadow_atlas_size, resolution);
+                        let faces = shadow_faces(self.gpu_scene.lights.0.as_slice()[i].light_type);
+                        fixed_faces += faces as f32 / (1u32 << (2 * shift)) as f32;
+                        shifts[slot] = shift;
+                    }
+                    None => adaptive_casters.push((i, slot)),
+                }
+            }
+            if let Some(adaptive) = self.adaptive_shadow_resolution {
+                let adaptive = libhelio::AdaptiveShadowResolution {
+                    budget_faces: (adaptive.budget_faces - fixed_faces).max(0.0),
+                    ..adaptive
+                };
+                let camera = *self.gpu_scene.camera.data();
+                let requests: Vec<(f32, u32)> = adaptive_casters
+                    .iter()
+                    .map(|&(i, _)| {
+                        let light = &self.gpu_scene.lights.0.as_slice()[i];
+                        (light_screen_coverage(light, &camera), shadow_faces(light.light_type))
+                    })
+                    .collect();
+                let previous: Vec<u32> = adaptive_casters
+                    .iter()
+                    .map(|&(_, slot)| self.gpu_scene.per_caster_resolution_shift[slot])
+                    .collect();
+                for (&(_, slot), shift) in adaptive_casters.iter().zip(adaptive.allocate(&requests, &previous)) {
+                    shifts[slot] = shift;
+                }
+            }
+            for &(i, slot) in &casters {
+                let light = self.gpu_scene.lights.0.as_slice()[i];
+                if light.shadow_resolution_shift != shifts[slot] {
+                    self.gpu_scene.lights.update(i, GpuLight { shadow_resolution_shift: shifts[slot], ..light });
+                }
+            }
+            self.gpu_scene.per_caster_resolution_shift = shifts;
+        }
+
         // ── Per-caster shadow dirty tracking ─────────────────────────────────
         // Compute a content hash per shadow caster. Each hash covers:
         //   • The caster light's own geometry (position, range, direction).
@@ -201,9 +302,12 @@ impl Scene {
                 if slot >= 42 {
                     continue;
                 }
+                // A resolution change moves the light's region, so it
+                // re-renders (and fully clears) the caster's faces.
                 let base_hash = fnv1a_f32s(&light.position_range)
                     ^ fnv1a_f32s(&light.direction_outer)
-                    ^ (light.light_type as u64).wrapping_mul(2654435761);
+                    ^ (light.light_type as u64).wrapping_mul(2654435761)
+                    ^ (light.shadow_resolution_shift as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
                 // Directional CSM depends on the camera frustum, but the GPU matrix pass
                 // already texel-snaps cascade placement. Mirror that coarseness here so
                 // sub-texel camera motion does not thrash the cached shadow atlas.
@@ -319,3 +423,63 @@ impl Scene {
         self.gpu_scene.flush();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn light(light_type: LightType, intensity: f32, range: f32) -> GpuLight {
+        GpuLight {
+            position_range: [0.0, 0.0, 0.0, range],
+            color_intensity: [1.0, 1.0, 1.0, intensity],
+            light_type: light_type as u32,
+            shadow_index: 0,
+            ..GpuLight::zeroed()
+        }
+    }
+
+    #[test]
+    fn every_directional_gets_cascades_before_local_lights() {
+        let lights = [
+            light(LightType::Point, 1000.0, 50.0),
+            light(LightType::Directional, 3.0, 0.0), // sun
+            light(LightType::Spot, 10.0, 5.0),
+            light(LightType::Directional, 0.1, 0.0), // moon
+        ];
+        assert_eq!(select_shadow_casters(&lights, 2), [1, 3]);
+        assert_eq!(select_shadow_casters(&lights, 3), [0, 1, 3]);
+        assert_eq!(select_shadow_casters(&lights, 42), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn directional_casters_are_capped_in_buffer_order() {
+        let mut lights = vec![light(LightType::Directional, 1.0, 0.0); 6];
+        lights[0].shadow_index = u32::MAX;
+        lights.push(light(LightType::Point, 1.0, 1.0));
+
+        let winners = select_shadow_casters(&lights, 42);
+        let mut expected: Vec<usize> = (1..=MAX_DIRECTIONAL_SHADOW_CASTERS).collect();
+        expected.push(6);
+        assert_eq!(winners, expected);
+    }
+
+    #[test]
+    fn fixed_shadow_resolutions_follow_the_movable_light_list() {
+        let record = |movability, shadow_resolution| LightRecord {
+            gpu: light(LightType::Point, 1.0, 1.0),
+            movability,
+            user_tag: 0,
+            casts_shadows: true,
+            shadow_resolution,
+        };
+        let records = [
+            record(libhelio::Movability::Static, Some(128)),
+            record(libhelio::Movability::Movable, None),
+            record(libhelio::Movability::Stationary, Some(256)),
+            record(libhelio::Movability::Movable, Some(512)),
+        ];
+        let (lights, resolutions) = runtime_lights(records.iter());
+        assert_eq!(lights.len(), 2);
+        assert_eq!(resolutions, [None, Some(512)]);
+    }
+}
@@ -4,7 +4,7 @@ use glam::{Mat3, Mat4, Vec3};
 use helio_core::{GpuDrawCall, GpuInstanceAabb, GpuInstanceData};
 
 use crate::groups::GroupMask;
-use crate::handles::MeshId;
+use crate::handles::{MeshId, TextureId};
 use crate::material::{
     GpuMaterialTextureSlot, GpuMaterialTextures, MaterialTextureRef, TextureTransform,
 };
@@ -129,6 +129,7 @@ pub(super) fn object_gpu_data(
             lightmap_index: 0xFFFFFFFF,  // No lightmap by default (populated after bake)
         },
         aabb: sphere_to_aabb(desc.bounds),
+        wind: libhelio::GpuWindParams::default(),
         // `first_instance` is set to 0 here; the actual GPU slot is assigned during
         // `rebuild_instance_buffers()` called from `flush()`. `instance_count` is not
         // meaningful per-object — it is computed per-group during the rebuild.
@@ -147,14 +148,20 @@ pub(super) fn object_gpu_data(
 ///
 /// # Parameters
 /// - `texture`: Optional texture reference with transform
+/// - `resolve`: Maps the reference to the texture slot it samples through
+///   (the texture itself, or its sampler-override variant)
 ///
 /// # Returns
 /// A GPU texture slot descriptor with texture index and UV transform parameters.
 /// Returns a "missing" slot if `texture` is `None`.
-pub(super) fn gpu_texture_slot(texture: Option<MaterialTextureRef>) -> GpuMaterialTextureSlot {
+pub(super) fn gpu_texture_slot(
+    texture: Option<MaterialTextureRef>,
+    resolve: &impl Fn(MaterialTextureRef) -> TextureId,
+) -> GpuMaterialTextureSlot {
     let Some(texture) = texture else {
         return GpuMaterialTextureSlot::missing();
     };
+    let texture_id = resolve(texture);
     let uv_channel = texture.uv_channel.min(1);
     let TextureTransform {
         offset,
@@ -162,7 +169,7 @@ pub(super) fn gpu_texture_slot(texture: Option<MaterialTextureRef>) -> GpuMateri
         rotation_radians,
     } = texture.transform;
     GpuMaterialTextureSlot {
-        texture_index: texture.texture.slot(),
+        texture_index: texture_id.slot(),
         uv_channel,
         _pad: [0; 2],
         offset_scale: [offset[0], offset[1], scale[0], scale[1]],
@@ -177,25 +184,27 @@ pub(super) fn gpu_texture_slot(texture: Option<MaterialTextureRef>) -> GpuMateri
 ///
 /// # Parameters
 /// - `textures`: Material texture references (base color, normal, roughness, etc.)
+/// - `resolve`: See [`gpu_texture_slot`]
 ///
 /// # Returns
 /// A GPU material texture descriptor with all texture slots and parameters.
 pub(super) fn gpu_material_textures(
     textures: &crate::material::MaterialTextures,
+    resolve: impl Fn(MaterialTextureRef) -> TextureId,
 ) -> GpuMaterialTextures {
     GpuMaterialTextures {
-        base_color: gpu_texture_slot(textures.base_color),
-        normal: gpu_texture_slot(textures.normal),
-        roughness_metallic: gpu_texture_slot(textures.roughness_metallic),
-        emissive: gpu_texture_slot(textures.emissive),
-        occlusion: gpu_texture_slot(textures.occlusion),
-        specular_color: gpu_texture_slot(textures.specular_color),
-        specular_weight: gpu_texture_slot(textures.specular_weight),
+        base_color: gpu_texture_slot(textures.base_color, &resolve),
+        normal: gpu_texture_slot(textures.normal, &resolve),
+        roughness_metallic: gpu_texture_slot(textures.roughness_metallic, &resolve),
+        emissive: gpu_texture_slot(textures.emissive, &resolve),
+        occlusion: gpu_texture_slot(textures.occlusion, &resolve),
+        specular_color: gpu_texture_slot(textures.specular_color, &resolve),
+        specular_weight: gpu_texture_slot(textures.specular_weight, &resolve),
         params: [
             textures.normal_scale,
             textures.occlusion_strength,
             textures.alpha_cutoff,
-            0.0,
+            textures.normal_map_convention.as_f32(),
         ],
     }
 }
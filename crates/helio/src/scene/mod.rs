@@ -83,9 +83,12 @@ pub use actor::{
     SceneActor, SceneActorId, SceneActorTrait, WaterHitboxDescriptor, WaterHitboxActor,
     WaterVolumeDescriptor, WaterVolumeActor,
 };
-pub use camera::Camera;
+pub use camera::{Camera, Frustum};
 pub use core::Scene;
 pub use errors::*;
+pub use resources::LoadFailure;
+#[cfg(feature = "async")]
+pub use resources::ResourceLoad;
 pub use types::{ObjectDescriptor, PickableObject, VoxelVolumeDescriptor};
 pub use voxel::VoxelMode;
 
@@ -23,7 +23,7 @@ impl super::super::Scene {
     ///   - `material`: Material handle from [`insert_material`](crate::Scene::insert_material)
     ///   - `transform`: World-space model matrix (column-major)
     ///   - `bounds`: Bounding sphere `[center.x, center.y, center.z, radius]`
-    ///   - `flags`: Render flags (bit 0 = casts shadow, bit 1 = receives shadow)
+    ///   - `flags`: Render flags (bit 0 = casts shadow, bit 1 = receives shadow, bit 2 = minimap)
     ///   - `groups`: Group membership mask for batch visibility control
     ///
     /// # Errors
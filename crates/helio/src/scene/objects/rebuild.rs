@@ -5,6 +5,7 @@
 //! objects with the same mesh + material into instanced draw calls.
 
 use helio_core::{DrawIndexedIndirectArgs, GpuDrawCall, GpuInstanceAabb, GpuInstanceData};
+use libhelio::GpuWindParams;
 
 use super::super::helpers::object_is_visible;
 
@@ -54,6 +55,7 @@ impl super::super::Scene {
         if n == 0 {
             self.gpu_scene.instances.set_data(Vec::new());
             self.gpu_scene.aabbs.set_data(Vec::new());
+            self.gpu_scene.wind.set_data(Vec::new());
             self.gpu_scene.draw_calls.set_data(Vec::new());
             self.gpu_scene.indirect.set_data(Vec::new());
             self.gpu_scene.visibility.set_data(Vec::new());
@@ -78,6 +80,7 @@ impl super::super::Scene {
 
         let mut instances: Vec<GpuInstanceData> = Vec::with_capacity(n);
         let mut aabbs: Vec<GpuInstanceAabb> = Vec::with_capacity(n);
+        let mut wind: Vec<GpuWindParams> = Vec::with_capacity(n);
         let mut draw_calls: Vec<GpuDrawCall> = Vec::new();
         let mut indirect: Vec<DrawIndexedIndirectArgs> = Vec::new();
         let mut visibility: Vec<u32> = Vec::with_capacity(n);
@@ -113,6 +116,7 @@ impl super::super::Scene {
                 gpu_slots[order[i]] = instances.len() as u32;
                 instances.push(r.instance);
                 aabbs.push(r.aabb);
+                wind.push(r.wind);
                 visibility.push(if object_is_visible(r.groups, group_hidden) {
                     1u32
                 } else {
@@ -173,6 +177,7 @@ impl super::super::Scene {
 
         self.gpu_scene.instances.set_data(instances);
         self.gpu_scene.aabbs.set_data(aabbs);
+        self.gpu_scene.wind.set_data(wind);
         self.gpu_scene.draw_calls.set_data(draw_calls);
         self.gpu_scene.indirect.set_data(indirect);
         self.gpu_scene.visibility.set_data(visibility);
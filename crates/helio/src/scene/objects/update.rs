@@ -210,6 +210,33 @@ impl super::super::Scene {
         Ok(())
     }
 
+    /// Set or clear an object's vertex wind sway.
+    ///
+    /// The G-buffer, depth prepass and shadow casters displace the object's
+    /// vertices each frame from `wind` and the renderer's elapsed time, so
+    /// instances of one mesh can sway independently. `None` stops the sway.
+    ///
+    /// # Mode Behavior
+    ///
+    /// Like bounds, wind does not affect instancing groups, so the update is
+    /// applied in-place in both modes.
+    ///
+    /// # Example
+    /// ```ignore
+    /// scene.set_object_wind(tree_id, Some(WindParams { phase: 1.3, ..Default::default() }))?;
+    /// ```
+    pub fn set_object_wind(&mut self, id: ObjectId, wind: Option<libhelio::WindParams>) -> Result<()> {
+        let Some((_, record)) = self.objects.get_mut_with_index(id) else {
+            return Err(invalid("object"));
+        };
+        record.wind = wind.map(|w| w.to_gpu()).unwrap_or_default();
+        if !self.objects_dirty {
+            let slot = record.draw.first_instance as usize;
+            self.gpu_scene.wind.update(slot, record.wind);
+        }
+        Ok(())
+    }
+
     /// Update lightmap indices for all static objects based on baked lightmap atlas regions.
     ///
     /// Called automatically by the renderer after baking completes. Maps each static object's
@@ -0,0 +1,257 @@
+//! Background texture and mesh loading (feature `async`).
+//!
+//! [`Scene::load_texture_async`](crate::Scene::load_texture_async) and
+//! [`Scene::load_mesh_async`](crate::Scene::load_mesh_async) run the CPU side
+//! of a load (file I/O, image decode, mesh generation) on a worker thread and
+//! return a [`ResourceLoad`] future. The decoded data is uploaded on the
+//! render thread during the next [`Scene::flush`](crate::Scene::flush), and
+//! the future resolves with the handle once the upload has been queued, so
+//! the resource is GPU-ready for the frame rendered after that flush.
+//!
+//! # Handles
+//!
+//! The handle only exists once the future resolves, so materials and objects
+//! that use it are created after the `.await`; there is no placeholder to draw
+//! by accident. Loads progress only while the application keeps calling
+//! `flush` (i.e. keeps rendering), so never block the render thread on one.
+//!
+//! No runtime is required: the future is woken from `flush`, so it can be
+//! awaited on any executor. On `wasm32` there are no worker threads and the
+//! CPU work runs inline; the upload still happens on the next flush.
+
+use std::future::Future;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use crate::handles::{MeshId, TextureId};
+use crate::ktx2::Ktx2Texture;
+use crate::material::{TextureSamplerDesc, TextureUpload};
+use crate::mesh::MeshUpload;
+
+use super::super::errors::{Result, SceneError};
+use super::super::Scene;
+
+/// Upload step of a finished load, run on the render thread during `flush`.
+type Completion = Box<dyn FnOnce(&mut Scene) + Send>;
+
+/// Finished background loads waiting for the next flush.
+pub(in crate::scene) struct AsyncLoadQueue {
+    sender: Sender<Completion>,
+    receiver: Receiver<Completion>,
+}
+
+impl AsyncLoadQueue {
+    pub(in crate::scene) fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self { sender, receiver }
+    }
+}
+
+struct LoadState<T> {
+    result: Option<Result<T>>,
+    waker: Option<Waker>,
+}
+
+/// A resource being loaded in the background; resolves to its handle.
+///
+/// Returned by [`Scene::load_texture_async`](crate::Scene::load_texture_async)
+/// and [`Scene::load_mesh_async`](crate::Scene::load_mesh_async). The handle
+/// only exists once this resolves, after the upload was queued in a
+/// [`Scene::flush`](crate::Scene::flush), so anything that draws the resource
+/// (materials, objects) is created after the `.await`.
+///
+/// Loads only complete while the application keeps flushing the scene, so
+/// never block the render thread on one. Dropping the future does not cancel
+/// the load; the resource is still added to the scene.
+#[must_use = "the handle is only available by awaiting the load"]
+pub struct ResourceLoad<T> {
+    state: Arc<Mutex<LoadState<T>>>,
+}
+
+impl<T> Future for ResourceLoad<T> {
+    type Output = Result<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<T>> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Completes a [`ResourceLoad`]. Dropped without a result (the worker
+/// panicked or the scene went away), it fails the load instead of leaving
+/// the future pending forever.
+struct Resolver<T> {
+    state: Option<Arc<Mutex<LoadState<T>>>>,
+}
+
+impl<T> Resolver<T> {
+    fn resolve(mut self, result: Result<T>) {
+        if let Some(state) = self.state.take() {
+            complete(&state, result);
+        }
+    }
+}
+
+impl<T> Drop for Resolver<T> {
+    fn drop(&mut self) {
+        if let Some(state) = self.state.take() {
+            complete(
+                &state,
+                Err(SceneError::InvalidOperation {
+                    reason: "background load was abandoned before it finished",
+                }),
+            );
+        }
+    }
+}
+
+fn complete<T>(state: &Mutex<LoadState<T>>, result: Result<T>) {
+    let waker = {
+        let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+        state.result = Some(result);
+        state.waker.take()
+    };
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+}
+
+fn load_pair<T>() -> (ResourceLoad<T>, Resolver<T>) {
+    let state = Arc::new(Mutex::new(LoadState { result: None, waker: None }));
+    (ResourceLoad { state: state.clone() }, Resolver { state: Some(state) })
+}
+
+impl Scene {
+    /// Load a texture file in the background.
+    ///
+    /// `.ktx2` files are read with [`Ktx2Texture`] and uploaded as stored;
+    /// anything else is decoded to RGBA8 with the `image` crate (PNG by
+    /// default). `srgb` selects `Rgba8UnormSrgb` for decoded images and is
+    /// ignored for KTX2, which carries its own format. The sampler is
+    /// [`TextureSamplerDesc::default`].
+    ///
+    /// The returned future resolves during a later [`flush`](Self::flush);
+    /// see [`ResourceLoad`] for when the handle may be used.
+    ///
+    /// # Errors
+    /// Resolves to [`SceneError::ImageLoad`] or [`SceneError::Ktx2`] when the
+    /// file cannot be read or decoded, or to any error of
+    /// [`insert_texture`](Self::insert_texture).
+    pub fn load_texture_async(&self, path: impl AsRef<Path>, srgb: bool) -> ResourceLoad<TextureId> {
+        let path = path.as_ref().to_path_buf();
+        self.spawn_load(
+            move || {
+                let label = path
+                    .file_name()
+                    .map_or_else(|| "texture".into(), |name| name.to_string_lossy().into_owned());
+                let is_ktx2 = path
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("ktx2"));
+                if is_ktx2 {
+                    return Ok(Ktx2Texture::open(&path)?.into_upload(label, TextureSamplerDesc::default())?);
+                }
+                let image = image::open(&path)
+                    .map_err(|err| SceneError::ImageLoad {
+                        path: path.clone(),
+                        reason: err.to_string(),
+                    })?
+                    .into_rgba8();
+                let (width, height) = image.dimensions();
+                Ok(TextureUpload::rgba8(
+                    label,
+                    width,
+                    height,
+                    srgb,
+                    image.into_raw(),
+                    TextureSamplerDesc::default(),
+                ))
+            },
+            |scene, upload| scene.insert_texture(upload),
+        )
+    }
+
+    /// Build a mesh in the background, e.g. parse a model file or generate
+    /// procedural geometry, and add it to the mesh pool.
+    ///
+    /// `build` runs on a worker thread. Its result is uploaded to the shared
+    /// mesh buffers during a later [`flush`](Self::flush), and the future
+    /// resolves with the handle in that same flush.
+    ///
+    /// # Errors
+    /// Resolves to whatever error `build` returns.
+    pub fn load_mesh_async<F>(&self, build: F) -> ResourceLoad<MeshId>
+    where
+        F: FnOnce() -> Result<MeshUpload> + Send + 'static,
+    {
+        self.spawn_load(build, |scene, mesh| Ok(scene.insert_mesh(mesh)))
+    }
+
+    /// Upload the results of finished background loads and resolve their
+    /// futures. Called at the start of [`flush`](Self::flush) so meshes
+    /// land in that flush's mesh-pool upload.
+    pub(in crate::scene) fn complete_async_loads(&mut self) {
+        while let Ok(completion) = self.async_loads.receiver.try_recv() {
+            completion(self);
+        }
+    }
+
+    fn spawn_load<T, U>(
+        &self,
+        work: impl FnOnce() -> Result<U> + Send + 'static,
+        upload: impl FnOnce(&mut Scene, U) -> Result<T> + Send + 'static,
+    ) -> ResourceLoad<T>
+    where
+        T: Send + 'static,
+        U: Send + 'static,
+    {
+        let (load, resolver) = load_pair();
+        let sender = self.async_loads.sender.clone();
+        let job = move || match work() {
+            Ok(data) => {
+                let completion: Completion = Box::new(move |scene| resolver.resolve(upload(scene, data)));
+                // A closed channel means the scene is gone; dropping the
+                // completion fails the load through the resolver.
+                let _ = sender.send(completion);
+            }
+            Err(err) => resolver.resolve(Err(err)),
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        std::thread::spawn(job);
+        #[cfg(target_arch = "wasm32")]
+        job();
+        load
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poll_now<T>(load: &mut ResourceLoad<T>) -> Poll<Result<T>> {
+        Pin::new(load).poll(&mut Context::from_waker(Waker::noop()))
+    }
+
+    #[test]
+    fn load_resolves_once_and_fails_when_abandoned() {
+        let (mut load, resolver) = load_pair::<u32>();
+        assert!(poll_now(&mut load).is_pending());
+        resolver.resolve(Ok(7));
+        assert!(matches!(poll_now(&mut load), Poll::Ready(Ok(7))));
+
+        let (mut abandoned, resolver) = load_pair::<u32>();
+        drop(resolver);
+        assert!(matches!(
+            poll_now(&mut abandoned),
+            Poll::Ready(Err(SceneError::InvalidOperation { .. }))
+        ));
+    }
+}
@@ -0,0 +1,175 @@
+//! Error material substituted for assets that failed to load.
+//!
+//! A missing texture or a material that cannot be resolved should not take
+//! the frame down with it. [`Scene::insert_material_asset_or_error`] and
+//! [`Scene::report_load_failure`] log a warning naming the failed asset,
+//! record it in [`Scene::load_failures`], and hand back the error material
+//! instead: an unlit magenta/black checkerboard that is impossible to miss.
+//! A material whose texture failed is replaced as a whole; the checkerboard
+//! only ever lands in a base-colour slot, never in a normal or ORM slot where
+//! it would read as garbage data. It is generated in code, so it is available
+//! even when every file on disk failed to load.
+
+use helio_core::GpuMaterial;
+
+use crate::handles::{MaterialId, TextureId};
+use crate::material::{MaterialAsset, MaterialTextureRef, MaterialTextures, TextureSamplerDesc, TextureUpload};
+
+use super::super::errors::{invalid, Result};
+
+/// Edge length of the embedded error texture, in texels.
+const ERROR_TEXTURE_SIZE: u32 = 64;
+/// Edge length of one checker square, in texels.
+const ERROR_CHECKER_SIZE: u32 = 8;
+
+/// An asset that failed to load and was replaced by the error material.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadFailure {
+    /// Name of the asset, e.g. a file path or texture label.
+    pub asset: String,
+    /// Why it failed.
+    pub reason: String,
+}
+
+/// Magenta/black checkerboard, sRGB, nearest-filtered so the squares stay crisp.
+fn error_texture_upload() -> TextureUpload {
+    let mut data = Vec::with_capacity((ERROR_TEXTURE_SIZE * ERROR_TEXTURE_SIZE * 4) as usize);
+    for y in 0..ERROR_TEXTURE_SIZE {
+        for x in 0..ERROR_TEXTURE_SIZE {
+            let magenta = (x / ERROR_CHECKER_SIZE + y / ERROR_CHECKER_SIZE).is_multiple_of(2);
+            data.extend_from_slice(if magenta { &[255, 0, 255, 255] } else { &[0, 0, 0, 255] });
+        }
+    }
+    TextureUpload::rgba8(
+        "helio-error-texture",
+        ERROR_TEXTURE_SIZE,
+        ERROR_TEXTURE_SIZE,
+        true,
+        data,
+        TextureSamplerDesc {
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            ..TextureSamplerDesc::default()
+        },
+    )
+}
+
+impl super::super::Scene {
+    /// The embedded magenta/black checkerboard texture, created on first use.
+    pub fn error_texture(&mut self) -> TextureId {
+        if let Some(id) = self.error_texture.filter(|&id| self.textures.get(id).is_some()) {
+            return id;
+        }
+        let id = self
+            .insert_texture(error_texture_upload())
+            .expect("the embedded error texture is valid RGBA8 on every device");
+        self.error_texture = Some(id);
+        id
+    }
+
+    /// The material substituted for assets that failed to load.
+    ///
+    /// Defaults to an unlit material showing [`error_texture`](Self::error_texture),
+    /// created on first use; see [`set_error_material`](Self::set_error_material)
+    /// to replace it.
+    pub fn error_material(&mut self) -> MaterialId {
+        if let Some(id) = self.error_material.filter(|&id| self.materials.get(id).is_some()) {
+            return id;
+        }
+        let texture = self.error_texture();
+        let asset = MaterialAsset {
+            gpu: GpuMaterial {
+                base_color: [1.0, 1.0, 1.0, 1.0],
+                emissive: [0.0, 0.0, 0.0, 0.0],
+                roughness_metallic: [1.0, 0.0, 1.5, 0.0],
+                tex_base_color: GpuMaterial::NO_TEXTURE,
+                tex_normal: GpuMaterial::NO_TEXTURE,
+                tex_roughness: GpuMaterial::NO_TEXTURE,
+                tex_emissive: GpuMaterial::NO_TEXTURE,
+                tex_occlusion: GpuMaterial::NO_TEXTURE,
+                workflow: 0,
+                flags: libhelio::FLAG_UNLIT,
+                material_class: 0,
+                class_params: [0.0; 4],
+            },
+            textures: MaterialTextures {
+                base_color: Some(MaterialTextureRef::new(texture)),
+                ..MaterialTextures::default()
+            },
+        };
+        let id = self
+            .insert_material_asset(asset)
+            .expect("the error material only references the error texture");
+        self.error_material = Some(id);
+        id
+    }
+
+    /// Use `material` as the error material from now on, e.g. a project's
+    /// own "missing asset" look.
+    ///
+    /// Only affects later substitutions; materials already handed out keep
+    /// the previous one.
+    ///
+    /// # Errors
+    /// - [`SceneError::InvalidHandle`](crate::SceneError::InvalidHandle) if the material ID is invalid
+    pub fn set_error_material(&mut self, material: MaterialId) -> Result<()> {
+        if self.materials.get(material).is_none() {
+            return Err(invalid("material"));
+        }
+        self.error_material = Some(material);
+        Ok(())
+    }
+
+    /// Insert a material asset, or fall back to the
+    /// [error material](Self::error_material) when it cannot be resolved
+    /// (e.g. it references a texture that failed to load or was removed).
+    ///
+    /// The failure is logged and recorded under `asset`.
+    pub fn insert_material_asset_or_error(&mut self, asset: &str, material: MaterialAsset) -> MaterialId {
+        match self.insert_material_asset(material) {
+            Ok(id) => id,
+            Err(err) => {
+                self.record_load_failure(asset, &err);
+                self.error_material()
+            }
+        }
+    }
+
+    /// Record an asset that failed to load outside the scene (missing file,
+    /// decode error) and get the error material to draw in its place.
+    pub fn report_load_failure(&mut self, asset: &str, reason: impl std::fmt::Display) -> MaterialId {
+        self.record_load_failure(asset, &reason);
+        self.error_material()
+    }
+
+    /// Every asset replaced by the error texture or material so far, oldest first.
+    pub fn load_failures(&self) -> &[LoadFailure] {
+        &self.load_failures
+    }
+
+    fn record_load_failure(&mut self, asset: &str, reason: &dyn std::fmt::Display) {
+        log::warn!("failed to load '{asset}': {reason}; using the error material");
+        self.load_failures.push(LoadFailure {
+            asset: asset.to_owned(),
+            reason: reason.to_string(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_texture_is_a_magenta_black_checkerboard() {
+        let upload = error_texture_upload();
+        assert_eq!(upload.expected_data_len(), Some(upload.data.len()));
+        let texel = |x: u32, y: u32| {
+            let i = ((y * ERROR_TEXTURE_SIZE + x) * 4) as usize;
+            [upload.data[i], upload.data[i + 1], upload.data[i + 2]]
+        };
+        assert_eq!(texel(0, 0), [255, 0, 255]);
+        assert_eq!(texel(ERROR_CHECKER_SIZE, 0), [0, 0, 0]);
+        assert_eq!(texel(ERROR_CHECKER_SIZE, ERROR_CHECKER_SIZE), [255, 0, 255]);
+    }
+}
@@ -22,7 +22,8 @@ impl super::super::Scene {
     ///   - Direction (for directional/spot lights)
     ///   - Color and intensity
     ///   - Light type (point, directional, spot)
-    ///   - Shadow settings (shadow_index, shadow resolution)
+    ///   - Shadow settings: any `shadow_index` other than `u32::MAX` requests a
+    ///     shadow slot (see [`set_light_casts_shadows`](Self::set_light_casts_shadows))
     ///
     /// # Returns
     /// A [`LightId`] handle that can be used to update or remove the light.
@@ -35,6 +36,9 @@ impl super::super::Scene {
     /// # Shadow Casting Limits
     /// The scene supports up to 42 shadow-casting lights (42 × 6 = 252 shadow atlas layers).
     /// Additional shadow-casting lights will have shadows disabled automatically.
+    /// Up to [`MAX_DIRECTIONAL_SHADOW_CASTERS`](libhelio::MAX_DIRECTIONAL_SHADOW_CASTERS)
+    /// directional lights (sun + moon, key + rim) each get their own cascades
+    /// and are served before point and spot lights.
     ///
     /// # Example
     /// ```ignore
@@ -65,6 +69,8 @@ impl super::super::Scene {
             gpu: light,
             movability,
             user_tag,
+            casts_shadows: light.shadow_index != u32::MAX,
+            shadow_resolution: None,
         });
         let pushed = self.gpu_scene.lights.push(light);
         debug_assert_eq!(pushed, dense_index);
@@ -126,6 +132,7 @@ impl super::super::Scene {
             }
         }
         record.gpu = light;
+        record.casts_shadows = light.shadow_index != u32::MAX;
 
         // Increment generation counter for movable lights (for shadow cache invalidation)
         // Only increment if the light can actually move
@@ -139,6 +146,96 @@ impl super::super::Scene {
         Ok(())
     }
 
+    /// Edit every light in place through a closure.
+    ///
+    /// The closure receives each light's handle and a mutable copy of its GPU
+    /// parameters. Lights the closure leaves untouched are skipped; changed
+    /// lights go through [`update_light`](Self::update_light), so movability
+    /// rules, shadow-request tracking and shadow cache invalidation apply
+    /// exactly as they would for individual updates.
+    ///
+    /// Replaces the pattern of iterating [`iter_lights`](Self::iter_lights),
+    /// collecting handles and calling `update_light` one by one.
+    ///
+    /// # Example
+    /// ```ignore
+    /// // Flicker every light
+    /// scene.update_lights(|_, light| light.color_intensity[3] *= 0.9);
+    /// ```
+    pub fn update_lights(&mut self, mut f: impl FnMut(LightId, &mut GpuLight)) {
+        let lights: Vec<(LightId, GpuLight)> = self
+            .lights
+            .iter_with_handles()
+            .map(|(id, record)| (id, record.gpu))
+            .collect();
+        for (id, before) in lights {
+            let mut light = before;
+            f(id, &mut light);
+            if bytemuck::bytes_of(&light) != bytemuck::bytes_of(&before) {
+                let updated = self.update_light(id, light);
+                debug_assert!(updated.is_ok());
+            }
+        }
+    }
+
+    /// Enable or disable shadow casting for a light without disabling the light.
+    ///
+    /// A non-casting light still contributes illumination but never takes a
+    /// shadow atlas slot, so it skips the shadow pass entirely (six faces for a
+    /// point light) and the lighting shaders treat it as unshadowed. Useful for
+    /// fill and accent lights.
+    ///
+    /// Enabling shadows only *requests* a slot; the highest-importance casters
+    /// win when the 42-caster budget is exceeded.
+    ///
+    /// # Errors
+    /// - [`SceneError::InvalidHandle`](super::super::SceneError::InvalidHandle) if the light ID is invalid
+    pub fn set_light_casts_shadows(&mut self, id: LightId, casts_shadows: bool) -> Result<()> {
+        let Some((dense_index, record)) = self.lights.get_mut_with_index(id) else {
+            return Err(invalid("light"));
+        };
+        if record.casts_shadows == casts_shadows {
+            return Ok(());
+        }
+        record.casts_shadows = casts_shadows;
+        // Keep the caller-visible copy consistent so a get_light()/update_light()
+        // round-trip preserves the setting. flush() assigns the real slot.
+        record.gpu.shadow_index = if casts_shadows { 0 } else { u32::MAX };
+        let updated = self.gpu_scene.lights.update(dense_index, record.gpu);
+        debug_assert!(updated);
+        Ok(())
+    }
+
+    /// Whether a light requests shadows, or `None` for an invalid handle.
+    pub fn light_casts_shadows(&self, id: LightId) -> Option<bool> {
+        self.lights.get(id).map(|record| record.casts_shadows)
+    }
+
+    /// Cap this light's shadow faces at a fixed resolution, e.g. low for
+    /// distant fill lights. `None` restores the default.
+    ///
+    /// This is a cap only: faces are sized in power-of-two steps down from
+    /// the renderer's atlas face size (to at most 1/8 per side), so
+    /// `resolution` rounds down to the nearest step, and values at or above
+    /// the atlas size give the atlas size, never more. A fixed light is left
+    /// out of [`AdaptiveShadowResolution`](libhelio::AdaptiveShadowResolution)
+    /// and its faces count against the budget first. Takes effect on the
+    /// next flush.
+    ///
+    /// # Errors
+    /// - [`SceneError::InvalidHandle`](super::super::SceneError::InvalidHandle) if the light ID is invalid
+    pub fn set_light_shadow_resolution(&mut self, id: LightId, resolution: Option<u32>) -> Result<()> {
+        let record = self.lights.get_mut(id).ok_or_else(|| invalid("light"))?;
+        record.shadow_resolution = resolution;
+        Ok(())
+    }
+
+    /// A light's fixed shadow resolution (`Some(None)` when it follows the
+    /// renderer settings), or `None` for an invalid handle.
+    pub fn light_shadow_resolution(&self, id: LightId) -> Option<Option<u32>> {
+        self.lights.get(id).map(|record| record.shadow_resolution)
+    }
+
     /// Remove a light from the scene.
     ///
     /// Removes the light from the dense arena and GPU storage buffer using swap-remove
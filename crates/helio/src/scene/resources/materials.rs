@@ -8,7 +8,7 @@ use bytemuck::Zeroable;
 use helio_core::GpuMaterial;
 
 use crate::handles::MaterialId;
-use crate::material::{MaterialAsset, MaterialTextures};
+use crate::material::{MaterialAsset, MaterialTextures, NormalMapConvention, MAX_TEXTURES};
 
 use super::super::errors::{invalid, Result, SceneError};
 use super::super::helpers::{each_material_texture_ref, gpu_material_textures};
@@ -99,6 +99,7 @@ impl super::super::Scene {
     ///             texture: albedo_texture_id,
     ///             uv_channel: 0,
     ///             transform: TextureTransform::default(),
+    ///             sampler: None,
     ///         }),
     ///         ..Default::default()
     ///     },
@@ -108,7 +109,8 @@ impl super::super::Scene {
         self.validate_material_textures(&material.textures)?;
         self.bump_texture_refs(&material.textures, 1)?;
 
-        let gpu_textures = gpu_material_textures(&material.textures);
+        let gpu_textures =
+            gpu_material_textures(&material.textures, |texture| self.resolve_texture_ref(texture));
         let (id, slot, _is_new) = self.materials.insert(MaterialRecord {
             gpu: material.gpu,
             textures: material.textures,
@@ -212,13 +214,35 @@ impl super::super::Scene {
         record.textures = material.textures.clone();
 
         let updated_material = self.gpu_scene.materials.update(slot, material.gpu);
-        let updated_textures = self
-            .material_textures
-            .update(slot, gpu_material_textures(&material.textures));
+        let gpu_textures =
+            gpu_material_textures(&material.textures, |texture| self.resolve_texture_ref(texture));
+        let updated_textures = self.material_textures.update(slot, gpu_textures);
         debug_assert!(updated_material && updated_textures);
         Ok(())
     }
 
+    /// Change how a material's normal map is decoded, e.g. flip green for a
+    /// DirectX-convention map an importer assumed was OpenGL.
+    ///
+    /// # Errors
+    /// - [`SceneError::InvalidHandle`] if the material ID is invalid
+    pub fn set_normal_map_convention(
+        &mut self,
+        id: MaterialId,
+        convention: NormalMapConvention,
+    ) -> Result<()> {
+        let Some((slot, record)) = self.materials.get_mut_with_slot(id) else {
+            return Err(invalid("material"));
+        };
+        record.textures.normal_map_convention = convention;
+        let textures = record.textures.clone();
+        let gpu_textures =
+            gpu_material_textures(&textures, |texture| self.resolve_texture_ref(texture));
+        let updated = self.material_textures.update(slot, gpu_textures);
+        debug_assert!(updated);
+        Ok(())
+    }
+
     /// Remove a material from the scene's material pool.
     ///
     /// Decrements reference counts for all referenced textures and writes a tombstone
@@ -334,23 +358,30 @@ impl super::super::Scene {
     /// Increment or decrement reference counts for all textures in a material.
     ///
     /// Used when materials are inserted, updated, or removed to track texture usage.
+    /// References with a sampler override also acquire or release their variant slot.
     pub(in crate::scene) fn bump_texture_refs(
         &mut self,
         textures: &MaterialTextures,
         delta: i32,
     ) -> Result<()> {
-        for texture in [
-            textures.base_color,
-            textures.normal,
-            textures.roughness_metallic,
-            textures.emissive,
-            textures.occlusion,
-            textures.specular_color,
-            textures.specular_weight,
-        ]
-        .into_iter()
-        .flatten()
-        {
+        let mut refs = Vec::new();
+        each_material_texture_ref(textures, |texture| refs.push(texture));
+
+        if delta > 0 {
+            // Reserve variant slots before touching any count, so a full pool
+            // leaves the scene unchanged.
+            let mut new_variants = Vec::new();
+            for key in refs.iter().filter_map(|&texture| self.override_key(texture)) {
+                if !self.texture_variants.contains_key(&key) && !new_variants.contains(&key) {
+                    new_variants.push(key);
+                }
+            }
+            if new_variants.len() > MAX_TEXTURES.saturating_sub(self.textures.live_len()) {
+                return Err(SceneError::TextureCapacityExceeded);
+            }
+        }
+
+        for texture in refs {
             let (_, record) = self
                 .textures
                 .get_mut_with_slot(texture.texture)
@@ -360,8 +391,14 @@ impl super::super::Scene {
             } else {
                 record.ref_count = record.ref_count.saturating_sub((-delta) as u32);
             }
+            if let Some(key) = self.override_key(texture) {
+                if delta > 0 {
+                    self.acquire_texture_variant(key)?;
+                } else if delta < 0 {
+                    self.release_texture_variant(key);
+                }
+            }
         }
         Ok(())
     }
 }
-
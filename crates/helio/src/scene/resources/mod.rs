@@ -10,6 +10,12 @@
 //! - **Materials** ([`materials`]): Surface appearance (color, roughness, textures)
 //! - **Lights** ([`lights`]): Scene lighting (point, directional, spot)
 //!
+//! Assets that fail to load can be replaced by an error material; see
+//! [`Scene::error_material`](crate::Scene::error_material).
+//!
+//! With the `async` feature, textures and meshes can also be loaded in the
+//! background; see [`ResourceLoad`].
+//!
 //! # Reference Counting
 //!
 //! Meshes, textures, and materials are reference-counted. They cannot be removed
@@ -18,9 +24,18 @@
 //!
 //! Lights are not reference-counted and can be removed at any time.
 
+#[cfg(feature = "async")]
+mod async_load;
+mod error_material;
 mod lights;
 mod materials;
 mod meshes;
 mod reflection;
 mod textures;
 
+#[cfg(feature = "async")]
+pub(in crate::scene) use async_load::AsyncLoadQueue;
+pub use error_material::LoadFailure;
+#[cfg(feature = "async")]
+pub use async_load::ResourceLoad;
+
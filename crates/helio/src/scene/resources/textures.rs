@@ -7,16 +7,30 @@
 //!
 //! The scene supports a maximum of [`MAX_TEXTURES`](crate::material::MAX_TEXTURES) (16384)
 //! concurrent textures due to bindless array limits.
+//!
+//! # Compressed Textures
+//!
+//! Block-compressed data (BC1–BC7, e.g. from KTX2 or DDS files) is uploaded
+//! as-is through [`TextureUpload::compressed`](crate::TextureUpload::compressed),
+//! which keeps it compressed in VRAM. The device must have the format's
+//! feature (`TEXTURE_COMPRESSION_BC`, requested by
+//! [`required_wgpu_features`](crate::required_wgpu_features) when the adapter
+//! has it); check [`HelioCapabilities::texture_compression_bc`](crate::HelioCapabilities).
+//! KTX2 files load directly with [`Scene::load_ktx2`](crate::Scene::load_ktx2).
 
 use wgpu::util::DeviceExt;
 
 use crate::handles::TextureId;
-use crate::material::{TextureUpload, MAX_TEXTURES};
+use crate::ktx2::Ktx2Texture;
+use crate::material::{MaterialTextureRef, TextureSamplerDesc, TextureUpload, MAX_TEXTURES};
 
 use super::super::errors::{invalid, Result, SceneError};
 use super::super::types::TextureRecord;
 
 impl super::super::Scene {
+    /// Format of textures created by [`insert_render_target`](Self::insert_render_target).
+    pub const RENDER_TARGET_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+
     /// Insert a texture into the scene's texture pool.
     ///
     /// Uploads texture data to GPU memory and creates a texture view and sampler.
@@ -31,6 +45,10 @@ impl super::super::Scene {
     ///
     /// # Errors
     /// - [`SceneError::TextureCapacityExceeded`] if the texture pool is at capacity (16384 textures)
+    /// - [`SceneError::UnsupportedTextureFormat`] if the format needs a device feature that
+    ///   is not enabled (e.g. BCn without `TEXTURE_COMPRESSION_BC`)
+    /// - [`SceneError::InvalidTextureData`] if the data length, block alignment or mip count
+    ///   does not match the declared size and format
     ///
     /// # Returns
     /// A [`TextureId`] handle that can be used with material texture slots.
@@ -47,6 +65,7 @@ impl super::super::Scene {
     ///     width: 1024,
     ///     height: 1024,
     ///     format: wgpu::TextureFormat::Rgba8UnormSrgb,
+    ///     mip_level_count: 1,
     ///     data: image_bytes,
     ///     sampler: SamplerDescriptor {
     ///         mag_filter: wgpu::FilterMode::Linear,
@@ -60,6 +79,7 @@ impl super::super::Scene {
         if !self.textures.has_free_slot() && self.textures.slot_len() >= MAX_TEXTURES {
             return Err(SceneError::TextureCapacityExceeded);
         }
+        validate_upload(&texture, self.gpu_scene.device.features())?;
 
         helio_core::upload::record_upload_bytes(texture.data.len() as u64);
         let gpu_texture = self.gpu_scene.device.create_texture_with_data(
@@ -71,7 +91,7 @@ impl super::super::Scene {
                     height: texture.height,
                     depth_or_array_layers: 1,
                 },
-                mip_level_count: 1,
+                mip_level_count: texture.mip_level_count,
                 sample_count: 1,
                 dimension: wgpu::TextureDimension::D2,
                 format: texture.format,
@@ -82,29 +102,124 @@ impl super::super::Scene {
             &texture.data,
         );
         let view = gpu_texture.create_view(&wgpu::TextureViewDescriptor::default());
-        let sampler = self
-            .gpu_scene
-            .device
-            .create_sampler(&wgpu::SamplerDescriptor {
-                label: texture.label.as_deref(),
-                address_mode_u: texture.sampler.address_mode_u,
-                address_mode_v: texture.sampler.address_mode_v,
-                address_mode_w: texture.sampler.address_mode_w,
-                mag_filter: texture.sampler.mag_filter,
-                min_filter: texture.sampler.min_filter,
-                mipmap_filter: texture.sampler.mipmap_filter,
-                ..Default::default()
-            });
+        let sampler = self.cached_sampler(texture.sampler);
+        let (id, _, _) = self.textures.insert(TextureRecord {
+            _texture: gpu_texture,
+            view,
+            sampler,
+            sampler_desc: texture.sampler,
+            variant_of: None,
+            ref_count: 0,
+        });
+        self.texture_binding_version = self.texture_binding_version.wrapping_add(1);
+        Ok(id)
+    }
+
+    /// Allocate a texture the renderer can draw into and materials or UI can
+    /// sample, e.g. the target of an offscreen camera.
+    ///
+    /// The contents start undefined until something renders into it. Format is
+    /// `Rgba8UnormSrgb`, sampled with [`TextureSamplerDesc::default`].
+    ///
+    /// # Errors
+    /// - [`SceneError::TextureCapacityExceeded`] if the texture pool is full
+    pub fn insert_render_target(&mut self, label: &str, width: u32, height: u32) -> Result<TextureId> {
+        if !self.textures.has_free_slot() && self.textures.slot_len() >= MAX_TEXTURES {
+            return Err(SceneError::TextureCapacityExceeded);
+        }
+        let gpu_texture = self.gpu_scene.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some(label),
+            size: wgpu::Extent3d {
+                width: width.max(1),
+                height: height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: Self::RENDER_TARGET_FORMAT,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+        let view = gpu_texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let sampler_desc = TextureSamplerDesc::default();
+        let sampler = self.cached_sampler(sampler_desc);
         let (id, _, _) = self.textures.insert(TextureRecord {
             _texture: gpu_texture,
             view,
             sampler,
+            sampler_desc,
+            variant_of: None,
             ref_count: 0,
         });
         self.texture_binding_version = self.texture_binding_version.wrapping_add(1);
         Ok(id)
     }
 
+    /// View of a pooled texture, for rendering into one created by
+    /// [`insert_render_target`](Self::insert_render_target).
+    pub fn texture_view(&self, id: TextureId) -> Option<&wgpu::TextureView> {
+        self.textures.get(id).map(|t| &t.view)
+    }
+
+    /// Load a 2D texture from a KTX2 file into the texture pool.
+    ///
+    /// The file's format and mip chain are uploaded as stored, so BCn/ASTC/ETC2
+    /// data stays compressed and nothing is decoded on the CPU. The sampler
+    /// uses [`TextureSamplerDesc::default`] (repeat, trilinear).
+    ///
+    /// # Errors
+    /// - [`SceneError::Ktx2`] if the file cannot be read or parsed, uses Basis
+    ///   Universal or an unavailable supercompression scheme, or is a cubemap or
+    ///   array (load those with [`Ktx2Texture::create_texture`](crate::Ktx2Texture::create_texture))
+    /// - Any error of [`insert_texture`](Self::insert_texture), e.g.
+    ///   [`SceneError::UnsupportedTextureFormat`] when the device lacks the format
+    pub fn load_ktx2(&mut self, path: impl AsRef<std::path::Path>) -> Result<TextureId> {
+        let path = path.as_ref();
+        let label = path.file_name().map_or_else(|| "ktx2".into(), |name| name.to_string_lossy().into_owned());
+        let upload = Ktx2Texture::open(path)?.into_upload(label, TextureSamplerDesc::default())?;
+        self.insert_texture(upload)
+    }
+
+    /// Change the sampler a texture is sampled with.
+    ///
+    /// Affects every material that uses the texture without a
+    /// [`MaterialTextureRef::sampler`](crate::MaterialTextureRef::sampler)
+    /// override. Samplers are cached by state, so switching back and forth
+    /// creates at most one `wgpu::Sampler` per distinct descriptor.
+    ///
+    /// # Errors
+    /// - [`SceneError::InvalidHandle`] if the texture ID is invalid
+    pub fn set_texture_sampler(&mut self, id: TextureId, sampler: TextureSamplerDesc) -> Result<()> {
+        let cached = self.cached_sampler(sampler);
+        let record = self
+            .textures
+            .get_mut_with_slot(id)
+            .map(|(_, record)| record)
+            .filter(|record| record.variant_of.is_none())
+            .ok_or_else(|| invalid("texture"))?;
+        if record.sampler_desc == sampler {
+            return Ok(());
+        }
+        record.sampler = cached;
+        record.sampler_desc = sampler;
+        self.texture_binding_version = self.texture_binding_version.wrapping_add(1);
+        Ok(())
+    }
+
+    /// The sampler state a texture is currently sampled with.
+    pub fn texture_sampler(&self, id: TextureId) -> Option<TextureSamplerDesc> {
+        self.textures
+            .get(id)
+            .filter(|record| record.variant_of.is_none())
+            .map(|record| record.sampler_desc)
+    }
+
+    /// Number of distinct samplers created for scene textures so far.
+    pub fn cached_sampler_count(&self) -> usize {
+        self.sampler_cache.len()
+    }
+
     /// Remove a texture from the scene's texture pool.
     ///
     /// # Errors
@@ -127,7 +242,7 @@ impl super::super::Scene {
     /// scene.remove_texture(texture_id)?;
     /// ```
     pub fn remove_texture(&mut self, id: TextureId) -> Result<()> {
-        let Some(texture) = self.textures.get(id) else {
+        let Some(texture) = self.textures.get(id).filter(|t| t.variant_of.is_none()) else {
             return Err(invalid("texture"));
         };
         if texture.ref_count != 0 {
@@ -185,5 +300,185 @@ impl super::super::Scene {
             .map(|texture| &texture.sampler)
             .unwrap_or(&self.placeholder_sampler)
     }
+
+    /// The shared sampler for `desc`, created on first use.
+    pub(in crate::scene) fn cached_sampler(&mut self, desc: TextureSamplerDesc) -> wgpu::Sampler {
+        let device = &self.gpu_scene.device;
+        self.sampler_cache
+            .entry(desc)
+            .or_insert_with(|| device.create_sampler(&desc.to_wgpu(Some("Helio Scene Sampler"))))
+            .clone()
+    }
+
+    /// The texture slot a material reference samples through: the texture
+    /// itself, or the variant holding its sampler override.
+    ///
+    /// Only valid for references whose variant has been acquired.
+    pub(in crate::scene) fn resolve_texture_ref(&self, texture: MaterialTextureRef) -> TextureId {
+        match self.override_key(texture) {
+            Some(key) => self.texture_variants.get(&key).copied().unwrap_or(texture.texture),
+            None => texture.texture,
+        }
+    }
+
+    /// `(base, sampler)` key for references that need a variant slot.
+    ///
+    /// Independent of the base texture's current sampler, so a later
+    /// [`set_texture_sampler`](Self::set_texture_sampler) cannot strand a variant.
+    pub(in crate::scene) fn override_key(
+        &self,
+        texture: MaterialTextureRef,
+    ) -> Option<(TextureId, TextureSamplerDesc)> {
+        texture.sampler.map(|desc| (texture.texture, desc))
+    }
+
+    /// Take a reference on the variant slot for `key`, creating it if needed.
+    pub(in crate::scene) fn acquire_texture_variant(
+        &mut self,
+        key: (TextureId, TextureSamplerDesc),
+    ) -> Result<()> {
+        if let Some(&variant) = self.texture_variants.get(&key) {
+            let (_, record) = self
+                .textures
+                .get_mut_with_slot(variant)
+                .ok_or_else(|| invalid("texture"))?;
+            record.ref_count += 1;
+            return Ok(());
+        }
+        if !self.textures.has_free_slot() && self.textures.slot_len() >= MAX_TEXTURES {
+            return Err(SceneError::TextureCapacityExceeded);
+        }
+        let (base, desc) = key;
+        let base_record = self.textures.get(base).ok_or_else(|| invalid("texture"))?;
+        let (texture, view) = (base_record._texture.clone(), base_record.view.clone());
+        let sampler = self.cached_sampler(desc);
+        let (variant, _, _) = self.textures.insert(TextureRecord {
+            _texture: texture,
+            view,
+            sampler,
+            sampler_desc: desc,
+            variant_of: Some(base),
+            ref_count: 1,
+        });
+        self.texture_variants.insert(key, variant);
+        self.texture_binding_version = self.texture_binding_version.wrapping_add(1);
+        Ok(())
+    }
+
+    /// Drop a reference on the variant slot for `key`, freeing it at zero.
+    pub(in crate::scene) fn release_texture_variant(&mut self, key: (TextureId, TextureSamplerDesc)) {
+        let Some(&variant) = self.texture_variants.get(&key) else {
+            return;
+        };
+        let Some((_, record)) = self.textures.get_mut_with_slot(variant) else {
+            return;
+        };
+        record.ref_count = record.ref_count.saturating_sub(1);
+        if record.ref_count == 0 {
+            self.textures.remove(variant);
+            self.texture_variants.remove(&key);
+            self.texture_binding_version = self.texture_binding_version.wrapping_add(1);
+        }
+    }
+}
+
+
+/// Check `texture` against the device features and its own declared layout,
+/// so a bad upload is an error instead of a wgpu validation panic.
+fn validate_upload(texture: &TextureUpload, device_features: wgpu::Features) -> Result<()> {
+    let format = texture.format;
+    if !device_features.contains(format.required_features()) {
+        return Err(SceneError::UnsupportedTextureFormat { format });
+    }
+    let data_error = |reason: String| Err(SceneError::InvalidTextureData { reason });
+
+    if texture.width == 0 || texture.height == 0 {
+        return data_error(format!("{}x{} texture has no texels", texture.width, texture.height));
+    }
+    let full_chain = 32 - texture.width.max(texture.height).leading_zeros();
+    if texture.mip_level_count == 0 || texture.mip_level_count > full_chain {
+        return data_error(format!(
+            "{} mip levels for a {}x{} texture (1..={full_chain} allowed)",
+            texture.mip_level_count, texture.width, texture.height
+        ));
+    }
+    let (block_w, block_h) = format.block_dimensions();
+    if !texture.width.is_multiple_of(block_w) || !texture.height.is_multiple_of(block_h) {
+        return data_error(format!(
+            "{}x{} is not a multiple of the {block_w}x{block_h} blocks of {format:?}",
+            texture.width, texture.height
+        ));
+    }
+    match texture.expected_data_len() {
+        Some(len) if len == texture.data.len() => Ok(()),
+        Some(len) => data_error(format!(
+            "expected {len} bytes for {format:?} with {} mip levels, got {}",
+            texture.mip_level_count,
+            texture.data.len()
+        )),
+        None => data_error(format!("{format:?} cannot be uploaded from the CPU")),
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::validate_upload;
+    use crate::material::{TextureSamplerDesc, TextureUpload};
+    use crate::scene::SceneError;
+
+    fn bc7(width: u32, height: u32, mips: u32, len: usize) -> TextureUpload {
+        TextureUpload::compressed(
+            "bc7",
+            width,
+            height,
+            wgpu::TextureFormat::Bc7RgbaUnormSrgb,
+            mips,
+            vec![0; len],
+            TextureSamplerDesc::default(),
+        )
+    }
+
+    #[test]
+    fn compressed_upload_requires_device_feature() {
+        let upload = bc7(8, 8, 1, 64);
+        assert!(matches!(
+            validate_upload(&upload, wgpu::Features::empty()),
+            Err(SceneError::UnsupportedTextureFormat { .. })
+        ));
+        assert!(validate_upload(&upload, wgpu::Features::TEXTURE_COMPRESSION_BC).is_ok());
+    }
+
+    #[test]
+    fn compressed_mip_chain_pads_small_levels_to_whole_blocks() {
+        // 8x8 = 4 blocks, 4x4 = 1 block, 2x2 and 1x1 still take a block each.
+        let upload = bc7(8, 8, 4, (4 + 1 + 1 + 1) * 16);
+        assert_eq!(upload.expected_data_len(), Some(112));
+        assert!(validate_upload(&upload, wgpu::Features::TEXTURE_COMPRESSION_BC).is_ok());
+    }
+
+    #[test]
+    fn rejects_misaligned_size_bad_length_and_mip_count() {
+        let bc = wgpu::Features::TEXTURE_COMPRESSION_BC;
+        for upload in [bc7(6, 8, 1, 64), bc7(8, 8, 1, 63), bc7(8, 8, 5, 112), bc7(8, 8, 0, 0)] {
+            assert!(matches!(
+                validate_upload(&upload, bc),
+                Err(SceneError::InvalidTextureData { .. })
+            ));
+        }
+        let rgba = TextureUpload::rgba8("rgba", 3, 5, true, vec![0; 60], TextureSamplerDesc::default());
+        assert!(validate_upload(&rgba, wgpu::Features::empty()).is_ok());
+    }
+
+    #[test]
+    fn anisotropy_only_applies_to_fully_linear_samplers() {
+        let aniso = TextureSamplerDesc::default().anisotropic(8);
+        assert_eq!(aniso.to_wgpu(None).anisotropy_clamp, 8);
+        assert_eq!(aniso.anisotropic(64).effective_anisotropy(), 16);
+        assert_eq!(aniso.nearest().to_wgpu(None).anisotropy_clamp, 1);
+        let trilinear_point_mips = TextureSamplerDesc {
+            mipmap_filter: wgpu::MipmapFilterMode::Nearest,
+            ..aniso
+        };
+        assert_eq!(trilinear_point_mips.effective_anisotropy(), 1);
+    }
+}
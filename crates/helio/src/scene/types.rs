@@ -6,8 +6,8 @@ use libhelio::{GpuMeshletEntry, GpuPostProcessVolume, GpuWaterHitbox, GpuWaterVo
 use bytemuck::{Pod, Zeroable};
 
 use crate::groups::GroupMask;
-use crate::handles::{MaterialId, MeshId, ObjectId};
-use crate::material::MaterialTextures;
+use crate::handles::{MaterialId, MeshId, ObjectId, TextureId};
+use crate::material::{MaterialTextures, TextureSamplerDesc};
 use crate::vg::VirtualMeshId;
 
 /// Descriptor for creating a voxel volume in the scene
@@ -69,7 +69,8 @@ pub struct ObjectDescriptor {
     /// at cull time. The radius scales by the maximum scale component of the transform.
     pub bounds: [f32; 4],
 
-    /// Render flags: bit 0 = casts shadow, bit 1 = receives shadow.
+    /// Render flags: bit 0 = casts shadow, bit 1 = receives shadow,
+    /// bit 2 = minimap geometry ([`OBJECT_FLAG_MINIMAP`](crate::OBJECT_FLAG_MINIMAP)).
     pub flags: u32,
 
     /// Group membership bitmask for batch visibility control.
@@ -147,6 +148,15 @@ pub(crate) struct LightRecord {
     pub movability: libhelio::Movability,
     /// Application-defined tag — see [`ObjectDescriptor::user_tag`].
     pub user_tag: u64,
+    /// Whether the light requests a shadow atlas slot.
+    ///
+    /// Kept separately from `gpu_scene.lights[i].shadow_index`, which `flush()`
+    /// overwrites with the granted slot (or `u32::MAX` when the caster budget is
+    /// full). Non-casters still illuminate; they are just shaded unshadowed.
+    pub casts_shadows: bool,
+    /// Fixed shadow face resolution in texels, overriding the global atlas
+    /// size and adaptive resolution. `None` follows the renderer settings.
+    pub shadow_resolution: Option<u32>,
 }
 
 /// Internal record for a scene object.
@@ -176,6 +186,9 @@ pub(crate) struct ObjectRecord {
     /// Axis-aligned bounding box (converted from sphere bounds).
     pub aabb: GpuInstanceAabb,
 
+    /// Vertex wind sway; zeroed when the object has none.
+    pub wind: libhelio::GpuWindParams,
+
     /// Draw call template (index count, first index, etc.).
     pub draw: GpuDrawCall,
 
@@ -196,9 +209,16 @@ pub(crate) struct TextureRecord {
     /// Texture view for shader binding.
     pub view: wgpu::TextureView,
 
-    /// Sampler for texture filtering.
+    /// Sampler for texture filtering (shared through the scene's sampler cache).
     pub sampler: wgpu::Sampler,
 
+    /// State `sampler` was created from.
+    pub sampler_desc: TextureSamplerDesc,
+
+    /// For a sampler-override slot, the texture whose view it shares.
+    /// Variants are internal; their ids never reach the caller.
+    pub variant_of: Option<TextureId>,
+
     /// Number of materials currently using this texture.
     pub ref_count: u32,
 }
@@ -252,6 +272,9 @@ pub(crate) struct VirtualObjectRecord {
 
     /// GPU instance data (model matrix, normal matrix, bounds).
     pub instance: GpuInstanceData,
+
+    /// Vertex wind sway; zeroed when the object has none.
+    pub wind: libhelio::GpuWindParams,
 }
 
 /// Internal record for a water volume.
@@ -93,6 +93,7 @@ impl super::super::Scene {
             groups: desc.groups,
             movability: desc.movability.unwrap_or_default(),
             instance,
+            wind: libhelio::GpuWindParams::default(),
         });
         self.vg_objects_dirty = true;
         Ok(id)
@@ -172,6 +173,29 @@ impl super::super::Scene {
         Ok(())
     }
 
+    /// Set or clear a virtual object's vertex wind sway.
+    ///
+    /// The virtual-geometry draw displaces the object's vertices exactly like
+    /// [`set_object_wind`](crate::Scene::set_object_wind) does for regular
+    /// objects. `None` stops the sway.
+    ///
+    /// # Performance
+    /// - CPU cost: O(1) record update + marks VG dirty
+    /// - GPU cost: Deferred to next `flush()` when VG buffers are rebuilt
+    pub fn set_virtual_object_wind(
+        &mut self,
+        id: VirtualObjectId,
+        wind: Option<libhelio::WindParams>,
+    ) -> Result<()> {
+        let record = self
+            .vg_objects
+            .get_mut(id)
+            .ok_or_else(|| invalid("virtual_object"))?;
+        record.wind = wind.map(|w| w.to_gpu()).unwrap_or_default();
+        self.vg_objects_dirty = true;
+        Ok(())
+    }
+
     /// Remove a virtual object from the scene.
     ///
     /// Removes the virtual object from the dense arena and decrements the virtual
@@ -68,6 +68,7 @@ impl super::super::Scene {
             meshlets: bytemuck::cast_slice(&self.vg_cpu_meshlets),
             objects: bytemuck::cast_slice(&self.vg_cpu_objects),
             instances: bytemuck::cast_slice(&self.vg_cpu_instances),
+            wind: bytemuck::cast_slice(&self.vg_cpu_wind),
             work_items: bytemuck::cast_slice(&self.vg_cpu_work_items),
             meshlet_count: u32::try_from(self.vg_cpu_meshlets.len())
                 .expect("virtual geometry exceeds the u32 descriptor address space"),
@@ -100,12 +101,14 @@ impl super::super::Scene {
         self.vg_cpu_meshlets.clear();
         self.vg_cpu_objects.clear();
         self.vg_cpu_instances.clear();
+        self.vg_cpu_wind.clear();
         self.vg_cpu_work_items.clear();
         self.vg_instance_dirty_range = None;
         self.vg_published_instance_dirty_range = None;
         self.vg_max_draw_count = 0;
         self.vg_cpu_objects.reserve(dense_object_count);
         self.vg_cpu_instances.reserve(dense_object_count);
+        self.vg_cpu_wind.reserve(dense_object_count);
 
         let referenced_meshes = (0..dense_object_count)
             .filter_map(|index| self.vg_objects.get_dense(index))
@@ -140,6 +143,7 @@ impl super::super::Scene {
             }
 
             self.vg_cpu_instances.push(object.instance);
+            self.vg_cpu_wind.push(object.wind);
             self.vg_cpu_objects.push(GpuVgObject {
                 instance_index,
                 lod_count: mesh.lod_count,
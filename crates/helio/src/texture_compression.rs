@@ -0,0 +1,347 @@
+//! CPU block compression for runtime-generated textures.
+//!
+//! Enabled by the `texture-compression` feature. Textures loaded from disk
+//! should ship pre-compressed (KTX2/DDS) and go through
+//! [`TextureUpload::compressed`]; this covers textures built at runtime
+//! (splatmaps, baked atlases, procedural detail) that would otherwise stay
+//! RGBA8 in VRAM.
+//!
+//! The encoders are fast bounding-box fits — the endpoints are the per-block
+//! channel extremes — not exhaustive searches. Quality is below an offline
+//! compressor but fine for the data this is meant for. BC7 and BC6H need a
+//! mode search that does not belong on the CPU at load time, so they are
+//! upload-only.
+
+use crate::material::TextureUpload;
+use crate::scene::{Result, SceneError};
+
+/// Block-compressed target for [`TextureUpload::compress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BcFormat {
+    /// RGB plus 1-bit alpha, 8 bytes per block (8:1 against RGBA8).
+    Bc1,
+    /// RGB plus smooth alpha, 16 bytes per block (4:1).
+    Bc3,
+    /// Single channel (R), 8 bytes per block. Masks, roughness, height.
+    Bc4,
+    /// Two channels (RG), 16 bytes per block. Tangent-space normal maps;
+    /// the G-buffer reconstructs Z.
+    Bc5,
+}
+
+impl BcFormat {
+    /// The `wgpu` format the compressed data is uploaded as. BC4 and BC5 have
+    /// no sRGB variant and ignore `srgb`.
+    pub fn texture_format(self, srgb: bool) -> wgpu::TextureFormat {
+        use wgpu::TextureFormat as F;
+        match (self, srgb) {
+            (BcFormat::Bc1, false) => F::Bc1RgbaUnorm,
+            (BcFormat::Bc1, true) => F::Bc1RgbaUnormSrgb,
+            (BcFormat::Bc3, false) => F::Bc3RgbaUnorm,
+            (BcFormat::Bc3, true) => F::Bc3RgbaUnormSrgb,
+            (BcFormat::Bc4, _) => F::Bc4RUnorm,
+            (BcFormat::Bc5, _) => F::Bc5RgUnorm,
+        }
+    }
+
+    fn block_size(self) -> usize {
+        match self {
+            BcFormat::Bc1 | BcFormat::Bc4 => 8,
+            BcFormat::Bc3 | BcFormat::Bc5 => 16,
+        }
+    }
+}
+
+impl TextureUpload {
+    /// Compress an RGBA8 upload (every mip level) to `target`.
+    ///
+    /// The sRGB-ness of the source format carries over for BC1/BC3. Fails
+    /// with [`SceneError::InvalidTextureData`] when the source is not
+    /// `Rgba8Unorm`/`Rgba8UnormSrgb`, its data length does not match, or its
+    /// size is not a multiple of 4.
+    pub fn compress(&self, target: BcFormat) -> Result<TextureUpload> {
+        let invalid = |reason: String| SceneError::InvalidTextureData { reason };
+        let srgb = match self.format {
+            wgpu::TextureFormat::Rgba8Unorm => false,
+            wgpu::TextureFormat::Rgba8UnormSrgb => true,
+            other => return Err(invalid(format!("cannot compress {other:?}, expected RGBA8"))),
+        };
+        if !self.width.is_multiple_of(4) || !self.height.is_multiple_of(4) {
+            return Err(invalid(format!(
+                "{}x{} is not a multiple of the 4x4 BC block size",
+                self.width, self.height
+            )));
+        }
+        if self.expected_data_len() != Some(self.data.len()) {
+            return Err(invalid(format!(
+                "RGBA8 data is {} bytes, expected {:?}",
+                self.data.len(),
+                self.expected_data_len()
+            )));
+        }
+
+        let mut data = Vec::new();
+        let mut offset = 0;
+        for level in 0..self.mip_level_count {
+            let w = (self.width >> level).max(1);
+            let h = (self.height >> level).max(1);
+            let len = (w * h * 4) as usize;
+            data.extend(compress_rgba8(&self.data[offset..offset + len], w, h, target));
+            offset += len;
+        }
+
+        Ok(TextureUpload {
+            label: self.label.clone(),
+            width: self.width,
+            height: self.height,
+            format: target.texture_format(srgb),
+            mip_level_count: self.mip_level_count,
+            data,
+            sampler: self.sampler,
+        })
+    }
+}
+
+/// Compress tightly packed RGBA8 texels to `target` blocks, row by row.
+///
+/// Sizes that are not a multiple of 4 (small mip levels) are padded by
+/// repeating the edge texels.
+pub fn compress_rgba8(rgba: &[u8], width: u32, height: u32, target: BcFormat) -> Vec<u8> {
+    assert_eq!(rgba.len(), (width * height * 4) as usize, "RGBA8 data does not match {width}x{height}");
+    let blocks_x = width.div_ceil(4);
+    let blocks_y = height.div_ceil(4);
+    let mut out = Vec::with_capacity((blocks_x * blocks_y) as usize * target.block_size());
+
+    for by in 0..blocks_y {
+        for bx in 0..blocks_x {
+            let mut block = [[0u8; 4]; 16];
+            for (i, texel) in block.iter_mut().enumerate() {
+                let x = (bx * 4 + i as u32 % 4).min(width - 1);
+                let y = (by * 4 + i as u32 / 4).min(height - 1);
+                let at = ((y * width + x) * 4) as usize;
+                texel.copy_from_slice(&rgba[at..at + 4]);
+            }
+            match target {
+                BcFormat::Bc1 => out.extend(encode_color_block(&block, true)),
+                BcFormat::Bc3 => {
+                    out.extend(encode_channel_block(&block.map(|t| t[3])));
+                    out.extend(encode_color_block(&block, false));
+                }
+                BcFormat::Bc4 => out.extend(encode_channel_block(&block.map(|t| t[0]))),
+                BcFormat::Bc5 => {
+                    out.extend(encode_channel_block(&block.map(|t| t[0])));
+                    out.extend(encode_channel_block(&block.map(|t| t[1])));
+                }
+            }
+        }
+    }
+    out
+}
+
+fn to_565(c: [u8; 3]) -> u16 {
+    ((c[0] as u16 >> 3) << 11) | ((c[1] as u16 >> 2) << 5) | (c[2] as u16 >> 3)
+}
+
+fn from_565(c: u16) -> [i32; 3] {
+    let r = (c >> 11) & 31;
+    let g = (c >> 5) & 63;
+    let b = c & 31;
+    [((r << 3) | (r >> 2)) as i32, ((g << 2) | (g >> 4)) as i32, ((b << 3) | (b >> 2)) as i32]
+}
+
+fn distance_sq(a: [i32; 3], b: [u8; 4]) -> i32 {
+    (0..3).map(|i| (a[i] - b[i] as i32).pow(2)).sum()
+}
+
+/// BC1 colour block. With `allow_alpha`, blocks containing texels below 50%
+/// alpha use the 3-colour mode and index 3 for transparent texels (BC1);
+/// otherwise the 4-colour mode is always used (the colour half of BC3).
+fn encode_color_block(block: &[[u8; 4]; 16], allow_alpha: bool) -> [u8; 8] {
+    let punch_through = allow_alpha && block.iter().any(|t| t[3] < 128);
+    let opaque = || block.iter().filter(|t| !punch_through || t[3] >= 128);
+
+    let mut lo = [255u8; 3];
+    let mut hi = [0u8; 3];
+    for texel in opaque() {
+        for c in 0..3 {
+            lo[c] = lo[c].min(texel[c]);
+            hi[c] = hi[c].max(texel[c]);
+        }
+    }
+    if opaque().next().is_none() {
+        lo = [0; 3];
+        hi = [0; 3];
+    }
+
+    let (mut c0, mut c1) = (to_565(hi), to_565(lo));
+    // The endpoint order selects the mode: c0 > c1 is 4-colour, c0 <= c1 is
+    // 3-colour + transparent.
+    if punch_through {
+        if c0 > c1 {
+            std::mem::swap(&mut c0, &mut c1);
+        }
+    } else if c0 < c1 {
+        std::mem::swap(&mut c0, &mut c1);
+    }
+
+    let (e0, e1) = (from_565(c0), from_565(c1));
+    let mix = |a: i32, b: i32, wa: i32, wb: i32| (a * wa + b * wb) / (wa + wb);
+    let palette: Vec<[i32; 3]> = if c0 > c1 {
+        vec![
+            e0,
+            e1,
+            [0, 1, 2].map(|i| mix(e0[i], e1[i], 2, 1)),
+            [0, 1, 2].map(|i| mix(e0[i], e1[i], 1, 2)),
+        ]
+    } else {
+        vec![e0, e1, [0, 1, 2].map(|i| mix(e0[i], e1[i], 1, 1))]
+    };
+
+    let mut indices = 0u32;
+    for (i, texel) in block.iter().enumerate() {
+        let index = if punch_through && texel[3] < 128 {
+            3
+        } else {
+            (0..palette.len())
+                .min_by_key(|&p| distance_sq(palette[p], *texel))
+                .unwrap_or(0) as u32
+        };
+        indices |= index << (i * 2);
+    }
+
+    let mut out = [0u8; 8];
+    out[0..2].copy_from_slice(&c0.to_le_bytes());
+    out[2..4].copy_from_slice(&c1.to_le_bytes());
+    out[4..8].copy_from_slice(&indices.to_le_bytes());
+    out
+}
+
+/// BC4 single-channel block, also the alpha half of BC3 and each half of BC5.
+/// Always uses the 8-value mode (`e0 > e1`).
+fn encode_channel_block(values: &[u8; 16]) -> [u8; 8] {
+    let e0 = *values.iter().max().unwrap_or(&0);
+    let e1 = *values.iter().min().unwrap_or(&0);
+
+    let mut bits = 0u64;
+    if e0 > e1 {
+        let (a, b) = (e0 as i32, e1 as i32);
+        let palette: [i32; 8] = std::array::from_fn(|i| match i {
+            0 => a,
+            1 => b,
+            _ => ((8 - i as i32) * a + (i as i32 - 1) * b) / 7,
+        });
+        for (i, &v) in values.iter().enumerate() {
+            let index = (0..8).min_by_key(|&p| (palette[p] - v as i32).abs()).unwrap_or(0) as u64;
+            bits |= index << (i * 3);
+        }
+    }
+
+    let mut out = [0u8; 8];
+    out[0] = e0;
+    out[1] = e1;
+    out[2..8].copy_from_slice(&bits.to_le_bytes()[..6]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode_channel_block(block: &[u8]) -> [u8; 16] {
+        let (a, b) = (block[0] as i32, block[1] as i32);
+        let mut bits = [0u8; 8];
+        bits[..6].copy_from_slice(&block[2..8]);
+        let bits = u64::from_le_bytes(bits);
+        std::array::from_fn(|i| {
+            let index = ((bits >> (i * 3)) & 7) as i32;
+            match index {
+                0 => a as u8,
+                1 => b as u8,
+                _ if a > b => (((8 - index) * a + (index - 1) * b) / 7) as u8,
+                6 => 0,
+                7 => 255,
+                _ => (((6 - index) * a + (index - 1) * b) / 5) as u8,
+            }
+        })
+    }
+
+    fn gradient(width: u32, height: u32) -> Vec<u8> {
+        (0..width * height)
+            .flat_map(|i| {
+                let (x, y) = (i % width, i / width);
+                [(x * 255 / (width - 1)) as u8, (y * 255 / (height - 1)) as u8, 128, 255]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn block_sizes_match_wgpu_formats() {
+        for format in [BcFormat::Bc1, BcFormat::Bc3, BcFormat::Bc4, BcFormat::Bc5] {
+            let wgpu_format = format.texture_format(false);
+            assert_eq!(wgpu_format.block_copy_size(None), Some(format.block_size() as u32));
+            assert_eq!(compress_rgba8(&gradient(8, 4), 8, 4, format).len(), 2 * format.block_size());
+        }
+    }
+
+    #[test]
+    fn bc5_keeps_both_channels_close() {
+        let rgba = gradient(4, 4);
+        let blocks = compress_rgba8(&rgba, 4, 4, BcFormat::Bc5);
+        let red = decode_channel_block(&blocks[0..8]);
+        let green = decode_channel_block(&blocks[8..16]);
+        for i in 0..16 {
+            assert!((red[i] as i32 - rgba[i * 4] as i32).abs() <= 19);
+            assert!((green[i] as i32 - rgba[i * 4 + 1] as i32).abs() <= 19);
+        }
+    }
+
+    #[test]
+    fn solid_colour_block_is_exact_in_565() {
+        let rgba = [[255u8, 0, 255, 255]; 16].concat();
+        let block = compress_rgba8(&rgba, 4, 4, BcFormat::Bc1);
+        let c0 = u16::from_le_bytes([block[0], block[1]]);
+        assert_eq!(from_565(c0), [255, 0, 255]);
+        // Every texel picks an endpoint equal to c0.
+        let indices = u32::from_le_bytes(block[4..8].try_into().unwrap());
+        for i in 0..16 {
+            let index = (indices >> (i * 2)) & 3;
+            let c = if index == 0 { c0 } else { u16::from_le_bytes([block[2], block[3]]) };
+            assert_eq!(from_565(c), [255, 0, 255]);
+        }
+    }
+
+    #[test]
+    fn bc1_marks_transparent_texels() {
+        let mut rgba = [[200u8, 100, 50, 255]; 16];
+        rgba[5][3] = 0;
+        let block = compress_rgba8(&rgba.concat(), 4, 4, BcFormat::Bc1);
+        let c0 = u16::from_le_bytes([block[0], block[1]]);
+        let c1 = u16::from_le_bytes([block[2], block[3]]);
+        assert!(c0 <= c1, "punch-through alpha needs the 3-colour mode");
+        let indices = u32::from_le_bytes(block[4..8].try_into().unwrap());
+        assert_eq!((indices >> 10) & 3, 3);
+        assert_ne!(indices & 3, 3);
+    }
+
+    #[test]
+    fn compress_keeps_mips_and_srgb() {
+        let mut data = gradient(8, 8);
+        data.extend(gradient(4, 4));
+        let upload = TextureUpload {
+            label: None,
+            width: 8,
+            height: 8,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            mip_level_count: 2,
+            data,
+            sampler: Default::default(),
+        };
+        let bc3 = upload.compress(BcFormat::Bc3).unwrap();
+        assert_eq!(bc3.format, wgpu::TextureFormat::Bc3RgbaUnormSrgb);
+        assert_eq!(bc3.data.len(), (4 + 1) * 16);
+        assert_eq!(bc3.expected_data_len(), Some(bc3.data.len()));
+
+        let odd = TextureUpload { width: 6, data: vec![0; 6 * 8 * 4], mip_level_count: 1, ..upload };
+        assert!(odd.compress(BcFormat::Bc1).is_err());
+    }
+}
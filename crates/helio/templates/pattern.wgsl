@@ -0,0 +1,60 @@
+// Procedural reference pattern (libhelio::ProceduralPattern).
+// class_params: x = kind + 4 * space, y = cells per unit,
+//               z/w = colour A/B as 8-bit RGB words (linear), stored as
+//               integer-valued floats.
+fn pattern_coords(material: GpuMaterial, input: VertexOutput) -> vec2<f32> {
+    let space = u32(material.class_params.x) / 4u;
+    if space == 1u {
+        return input.tex_coords;
+    }
+    // World space: project along the dominant normal axis so floors, walls
+    // and ceilings all get square cells.
+    let n = abs(input.world_normal);
+    let p = input.world_position;
+    if n.y >= n.x && n.y >= n.z {
+        return p.xz;
+    }
+    if n.x >= n.z {
+        return p.zy;
+    }
+    return p.xy;
+}
+
+// 0 = colour A, 1 = colour B. Edges are widened by the screen-space
+// derivative so the pattern stays stable at grazing angles.
+fn pattern_mask(kind: u32, coords: vec2<f32>) -> f32 {
+    let aa = max(fwidth(coords), vec2<f32>(1e-4));
+    if kind == 1u {
+        // Grid: colour B lines 5% of a cell wide, centred on integer coords.
+        let dist = abs(fract(coords - 0.5) - 0.5);
+        let cover = clamp((0.025 - dist) / aa + 0.5, vec2<f32>(0.0), vec2<f32>(1.0));
+        return max(cover.x, cover.y);
+    }
+    if kind == 2u {
+        // Dots: one disc of radius 0.25 per cell.
+        let r = length(fract(coords) - 0.5);
+        let w = max(aa.x, aa.y);
+        return 1.0 - smoothstep(0.25 - w, 0.25 + w, r);
+    }
+    // Checker: XOR of two antialiased square waves.
+    let s = clamp((abs(fract(coords + 0.25) - 0.5) - 0.25) / aa + 0.5, vec2<f32>(0.0), vec2<f32>(1.0));
+    return s.x + s.y - 2.0 * s.x * s.y;
+}
+
+fn radiant_eval_surface(material: GpuMaterial,
+                        material_tex: MaterialTextureData,
+                        input: VertexOutput) -> SurfaceData {
+    var s = default_pbr_surface(material, material_tex, input);
+
+    let kind = u32(material.class_params.x) % 4u;
+    let coords = pattern_coords(material, input) * material.class_params.y;
+    let color_a = unpack4x8unorm(u32(material.class_params.z)).rgb;
+    let color_b = unpack4x8unorm(u32(material.class_params.w)).rgb;
+    s.albedo = vec4<f32>(mix(color_a, color_b, pattern_mask(kind, coords)), s.albedo.a);
+    s.specular_f0 = resolve_specular_f0(material, material_tex, s.albedo.rgb, s.metallic, input.tex_coords);
+
+    // RADIANT_OVERRIDE_SURFACE
+    // RADIANT_OVERRIDE_END
+
+    return s;
+}
@@ -0,0 +1,47 @@
+// Triplanar projection (libhelio::TriplanarMapping).
+// class_params: x = space (0 world, 1 object), y = repeats per unit,
+//               z = blend sharpness exponent.
+
+// Axis blend weights: |n|^sharpness, normalised to sum to 1.
+fn triplanar_weights(n: vec3<f32>, sharpness: f32) -> vec3<f32> {
+    let w = pow(abs(n), vec3<f32>(sharpness));
+    return w / max(w.x + w.y + w.z, 1e-4);
+}
+
+// Every projection is sampled, weighted or not, so derivatives stay valid
+// across the blend regions.
+fn triplanar_sample(slot: MaterialTextureSlot, p: vec3<f32>, w: vec3<f32>, fallback: vec4<f32>) -> vec4<f32> {
+    let x = sample_texture(slot, p.zy, fallback);
+    let y = sample_texture(slot, p.xz, fallback);
+    let z = sample_texture(slot, p.xy, fallback);
+    return x * w.x + y * w.y + z * w.z;
+}
+
+fn radiant_eval_surface(material: GpuMaterial,
+                        material_tex: MaterialTextureData,
+                        input: VertexOutput) -> SurfaceData {
+    var s = default_pbr_surface(material, material_tex, input);
+
+    let object_space = material.class_params.x > 0.5;
+    let p = select(input.world_position, input.object_position, object_space) * material.class_params.y;
+    let n = normalize(select(input.world_normal, input.object_normal, object_space));
+    let w = triplanar_weights(n, material.class_params.z);
+
+    s.albedo = material.base_color * triplanar_sample(material_tex.base_color, p, w, vec4<f32>(1.0));
+    s.alpha = s.albedo.a;
+    let orm = triplanar_sample(material_tex.roughness_metallic, p, w, vec4<f32>(1.0));
+    let occlusion = triplanar_sample(material_tex.occlusion, p, w, vec4<f32>(1.0));
+    let emissive = triplanar_sample(material_tex.emissive, p, w, vec4<f32>(1.0));
+    s.ao = 1.0 + (occlusion.r - 1.0) * material_tex.params.y;
+    s.roughness = clamp(material.roughness_metallic.x * orm.g, 0.045, 1.0);
+    s.metallic = clamp(material.roughness_metallic.y * orm.b, 0.0, 1.0);
+    s.emissive = material.emissive.rgb * material.emissive.w * emissive.rgb;
+    s.specular_f0 = resolve_specular_f0(material, material_tex, s.albedo.rgb, s.metallic, input.tex_coords);
+    // Normal maps need UV tangents; keep the geometric normal.
+    s.normal = normalize(input.world_normal);
+
+    // RADIANT_OVERRIDE_SURFACE
+    // RADIANT_OVERRIDE_END
+
+    return s;
+}
@@ -22,7 +22,12 @@ pub struct GpuCameraUniforms {
     pub position_near: [f32; 4],
     /// Camera forward direction (xyz) + far plane (w)
     pub forward_far: [f32; 4],
-    /// Jitter offset for TAA (xy) + frame index (z) + padding (w)
+    /// Jitter offset for TAA (xy) + frame index (z) + pre-exposure (w).
+    ///
+    /// Pre-exposure scales the radiance written to the HDR target so it stays
+    /// in `Rgba16Float`'s precise range: passes that write scene radiance
+    /// multiply by it, and post-processing divides it back out before
+    /// exposure, bloom and tonemapping. 1.0 leaves values unscaled.
     pub jitter_frame: [f32; 4],
     /// Previous frame view-projection (for TAA motion vectors)
     pub prev_view_proj: [f32; 16],
@@ -50,9 +55,24 @@ impl GpuCameraUniforms {
             inv_view_proj: inv_view_proj.to_cols_array(),
             position_near: [position.x, position.y, position.z, near],
             forward_far: [forward.x, forward.y, forward.z, far],
-            jitter_frame: [jitter[0], jitter[1], frame as f32, 0.0],
+            jitter_frame: [jitter[0], jitter[1], frame as f32, 1.0],
             prev_view_proj: prev_view_proj.to_cols_array(),
         }
     }
+
+    /// Camera world position.
+    pub fn position(&self) -> Vec3 {
+        Vec3::new(self.position_near[0], self.position_near[1], self.position_near[2])
+    }
+
+    /// Pre-exposure factor applied to HDR radiance (see [`Self::jitter_frame`]).
+    pub fn pre_exposure(&self) -> f32 {
+        self.jitter_frame[3]
+    }
+
+    /// Combined view-projection matrix.
+    pub fn view_proj_matrix(&self) -> Mat4 {
+        Mat4::from_cols_array(&self.view_proj)
+    }
 }
 
@@ -0,0 +1,226 @@
+/// Depth state for a draw, chosen from pipeline variants built up front.
+///
+/// Depth test and write are baked into a `wgpu::RenderPipeline`, so they
+/// cannot be flipped per draw. Passes that need more than one behaviour build
+/// one pipeline per mode (see [`DepthMode::ALL`]) and select between them
+/// when recording.
+///
+/// - **Normal**: test and write — ordinary opaque geometry.
+/// - **TestNoWrite**: occluded by the scene but leaves depth untouched —
+///   transparent surfaces, decals, in-scene debug geometry.
+/// - **NoTestNoWrite**: drawn over everything — HUD markers, x-ray
+///   highlights, always-on-top gizmos.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(u8)]
+pub enum DepthMode {
+    /// Depth test and depth write enabled.
+    #[default]
+    Normal = 0,
+    /// Depth test enabled, depth write disabled.
+    TestNoWrite = 1,
+    /// Depth test and depth write disabled.
+    NoTestNoWrite = 2,
+}
+
+impl DepthMode {
+    /// Every mode, in `index()` order — for building one pipeline per variant.
+    pub const ALL: [DepthMode; 3] = [
+        DepthMode::Normal,
+        DepthMode::TestNoWrite,
+        DepthMode::NoTestNoWrite,
+    ];
+
+    /// Position of this mode in [`DepthMode::ALL`], for indexing variant arrays.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns true if fragments are rejected by the depth buffer.
+    pub fn tests_depth(self) -> bool {
+        !matches!(self, DepthMode::NoTestNoWrite)
+    }
+
+    /// Returns true if fragments write the depth buffer.
+    pub fn writes_depth(self) -> bool {
+        matches!(self, DepthMode::Normal)
+    }
+
+    /// Depth-stencil state for this mode against a `format` attachment.
+    ///
+    /// `compare` is the test used when the mode tests depth; `NoTestNoWrite`
+    /// uses `Always` instead. The attachment is still declared in that case
+    /// so all variants stay compatible with the same render pass.
+    pub fn depth_stencil_state(
+        self,
+        format: wgpu::TextureFormat,
+        compare: wgpu::CompareFunction,
+    ) -> wgpu::DepthStencilState {
+        wgpu::DepthStencilState {
+            format,
+            depth_write_enabled: Some(self.writes_depth()),
+            depth_compare: Some(if self.tests_depth() {
+                compare
+            } else {
+                wgpu::CompareFunction::Always
+            }),
+            stencil: wgpu::StencilState::default(),
+            bias: wgpu::DepthBiasState::default(),
+        }
+    }
+}
+
+/// Which end of the `[0, 1]` depth range is near.
+///
+/// Every pass that writes or compares a depth buffer has to agree on this:
+/// depth tests, comparison samplers, clear values, bias signs and the
+/// projection matrices all flip together. The renderer owns the choice and
+/// hands it to each pass, so they cannot drift apart.
+///
+/// - **Standard**: near = 0, far = 1, closer surfaces compare `Less`.
+/// - **Reversed**: near = 1, far = 0, closer surfaces compare `Greater`.
+///   Spends float precision where perspective depth needs it most.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(u32)]
+pub enum DepthConvention {
+    #[default]
+    Standard = 0,
+    Reversed = 1,
+}
+
+impl DepthConvention {
+    pub fn is_reversed(self) -> bool {
+        matches!(self, DepthConvention::Reversed)
+    }
+
+    /// `standard`, the comparison written for standard depth, expressed in
+    /// this convention: `Less` becomes `Greater`, `LessEqual` becomes
+    /// `GreaterEqual` and so on. `Always`, `Never`, `Equal` and `NotEqual`
+    /// are unchanged.
+    pub fn compare(self, standard: wgpu::CompareFunction) -> wgpu::CompareFunction {
+        use wgpu::CompareFunction as C;
+        if !self.is_reversed() {
+            return standard;
+        }
+        match standard {
+            C::Less => C::Greater,
+            C::LessEqual => C::GreaterEqual,
+            C::Greater => C::Less,
+            C::GreaterEqual => C::LessEqual,
+            other => other,
+        }
+    }
+
+    /// Depth value of the far plane, used to clear depth buffers.
+    pub fn far_depth(self) -> f32 {
+        if self.is_reversed() { 0.0 } else { 1.0 }
+    }
+
+    /// Rasterizer depth bias pushing surfaces away from the viewer.
+    pub fn depth_bias(self, standard: wgpu::DepthBiasState) -> wgpu::DepthBiasState {
+        if !self.is_reversed() {
+            return standard;
+        }
+        wgpu::DepthBiasState {
+            constant: -standard.constant,
+            slope_scale: -standard.slope_scale,
+            clamp: -standard.clamp,
+        }
+    }
+
+    /// Sampler border colour that reads as the far plane, so comparisons
+    /// outside a shadow map pass as lit.
+    pub fn far_border_color(self) -> wgpu::SamplerBorderColor {
+        if self.is_reversed() {
+            wgpu::SamplerBorderColor::TransparentBlack
+        } else {
+            wgpu::SamplerBorderColor::OpaqueWhite
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DepthConvention;
+    use glam::{Mat4, Vec3, Vec4};
+    use wgpu::CompareFunction;
+
+    /// Light projection in `convention`, as `shadow_matrices.wgsl` builds it.
+    fn light_view_proj(convention: DepthConvention) -> Mat4 {
+        let view_proj = Mat4::perspective_rh(1.2, 1.0, 0.1, 50.0)
+            * Mat4::look_at_rh(Vec3::new(0.0, 10.0, 0.0), Vec3::ZERO, Vec3::Z);
+        if !convention.is_reversed() {
+            return view_proj;
+        }
+        let flip = Mat4::from_cols(
+            Vec4::new(1.0, 0.0, 0.0, 0.0),
+            Vec4::new(0.0, 1.0, 0.0, 0.0),
+            Vec4::new(0.0, 0.0, -1.0, 0.0),
+            Vec4::new(0.0, 0.0, 1.0, 1.0),
+        );
+        flip * view_proj
+    }
+
+    fn atlas_depth(convention: DepthConvention, height: f32) -> f32 {
+        let clip = light_view_proj(convention) * Vec4::new(0.0, height, 0.0, 1.0);
+        clip.z / clip.w
+    }
+
+    fn passes(compare: CompareFunction, reference: f32, stored: f32) -> bool {
+        match compare {
+            CompareFunction::Less => reference < stored,
+            CompareFunction::LessEqual => reference <= stored,
+            CompareFunction::Greater => reference > stored,
+            CompareFunction::GreaterEqual => reference >= stored,
+            other => panic!("unexpected shadow comparison {other:?}"),
+        }
+    }
+
+    /// Whether a receiver at `receiver` metres above the ground is lit past a
+    /// caster at `caster`, following the PCF path of `deferred_lighting.wgsl`.
+    fn lit(convention: DepthConvention, caster: f32, receiver: f32) -> bool {
+        const DEPTH_BIAS: f32 = 0.0005;
+        // shadow_depth(): the involution between the atlas convention and
+        // standard depth.
+        let shadow_depth = |d: f32| if convention.is_reversed() { 1.0 - d } else { d };
+
+        // Cleared face, then a caster drawn with the convention's depth test.
+        let mut stored = convention.far_depth();
+        let caster_depth = atlas_depth(convention, caster);
+        if passes(convention.compare(CompareFunction::Less), caster_depth, stored) {
+            stored = caster_depth;
+        }
+
+        let reference = shadow_depth(shadow_depth(atlas_depth(convention, receiver)) - DEPTH_BIAS);
+        passes(convention.compare(CompareFunction::LessEqual), reference, stored)
+    }
+
+    #[test]
+    fn reversed_projection_maps_near_to_one() {
+        for height in [9.0, 5.0, 0.0, -20.0] {
+            let standard = atlas_depth(DepthConvention::Standard, height);
+            let reversed = atlas_depth(DepthConvention::Reversed, height);
+            assert!((reversed - (1.0 - standard)).abs() < 1e-6, "height {height}");
+        }
+    }
+
+    #[test]
+    fn shadow_decisions_match_across_conventions() {
+        let cases = [
+            // (caster height, receiver height, lit)
+            (2.0, 0.0, false),
+            (2.0, 2.0, true),
+            (2.0, 3.0, true),
+            (9.5, -30.0, false),
+            (0.0, 0.5, true),
+        ];
+        for convention in [DepthConvention::Standard, DepthConvention::Reversed] {
+            for (caster, receiver, expected) in cases {
+                assert_eq!(
+                    lit(convention, caster, receiver),
+                    expected,
+                    "{convention:?}: caster {caster}, receiver {receiver}"
+                );
+            }
+        }
+    }
+}
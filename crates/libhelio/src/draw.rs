@@ -67,3 +67,12 @@ impl DrawIndexedIndirectArgs {
     }
 }
 
+/// Debug mode that colours front faces green and back faces red, with front
+/// faces whose vertex normal points away from the camera (inverted normals)
+/// in yellow. Back-face culling is disabled while it is active, so meshes with
+/// flipped winding show up instead of disappearing.
+pub const DEBUG_MODE_FACE_ORIENTATION: u32 = 41;
+
+/// [`DEBUG_MODE_FACE_ORIENTATION`] with the world normal direction blended in
+/// as RGB (R=+X, G=+Y, B=+Z).
+pub const DEBUG_MODE_FACE_ORIENTATION_NORMALS: u32 = 42;
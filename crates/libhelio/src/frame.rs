@@ -21,6 +21,47 @@ pub struct BillboardFrameData<'a> {
     pub generation: u64,
 }
 
+/// How a billboard instance is blended onto the target.
+///
+/// Stored per instance as an `f32` in `scale_flags.w`, so a zeroed field
+/// (every instance written before blend modes existed) means `Alpha`.
+/// `BillboardPass` groups instances by mode and draws each group with its
+/// own pipeline, in the order `Opaque`, `Multiply`, `Alpha`, `Additive`.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BillboardBlendMode {
+    /// Standard "over" blending by the sprite alpha.
+    #[default]
+    Alpha = 0,
+    /// Adds `rgb * alpha` to the target. Glows, fire, sparks, light shafts.
+    Additive = 1,
+    /// Multiplies the target by the tint, faded towards white by alpha.
+    /// Shadows blobs, smoke darkening, stained glass.
+    Multiply = 2,
+    /// No blending; writes depth. Alpha below 0.01 is still discarded.
+    Opaque = 3,
+}
+
+impl BillboardBlendMode {
+    /// Every mode, in draw order.
+    pub const DRAW_ORDER: [Self; 4] = [Self::Opaque, Self::Multiply, Self::Alpha, Self::Additive];
+
+    /// Value stored in `scale_flags.w`.
+    pub fn as_f32(self) -> f32 {
+        self as u32 as f32
+    }
+
+    /// Decode `scale_flags.w`. Unknown values fall back to `Alpha`.
+    pub fn from_f32(value: f32) -> Self {
+        match value.round() as i32 {
+            1 => Self::Additive,
+            2 => Self::Multiply,
+            3 => Self::Opaque,
+            _ => Self::Alpha,
+        }
+    }
+}
+
 /// Views into the GBuffer textures.
 ///
 /// Produced by `GBufferPass`, consumed by `DeferredLightingPass`, `SsaoPass`, etc.
@@ -71,12 +112,39 @@ pub struct MainSceneResources<'a> {
     pub clear_color: [f32; 4],
     pub ambient_color: [f32; 3],
     pub ambient_intensity: f32,
+    /// Hemisphere ground colour for normals facing down. `None` derives it
+    /// from `ambient_color`.
+    pub ambient_ground_color: Option<[f32; 3]>,
+    /// Directional ambient that replaces the sky and ground colours (still
+    /// scaled by `ambient_intensity`). `None` uses the hemisphere.
+    pub ambient_sh: Option<crate::AmbientSh>,
+    /// Optional non-shadowing directional fill added to the ambient term.
+    pub fill_light: Option<crate::FillLight>,
+    /// Camera distances (start, end) over which shadows fade to fully lit.
+    /// `end <= start` disables the fade.
+    pub shadow_fade_range: [f32; 2],
     /// Radiance Cascades volume bounds (dual-tier GI: RC near, ambient far).
     /// RC active within these bounds, simpler ambient fallback outside.
     pub rc_world_min: [f32; 3],
     pub rc_world_max: [f32; 3],
     /// Hardware ray tracing TLAS, if available. None on non-RT hardware or WASM.
     pub tlas: Option<&'a wgpu::Tlas>,
+    /// How the first pass to draw into the scene colour starts the frame.
+    pub color_load: LoadOp,
+    /// How the first pass to draw into the main depth buffer starts the frame.
+    pub depth_load: LoadOp,
+}
+
+/// Whether a frame starts by clearing a main render target or drawing over
+/// what it already holds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LoadOp {
+    /// Colour to black (or the sky), depth to the far plane.
+    #[default]
+    Clear,
+    /// Keep the previous contents, for accumulation or compositing over an
+    /// externally provided background.
+    Load,
 }
 
 /// Debug-tracked resource slot.
@@ -194,7 +262,7 @@ pub struct FrameResources<'a> {
     /// Populated by GBufferPass, consumed by DeferredLightPass and SssBlurPass.
     pub gbuffer_sss: Tracked<&'a wgpu::TextureView>,
     /// GBuffer extra surface data (Rgba16Float): roughness_aniso_x, roughness_aniso_y,
-    /// aniso_rotation, bitcast<f32>(surface_flags).
+    /// aniso_rotation, f32(surface_flags).
     /// Populated by GBufferPass, consumed by DeferredLightPass.
     pub gbuffer_extra: Tracked<&'a wgpu::TextureView>,
     /// Shadow atlas (2D array texture view) — populated after ShadowPass (dynamic/Movable objects)
@@ -216,8 +284,17 @@ pub struct FrameResources<'a> {
     pub sky_lut: Tracked<&'a wgpu::TextureView>,
     /// Sky LUT sampler (linear, clamp)
     pub sky_lut_sampler: Tracked<&'a wgpu::Sampler>,
+    /// Gradient sky uniforms (`GpuGradientSky`), published by GradientSkyPass
+    /// when it drew the background this frame.
+    pub sky_gradient: Tracked<&'a wgpu::Buffer>,
     /// SSAO result texture
     pub ssao: Tracked<&'a wgpu::TextureView>,
+    /// Screen-space contact shadow mask (R8Unorm, 1 = lit), populated by
+    /// ContactShadowsPass. Applies to light `contact_shadow_light` only.
+    pub contact_shadows: Tracked<&'a wgpu::TextureView>,
+    /// Index into the GPU light buffer of the light `contact_shadows` was
+    /// traced toward; `u32::MAX` when no contact shadows were traced.
+    pub contact_shadow_light: u32,
     /// Volumetric fog accumulation, internal resolution (or a divisor of it).
     /// rgb = in-scattered radiance, a = transmittance to the surface.
     pub fog_accum: Tracked<&'a wgpu::TextureView>,
@@ -432,7 +509,10 @@ impl<'a> FrameResources<'a> {
             static_hiz_sampler: Tracked::empty(),
             sky_lut: Tracked::empty(),
             sky_lut_sampler: Tracked::empty(),
+            sky_gradient: Tracked::empty(),
             ssao: Tracked::empty(),
+            contact_shadows: Tracked::empty(),
+            contact_shadow_light: u32::MAX,
             fog_accum: Tracked::empty(),
             pre_aa: Tracked::empty(),
             tile_light_lists: Tracked::empty(),
@@ -504,7 +584,9 @@ impl<'a> FrameResources<'a> {
             reset_field!(static_hiz_sampler);
             reset_field!(sky_lut);
             reset_field!(sky_lut_sampler);
+            reset_field!(sky_gradient);
             reset_field!(ssao);
+            reset_field!(contact_shadows);
             reset_field!(pre_aa);
             reset_field!(tile_light_lists);
             reset_field!(tile_light_counts);
@@ -537,6 +619,23 @@ impl<'a> FrameResources<'a> {
             reset_field!(planar_reflection_sampler);
         }
     }
+
+    /// Load op for the first pass writing the scene colour: `clear`, unless
+    /// the renderer asked to keep the previous contents.
+    pub fn scene_color_load_op(&self, clear: wgpu::Color) -> wgpu::LoadOp<wgpu::Color> {
+        match self.main_scene.get().map(|m| m.color_load) {
+            Some(LoadOp::Load) => wgpu::LoadOp::Load,
+            _ => wgpu::LoadOp::Clear(clear),
+        }
+    }
+
+    /// Load op for the first pass writing the main depth buffer.
+    pub fn scene_depth_load_op(&self) -> wgpu::LoadOp<f32> {
+        match self.main_scene.get().map(|m| m.depth_load) {
+            Some(LoadOp::Load) => wgpu::LoadOp::Load,
+            _ => wgpu::LoadOp::Clear(1.0),
+        }
+    }
 }
 
 /// Per-frame virtual geometry data: immutable mesh/object slices and dirty-tracked instances.
@@ -552,6 +651,8 @@ pub struct VgFrameData<'a> {
     pub objects: &'a [u8],
     /// Raw bytes of a `GpuInstanceData` array (one entry per VG object).
     pub instances: &'a [u8],
+    /// Raw bytes of a `GpuWindParams` array, parallel to `instances`.
+    pub wind: &'a [u8],
     /// Raw bytes of immutable `GpuVgWorkItem` expansion records.
     pub work_items: &'a [u8],
     /// Number of unique meshlet descriptors across all referenced virtual meshes.
@@ -14,6 +14,7 @@
 pub mod camera;
 pub mod corona;
 pub mod decal;
+pub mod depth;
 pub mod draw;
 pub mod frame;
 pub mod instance;
@@ -27,10 +28,12 @@ pub mod shader;
 pub mod shadow;
 pub mod sky;
 pub mod water;
+pub mod wind;
 
 pub use camera::*;
 pub use corona::*;
 pub use decal::*;
+pub use depth::*;
 pub use draw::*;
 pub use frame::*;
 pub use instance::*;
@@ -41,5 +44,6 @@ pub use movability::*;
 pub use postprocess::*;
 pub use reflection::*;
 pub use shadow::*;
-pub use sky::{SkyActor, VolumetricClouds};
+pub use sky::{GpuGradientSky, GradientSky, SkyActor, TimeOfDay, VolumetricClouds};
 pub use water::*;
+pub use wind::*;
@@ -29,15 +29,15 @@ pub enum LightType {
 ///     god_rays_weight:   f32,
 ///     god_rays_decay:    f32,
 ///     god_rays_exposure: f32,
-///     _pad2_0:           u32,
-///     _pad2_1:           u32,
-///     _pad2_2:           u32,
+///     shadow_bias:        f32,
+///     shadow_normal_bias: f32,
+///     shadow_resolution_shift: u32,
 /// }
 /// ```
 ///
-/// The tail padding is three scalars, not a `vec3<u32>`: a WGSL `vec3` has
-/// 16-byte alignment, so it would be pushed from offset 84 to 96 and grow the
-/// struct to 112 — silently mismatching the 96-byte Rust side.
+/// The tail is three scalars, not a `vec3`: a WGSL `vec3` has 16-byte
+/// alignment, so it would be pushed from offset 84 to 96 and grow the struct
+/// to 112 — silently mismatching the 96-byte Rust side.
 ///
 /// # Layout contract
 ///
@@ -85,7 +85,20 @@ pub struct GpuLight {
     pub god_rays_decay: f32,
     /// Final scale applied to the accumulated shaft radiance.
     pub god_rays_exposure: f32,
-    pub _pad2: [u32; 3],
+
+    // ── Shadow bias (lighting pass shadow lookup) ──
+    /// Offset subtracted from the receiver's light-space depth (0..1 NDC)
+    /// before the shadow comparison. Raise to remove acne; too much detaches
+    /// shadows from their casters (peter-panning).
+    pub shadow_bias: f32,
+    /// World-space distance the receiver is pushed along its normal before
+    /// projecting into the shadow map, scaled by `1 - N·L` so grazing surfaces
+    /// get the full amount. Wide lights with coarse texels need more.
+    pub shadow_normal_bias: f32,
+    /// Atlas resolution tier of this light's shadow faces, assigned by
+    /// `Scene::flush` under [`AdaptiveShadowResolution`](crate::AdaptiveShadowResolution):
+    /// faces cover `atlas_size >> shift` texels per side. 0 = full resolution.
+    pub shadow_resolution_shift: u32,
 }
 
 // The WGSL mirrors above assume this exact size. A storage-buffer array of
@@ -116,11 +129,131 @@ impl Default for GpuLight {
             god_rays_weight: 0.6,
             god_rays_decay: 1.0,
             god_rays_exposure: 0.7,
-            _pad2: [0; 3],
+
+            // The caster pipeline's slope-scaled bias handles most acne; the
+            // normal offset covers the rest at grazing angles.
+            shadow_bias: 0.0,
+            shadow_normal_bias: 0.01,
+            shadow_resolution_shift: 0,
         }
     }
 }
 
+/// Non-shadowing directional fill used to shape the ambient term.
+///
+/// Diffuse only and never takes a shadow slot, so it costs a few ALU ops per
+/// pixel. Typically placed opposite the key light so shadowed sides keep
+/// their form instead of flattening into constant ambient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FillLight {
+    /// Direction the light travels (from the light towards the scene).
+    pub direction: [f32; 3],
+    /// Linear RGB colour.
+    pub color: [f32; 3],
+    pub intensity: f32,
+}
+
+/// Directional ambient as order-2 (L2, nine coefficient) spherical harmonics.
+///
+/// Stores the cosine-convolved irradiance divided by π, so
+/// [`evaluate`](Self::evaluate) returns the colour the ambient term multiplies
+/// albedo by for a given normal: uniform radiance `c` evaluates to `c` in
+/// every direction, like the constant ambient it replaces.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AmbientSh {
+    /// Linear RGB coefficients in band order: `(0,0)`, `(1,-1)`, `(1,0)`,
+    /// `(1,1)`, `(2,-2)`, `(2,-1)`, `(2,0)`, `(2,1)`, `(2,2)`.
+    pub coefficients: [[f32; 3]; 9],
+}
+
+/// Directions used to project radiance onto SH. Fibonacci-spaced, so each
+/// sample covers an equal solid angle.
+const AMBIENT_SH_SAMPLES: usize = 256;
+
+impl AmbientSh {
+    /// Project a radiance function over the sphere. `radiance` receives unit
+    /// world directions (+Y up) and returns linear RGB.
+    ///
+    /// Use it to derive ambient from a sky model or an averaged environment.
+    pub fn from_radiance(mut radiance: impl FnMut([f32; 3]) -> [f32; 3]) -> Self {
+        let mut projected = [[0.0f32; 3]; 9];
+        let golden_angle = std::f32::consts::PI * (3.0 - 5.0f32.sqrt());
+        for i in 0..AMBIENT_SH_SAMPLES {
+            let y = 1.0 - (i as f32 + 0.5) * 2.0 / AMBIENT_SH_SAMPLES as f32;
+            let r = (1.0 - y * y).sqrt();
+            let phi = golden_angle * i as f32;
+            let dir = [r * phi.cos(), y, r * phi.sin()];
+            let color = radiance(dir);
+            for (coefficient, basis) in projected.iter_mut().zip(sh_basis(dir)) {
+                for c in 0..3 {
+                    coefficient[c] += color[c] * basis;
+                }
+            }
+        }
+        // Monte Carlo weight (4π / N), then the cosine lobe per band divided
+        // by π: 1, 2/3, 1/4.
+        let weight = 4.0 * std::f32::consts::PI / AMBIENT_SH_SAMPLES as f32;
+        for (index, coefficient) in projected.iter_mut().enumerate() {
+            let band_scale = match index {
+                0 => 1.0,
+                1..=3 => 2.0 / 3.0,
+                _ => 0.25,
+            };
+            for c in coefficient.iter_mut() {
+                *c *= weight * band_scale;
+            }
+        }
+        Self { coefficients: projected }
+    }
+
+    /// Sky colour for upward normals, ground colour for downward ones,
+    /// blended across the horizon.
+    pub fn from_hemisphere(sky: [f32; 3], ground: [f32; 3]) -> Self {
+        Self::from_radiance(|dir| {
+            let t = dir[1] * 0.5 + 0.5;
+            std::array::from_fn(|c| ground[c] + (sky[c] - ground[c]) * t)
+        })
+    }
+
+    /// Ambient lit by a gradient sky seen in every direction.
+    pub fn from_gradient_sky(sky: &crate::GradientSky) -> Self {
+        Self::from_radiance(|dir| sky.sample(dir[1]))
+    }
+
+    /// Ambient colour for a surface with unit normal `normal`.
+    pub fn evaluate(&self, normal: [f32; 3]) -> [f32; 3] {
+        let mut color = [0.0f32; 3];
+        for (coefficient, basis) in self.coefficients.iter().zip(sh_basis(normal)) {
+            for c in 0..3 {
+                color[c] += coefficient[c] * basis;
+            }
+        }
+        color.map(|c| c.max(0.0))
+    }
+
+    /// Coefficients as `vec4` rows with the real-SH basis constants folded in,
+    /// matching `ambient_sh` in deferred_lighting.wgsl.
+    pub fn to_gpu(&self) -> [[f32; 4]; 9] {
+        std::array::from_fn(|i| {
+            let [r, g, b] = self.coefficients[i];
+            let k = SH_BASIS_CONSTANTS[i];
+            [r * k, g * k, b * k, 0.0]
+        })
+    }
+}
+
+/// Normalisation constants of the real SH basis up to band 2.
+const SH_BASIS_CONSTANTS: [f32; 9] = [
+    0.282_095, 0.488_603, 0.488_603, 0.488_603, 1.092_548, 1.092_548, 0.315_392, 1.092_548, 0.546_274,
+];
+
+/// Real SH basis functions up to band 2 for unit direction `d`.
+fn sh_basis(d: [f32; 3]) -> [f32; 9] {
+    let [x, y, z] = d;
+    let polynomials = [1.0, y, z, x, x * y, y * z, 3.0 * z * z - 1.0, x * z, x * x - y * y];
+    std::array::from_fn(|i| SH_BASIS_CONSTANTS[i] * polynomials[i])
+}
+
 /// Per-light shadow matrix for the shadow map atlas.
 /// Layout: one `mat4x4<f32>` = 64 bytes, matching `LightMatrix` in all WGSL shaders.
 /// 6 consecutive entries per light (indices light_idx*6 .. light_idx*6+5):
@@ -134,3 +267,84 @@ pub struct GpuShadowMatrix {
     pub light_view_proj: [f32; 16],
 }
 
+
+/// One colour band of the light-complexity debug view.
+///
+/// Covers tiles lit by `min_lights..=max_lights` lights after culling.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LightComplexityBand {
+    pub min_lights: u32,
+    pub max_lights: u32,
+    /// Linear RGB the band is drawn in.
+    pub color: [f32; 3],
+}
+
+/// Debug mode that tints every 16×16 screen tile by how many lights survived
+/// culling for it, with this legend drawn along the bottom edge.
+pub const DEBUG_MODE_LIGHT_COMPLEXITY: u32 = 40;
+
+/// Debug mode that replaces the lit image with the radiance-cascade
+/// irradiance each pixel samples (before albedo), to check GI injection and
+/// propagation. Black when the pipeline runs no radiance cascades.
+pub const DEBUG_MODE_GI_IRRADIANCE: u32 = 43;
+
+/// Legend for [`DEBUG_MODE_LIGHT_COMPLEXITY`], from no lights (dark grey)
+/// through green (few) to red (many). Bands double in width; the last one is
+/// a full tile list (64 lights), where further lights are dropped.
+pub const LIGHT_COMPLEXITY_LEGEND: [LightComplexityBand; 8] = [
+    LightComplexityBand { min_lights: 0, max_lights: 0, color: [0.05, 0.05, 0.05] },
+    LightComplexityBand { min_lights: 1, max_lights: 1, color: [0.0, 0.6, 0.0] },
+    LightComplexityBand { min_lights: 2, max_lights: 3, color: [0.4, 0.8, 0.0] },
+    LightComplexityBand { min_lights: 4, max_lights: 7, color: [0.8, 0.8, 0.0] },
+    LightComplexityBand { min_lights: 8, max_lights: 15, color: [1.0, 0.5, 0.0] },
+    LightComplexityBand { min_lights: 16, max_lights: 31, color: [1.0, 0.2, 0.0] },
+    LightComplexityBand { min_lights: 32, max_lights: 63, color: [0.7, 0.0, 0.0] },
+    LightComplexityBand { min_lights: 64, max_lights: 64, color: [1.0, 0.0, 1.0] },
+];
+
+/// Index into [`LIGHT_COMPLEXITY_LEGEND`] for a tile lit by `light_count` lights.
+pub fn light_complexity_band(light_count: u32) -> usize {
+    if light_count == 0 {
+        return 0;
+    }
+    (light_count.ilog2() as usize + 1).min(LIGHT_COMPLEXITY_LEGEND.len() - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{light_complexity_band, AmbientSh, LIGHT_COMPLEXITY_LEGEND};
+
+    #[test]
+    fn light_complexity_bands_are_contiguous() {
+        assert_eq!(LIGHT_COMPLEXITY_LEGEND[0].min_lights, 0);
+        for pair in LIGHT_COMPLEXITY_LEGEND.windows(2) {
+            assert_eq!(pair[1].min_lights, pair[0].max_lights + 1);
+        }
+        for count in 0..=64 {
+            let band = LIGHT_COMPLEXITY_LEGEND[light_complexity_band(count)];
+            assert!(
+                (band.min_lights..=band.max_lights).contains(&count),
+                "{count} lights fell in band {band:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn ambient_sh_reproduces_constant_and_hemisphere_ambient() {
+        let constant = AmbientSh::from_radiance(|_| [0.2, 0.4, 0.6]);
+        for normal in [[0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, -0.6, 0.8]] {
+            let color = constant.evaluate(normal);
+            for (got, want) in color.iter().zip([0.2, 0.4, 0.6]) {
+                assert!((got - want).abs() < 1e-3, "{normal:?}: {color:?}");
+            }
+        }
+
+        let hemisphere = AmbientSh::from_hemisphere([1.0, 1.0, 1.0], [0.0, 0.0, 0.0]);
+        let up = hemisphere.evaluate([0.0, 1.0, 0.0])[0];
+        let side = hemisphere.evaluate([1.0, 0.0, 0.0])[0];
+        let down = hemisphere.evaluate([0.0, -1.0, 0.0])[0];
+        assert!(up > side && side > down, "{up} {side} {down}");
+        // Irradiance of a linear gradient: 0.5 ± 1/3 at the poles.
+        assert!((up - 5.0 / 6.0).abs() < 1e-2 && (side - 0.5).abs() < 1e-2);
+    }
+}
@@ -22,6 +22,9 @@ pub const FLAG_HAS_CLEAR_COAT: u32 = 1 << 4;
 pub const FLAG_HAS_SUBSURFACE: u32 = 1 << 5;
 pub const FLAG_HAS_ANISOTROPY: u32 = 1 << 6;
 pub const FLAG_HAS_CUSTOM_SHADER: u32 = 1 << 7;
+/// Skip lighting, shadows and GI: the surface shows its base colour
+/// (times any texture) plus emissive.
+pub const FLAG_UNLIT: u32 = 1 << 8;
 
 /// Material class shader archetypes.
 pub const MATERIAL_CLASS_DEFAULT: u32 = 0;
@@ -29,6 +32,10 @@ pub const MATERIAL_CLASS_CLEAR_COAT: u32 = 1;
 pub const MATERIAL_CLASS_SUBSURFACE: u32 = 2;
 pub const MATERIAL_CLASS_ANISOTROPIC: u32 = 3;
 pub const MATERIAL_CLASS_SKIN: u32 = 4;
+/// Procedural reference pattern; `class_params` come from [`ProceduralPattern::class_params`].
+pub const MATERIAL_CLASS_PATTERN: u32 = 5;
+/// Triplanar projection; `class_params` come from [`TriplanarMapping::class_params`].
+pub const MATERIAL_CLASS_TRIPLANAR: u32 = 6;
 pub const MATERIAL_CLASS_CUSTOM: u32 = 0xFFFF;
 
 /// GPU material data. 112 bytes.
@@ -83,5 +90,195 @@ pub struct GpuMaterial {
 impl GpuMaterial {
     /// Index used to indicate "no texture bound"
     pub const NO_TEXTURE: u32 = u32::MAX;
+
+    /// Whether the material is rendered unlit ([`FLAG_UNLIT`]).
+    pub fn unlit(&self) -> bool {
+        self.flags & FLAG_UNLIT != 0
+    }
+
+    /// Render the material unlit: base colour (times any texture) plus
+    /// emissive, skipping lighting, shadows and GI. Suits light bulbs,
+    /// screens, UI and debug geometry.
+    pub fn set_unlit(&mut self, unlit: bool) {
+        if unlit {
+            self.flags |= FLAG_UNLIT;
+        } else {
+            self.flags &= !FLAG_UNLIT;
+        }
+    }
+
+    /// Builder form of [`set_unlit`](Self::set_unlit).
+    pub fn with_unlit(mut self, unlit: bool) -> Self {
+        self.set_unlit(unlit);
+        self
+    }
+}
+
+/// Shape drawn by [`ProceduralPattern`].
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PatternKind {
+    /// Alternating squares.
+    #[default]
+    Checker = 0,
+    /// Thin colour-B lines on colour A at every cell boundary.
+    Grid = 1,
+    /// One colour-B disc per cell on colour A.
+    Dots = 2,
+}
+
+/// Coordinates a [`ProceduralPattern`] is laid out in.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PatternSpace {
+    /// World units, projected along the surface's dominant axis. Cells have
+    /// the same size on every object, which makes scale errors obvious.
+    #[default]
+    World = 0,
+    /// Mesh texture coordinates. Stretching or seams expose UV problems.
+    Uv = 1,
+}
+
+/// Procedural checker / grid / dots albedo for test scenes and reference
+/// floors, evaluated by the [`MATERIAL_CLASS_PATTERN`] template.
+///
+/// The pattern replaces the base colour; roughness, metallic, normal and
+/// emissive inputs of the material still apply.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProceduralPattern {
+    pub kind: PatternKind,
+    pub space: PatternSpace,
+    /// Cells per unit: per metre in world space, per 0..1 UV span otherwise.
+    pub scale: f32,
+    /// Linear RGB. Stored at 8 bits per channel.
+    pub color_a: [f32; 3],
+    /// Linear RGB. Stored at 8 bits per channel.
+    pub color_b: [f32; 3],
+}
+
+impl Default for ProceduralPattern {
+    fn default() -> Self {
+        Self {
+            kind: PatternKind::Checker,
+            space: PatternSpace::World,
+            scale: 1.0,
+            color_a: [0.8, 0.8, 0.8],
+            color_b: [0.2, 0.2, 0.2],
+        }
+    }
+}
+
+impl ProceduralPattern {
+    /// Pack into `GpuMaterial::class_params`: x = kind + 4 × space,
+    /// y = scale, z/w = colours as 8-bit RGB words (`r | g << 8 | b << 16`)
+    /// stored as integer-valued floats, which 24 bits keep exact. A bit-cast
+    /// word would land on NaN and infinity patterns for many colours.
+    pub fn class_params(&self) -> [f32; 4] {
+        fn pack(rgb: [f32; 3]) -> f32 {
+            let [r, g, b] = rgb.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u32);
+            (r | (g << 8) | (b << 16)) as f32
+        }
+        [
+            (self.kind as u32 + 4 * self.space as u32) as f32,
+            self.scale.max(0.0),
+            pack(self.color_a),
+            pack(self.color_b),
+        ]
+    }
+}
+
+/// Coordinates a [`TriplanarMapping`] projects from.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TriplanarSpace {
+    /// World position and normal. Texture stays put as objects move, so
+    /// adjacent terrain chunks and CSG pieces line up seamlessly.
+    #[default]
+    World = 0,
+    /// Mesh-local position and normal. Texture moves, rotates and scales
+    /// with the object.
+    Object = 1,
 }
 
+/// Texture projection along the three axes of [`space`](Self::space),
+/// blended by the surface normal, evaluated by the
+/// [`MATERIAL_CLASS_TRIPLANAR`] template. Needs no mesh UVs, which suits
+/// terrain, CSG and procedural primitives.
+///
+/// Base colour, roughness/metallic, occlusion and emissive textures of the
+/// material are projected; their UV transforms still apply on each axis.
+/// Normal maps are ignored, since tangent space comes from the UVs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TriplanarMapping {
+    pub space: TriplanarSpace,
+    /// Texture repeats per unit of `space`.
+    pub scale: f32,
+    /// Exponent on the normal when blending axes. 1 gives soft, wide
+    /// transitions; higher values narrow them towards hard seams.
+    pub sharpness: f32,
+}
+
+impl Default for TriplanarMapping {
+    fn default() -> Self {
+        Self {
+            space: TriplanarSpace::World,
+            scale: 1.0,
+            sharpness: 4.0,
+        }
+    }
+}
+
+impl TriplanarMapping {
+    /// Pack into `GpuMaterial::class_params`: x = space, y = scale,
+    /// z = sharpness (at least 1).
+    pub fn class_params(&self) -> [f32; 4] {
+        [
+            self.space as u32 as f32,
+            self.scale.max(0.0),
+            self.sharpness.max(1.0),
+            0.0,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Mirrors `unpack4x8unorm(u32(x)).rgb` in `pattern.wgsl`.
+    fn unpack(packed: f32) -> [f32; 3] {
+        assert!(packed.is_finite() && packed.fract() == 0.0, "{packed} is not an integer");
+        let word = packed as u32;
+        [0, 8, 16].map(|shift| ((word >> shift) & 0xff) as f32 / 255.0)
+    }
+
+    #[test]
+    fn pattern_colours_round_trip() {
+        // Bright blues and whites bit-cast to NaN / infinity under the old
+        // packing; every colour must survive as an exact integer.
+        for color_a in [[1.0, 1.0, 1.0], [0.0, 0.0, 1.0], [0.2, 0.6, 1.0], [0.0; 3]] {
+            let pattern = ProceduralPattern {
+                color_a,
+                color_b: [0.5, 0.25, 0.75],
+                ..ProceduralPattern::default()
+            };
+            let params = pattern.class_params();
+            for (packed, rgb) in [(params[2], pattern.color_a), (params[3], pattern.color_b)] {
+                let unpacked = unpack(packed);
+                for (got, want) in unpacked.iter().zip(rgb) {
+                    assert!((got - want).abs() <= 0.5 / 255.0 + 1e-6, "{unpacked:?} vs {rgb:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn unlit_toggles_only_its_flag() {
+        let lit = GpuMaterial { flags: FLAG_DOUBLE_SIDED, ..GpuMaterial::zeroed() };
+        assert!(!lit.unlit());
+        let unlit = lit.with_unlit(true);
+        assert!(unlit.unlit());
+        assert_eq!(unlit.flags, FLAG_DOUBLE_SIDED | FLAG_UNLIT);
+        assert_eq!(unlit.with_unlit(false).flags, FLAG_DOUBLE_SIDED);
+    }
+}
@@ -22,6 +22,150 @@ pub enum ExposureMode {
     Auto = 1,
 }
 
+// ── Metering mode ──────────────────────────────────────────────────────────────
+
+/// How auto exposure weights pixels when building the luminance histogram.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MeteringMode {
+    /// Every pixel counts equally.
+    #[default]
+    Average = 0,
+    /// Pixels near the centre of the screen count more, falling off towards
+    /// the edges — keeps a bright sky at the top of frame from darkening the
+    /// subject.
+    CenterWeighted = 1,
+}
+
+// ── Bloom composite mode ───────────────────────────────────────────────────────
+
+/// How the blurred bloom chain is combined with the scene.
+///
+/// Either way the composite happens on the exposed scene-linear HDR colour,
+/// after exposure and before colour grading and tonemapping, so the
+/// tonemapper compresses scene and bloom together.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BloomCompositeMode {
+    /// Add the bloom chain on top of the scene, scaled by `bloom_intensity`.
+    /// Brightens the frame overall; can clip regions that were already bright.
+    #[default]
+    Additive = 0,
+    /// Redistribute light instead of adding it: the bright part extracted at
+    /// each pixel is swapped for the blurred chain, weighted by
+    /// `bloom_intensity` (clamped to 0..=1). Total energy is preserved, so
+    /// highlights spread without raising the exposed frame's brightness.
+    /// With `bloom_threshold = 0` this is `lerp(scene, blurred, intensity)`.
+    EnergyConserving = 1,
+}
+
+// ── Output transfer function ───────────────────────────────────────────────────
+
+/// Encoding applied to the tonemapped colour as the very last step of the
+/// post-process uber shader, before it is written to the output target.
+///
+/// On `*Srgb` targets the hardware applies the sRGB curve on write, so the
+/// shader pre-compensates for it and [`OutputTransfer::Srgb`] costs nothing.
+/// Pick the transfer from the swapchain format and colour space:
+/// [`for_surface_format`](Self::for_surface_format) covers the SDR and scRGB
+/// cases, but HDR10 shares `Rgb10a2Unorm` with SDR swapchains, so
+/// [`Pq`](Self::Pq) must be chosen explicitly when the surface is HDR10.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum OutputTransfer {
+    /// IEC 61966-2-1 sRGB curve (SDR displays).
+    #[default]
+    Srgb,
+    /// ITU-R BT.709 camera OETF (broadcast SDR).
+    Rec709,
+    /// SMPTE ST 2084 (PQ) with BT.2020 primaries, for HDR10 swapchains.
+    /// Tonemapped 1.0 maps to `peak_nits`.
+    Pq { peak_nits: f32 },
+    /// Linear BT.709 with extended range, for `Rgba16Float` (scRGB)
+    /// swapchains. 1.0 is 80 nits; values above 1.0 pass through.
+    ScRgbLinear,
+}
+
+impl OutputTransfer {
+    /// Peak luminance used by [`OutputTransfer::pq`].
+    pub const DEFAULT_PQ_PEAK_NITS: f32 = 1000.0;
+
+    /// HDR10 output at [`DEFAULT_PQ_PEAK_NITS`](Self::DEFAULT_PQ_PEAK_NITS).
+    pub fn pq() -> Self {
+        Self::Pq { peak_nits: Self::DEFAULT_PQ_PEAK_NITS }
+    }
+
+    /// Transfer matching a swapchain format: scRGB-linear for `Rgba16Float`,
+    /// sRGB for everything else.
+    pub fn for_surface_format(format: wgpu::TextureFormat) -> Self {
+        match format {
+            wgpu::TextureFormat::Rgba16Float => Self::ScRgbLinear,
+            _ => Self::Srgb,
+        }
+    }
+
+    /// Whether the encoded signal can exceed SDR white.
+    pub fn is_hdr(self) -> bool {
+        matches!(self, Self::Pq { .. } | Self::ScRgbLinear)
+    }
+
+    /// Value of the `OUTPUT_TRANSFER` override in `postprocess.wgsl`.
+    pub fn shader_id(self) -> u32 {
+        match self {
+            Self::Srgb => 0,
+            Self::Rec709 => 1,
+            Self::Pq { .. } => 2,
+            Self::ScRgbLinear => 3,
+        }
+    }
+
+    /// Value of the `OUTPUT_PEAK_NITS` override (only PQ reads it).
+    pub fn peak_nits(self) -> f32 {
+        match self {
+            Self::Pq { peak_nits } => peak_nits.max(1.0),
+            _ => Self::DEFAULT_PQ_PEAK_NITS,
+        }
+    }
+
+    /// CPU reference of the shader's encoding of a linear BT.709 colour,
+    /// before any hardware sRGB write.
+    pub fn encode(self, linear: [f32; 3]) -> [f32; 3] {
+        match self {
+            Self::Srgb => linear.map(|c| srgb_oetf(c.clamp(0.0, 1.0))),
+            Self::Rec709 => linear.map(|c| rec709_oetf(c.clamp(0.0, 1.0))),
+            Self::Pq { .. } => {
+                let [r, g, b] = linear.map(|c| c.max(0.0));
+                let rec2020 = [
+                    0.6274 * r + 0.3293 * g + 0.0433 * b,
+                    0.0691 * r + 0.9195 * g + 0.0114 * b,
+                    0.0164 * r + 0.0880 * g + 0.8956 * b,
+                ];
+                let scale = self.peak_nits() / 10_000.0;
+                rec2020.map(|c| pq_oetf(c * scale))
+            }
+            Self::ScRgbLinear => linear,
+        }
+    }
+}
+
+fn srgb_oetf(c: f32) -> f32 {
+    if c <= 0.003_130_8 { c * 12.92 } else { 1.055 * c.powf(1.0 / 2.4) - 0.055 }
+}
+
+fn rec709_oetf(c: f32) -> f32 {
+    if c < 0.018 { c * 4.5 } else { 1.099 * c.powf(0.45) - 0.099 }
+}
+
+/// ST 2084 inverse EOTF; `y` is luminance / 10 000 nits.
+fn pq_oetf(y: f32) -> f32 {
+    const M1: f32 = 0.159_301_76;
+    const M2: f32 = 78.843_75;
+    const C1: f32 = 0.835_937_5;
+    const C2: f32 = 18.851_563;
+    const C3: f32 = 18.6875;
+    let ym = y.clamp(0.0, 1.0).powf(M1);
+    ((C1 + C2 * ym) / (1.0 + C3 * ym)).powf(M2)
+}
+
 // ── GpuPostProcessUniforms ─────────────────────────────────────────────────────
 //
 // Flat uniform struct uploaded to GPU each frame. All fields are driven by the
@@ -39,7 +183,7 @@ pub struct GpuPostProcessUniforms {
     // ── Bloom (8 x 4 = 32 bytes) ──
     pub bloom_intensity: f32,
     pub bloom_threshold: f32,
-    pub bloom_knee: f32,              // soft knee around threshold
+    pub bloom_knee: f32,              // soft-knee half-width around threshold (scene-linear)
     pub bloom_radius: f32,            // scatter size (1.0 = default)
     pub bloom_tint: [f32; 3],
     pub bloom_enabled: u32,
@@ -114,44 +258,50 @@ pub struct GpuPostProcessUniforms {
     pub blend_weight_ca: f32,
     pub blend_weight_grain: f32,
     pub blend_weight_exposure: f32,
-    pub pad_bw: f32,
+    pub bloom_composite: u32,         // BloomCompositeMode discriminant
+
+    // ── Exposure adaptation (16 bytes) ──
+    pub exposure_speed_up: f32,       // seconds to adapt to a brighter scene
+    pub exposure_speed_down: f32,     // seconds to adapt to a darker scene
+    pub exposure_metering: u32,       // MeteringMode discriminant
+    pub pad_exp: f32,
 
     // ── Volumetric Fog (64 bytes) ──
     // Consumed by helio-pass-volumetric-fog (accumulation) and by fs_uber (composite).
     //
-    // Field order is deliberate: the two vec3s sit at offsets 336 and 352, both
+    // Field order is deliberate: the two vec3s sit at offsets 352 and 368, both
     // multiples of 16. WGSL aligns vec3<f32> to 16 bytes, so a vec3 placed at a
     // non-multiple-of-16 offset is silently pushed forward on the GPU while
     // #[repr(C)] keeps it put — skewing every field after it. The scalars are
     // grouped ahead of the vectors to pad the block out naturally.
-    pub fog_enabled: u32,               // 304
-    pub fog_mode: u32,                  // 308 — FogMode discriminant
-    pub fog_density: f32,               // 312
-    pub fog_height_falloff: f32,        // 316 — exponential decay for height fog
-    pub fog_start_distance: f32,        // 320 — distance from camera where fog begins
-    pub fog_max_distance: f32,          // 324 — distance at which fog reaches full opacity
-    pub fog_height: f32,                // 328 — base world height for height fog
-    pub fog_scattering_anisotropy: f32, // 332 — Henyey-Greenstein g, (-1, 1)
-    pub fog_color: [f32; 3],            // 336 ← 16-aligned
-    pub pad_fog_color: f32,             // 348
-    pub fog_emissive: [f32; 3],         // 352 ← 16-aligned
-    pub pad_fog_emissive: f32,          // 364
+    pub fog_enabled: u32,               // 320
+    pub fog_mode: u32,                  // 324 — FogMode discriminant
+    pub fog_density: f32,               // 328
+    pub fog_height_falloff: f32,        // 332 — exponential decay for height fog
+    pub fog_start_distance: f32,        // 336 — distance from camera where fog begins
+    pub fog_max_distance: f32,          // 340 — distance at which fog reaches full opacity
+    pub fog_height: f32,                // 344 — base world height for height fog
+    pub fog_scattering_anisotropy: f32, // 348 — Henyey-Greenstein g, (-1, 1)
+    pub fog_color: [f32; 3],            // 352 ← 16-aligned
+    pub pad_fog_color: f32,             // 364
+    pub fog_emissive: [f32; 3],         // 368 ← 16-aligned
+    pub pad_fog_emissive: f32,          // 380
 }
 
-// Total: 16 + 32 + 80 + 16 + 16 + 32 + 16 + 16 + 32 + 16 + 32 + 64 = 368 bytes
-// WGSL uniform buffer rule: must be multiple of 16 → 368 / 16 = 23 slots. ✓
+// Total: 16 + 32 + 80 + 16 + 16 + 32 + 16 + 16 + 32 + 16 + 32 + 16 + 64 = 384 bytes
+// WGSL uniform buffer rule: must be multiple of 16 → 384 / 16 = 24 slots. ✓
 //
 // This struct is mirrored by hand in helio-pass-postprocess/shaders/postprocess.wgsl
 // and is embedded in GpuPostProcessVolume, which cs_volume_blend reads as a storage
 // array. A field added here without updating that mirror misreads the buffer silently.
-const _: () = assert!(std::mem::size_of::<GpuPostProcessUniforms>() == 368);
+const _: () = assert!(std::mem::size_of::<GpuPostProcessUniforms>() == 384);
 const _: () = assert!(std::mem::size_of::<GpuPostProcessUniforms>() % 16 == 0);
 
 // ── GpuFogUniforms ─────────────────────────────────────────────────────────────
 
 /// The fog block of [`GpuPostProcessUniforms`], standalone.
 ///
-/// The volumetric fog pass binds this instead of mirroring all 368 bytes of
+/// The volumetric fog pass binds this instead of mirroring all 384 bytes of
 /// `GpuPostProcessUniforms` in WGSL: it needs 64 of them, and a third hand-written
 /// mirror of the full struct is a third thing to keep in sync. The pass copies the
 /// block out of the post-process uniform buffer at [`GpuPostProcessUniforms::FOG_BLOCK_OFFSET`],
@@ -285,7 +435,12 @@ impl Default for GpuPostProcessUniforms {
             blend_weight_ca: 1.0,
             blend_weight_grain: 1.0,
             blend_weight_exposure: 1.0,
-            pad_bw: 0.0,
+            bloom_composite: BloomCompositeMode::Additive as u32,
+
+            exposure_speed_up: 0.5,
+            exposure_speed_down: 1.0,
+            exposure_metering: MeteringMode::Average as u32,
+            pad_exp: 0.0,
 
             fog_enabled: 0,
             fog_mode: FogMode::Uniform as u32,
@@ -317,14 +472,23 @@ pub struct PostProcessSettings {
     pub exposure_max: f32,
     pub exposure_speed_up: f32,     // seconds to bright-adapt
     pub exposure_speed_down: f32,   // seconds to dark-adapt
+    /// Pixel weighting used by [`ExposureMode::Auto`].
+    pub exposure_metering: MeteringMode,
 
     // Bloom
     pub bloom_intensity: f32,
+    /// Luminance at which pixels start to bloom, measured on the scene-linear
+    /// HDR colour before exposure and tonemapping (1.0 = diffuse white under
+    /// unit lighting). Pre-exposure is divided out before the threshold test,
+    /// so the threshold does not move when the pre-exposure factor changes.
     pub bloom_threshold: f32,
+    /// Half-width of the soft knee around `bloom_threshold`, in the same
+    /// units. 0 gives a hard cut; larger values fade bloom in gradually.
     pub bloom_knee: f32,
     pub bloom_radius: f32,
     pub bloom_tint: [f32; 3],
     pub bloom_enabled: bool,
+    pub bloom_composite: BloomCompositeMode,
 
     // Color Grading
     pub color_saturation: [f32; 3],
@@ -403,6 +567,30 @@ pub struct PostProcessSettings {
 }
 
 impl PostProcessSettings {
+    /// Set the bloom threshold (scene-linear luminance, clamped to >= 0).
+    pub fn set_bloom_threshold(&mut self, threshold: f32) {
+        self.bloom_threshold = threshold.max(0.0);
+    }
+
+    /// Set the soft-knee half-width around the bloom threshold (clamped to >= 0).
+    pub fn set_bloom_knee(&mut self, knee: f32) {
+        self.bloom_knee = knee.max(0.0);
+    }
+
+    /// Set the EV range auto exposure may choose from. The bounds are
+    /// swapped if given out of order.
+    pub fn set_exposure_range(&mut self, min_ev: f32, max_ev: f32) {
+        self.exposure_min = min_ev.min(max_ev);
+        self.exposure_max = min_ev.max(max_ev);
+    }
+
+    /// Set how long auto exposure takes to adapt, in seconds, to a brighter
+    /// and to a darker scene (clamped to >= 0; 0 snaps immediately).
+    pub fn set_exposure_adaptation(&mut self, speed_up: f32, speed_down: f32) {
+        self.exposure_speed_up = speed_up.max(0.0);
+        self.exposure_speed_down = speed_down.max(0.0);
+    }
+
     /// Pack CPU settings into GPU uniform struct.
     pub fn to_gpu(&self) -> GpuPostProcessUniforms {
         GpuPostProcessUniforms {
@@ -477,7 +665,12 @@ impl PostProcessSettings {
             blend_weight_ca: self.blend_weight_ca,
             blend_weight_grain: self.blend_weight_grain,
             blend_weight_exposure: self.blend_weight_exposure,
-            pad_bw: 0.0,
+            bloom_composite: self.bloom_composite as u32,
+
+            exposure_speed_up: self.exposure_speed_up.max(0.0),
+            exposure_speed_down: self.exposure_speed_down.max(0.0),
+            exposure_metering: self.exposure_metering as u32,
+            pad_exp: 0.0,
 
             fog_enabled: self.fog_enabled as u32,
             fog_mode: self.fog_mode as u32,
@@ -505,6 +698,7 @@ impl Default for PostProcessSettings {
             exposure_max: 4.0,
             exposure_speed_up: 0.5,
             exposure_speed_down: 1.0,
+            exposure_metering: MeteringMode::Average,
 
             bloom_intensity: 0.3,
             bloom_threshold: 1.0,
@@ -512,6 +706,7 @@ impl Default for PostProcessSettings {
             bloom_radius: 1.0,
             bloom_tint: [1.0, 1.0, 1.0],
             bloom_enabled: false,
+            bloom_composite: BloomCompositeMode::Additive,
 
             color_saturation: [1.0, 1.0, 1.0],
             color_contrast: [1.0, 1.0, 1.0],
@@ -604,7 +799,7 @@ pub struct GpuPostProcessVolume {
 // WGSL places `settings` at 64 because GpuPostProcessUniforms aligns to 16.
 const _: () = assert!(std::mem::offset_of!(GpuPostProcessVolume, settings) == 64);
 // Storage-buffer array stride must match WGSL's, which rounds to the 16-byte alignment.
-const _: () = assert!(std::mem::size_of::<GpuPostProcessVolume>() == 432);
+const _: () = assert!(std::mem::size_of::<GpuPostProcessVolume>() == 448);
 const _: () = assert!(std::mem::size_of::<GpuPostProcessVolume>() % 16 == 0);
 
 // ── PostProcessVolume descriptor (CPU-side) ────────────────────────────────────
@@ -735,6 +930,7 @@ impl PostProcessBlender {
             exposure_max: lerp(a.exposure_max, b.exposure_max, t),
             exposure_speed_up: lerp(a.exposure_speed_up, b.exposure_speed_up, t),
             exposure_speed_down: lerp(a.exposure_speed_down, b.exposure_speed_down, t),
+            exposure_metering: if t > 0.5 { b.exposure_metering } else { a.exposure_metering },
 
             bloom_intensity: lerp(a.bloom_intensity, b.bloom_intensity, t),
             bloom_threshold: lerp(a.bloom_threshold, b.bloom_threshold, t),
@@ -742,6 +938,7 @@ impl PostProcessBlender {
             bloom_radius: lerp(a.bloom_radius, b.bloom_radius, t),
             bloom_tint: lerp3(a.bloom_tint, b.bloom_tint, t),
             bloom_enabled: if t > 0.5 { b.bloom_enabled } else { a.bloom_enabled },
+            bloom_composite: if t > 0.5 { b.bloom_composite } else { a.bloom_composite },
 
             color_saturation: lerp3(a.color_saturation, b.color_saturation, t),
             color_contrast: lerp3(a.color_contrast, b.color_contrast, t),
@@ -818,8 +1015,9 @@ fn unpack_settings(gpu: &GpuPostProcessUniforms) -> PostProcessSettings {
         exposure_compensation: gpu.exposure_compensation,
         exposure_min: gpu.exposure_min,
         exposure_max: gpu.exposure_max,
-        exposure_speed_up: 0.5,
-        exposure_speed_down: 1.0,
+        exposure_speed_up: gpu.exposure_speed_up,
+        exposure_speed_down: gpu.exposure_speed_down,
+        exposure_metering: if gpu.exposure_metering == 1 { MeteringMode::CenterWeighted } else { MeteringMode::Average },
 
         bloom_intensity: gpu.bloom_intensity,
         bloom_threshold: gpu.bloom_threshold,
@@ -827,6 +1025,11 @@ fn unpack_settings(gpu: &GpuPostProcessUniforms) -> PostProcessSettings {
         bloom_radius: gpu.bloom_radius,
         bloom_tint: gpu.bloom_tint,
         bloom_enabled: gpu.bloom_enabled != 0,
+        bloom_composite: if gpu.bloom_composite == 1 {
+            BloomCompositeMode::EnergyConserving
+        } else {
+            BloomCompositeMode::Additive
+        },
 
         color_saturation: gpu.color_saturation,
         color_contrast: gpu.color_contrast,
@@ -900,3 +1103,35 @@ fn unpack_settings(gpu: &GpuPostProcessUniforms) -> PostProcessSettings {
         fog_emissive: gpu.fog_emissive,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    #[test]
+    fn output_transfers_match_reference_values() {
+        assert!(close(OutputTransfer::Srgb.encode([0.5; 3])[0], 0.7354));
+        assert!(close(OutputTransfer::Rec709.encode([0.5; 3])[0], 0.7055));
+        assert_eq!(OutputTransfer::ScRgbLinear.encode([4.0, 0.5, 0.0]), [4.0, 0.5, 0.0]);
+
+        // ST 2084 code values: 100 nits ≈ 0.508, 1000 nits ≈ 0.752, 10 000 nits = 1.
+        let white = |peak_nits| OutputTransfer::Pq { peak_nits }.encode([1.0; 3]);
+        assert!(white(100.0).iter().all(|&c| close(c, 0.5081)));
+        assert!(white(1000.0).iter().all(|&c| close(c, 0.7518)));
+        assert!(white(10_000.0).iter().all(|&c| close(c, 1.0)));
+        assert_eq!(OutputTransfer::pq().encode([0.0; 3]), [pq_oetf(0.0); 3]);
+    }
+
+    #[test]
+    fn surface_formats_pick_a_transfer() {
+        use wgpu::TextureFormat as F;
+        assert_eq!(OutputTransfer::for_surface_format(F::Bgra8UnormSrgb), OutputTransfer::Srgb);
+        assert_eq!(OutputTransfer::for_surface_format(F::Bgra8Unorm), OutputTransfer::Srgb);
+        assert_eq!(OutputTransfer::for_surface_format(F::Rgba16Float), OutputTransfer::ScRgbLinear);
+        assert!(OutputTransfer::ScRgbLinear.is_hdr() && !OutputTransfer::Rec709.is_hdr());
+    }
+}
@@ -12,6 +12,15 @@ use bytemuck::{Pod, Zeroable};
 /// `helio-pass-shadow-matrix/shaders/shadow_matrices.wgsl`.
 pub const CSM_SPLITS: [f32; 4] = [16.0, 80.0, 300.0, 1400.0];
 
+/// Directional lights that get their own cascaded shadow maps.
+///
+/// Each shadowed directional (sun, moon, key, rim) takes one caster slot with
+/// its own four frustum-fitted cascades. Directionals are granted slots ahead
+/// of point and spot lights, in light-buffer order, so the first inserted
+/// directional is the key light. Directionals beyond this cap stay lit but
+/// unshadowed, leaving the rest of the atlas to local lights.
+pub const MAX_DIRECTIONAL_SHADOW_CASTERS: usize = 4;
+
 /// Shadow quality presets for runtime configuration.
 ///
 /// The Vogel disk uses a stable per-pixel hash so noise is static across
@@ -42,7 +51,7 @@ pub struct CascadeConfig {
     pub pcss_light_size: f32,
 }
 
-/// Global shadow configuration (96 bytes, GPU uniform).
+/// Global shadow configuration (112 bytes, GPU uniform).
 ///
 /// Uploaded to GPU as a uniform buffer for runtime shadow quality control.
 /// Changes to this config require a single buffer write (delta upload).
@@ -60,6 +69,12 @@ pub struct ShadowConfig {
     /// Standard PCF sample count (non-PCSS path). Quality-driven.
     /// Low=4, Medium=8, High=12, Ultra=16
     pub pcf_sample_count: u32,
+    /// x = distance from the camera where shadows start fading, y = distance
+    /// where they are gone (metres). Disabled when `y <= x`. zw unused.
+    pub fade_range: [f32; 4],
+    /// 1 when the shadow atlas uses [`DepthConvention::Reversed`](crate::DepthConvention).
+    pub reversed_depth: u32,
+    pub _pad: [u32; 3],
 }
 
 impl Default for ShadowConfig {
@@ -103,6 +118,9 @@ impl ShadowConfig {
                 pcss_blocker_samples: 4,
                 pcss_filter_samples: 4,
                 pcf_sample_count: 8,
+                fade_range: [0.0; 4],
+                reversed_depth: 0,
+                _pad: [0; 3],
             },
             ShadowQuality::Medium => Self {
                 cascades: [
@@ -135,6 +153,9 @@ impl ShadowConfig {
                 pcss_blocker_samples: 8,
                 pcss_filter_samples: 8,
                 pcf_sample_count: 12,
+                fade_range: [0.0; 4],
+                reversed_depth: 0,
+                _pad: [0; 3],
             },
             ShadowQuality::High => Self {
                 cascades: [
@@ -167,6 +188,9 @@ impl ShadowConfig {
                 pcss_blocker_samples: 8,
                 pcss_filter_samples: 16,
                 pcf_sample_count: 12,
+                fade_range: [0.0; 4],
+                reversed_depth: 0,
+                _pad: [0; 3],
             },
             ShadowQuality::Ultra => Self {
                 cascades: [
@@ -199,10 +223,28 @@ impl ShadowConfig {
                 pcss_blocker_samples: 16,
                 pcss_filter_samples: 32,
                 pcf_sample_count: 16,
+                fade_range: [0.0; 4],
+                reversed_depth: 0,
+                _pad: [0; 3],
             },
         }
     }
 
+    /// Fade shadows to fully lit between `start` and `end` metres from the
+    /// camera, hiding the cutoff at the last cascade or a light's range.
+    /// `end <= start` disables the fade.
+    pub fn with_fade_range(mut self, start: f32, end: f32) -> Self {
+        self.fade_range = [start, end, 0.0, 0.0];
+        self
+    }
+
+    /// Tell the sampling shaders which depth convention the atlas was
+    /// rendered with.
+    pub fn with_depth_convention(mut self, convention: crate::DepthConvention) -> Self {
+        self.reversed_depth = convention.is_reversed() as u32;
+        self
+    }
+
     /// PSSM (Practical Split Scheme) cascade distribution.
     ///
     /// Computes cascade split distances using a blend of uniform and logarithmic
@@ -224,3 +266,205 @@ impl ShadowConfig {
     }
 }
 
+
+/// Coarsest tier of [`AdaptiveShadowResolution`]: faces shrink to at most
+/// 1/2^`MAX_SHADOW_RESOLUTION_SHIFT` (1/8) of the atlas face size per side.
+pub const MAX_SHADOW_RESOLUTION_SHIFT: u32 = 3;
+
+/// Texels rendered past the edge of a reduced shadow region, so filter
+/// kernels at the border read real depth instead of the cleared remainder
+/// of the layer.
+pub const SHADOW_REGION_MARGIN: u32 = 8;
+
+/// Side length in texels of a shadow face rendered at resolution `shift`.
+pub fn shadow_region_size(atlas_size: u32, shift: u32) -> u32 {
+    (atlas_size >> shift.min(MAX_SHADOW_RESOLUTION_SHIFT)).max(1)
+}
+
+/// Tier whose faces fit within `resolution` texels per side: the largest
+/// power-of-two reduction of `atlas_size` not exceeding it, clamped to
+/// [`MAX_SHADOW_RESOLUTION_SHIFT`]. Resolutions at or above the atlas size
+/// map to full resolution.
+///
+/// Every caster shares the one shadow atlas, so a per-light resolution is a
+/// region of an atlas face, never a texture of its own: lights cannot go
+/// above the atlas face size, and shading finds the region through the
+/// light's shadow matrices rather than a per-light array or layer index.
+pub fn shadow_resolution_shift(atlas_size: u32, resolution: u32) -> u32 {
+    (0..MAX_SHADOW_RESOLUTION_SHIFT)
+        .find(|&shift| shadow_region_size(atlas_size, shift) <= resolution)
+        .unwrap_or(MAX_SHADOW_RESOLUTION_SHIFT)
+}
+
+/// Shadow resolution driven by each caster's screen coverage.
+///
+/// Every shadow-casting light gets a power-of-two tier of the atlas face
+/// size: a light whose influence fills the screen renders at full
+/// resolution, one covering a quarter of it at half, and so on down to
+/// [`MAX_SHADOW_RESOLUTION_SHIFT`]. A reduced face occupies the top-left
+/// corner of its atlas layer; the light's shadow matrices are remapped into
+/// that region, so sampling shaders are unchanged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptiveShadowResolution {
+    /// Total shadow texels across all casters, measured in full-resolution
+    /// faces (a point light at full resolution costs 6, a directional 4,
+    /// a spot 1). Casters are stepped down until the total fits.
+    pub budget_faces: f32,
+    /// How far, in tiers, coverage must move past a tier boundary before a
+    /// caster changes tier. Changing tier re-renders the caster, so this
+    /// keeps lights near a boundary from thrashing.
+    pub hysteresis: f32,
+}
+
+impl Default for AdaptiveShadowResolution {
+    fn default() -> Self {
+        Self { budget_faces: 24.0, hysteresis: 0.25 }
+    }
+}
+
+impl AdaptiveShadowResolution {
+    /// Pick a resolution shift for each caster.
+    ///
+    /// `casters` holds each caster's screen coverage (`0..=1`, see
+    /// [`light_screen_coverage`]) and the number of faces it renders;
+    /// `previous` holds last frame's shifts in the same order, or is shorter
+    /// for casters that are new.
+    pub fn allocate(&self, casters: &[(f32, u32)], previous: &[u32]) -> Vec<u32> {
+        // Ideal tier: linear resolution proportional to sqrt(coverage).
+        let levels: Vec<f32> = casters
+            .iter()
+            .map(|&(coverage, _)| {
+                (-0.5 * coverage.max(1e-6).log2()).clamp(0.0, MAX_SHADOW_RESOLUTION_SHIFT as f32)
+            })
+            .collect();
+        let mut shifts: Vec<u32> = levels
+            .iter()
+            .enumerate()
+            .map(|(i, &level)| match previous.get(i) {
+                Some(&prev) if (prev as f32 - level).abs() <= 0.5 + self.hysteresis => prev,
+                _ => level.round() as u32,
+            })
+            .collect();
+
+        let cost = |shifts: &[u32]| -> f32 {
+            casters
+                .iter()
+                .zip(shifts)
+                .map(|(&(_, faces), &shift)| faces as f32 / (1u32 << (2 * shift)) as f32)
+                .sum()
+        };
+        // Over budget: step down the caster furthest above its ideal tier
+        // (ties go to the one with more faces) until the total fits.
+        while cost(&shifts) > self.budget_faces {
+            let Some(i) = (0..shifts.len())
+                .filter(|&i| shifts[i] < MAX_SHADOW_RESOLUTION_SHIFT)
+                .min_by(|&a, &b| {
+                    let over = |i: usize| shifts[i] as f32 - levels[i];
+                    over(a).total_cmp(&over(b)).then(casters[b].1.cmp(&casters[a].1))
+                })
+            else {
+                break;
+            };
+            shifts[i] += 1;
+        }
+        shifts
+    }
+}
+
+/// Fraction of the screen (`0..=1`) covered by a light's influence volume.
+///
+/// Point and spot lights are bounded by the sphere of their range, projected
+/// with `camera`'s matrices and clipped to the screen; directional lights
+/// cover the whole screen. A camera inside the sphere also counts as full
+/// coverage.
+pub fn light_screen_coverage(light: &crate::GpuLight, camera: &crate::GpuCameraUniforms) -> f32 {
+    if light.light_type == crate::LightType::Directional as u32 {
+        return 1.0;
+    }
+    let view = glam::Mat4::from_cols_array(&camera.view);
+    let proj = glam::Mat4::from_cols_array(&camera.proj);
+    let [x, y, z, range] = light.position_range;
+    let center = view.transform_point3(glam::Vec3::new(x, y, z));
+    let range = range.max(0.0);
+    if center.length() <= range {
+        return 1.0;
+    }
+
+    let (cx, cy, rx, ry) = if proj.w_axis.w == 1.0 {
+        // Orthographic: extents do not shrink with distance.
+        let c = proj.transform_point3(center);
+        (c.x, c.y, range * proj.x_axis.x.abs(), range * proj.y_axis.y.abs())
+    } else {
+        let depth = -center.z;
+        if depth <= range {
+            // The sphere reaches the camera plane; treat it as filling the view.
+            return if depth + range > 0.0 { 1.0 } else { 0.0 };
+        }
+        (
+            center.x * proj.x_axis.x / depth,
+            center.y * proj.y_axis.y / depth,
+            range * proj.x_axis.x.abs() / depth,
+            range * proj.y_axis.y.abs() / depth,
+        )
+    };
+    let overlap = |c: f32, r: f32| ((c + r).min(1.0) - (c - r).max(-1.0)).max(0.0);
+    (overlap(cx, rx) * overlap(cy, ry) / 4.0).min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coverage_picks_tiers_and_budget_steps_down() {
+        let policy = AdaptiveShadowResolution { budget_faces: 100.0, hysteresis: 0.25 };
+        let shifts = policy.allocate(&[(1.0, 4), (0.25, 6), (0.0, 1)], &[]);
+        assert_eq!(shifts, [0, 1, MAX_SHADOW_RESOLUTION_SHIFT]);
+
+        // Just past the half-resolution boundary: a fresh caster moves,
+        // one already at full resolution stays put.
+        assert_eq!(policy.allocate(&[(0.45, 6)], &[]), [1]);
+        assert_eq!(policy.allocate(&[(0.45, 6)], &[0]), [0]);
+
+        // 3 full-resolution point lights (18 faces) into a budget of 12.
+        let tight = AdaptiveShadowResolution { budget_faces: 12.0, ..policy };
+        let shifts = tight.allocate(&[(1.0, 6), (1.0, 6), (1.0, 6)], &[]);
+        let cost: f32 = shifts.iter().map(|&s| 6.0 / (1u32 << (2 * s)) as f32).sum();
+        assert!(cost <= 12.0, "{shifts:?}");
+        assert_eq!(shifts.iter().filter(|&&s| s == 0).count(), 1);
+    }
+
+    #[test]
+    fn fixed_resolutions_round_down_to_a_tier() {
+        assert_eq!(shadow_resolution_shift(2048, 4096), 0);
+        assert_eq!(shadow_resolution_shift(2048, 2048), 0);
+        assert_eq!(shadow_resolution_shift(2048, 1024), 1);
+        assert_eq!(shadow_resolution_shift(2048, 600), 2);
+        assert_eq!(shadow_resolution_shift(2048, 16), MAX_SHADOW_RESOLUTION_SHIFT);
+    }
+
+    #[test]
+    fn distant_lights_cover_less_of_the_screen() {
+        let camera = crate::GpuCameraUniforms::new(
+            glam::Mat4::IDENTITY,
+            glam::Mat4::perspective_rh(std::f32::consts::FRAC_PI_2, 1.0, 0.1, 1000.0),
+            glam::Vec3::ZERO,
+            0.1,
+            1000.0,
+            0,
+            [0.0; 2],
+            glam::Mat4::IDENTITY,
+        );
+        let light = |z: f32| crate::GpuLight {
+            position_range: [0.0, 0.0, z, 1.0],
+            light_type: crate::LightType::Point as u32,
+            ..Default::default()
+        };
+        assert_eq!(light_screen_coverage(&light(-0.5), &camera), 1.0);
+        let near = light_screen_coverage(&light(-2.0), &camera);
+        let far = light_screen_coverage(&light(-20.0), &camera);
+        assert!((near - 0.25).abs() < 1e-5, "{near}");
+        assert!(far < near / 50.0);
+        assert_eq!(light_screen_coverage(&light(20.0), &camera), 0.0);
+    }
+}
@@ -50,6 +50,183 @@ impl Default for VolumetricClouds {
     }
 }
 
+/// Screen-space vertical gradient drawn behind the scene when there is no
+/// atmospheric sky.
+///
+/// The colour is picked by the Y component of the view ray: `horizon_color`
+/// at the horizon, blending to `zenith_color` straight up and to
+/// `ground_color` straight down. Colours are linear HDR and scaled by
+/// `intensity`, so the result goes through exposure and tone mapping like any
+/// other radiance. The deferred lighting pass also uses the gradient as the
+/// ambient and reflection fallback while it is active.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradientSky {
+    pub zenith_color: [f32; 3],
+    pub horizon_color: [f32; 3],
+    pub ground_color: [f32; 3],
+    pub intensity: f32,
+}
+
+impl Default for GradientSky {
+    fn default() -> Self {
+        Self {
+            zenith_color: [0.15, 0.3, 0.65],
+            horizon_color: [0.6, 0.7, 0.8],
+            ground_color: [0.12, 0.1, 0.08],
+            intensity: 1.0,
+        }
+    }
+}
+
+impl GradientSky {
+    /// Colour seen along a ray with vertical component `dir_y` (-1..1).
+    /// Mirrors `gradient_sky()` in the WGSL shaders.
+    pub fn sample(&self, dir_y: f32) -> [f32; 3] {
+        let y = dir_y.clamp(-1.0, 1.0);
+        let (end, t) = if y >= 0.0 {
+            (self.zenith_color, y.sqrt())
+        } else {
+            (self.ground_color, (-y).sqrt())
+        };
+        let h = self.horizon_color;
+        [
+            (h[0] + (end[0] - h[0]) * t) * self.intensity,
+            (h[1] + (end[1] - h[1]) * t) * self.intensity,
+            (h[2] + (end[2] - h[2]) * t) * self.intensity,
+        ]
+    }
+
+    /// GPU form with `intensity` folded into the colours.
+    pub fn to_gpu(&self) -> GpuGradientSky {
+        let scale = |c: [f32; 3]| [c[0] * self.intensity, c[1] * self.intensity, c[2] * self.intensity, 1.0];
+        GpuGradientSky {
+            zenith: scale(self.zenith_color),
+            horizon: scale(self.horizon_color),
+            ground: scale(self.ground_color),
+        }
+    }
+}
+
+/// Gradient sky uniforms. 48 bytes.
+///
+/// # WGSL equivalent
+/// ```wgsl
+/// struct GradientSky {
+///     zenith:  vec4<f32>,  // rgb * intensity, w = 1 when enabled
+///     horizon: vec4<f32>,
+///     ground:  vec4<f32>,
+/// }
+/// ```
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct GpuGradientSky {
+    pub zenith: [f32; 4],
+    pub horizon: [f32; 4],
+    pub ground: [f32; 4],
+}
+
+/// Sun position and colour for a day/night cycle.
+///
+/// The sun follows its real path across the sky for a given latitude and
+/// solar declination, with the world's +Y up, +X east and -Z north. As it
+/// nears the horizon its colour shifts from `noon_color` to `horizon_color`
+/// and its illuminance fades out; the ambient term blends from
+/// `night_ambient` to `day_ambient` with elevation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeOfDay {
+    /// Local solar time in hours, `0.0..24.0`; 12 is solar noon.
+    pub hours: f32,
+    /// Observer latitude in degrees, positive north.
+    pub latitude: f32,
+    /// Solar declination in degrees: 0 at the equinoxes, about ±23.44 at
+    /// the solstices.
+    pub declination: f32,
+    /// Sun colour high in the sky (linear RGB).
+    pub noon_color: [f32; 3],
+    /// Sun colour at sunrise and sunset (linear RGB).
+    pub horizon_color: [f32; 3],
+    /// Sun intensity when it is high in the sky, in lux.
+    pub sun_intensity: f32,
+    /// Ambient colour and intensity at full daylight.
+    pub day_ambient: ([f32; 3], f32),
+    /// Ambient colour and intensity once the sun is well below the horizon.
+    pub night_ambient: ([f32; 3], f32),
+}
+
+impl Default for TimeOfDay {
+    fn default() -> Self {
+        Self {
+            hours: 12.0,
+            latitude: 45.0,
+            declination: 0.0,
+            noon_color: [1.0, 0.96, 0.9],
+            horizon_color: [1.0, 0.45, 0.15],
+            sun_intensity: 3.0,
+            day_ambient: ([0.4, 0.5, 0.65], 0.3),
+            night_ambient: ([0.05, 0.07, 0.15], 0.03),
+        }
+    }
+}
+
+impl TimeOfDay {
+    /// The default sun at `hours` local solar time.
+    pub fn at(hours: f32) -> Self {
+        Self { hours, ..Self::default() }
+    }
+
+    /// Advance the clock by `hours`, wrapping into `0.0..24.0`.
+    pub fn advance(&mut self, hours: f32) {
+        self.hours = (self.hours + hours).rem_euclid(24.0);
+    }
+
+    /// Unit vector from the scene towards the sun.
+    pub fn sun_direction(&self) -> [f32; 3] {
+        let hour_angle = ((self.hours - 12.0) * 15.0).to_radians();
+        let (sin_lat, cos_lat) = self.latitude.to_radians().sin_cos();
+        let (sin_dec, cos_dec) = self.declination.to_radians().sin_cos();
+        let up = sin_lat * sin_dec + cos_lat * cos_dec * hour_angle.cos();
+        let east = -cos_dec * hour_angle.sin();
+        let north = cos_lat * sin_dec - sin_lat * cos_dec * hour_angle.cos();
+        [east, up, -north]
+    }
+
+    /// Sine of the sun's elevation above the horizon (negative at night).
+    pub fn sun_elevation(&self) -> f32 {
+        self.sun_direction()[1]
+    }
+
+    /// Direction the sunlight travels, as stored in a directional light.
+    pub fn light_direction(&self) -> [f32; 3] {
+        let [x, y, z] = self.sun_direction();
+        [-x, -y, -z]
+    }
+
+    /// Sun colour and intensity for the current elevation.
+    pub fn sun_color_intensity(&self) -> ([f32; 3], f32) {
+        let elevation = self.sun_elevation();
+        // Full noon colour from about 30° up; fully tinted at the horizon.
+        let color = lerp3(self.horizon_color, self.noon_color, smoothstep(0.0, 0.5, elevation));
+        (color, self.sun_intensity * smoothstep(-0.02, 0.1, elevation))
+    }
+
+    /// Ambient colour and intensity for the current elevation.
+    pub fn ambient(&self) -> ([f32; 3], f32) {
+        let t = smoothstep(-0.2, 0.3, self.sun_elevation());
+        let (night, night_intensity) = self.night_ambient;
+        let (day, day_intensity) = self.day_ambient;
+        (lerp3(night, day, t), night_intensity + (day_intensity - night_intensity) * t)
+    }
+}
+
+fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+    let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+fn lerp3(a: [f32; 3], b: [f32; 3], t: f32) -> [f32; 3] {
+    [a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t, a[2] + (b[2] - a[2]) * t]
+}
+
 /// Sky state passed to passes that need sky information.
 #[derive(Debug, Clone, Copy)]
 pub struct SkyContext {
@@ -61,6 +238,22 @@ pub struct SkyContext {
     pub sky_color: [f32; 3],
     /// Optional volumetric cloud properties from a `volumetric_clouds` actor.
     pub clouds: Option<VolumetricClouds>,
+    /// Gradient background. Only used when `has_sky` is false.
+    pub gradient: Option<GradientSky>,
+    /// World-space direction towards the sun for the atmosphere, e.g. from a
+    /// [`TimeOfDay`]. `None` keeps the atmosphere's built-in sun.
+    pub sun_direction: Option<[f32; 3]>,
+}
+
+impl SkyContext {
+    /// The gradient to draw this frame, if any. The atmosphere takes precedence.
+    pub fn active_gradient(&self) -> Option<GradientSky> {
+        if self.has_sky {
+            None
+        } else {
+            self.gradient
+        }
+    }
 }
 
 /// Scene actor representing a sky system (atmospheric sky + optional clouds).
@@ -83,8 +276,11 @@ impl SkyActor {
     }
 
     /// Enable atmospheric sky rendering with this sky color.
+    ///
+    /// Replaces any gradient background.
     pub fn with_sky_color(mut self, sky_color: [f32; 3]) -> Self {
         self.context.has_sky = true;
+        self.context.gradient = None;
         self.context.sky_state_changed = true;
         self.context.sky_color = sky_color;
         self
@@ -98,10 +294,24 @@ impl SkyActor {
 
     /// Attach volumetric clouds to this sky actor.
     ///
-    /// Automatically enables sky rendering when clouds are added.
+    /// Automatically enables sky rendering when clouds are added, replacing
+    /// any gradient background.
     pub fn with_clouds(mut self, clouds: VolumetricClouds) -> Self {
         self.context.clouds = Some(clouds);
         self.context.has_sky = true;
+        self.context.gradient = None;
+        self.context.sky_state_changed = true;
+        self
+    }
+
+    /// Draw a vertical gradient background instead of the atmosphere.
+    ///
+    /// The atmosphere and the gradient are mutually exclusive: this disables
+    /// atmospheric sky rendering and clouds.
+    pub fn with_gradient(mut self, gradient: GradientSky) -> Self {
+        self.context.gradient = Some(gradient);
+        self.context.has_sky = false;
+        self.context.clouds = None;
         self.context.sky_state_changed = true;
         self
     }
@@ -125,6 +335,8 @@ impl Default for SkyContext {
             sky_state_changed: false,
             sky_color: [0.1, 0.1, 0.15],
             clouds: None,
+            gradient: None,
+            sun_direction: None,
         }
     }
 }
@@ -153,9 +365,55 @@ mod tests {
             sky_state_changed: true,
             sky_color: [0.3, 0.4, 0.5],
             clouds: None,
+            gradient: None,
+            sun_direction: None,
         };
         assert!(ctx.has_sky);
         assert_eq!(ctx.sky_color, [0.3, 0.4, 0.5]);
     }
-}
 
+    #[test]
+    fn gradient_and_atmosphere_are_exclusive() {
+        let gradient = SkyActor::new().with_gradient(GradientSky::default()).context();
+        assert!(!gradient.has_sky);
+        assert!(gradient.active_gradient().is_some());
+
+        let atmosphere = SkyActor::new()
+            .with_gradient(GradientSky::default())
+            .with_sky_color([0.5, 0.6, 0.9])
+            .context();
+        assert!(atmosphere.has_sky);
+        assert!(atmosphere.active_gradient().is_none());
+    }
+
+    #[test]
+    fn gradient_hits_its_three_colors() {
+        let g = GradientSky {
+            intensity: 2.0,
+            ..GradientSky::default()
+        };
+        let close = |a: [f32; 3], b: [f32; 3]| (0..3).all(|i| (a[i] - 2.0 * b[i]).abs() < 1e-6);
+        assert!(close(g.sample(1.0), g.zenith_color));
+        assert!(close(g.sample(0.0), g.horizon_color));
+        assert!(close(g.sample(-1.0), g.ground_color));
+    }
+
+    #[test]
+    fn sun_rises_in_the_east_and_peaks_at_noon() {
+        let at = |hours| TimeOfDay { latitude: 0.0, ..TimeOfDay::at(hours) };
+        let noon = at(12.0).sun_direction();
+        assert!((noon[1] - 1.0).abs() < 1e-5);
+        let morning = at(6.0).sun_direction();
+        assert!(morning[1].abs() < 1e-5 && morning[0] > 0.99);
+        assert!(at(0.0).sun_elevation() < -0.99);
+
+        let (_, night) = at(0.0).sun_color_intensity();
+        assert_eq!(night, 0.0);
+        let (color, _) = at(6.5).sun_color_intensity();
+        assert!(color[2] < at(12.0).sun_color_intensity().0[2]);
+
+        let mut tod = TimeOfDay::at(23.0);
+        tod.advance(2.0);
+        assert!((tod.hours - 1.0).abs() < 1e-5);
+    }
+}
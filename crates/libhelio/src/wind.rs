@@ -0,0 +1,116 @@
+use bytemuck::{Pod, Zeroable};
+
+/// Angular frequency of the primary sway at `stiffness == 1`, in radians per second.
+pub const WIND_BASE_FREQUENCY: f32 = 1.7;
+
+/// Per-object wind sway for vegetation.
+///
+/// Applied in the vertex shader, so a forest of instances of one tree mesh
+/// sways with per-instance variation and no CPU vertex work. Displacement
+/// grows with the square of the vertex height above the object's origin —
+/// trunks stay planted, tips move most.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindParams {
+    /// World-space XZ direction the wind blows towards. Normalised on upload.
+    pub direction: [f32; 2],
+    /// Sway amplitude in metres at 1 m above the object's origin.
+    pub strength: f32,
+    /// Phase offset in radians. Vary it per instance so identical meshes do
+    /// not sway in lockstep.
+    pub phase: f32,
+    /// Resistance to bending. Stiffer objects sway less and faster.
+    pub stiffness: f32,
+    /// `0..=1` mask scaling the whole effect, e.g. lower for sheltered objects.
+    pub influence: f32,
+}
+
+impl Default for WindParams {
+    fn default() -> Self {
+        Self {
+            direction: [1.0, 0.0],
+            strength: 0.05,
+            phase: 0.0,
+            stiffness: 1.0,
+            influence: 1.0,
+        }
+    }
+}
+
+impl WindParams {
+    /// Shader-ready form: amplitude and frequency are resolved here so the
+    /// vertex shader only evaluates the sway.
+    pub fn to_gpu(&self) -> GpuWindParams {
+        let [x, z] = self.direction;
+        let len = (x * x + z * z).sqrt();
+        let (x, z) = if len > 1e-6 { (x / len, z / len) } else { (1.0, 0.0) };
+        let stiffness = self.stiffness.max(1e-3);
+        GpuWindParams {
+            direction_amplitude: [x, z, self.strength * self.influence.clamp(0.0, 1.0) / stiffness, self.phase],
+            frequency: [WIND_BASE_FREQUENCY * stiffness.sqrt(), 0.0, 0.0, 0.0],
+        }
+    }
+}
+
+/// Per-instance wind parameters, parallel to the instance buffer. 32 bytes.
+///
+/// All zeroes (the default for objects without wind) means no displacement.
+///
+/// # WGSL equivalent
+/// `HelioWindParams` in the shader prelude (`helio_core::shader`):
+/// ```wgsl
+/// struct HelioWindParams {
+///     direction_amplitude: vec4<f32>, // xy = XZ direction, z = amplitude, w = phase
+///     frequency:           vec4<f32>, // x = angular frequency, yzw unused
+/// }
+/// ```
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Pod, Zeroable)]
+pub struct GpuWindParams {
+    pub direction_amplitude: [f32; 4],
+    pub frequency: [f32; 4],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_gpu_resolves_amplitude_and_frequency() {
+        let gpu = WindParams {
+            direction: [3.0, 4.0],
+            strength: 0.2,
+            phase: 1.5,
+            stiffness: 4.0,
+            influence: 0.5,
+        }
+        .to_gpu();
+
+        // Direction is normalised; amplitude is strength × influence / stiffness.
+        assert_eq!(gpu.direction_amplitude, [0.6, 0.8, 0.025, 1.5]);
+        // Stiffer objects sway faster: frequency scales with sqrt(stiffness).
+        assert_eq!(gpu.frequency, [WIND_BASE_FREQUENCY * 2.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn to_gpu_clamps_degenerate_inputs() {
+        let gpu = WindParams {
+            direction: [0.0, 0.0],
+            strength: 1.0,
+            phase: 0.0,
+            stiffness: 0.0,
+            influence: 2.0,
+        }
+        .to_gpu();
+
+        // A zero direction falls back to +X instead of producing NaNs.
+        assert_eq!(&gpu.direction_amplitude[..2], &[1.0, 0.0]);
+        // Influence is clamped to 1 and stiffness floored at 1e-3.
+        assert_eq!(gpu.direction_amplitude[2], 1.0 / 1e-3);
+        assert!(gpu.frequency[0] > 0.0);
+    }
+
+    #[test]
+    fn default_gpu_params_do_not_sway() {
+        assert_eq!(GpuWindParams::default().direction_amplitude[2], 0.0);
+    }
+}
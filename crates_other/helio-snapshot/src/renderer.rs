@@ -243,7 +243,7 @@ async fn render_snapshot_async<P: AsRef<Path>>(
         god_rays_weight: 0.6,
         god_rays_decay: 1.0,
         god_rays_exposure: 0.7,
-        _pad2: [0; 3],
+        ..Default::default()
     }));
     renderer.scene_mut().insert_actor(SceneActor::light(GpuLight {
         position_range: [0.0, 0.0, 0.0, f32::MAX],
@@ -258,7 +258,7 @@ async fn render_snapshot_async<P: AsRef<Path>>(
         god_rays_weight: 0.6,
         god_rays_decay: 1.0,
         god_rays_exposure: 0.7,
-        _pad2: [0; 3],
+        ..Default::default()
     }));
 
     renderer.scene_mut().flush();
@@ -600,7 +600,7 @@ impl SnapshotBatch {
                 god_rays_weight:     0.6,
                 god_rays_decay:      1.0,
                 god_rays_exposure:   0.7,
-                _pad2:               [0; 3],
+                ..Default::default()
             }));
         }
 